@@ -3,7 +3,7 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use crate::any_calendar::{AnyCalendar, IntoAnyCalendar};
-use crate::week::{WeekCalculator, WeekOf};
+use crate::week::{RelativeUnit, WeekCalculator, WeekDate, WeekOf};
 use crate::{types, Calendar, CalendarError, DateDuration, DateDurationUnit, Iso};
 use alloc::rc::Rc;
 use alloc::sync::Arc;
@@ -252,6 +252,7 @@ impl<A: AsCalendar> Date<A> {
         let config = WeekCalculator {
             first_weekday,
             min_week_days: 0, // ignored
+            weekend: None,
         };
         config.week_of_month(self.day_of_month(), self.day_of_week())
     }
@@ -284,6 +285,127 @@ impl<A: AsCalendar> Date<A> {
         config.week_of_year(self.day_of_year_info(), self.day_of_week())
     }
 
+    /// The week date of this date: its week-based year, the week of that year, and its day of
+    /// the week.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::types::IsoWeekday;
+    /// use icu::calendar::week::WeekCalculator;
+    /// use icu::calendar::Date;
+    ///
+    /// let date = Date::try_new_iso_date(2021, 1, 1).unwrap();
+    ///
+    /// // The following info is usually locale-specific
+    /// let week_calculator = WeekCalculator::default();
+    ///
+    /// let week_date = date.week_date(&week_calculator).unwrap();
+    /// assert_eq!(week_date.year.number, 2021);
+    /// assert_eq!(week_date.week, 1);
+    /// assert_eq!(week_date.weekday, IsoWeekday::Friday);
+    /// ```
+    pub fn week_date(&self, config: &WeekCalculator) -> Result<WeekDate, CalendarError> {
+        let day_of_year_info = self.day_of_year_info();
+        let weekday = self.day_of_week();
+        let week_of = config.week_of_year(day_of_year_info, weekday)?;
+        Ok(WeekDate {
+            year: match week_of.unit {
+                RelativeUnit::Previous => day_of_year_info.prev_year,
+                RelativeUnit::Current => self.year(),
+                RelativeUnit::Next => day_of_year_info.next_year,
+            },
+            week: week_of.week,
+            weekday,
+        })
+    }
+
+    /// The ISO 8601 week date of this date.
+    ///
+    /// Weeks start on Monday, and the first week of a year is the one containing its first
+    /// Thursday. The week date is computed on the ISO equivalent of this date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::types::IsoWeekday;
+    /// use icu::calendar::Date;
+    ///
+    /// let date = Date::try_new_iso_date(2021, 1, 1).unwrap();
+    ///
+    /// // 2021-01-01 is in the last week of 2020:
+    /// let week_date = date.iso_week();
+    /// assert_eq!(week_date.year.number, 2020);
+    /// assert_eq!(week_date.week, 53);
+    /// assert_eq!(week_date.weekday, IsoWeekday::Friday);
+    /// ```
+    pub fn iso_week(&self) -> WeekDate {
+        #[allow(clippy::unwrap_used)] // ISO years are always long enough for week arithmetic
+        self.to_iso().week_date(&WeekCalculator::ISO_8601).unwrap()
+    }
+
+    /// Construct a date from a week-based year, a week of that year and a day of the week,
+    /// according to the week rules in `config`.
+    ///
+    /// The year is given in `era` like in [`Date::try_new_from_codes()`]; week-based years start
+    /// relative to the first day of the calendar year.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::types::{Era, IsoWeekday};
+    /// use icu::calendar::week::WeekCalculator;
+    /// use icu::calendar::{Date, Gregorian};
+    /// use tinystr::tinystr;
+    ///
+    /// let week_calculator = WeekCalculator::try_new(
+    ///     &icu::locid::locale!("en-US").into(),
+    /// )
+    /// .expect("locale should be present");
+    ///
+    /// let date = Date::try_new_from_week_date(
+    ///     Era(tinystr!(16, "ce")),
+    ///     2023,
+    ///     1,
+    ///     IsoWeekday::Sunday,
+    ///     &week_calculator,
+    ///     Gregorian,
+    /// )
+    /// .expect("week date should be valid");
+    ///
+    /// // The first week of 2023 in the US starts on Sunday, January 1.
+    /// assert_eq!(date, Date::try_new_gregorian_date(2023, 1, 1).unwrap());
+    ///
+    /// let week_date = date.week_date(&week_calculator).unwrap();
+    /// assert_eq!(week_date.year.number, 2023);
+    /// assert_eq!(week_date.week, 1);
+    /// ```
+    pub fn try_new_from_week_date(
+        era: types::Era,
+        year: i32,
+        week: u16,
+        weekday: types::IsoWeekday,
+        config: &WeekCalculator,
+        calendar: A,
+    ) -> Result<Self, CalendarError> {
+        let first_day = Self::try_new_from_codes(
+            era,
+            year,
+            types::MonthCode(tinystr::tinystr!(4, "M01")),
+            1,
+            calendar,
+        )?;
+        let day_of_year = config.day_of_year_for_week(
+            first_day.day_of_week(),
+            first_day.days_in_year(),
+            week,
+            weekday,
+        )?;
+        let mut iso = first_day.to_iso();
+        iso.add(DateDuration::new(0, 0, 0, day_of_year - 1));
+        Ok(Self::new_from_iso(iso, first_day.calendar))
+    }
+
     /// Construct a date from raw values for a given calendar. This does not check any
     /// invariants for the date and calendar, and should only be called by calendar implementations.
     ///
@@ -33,6 +33,7 @@ use crate::any_calendar::AnyCalendarKind;
 use crate::calendar_arithmetic::{ArithmeticDate, CalendarArithmetic};
 use crate::helpers::{div_rem_euclid64, i64_to_i32, i64_to_saturated_i32, quotient64, I32Result};
 use crate::rata_die::RataDie;
+use crate::week::WeekCalculator;
 use crate::{types, Calendar, CalendarError, Date, DateDuration, DateDurationUnit, DateTime};
 use tinystr::tinystr;
 
@@ -246,6 +247,35 @@ impl Date<Iso> {
             .map(|inner| Date::from_raw(inner, Iso))
     }
 
+    /// Construct a new ISO date from an ISO 8601 week date.
+    ///
+    /// ```rust
+    /// use icu::calendar::types::IsoWeekday;
+    /// use icu::calendar::Date;
+    ///
+    /// // 2020-W53-5 is January 1, 2021:
+    /// let date_iso = Date::try_new_iso_week_date(2020, 53, IsoWeekday::Friday)
+    ///     .expect("Failed to initialize ISO Date instance.");
+    ///
+    /// assert_eq!(date_iso, Date::try_new_iso_date(2021, 1, 1).unwrap());
+    /// assert_eq!(date_iso.iso_week().year.number, 2020);
+    /// ```
+    pub fn try_new_iso_week_date(
+        year: i32,
+        week: u16,
+        weekday: types::IsoWeekday,
+    ) -> Result<Date<Iso>, CalendarError> {
+        let mut date = Self::try_new_iso_date(year, 1, 1)?;
+        let day_of_year = WeekCalculator::ISO_8601.day_of_year_for_week(
+            date.day_of_week(),
+            date.days_in_year(),
+            week,
+            weekday,
+        )?;
+        date.add(DateDuration::new(0, 0, 0, day_of_year - 1));
+        Ok(date)
+    }
+
     /// Constructs an ISO date representing the UNIX epoch on January 1, 1970.
     pub fn unix_epoch() -> Self {
         Date::from_raw(IsoDateInner(ArithmeticDate::new_unchecked(1970, 1, 1)), Iso)
@@ -147,6 +147,7 @@ pub mod week {
     use crate::week_of;
     pub use week_of::RelativeUnit;
     pub use week_of::WeekCalculator;
+    pub use week_of::WeekDate;
     pub use week_of::WeekOf;
 }
 
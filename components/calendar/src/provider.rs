@@ -35,6 +35,7 @@ const _: () = {
     icu_calendar_data::impl_calendar_japanese_v1!(Baked);
     icu_calendar_data::impl_calendar_japanext_v1!(Baked);
     icu_calendar_data::impl_datetime_week_data_v1!(Baked);
+    icu_calendar_data::impl_datetime_week_data_v2!(Baked);
 };
 
 /// The date at which an era started
@@ -136,3 +137,80 @@ pub struct WeekDataV1 {
     /// For a given week, the minimum number of that week's days present in a given month or year for the week to be considered part of that month or year.
     pub min_week_days: u8,
 }
+
+/// An ICU4X mapping to a subset of CLDR weekData, including the weekend.
+/// See CLDR-JSON's weekData.json for more context.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[icu_provider::data_struct(marker(
+    WeekDataV2Marker,
+    "datetime/week_data@2",
+    fallback_by = "region"
+))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_calendar::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[allow(clippy::exhaustive_structs)] // used in data provider
+pub struct WeekDataV2 {
+    /// The first day of a week.
+    pub first_weekday: IsoWeekday,
+    /// For a given week, the minimum number of that week's days present in a given month or year for the week to be considered part of that month or year.
+    pub min_week_days: u8,
+    /// The days of the week that are part of the weekend.
+    pub weekend: WeekdaySet,
+}
+
+/// A set of weekdays, stored as a bitset.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct WeekdaySet(u8);
+
+impl WeekdaySet {
+    /// Creates a [`WeekdaySet`] containing the given days.
+    pub const fn new(mut days: &[IsoWeekday]) -> Self {
+        let mut bits = 0;
+        while let [day, rest @ ..] = days {
+            bits |= Self::bit(*day);
+            days = rest;
+        }
+        Self(bits)
+    }
+
+    /// Returns whether `day` is in this set.
+    pub const fn contains(&self, day: IsoWeekday) -> bool {
+        self.0 & Self::bit(day) != 0
+    }
+
+    const fn bit(day: IsoWeekday) -> u8 {
+        1 << (day as u8 - 1)
+    }
+}
+
+#[cfg(feature = "datagen")]
+impl databake::Bake for WeekdaySet {
+    fn bake(&self, env: &databake::CrateEnv) -> databake::TokenStream {
+        env.insert("icu_calendar");
+        let days = (1..=7)
+            .map(IsoWeekday::from)
+            .filter(|&day| self.contains(day))
+            .map(|day| databake::Bake::bake(&day, env));
+        databake::quote! {
+            icu_calendar::provider::WeekdaySet::new(&[#(#days),*])
+        }
+    }
+}
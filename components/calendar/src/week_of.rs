@@ -4,8 +4,8 @@
 
 use crate::{
     error::CalendarError,
-    provider::WeekDataV1,
-    types::{DayOfMonth, DayOfYearInfo, IsoWeekday, WeekOfMonth},
+    provider::{WeekDataV1, WeekDataV2, WeekdaySet},
+    types::{DayOfMonth, DayOfYearInfo, FormattableYear, IsoWeekday, WeekOfMonth},
 };
 use icu_provider::prelude::*;
 
@@ -21,6 +21,8 @@ pub struct WeekCalculator {
     /// For a given week, the minimum number of that week's days present in a given month or year
     /// for the week to be considered part of that month or year.
     pub min_week_days: u8,
+    /// The days of the week that are part of the weekend, if known. See [`Self::weekend`].
+    pub(crate) weekend: Option<WeekdaySet>,
 }
 
 impl From<WeekDataV1> for WeekCalculator {
@@ -28,6 +30,7 @@ impl From<WeekDataV1> for WeekCalculator {
         Self {
             first_weekday: other.first_weekday,
             min_week_days: other.min_week_days,
+            weekend: None,
         }
     }
 }
@@ -37,11 +40,40 @@ impl From<&WeekDataV1> for WeekCalculator {
         Self {
             first_weekday: other.first_weekday,
             min_week_days: other.min_week_days,
+            weekend: None,
+        }
+    }
+}
+
+impl From<WeekDataV2> for WeekCalculator {
+    fn from(other: WeekDataV2) -> Self {
+        Self {
+            first_weekday: other.first_weekday,
+            min_week_days: other.min_week_days,
+            weekend: Some(other.weekend),
+        }
+    }
+}
+
+impl From<&WeekDataV2> for WeekCalculator {
+    fn from(other: &WeekDataV2) -> Self {
+        Self {
+            first_weekday: other.first_weekday,
+            min_week_days: other.min_week_days,
+            weekend: Some(other.weekend),
         }
     }
 }
 
 impl WeekCalculator {
+    /// The week rules of ISO 8601: weeks start on Monday, and the first week of a year is the
+    /// one containing its first Thursday.
+    pub(crate) const ISO_8601: Self = Self {
+        first_weekday: IsoWeekday::Monday,
+        min_week_days: 4,
+        weekend: Some(WeekdaySet::new(&[IsoWeekday::Saturday, IsoWeekday::Sunday])),
+    };
+
     icu_provider::gen_any_buffer_data_constructors!(
         locale: include,
         options: skip,
@@ -64,6 +96,39 @@ impl WeekCalculator {
             .map_err(Into::into)
     }
 
+    icu_provider::gen_any_buffer_data_constructors!(
+        locale: include,
+        options: skip,
+        error: CalendarError,
+        /// Creates a new [`WeekCalculator`] that also knows the [`weekend`](Self::weekend), from
+        /// compiled locale data.
+        functions: [
+            try_new_with_weekend,
+            try_new_with_weekend_with_any_provider,
+            try_new_with_weekend_with_buffer_provider,
+            try_new_with_weekend_unstable,
+            Self,
+        ]
+    );
+
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::try_new_with_weekend)]
+    pub fn try_new_with_weekend_unstable<P>(
+        provider: &P,
+        locale: &DataLocale,
+    ) -> Result<Self, CalendarError>
+    where
+        P: DataProvider<crate::provider::WeekDataV2Marker>,
+    {
+        provider
+            .load(DataRequest {
+                locale,
+                metadata: Default::default(),
+            })
+            .and_then(DataResponse::take_payload)
+            .map(|payload| payload.get().into())
+            .map_err(Into::into)
+    }
+
     /// Returns the week of month according to a calendar with min_week_days = 1.
     ///
     /// This is different from what the UTS35 spec describes [1] but the latter is
@@ -135,6 +200,112 @@ impl WeekCalculator {
         )
     }
 
+    /// Returns the days of the week that are part of the weekend, in the order of the week
+    /// starting on [`Self::first_weekday`], or `None` if the weekend is not known.
+    ///
+    /// The weekend is not necessarily contiguous, and it can be empty. It is not known for
+    /// calculators created with [`Self::try_new`] and its variants, which load week data without
+    /// the weekend. Calculators created with [`Self::try_new_with_weekend`] and its variants use
+    /// the weekend of the locale, and [`WeekCalculator::default()`] uses Saturday and Sunday.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_calendar::types::IsoWeekday;
+    /// use icu_calendar::week::WeekCalculator;
+    ///
+    /// let us = WeekCalculator::try_new_with_weekend(&icu_locid::locale!("und-US").into())
+    ///     .expect("locale should be present");
+    /// assert_eq!(
+    ///     us.weekend().map(Iterator::collect::<Vec<_>>),
+    ///     Some(vec![IsoWeekday::Sunday, IsoWeekday::Saturday])
+    /// );
+    ///
+    /// // The week starts on Saturday in Egypt:
+    /// let eg = WeekCalculator::try_new_with_weekend(&icu_locid::locale!("und-EG").into())
+    ///     .expect("locale should be present");
+    /// assert_eq!(
+    ///     eg.weekend().map(Iterator::collect::<Vec<_>>),
+    ///     Some(vec![IsoWeekday::Saturday, IsoWeekday::Friday])
+    /// );
+    ///
+    /// let india = WeekCalculator::try_new_with_weekend(&icu_locid::locale!("und-IN").into())
+    ///     .expect("locale should be present");
+    /// assert_eq!(
+    ///     india.weekend().map(Iterator::collect::<Vec<_>>),
+    ///     Some(vec![IsoWeekday::Sunday])
+    /// );
+    ///
+    /// // The week data loaded by `try_new` does not include the weekend:
+    /// let us = WeekCalculator::try_new(&icu_locid::locale!("und-US").into())
+    ///     .expect("locale should be present");
+    /// assert!(us.weekend().is_none());
+    /// ```
+    pub fn weekend(&self) -> Option<impl Iterator<Item = IsoWeekday>> {
+        let first_weekday = self.first_weekday;
+        let weekend = self.weekend?;
+        Some(
+            (0..7)
+                .map(move |i| add_to_weekday(first_weekday, i))
+                .filter(move |&day| weekend.contains(day)),
+        )
+    }
+
+    /// Returns the 1-based local day of the week of `weekday`, i.e. its position in a week that
+    /// starts on [`Self::first_weekday`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_calendar::types::IsoWeekday;
+    /// use icu_calendar::week::WeekCalculator;
+    ///
+    /// let week_calculator = WeekCalculator::try_new(
+    ///     &icu_locid::locale!("en-US").into(),
+    /// )
+    /// .expect("locale should be present");
+    ///
+    /// // Weeks start on Sunday in the US:
+    /// assert_eq!(1, week_calculator.local_day_of_week(IsoWeekday::Sunday));
+    /// assert_eq!(2, week_calculator.local_day_of_week(IsoWeekday::Monday));
+    /// assert_eq!(7, week_calculator.local_day_of_week(IsoWeekday::Saturday));
+    /// ```
+    pub fn local_day_of_week(&self, weekday: IsoWeekday) -> u8 {
+        self.weekday_index(weekday) as u8 + 1
+    }
+
+    /// Returns the 1-based day of year of `weekday` in week `week` of a year that starts on
+    /// `first_day` and is `days_in_year` long.
+    ///
+    /// The returned value can be less than 1 or greater than `days_in_year` if the requested day
+    /// falls into the previous or next year.
+    pub(crate) fn day_of_year_for_week(
+        &self,
+        first_day: IsoWeekday,
+        days_in_year: u16,
+        week: u16,
+        weekday: IsoWeekday,
+    ) -> Result<i32, CalendarError> {
+        let unit = UnitInfo::new(first_day, days_in_year)?;
+        if week < 1 {
+            return Err(CalendarError::Underflow {
+                field: "week",
+                min: 1,
+            });
+        }
+        let num_weeks = unit.num_weeks(self);
+        if week > num_weeks {
+            return Err(CalendarError::Overflow {
+                field: "week",
+                max: num_weeks as usize,
+            });
+        }
+        Ok(i32::from(unit.first_week_offset(self))
+            + 7 * (i32::from(week) - 1)
+            + i32::from(self.weekday_index(weekday))
+            + 1)
+    }
+
     /// Returns the zero based index of `weekday` vs this calendar's start of week.
     fn weekday_index(&self, weekday: IsoWeekday) -> i8 {
         (7 + (weekday as i8) - (self.first_weekday as i8)) % 7
@@ -146,6 +317,7 @@ impl Default for WeekCalculator {
         Self {
             first_weekday: IsoWeekday::Monday,
             min_week_days: 1,
+            weekend: Some(WeekdaySet::new(&[IsoWeekday::Saturday, IsoWeekday::Sunday])),
         }
     }
 }
@@ -255,6 +427,21 @@ pub struct WeekOf {
     pub unit: RelativeUnit,
 }
 
+/// A date expressed as a week-based year, a week of that year, and a day of the week.
+///
+/// The week-based year can differ from the calendar year for days at the start or end of a year;
+/// e.g. 2021-01-01 is in week 53 of week-based year 2020 per ISO 8601.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct WeekDate {
+    /// The week-based year.
+    pub year: FormattableYear,
+    /// The week of the week-based year. 1 based.
+    pub week: u16,
+    /// The day of the week.
+    pub weekday: IsoWeekday,
+}
+
 /// Computes & returns the week of given month/year according to `calendar`.
 ///
 /// # Arguments
@@ -313,6 +500,7 @@ pub fn simple_week_of(first_weekday: IsoWeekday, day: u16, week_day: IsoWeekday)
     let calendar = WeekCalculator {
         first_weekday,
         min_week_days: 1,
+        weekend: None,
     };
 
     #[allow(clippy::unwrap_used)] // week_of should can't fail with MIN_UNIT_DAYS
@@ -332,21 +520,29 @@ pub fn simple_week_of(first_weekday: IsoWeekday, day: u16, week_day: IsoWeekday)
 #[cfg(test)]
 mod tests {
     use super::{week_of, RelativeUnit, RelativeWeek, UnitInfo, WeekCalculator, WeekOf};
-    use crate::{error::CalendarError, types::IsoWeekday, Date, DateDuration};
+    use crate::buddhist::Buddhist;
+    use crate::coptic::Coptic;
+    use crate::gregorian::Gregorian;
+    use crate::hebrew::Hebrew;
+    use crate::provider::WeekdaySet;
+    use crate::{error::CalendarError, types::IsoWeekday, AsCalendar, Date, DateDuration, Iso};
 
     static ISO_CALENDAR: WeekCalculator = WeekCalculator {
         first_weekday: IsoWeekday::Monday,
         min_week_days: 4,
+        weekend: None,
     };
 
     static AE_CALENDAR: WeekCalculator = WeekCalculator {
         first_weekday: IsoWeekday::Saturday,
         min_week_days: 4,
+        weekend: None,
     };
 
     static US_CALENDAR: WeekCalculator = WeekCalculator {
         first_weekday: IsoWeekday::Sunday,
         min_week_days: 1,
+        weekend: None,
     };
 
     #[test]
@@ -454,6 +650,7 @@ mod tests {
                 let calendar = WeekCalculator {
                     first_weekday: IsoWeekday::from(start_of_week),
                     min_week_days,
+                    weekend: None,
                 };
                 for unit_duration in super::MIN_UNIT_DAYS..400 {
                     for start_of_unit in 1..7 {
@@ -565,6 +762,135 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_weekend() {
+        let mut calendar = WeekCalculator {
+            first_weekday: IsoWeekday::Saturday,
+            min_week_days: 1,
+            weekend: Some(WeekdaySet::new(&[IsoWeekday::Thursday, IsoWeekday::Friday])),
+        };
+        assert_eq!(
+            calendar.weekend().map(Iterator::collect::<Vec<_>>),
+            Some(vec![IsoWeekday::Thursday, IsoWeekday::Friday])
+        );
+
+        // Weekends need not be contiguous.
+        calendar.weekend = Some(WeekdaySet::new(&[IsoWeekday::Friday, IsoWeekday::Sunday]));
+        assert_eq!(
+            calendar.weekend().map(Iterator::collect::<Vec<_>>),
+            Some(vec![IsoWeekday::Sunday, IsoWeekday::Friday])
+        );
+
+        // An empty weekend differs from an unknown one.
+        calendar.weekend = Some(WeekdaySet::default());
+        assert_eq!(
+            calendar.weekend().map(Iterator::collect::<Vec<_>>),
+            Some(vec![])
+        );
+        calendar.weekend = None;
+        assert!(calendar.weekend().is_none());
+
+        assert_eq!(
+            WeekCalculator::default()
+                .weekend()
+                .map(Iterator::collect::<Vec<_>>),
+            Some(vec![IsoWeekday::Saturday, IsoWeekday::Sunday])
+        );
+    }
+
+    #[test]
+    fn test_day_of_year_for_week() -> Result<(), CalendarError> {
+        // 2021 starts on a Friday, so its first ISO week starts on January 4.
+        assert_eq!(
+            ISO_CALENDAR.day_of_year_for_week(IsoWeekday::Friday, 365, 1, IsoWeekday::Monday)?,
+            4
+        );
+        // 2020 starts on a Wednesday, so its first ISO week starts on December 30, 2019.
+        assert_eq!(
+            ISO_CALENDAR.day_of_year_for_week(IsoWeekday::Wednesday, 366, 1, IsoWeekday::Monday)?,
+            -1
+        );
+        // Week 53 of 2020 ends on January 3, 2021.
+        assert_eq!(
+            ISO_CALENDAR.day_of_year_for_week(
+                IsoWeekday::Wednesday,
+                366,
+                53,
+                IsoWeekday::Sunday
+            )?,
+            369
+        );
+
+        assert_eq!(
+            ISO_CALENDAR.day_of_year_for_week(IsoWeekday::Friday, 365, 0, IsoWeekday::Monday),
+            Err(CalendarError::Underflow {
+                field: "week",
+                min: 1
+            })
+        );
+        assert_eq!(
+            ISO_CALENDAR.day_of_year_for_week(IsoWeekday::Friday, 365, 53, IsoWeekday::Monday),
+            Err(CalendarError::Overflow {
+                field: "week",
+                max: 52
+            })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_iso_week() -> Result<(), CalendarError> {
+        // (ISO date, ISO week-based year, week, weekday)
+        let cases = [
+            ((2005, 1, 1), (2004, 53, IsoWeekday::Saturday)),
+            ((2007, 1, 1), (2007, 1, IsoWeekday::Monday)),
+            ((2007, 12, 30), (2007, 52, IsoWeekday::Sunday)),
+            ((2008, 12, 29), (2009, 1, IsoWeekday::Monday)),
+            ((2010, 1, 3), (2009, 53, IsoWeekday::Sunday)),
+            ((2021, 1, 1), (2020, 53, IsoWeekday::Friday)),
+            ((2022, 8, 26), (2022, 34, IsoWeekday::Friday)),
+        ];
+        for ((y, m, d), (week_year, week, weekday)) in cases {
+            let date = Date::try_new_iso_date(y, m, d)?;
+            let week_date = date.iso_week();
+            assert_eq!(week_date.year.number, week_year, "{date:?}");
+            assert_eq!(week_date.week, week, "{date:?}");
+            assert_eq!(week_date.weekday, weekday, "{date:?}");
+            assert_eq!(Date::try_new_iso_week_date(week_year, week, weekday)?, date);
+        }
+        Ok(())
+    }
+
+    fn check_week_date_round_trip<A: AsCalendar + Clone>(calendar: A) {
+        let mut iso = Date::try_new_iso_date(1999, 11, 1).unwrap();
+        while iso.year().number < 2026 {
+            let date = iso.to_calendar(calendar.clone());
+            for config in [&ISO_CALENDAR, &AE_CALENDAR, &US_CALENDAR] {
+                let week_date = date.week_date(config).unwrap();
+                let round_trip = Date::try_new_from_week_date(
+                    week_date.year.era,
+                    week_date.year.number,
+                    week_date.week,
+                    week_date.weekday,
+                    config,
+                    calendar.clone(),
+                )
+                .unwrap();
+                assert_eq!(round_trip.to_iso(), iso, "{week_date:?} {config:?}");
+            }
+            iso.add(DateDuration::new(0, 0, 0, 1));
+        }
+    }
+
+    #[test]
+    fn test_week_date_round_trip() {
+        check_week_date_round_trip(Iso);
+        check_week_date_round_trip(Gregorian);
+        check_week_date_round_trip(Buddhist);
+        check_week_date_round_trip(Coptic);
+        check_week_date_round_trip(Hebrew::new());
+    }
 }
 
 #[test]
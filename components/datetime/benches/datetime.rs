@@ -34,17 +34,8 @@ fn datetime_benches(c: &mut Criterion) {
                     for setup in &fx.setups {
                         let locale: Locale = setup.locale.parse().expect("Failed to parse locale.");
                         let options = fixtures::get_options(&setup.options).unwrap();
-                        #[cfg(feature = "experimental")]
                         let dtf = {
-                            TypedDateTimeFormatter::<Gregorian>::try_new_experimental(
-                                &locale.into(),
-                                options.clone(),
-                            )
-                            .expect("Failed to create TypedDateTimeFormatter.")
-                        };
-                        #[cfg(not(feature = "experimental"))]
-                        let dtf = {
-                            TypedDateTimeFormatter::<Gregorian>::try_new(
+                            TypedDateTimeFormatter::<Gregorian>::try_new_with_components(
                                 &locale.into(),
                                 options.clone(),
                             )
@@ -66,7 +57,6 @@ fn datetime_benches(c: &mut Criterion) {
 
     bench_datetime_with_fixture("lengths");
 
-    #[cfg(feature = "experimental")]
     bench_datetime_with_fixture("components");
 
     let fxs = fixtures::get_fixture("lengths_with_zones").unwrap();
@@ -28,9 +28,6 @@ pub fn get_patterns_fixture() -> std::io::Result<structs::PatternsFixture> {
 pub fn get_options(input: &structs::TestOptions) -> Option<DateTimeFormatterOptions> {
     match input {
         structs::TestOptions::Length(bag) => Some((*bag).into()),
-        #[cfg(feature = "experimental")]
         structs::TestOptions::Components(bag) => Some((*bag).into()),
-        #[cfg(not(feature = "experimental"))]
-        structs::TestOptions::Components(_) => None,
     }
 }
@@ -1,5 +1,10 @@
 // @generated
 #[macro_use]
+#[path = "macros/datetime_appenditems_v1.data.rs"]
+mod datetime_appenditems_v1;
+#[doc(inline)]
+pub use __impl_datetime_appenditems_v1 as impl_datetime_appenditems_v1;
+#[macro_use]
 #[path = "macros/datetime_buddhist_datelengths_v1.data.rs"]
 mod datetime_buddhist_datelengths_v1;
 #[doc(inline)]
@@ -50,6 +55,11 @@ mod datetime_ethiopic_datesymbols_v1;
 #[doc(inline)]
 pub use __impl_datetime_ethiopic_datesymbols_v1 as impl_datetime_ethiopic_datesymbols_v1;
 #[macro_use]
+#[path = "macros/datetime_flexibledayperiods_v1.data.rs"]
+mod datetime_flexibledayperiods_v1;
+#[doc(inline)]
+pub use __impl_datetime_flexibledayperiods_v1 as impl_datetime_flexibledayperiods_v1;
+#[macro_use]
 #[path = "macros/datetime_gregory_datelengths_v1.data.rs"]
 mod datetime_gregory_datelengths_v1;
 #[doc(inline)]
@@ -120,6 +130,11 @@ mod datetime_persian_datesymbols_v1;
 #[doc(inline)]
 pub use __impl_datetime_persian_datesymbols_v1 as impl_datetime_persian_datesymbols_v1;
 #[macro_use]
+#[path = "macros/datetime_quartersymbols_v1.data.rs"]
+mod datetime_quartersymbols_v1;
+#[doc(inline)]
+pub use __impl_datetime_quartersymbols_v1 as impl_datetime_quartersymbols_v1;
+#[macro_use]
 #[path = "macros/datetime_roc_datelengths_v1.data.rs"]
 mod datetime_roc_datelengths_v1;
 #[doc(inline)]
@@ -0,0 +1,165 @@
+// @generated
+/// Implement `DataProvider<AppendItemsV1Marker>` on the given struct using the data
+/// hardcoded in this file. This allows the struct to be used with
+/// `icu`'s `_unstable` constructors.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_datetime_appenditems_v1 {
+    ($ provider : path) => {
+        #[clippy::msrv = "1.66"]
+        impl icu_provider::DataProvider<icu::datetime::provider::calendar::AppendItemsV1Marker> for $provider {
+            fn load(&self, req: icu_provider::DataRequest) -> Result<icu_provider::DataResponse<icu::datetime::provider::calendar::AppendItemsV1Marker>, icu_provider::DataError> {
+                static HI_LATN: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0q\0\0u\0\0a\0\0r\0\0t\0\0e\0\0r\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0a\0\0h\0\0i\0\0n\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0h\0\0a\0\0f\0\0t\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0d\0\0i\0\0n\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0g\0\0h\0\0a\0\0n\0\0t\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0i\0\0n\0\0u\0\0t\0\0e\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0c\0\0o\0\0n\0\0d\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static EN: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0q\0\0u\0\0a\0\0r\0\0t\0\0e\0\0r\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0o\0\0n\0\0t\0\0h\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0w\0\0e\0\0e\0\0k\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0d\0\0a\0\0y\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0h\0\0o\0\0u\0\0r\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0i\0\0n\0\0u\0\0t\0\0e\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0c\0\0o\0\0n\0\0d\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static AZ: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0R\0\0\xFC\0\0b\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0A\0\0y\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0H\0\x02Y\0\0f\0\0t\0\x02Y\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0G\0\0\xFC\0\0n\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0S\0\0a\0\0a\0\0t\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0D\0\x02Y\0\0q\0\0i\0\0q\0\x02Y\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0S\0\0a\0\0n\0\0i\0\0y\0\x02Y\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static SO: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0R\0\0u\0\0b\0\0u\0\0c\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0B\0\0i\0\0l\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0T\0\0o\0\0d\0\0d\0\0o\0\0b\0\0a\0\0a\0\0d\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0a\0\0a\0\0l\0\0i\0\0n\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0S\0\0a\0\0a\0\0c\0\0a\0\0d\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0d\0\0a\0\0q\0\0i\0\0i\0\0q\0\0a\0\0d\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0i\0\0l\0\0b\0\0i\0\0r\0\0i\0\0q\0\0s\0\0i\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static ZU: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0I\0\0k\0\0o\0\0t\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0I\0\0n\0\0y\0\0a\0\0n\0\0g\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0I\0\0v\0\0i\0\0k\0\0i\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0U\0\0s\0\0u\0\0k\0\0u\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0I\0\0h\0\0o\0\0r\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0I\0\0m\0\0i\0\0n\0\0i\0\0t\0\0h\0\0i\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0I\0\0s\0\0e\0\0k\0\0h\0\0o\0\0n\0\0d\0\0i\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static ES_DO: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0T\0\0r\0\0i\0\0m\0\0e\0\0s\0\0t\0\0r\0\0e\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0M\0\0e\0\0s\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0S\0\0e\0\0m\0\0a\0\0n\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0D\0\0\xED\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0h\0\0o\0\0r\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0M\0\0i\0\0n\0\0u\0\0t\0\0o\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0S\0\0e\0\0g\0\0u\0\0n\0\0d\0\0o\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static DE: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0Q\0\0u\0\0a\0\0r\0\0t\0\0a\0\0l\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0M\0\0o\0\0n\0\0a\0\0t\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0W\0\0o\0\0c\0\0h\0\0e\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0T\0\0a\0\0g\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0S\0\0t\0\0u\0\0n\0\0d\0\0e\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0M\0\0i\0\0n\0\0u\0\0t\0\0e\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0S\0\0e\0\0k\0\0u\0\0n\0\0d\0\0e\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static UND: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0Q\0\0u\0\0a\0\0r\0\0t\0\0e\0\0r\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0M\0\0o\0\0n\0\0t\0\0h\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0W\0\0e\0\0e\0\0k\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0D\0\0a\0\0y\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0H\0\0o\0\0u\0\0r\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0M\0\0i\0\0n\0\0u\0\0t\0\0e\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0S\0\0e\0\0c\0\0o\0\0n\0\0d\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static PCM: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0K\0\0w\0\x1E\xCD\0\x03\x01\0\0t\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0M\0\x1E\xCD\0\0n\0\0t\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0W\0\0i\0\0k\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0D\0\0\xE8\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0A\0\0w\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0M\0\0\xED\0\0n\0\0i\0\0t\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0S\0\x1E\xB9\0\x03\x01\0\0k\0\x1E\xCD\0\0n\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static YO_BJ: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0\xCC\0\0d\0\0\xE1\0\0 \0\0m\0\x02[\0\x03\x01\0\0r\0\0i\0\0n\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0O\0\0s\0\0\xF9\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x01\x86\0\x03\0\0\0s\0\x02[\0\x03\0\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x01\x86\0\0j\0\x02T\0\x03\x01\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0W\0\0\xE1\0\0k\0\0\xE0\0\0t\0\0\xED\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0\xCC\0\0s\0\x02[\0\x03\x01\0\0j\0\0\xFA\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0\xCC\0\0s\0\x02[\0\x03\x01\0\0j\0\0\xFA\0\0 \0\0\xC0\0\0\xE0\0\0y\0\0\xE1\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static YO: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0\xCC\0\0d\0\0\xE1\0\0 \0\0m\0\x1E\xB9\0\x03\x01\0\0r\0\0i\0\0n\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0O\0\0s\0\0\xF9\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x1E\xCC\0\x03\0\0\0s\0\x1E\xB9\0\x03\0\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x1E\xCC\0\0j\0\x1E\xCD\0\x03\x01\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0W\0\0\xE1\0\0k\0\0\xE0\0\0t\0\0\xED\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0\xCC\0\0s\0\x1E\xB9\0\x03\x01\0\0j\0\0\xFA\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0\xCC\0\0s\0\x1E\xB9\0\x03\x01\0\0j\0\0\xFA\0\0 \0\0\xC0\0\0\xE0\0\0y\0\0\xE1\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static VI: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0Q\0\0u\0\0\xFD\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0T\0\0h\0\0\xE1\0\0n\0\0g\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0T\0\0u\0\x1E\xA7\0\0n\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0N\0\0g\0\0\xE0\0\0y\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0G\0\0i\0\x1E\xDD\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0P\0\0h\0\0\xFA\0\0t\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0G\0\0i\0\0\xE2\0\0y\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static TK: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0\xE7\0\0\xE4\0\0r\0\0\xFD\0\0e\0\0k\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0a\0\0\xFD\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0h\0\0e\0\0p\0\0d\0\0e\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0g\0\0\xFC\0\0n\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0a\0\0g\0\0a\0\0t\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0i\0\0n\0\0u\0\0t\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0k\0\0u\0\0n\0\0t\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static TR: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0\xE7\0\0e\0\0y\0\0r\0\0e\0\0k\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0a\0\0y\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0h\0\0a\0\0f\0\0t\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0g\0\0\xFC\0\0n\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0a\0\0a\0\0t\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0d\0\0a\0\0k\0\0i\0\0k\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0a\0\0n\0\0i\0\0y\0\0e\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static ID: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0k\0\0u\0\0a\0\0r\0\0t\0\0a\0\0l\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0b\0\0u\0\0l\0\0a\0\0n\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0i\0\0n\0\0g\0\0g\0\0u\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0h\0\0a\0\0r\0\0i\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0j\0\0a\0\0m\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0e\0\0n\0\0i\0\0t\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0d\0\0e\0\0t\0\0i\0\0k\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static MS: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0u\0\0k\0\0u\0\0 \0\0t\0\0a\0\0h\0\0u\0\0n\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0b\0\0u\0\0l\0\0a\0\0n\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0i\0\0n\0\0g\0\0g\0\0u\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0h\0\0a\0\0r\0\0i\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0j\0\0a\0\0m\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0i\0\0n\0\0i\0\0t\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0a\0\0a\0\0t\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static FIL: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0q\0\0u\0\0a\0\0r\0\0t\0\0e\0\0r\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0b\0\0u\0\0w\0\0a\0\0n\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0l\0\0i\0\0n\0\0g\0\0g\0\0o\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0a\0\0r\0\0a\0\0w\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0o\0\0r\0\0a\0\0s\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0i\0\0n\0\0u\0\0t\0\0o\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0g\0\0u\0\0n\0\0d\0\0o\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static CEB: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0q\0\0u\0\0a\0\0r\0\0t\0\0e\0\0r\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0b\0\0u\0\0w\0\0a\0\0n\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0m\0\0a\0\0n\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0a\0\0d\0\0l\0\0a\0\0w\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0o\0\0r\0\0a\0\0s\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0i\0\0n\0\0u\0\0t\0\0o\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0g\0\0u\0\0n\0\0d\0\0o\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static HU: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0n\0\0e\0\0g\0\0y\0\0e\0\0d\0\0\xE9\0\0v\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0h\0\0\xF3\0\0n\0\0a\0\0p\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0h\0\0\xE9\0\0t\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0n\0\0a\0\0p\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0\xF3\0\0r\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0p\0\0e\0\0r\0\0c\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0\xE1\0\0s\0\0o\0\0d\0\0p\0\0e\0\0r\0\0c\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static EU: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0h\0\0i\0\0r\0\0u\0\0h\0\0i\0\0l\0\0e\0\0k\0\0o\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0h\0\0i\0\0l\0\0a\0\0b\0\0e\0\0t\0\0e\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0a\0\0s\0\0t\0\0e\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0e\0\0g\0\0u\0\0n\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0o\0\0r\0\0d\0\0u\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0i\0\0n\0\0u\0\0t\0\0u\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0g\0\0u\0\0n\0\0d\0\0o\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\x80\0\x01\0\0)") } } };
+                static XH: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0i\0\0k\0\0o\0\0t\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0i\0\0n\0\0y\0\0a\0\0n\0\0g\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0i\0\0v\0\0e\0\0k\0\0i\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0u\0\0s\0\0u\0\0k\0\0u\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0i\0\0y\0\0u\0\0r\0\0e\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0u\0\0m\0\0z\0\0u\0\0z\0\0u\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0u\0\0m\0\0z\0\0u\0\0z\0\0w\0\0a\0\0n\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static ET: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0k\0\0v\0\0a\0\0r\0\0t\0\0a\0\0l\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0k\0\0u\0\0u\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0n\0\0\xE4\0\0d\0\0a\0\0l\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0p\0\0\xE4\0\0e\0\0v\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0t\0\0u\0\0n\0\0d\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0i\0\0n\0\0u\0\0t\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0k\0\0u\0\0n\0\0d\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static FI: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0n\0\0e\0\0l\0\0j\0\0\xE4\0\0n\0\0n\0\0e\0\0s\0\0v\0\0u\0\0o\0\0s\0\0i\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0k\0\0u\0\0u\0\0k\0\0a\0\0u\0\0s\0\0i\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0v\0\0i\0\0i\0\0k\0\0k\0\0o\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0p\0\0\xE4\0\0i\0\0v\0\0\xE4\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0t\0\0u\0\0n\0\0t\0\0i\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0i\0\0n\0\0u\0\0u\0\0t\0\0t\0\0i\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0k\0\0u\0\0n\0\0t\0\0i\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static KGP: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0k\0\0y\0\0s\0\0\xE3\0\0-\0\0t\0\x1E\xBD\0\0g\0\0t\0\x01i\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0k\0\0y\0\0s\0\0\xE3\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0i\0\0m\0\x1E\xF9\0\0n\0\x1E\xF9\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0k\0\0u\0\0r\0\0\xE3\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0\xF3\0\0r\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\x01)\0\0n\0\x01i\0\0t\0\0u\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0g\0\x01i\0\0n\0\0o\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static RO: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0t\0\0r\0\0i\0\0m\0\0e\0\0s\0\0t\0\0r\0\0u\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0l\0\0u\0\0n\0\x01\x03\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\x01\x03\0\0p\0\0t\0\x01\x03\0\0m\0\0\xE2\0\0n\0\x01\x03\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0z\0\0i\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0o\0\0r\0\x01\x03\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0i\0\0n\0\0u\0\0t\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0c\0\0u\0\0n\0\0d\0\x01\x03\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static FO: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0\xE1\0\0r\0\0s\0\0f\0\0j\0\0\xF3\0\0r\0\0\xF0\0\0i\0\0n\0\0g\0\0u\0\0r\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0\xE1\0\0n\0\0a\0\0\xF0\0\0u\0\0r\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0v\0\0i\0\0k\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0d\0\0a\0\0g\0\0u\0\0r\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0t\0\0\xED\0\0m\0\0i\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0i\0\0n\0\0u\0\0t\0\0t\0\0u\0\0r\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0k\0\0u\0\0n\0\0d\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static IS: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0\xE1\0\0r\0\0s\0\0f\0\0j\0\0\xF3\0\0r\0\0\xF0\0\0u\0\0n\0\0g\0\0u\0\0r\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0\xE1\0\0n\0\0u\0\0\xF0\0\0u\0\0r\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0v\0\0i\0\0k\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0d\0\0a\0\0g\0\0u\0\0r\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0k\0\0l\0\0u\0\0k\0\0k\0\0u\0\0s\0\0t\0\0u\0\0n\0\0d\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0\xED\0\0n\0\0\xFA\0\0t\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0k\0\0\xFA\0\0n\0\0d\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static SV: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0k\0\0v\0\0a\0\0r\0\0t\0\0a\0\0l\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0\xE5\0\0n\0\0a\0\0d\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0v\0\0e\0\0c\0\0k\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0d\0\0a\0\0g\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0t\0\0i\0\0m\0\0m\0\0e\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0i\0\0n\0\0u\0\0t\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0k\0\0u\0\0n\0\0d\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static NN: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0k\0\0v\0\0a\0\0r\0\0t\0\0a\0\0l\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0\xE5\0\0n\0\0a\0\0d\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0v\0\0e\0\0k\0\0e\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0d\0\0a\0\0g\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0t\0\0i\0\0m\0\0e\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0i\0\0n\0\0u\0\0t\0\0t\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0k\0\0u\0\0n\0\0d\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static DA: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0k\0\0v\0\0a\0\0r\0\0t\0\0a\0\0l\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0\xE5\0\0n\0\0e\0\0d\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0u\0\0g\0\0e\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0d\0\0a\0\0g\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0t\0\0i\0\0m\0\0e\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0i\0\0n\0\0u\0\0t\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0k\0\0u\0\0n\0\0d\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static NO: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0k\0\0v\0\0a\0\0r\0\0t\0\0a\0\0l\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0\xE5\0\0n\0\0e\0\0d\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0u\0\0k\0\0e\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0d\0\0a\0\0g\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0t\0\0i\0\0m\0\0e\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0i\0\0n\0\0u\0\0t\0\0t\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0k\0\0u\0\0n\0\0d\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static PT: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0t\0\0r\0\0i\0\0m\0\0e\0\0s\0\0t\0\0r\0\0e\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0\xEA\0\0s\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0m\0\0a\0\0n\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0d\0\0i\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0h\0\0o\0\0r\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0i\0\0n\0\0u\0\0t\0\0o\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0g\0\0u\0\0n\0\0d\0\0o\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static GD: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0c\0\0a\0\0i\0\0r\0\0t\0\0e\0\0a\0\0l\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0\xEC\0\0o\0\0s\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0a\0\0c\0\0h\0\0d\0\0a\0\0i\0\0n\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0l\0\0a\0\0t\0\0h\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0u\0\0a\0\0i\0\0r\0\0 \0\0a\0\0 \0\0t\0\0h\0\0\xEC\0\0d\0\0e\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0i\0\0o\0\0n\0\0a\0\0i\0\0d\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0d\0\0i\0\0o\0\0g\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static GA: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0r\0\0\xE1\0\0i\0\0t\0\0h\0\0e\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0\xED\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0a\0\0c\0\0h\0\0t\0\0a\0\0i\0\0n\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0l\0\0\xE1\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0u\0\0a\0\0i\0\0r\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0n\0\0\xF3\0\0i\0\0m\0\0\xE9\0\0a\0\0d\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0o\0\0i\0\0c\0\0i\0\0n\0\0d\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static NL: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0k\0\0w\0\0a\0\0r\0\0t\0\0a\0\0a\0\0l\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0a\0\0a\0\0n\0\0d\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0w\0\0e\0\0e\0\0k\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0d\0\0a\0\0g\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0u\0\0u\0\0r\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0i\0\0n\0\0u\0\0u\0\0t\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0c\0\0o\0\0n\0\0d\0\0e\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static AF: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0k\0\0w\0\0a\0\0r\0\0t\0\0a\0\0a\0\0l\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0a\0\0a\0\0n\0\0d\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0w\0\0e\0\0e\0\0k\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0d\0\0a\0\0g\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0u\0\0u\0\0r\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0i\0\0n\0\0u\0\0u\0\0t\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0k\0\0o\0\0n\0\0d\0\0e\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static RM: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0q\0\0u\0\0a\0\0r\0\0t\0\0a\0\0l\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0a\0\0i\0\0s\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0e\0\0m\0\0n\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0d\0\0i\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0u\0\0r\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0i\0\0n\0\0u\0\0t\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0c\0\0u\0\0n\0\0d\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static MI: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0h\0\0a\0\0u\0\0w\0\0h\0\x01\x01\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0a\0\0r\0\0a\0\0m\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0w\0\0i\0\0k\0\0i\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0r\0\x01\x01\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0h\0\x01\x01\0\0o\0\0r\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0e\0\0n\0\0e\0\0t\0\0i\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0h\0\x01\x13\0\0k\0\0o\0\0n\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static IA: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0t\0\0r\0\0i\0\0m\0\0e\0\0s\0\0t\0\0r\0\0e\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0e\0\0n\0\0s\0\0e\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0p\0\0t\0\0i\0\0m\0\0a\0\0n\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0d\0\0i\0\0e\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0h\0\0o\0\0r\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0i\0\0n\0\0u\0\0t\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0c\0\0u\0\0n\0\0d\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static AST: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0t\0\0r\0\0i\0\0m\0\0e\0\0s\0\0t\0\0r\0\0e\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0e\0\0s\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0l\0\0m\0\0a\0\0n\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0d\0\0\xED\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0h\0\0o\0\0r\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0i\0\0n\0\0u\0\0t\0\0u\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0g\0\0u\0\0n\0\0d\0\0u\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static ES: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0t\0\0r\0\0i\0\0m\0\0e\0\0s\0\0t\0\0r\0\0e\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0e\0\0s\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0m\0\0a\0\0n\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0d\0\0\xED\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0h\0\0o\0\0r\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0i\0\0n\0\0u\0\0t\0\0o\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0g\0\0u\0\0n\0\0d\0\0o\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static CA: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0t\0\0r\0\0i\0\0m\0\0e\0\0s\0\0t\0\0r\0\0e\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0e\0\0s\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0t\0\0m\0\0a\0\0n\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0d\0\0i\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0h\0\0o\0\0r\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0i\0\0n\0\0u\0\0t\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0g\0\0o\0\0n\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static KEA: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0t\0\0r\0\0i\0\0m\0\0e\0\0s\0\0t\0\0r\0\0i\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0e\0\0s\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0i\0\0m\0\0a\0\0n\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0d\0\0i\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0o\0\0r\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0i\0\0n\0\0u\0\0t\0\0u\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0i\0\0g\0\0u\0\0n\0\0d\0\0u\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static SC: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0t\0\0r\0\0i\0\0m\0\0e\0\0s\0\0t\0\0r\0\0e\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0e\0\0s\0\0e\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0c\0\0h\0\0i\0\0d\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0d\0\0i\0\0e\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0o\0\0r\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0i\0\0n\0\0u\0\0t\0\0u\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0g\0\0u\0\0n\0\0d\0\0u\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static IT: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0t\0\0r\0\0i\0\0m\0\0e\0\0s\0\0t\0\0r\0\0e\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0e\0\0s\0\0e\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0t\0\0t\0\0i\0\0m\0\0a\0\0n\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0g\0\0i\0\0o\0\0r\0\0n\0\0o\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0o\0\0r\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0i\0\0n\0\0u\0\0t\0\0o\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0c\0\0o\0\0n\0\0d\0\0o\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static SR_LATN: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0k\0\0v\0\0a\0\0r\0\0t\0\0a\0\0l\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0e\0\0s\0\0e\0\0c\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0n\0\0e\0\0d\0\0e\0\0l\0\0j\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0d\0\0a\0\0n\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0a\0\0t\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0i\0\0n\0\0u\0\0t\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0k\0\0u\0\0n\0\0d\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static SL: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x01\r\0\0e\0\0t\0\0r\0\0t\0\0l\0\0e\0\0t\0\0j\0\0e\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0e\0\0s\0\0e\0\0c\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0t\0\0e\0\0d\0\0e\0\0n\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0d\0\0a\0\0n\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0u\0\0r\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0i\0\0n\0\0u\0\0t\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0k\0\0u\0\0n\0\0d\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static SK: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x01a\0\0t\0\0v\0\0r\0\x01e\0\0r\0\0o\0\0k\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0e\0\0s\0\0i\0\0a\0\0c\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0t\0\0\xFD\0\x01~\0\0d\0\0e\0\x01H\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0d\0\0e\0\x01H\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0h\0\0o\0\0d\0\0i\0\0n\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0i\0\0n\0\0\xFA\0\0t\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0k\0\0u\0\0n\0\0d\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static PL: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0k\0\0w\0\0a\0\0r\0\0t\0\0a\0\x01B\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0i\0\0e\0\0s\0\0i\0\x01\x05\0\0c\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0t\0\0y\0\0d\0\0z\0\0i\0\0e\0\x01D\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0d\0\0z\0\0i\0\0e\0\x01D\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0g\0\0o\0\0d\0\0z\0\0i\0\0n\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0i\0\0n\0\0u\0\0t\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0k\0\0u\0\0n\0\0d\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static CY: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0c\0\0h\0\0w\0\0a\0\0r\0\0t\0\0e\0\0r\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0i\0\0s\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0w\0\0y\0\0t\0\0h\0\0n\0\0o\0\0s\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0d\0\0i\0\0w\0\0r\0\0n\0\0o\0\0d\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0a\0\0w\0\0r\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0u\0\0n\0\0u\0\0d\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0e\0\0i\0\0l\0\0i\0\0a\0\0d\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static BR: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0t\0\0r\0\0i\0\0m\0\0i\0\0z\0\0i\0\0a\0\0d\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0i\0\0z\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0i\0\0z\0\0h\0\0u\0\0n\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0d\0\0e\0\0i\0\0z\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0e\0\0u\0\0r\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0u\0\0n\0\0u\0\0t\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0e\0\0i\0\0l\0\0e\0\0n\0\0n\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static DSB: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0k\0\0w\0\0a\0\0r\0\0t\0\0a\0\0l\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0j\0\0a\0\0s\0\0e\0\0c\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0t\0\0y\0\x01z\0\0e\0\x01D\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x01z\0\0e\0\x01D\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0g\0\0\xF3\0\x01z\0\0i\0\0n\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0i\0\0n\0\0u\0\0t\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0k\0\0u\0\0n\0\0d\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static SR_LATN_BA: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0k\0\0v\0\0a\0\0r\0\0t\0\0a\0\0l\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0j\0\0e\0\0s\0\0e\0\0c\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0d\0\0m\0\0i\0\0c\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0d\0\0a\0\0n\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0a\0\0t\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0i\0\0n\0\0u\0\0t\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0k\0\0u\0\0n\0\0d\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static BS: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0k\0\0v\0\0a\0\0r\0\0t\0\0a\0\0l\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0j\0\0e\0\0s\0\0e\0\0c\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0d\0\0m\0\0i\0\0c\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0d\0\0a\0\0n\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0a\0\0t\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0i\0\0n\0\0u\0\0t\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0k\0\0u\0\0n\0\0d\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static HR: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0k\0\0v\0\0a\0\0r\0\0t\0\0a\0\0l\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0j\0\0e\0\0s\0\0e\0\0c\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0t\0\0j\0\0e\0\0d\0\0a\0\0n\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0d\0\0a\0\0n\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0a\0\0t\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0i\0\0n\0\0u\0\0t\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0k\0\0u\0\0n\0\0d\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\x80\0\x01\0\0)") } } };
+                static FR: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0t\0\0r\0\0i\0\0m\0\0e\0\0s\0\0t\0\0r\0\0e\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0o\0\0i\0\0s\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0m\0\0a\0\0i\0\0n\0\0e\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0j\0\0o\0\0u\0\0r\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0h\0\0e\0\0u\0\0r\0\0e\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0i\0\0n\0\0u\0\0t\0\0e\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0c\0\0o\0\0n\0\0d\0\0e\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static SQ: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0t\0\0r\0\0e\0\0m\0\0u\0\0j\0\0o\0\0r\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0u\0\0a\0\0j\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0j\0\0a\0\0v\0\0\xEB\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0d\0\0i\0\0t\0\0\xEB\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0o\0\0r\0\0\xEB\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0i\0\0n\0\0u\0\0t\0\0\xEB\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0k\0\0o\0\0n\0\0d\0\0\xEB\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0,\0\0 \x80\0\x01") } } };
+                static SW_CD: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0r\0\0o\0\0b\0\0o\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0w\0\0e\0\0z\0\0i\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0J\0\0u\0\0m\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0i\0\0k\0\0u\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0a\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0d\0\0a\0\0k\0\0i\0\0k\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0k\0\0u\0\0n\0\0d\0\0e\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static SW: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0r\0\0o\0\0b\0\0o\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0w\0\0e\0\0z\0\0i\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0w\0\0i\0\0k\0\0i\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0i\0\0k\0\0u\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0a\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0d\0\0a\0\0k\0\0i\0\0k\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0k\0\0u\0\0n\0\0d\0\0e\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static TO: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0k\0\0u\0\0a\0\0t\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\x01\x01\0\0h\0\0i\0\0n\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0u\0\0i\0\0k\0\0e\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x02\xBB\0\0a\0\0h\0\0o\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0h\0\0o\0\0u\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0i\0\0n\0\0i\0\0t\0\0i\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0k\0\0o\0\0n\0\0i\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static LV: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0c\0\0e\0\0t\0\0u\0\0r\0\0k\0\0s\0\0n\0\0i\0\0s\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\x01\x13\0\0n\0\0e\0\0s\0\0i\0\0s\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0n\0\0e\0\0d\0\x01\x13\0\x01<\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0d\0\0i\0\0e\0\0n\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0t\0\0u\0\0n\0\0d\0\0a\0\0s\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0i\0\0n\0\x01k\0\0t\0\0e\0\0s\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0k\0\0u\0\0n\0\0d\0\0e\0\0s\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static LT: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0k\0\0e\0\0t\0\0v\0\0i\0\0r\0\0t\0\0i\0\0s\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\x01\x17\0\0n\0\0u\0\0o\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0a\0\0v\0\0a\0\0i\0\0t\0\x01\x17\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0d\0\0i\0\0e\0\0n\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0v\0\0a\0\0l\0\0a\0\0n\0\0d\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0i\0\0n\0\0u\0\0t\0\x01\x17\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0k\0\0u\0\0n\0\0d\0\x01\x17\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static CS: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x01\r\0\0t\0\0v\0\0r\0\0t\0\0l\0\0e\0\0t\0\0\xED\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\x01\x1B\0\0s\0\0\xED\0\0c\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0t\0\0\xFD\0\0d\0\0e\0\0n\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0d\0\0e\0\0n\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0h\0\0o\0\0d\0\0i\0\0n\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0i\0\0n\0\0u\0\0t\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0k\0\0u\0\0n\0\0d\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static HSB: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0k\0\0w\0\0a\0\0r\0\0t\0\0a\0\0l\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\x01\x1B\0\0s\0\0a\0\0c\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0t\0\0y\0\0d\0\x01z\0\0e\0\x01D\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0d\0\x01z\0\0e\0\x01D\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0h\0\0o\0\0d\0\x01z\0\0i\0\0n\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0i\0\0n\0\0u\0\0t\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0k\0\0u\0\0n\0\0d\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static UZ: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0c\0\0h\0\0o\0\0r\0\0a\0\0k\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0o\0\0y\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0h\0\0a\0\0f\0\0t\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0k\0\0u\0\0n\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0o\0\0a\0\0t\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0d\0\0a\0\0q\0\0i\0\0q\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0o\0\0n\0\0i\0\0y\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static JV: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0t\0\0r\0\0i\0\0w\0\0u\0\0l\0\0a\0\0n\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0a\0\0s\0\0i\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0p\0\0e\0\0k\0\0a\0\0n\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0d\0\0i\0\0n\0\0o\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0j\0\0a\0\0m\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0e\0\0n\0\0i\0\0t\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0d\0\0e\0\0t\0\0i\0\0k\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static SU: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0t\0\0r\0\0i\0\0w\0\0u\0\0l\0\0a\0\0n\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0a\0\0s\0\0i\0\0h\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0i\0\0n\0\0g\0\0g\0\0u\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0d\0\0i\0\0n\0\0t\0\0e\0\0n\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0j\0\0a\0\0m\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0e\0\0n\0\0i\0\0t\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0d\0\0e\0\0t\0\0i\0\0k\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static HA: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0k\0\0w\0\0a\0\0t\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0w\0\0a\0\0t\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0a\0\0k\0\0o\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0k\0\0w\0\0a\0\0n\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0a\0\0w\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0i\0\0n\0\0t\0\0i\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0d\0\0a\0\x01\x99\0\0i\0\x01\x99\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static WO: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0\xF1\0\0e\0\0e\0\0n\0\0t\0\0i\0\0-\0\0w\0\0e\0\0e\0\0r\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0w\0\0e\0\0e\0\0r\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0a\0\0y\0\0u\0\0-\0\0b\0\0i\0\0s\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0f\0\0a\0\0n\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0w\0\0a\0\0x\0\0t\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0i\0\0m\0\0i\0\0l\0\0i\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0a\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static YRL: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0u\0\0s\0\0a\0\0p\0\0\xED\0\0r\0\0i\0\0-\0\0y\0\0a\0\0s\0\0\xED\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0y\0\0a\0\0s\0\0\xED\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\x1E\xBD\0\0m\0\0\xE3\0\0n\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0a\0\0r\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0h\0\0u\0\0r\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0m\0\0i\0\0n\0\0u\0\0t\0\0u\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0s\0\0e\0\0g\0\x01i\0\0d\0\0u\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static PA: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\n$\0\n?\0\n.\0\n>\0\n9\0\n@\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\n.\0\n9\0\n@\0\n(\0\n>\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\n9\0\n+\0\n<\0\n$\0\n>\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\n&\0\n?\0\n(\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\n\x18\0\np\0\n\x1F\0\n>\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\n.\0\n?\0\np\0\n\x1F\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\n8\0\n\x15\0\n?\0\np\0\n\x1F\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static GU: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\n\xA4\0\n\xCD\0\n\xB0\0\n\xBF\0\n\xAE\0\n\xBE\0\n\xB8\0\n\xBF\0\n\x95\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\n\xAE\0\n\xB9\0\n\xBF\0\n\xA8\0\n\xCB\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\n\x85\0\n\xA0\0\n\xB5\0\n\xBE\0\n\xA1\0\n\xBF\0\n\xAF\0\n\xC1\0\n\x82\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\n\xA6\0\n\xBF\0\n\xB5\0\n\xB8\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\n\x95\0\n\xB2\0\n\xBE\0\n\x95\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\n\xAE\0\n\xBF\0\n\xA8\0\n\xBF\0\n\x9F\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\n\xB8\0\n\xC7\0\n\x95\0\n\xA8\0\n\xCD\0\n\xA1\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static ML: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\r*\0\r>\0\r&\0\r\x02\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\r.\0\r>\0\r8\0\r\x02\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\r\x06\0\r4\0\rM\0\r\x1A\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\r&\0\r?\0\r5\0\r8\0\r\x02\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\r.\0\r#\0\r?\0\r\x15\0\rM\0\r\x15\0\rB\0\r|\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\r.\0\r?\0\r(\0\r?\0\r1\0\rM\0\r1\0\rM\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\r8\0\rF\0\r\x15\0\rM\0\r\x15\0\r{\0\r!\0\rM\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static SI: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\r\x9A\0\r\xCF\0\r\xBB\0\r\xCA\0\r\xAD\0\r\xD4\0\r\xC0\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\r\xB8\0\r\xCF\0\r\xC3\0\r\xBA\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\r\xC3\0\r\xAD\0\r\xD2\0\r\xBA\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\r\xAF\0\r\xD2\0\r\xB1\0\r\xBA\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\r\xB4\0\r\xD0\0\r\xBA\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\r\xB8\0\r\xD2\0\r\xB1\0\r\xD2\0\r\xAD\0\r\xCA\0\r\xAD\0\r\xD4\0\r\xC0\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\r\xAD\0\r\xAD\0\r\xCA\0\r\xB4\0\r\xBB\0\r\xBA\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static BRX: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t,\0\tM\0\t0\0\tH\0\0 \0\t,\0\t>\0\t9\0\t>\0\t\x17\0\tK\0\0 \0\t\x16\0\tK\0\t(\0\tM\0\t&\0\tK\0\tG\0\t8\0\tG\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t&\0\t>\0\t(\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t8\0\t*\0\tM\0\t%\0\t>\0\t9\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t8\0\t>\0\t(\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t\x18\0\t(\0\tM\0\t\x1F\0\t>\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t.\0\t?\0\t(\0\t?\0\t$\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t8\0\tG\0\t\x15\0\tG\0\t#\0\tM\0\t!\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static NE: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t8\0\t$\0\tM\0\t0\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t.\0\t9\0\t?\0\t(\0\t>\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t9\0\t*\0\tM\0\t$\0\t>\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t,\0\t>\0\t0\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t\x18\0\t#\0\tM\0\t\x1F\0\t>\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t.\0\t?\0\t(\0\tG\0\t\x1F\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t8\0\tG\0\t\x15\0\tG\0\t(\0\tM\0\t!\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static MR: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t$\0\t?\0\t.\0\t>\0\t9\0\t@\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t.\0\t9\0\t?\0\t(\0\t>\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t\x06\0\t \0\t5\0\t!\0\t>\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t&\0\t?\0\t5\0\t8\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t$\0\t>\0\t8\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t.\0\t?\0\t(\0\t?\0\t\x1F\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t8\0\tG\0\t\x15\0\t\x02\0\t&\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static SD_DEVA: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t\x1F\0\t?\0\t.\0\t>\0\t9\0\t@\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t.\0\t9\0\t?\0\t(\0\tK\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t9\0\t+\0\tM\0\t$\0\tK\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t&\0\t@\0\t\x02\0\tR\0\t9\0\tA\0\t\x02\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t\x15\0\t2\0\t>\0\t\x15\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t.\0\t?\0\t\x02\0\t\x1F\0\tA\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t8\0\tG\0\t\x15\0\t?\0\t\x02\0\t!\0\tA\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static MAI: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t$\0\t?\0\t.\0\t>\0\t9\0\t@\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t.\0\t9\0\t@\0\t(\0\t>\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t8\0\t*\0\tM\0\t$\0\t>\0\t9\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t&\0\t?\0\t(\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t\x18\0\t\x02\0\t\x1F\0\t>\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t.\0\t?\0\t(\0\t\x1F\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t8\0\tG\0\t\x15\0\t\x02\0\t!\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static SA: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t$\0\tM\0\t0\0\tG\0\t.\0\t>\0\t8\0\t?\0\t\x15\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t.\0\t>\0\t8\0\0:\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t8\0\t*\0\tM\0\t$\0\t>\0\t9\0\0:\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t5\0\t>\0\t8\0\t0\0\0:\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t9\0\tK\0\t0\0\t>\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t(\0\t?\0\t.\0\tG\0\t7\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t\x15\0\tM\0\t7\0\t#\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static HI: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t$\0\t?\0\t.\0\t>\0\t9\0\t@\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t.\0\t>\0\t9\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t8\0\t*\0\tM\0\t$\0\t>\0\t9\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t&\0\t?\0\t(\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t\x18\0\t\x02\0\t\x1F\0\t>\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t.\0\t?\0\t(\0\t\x1F\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t8\0\tG\0\t\x15\0\t\x02\0\t!\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static KS_DEVA: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t\x1A\0\tB\0\t0\0\t?\0\t.\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t.\0\t>\0\t9\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t9\0\t+\0\t<\0\t$\0\t?\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t&\0\tK\0\t9\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t\x17\0\t(\0\t\x1F\0\t?\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t.\0\t?\0\t(\0\t\x1F\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t8\0\tG\0\t\x15\0\t\x02\0\t!\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static KOK: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t$\0\tM\0\t0\0\tH\0\t.\0\t>\0\t8\0\t@\0\t\x15\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t.\0\tM\0\t9\0\t/\0\t(\0\tK\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t8\0\t*\0\tM\0\t$\0\t\x15\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t&\0\t@\0\t8\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t5\0\t0\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t.\0\t?\0\t(\0\t@\0\t\x1F\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t8\0\tG\0\t\x15\0\t\x02\0\t&\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static DOI: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t$\0\tM\0\t0\0\t.\0\t>\0\t9\0\t@\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t.\0\tM\0\t9\0\t@\0\t(\0\t>\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t9\0\t+\0\tM\0\t$\0\t>\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t&\0\t?\0\t(\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t\x18\0\tH\0\t\x02\0\t\x1F\0\t>\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t.\0\t?\0\t\x02\0\t\x1F\0\tM\0 \x0C\0\t\x1F\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t8\0\t\x15\0\tH\0\t\x02\0\t\x1F\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static MNI: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t\xAE\0\t\xB8\0\t\xC1\0\t\x82\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t\xA5\0\t\xBE\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t\x9A\0\t\xAF\0\t\xBC\0\t\xCB\0\t\xB2\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t\xA8\0\t\xC1\0\t\xAE\0\t\xBF\0\t\xCE\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t\xAA\0\t\xC1\0\t\x82\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t\xAE\0\t\xBF\0\t\xA8\0\t\x9F\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t\xB8\0\t\xC7\0\t\x95\0\t\xC7\0\t\xA3\0\t\xCD\0\t\xA1\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static BN: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t\xA4\0\t\xCD\0\t\xB0\0\t\xC8\0\t\xAE\0\t\xBE\0\t\xB8\0\t\xBF\0\t\x95\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t\xAE\0\t\xBE\0\t\xB8\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t\xB8\0\t\xAA\0\t\xCD\0\t\xA4\0\t\xBE\0\t\xB9\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t\xA6\0\t\xBF\0\t\xA8\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t\x98\0\t\xA3\0\t\xCD\0\t\x9F\0\t\xBE\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t\xAE\0\t\xBF\0\t\xA8\0\t\xBF\0\t\x9F\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t\xB8\0\t\xC7\0\t\x95\0\t\xC7\0\t\xA8\0\t\xCD\0\t\xA1\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static AS: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t\xA4\0\t\xBF\0\t\xA8\0\t\xBF\0\0 \0\t\xAE\0\t\xBE\0\t\xB9\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t\xAE\0\t\xBE\0\t\xB9\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t\xB8\0\t\xAA\0\t\xCD\0\t\xA4\0\t\xBE\0\t\xB9\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t\xA6\0\t\xBF\0\t\xA8\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t\x98\0\t\xA3\0\t\xCD\0\t\x9F\0\t\xBE\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t\xAE\0\t\xBF\0\t\xA8\0\t\xBF\0\t\x9F\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\t\x9B\0\t\xC7\0\t\x95\0\t\xC7\0\t\xA3\0\t\xCD\0\t\xA1\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static EL: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x03\xC4\0\x03\xC1\0\x03\xAF\0\x03\xBC\0\x03\xB7\0\x03\xBD\0\x03\xBF\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x03\xBC\0\x03\xAE\0\x03\xBD\0\x03\xB1\0\x03\xC2\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x03\xB5\0\x03\xB2\0\x03\xB4\0\x03\xBF\0\x03\xBC\0\x03\xAC\0\x03\xB4\0\x03\xB1\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x03\xB7\0\x03\xBC\0\x03\xAD\0\x03\xC1\0\x03\xB1\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x03\xCE\0\x03\xC1\0\x03\xB1\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x03\xBB\0\x03\xB5\0\x03\xC0\0\x03\xC4\0\x03\xCC\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x03\xB4\0\x03\xB5\0\x03\xC5\0\x03\xC4\0\x03\xB5\0\x03\xC1\0\x03\xCC\0\x03\xBB\0\x03\xB5\0\x03\xC0\0\x03\xC4\0\x03\xBF\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static KY: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04G\0\x045\0\x049\0\x04@\0\x045\0\x04:\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x040\0\x049\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x040\0\x04?\0\x04B\0\x040\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04:\0\x04\xAF\0\x04=\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04A\0\x040\0\x040\0\x04B\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04<\0\x04\xAF\0\x04=\0\x04\xE9\0\x04B\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04A\0\x045\0\x04:\0\x04C\0\x04=\0\x044\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static KK: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04H\0\x048\0\x04@\0\x045\0\x04:\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x040\0\x049\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x040\0\x04?\0\x04B\0\x040\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04:\0\x04\xAF\0\x04=\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04A\0\x040\0\x04\x93\0\x040\0\x04B\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04<\0\x048\0\x04=\0\x04C\0\x04B\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04A\0\x045\0\x04:\0\x04C\0\x04=\0\x044\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static TT: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04:\0\x042\0\x040\0\x04@\0\x04B\0\x040\0\x04;\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x040\0\x049\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x040\0\x04B\0\x04=\0\x040\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04:\0\x04\xE9\0\x04=\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04A\0\x04\xD9\0\x043\0\x040\0\x04B\0\x04L\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04<\0\x048\0\x04=\0\x04C\0\x04B\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04A\0\x045\0\x04:\0\x04C\0\x04=\0\x044\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static SR: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04:\0\x042\0\x040\0\x04@\0\x04B\0\x040\0\x04;\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04<\0\x045\0\x04A\0\x045\0\x04F\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04=\0\x045\0\x044\0\x045\0\x04Y\0\x040\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x044\0\x040\0\x04=\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04A\0\x040\0\x04B\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04<\0\x048\0\x04=\0\x04C\0\x04B\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04A\0\x045\0\x04:\0\x04C\0\x04=\0\x044\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static BG: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04B\0\x04@\0\x048\0\x04<\0\x045\0\x04A\0\x045\0\x04G\0\x048\0\x045\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04<\0\x045\0\x04A\0\x045\0\x04F\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04A\0\x045\0\x044\0\x04<\0\x048\0\x04F\0\x040\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x044\0\x045\0\x04=\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04G\0\x040\0\x04A\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04<\0\x048\0\x04=\0\x04C\0\x04B\0\x040\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04A\0\x045\0\x04:\0\x04C\0\x04=\0\x044\0\x040\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static MK: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04B\0\x04@\0\x04>\0\x04<\0\x045\0\x04A\0\x045\0\x04G\0\x04X\0\x045\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04<\0\x045\0\x04A\0\x045\0\x04F\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04A\0\x045\0\x044\0\x04<\0\x048\0\x04F\0\x040\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x044\0\x045\0\x04=\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04G\0\x040\0\x04A\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04<\0\x048\0\x04=\0\x04C\0\x04B\0\x040\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04A\0\x045\0\x04:\0\x04C\0\x04=\0\x044\0\x040\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static RU: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04:\0\x042\0\x040\0\x04@\0\x04B\0\x040\0\x04;\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04<\0\x045\0\x04A\0\x04O\0\x04F\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04=\0\x045\0\x044\0\x045\0\x04;\0\x04O\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x044\0\x045\0\x04=\0\x04L\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04G\0\x040\0\x04A\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04<\0\x048\0\x04=\0\x04C\0\x04B\0\x040\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04A\0\x045\0\x04:\0\x04C\0\x04=\0\x044\0\x040\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static BE: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04:\0\x042\0\x040\0\x04@\0\x04B\0\x040\0\x04;\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04<\0\x045\0\x04A\0\x04O\0\x04F\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04B\0\x04K\0\x044\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x044\0\x047\0\x045\0\x04=\0\x04L\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x043\0\x040\0\x044\0\x047\0\x04V\0\x04=\0\x040\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04E\0\x042\0\x04V\0\x04;\0\x04V\0\x04=\0\x040\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04A\0\x045\0\x04:\0\x04C\0\x04=\0\x044\0\x040\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static TG: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04G\0\x04>\0\x04@\0\x04O\0\x04:\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04<\0\x04>\0\x04\xB3\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04\xB3\0\x040\0\x04D\0\x04B\0\x040\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04@\0\x04\xEF\0\x047\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04A\0\x04>\0\x040\0\x04B\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x044\0\x040\0\x04\x9B\0\x048\0\x04\x9B\0\x040\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04A\0\x04>\0\x04=\0\x048\0\x04O\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static UK: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04:\0\x042\0\x040\0\x04@\0\x04B\0\x040\0\x04;\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04<\0\x04V\0\x04A\0\x04O\0\x04F\0\x04L\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04B\0\x048\0\x046\0\x044\0\x045\0\x04=\0\x04L\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x044\0\x045\0\x04=\0\x04L\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x043\0\x04>\0\x044\0\x048\0\x04=\0\x040\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04E\0\x042\0\x048\0\x04;\0\x048\0\x04=\0\x040\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04A\0\x045\0\x04:\0\x04C\0\x04=\0\x044\0\x040\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static BS_CYRL: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04B\0\x04@\0\x04>\0\x04<\0\x04X\0\x045\0\x04A\0\x045\0\x04G\0\x04X\0\x045\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04<\0\x04X\0\x045\0\x04A\0\x045\0\x04F\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04A\0\x045\0\x044\0\x04<\0\x048\0\x04F\0\x040\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x044\0\x040\0\x04=\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04A\0\x040\0\x04B\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04<\0\x048\0\x04=\0\x04C\0\x04B\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04A\0\x045\0\x04:\0\x04C\0\x04=\0\x044\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static SR_BA: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04:\0\x042\0\x040\0\x04@\0\x04B\0\x040\0\x04;\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04<\0\x04X\0\x045\0\x04A\0\x045\0\x04F\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04A\0\x045\0\x044\0\x04<\0\x048\0\x04F\0\x040\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x044\0\x040\0\x04=\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04A\0\x040\0\x04B\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04<\0\x048\0\x04=\0\x04C\0\x04B\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04A\0\x045\0\x04:\0\x04C\0\x04=\0\x044\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static MN: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04C\0\x04;\0\x048\0\x04@\0\x040\0\x04;\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04A\0\x040\0\x04@\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x044\0\x04>\0\x04;\0\x04>\0\x04>\0\0 \0\x04E\0\x04>\0\x04=\0\x04>\0\x043\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04\xE9\0\x044\0\x04\xE9\0\x04@\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04F\0\x040\0\x043\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04<\0\x048\0\x04=\0\x04C\0\x04B\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04A\0\x045\0\x04:\0\x04C\0\x04=\0\x044\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\x80\0\x01\0\0)") } } };
+                static CV: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04:\0\x042\0\x040\0\x04@\0\x04B\0\x040\0\x04;\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04C\0\x049\0\x04\xD1\0\x04E\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04M\0\x04@\0\x04=\0\x045\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04:\0\x04C\0\x04=\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04A\0\x045\0\x04E\0\x045\0\x04B\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04<\0\x048\0\x04=\0\x04C\0\x04B\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04A\0\x045\0\x04:\0\x04C\0\x04=\0\x044\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static UZ_CYRL: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0Q\0\0u\0\0a\0\0r\0\0t\0\0e\0\0r\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04\x1E\0\x049\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04\xB2\0\x040\0\x04D\0\x04B\0\x040\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04\x1A\0\x04C\0\x04=\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04!\0\x04>\0\x040\0\x04B\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04\x14\0\x040\0\x04\x9B\0\x048\0\x04\x9B\0\x040\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x04!\0\x04>\0\x04=\0\x048\0\x04O\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static HE: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x05\xE8\0\x05\xD1\0\x05\xE2\0\x05\xD5\0\x05\xDF\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x05\xD7\0\x05\xD5\0\x05\xD3\0\x05\xE9\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x05\xE9\0\x05\xD1\0\x05\xD5\0\x05\xE2\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x05\xD9\0\x05\xD5\0\x05\xDD\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x05\xE9\0\x05\xE2\0\x05\xD4\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x05\xD3\0\x05\xE7\0\x05\xD4\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x05\xE9\0\x05\xE0\0\x05\xD9\0\x05\xD9\0\x05\xD4\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static HY: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x05e\0\x05|\0\x05a\0\x05t\0\x05}\0\x05u\0\x05a\0\x05o\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x05a\0\x05t\0\x05k\0\x05}\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x05w\0\x05a\0\x05b\0\x05a\0\x05i\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x05\x85\0\x05\x80\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x05j\0\x05a\0\x05t\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x05\x80\0\x05x\0\x05z\0\x05e\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x05~\0\x05a\0\x05u\0\x05\x80\0\x05o\0\x05u\0\x05a\0\x05v\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static AR: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x061\0\x06(\0\x069\0\0 \0\x06'\0\x06D\0\x063\0\x06F\0\x06)\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x06'\0\x06D\0\x064\0\x06G\0\x061\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x06'\0\x06D\0\x06#\0\x063\0\x06(\0\x06H\0\x069\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x06J\0\x06H\0\x06E\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x06'\0\x06D\0\x063\0\x06'\0\x069\0\x06'\0\x06*\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x06'\0\x06D\0\x06/\0\x06B\0\x06'\0\x06&\0\x06B\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x06'\0\x06D\0\x06+\0\x06H\0\x06'\0\x06F\0\x06J\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static KS: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x06\xA9\0\x06H\0\x06'\0\x061\0\x06y\0\x061\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x061\0\x06 \0\x06*\0\x06\xBE\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x06\xC1\0\x06A\0\x06*\0\x06\xC1\0\x06U\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x06/\0\x06\xC4\0\x06\xC1\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x06\xAF\0\x06r\0\x06F\0\x06y\0\x06\xC1\0\x06U\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x06E\0\x06P\0\x06F\0\x06N\0\x06y\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x063\0\x06 \0\x06\xA9\0\x06N\0\x06F\0\x06\x88\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static FA: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x063\0\x06G\0 \x0C\0\x06E\0\x06'\0\x06G\0\x06G\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x06E\0\x06'\0\x06G\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x06G\0\x06A\0\x06*\0\x06G\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x061\0\x06H\0\x062\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x063\0\x06'\0\x069\0\x06*\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x06/\0\x06B\0\x06\xCC\0\x06B\0\x06G\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x06+\0\x06'\0\x06F\0\x06\xCC\0\x06G\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\x80\0\x01\0\0)") } } };
+                static FA_AF: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x063\0\x06G\0 \x0C\0\x06E\0\x06'\0\x06G\0\x06G\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x06E\0\x06'\0\x06G\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x06G\0\x06A\0\x06*\0\x06G\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x061\0\x06H\0\x062\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x063\0\x06'\0\x069\0\x06*\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x06/\0\x06B\0\x06\xCC\0\x06B\0\x06G\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x06+\0\x06'\0\x06F\0\x06\xCC\0\x06G\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static SD: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x06}\0\x06J\0\0 \0\x06E\0\x06'\0\x06G\0\x06J\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x06E\0\x06G\0\x06J\0\x06F\0\x06H\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x06G\0\x06A\0\x06*\0\x06H\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x06\x8F\0\x06J\0\x06F\0\x06G\0\x06F\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x06\xAA\0\x06D\0\x06'\0\x06\xAA\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x06E\0\x06F\0\x06}\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x063\0\x06J\0\x06\xAA\0\x06F\0\x06\x8A\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static PS: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x06\x85\0\x06D\0\x06H\0\x061\0\x06E\0\x06G\0\0 \0\x06(\0\x061\0\x06.\0\x06G\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x06E\0\x06J\0\x06'\0\x064\0\x06*\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x06'\0\x06H\0\x06F\0\x06\xCD\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x06H\0\x061\0\x06\x81\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x063\0\x06'\0\x069\0\x06*\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x06/\0\x06B\0\x06J\0\x06B\0\x06G\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x06+\0\x06'\0\x06F\0\x06J\0\x06G\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\x80\0\x01\0\0)") } } };
+                static UR: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x063\0\x06\xC1\0\0 \0\x06E\0\x06'\0\x06\xC1\0\x06\xCC\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x06E\0\x06\xC1\0\x06\xCC\0\x06F\0\x06\xC1\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x06\xC1\0\x06A\0\x06*\0\x06\xC1\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x06/\0\x06F\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x06\xAF\0\x06\xBE\0\x06F\0\x06y\0\x06\xC1\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x06E\0\x06F\0\x06y\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x063\0\x06\xCC\0\x06\xA9\0\x06F\0\x06\x88\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static OR: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x0B$\0\x0BM\0\x0B0\0\x0B_\0\x0B.\0\x0B>\0\x0B8\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x0B.\0\x0B>\0\x0B8\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x0B8\0\x0B*\0\x0BM\0\x0B$\0\x0B>\0\x0B9\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x0B&\0\x0B?\0\x0B(\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x0B\x18\0\x0B#\0\x0BM\0\x0B\x1F\0\x0B>\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x0B.\0\x0B?\0\x0B(\0\x0B?\0\x0B\x1F\0\x0BM\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x0B8\0\x0BG\0\x0B\x15\0\x0BG\0\x0B#\0\x0BM\0\x0B!\0\x0BM\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static TA: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x0B\x95\0\x0B\xBE\0\x0B\xB2\0\x0B\xBE\0\x0B\xA3\0\x0B\xCD\0\x0B\x9F\0\x0B\xC1\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x0B\xAE\0\x0B\xBE\0\x0B\xA4\0\x0B\xAE\0\x0B\xCD\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x0B\xB5\0\x0B\xBE\0\x0B\xB0\0\x0B\xAE\0\x0B\xCD\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x0B\xA8\0\x0B\xBE\0\x0B\xB3\0\x0B\xCD\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x0B\xAE\0\x0B\xA3\0\x0B\xBF\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x0B\xA8\0\x0B\xBF\0\x0B\xAE\0\x0B\xBF\0\x0B\x9F\0\x0B\xAE\0\x0B\xCD\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x0B\xB5\0\x0B\xBF\0\x0B\xA8\0\x0B\xBE\0\x0B\x9F\0\x0B\xBF\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static TE: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x0C$\0\x0CM\0\x0C0\0\x0CH\0\x0C.\0\x0C>\0\x0C8\0\x0C?\0\x0C\x15\0\x0C\x02\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x0C(\0\x0CF\0\x0C2\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x0C5\0\x0C>\0\x0C0\0\x0C.\0\x0CA\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x0C&\0\x0C?\0\x0C(\0\x0C\x02\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x0C\x17\0\x0C\x02\0\x0C\x1F\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x0C(\0\x0C?\0\x0C.\0\x0C?\0\x0C7\0\x0C.\0\x0CA\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x0C8\0\x0CF\0\x0C\x15\0\x0C(\0\x0CA\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static KN: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x0C\xA4\0\x0C\xCD\0\x0C\xB0\0\x0C\xC8\0\x0C\xAE\0\x0C\xBE\0\x0C\xB8\0\x0C\xBF\0\x0C\x95\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x0C\xA4\0\x0C\xBF\0\x0C\x82\0\x0C\x97\0\x0C\xB3\0\x0C\xC1\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x0C\xB5\0\x0C\xBE\0\x0C\xB0\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x0C\xA6\0\x0C\xBF\0\x0C\xA8\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x0C\x97\0\x0C\x82\0\x0C\x9F\0\x0C\xC6\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x0C\xA8\0\x0C\xBF\0\x0C\xAE\0\x0C\xBF\0\x0C\xB7\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x0C\xB8\0\x0C\xC6\0\x0C\x95\0\x0C\xC6\0\x0C\x82\0\x0C\xA1\0\x0C\xCD\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static TH: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x0ED\0\x0E\x15\0\x0E#\0\x0E!\0\x0E2\0\x0E*\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x0E@\0\x0E\x14\0\x0E7\0\x0E-\0\x0E\x19\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x0E*\0\x0E1\0\x0E\x1B\0\x0E\x14\0\x0E2\0\x0E+\0\x0EL\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x0E'\0\x0E1\0\x0E\x19\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x0E\n\0\x0E1\0\x0EH\0\x0E'\0\x0EB\0\x0E!\0\x0E\x07\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x0E\x19\0\x0E2\0\x0E\x17\0\x0E5\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x0E'\0\x0E4\0\x0E\x19\0\x0E2\0\x0E\x17\0\x0E5\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static LO: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x0E\xC4\0\x0E\x95\0\x0E\xA3\0\x0E\xA1\0\x0E\xB2\0\x0E\x94\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x0E\xC0\0\x0E\x94\0\x0E\xB7\0\x0E\xAD\0\x0E\x99\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x0E\xAD\0\x0E\xB2\0\x0E\x97\0\x0E\xB4\0\x0E\x94\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x0E\xA1\0\x0E\xB7\0\x0E\xC9\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x0E\x8A\0\x0E\xBB\0\x0E\xC8\0\x0E\xA7\0\x0E\xC2\0\x0E\xA1\0\x0E\x87\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x0E\x99\0\x0E\xB2\0\x0E\x97\0\x0E\xB5\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x0E\xA7\0\x0E\xB4\0\x0E\x99\0\x0E\xB2\0\x0E\x97\0\x0E\xB5\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static MY: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x10\x1E\0\x10/\0\x106\0\x108\0\x10\x1C\0\x10\x15\0\x10\x10\0\x10:\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x10\x1C\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x10\x15\0\x10\x10\0\x10:\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x10\x1B\0\x10\0\0\x10:\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x10\x14\0\x10,\0\x10\x1B\0\x10.\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x10\x19\0\x10-\0\x10\x14\0\x10\x05\0\x10:\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x10\x05\0\x10\0\0\x109\0\x10\0\0\x10\x14\0\x107\0\x10:\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } } };
+                static KA: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x10\xD9\0\x10\xD5\0\x10\xD0\0\x10\xE0\0\x10\xE2\0\x10\xD0\0\x10\xDA\0\x10\xD8\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x10\xD7\0\x10\xD5\0\x10\xD4\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x10\xD9\0\x10\xD5\0\x10\xD8\0\x10\xE0\0\x10\xD0\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x10\xD3\0\x10\xE6\0\x10\xD4\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x10\xE1\0\x10\xD0\0\x10\xD0\0\x10\xD7\0\x10\xD8\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x10\xEC\0\x10\xE3\0\x10\xD7\0\x10\xD8\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x10\xEC\0\x10\xD0\0\x10\xDB\0\x10\xD8\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static AM: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x12)\0\x12e\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x12\xC8\0\x12-\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x123\0\x12\x1D\0\x12\x95\0\x12u\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x12@\0\x12\x95\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x120\0\x12\xD3\0\x12u\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x12\xF0\0\x12B\0\x12C\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x120\0\x12\xA8\0\x12\x95\0\x12\xF5\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static TI: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x12-\0\x12e\0\x12\xD2\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x12\xC8\0\x12-\0\x12\x12\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x120\0\x12\x19\0\x12\x95\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x12\x18\0\x12\xD3\0\x12\r\0\x12r\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x120\0\x12\xD3\0\x12u\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x12\xF0\0\x12R\0\x12U\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x12\xAB\0\x12\r\0\x12\xA2\0\x12u\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static CHR: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x13\xA9\0\x13\xC4\0\x13\xD9\0\x13\xD7\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x13\xA7\0\x13\xB8\0\x13\xA2\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x13\xD2\0\x13\xBE\0\x13\xD9\0\x13\xD3\0\x13\xC6\0\x13\xCD\0\x13\xD7\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x13\xA2\0\x13\xA6\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x13\xD1\0\x13\xDF\0\x13\xB6\0\x13\xD3\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x13\xA2\0\x13\xEF\0\x13\xD4\0\x13\xEC\0\x13\xCD\0\x13\xD4\0\x13\xC5\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x13\xA0\0\x13\xCE\0\x13\xE2\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static KM: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x17\x8F\0\x17\xD2\0\x17\x9A\0\x17\xB8\0\x17\x98\0\x17\xB6\0\x17\x9F\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x17\x81\0\x17\xC2\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x17\x9F\0\x17\x94\0\x17\xD2\0\x17\x8A\0\x17\xB6\0\x17\xA0\0\x17\xCD\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x17\x90\0\x17\xD2\0\x17\x84\0\x17\xC3\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x17\x98\0\x17\xC9\0\x17\xC4\0\x17\x84\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x17\x93\0\x17\xB6\0\x17\x91\0\x17\xB8\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x17\x9C\0\x17\xB7\0\x17\x93\0\x17\xB6\0\x17\x91\0\x17\xB8\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static SAT: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x1Co\0\x1Cn\0\x1Cj\0\x1C_\0\x1Cx\0\x1Ck\0\x1CZ\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x1Cj\0\x1C_\0\x1Cx\0\x1Ck\0\x1CZ\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x1Cf\0\x1C_\0\x1Co\0\x1C[\0\x1C_\0\0/\0\x1Cn\0\x1Cb\0\x1Cf\0\x1C_\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x1Cb\0\x1C_\0\x1Cf\0\x1C_\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x1Ct\0\x1C_\0\x1Cr\0\x1C_\0\x1C]\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x1Ct\0\x1Cd\0\x1Co\0\x1Cd\0\x1Ca\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x1Ct\0\x1Cd\0\x1Ch\0\x1Cd\0\x1Ca\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static IG: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x1E\xCC\0\0n\0\0w\0\0a\0\0 \0\0a\0\0t\0\x1E\xCD\0\0 \0\0n\0 \x19\0\0a\0\0f\0\x1E\xCD\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x1E\xCC\0\0n\0\0w\0\0a\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0I\0\0z\0\0u\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x1E\xE4\0\0b\0\x1E\xCD\0\0c\0\0h\0\x1E\xCB\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0E\0\0l\0\0e\0\0k\0\0e\0\0r\0\0e\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0N\0\0k\0\0e\0\0j\0\0i\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\0T\0\x1E\xCB\0\0m\0\0 \0\0k\0\x1E\xCD\0\0m\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static KO: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\xBD\x84\0\xAE0\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\xC6\xD4\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\xC8\xFC\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\xC7|\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\xC2\xDC\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\xBD\x84\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\xCD\x08\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static YUE_HANS: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0[c\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0g\x08\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0Th\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0e\xE5\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\\\x0F\0e\xF6\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0R\x06\0\x94\x9F\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0y\xD2\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\x80\0\0") } } };
+                static ZH: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0[c\0^\xA6\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0g\x08\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0Th\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0e\xE5\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\\\x0F\0e\xF6\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0R\x06\0\x94\x9F\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0y\xD2\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\x80\0\0") } } };
+                static YUE: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0[c\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0g\x08\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x901\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0e\xE5\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\\\x0F\0fB\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0R\x06\0\x94\x18\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0y\xD2\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static JA: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0V\xDB\0SJ\0g\x1F\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0g\x08\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\x901\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0e\xE5\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0fB\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0R\x06\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0y\xD2\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static ZH_HK: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0[c\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0g\x08\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0f\x1F\0g\x1F\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0e\xE5\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0\\\x0F\0fB\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0R\x06\0\x94\x18\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\0y\xD2\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static FF_ADLM: <icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::AppendItemsV1 { era: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, year: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\x01\0\0 \x80\0\0") } }, quarter: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\x01\xE92\x01\xE9\"\x01\xE94\x01\xE9\"\x01\xE9)\x01\xE9-\x01\xE9E\x01\xE9*\x01\xE9+\0\0:\0\0 \x80\0\x01\0\0)") } }, month: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\x01\xE9$\x01\xE9+\x01\xE91\x01\xE9*\x01\xE95\0\0:\0\0 \x80\0\x01\0\0)") } }, week: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\x01\xE94\x01\xE9.\x01\xE92\x01\xE9<\x01\xE9+\x01\xE9*\x01\xE9+\0\0:\0\0 \x80\0\x01\0\0)") } }, day: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\x01\xE9;\x01\xE9\"\x01\xE9$\x01\xE9\"\x01\xE9D\x01\xE92\x01\xE9#\x01\xE9+\0\0:\0\0 \x80\0\x01\0\0)") } }, weekday: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } }, hour: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\x01\xE92\x01\xE96\x01\xE9\"\x01\xE9%\x01\xE9#\x01\xE9-\0\0:\0\0 \x80\0\x01\0\0)") } }, minute: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\x01\xE98\x01\xE9.\x01\xE96\x01\xE9.\x01\xE9%\x01\xE9\"\x01\xE9D\x01\xE9*\x01\xE9+\0\0:\0\0 \x80\0\x01\0\0)") } }, second: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \0\0(\x01\xE93\x01\xE9-\x01\xE92\x01\xE90\x01\xE9+\x01\xE9*\x01\xE9+\0\0:\0\0 \x80\0\x01\0\0)") } }, time_zone: icu::datetime::pattern::runtime::GenericPattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\0\0\0\0 \x80\0\x01") } } };
+                static VALUES: [&<icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::DataMarker>::Yokeable; 135usize] = [&AF, &AM, &AR, &AS, &AST, &AZ, &BE, &BG, &BN, &BR, &BRX, &BS, &BS_CYRL, &CA, &CEB, &CHR, &CS, &CV, &CY, &DA, &DE, &DOI, &DSB, &EL, &EN, &ES, &ES_DO, &ET, &EU, &FA, &FA_AF, &FF_ADLM, &FI, &FIL, &FO, &FR, &GA, &GD, &ES, &GU, &HA, &HE, &HI, &HI_LATN, &HR, &HSB, &HU, &HY, &IA, &ID, &IG, &IS, &IT, &JA, &JV, &KA, &KEA, &KGP, &KK, &KM, &KN, &KO, &KOK, &KS, &KS_DEVA, &KY, &LO, &LT, &LV, &MAI, &MI, &MK, &ML, &MN, &MNI, &MR, &MS, &MY, &NE, &NL, &NN, &NO, &OR, &PA, &PCM, &PL, &PS, &PT, &RM, &RO, &RU, &SA, &SAT, &SC, &SD, &SD_DEVA, &SI, &SK, &SL, &SO, &SQ, &SR, &SR_BA, &SR_LATN, &SR_LATN_BA, &SU, &SV, &SW, &SW_CD, &TA, &TE, &TG, &TH, &TI, &TK, &TO, &TR, &TT, &UK, &UND, &UR, &UZ, &UZ_CYRL, &VI, &WO, &XH, &YO, &YO_BJ, &YRL, &YUE, &YUE_HANS, &ZH, &ZH_HK, &YUE, &ZU];
+                static KEYS: [&str; 135usize] = ["af", "am", "ar", "as", "ast", "az", "be", "bg", "bn", "br", "brx", "bs", "bs-Cyrl", "ca", "ceb", "chr", "cs", "cv", "cy", "da", "de", "doi", "dsb", "el", "en", "es", "es-DO", "et", "eu", "fa", "fa-AF", "ff-Adlm", "fi", "fil", "fo", "fr", "ga", "gd", "gl", "gu", "ha", "he", "hi", "hi-Latn", "hr", "hsb", "hu", "hy", "ia", "id", "ig", "is", "it", "ja", "jv", "ka", "kea", "kgp", "kk", "km", "kn", "ko", "kok", "ks", "ks-Deva", "ky", "lo", "lt", "lv", "mai", "mi", "mk", "ml", "mn", "mni", "mr", "ms", "my", "ne", "nl", "nn", "no", "or", "pa", "pcm", "pl", "ps", "pt", "rm", "ro", "ru", "sa", "sat", "sc", "sd", "sd-Deva", "si", "sk", "sl", "so", "sq", "sr", "sr-BA", "sr-Latn", "sr-Latn-BA", "su", "sv", "sw", "sw-CD", "ta", "te", "tg", "th", "ti", "tk", "to", "tr", "tt", "uk", "und", "ur", "uz", "uz-Cyrl", "vi", "wo", "xh", "yo", "yo-BJ", "yrl", "yue", "yue-Hans", "zh", "zh-HK", "zh-Hant", "zu"];
+                let mut metadata = icu_provider::DataResponseMetadata::default();
+                let payload = if let Ok(payload) = KEYS.binary_search_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse()).map(|i| *unsafe { VALUES.get_unchecked(i) }) {
+                    payload
+                } else {
+                    const FALLBACKER: icu::locid_transform::fallback::LocaleFallbackerWithConfig<'static> = icu::locid_transform::fallback::LocaleFallbacker::new().for_config(<icu::datetime::provider::calendar::AppendItemsV1Marker as icu_provider::KeyedDataMarker>::KEY.fallback_config());
+                    let mut fallback_iterator = FALLBACKER.fallback_for(req.locale.clone());
+                    loop {
+                        if let Ok(payload) = KEYS.binary_search_by(|k| fallback_iterator.get().strict_cmp(k.as_bytes()).reverse()).map(|i| *unsafe { VALUES.get_unchecked(i) }) {
+                            metadata.locale = Some(fallback_iterator.take());
+                            break payload;
+                        }
+                        fallback_iterator.step();
+                    }
+                };
+                Ok(icu_provider::DataResponse { payload: Some(icu_provider::DataPayload::from_static_ref(payload)), metadata })
+            }
+        }
+    };
+}
@@ -0,0 +1,4324 @@
+// @generated
+/// Implement `DataProvider<FlexibleDayPeriodsV1Marker>` on the given struct using the data
+/// hardcoded in this file. This allows the struct to be used with
+/// `icu`'s `_unstable` constructors.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_datetime_flexibledayperiods_v1 {
+    ($ provider : path) => {
+        #[clippy::msrv = "1.66"]
+        impl icu_provider::DataProvider<icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker> for $provider {
+            fn load(&self, req: icu_provider::DataRequest) -> Result<icu_provider::DataResponse<icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker>, icu_provider::DataError> {
+                static FI: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b",\x01X\x02\xD0\x028\x04d\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x07\0\r\0\x13\0\x1A\0aamullaaamup.iltap.illallay\xC3\xB6ll\xC3\xA4") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("keskip.")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("keskiyöllä")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b",\x01X\x02\xD0\x028\x04d\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x07\0\n\0\r\0\x14\0aamullaap.ip.illallay\xC3\xB6ll\xC3\xA4") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("kp.")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("ky.")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b",\x01X\x02\xD0\x028\x04d\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x07\0\x16\0%\0,\0aamullaaamup\xC3\xA4iv\xC3\xA4ll\xC3\xA4iltap\xC3\xA4iv\xC3\xA4ll\xC3\xA4illallay\xC3\xB6ll\xC3\xA4") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("keskipäivällä")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("keskiyöllä")),
+                        },
+                    },
+                };
+                static RO: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b",\x01\xD0\x028\x04(\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\n\0\x16\0\x1B\0diminea\xC8\x9Badup\xC4\x83-amiazasearanoaptea") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("amiază")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("miezul nopții")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b",\x01\xD0\x028\x04(\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\n\0\x16\0\x1B\0diminea\xC8\x9Badup\xC4\x83-amiazasearanoaptea") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("amiază")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("miezul nopții")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b",\x01\xD0\x028\x04(\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\n\0\x16\0\x1B\0diminea\xC8\x9Badup\xC4\x83-amiazasearanoaptea") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("la amiază")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("la miezul nopții")),
+                        },
+                    },
+                };
+                static RO_MD: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b",\x01\xD0\x028\x04(\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\n\0\x16\0\x1B\0diminea\xC8\x9Badup\xC4\x83-amiazasearanoaptea") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("amiază")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("miezul nopții")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b",\x01\xD0\x028\x04(\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0B\0\x18\0\x1E\0diminea\xC8\x9B\xC4\x83dup\xC4\x83-amiaz\xC4\x83sear\xC4\x83noapte") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("amiază")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("miezul nopții")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b",\x01\xD0\x028\x04(\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\n\0\x16\0\x1B\0diminea\xC8\x9Badup\xC4\x83-amiazasearanoaptea") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("amiază")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("miezul nopții")),
+                        },
+                    },
+                };
+                static KA: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b",\x01\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\n\0 \0*\0\xE1\x83\x93\xE1\x83\x98\xE1\x83\x9A.\xE1\x83\x9C\xE1\x83\x90\xE1\x83\xA8\xE1\x83\xA3\xE1\x83\x90\xE1\x83\x93\xE1\x83\xA6.\xE1\x83\xA1\xE1\x83\x90\xE1\x83\xA6.\xE1\x83\xA6\xE1\x83\x90\xE1\x83\x9B.") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("შუადღ.")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("შუაღამეს")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b",\x01\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\n\0 \0*\0\xE1\x83\x93\xE1\x83\x98\xE1\x83\x9A.\xE1\x83\x9C\xE1\x83\x90\xE1\x83\xA8\xE1\x83\xA3\xE1\x83\x90\xE1\x83\x93\xE1\x83\xA6.\xE1\x83\xA1\xE1\x83\x90\xE1\x83\xA6.\xE1\x83\xA6\xE1\x83\x90\xE1\x83\x9B.") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("შუადღ.")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("შუაღამეს")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b",\x01\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0F\0-\0B\0\xE1\x83\x93\xE1\x83\x98\xE1\x83\x9A\xE1\x83\x98\xE1\x83\x97\xE1\x83\x9C\xE1\x83\x90\xE1\x83\xA8\xE1\x83\xA3\xE1\x83\x90\xE1\x83\x93\xE1\x83\xA6\xE1\x83\x94\xE1\x83\x95\xE1\x83\xA1\xE1\x83\xA1\xE1\x83\x90\xE1\x83\xA6\xE1\x83\x90\xE1\x83\x9B\xE1\x83\x9D\xE1\x83\xA1\xE1\x83\xA6\xE1\x83\x90\xE1\x83\x9B\xE1\x83\x98\xE1\x83\x97") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("შუადღეს")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("შუაღამეს")),
+                        },
+                    },
+                };
+                static ET: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b",\x01\xD0\x028\x04d\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x08\0\x16\0\x1C\0hommikulp\xC3\xA4rastl\xC3\xB5unal\xC3\xB5htul\xC3\xB6\xC3\xB6sel") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("keskpäeval")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("keskööl")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b",\x01\xD0\x028\x04d\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x08\0\x16\0\x1C\0hommikulp\xC3\xA4rastl\xC3\xB5unal\xC3\xB5htul\xC3\xB6\xC3\xB6sel") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("keskpäeval")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("keskööl")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b",\x01\xD0\x028\x04d\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x08\0\x16\0\x1C\0hommikulp\xC3\xA4rastl\xC3\xB5unal\xC3\xB5htul\xC3\xB6\xC3\xB6sel") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("keskpäeval")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("keskööl")),
+                        },
+                    },
+                };
+                static LO: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b",\x01\xD0\x02\xC0\x03\xB0\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x18\0-\0?\0\xE0\xBA\x95\xE0\xBA\xAD\xE0\xBA\x99\xE0\xBB\x80\xE0\xBA\x8A\xE0\xBA\xBB\xE0\xBB\x89\xE0\xBA\xB2\xE0\xBA\x95\xE0\xBA\xAD\xE0\xBA\x99\xE0\xBA\x9A\xE0\xBB\x88\xE0\xBA\xB2\xE0\xBA\x8D\xE0\xBA\x95\xE0\xBA\xAD\xE0\xBA\x99\xE0\xBB\x81\xE0\xBA\xA5\xE0\xBA\x87\xE0\xBA\x81\xE0\xBA\xB2\xE0\xBA\x87\xE0\xBA\x84\xE0\xBA\xB7\xE0\xBA\x99") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("ຕອນທ\u{ec8}ຽງ")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("ທ\u{ec8}ຽງຄ\u{eb7}ນ")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b",\x01\xD0\x02\xC0\x03\xB0\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x18\0-\0?\0\xE0\xBA\x95\xE0\xBA\xAD\xE0\xBA\x99\xE0\xBB\x80\xE0\xBA\x8A\xE0\xBA\xBB\xE0\xBB\x89\xE0\xBA\xB2\xE0\xBA\x95\xE0\xBA\xAD\xE0\xBA\x99\xE0\xBA\x97\xE0\xBB\x88\xE0\xBA\xBD\xE0\xBA\x87\xE0\xBA\x95\xE0\xBA\xAD\xE0\xBA\x99\xE0\xBB\x81\xE0\xBA\xA5\xE0\xBA\x87\xE0\xBA\x81\xE0\xBA\xB2\xE0\xBA\x87\xE0\xBA\x84\xE0\xBA\xB7\xE0\xBA\x99") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("ຕອນທ\u{ec8}ຽງ")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("ທ\u{ec8}ຽງຄ\u{eb7}ນ")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b",\x01\xD0\x02\xC0\x03\xB0\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x18\0-\0?\0\xE0\xBA\x95\xE0\xBA\xAD\xE0\xBA\x99\xE0\xBB\x80\xE0\xBA\x8A\xE0\xBA\xBB\xE0\xBB\x89\xE0\xBA\xB2\xE0\xBA\x95\xE0\xBA\xAD\xE0\xBA\x99\xE0\xBA\x9A\xE0\xBB\x88\xE0\xBA\xB2\xE0\xBA\x8D\xE0\xBA\x95\xE0\xBA\xAD\xE0\xBA\x99\xE0\xBB\x81\xE0\xBA\xA5\xE0\xBA\x87\xE0\xBA\x95\xE0\xBA\xAD\xE0\xBA\x99\xE0\xBA\x81\xE0\xBA\xB2\xE0\xBA\x87\xE0\xBA\x84\xE0\xBA\xB7\xE0\xBA\x99") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("ຕອນທ\u{ec8}ຽງ")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("ທ\u{ec8}ຽງຄ\u{eb7}ນ")),
+                        },
+                    },
+                };
+                static SV: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0,\x01X\x02\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\n\0\x13\0\x1D\0(\0p\xC3\xA5 nattenp\xC3\xA5 morg.p\xC3\xA5 f\xC3\xB6rm.p\xC3\xA5 efterm.p\xC3\xA5 kv\xC3\xA4llen") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("midnatt")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0,\x01X\x02\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\n\0\x13\0\x1D\0(\0p\xC3\xA5 nattenp\xC3\xA5 morg.p\xC3\xA5 f\xC3\xB6rm.p\xC3\xA5 efterm.p\xC3\xA5 kv\xC3\xA4llen") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("midn.")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0,\x01X\x02\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\n\0\x16\0&\x007\0p\xC3\xA5 nattenp\xC3\xA5 morgonenp\xC3\xA5 f\xC3\xB6rmiddagenp\xC3\xA5 eftermiddagenp\xC3\xA5 kv\xC3\xA4llen") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("midnatt")),
+                        },
+                    },
+                };
+                static DA: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0,\x01X\x02\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\t\0\x14\0\"\x002\0om nattenom morgenenom formiddagenom eftermiddagenom aftenen") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("midnat")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0,\x01X\x02\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\t\0\x14\0\"\x002\0om nattenom morgenenom formiddagenom eftermiddagenom aftenen") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("midnat")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0,\x01X\x02\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\t\0\x14\0\"\x002\0om nattenom morgenenom formiddagenom eftermiddagenom aftenen") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("midnat")),
+                        },
+                    },
+                };
+                static DE: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0,\x01X\x02\xD0\x02\x0C\x038\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x06\0\r\0\x12\0\x19\0\x1F\0nachtsmorgensvorm.mittagsnachm.abends") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("Mitternacht")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0,\x01X\x02\xD0\x02\x0C\x038\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x06\0\r\0\x12\0\x19\0\x1F\0nachtsmorgensvorm.mittagsnachm.abends") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("Mitternacht")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0,\x01X\x02\xD0\x02\x0C\x038\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x06\0\r\0\x17\0\x1E\0)\0nachtsmorgensvormittagsmittagsnachmittagsabends") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("Mitternacht")),
+                        },
+                    },
+                };
+                static AF: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0,\x01\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x07\0\x11\0\x1B\0die nagdie oggenddie middagdie aand") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("middernag")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0,\x01\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x01\0\x02\0\x03\0noma") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("mn")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0,\x01\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x07\0\x11\0\x1B\0die nagdie oggenddie middagdie aand") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("middernag")),
+                        },
+                    },
+                };
+                static ZH_HK: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0,\x01\xE0\x01\xD0\x02\x0C\x03t\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x06\0\x0C\0\x12\0\x18\0\x1E\0\xE5\x87\x8C\xE6\x99\xA8\xE6\x97\xA9\xE4\xB8\x8A\xE4\xB8\x8A\xE5\x8D\x88\xE4\xB8\xAD\xE5\x8D\x88\xE4\xB8\x8B\xE5\x8D\x88\xE6\x99\x9A\xE4\xB8\x8A") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("午夜")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0,\x01\xE0\x01\xD0\x02\x0C\x03t\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x06\0\x0C\0\x12\0\x18\0\x1E\0\xE5\x87\x8C\xE6\x99\xA8\xE6\x97\xA9\xE4\xB8\x8A\xE4\xB8\x8A\xE5\x8D\x88\xE4\xB8\xAD\xE5\x8D\x88\xE4\xB8\x8B\xE5\x8D\x88\xE6\x99\x9A\xE4\xB8\x8A") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("午夜")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0,\x01\xE0\x01\xD0\x02\x0C\x03t\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x06\0\x0C\0\x12\0\x18\0\x1E\0\xE5\x87\x8C\xE6\x99\xA8\xE6\x97\xA9\xE4\xB8\x8A\xE4\xB8\x8A\xE5\x8D\x88\xE4\xB8\xAD\xE5\x8D\x88\xE4\xB8\x8B\xE5\x8D\x88\xE6\x99\x9A\xE4\xB8\x8A") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("午夜")),
+                        },
+                    },
+                };
+                static ZH: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0,\x01\xE0\x01\xD0\x02\x0C\x03t\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x06\0\x0C\0\x12\0\x18\0\x1E\0\xE5\x87\x8C\xE6\x99\xA8\xE6\x97\xA9\xE4\xB8\x8A\xE4\xB8\x8A\xE5\x8D\x88\xE4\xB8\xAD\xE5\x8D\x88\xE4\xB8\x8B\xE5\x8D\x88\xE6\x99\x9A\xE4\xB8\x8A") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("午夜")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0,\x01\xE0\x01\xD0\x02\x0C\x03t\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x06\0\x0C\0\x12\0\x18\0\x1E\0\xE5\x87\x8C\xE6\x99\xA8\xE6\x97\xA9\xE4\xB8\x8A\xE4\xB8\x8A\xE5\x8D\x88\xE4\xB8\xAD\xE5\x8D\x88\xE4\xB8\x8B\xE5\x8D\x88\xE6\x99\x9A\xE4\xB8\x8A") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("午夜")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0,\x01\xE0\x01\xD0\x02\x0C\x03t\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x06\0\x0C\0\x12\0\x18\0\x1E\0\xE5\x87\x8C\xE6\x99\xA8\xE6\xB8\x85\xE6\x99\xA8\xE4\xB8\x8A\xE5\x8D\x88\xE4\xB8\xAD\xE5\x8D\x88\xE4\xB8\x8B\xE5\x8D\x88\xE6\x99\x9A\xE4\xB8\x8A") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("午夜")),
+                        },
+                    },
+                };
+                static ZH_HANT: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0,\x01\xE0\x01\xD0\x02\x0C\x03t\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x06\0\x0C\0\x12\0\x18\0\x1E\0\xE5\x87\x8C\xE6\x99\xA8\xE6\xB8\x85\xE6\x99\xA8\xE4\xB8\x8A\xE5\x8D\x88\xE4\xB8\xAD\xE5\x8D\x88\xE4\xB8\x8B\xE5\x8D\x88\xE6\x99\x9A\xE4\xB8\x8A") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("午夜")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0,\x01\xE0\x01\xD0\x02\x0C\x03t\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x06\0\x0C\0\x12\0\x18\0\x1E\0\xE5\x87\x8C\xE6\x99\xA8\xE6\xB8\x85\xE6\x99\xA8\xE4\xB8\x8A\xE5\x8D\x88\xE4\xB8\xAD\xE5\x8D\x88\xE4\xB8\x8B\xE5\x8D\x88\xE6\x99\x9A\xE4\xB8\x8A") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("午夜")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0,\x01\xE0\x01\xD0\x02\x0C\x03t\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x06\0\x0C\0\x12\0\x18\0\x1E\0\xE5\x87\x8C\xE6\x99\xA8\xE6\xB8\x85\xE6\x99\xA8\xE4\xB8\x8A\xE5\x8D\x88\xE4\xB8\xAD\xE5\x8D\x88\xE4\xB8\x8B\xE5\x8D\x88\xE6\x99\x9A\xE4\xB8\x8A") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("午夜")),
+                        },
+                    },
+                };
+                static YUE_HANS: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0,\x01\xE0\x01\xD0\x02\x0C\x03t\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x06\0\x0C\0\x12\0\x18\0\x1E\0\xE5\x87\x8C\xE6\x99\xA8\xE6\xB8\x85\xE6\x99\xA8\xE6\x9C\x9D\xE6\x97\xA9\xE4\xB8\xAD\xE5\x8D\x88\xE4\xB8\x8B\xE6\x98\xBC\xE5\xA4\x9C\xE6\x99\x9A") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("午夜")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0,\x01\xE0\x01\xD0\x02\x0C\x03t\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x06\0\x0C\0\x12\0\x18\0\x1E\0\xE5\x87\x8C\xE6\x99\xA8\xE6\xB8\x85\xE6\x99\xA8\xE6\x9C\x9D\xE6\x97\xA9\xE4\xB8\xAD\xE5\x8D\x88\xE4\xB8\x8B\xE6\x98\xBC\xE5\xA4\x9C\xE6\x99\x9A") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("午夜")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0,\x01\xE0\x01\xD0\x02\x0C\x03t\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x06\0\x0C\0\x12\0\x18\0\x1E\0\xE5\x87\x8C\xE6\x99\xA8\xE6\xB8\x85\xE6\x99\xA8\xE6\x9C\x9D\xE6\x97\xA9\xE4\xB8\xAD\xE5\x8D\x88\xE4\xB8\x8B\xE6\x98\xBC\xE5\xA4\x9C\xE6\x99\x9A") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("午夜")),
+                        },
+                    },
+                };
+                static YUE: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0,\x01\xE0\x01\xD0\x02\x0C\x03t\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x06\0\x0C\0\x12\0\x18\0\x1E\0\xE5\x87\x8C\xE6\x99\xA8\xE6\xB8\x85\xE6\x99\xA8\xE6\x9C\x9D\xE6\x97\xA9\xE4\xB8\xAD\xE5\x8D\x88\xE4\xB8\x8B\xE6\x99\x9D\xE5\xA4\x9C\xE6\x99\x9A") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("午夜")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0,\x01\xE0\x01\xD0\x02\x0C\x03t\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x06\0\x0C\0\x12\0\x18\0\x1E\0\xE5\x87\x8C\xE6\x99\xA8\xE6\xB8\x85\xE6\x99\xA8\xE6\x9C\x9D\xE6\x97\xA9\xE4\xB8\xAD\xE5\x8D\x88\xE4\xB8\x8B\xE6\x99\x9D\xE5\xA4\x9C\xE6\x99\x9A") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("午夜")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0,\x01\xE0\x01\xD0\x02\x0C\x03t\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x06\0\x0C\0\x12\0\x18\0\x1E\0\xE5\x87\x8C\xE6\x99\xA8\xE6\xB8\x85\xE6\x99\xA8\xE6\x9C\x9D\xE6\x97\xA9\xE4\xB8\xAD\xE5\x8D\x88\xE4\xB8\x8B\xE6\x99\x9D\xE5\xA4\x9C\xE6\x99\x9A") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("午夜")),
+                        },
+                    },
+                };
+                static AR_LY: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0<\0\xB4\0h\x01\xD0\x02\x0C\x038\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x07\0\0\0\0\0\x11\0\x13\0\x1D\0\x1F\0)\0:\0\xD9\x81\xD9\x8A \xD8\xA7\xD9\x84\xD9\x85\xD8\xB3\xD8\xA7\xD8\xA1\xD9\x84\xD9\x81\xD8\xAC\xD8\xB1\xD9\x8B\xD8\xA7\xD8\xB5\xD8\xB8\xD9\x87\xD8\xB1\xD9\x8B\xD8\xA7\xD8\xA8\xD8\xB9\xD8\xAF \xD8\xA7\xD9\x84\xD8\xB8\xD9\x87\xD8\xB1\xD9\x85\xD8\xB3\xD8\xA7\xD8\xA1\xD9\x8B") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0<\0\xB4\0h\x01\xD0\x02\x0C\x038\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x07\0\0\0\0\0\x15\0\x1F\0)\x005\0?\0P\0\xD9\x85\xD9\x86\xD8\xAA\xD8\xB5\xD9\x81 \xD8\xA7\xD9\x84\xD9\x84\xD9\x8A\xD9\x84\xD9\x84\xD9\x8A\xD9\x84\xD8\xA7\xD9\x8B\xD9\x81\xD8\xAC\xD8\xB1\xD9\x8B\xD8\xA7\xD8\xB5\xD8\xA8\xD8\xA7\xD8\xAD\xD9\x8B\xD8\xA7\xD8\xB8\xD9\x87\xD8\xB1\xD9\x8B\xD8\xA7\xD8\xA8\xD8\xB9\xD8\xAF \xD8\xA7\xD9\x84\xD8\xB8\xD9\x87\xD8\xB1\xD9\x85\xD8\xB3\xD8\xA7\xD8\xA1\xD9\x8B") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0<\0\xB4\0h\x01\xD0\x02\x0C\x038\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x07\0\0\0\0\0\x11\0\x1B\0,\08\0B\0S\0\xD9\x81\xD9\x8A \xD8\xA7\xD9\x84\xD9\x85\xD8\xB3\xD8\xA7\xD8\xA1\xD9\x84\xD9\x8A\xD9\x84\xD8\xA7\xD9\x8B\xD9\x81\xD9\x8A \xD8\xA7\xD9\x84\xD8\xB5\xD8\xA8\xD8\xA7\xD8\xAD\xD8\xB5\xD8\xA8\xD8\xA7\xD8\xAD\xD9\x8B\xD8\xA7\xD8\xB8\xD9\x87\xD8\xB1\xD9\x8B\xD8\xA7\xD8\xA8\xD8\xB9\xD8\xAF \xD8\xA7\xD9\x84\xD8\xB8\xD9\x87\xD8\xB1\xD9\x85\xD8\xB3\xD8\xA7\xD8\xA1\xD9\x8B") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                    },
+                };
+                static AR: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0<\0\xB4\0h\x01\xD0\x02\x0C\x038\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x07\0\0\0\0\0\x11\0\x1B\0%\0'\x001\0B\0\xD9\x81\xD9\x8A \xD8\xA7\xD9\x84\xD9\x85\xD8\xB3\xD8\xA7\xD8\xA1\xD9\x84\xD9\x8A\xD9\x84\xD8\xA7\xD9\x8B\xD9\x81\xD8\xAC\xD8\xB1\xD9\x8B\xD8\xA7\xD8\xB5\xD8\xB8\xD9\x87\xD8\xB1\xD9\x8B\xD8\xA7\xD8\xA8\xD8\xB9\xD8\xAF \xD8\xA7\xD9\x84\xD8\xB8\xD9\x87\xD8\xB1\xD9\x85\xD8\xB3\xD8\xA7\xD8\xA1\xD9\x8B") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0<\0\xB4\0h\x01\xD0\x02\x0C\x038\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x07\0\0\0\0\0\x15\0\x1F\0)\x005\0?\0P\0\xD9\x85\xD9\x86\xD8\xAA\xD8\xB5\xD9\x81 \xD8\xA7\xD9\x84\xD9\x84\xD9\x8A\xD9\x84\xD9\x84\xD9\x8A\xD9\x84\xD8\xA7\xD9\x8B\xD9\x81\xD8\xAC\xD8\xB1\xD9\x8B\xD8\xA7\xD8\xB5\xD8\xA8\xD8\xA7\xD8\xAD\xD9\x8B\xD8\xA7\xD8\xB8\xD9\x87\xD8\xB1\xD9\x8B\xD8\xA7\xD8\xA8\xD8\xB9\xD8\xAF \xD8\xA7\xD9\x84\xD8\xB8\xD9\x87\xD8\xB1\xD9\x85\xD8\xB3\xD8\xA7\xD8\xA1\xD9\x8B") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0<\0\xB4\0h\x01\xD0\x02\x0C\x038\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x07\0\0\0\0\0\x11\0\x1B\0,\08\0B\0S\0\xD9\x81\xD9\x8A \xD8\xA7\xD9\x84\xD9\x85\xD8\xB3\xD8\xA7\xD8\xA1\xD9\x84\xD9\x8A\xD9\x84\xD8\xA7\xD9\x8B\xD9\x81\xD9\x8A \xD8\xA7\xD9\x84\xD8\xB5\xD8\xA8\xD8\xA7\xD8\xAD\xD8\xB5\xD8\xA8\xD8\xA7\xD8\xAD\xD9\x8B\xD8\xA7\xD8\xB8\xD9\x87\xD8\xB1\xD9\x8B\xD8\xA7\xD8\xA8\xD8\xB9\xD8\xAF \xD8\xA7\xD9\x84\xD8\xB8\xD9\x87\xD8\xB1\xD9\x85\xD8\xB3\xD8\xA7\xD8\xA1\xD9\x8B") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                    },
+                };
+                static MS: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0<\0\xD0\x02H\x03t\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x04\0\x08\0\x13\0\x19\0pagipagitengah haripetangmalam") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0<\0\xD0\x02H\x03t\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x04\0\x08\0\x13\0\x19\0pagipagitengah haripetangmalam") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0<\0\xD0\x02H\x03t\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x0C\0\x10\0\x1B\0!\0tengah malampagitengah haripetangmalam") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                    },
+                };
+                static FA: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0<\0\xF0\0\xD0\x02\x0C\x03t\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x0F\0\x1B\0!\0'\0-\0\xD9\x86\xDB\x8C\xD9\x85\xD9\x87\xE2\x80\x8C\xD8\xB4\xD8\xA8\xD8\xA8\xD8\xA7\xD9\x85\xD8\xAF\xD8\xA7\xD8\xAF\xD8\xB5\xD8\xA8\xD8\xAD\xD8\xB8\xD9\x87\xD8\xB1\xD8\xB9\xD8\xB5\xD8\xB1\xD8\xB4\xD8\xA8") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0<\0\xF0\0\xD0\x02\x0C\x03t\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x02\0\x04\0\x06\0\x08\0\n\0\xD9\x86\xD8\xA8\xD8\xB5\xD8\xB8\xD8\xB9\xD8\xB4") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0<\0\xF0\0\xD0\x02\x0C\x03t\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x0F\0\x1B\0!\x001\x007\0\xD9\x86\xDB\x8C\xD9\x85\xD9\x87\xE2\x80\x8C\xD8\xB4\xD8\xA8\xD8\xA8\xD8\xA7\xD9\x85\xD8\xAF\xD8\xA7\xD8\xAF\xD8\xB5\xD8\xA8\xD8\xAD\xD8\xA8\xD8\xB9\xD8\xAF\xD8\xA7\xD8\xB2\xD8\xB8\xD9\x87\xD8\xB1\xD8\xB9\xD8\xB5\xD8\xB1\xD8\xB4\xD8\xA8") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                    },
+                };
+                static FA_AF: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0<\0\xF0\0\xD0\x02\x0C\x03t\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x0F\0\x1B\0!\0'\09\0\xD9\x86\xDB\x8C\xD9\x85\xD9\x87\xE2\x80\x8C\xD8\xB4\xD8\xA8\xD8\xA8\xD8\xA7\xD9\x85\xD8\xAF\xD8\xA7\xD8\xAF\xD8\xB5\xD8\xA8\xD8\xAD\xD8\xB8\xD9\x87\xD8\xB1\xD8\xA8\xD8\xB9\xD8\xAF\xD8\xA7\xD8\xB2\xDA\x86\xD8\xA7\xD8\xB4\xD8\xAA\xD8\xB4\xD8\xA8") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0<\0\xF0\0\xD0\x02\x0C\x03t\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x02\0\x04\0\x06\0\x08\0\n\0\xD9\x86\xD8\xA8\xD8\xB5\xD8\xB8\xD8\xB9\xD8\xB4") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0<\0\xF0\0\xD0\x02\x0C\x03t\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x0F\0\x1B\0!\0'\09\0\xD9\x86\xDB\x8C\xD9\x85\xD9\x87\xE2\x80\x8C\xD8\xB4\xD8\xA8\xD8\xA8\xD8\xA7\xD9\x85\xD8\xAF\xD8\xA7\xD8\xAF\xD8\xB5\xD8\xA8\xD8\xAD\xD8\xB8\xD9\x87\xD8\xB1\xD8\xA8\xD8\xB9\xD8\xAF\xD8\xA7\xD8\xB2\xDA\x86\xD8\xA7\xD8\xB4\xD8\xAA\xD8\xB4\xD8\xA8") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                    },
+                };
+                static SI: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0<\0h\x01\xD0\x02H\x038\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x1F\x001\0:\0F\0O\0\xE0\xB6\xB8\xE0\xB7\x90\xE0\xB6\xAF\xE0\xB7\x92\xE0\xB6\xBA\xE0\xB6\xB8\xE0\xB6\xA7 \xE0\xB6\xB4\xE0\xB7\x83\xE0\xB7\x94\xE0\xB6\xB4\xE0\xB7\x8F\xE0\xB6\xB1\xE0\xB7\x8A\xE0\xB6\xAF\xE0\xB6\xBB\xE0\xB6\x8B\xE0\xB6\xAF\xE0\xB7\x9A\xE0\xB6\xAF\xE0\xB7\x80\xE0\xB6\xBD\xE0\xB7\x8A\xE0\xB7\x84\xE0\xB7\x80\xE0\xB7\x83\xE0\xB6\xBB\xE0\xB7\x91") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("මධ\u{dca}\u{200d}ය\u{dcf}හ\u{dca}නය")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("මැද\u{dd2}යම")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0<\0h\x01\xD0\x02H\x038\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x06\0\x0C\0\x0F\0\x12\0\x15\0\xE0\xB6\xB8\xE0\xB7\x90\xE0\xB6\xB4\xE0\xB7\x8F\xE0\xB6\x8B\xE0\xB6\xAF\xE0\xB7\x84\xE0\xB6\xBB\xE0\xB7\x91") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("ම")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("මැ")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0<\0h\x01\xD0\x02H\x038\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x1F\x001\0:\0F\0O\0\xE0\xB6\xB8\xE0\xB7\x90\xE0\xB6\xAF\xE0\xB7\x92\xE0\xB6\xBA\xE0\xB6\xB8\xE0\xB6\xA7 \xE0\xB6\xB4\xE0\xB7\x83\xE0\xB7\x94\xE0\xB6\xB4\xE0\xB7\x8F\xE0\xB6\xB1\xE0\xB7\x8A\xE0\xB6\xAF\xE0\xB6\xBB\xE0\xB6\x8B\xE0\xB6\xAF\xE0\xB7\x9A\xE0\xB6\xAF\xE0\xB7\x80\xE0\xB6\xBD\xE0\xB7\x8A\xE0\xB7\x84\xE0\xB7\x80\xE0\xB7\x83\xE0\xB6\xBB\xE0\xB7\x91") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("මධ\u{dca}\u{200d}ය\u{dcf}හ\u{dca}නය")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("මැද\u{dd2}යම")),
+                        },
+                    },
+                };
+                static ID: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0X\x02\x84\x038\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x04\0\t\0\r\0pagisiangsoremalam") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("tengah hari")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("tengah malam")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0X\x02\x84\x038\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x04\0\t\0\r\0pagisiangsoremalam") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("tengah hari")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("tengah malam")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0X\x02\x84\x038\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x04\0\t\0\r\0pagisiangsoremalam") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("tengah hari")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("tengah malam")),
+                        },
+                    },
+                };
+                static YO: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\n\0\xC3\x80\xC3\xA1r\xE1\xBB\x8D\xCC\x80\xE1\xBB\x8C\xCC\x80s\xC3\xA1n") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\n\0\xC3\x80\xC3\xA1r\xE1\xBB\x8D\xCC\x80\xE1\xBB\x8C\xCC\x80s\xC3\xA1n") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\n\0\xC3\x80\xC3\xA1r\xE1\xBB\x8D\xCC\x80\xE1\xBB\x8C\xCC\x80s\xC3\xA1n") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                    },
+                };
+                static YO_BJ: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\t\0\xC3\x80\xC3\xA1r\xC9\x94\xCC\x80\xC6\x86\xCC\x80s\xC3\xA1n") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\t\0\xC3\x80\xC3\xA1r\xC9\x94\xCC\x80\xC6\x86\xCC\x80s\xC3\xA1n") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\t\0\xC3\x80\xC3\xA1r\xC9\x94\xCC\x80\xC6\x86\xCC\x80s\xC3\xA1n") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                    },
+                };
+                static BRX: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\t\0\xE0\xA4\xAB\xE0\xA5\x81\xE0\xA4\x82\xE0\xA4\xAC\xE0\xA5\x87\xE0\xA4\xB2\xE0\xA4\xBE\xE0\xA4\xB8\xE0\xA5\x87") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\t\0\xE0\xA4\xAB\xE0\xA5\x81\xE0\xA4\x82\xE0\xA4\xAC\xE0\xA5\x87") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\t\0\xE0\xA4\xAB\xE0\xA5\x81\xE0\xA4\x82\xE0\xA4\xAC\xE0\xA5\x87\xE0\xA4\xB2\xE0\xA4\xBE\xE0\xA4\xB8\xE0\xA5\x87") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                    },
+                };
+                static MAI: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\t\0\xE0\xA4\xAD\xE0\xA5\x8B\xE0\xA4\xB0\xE0\xA4\xB8\xE0\xA4\xBE\xE0\xA4\x82\xE0\xA4\x9D") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\t\0\xE0\xA4\xAD\xE0\xA5\x8B\xE0\xA4\xB0\xE0\xA4\xB8\xE0\xA4\xBE\xE0\xA4\x82\xE0\xA4\x9D") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\t\0\xE0\xA4\xAD\xE0\xA5\x8B\xE0\xA4\xB0\xE0\xA4\xB8\xE0\xA4\xBE\xE0\xA4\x82\xE0\xA4\x9D") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                    },
+                };
+                static CHR: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\t\0\xE1\x8F\x8C\xE1\x8E\xBE\xE1\x8E\xB4\xE1\x8F\x92\xE1\x8E\xAF\xE1\x8F\xB1\xE1\x8E\xA2\xE1\x8F\x97\xE1\x8F\xA2") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("ᎢᎦ")),
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\t\0\xE1\x8F\x8C\xE1\x8E\xBE\xE1\x8E\xB4\xE1\x8F\x92\xE1\x8E\xAF\xE1\x8F\xB1\xE1\x8E\xA2\xE1\x8F\x97\xE1\x8F\xA2") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("Ꭲ")),
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\t\0\xE1\x8F\x8C\xE1\x8E\xBE\xE1\x8E\xB4\xE1\x8F\x92\xE1\x8E\xAF\xE1\x8F\xB1\xE1\x8E\xA2\xE1\x8F\x97\xE1\x8F\xA2") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("ᎢᎦ")),
+                            midnight: None,
+                        },
+                    },
+                };
+                static GD: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x01\0mf") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x01\0mf") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x01\0mf") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                    },
+                };
+                static AST: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x02\0AMPM") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x01\0ap") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\r\0de la ma\xC3\xB1anade la tarde") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                    },
+                };
+                static CEB: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x02\0AMPM") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x01\0ap") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x02\0AMPM") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                    },
+                };
+                static KS: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x02\0AMPM") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x01\0ap") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x0B\0\xD8\xA7\xDB\x92 \xD8\xA7\xDB\x8C\xD9\x85\xD9\xBE\xDB\x8C \xD8\xA7\xDB\x8C\xD9\x85") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                    },
+                };
+                static KOK: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x02\0AMPM") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x01\0ap") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x0C\0\xE0\xA4\xB8\xE0\xA4\x95\xE0\xA4\xBE\xE0\xA4\xB3\xE0\xA4\xB8\xE0\xA4\xBE\xE0\xA4\x82\xE0\xA4\x9C") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                    },
+                };
+                static TO: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x02\0AMPM") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x02\0AMPM") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\n\0hengihengiefiafi") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                    },
+                };
+                static KS_DEVA: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x02\0AMPM") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x02\0AMPM") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\r\0\xE0\xA4\xAF\xE0\xA5\x87 \xE0\xA4\x8F\xE0\xA4\xAE\xE0\xA4\xAA\xE0\xA5\x80 \xE0\xA4\x8F\xE0\xA4\xAE") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                    },
+                };
+                static BHO: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x02\0AMPM") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x02\0AMPM") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\t\0\xE0\xA4\xAD\xE0\xA5\x8B\xE0\xA4\xB0\xE0\xA4\xB8\xE0\xA4\xBE\xE0\xA4\x82\xE0\xA4\x9D") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                    },
+                };
+                static UND: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x02\0AMPM") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x02\0AMPM") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x02\0AMPM") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                    },
+                };
+                static BGC: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x02\0AMPM") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x02\0AMPM") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x0C\0\xE0\xA4\xA4\xE0\xA4\xA1\xE0\xA4\x95\xE0\xA5\x87\xE0\xA4\xB8\xE0\xA4\xBE\xE0\xA4\x82\xE0\xA4\x9D") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                    },
+                };
+                static RAJ: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x02\0AMPM") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x02\0AMPM") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x0F\0\xE0\xA4\xB8\xE0\xA4\xAC\xE0\xA5\x87\xE0\xA4\xB0\xE0\xA5\x87\xE0\xA4\xB8\xE0\xA4\xBE\xE0\xA4\x82\xE0\xA4\xAE") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                    },
+                };
+                static SAT: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x02\0AMPM") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x02\0AMPM") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x0F\0\xE1\xB1\xA5\xE1\xB1\xAE\xE1\xB1\x9B\xE1\xB1\x9F\xE1\xB1\x9C\xE1\xB1\xA7\xE1\xB1\xA4\xE1\xB1\xAB\xE1\xB1\x9F\xE1\xB1\xB9") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                    },
+                };
+                static SD_DEVA: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x02\0AMPM") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x02\0AMPM") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x16\0\xE0\xA4\xB8\xE0\xA5\x81\xE0\xA4\xAC\xE0\xA5\x81\xE0\xA4\xB9 \xE0\xA4\x9C\xE0\xA4\xBE\xE0\xA4\xB6\xE0\xA4\xBE\xE0\xA4\xAE \xE0\xA4\x9C\xE0\xA4\xBE") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                    },
+                };
+                static SA: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x02\0AMPM") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x02\0AMPM") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x1B\0\xE0\xA4\xAA\xE0\xA5\x82\xE0\xA4\xB0\xE0\xA5\x8D\xE0\xA4\xB5\xE0\xA4\xBE\xE0\xA4\xB9\xE0\xA5\x8D\xE0\xA4\xA8\xE0\xA4\x85\xE0\xA4\xAA\xE0\xA4\xB0\xE0\xA4\xBE\xE0\xA4\xB9\xE0\xA5\x8D\xE0\xA4\xA8") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                    },
+                };
+                static BE: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x02\0AMPM") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x02\0ampm") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x02\0AMPM") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                    },
+                };
+                static SC: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x02\0AMPM") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x02\0m.b.") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x02\0AMPM") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                    },
+                };
+                static OR: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x02\0AMPM") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x06\0\xE0\xAC\xAA\xE0\xAD\x82\xE0\xAC\x85") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x02\0AMPM") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                    },
+                };
+                static PCM: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x02\0FMFI") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x02\0FMFI") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x0E\0F\xE1\xBB\x8D m\xE1\xBB\x8D\xCC\x81ninF\xE1\xBB\x8D \xC3\xADvnin") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                    },
+                };
+                static SO: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x02\0GHGD") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x01\0hd") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x02\0GHGD") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                    },
+                };
+                static HA: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x02\0SFYM") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x02\0SFYM") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x06\0SafiyaYamma") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                    },
+                };
+                static KEA: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x02\0ampm") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x02\0ampm") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x02\0ampm") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                    },
+                };
+                static WO: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x03\0SubNgo") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x03\0SubNgo") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x03\0SubNgo") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                    },
+                };
+                static BR: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x04\0A.M.G.M.") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x02\0amgm") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x04\0A.M.G.M.") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                    },
+                };
+                static IG: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x04\0A.M.P.M.") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x04\0A.M.P.M.") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x0F\0N\xE2\x80\x99\xE1\xBB\xA5t\xE1\xBB\xA5t\xE1\xBB\xA5N\xE2\x80\x99abali") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                    },
+                };
+                static JV: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x04\0IsukWengi") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x04\0IsukWengi") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x04\0IsukWengi") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                    },
+                };
+                static QU: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x04\0a.m.p.m.") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x04\0a.m.p.m.") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x04\0a.m.p.m.") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                    },
+                };
+                static HSB: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x04\0dop.pop.") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x04\0dop.pop.") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\n\0dopo\xC5\x82dnjapopo\xC5\x82dnju") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                    },
+                };
+                static DSB: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x04\0dop.w\xC3\xB3tp.") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x04\0dop.w\xC3\xB3tp.") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\n\0dopo\xC5\x82dnjaw\xC3\xB3tpo\xC5\x82dnja") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                    },
+                };
+                static GA: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x04\0r.n.i.n.") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x04\0r.n.i.n.") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x04\0r.n.i.n.") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                    },
+                };
+                static EL_POLYTON: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x06\0\xCF\x80.\xCE\xBC.\xCE\xBC.\xCE\xBC.") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x04\0\xCF\x80\xCE\xBC\xCE\xBC\xCE\xBC") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x06\0\xCF\x80.\xCE\xBC.\xCE\xBC.\xCE\xBC.") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                    },
+                };
+                static PS: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x06\0\xD8\xBA.\xD9\x85.\xD8\xBA.\xD9\x88.") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x06\0\xD8\xBA.\xD9\x85.\xD8\xBA.\xD9\x88.") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x06\0\xD8\xBA.\xD9\x85.\xD8\xBA.\xD9\x88.") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                    },
+                };
+                static TK: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x07\0go.\xC3\xB6\xC5\x88go.so\xC5\x88") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x04\0\xC3\xB6\xC5\x88so\xC5\x88") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x10\0g\xC3\xBCnortadan \xC3\xB6\xC5\x88g\xC3\xBCnortadan so\xC5\x88") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                    },
+                };
+                static TI: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x08\0\xE1\x89\x85.\xE1\x89\x80.\xE1\x8B\xB5.\xE1\x89\x80.") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x08\0\xE1\x89\x85.\xE1\x89\x80.\xE1\x8B\xB5.\xE1\x89\x80.") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x08\0\xE1\x89\x85.\xE1\x89\x80.\xE1\x8B\xB5.\xE1\x89\x80.") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                    },
+                };
+                static FF_ADLM: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x08\0\xF0\x9E\xA4\x80\xF0\x9E\xA4\x8E\xF0\x9E\xA4\x87\xF0\x9E\xA4\x8E") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x04\0\xF0\x9E\xA4\xA2\xF0\x9E\xA4\xA9") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x08\0\xF0\x9E\xA4\x80\xF0\x9E\xA4\x8E\xF0\x9E\xA4\x87\xF0\x9E\xA4\x8E") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                    },
+                };
+                static DOI: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x0C\0\xE0\xA4\xB8\xE0\xA4\xB5\xE0\xA5\x87\xE0\xA4\xB0\xE0\xA4\xB8\xE2\x80\x99\xE0\xA4\x9E") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x0C\0\xE0\xA4\xB8\xE0\xA4\xB5\xE0\xA5\x87\xE0\xA4\xB0\xE0\xA4\xB8\xE2\x80\x99\xE0\xA4\x9E") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x0C\0\xE0\xA4\xB8\xE0\xA4\xB5\xE0\xA5\x87\xE0\xA4\xB0\xE0\xA4\xA6\xE0\xA4\xAA\xE0\xA5\x88\xE0\xA4\xB9\xE0\xA4\xB0 \xE0\xA4\xAC\xE0\xA4\xBE\xE0\xA4\xA6") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                    },
+                };
+                static MNI: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x0F\0\xE0\xA6\xA8\xE0\xA7\x81\xE0\xA6\xAE\xE0\xA6\xBE\xE0\xA6\x82PM") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x0F\0\xE0\xA6\xA8\xE0\xA7\x81\xE0\xA6\xAE\xE0\xA6\xBE\xE0\xA6\x82PM") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\n\0\xE0\xA6\x8F \xE0\xA6\x8F\xE0\xA6\xAE\xE0\xA6\xAA\xE0\xA6\xBF \xE0\xA6\x8F\xE0\xA6\xAE") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                    },
+                };
+                static SD: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x17\0\xD8\xB5\xD8\xA8\xD8\xAD\xD8\x8C\xE2\x80\xAF\xD9\x85\xD9\x86\xD8\xAC\xD9\x87\xD9\x86\xD8\xAF\xD8\xB4\xD8\xA7\xD9\x85\xD8\x8C\xE2\x80\xAF\xD9\x85\xD9\x86\xD8\xAC\xD9\x87\xD9\x86\xD8\xAF") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x17\0\xD8\xB5\xD8\xA8\xD8\xAD\xD8\x8C\xE2\x80\xAF\xD9\x85\xD9\x86\xD8\xAC\xD9\x87\xD9\x86\xD8\xAF\xD9\x85\xD9\x86\xD8\xAC\xD9\x87\xD9\x86\xD8\xAF\xD8\x8C\xE2\x80\xAF\xD8\xB4\xD8\xA7\xD9\x85") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x15\0\xD8\xB5\xD8\xA8\xD8\xAD\xD8\x8C \xD9\x85\xD9\x86\xD8\xAC\xD9\x87\xD9\x86\xD8\xAF\xD9\x85\xD9\x86\xD8\xAC\xD9\x87\xD9\x86\xD8\xAF\xD8\x8C \xD8\xB4\xD8\xA7\xD9\x85") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                    },
+                };
+                static AS: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x1B\0\xE0\xA6\xAA\xE0\xA7\x82\xE0\xA7\xB0\xE0\xA7\x8D\xE0\xA6\xAC\xE0\xA6\xBE\xE0\xA6\xB9\xE0\xA7\x8D\xE0\xA6\xA8\xE0\xA6\x85\xE0\xA6\xAA\xE0\xA7\xB0\xE0\xA6\xBE\xE0\xA6\xB9\xE0\xA7\x8D\xE0\xA6\xA8") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x1B\0\xE0\xA6\xAA\xE0\xA7\x82\xE0\xA7\xB0\xE0\xA7\x8D\xE0\xA6\xAC\xE0\xA6\xBE\xE0\xA6\xB9\xE0\xA7\x8D\xE0\xA6\xA8\xE0\xA6\x85\xE0\xA6\xAA\xE0\xA7\xB0\xE0\xA6\xBE\xE0\xA6\xB9\xE0\xA7\x8D\xE0\xA6\xA8") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x02\0\0\0\0\0\x1B\0\xE0\xA6\xAA\xE0\xA7\x82\xE0\xA7\xB0\xE0\xA7\x8D\xE0\xA6\xAC\xE0\xA6\xBE\xE0\xA6\xB9\xE0\xA7\x8D\xE0\xA6\xA8\xE0\xA6\x85\xE0\xA6\xAA\xE0\xA7\xB0\xE0\xA6\xBE\xE0\xA6\xB9\xE0\xA7\x8D\xE0\xA6\xA8") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                    },
+                };
+                static CY: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x03\0\0\0\0\0\x06\0\x10\0y borey prynhawnyr hwyr") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("canol dydd")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("canol nos")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x03\0\0\0\0\0\t\0\x16\0yn y boreyn y prynhawnmin nos") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("canol dydd")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("canol nos")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x03\0\0\0\0\0\x06\0\x10\0y borey prynhawnyr hwyr") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("canol dydd")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("canol nos")),
+                        },
+                    },
+                };
+                static KM: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0!\0<\0]\0\xE1\x9E\x93\xE1\x9F\x85\xE1\x9E\x96\xE1\x9F\x81\xE1\x9E\x9B\xE2\x80\x8B\xE1\x9E\x96\xE1\x9F\x92\xE1\x9E\x9A\xE1\x9E\xB9\xE1\x9E\x80\xE1\x9E\x93\xE1\x9F\x85\xE1\x9E\x96\xE1\x9F\x81\xE1\x9E\x9B\xE1\x9E\x9A\xE1\x9E\x9F\xE1\x9F\x80\xE1\x9E\x9B\xE1\x9E\x93\xE1\x9F\x85\xE1\x9E\x96\xE1\x9F\x81\xE1\x9E\x9B\xE2\x80\x8B\xE1\x9E\x9B\xE1\x9F\x92\xE1\x9E\x84\xE1\x9E\xB6\xE1\x9E\x85\xE1\x9E\x93\xE1\x9F\x85\xE1\x9E\x96\xE1\x9F\x81\xE1\x9E\x9B\xE2\x80\x8B\xE1\x9E\x99\xE1\x9E\x94\xE1\x9F\x8B") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("ថ\u{17d2}ងៃត\u{17d2}រង\u{17cb}")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("អធ\u{17d2}រាត\u{17d2}រ")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0!\0<\0]\0\xE1\x9E\x93\xE1\x9F\x85\xE1\x9E\x96\xE1\x9F\x81\xE1\x9E\x9B\xE2\x80\x8B\xE1\x9E\x96\xE1\x9F\x92\xE1\x9E\x9A\xE1\x9E\xB9\xE1\x9E\x80\xE1\x9E\x93\xE1\x9F\x85\xE1\x9E\x96\xE1\x9F\x81\xE1\x9E\x9B\xE1\x9E\x9A\xE1\x9E\x9F\xE1\x9F\x80\xE1\x9E\x9B\xE1\x9E\x93\xE1\x9F\x85\xE1\x9E\x96\xE1\x9F\x81\xE1\x9E\x9B\xE2\x80\x8B\xE1\x9E\x9B\xE1\x9F\x92\xE1\x9E\x84\xE1\x9E\xB6\xE1\x9E\x85\xE1\x9E\x93\xE1\x9F\x85\xE1\x9E\x96\xE1\x9F\x81\xE1\x9E\x9B\xE2\x80\x8B\xE1\x9E\x99\xE1\x9E\x94\xE1\x9F\x8B") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("ថ\u{17d2}ងៃត\u{17d2}រង\u{17cb}")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("អធ\u{17d2}រាត\u{17d2}រ")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0!\0<\0]\0\xE1\x9E\x93\xE1\x9F\x85\xE1\x9E\x96\xE1\x9F\x81\xE1\x9E\x9B\xE2\x80\x8B\xE1\x9E\x96\xE1\x9F\x92\xE1\x9E\x9A\xE1\x9E\xB9\xE1\x9E\x80\xE1\x9E\x93\xE1\x9F\x85\xE1\x9E\x96\xE1\x9F\x81\xE1\x9E\x9B\xE1\x9E\x9A\xE1\x9E\x9F\xE1\x9F\x80\xE1\x9E\x9B\xE1\x9E\x93\xE1\x9F\x85\xE1\x9E\x96\xE1\x9F\x81\xE1\x9E\x9B\xE2\x80\x8B\xE1\x9E\x9B\xE1\x9F\x92\xE1\x9E\x84\xE1\x9E\xB6\xE1\x9E\x85\xE1\x9E\x93\xE1\x9F\x85\xE1\x9E\x96\xE1\x9F\x81\xE1\x9E\x9B\xE2\x80\x8B\xE1\x9E\x99\xE1\x9E\x94\xE1\x9F\x8B") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("ថ\u{17d2}ងៃត\u{17d2}រង\u{17cb}")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("អធ\u{17d2}រាត\u{17d2}រ")),
+                        },
+                    },
+                };
+                static ES_CO: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02\xB0\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x03\0\0\0\0\0\r\0\x18\0de la ma\xC3\xB1anade la tardede la noche") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("m.")),
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02\xB0\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x03\0\0\0\0\0\r\0\x18\0de la ma\xC3\xB1anade la tardede la noche") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("del mediodía")),
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02\xB0\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x03\0\0\0\0\0\r\0\x18\0de la ma\xC3\xB1anade la tardede la noche") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("del mediodía")),
+                            midnight: None,
+                        },
+                    },
+                };
+                static MY: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02\xC0\x03t\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0F\0!\0*\0\xE1\x80\x94\xE1\x80\xB6\xE1\x80\x94\xE1\x80\x80\xE1\x80\xBA\xE1\x80\x94\xE1\x80\xB1\xE1\x80\xB7\xE1\x80\x9C\xE1\x80\x9A\xE1\x80\xBA\xE1\x80\x8A\xE1\x80\x94\xE1\x80\xB1\xE1\x80\x8A") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("မ\u{103d}န\u{103a}းတည\u{1037}\u{103a}")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("သန\u{103a}းခေါင\u{103a}ယ\u{1036}")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02\xC0\x03t\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0F\0!\0*\0\xE1\x80\x94\xE1\x80\xB6\xE1\x80\x94\xE1\x80\x80\xE1\x80\xBA\xE1\x80\x94\xE1\x80\xB1\xE1\x80\xB7\xE1\x80\x9C\xE1\x80\x9A\xE1\x80\xBA\xE1\x80\x8A\xE1\x80\x94\xE1\x80\xB1\xE1\x80\x8A") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("မ\u{103d}န\u{103a}းတည\u{1037}\u{103a}")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("သန\u{103a}းခေါင\u{103a}ယ\u{1036}")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xD0\x02\xC0\x03t\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0F\0!\0*\0\xE1\x80\x94\xE1\x80\xB6\xE1\x80\x94\xE1\x80\x80\xE1\x80\xBA\xE1\x80\x94\xE1\x80\xB1\xE1\x80\xB7\xE1\x80\x9C\xE1\x80\x9A\xE1\x80\xBA\xE1\x80\x8A\xE1\x80\x94\xE1\x80\xB1\xE1\x80\x8A") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("မ\u{103d}န\u{103a}းတည\u{1037}\u{103a}")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("သန\u{103a}းခေါင\u{103a}ယ\u{1036}")),
+                        },
+                    },
+                };
+                static MK: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xF0\0X\x02\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x08\0\x14\0(\08\0\xD0\xBD\xD0\xBE\xD1\x9C\xD0\xB5\xD0\xBD\xD0\xB0\xD1\x83\xD1\x82\xD1\x80\xD0\xBE\xD0\xBF\xD1\x80\xD0\xB5\xD1\x82\xD0\xBF\xD0\xBB\xD0\xB0\xD0\xB4\xD0\xBD\xD0\xB5\xD0\xBF\xD0\xBE\xD0\xBF\xD0\xBB\xD0\xB0\xD0\xB4\xD0\xBD\xD0\xB5\xD0\xBD\xD0\xB0\xD0\xB2\xD0\xB5\xD1\x87\xD0\xB5\xD1\x80") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("напладне")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("полноќ")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xF0\0X\x02\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x08\0\x14\0!\0*\0\xD0\xBD\xD0\xBE\xD1\x9C\xD0\xB5\xD0\xBD\xD0\xB0\xD1\x83\xD1\x82\xD1\x80\xD0\xBE\xD0\xBF\xD1\x80\xD0\xB5\xD1\x82\xD0\xBF\xD0\xBB.\xD0\xBF\xD0\xBE\xD0\xBF\xD0\xBB.\xD0\xBD\xD0\xB0\xD0\xB2\xD0\xB5\xD1\x87\xD0\xB5\xD1\x80") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("напл.")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("полн.")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xF0\0X\x02\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x08\0\x14\0(\08\0\xD0\xBD\xD0\xBE\xD1\x9C\xD0\xB5\xD0\xBD\xD0\xB0\xD1\x83\xD1\x82\xD1\x80\xD0\xBE\xD0\xBF\xD1\x80\xD0\xB5\xD1\x82\xD0\xBF\xD0\xBB\xD0\xB0\xD0\xB4\xD0\xBD\xD0\xB5\xD0\xBF\xD0\xBE\xD0\xBF\xD0\xBB\xD0\xB0\xD0\xB4\xD0\xBD\xD0\xB5\xD0\xBD\xD0\xB0\xD0\xB2\xD0\xB5\xD1\x87\xD0\xB5\xD1\x80") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("напладне")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("полноќ")),
+                        },
+                    },
+                };
+                static SQ: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xF0\0\x1C\x02\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x08\0\x14\0\x1F\0)\0e nat\xC3\xABse m\xC3\xABngjesite paraditese pasditese mbr\xC3\xABmjes") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("e mesditës")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("e mesnatës")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xF0\0\x1C\x02\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x08\0\x14\0\x1F\0)\0e nat\xC3\xABse m\xC3\xABngjesite paraditese pasditese mbr\xC3\xABmjes") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("e mesditës")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("e mesnatës")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xF0\0\x1C\x02\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x08\0\x14\0\x1F\0)\0e nat\xC3\xABse m\xC3\xABngjesite paraditese pasditese mbr\xC3\xABmjes") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("e mesditës")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("e mesnatës")),
+                        },
+                    },
+                };
+                static FR_CD: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xF0\0\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x04\0\t\0\x14\0nuitmatinapr\xC3\xA8s-midisoir") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("midi")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("minuit")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xF0\0\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x04\0\x08\0\r\0nuitmat.ap.m.soir") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("midi")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("min.")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xF0\0\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x08\0\x10\0\"\0du matindu matinde l\xE2\x80\x99apr\xC3\xA8s-mididu soir") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("midi")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("minuit")),
+                        },
+                    },
+                };
+                static FR: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xF0\0\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x04\0\t\0\x14\0nuitmatinapr\xC3\xA8s-midisoir") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("midi")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("minuit")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xF0\0\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x04\0\x08\0\r\0nuitmat.ap.m.soir") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("midi")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("minuit")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xF0\0\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x08\0\x10\0\"\0du matindu matinde l\xE2\x80\x99apr\xC3\xA8s-mididu soir") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("midi")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("minuit")),
+                        },
+                    },
+                };
+                static FR_SN: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xF0\0\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x04\0\x08\0\r\0nuitmat.ap.m.soir") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("midi")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("min.")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xF0\0\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x04\0\x08\0\r\0nuitmat.ap.m.soir") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("midi")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("min.")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xF0\0\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x08\0\x10\0\"\0du matindu matinde l\xE2\x80\x99apr\xC3\xA8s-mididu soir") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("midi")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("minuit")),
+                        },
+                    },
+                };
+                static FR_RE: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xF0\0\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x04\0\x08\0\r\0nuitmat.ap.m.soir") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("midi")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("min.")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xF0\0\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x04\0\x08\0\r\0nuitmat.ap.m.soir") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("midi")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("minuit")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xF0\0\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x08\0\x10\0\"\0du matindu matinde l\xE2\x80\x99apr\xC3\xA8s-mididu soir") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("midi")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("minuit")),
+                        },
+                    },
+                };
+                static FR_CA: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xF0\0\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x07\0\x0E\0\x19\0du mat.du mat.apr\xC3\xA8s-mididu soir") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("midi")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("minuit")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xF0\0\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x04\0\x08\0\x13\0mat.mat.apr\xC3\xA8s-midisoir") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("midi")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("minuit")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xF0\0\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x08\0\x10\0\"\0du matindu matinde l\xE2\x80\x99apr\xC3\xA8s-mididu soir") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("midi")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("minuit")),
+                        },
+                    },
+                };
+                static FR_CH: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xF0\0\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x07\0\x0E\0\x1A\0du mat.du mat.de l\xE2\x80\x99ap.m.du soir") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("midi")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("min.")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xF0\0\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x07\0\x0E\0\x1A\0du mat.du mat.de l\xE2\x80\x99ap.m.du soir") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("midi")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("min.")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xF0\0\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x08\0\x10\0\"\0du matindu matinde l\xE2\x80\x99apr\xC3\xA8s-mididu soir") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("midi")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("minuit")),
+                        },
+                    },
+                };
+                static UK: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xF0\0\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x08\0\x12\0\x18\0\xD0\xBD\xD0\xBE\xD1\x87\xD1\x96\xD1\x80\xD0\xB0\xD0\xBD\xD0\xBA\xD1\x83\xD0\xB4\xD0\xBD\xD1\x8F\xD0\xB2\xD0\xB5\xD1\x87\xD0\xBE\xD1\x80\xD0\xB0") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("пополудні")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("опівночі")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xF0\0\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x08\0\x12\0\x18\0\xD0\xBD\xD0\xBE\xD1\x87\xD1\x96\xD1\x80\xD0\xB0\xD0\xBD\xD0\xBA\xD1\x83\xD0\xB4\xD0\xBD\xD1\x8F\xD0\xB2\xD0\xB5\xD1\x87\xD0\xBE\xD1\x80\xD0\xB0") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("п")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("північ")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xF0\0\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x08\0\x12\0\x18\0\xD0\xBD\xD0\xBE\xD1\x87\xD1\x96\xD1\x80\xD0\xB0\xD0\xBD\xD0\xBA\xD1\x83\xD0\xB4\xD0\xBD\xD1\x8F\xD0\xB2\xD0\xB5\xD1\x87\xD0\xBE\xD1\x80\xD0\xB0") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("пополудні")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("опівночі")),
+                        },
+                    },
+                };
+                static AZ: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xF0\0h\x01\xD0\x02\xFC\x03t\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x05\0\n\0\x11\0\x19\0%\0gec\xC9\x99s\xC3\xBCbhs\xC9\x99h\xC9\x99rg\xC3\xBCnd\xC3\xBCzax\xC5\x9Fam\xC3\xBCst\xC3\xBCax\xC5\x9Fam") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("günorta")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("gecəyarı")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xF0\0h\x01\xD0\x02\xFC\x03t\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x05\0\n\0\x11\0\x19\0%\0gec\xC9\x99s\xC3\xBCbhs\xC9\x99h\xC9\x99rg\xC3\xBCnd\xC3\xBCzax\xC5\x9Fam\xC3\xBCst\xC3\xBCax\xC5\x9Fam") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("g")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("gecəyarı")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\xF0\0h\x01\xD0\x02\xFC\x03t\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x05\0\n\0\x11\0\x19\0%\0gec\xC9\x99s\xC3\xBCbhs\xC9\x99h\xC9\x99rg\xC3\xBCnd\xC3\xBCzax\xC5\x9Fam\xC3\xBCst\xC3\xBCax\xC5\x9Fam") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("günorta")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("gecəyarı")),
+                        },
+                    },
+                };
+                static ZU: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01X\x02\x0C\x03t\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x0B\0\x12\0\x17\0\x1F\0entathakusaekusenieminintambamaebusuku") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01X\x02\x0C\x03t\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x0B\0\x12\0\x17\0\x1F\0entathakusaekusenieminintambamaebusuku") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01X\x02\x0C\x03t\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x0B\0\x12\0\x17\0\x1F\0entathakusaekusenieminintambamaebusuku") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                    },
+                };
+                static NO: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01X\x02\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x04\0\t\0\x0E\0\x15\0nattmorg.form.etterm.kveld") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("midn.")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01X\x02\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x03\0\x06\0\t\0\x0C\0nt.mg.fm.em.kv.") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("mn.")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01X\x02\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\n\0\x16\0%\x006\0p\xC3\xA5 nattenp\xC3\xA5 morgenenp\xC3\xA5 formiddagenp\xC3\xA5 ettermiddagenp\xC3\xA5 kvelden") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("midnatt")),
+                        },
+                    },
+                };
+                static NN: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01X\x02\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x04\0\t\0\x0E\0\x15\0nattmorg.form.etterm.kveld") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("midn.")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01X\x02\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x03\0\x06\0\t\0\x0C\0nt.mg.fm.em.kv.") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("mn.")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01X\x02\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\t\0\x15\0$\x005\0p\xC3\xA5 nattap\xC3\xA5 morgonenp\xC3\xA5 formiddagenp\xC3\xA5 ettermiddagenp\xC3\xA5 kvelden") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("midnatt")),
+                        },
+                    },
+                };
+                static AM: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\n\0\x14\0\"\0\xE1\x88\x8C\xE1\x88\x8A\xE1\x89\xB51\xE1\x8C\xA5\xE1\x8B\x8B\xE1\x89\xB51\xE1\x8A\xA8\xE1\x88\xB0\xE1\x8B\x93\xE1\x89\xB5 7\xE1\x88\x9B\xE1\x89\xB31") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("ቀትር")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("እኩለ ሌሊት")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\n\0\x14\0!\0\xE1\x88\x8C\xE1\x88\x8A\xE1\x89\xB51\xE1\x8C\xA5\xE1\x8B\x8B\xE1\x89\xB51\xE1\x8A\xA8\xE1\x88\xB0\xE1\x8B\x93\xE1\x89\xB51\xE1\x88\x9B\xE1\x89\xB31") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("ቀ")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("እኩለ ሌሊት")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\n\0\x14\0,\0\xE1\x88\x8C\xE1\x88\x8A\xE1\x89\xB51\xE1\x8C\xA5\xE1\x8B\x8B\xE1\x89\xB51\xE1\x8A\xA8\xE1\x88\xB0\xE1\x8B\x93\xE1\x89\xB5 7 \xE1\x88\xB0\xE1\x8B\x93\xE1\x89\xB5\xE1\x88\x9B\xE1\x89\xB31") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("ቀትር")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("እኩለ ሌሊት")),
+                        },
+                    },
+                };
+                static IS: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\n\0\x14\0\x1E\0a\xC3\xB0 n\xC3\xB3ttua\xC3\xB0 morgnis\xC3\xAD\xC3\xB0degisa\xC3\xB0 kv\xC3\xB6ldi") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("hádegi")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("miðnætti")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x02\0\x06\0\t\0n.mrg.sd.kv.") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("h.")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("mn.")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\n\0\x14\0\x1E\0a\xC3\xB0 n\xC3\xB3ttua\xC3\xB0 morgnis\xC3\xAD\xC3\xB0degisa\xC3\xB0 kv\xC3\xB6ldi") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("hádegi")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("miðnætti")),
+                        },
+                    },
+                };
+                static LT: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x06\0\x0B\0\x13\0naktisrytaspopiet\xC4\x97vakaras") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("perpiet")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("vidurnaktis")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x06\0\x0B\0\x13\0naktisrytaspopiet\xC4\x97vakaras") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("perpiet")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("vidurnaktis")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x06\0\x0B\0\x13\0naktisrytaspopiet\xC4\x97vakaras") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("perpiet")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("vidurnaktis")),
+                        },
+                    },
+                };
+                static IT: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x08\0\x12\0\x1F\0di nottedi mattinadi pomeriggiodi sera") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("mezzogiorno")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("mezzanotte")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x08\0\x12\0\x1F\0di nottedi mattinadi pomeriggiodi sera") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("mezzogiorno")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("mezzanotte")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x08\0\x12\0 \0di nottedi mattinadel pomeriggiodi sera") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("mezzogiorno")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("mezzanotte")),
+                        },
+                    },
+                };
+                static NL: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0B\0\x18\0$\0\xE2\x80\x99s nachts\xE2\x80\x99s ochtends\xE2\x80\x99s middags\xE2\x80\x99s avonds") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("middernacht")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0B\0\x18\0$\0\xE2\x80\x99s nachts\xE2\x80\x99s ochtends\xE2\x80\x99s middags\xE2\x80\x99s avonds") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("middernacht")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0B\0\x18\0$\0\xE2\x80\x99s nachts\xE2\x80\x99s ochtends\xE2\x80\x99s middags\xE2\x80\x99s avonds") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("middernacht")),
+                        },
+                    },
+                };
+                static HY: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0C\0\x1E\0*\0\xD5\xA3\xD5\xAB\xD5\xB7\xD5\xA5\xD6\x80\xD5\xA8\xD5\xA1\xD5\xBC\xD5\xA1\xD5\xBE\xD5\xB8\xD5\xBF\xD5\xB5\xD5\xA1\xD5\xB6\xD6\x81\xD5\xA5\xD6\x80\xD5\xA5\xD5\xAF\xD5\xA8\xD5\xA5\xD6\x80\xD5\xA5\xD5\xAF\xD5\xB8\xD5\xB5\xD5\xA1\xD5\xB6") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("կեսօր")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("կեսգիշեր")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x06\0\x0C\0\x12\0\xD5\xA3\xD5\xB7\xD6\x80\xD5\xA1\xD5\xBC\xD5\xBE\xD6\x81\xD6\x80\xD5\xAF\xD5\xA5\xD6\x80\xD5\xAF") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("կօ․")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("կգ․")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x028\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0E\0 \0.\0\xD5\xA3\xD5\xAB\xD5\xB7\xD5\xA5\xD6\x80\xD5\xBE\xD5\xA1\xD5\xA1\xD5\xBC\xD5\xA1\xD5\xBE\xD5\xB8\xD5\xBF\xD5\xB5\xD5\xA1\xD5\xB6\xD6\x81\xD5\xA5\xD6\x80\xD5\xA5\xD5\xAF\xD5\xBE\xD5\xA1\xD5\xA5\xD6\x80\xD5\xA5\xD5\xAF\xD5\xB8\xD5\xB5\xD5\xA1\xD5\xB6") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("կեսօրին")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("կեսգիշերին")),
+                        },
+                    },
+                };
+                static EU: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x02H\x03t\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x08\0\x0F\0\x16\0\x1D\0$\0goizald.goizekoeguerd.arrats.iluntz.gaueko") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("gauerdia")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x02H\x03t\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x08\0\x0F\0\x16\0\x1D\0$\0goizald.goizekoeguerd.arrats.iluntz.gaueko") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("gauerd.")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x02H\x03t\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\n\0\x11\0\x1A\0&\0/\0goizaldekogoizekoeguerdikoarratsaldekoiluntzekogaueko") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("gauerdia")),
+                        },
+                    },
+                };
+                static GL: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x02\x0C\x03\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x0C\0\x15\0!\0)\0da madrugadada ma\xC3\xB1\xC3\xA1do mediod\xC3\xADada tardeda noite") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("da noite")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x02\x0C\x03\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x0C\0\x15\0!\0)\0da madrugadada ma\xC3\xB1\xC3\xA1do mediod\xC3\xADada tardeda noite") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("da noite")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x02\x0C\x03\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x0C\0\x15\0!\0)\0da madrugadada ma\xC3\xB1\xC3\xA1do mediod\xC3\xADada tardeda noite") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("da noite")),
+                        },
+                    },
+                };
+                static CA: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x02\x0C\x03t\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x08\0\r\0\x13\0\x18\0\x1E\0matinadamat\xC3\xADmigdiatardavesprenit") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("mitjanit")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x02\x0C\x03t\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x04\0\t\0\x0B\0\x10\0\x16\0mat.mat\xC3\xADmdtardavesprenit") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("mitjanit")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x02\x0C\x03t\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x08\0\r\0\x13\0\x18\0\x1E\0matinadamat\xC3\xADmigdiatardavesprenit") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("mitjanit")),
+                        },
+                    },
+                };
+                static ES_AR: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x02\xB0\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\t\0\x10\0\x15\0madrugadama\xC3\xB1anatardenoche") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("mediodía")),
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x02\xB0\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0F\0\x1C\0'\0de la madrugadade la ma\xC3\xB1anade la tardede la noche") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("del mediodía")),
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x02\xB0\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\t\0\x10\0\x15\0madrugadama\xC3\xB1anatardenoche") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("mediodía")),
+                            midnight: None,
+                        },
+                    },
+                };
+                static ES_DO: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x02\xB0\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0F\0\x1C\0'\0de la madrugadade la ma\xC3\xB1anade la tardede la noche") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("del mediodía")),
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x02\xB0\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x04\0\x0B\0\x10\0d\xC3\xADama\xC3\xB1anatardenoche") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("mediodía")),
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x02\xB0\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0F\0\x1C\0'\0de la madrugadade la ma\xC3\xB1anade la tardede la noche") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("del mediodía")),
+                            midnight: None,
+                        },
+                    },
+                };
+                static ES_MX: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x02\xB0\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0F\0\x1C\0'\0de la madrugadade la ma\xC3\xB1anade la tardede la noche") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("del mediodía")),
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x02\xB0\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0F\0\x16\0!\0de la madrugadama\xC3\xB1anade la tardede la noche") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("del mediodía")),
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x02\xB0\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0F\0\x1C\0'\0de la madrugadade la ma\xC3\xB1anade la tardede la noche") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("del mediodía")),
+                            midnight: None,
+                        },
+                    },
+                };
+                static ES: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x02\xB0\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0F\0\x1C\0'\0de la madrugadade la ma\xC3\xB1anade la tardede la noche") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("del mediodía")),
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x02\xB0\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0F\0\x1C\0'\0de la madrugadade la ma\xC3\xB1anade la tardede la noche") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("del mediodía")),
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x02\xB0\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0F\0\x1C\0'\0de la madrugadade la ma\xC3\xB1anade la tardede la noche") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("del mediodía")),
+                            midnight: None,
+                        },
+                    },
+                };
+                static ES_PA: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x02\xB0\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0F\0\x1C\0'\0de la madrugadade la ma\xC3\xB1anade la tardede la noche") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("mediodía")),
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x02\xB0\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0F\0\x1C\0'\0de la madrugadade la ma\xC3\xB1anade la tardede la noche") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("del mediodía")),
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x02\xB0\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0F\0\x1C\0'\0de la madrugadade la ma\xC3\xB1anade la tardede la noche") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("mediodía")),
+                            midnight: None,
+                        },
+                    },
+                };
+                static FIL: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x02\xC0\x038\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\n\0\x17\0\x1F\0&\0nang umagamadaling-arawtanghaling gabigabi") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("tanghaling-tapat")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("hatinggabi")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x02\xC0\x038\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x05\0\x12\0\x1A\0!\0umagamadaling-arawsa haponsa gabigabi") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("tanghaling-tapat")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("hatinggabi")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x02\xC0\x038\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\n\0\x17\0\x1F\0&\0nang umagamadaling-arawtanghaling gabing gabi") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("tanghaling-tapat")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("hatinggabi")),
+                        },
+                    },
+                };
+                static KGP: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x02t\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x08\0\x11\0\x1E\0kur\xC3\xA3 gekus\xC3\xA3g kir\xC3\xA3k\xC3\xA3nh k\xE1\xBB\xB9kuty k\xE1\xBB\xB9") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("kurã-kuju")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("kuty-si")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x02t\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x08\0\x11\0\x1E\0kur\xC3\xA3 gekus\xC3\xA3g kir\xC3\xA3k\xC3\xA3nh k\xE1\xBB\xB9kuty k\xE1\xBB\xB9") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("kurã-kuju")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("kuty-si")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x02t\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x08\0\x11\0\x1E\0kur\xC3\xA3 gekus\xC3\xA3g kir\xC3\xA3k\xC3\xA3nh k\xE1\xBB\xB9kuty k\xE1\xBB\xB9") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("kurã-kuju")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("kuty-si")),
+                        },
+                    },
+                };
+                static PT_PT: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x02t\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0C\0\x15\0\x1D\0da madrugadada manh\xC3\xA3da tardeda noite") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("meio-dia")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("meia-noite")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x02t\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\t\0\x0F\0\x14\0madrugadamanh\xC3\xA3tardenoite") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("meio-dia")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("meia-noite")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x02t\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0C\0\x15\0\x1D\0da madrugadada manh\xC3\xA3da tardeda noite") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("meio-dia")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("meia-noite")),
+                        },
+                    },
+                };
+                static PT: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x02t\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0C\0\x15\0\x1D\0da madrugadada manh\xC3\xA3da tardeda noite") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("meio-dia")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("meia-noite")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x02t\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0C\0\x15\0\x1D\0da madrugadada manh\xC3\xA3da tardeda noite") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("meio-dia")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("meia-noite")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x02t\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0C\0\x15\0\x1D\0da madrugadada manh\xC3\xA3da tardeda noite") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("meio-dia")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("meia-noite")),
+                        },
+                    },
+                };
+                static YRL: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x02t\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x11\0\x1E\0+\0pitunaet\xC3\xA9 ram\xE1\xBA\xBDku\xC3\xAAma ram\xE1\xBA\xBDkaruka ram\xE1\xBA\xBDpituna ram\xE1\xBA\xBD") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("iandé-ara-pyturepé")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("pituna pyterupé")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x02t\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x11\0\x1E\0+\0pitunaet\xC3\xA9 ram\xE1\xBA\xBDku\xC3\xAAma ram\xE1\xBA\xBDkaruka ram\xE1\xBA\xBDpituna ram\xE1\xBA\xBD") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("iandé-ara-pyturepé")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("pituna pyterupé")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0h\x01\xD0\x02t\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x11\0\x1E\0+\0pitunaet\xC3\xA9 ram\xE1\xBA\xBDku\xC3\xAAma ram\xE1\xBA\xBDkaruka ram\xE1\xBA\xBDpituna ram\xE1\xBA\xBD") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("iandé-ara-pyturepé")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("pituna pyterupé")),
+                        },
+                    },
+                };
+                static TA: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xB4\0,\x01\xD0\x02H\x03\xC0\x038\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x07\0\0\0\0\0\x15\0!\x003\0K\0W\0s\0\xE0\xAE\x85\xE0\xAE\xA4\xE0\xAE\xBF\xE0\xAE\x95\xE0\xAE\xBE\xE0\xAE\xB2\xE0\xAF\x88\xE0\xAE\x95\xE0\xAE\xBE\xE0\xAE\xB2\xE0\xAF\x88\xE0\xAE\xAE\xE0\xAE\xA4\xE0\xAE\xBF\xE0\xAE\xAF\xE0\xAE\xAE\xE0\xAF\x8D\xE0\xAE\xAA\xE0\xAE\xBF\xE0\xAE\xB1\xE0\xAF\x8D\xE0\xAE\xAA\xE0\xAE\x95\xE0\xAE\xB2\xE0\xAF\x8D\xE0\xAE\xAE\xE0\xAE\xBE\xE0\xAE\xB2\xE0\xAF\x88\xE0\xAE\x85\xE0\xAE\xA8\xE0\xAF\x8D\xE0\xAE\xA4\xE0\xAE\xBF \xE0\xAE\xAE\xE0\xAE\xBE\xE0\xAE\xB2\xE0\xAF\x88\xE0\xAE\x87\xE0\xAE\xB0\xE0\xAE\xB5\xE0\xAF\x81") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("நண\u{bcd}பகல\u{bcd}")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("நள\u{bcd}ளிரவு")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xB4\0,\x01\xD0\x02H\x03\xC0\x038\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x07\0\0\0\0\0\n\0\x11\0\x1B\0(\0/\0F\0\xE0\xAE\x85\xE0\xAE\xA4\xE0\xAE\xBF.\xE0\xAE\x95\xE0\xAE\xBE.\xE0\xAE\xAE\xE0\xAE\xA4\xE0\xAE\xBF.\xE0\xAE\xAA\xE0\xAE\xBF\xE0\xAE\xB1\xE0\xAF\x8D.\xE0\xAE\xAE\xE0\xAE\xBE.\xE0\xAE\x85\xE0\xAE\xA8\xE0\xAF\x8D\xE0\xAE\xA4\xE0\xAE\xBF \xE0\xAE\xAE\xE0\xAE\xBE.\xE0\xAE\x87\xE0\xAE\xB0.") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("நண\u{bcd}.")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("நள\u{bcd}.")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xB4\0,\x01\xD0\x02H\x03\xC0\x038\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x07\0\0\0\0\0\x15\0!\x003\0K\0W\0s\0\xE0\xAE\x85\xE0\xAE\xA4\xE0\xAE\xBF\xE0\xAE\x95\xE0\xAE\xBE\xE0\xAE\xB2\xE0\xAF\x88\xE0\xAE\x95\xE0\xAE\xBE\xE0\xAE\xB2\xE0\xAF\x88\xE0\xAE\xAE\xE0\xAE\xA4\xE0\xAE\xBF\xE0\xAE\xAF\xE0\xAE\xAE\xE0\xAF\x8D\xE0\xAE\xAA\xE0\xAE\xBF\xE0\xAE\xB1\xE0\xAF\x8D\xE0\xAE\xAA\xE0\xAE\x95\xE0\xAE\xB2\xE0\xAF\x8D\xE0\xAE\xAE\xE0\xAE\xBE\xE0\xAE\xB2\xE0\xAF\x88\xE0\xAE\x85\xE0\xAE\xA8\xE0\xAF\x8D\xE0\xAE\xA4\xE0\xAE\xBF \xE0\xAE\xAE\xE0\xAE\xBE\xE0\xAE\xB2\xE0\xAF\x88\xE0\xAE\x87\xE0\xAE\xB0\xE0\xAE\xB5\xE0\xAF\x81") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("நண\u{bcd}பகல\u{bcd}")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("நள\u{bcd}ளிரவு")),
+                        },
+                    },
+                };
+                static KO: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xB4\0h\x01\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\x0C\0\x12\0\x18\0\xEC\x83\x88\xEB\xB2\xBD\xEC\x98\xA4\xEC\xA0\x84\xEC\x98\xA4\xED\x9B\x84\xEC\xA0\x80\xEB\x85\x81\xEB\xB0\xA4") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("정오")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("자정")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xB4\0h\x01\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\x0C\0\x12\0\x18\0\xEC\x83\x88\xEB\xB2\xBD\xEC\x98\xA4\xEC\xA0\x84\xEC\x98\xA4\xED\x9B\x84\xEC\xA0\x80\xEB\x85\x81\xEB\xB0\xA4") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("정오")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("자정")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xB4\0h\x01\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\x0C\0\x12\0\x18\0\xEC\x83\x88\xEB\xB2\xBD\xEC\x98\xA4\xEC\xA0\x84\xEC\x98\xA4\xED\x9B\x84\xEC\xA0\x80\xEB\x85\x81\xEB\xB0\xA4") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("정오")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("자정")),
+                        },
+                    },
+                };
+                static ML: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xB4\0h\x01\xD0\x02H\x03\x84\x038\x04t\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x07\0\0\0\0\0\x18\0*\0H\0l\0\x8A\0\x9C\0\xE0\xB4\xAA\xE0\xB5\x81\xE0\xB4\xB2\xE0\xB5\xBC\xE0\xB4\x9A\xE0\xB5\x8D\xE0\xB4\x9A\xE0\xB5\x86\xE0\xB4\xB0\xE0\xB4\xBE\xE0\xB4\xB5\xE0\xB4\xBF\xE0\xB4\xB2\xE0\xB5\x86\xE0\xB4\x89\xE0\xB4\x9A\xE0\xB5\x8D\xE0\xB4\x9A\xE0\xB4\xAF\xE0\xB5\x8D\xE0\xB4\x95\xE0\xB5\x8D\xE0\xB4\x95\xE0\xB5\x8D\xE0\xB4\x89\xE0\xB4\x9A\xE0\xB5\x8D\xE0\xB4\x9A\xE0\xB4\xA4\xE0\xB4\xBF\xE0\xB4\xB0\xE0\xB4\xBF\xE0\xB4\x9E\xE0\xB5\x8D\xE0\xB4\x9E\xE0\xB5\x8D\xE0\xB4\xB5\xE0\xB5\x88\xE0\xB4\x95\xE0\xB5\x81\xE0\xB4\xA8\xE0\xB5\x8D\xE0\xB4\xA8\xE0\xB5\x87\xE0\xB4\xB0\xE0\xB4\x82\xE0\xB4\xB8\xE0\xB4\xA8\xE0\xB5\x8D\xE0\xB4\xA7\xE0\xB5\x8D\xE0\xB4\xAF\xE0\xB4\xB0\xE0\xB4\xBE\xE0\xB4\xA4\xE0\xB5\x8D\xE0\xB4\xB0\xE0\xB4\xBF") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("ഉച\u{d4d}ച")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("അർദ\u{d4d}ധര\u{d3e}ത\u{d4d}രി")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xB4\0h\x01\xD0\x02H\x03\x84\x038\x04t\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x07\0\0\0\0\0\x18\0*\0H\0l\0\x8A\0\x9C\0\xE0\xB4\xAA\xE0\xB5\x81\xE0\xB4\xB2\xE0\xB5\xBC\xE0\xB4\x9A\xE0\xB5\x8D\xE0\xB4\x9A\xE0\xB5\x86\xE0\xB4\xB0\xE0\xB4\xBE\xE0\xB4\xB5\xE0\xB4\xBF\xE0\xB4\xB2\xE0\xB5\x86\xE0\xB4\x89\xE0\xB4\x9A\xE0\xB5\x8D\xE0\xB4\x9A\xE0\xB4\xAF\xE0\xB5\x8D\xE0\xB4\x95\xE0\xB5\x8D\xE0\xB4\x95\xE0\xB5\x8D\xE0\xB4\x89\xE0\xB4\x9A\xE0\xB5\x8D\xE0\xB4\x9A\xE0\xB4\xA4\xE0\xB4\xBF\xE0\xB4\xB0\xE0\xB4\xBF\xE0\xB4\x9E\xE0\xB5\x8D\xE0\xB4\x9E\xE0\xB5\x8D\xE0\xB4\xB5\xE0\xB5\x88\xE0\xB4\x95\xE0\xB5\x81\xE0\xB4\xA8\xE0\xB5\x8D\xE0\xB4\xA8\xE0\xB5\x87\xE0\xB4\xB0\xE0\xB4\x82\xE0\xB4\xB8\xE0\xB4\xA8\xE0\xB5\x8D\xE0\xB4\xA7\xE0\xB5\x8D\xE0\xB4\xAF\xE0\xB4\xB0\xE0\xB4\xBE\xE0\xB4\xA4\xE0\xB5\x8D\xE0\xB4\xB0\xE0\xB4\xBF") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("ഉച\u{d4d}ച")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("അ")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xB4\0h\x01\xD0\x02H\x03\x84\x038\x04t\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x07\0\0\0\0\0\x18\0*\0H\0l\0\x8A\0\x9C\0\xE0\xB4\xAA\xE0\xB5\x81\xE0\xB4\xB2\xE0\xB5\xBC\xE0\xB4\x9A\xE0\xB5\x8D\xE0\xB4\x9A\xE0\xB5\x86\xE0\xB4\xB0\xE0\xB4\xBE\xE0\xB4\xB5\xE0\xB4\xBF\xE0\xB4\xB2\xE0\xB5\x86\xE0\xB4\x89\xE0\xB4\x9A\xE0\xB5\x8D\xE0\xB4\x9A\xE0\xB4\xAF\xE0\xB5\x8D\xE0\xB4\x95\xE0\xB5\x8D\xE0\xB4\x95\xE0\xB5\x8D\xE0\xB4\x89\xE0\xB4\x9A\xE0\xB5\x8D\xE0\xB4\x9A\xE0\xB4\xA4\xE0\xB4\xBF\xE0\xB4\xB0\xE0\xB4\xBF\xE0\xB4\x9E\xE0\xB5\x8D\xE0\xB4\x9E\xE0\xB5\x8D\xE0\xB4\xB5\xE0\xB5\x88\xE0\xB4\x95\xE0\xB5\x81\xE0\xB4\xA8\xE0\xB5\x8D\xE0\xB4\xA8\xE0\xB5\x87\xE0\xB4\xB0\xE0\xB4\x82\xE0\xB4\xB8\xE0\xB4\xA8\xE0\xB5\x8D\xE0\xB4\xA7\xE0\xB5\x8D\xE0\xB4\xAF\xE0\xB4\xB0\xE0\xB4\xBE\xE0\xB4\xA4\xE0\xB5\x8D\xE0\xB4\xB0\xE0\xB4\xBF") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("ഉച\u{d4d}ച")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("അർദ\u{d4d}ധര\u{d3e}ത\u{d4d}രി")),
+                        },
+                    },
+                };
+                static HE: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xB4\0h\x01\xD0\x02\xC0\x038\x04(\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x13\0\x1B\0'\0<\0B\0\xD7\x9C\xD7\xA4\xD7\xA0\xD7\x95\xD7\xAA \xD7\x91\xD7\x95\xD7\xA7\xD7\xA8\xD7\x91\xD7\x95\xD7\xA7\xD7\xA8\xD7\xA6\xD7\x94\xD7\xA8\xD7\x99\xD7\x99\xD7\x9D\xD7\x90\xD7\x97\xD7\xA8 \xD7\x94\xD7\xA6\xD7\x94\xD7\xA8\xD7\x99\xD7\x99\xD7\x9D\xD7\xA2\xD7\xA8\xD7\x91\xD7\x9C\xD7\x99\xD7\x9C\xD7\x94") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("חצות")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xB4\0h\x01\xD0\x02\xC0\x038\x04(\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x13\0\x1D\0+\x005\0=\0\xD7\x9C\xD7\xA4\xD7\xA0\xD7\x95\xD7\xAA \xD7\x91\xD7\x95\xD7\xA7\xD7\xA8\xD7\x91\xD7\x91\xD7\x95\xD7\xA7\xD7\xA8\xD7\x91\xD7\xA6\xD7\x94\xD7\xA8\xD7\x99\xD7\x99\xD7\x9D\xD7\x90\xD7\x97\xD7\x94\xD7\xB4\xD7\xA6\xD7\x91\xD7\xA2\xD7\xA8\xD7\x91\xD7\x91\xD7\x9C\xD7\x99\xD7\x9C\xD7\x94") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("חצות")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xB4\0h\x01\xD0\x02\xC0\x038\x04(\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x13\0\x1D\0+\0@\0H\0\xD7\x9C\xD7\xA4\xD7\xA0\xD7\x95\xD7\xAA \xD7\x91\xD7\x95\xD7\xA7\xD7\xA8\xD7\x91\xD7\x91\xD7\x95\xD7\xA7\xD7\xA8\xD7\x91\xD7\xA6\xD7\x94\xD7\xA8\xD7\x99\xD7\x99\xD7\x9D\xD7\x90\xD7\x97\xD7\xA8 \xD7\x94\xD7\xA6\xD7\x94\xD7\xA8\xD7\x99\xD7\x99\xD7\x9D\xD7\x91\xD7\xA2\xD7\xA8\xD7\x91\xD7\x91\xD7\x9C\xD7\x99\xD7\x9C\xD7\x94") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("חצות")),
+                        },
+                    },
+                };
+                static CS: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0\x1C\x02\xD0\x028\x04(\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x02\0\x06\0\n\0\x0F\0r.dop.odp.ve\xC4\x8D.v n.") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("pol.")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("půln.")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0\x1C\x02\xD0\x028\x04(\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x02\0\x04\0\x06\0\x08\0r.d.o.v.n.") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("pol.")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("půl.")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0\x1C\x02\xD0\x028\x04(\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x05\0\x0E\0\x17\0\x1D\0r\xC3\xA1nodopoledneodpoledneve\xC4\x8Derv noci") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("poledne")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("půlnoc")),
+                        },
+                    },
+                };
+                static SK: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0\x1C\x02\xD0\x028\x04(\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x05\0\x0B\0\x11\0\x17\0r\xC3\xA1nodopol.popol.ve\xC4\x8Derv noci") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("napol.")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("o poln.")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0\x1C\x02\xD0\x028\x04(\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x05\0\t\0\r\0\x12\0r\xC3\xA1nodop.pop.ve\xC4\x8D.v n.") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("nap.")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("o poln.")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0\x1C\x02\xD0\x028\x04(\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x05\0\x0F\0\x19\0\x1F\0r\xC3\xA1nodopoludniapopoludn\xC3\xADve\xC4\x8Derv noci") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("napoludnie")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("o polnoci")),
+                        },
+                    },
+                };
+                static BG: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0\x94\x02H\x038\x04(\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x10\0\x1D\0-\0;\0\xD1\x81\xD1\x83\xD1\x82\xD1\x80\xD0\xB8\xD0\xBD\xD1\x82\xD0\xB0\xD0\xBD\xD0\xB0 \xD0\xBE\xD0\xB1\xD1\x8F\xD0\xB4\xD1\x81\xD0\xBB\xD0\xB5\xD0\xB4\xD0\xBE\xD0\xB1\xD0\xB5\xD0\xB4\xD0\xB2\xD0\xB5\xD1\x87\xD0\xB5\xD1\x80\xD1\x82\xD0\xB0\xD0\xBF\xD1\x80\xD0\xB5\xD0\xB7 \xD0\xBD\xD0\xBE\xD1\x89\xD1\x82\xD0\xB0") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("полунощ")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0\x94\x02H\x038\x04(\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x10\0\x1D\0-\0;\0\xD1\x81\xD1\x83\xD1\x82\xD1\x80\xD0\xB8\xD0\xBD\xD1\x82\xD0\xB0\xD0\xBD\xD0\xB0 \xD0\xBE\xD0\xB1\xD1\x8F\xD0\xB4\xD1\x81\xD0\xBB\xD0\xB5\xD0\xB4\xD0\xBE\xD0\xB1\xD0\xB5\xD0\xB4\xD0\xB2\xD0\xB5\xD1\x87\xD0\xB5\xD1\x80\xD1\x82\xD0\xB0\xD0\xBF\xD1\x80\xD0\xB5\xD0\xB7 \xD0\xBD\xD0\xBE\xD1\x89\xD1\x82\xD0\xB0") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("полунощ")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0\x94\x02H\x038\x04(\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x10\0\x1D\0-\0;\0\xD1\x81\xD1\x83\xD1\x82\xD1\x80\xD0\xB8\xD0\xBD\xD1\x82\xD0\xB0\xD0\xBD\xD0\xB0 \xD0\xBE\xD0\xB1\xD1\x8F\xD0\xB4\xD1\x81\xD0\xBB\xD0\xB5\xD0\xB4\xD0\xBE\xD0\xB1\xD0\xB5\xD0\xB4\xD0\xB2\xD0\xB5\xD1\x87\xD0\xB5\xD1\x80\xD1\x82\xD0\xB0\xD0\xBF\xD1\x80\xD0\xB5\xD0\xB7 \xD0\xBD\xD0\xBE\xD1\x89\xD1\x82\xD0\xB0") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("полунощ")),
+                        },
+                    },
+                };
+                static SW: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0\xA4\x01\xD0\x02\xC0\x03t\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x08\0\x0F\0\x15\0\x1A\0alfajiriasubuhimchanajioniusiku") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("adhuhuri")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("saa sita za usiku")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0\xA4\x01\xD0\x02\xC0\x03t\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x08\0\x0F\0\x15\0\x1A\0alfajiriasubuhimchanajioniusiku") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("mchana")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("usiku")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0\xA4\x01\xD0\x02\xC0\x03t\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x08\0\x0F\0\x15\0\x1A\0alfajiriasubuhimchanajioniusiku") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("saa sita za mchana")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("saa sita za usiku")),
+                        },
+                    },
+                };
+                static RU: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0\xD0\x028\x04(\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x08\0\x0E\0\x1A\0\xD1\x83\xD1\x82\xD1\x80\xD0\xB0\xD0\xB4\xD0\xBD\xD1\x8F\xD0\xB2\xD0\xB5\xD1\x87\xD0\xB5\xD1\x80\xD0\xB0\xD0\xBD\xD0\xBE\xD1\x87\xD0\xB8") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("полд.")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("полн.")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0\xD0\x028\x04(\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x08\0\x0E\0\x15\0\xD1\x83\xD1\x82\xD1\x80\xD0\xB0\xD0\xB4\xD0\xBD\xD1\x8F\xD0\xB2\xD0\xB5\xD1\x87.\xD0\xBD\xD0\xBE\xD1\x87\xD0\xB8") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("полд.")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("полн.")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0\xD0\x028\x04(\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x08\0\x0E\0\x1A\0\xD1\x83\xD1\x82\xD1\x80\xD0\xB0\xD0\xB4\xD0\xBD\xD1\x8F\xD0\xB2\xD0\xB5\xD1\x87\xD0\xB5\xD1\x80\xD0\xB0\xD0\xBD\xD0\xBE\xD1\x87\xD0\xB8") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("полдень")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("полночь")),
+                        },
+                    },
+                };
+                static VI: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x05\0\x0C\0\x11\0s\xC3\xA1ngchi\xE1\xBB\x81ut\xE1\xBB\x91i\xC4\x91\xC3\xAAm") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("TR")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("nửa đêm")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x05\0\x0C\0\x11\0s\xC3\xA1ngchi\xE1\xBB\x81ut\xE1\xBB\x91i\xC4\x91\xC3\xAAm") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("tr")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("nửa đêm")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x05\0\x0C\0\x11\0s\xC3\xA1ngchi\xE1\xBB\x81ut\xE1\xBB\x91i\xC4\x91\xC3\xAAm") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("TR")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("nửa đêm")),
+                        },
+                    },
+                };
+                static HR: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x06\0\r\0\x15\0ujutropopodnenave\xC4\x8Derno\xC4\x87u") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("podne")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("ponoć")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x06\0\r\0\x15\0ujutropopodnenave\xC4\x8Derno\xC4\x87u") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("podne")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("ponoć")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x06\0\x13\0\x1B\0ujutroposlije podnenave\xC4\x8Derno\xC4\x87u") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("podne")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("ponoć")),
+                        },
+                    },
+                };
+                static BS: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x06\0\x12\0\x1A\0ujutroposlijepodnenave\xC4\x8Derpo no\xC4\x87i") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("podne")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("ponoć")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x06\0\x12\0\x1A\0ujutroposlijepodnenave\xC4\x8Derpo no\xC4\x87i") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("podne")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("ponoć")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x06\0\x12\0\x1A\0ujutroposlijepodnenave\xC4\x8Derpo no\xC4\x87i") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("podne")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("ponoć")),
+                        },
+                    },
+                };
+                static BS_CYRL: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0C\0\x19\0#\0\xD1\x83\xD1\x98\xD1\x83\xD1\x82\xD1\x80\xD1\x83\xD1\x83 \xD0\xBF\xD0\xBE\xD0\xB4\xD0\xBD\xD0\xB5\xD1\x83\xD0\xB2\xD0\xB5\xD1\x87\xD0\xB5\xD0\xBD\xD0\xBE\xD1\x9B\xD1\x83") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("подне")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("поноћ")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0C\0\x19\0#\0\xD1\x83\xD1\x98\xD1\x83\xD1\x82\xD1\x80\xD1\x83\xD1\x83 \xD0\xBF\xD0\xBE\xD0\xB4\xD0\xBD\xD0\xB5\xD1\x83\xD0\xB2\xD0\xB5\xD1\x87\xD0\xB5\xD0\xBD\xD0\xBE\xD1\x9B\xD1\x83") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("подне")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("поноћ")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0C\0\x19\0#\0\xD1\x83\xD1\x98\xD1\x83\xD1\x82\xD1\x80\xD1\x83\xD1\x83 \xD0\xBF\xD0\xBE\xD0\xB4\xD0\xBD\xD0\xB5\xD1\x83\xD0\xB2\xD0\xB5\xD1\x87\xD0\xB5\xD0\xBD\xD0\xBE\xD1\x9B\xD1\x83") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("подне")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("поноћ")),
+                        },
+                    },
+                };
+                static UR: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0\xD0\x02\xC0\x038\x04\xB0\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\x10\0\x1B\0!\0\xD8\xB5\xD8\xA8\xD8\xAD\xD8\xAF\xD9\x88\xD9\xBE\xDB\x81\xD8\xB1\xD8\xB3\xDB\x81 \xD9\xBE\xDB\x81\xD8\xB1\xD8\xB4\xD8\xA7\xD9\x85\xD8\xB1\xD8\xA7\xD8\xAA") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("آدھی رات")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0\xD0\x02\xC0\x038\x04\xB0\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\x10\0\x1B\0!\0\xD8\xB5\xD8\xA8\xD8\xAD\xD8\xAF\xD9\x88\xD9\xBE\xDB\x81\xD8\xB1\xD8\xB3\xDB\x81 \xD9\xBE\xDB\x81\xD8\xB1\xD8\xB4\xD8\xA7\xD9\x85\xD8\xB1\xD8\xA7\xD8\xAA") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("آدھی رات")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0\xD0\x02\xC0\x038\x04\xB0\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\r\0\x1E\0)\x006\0\xD8\xB5\xD8\xA8\xD8\xAD \xD9\x85\xDB\x8C\xDA\xBA\xD8\xAF\xD9\x88\xD9\xBE\xDB\x81\xD8\xB1 \xD9\x85\xDB\x8C\xDA\xBA\xD8\xB3\xDB\x81 \xD9\xBE\xDB\x81\xD8\xB1\xD8\xB4\xD8\xA7\xD9\x85 \xD9\x85\xDB\x8C\xDA\xBA\xD8\xB1\xD8\xA7\xD8\xAA \xD9\x85\xDB\x8C\xDA\xBA") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("آدھی رات")),
+                        },
+                    },
+                };
+                static HI_LATN: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0\xD0\x02\xC0\x03\xB0\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x05\0\x0C\0\x11\0subahdopaharshaamraat") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("noon")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("midnight")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0\xD0\x02\xC0\x03\xB0\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x05\0\x0C\0\x11\0subahdopaharshaamraat") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("n")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("mi")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0\xD0\x02\xC0\x03\xB0\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x05\0\x0C\0\x11\0subahdopaharshaamraat") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("Madhyanh")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("aadhi raat")),
+                        },
+                    },
+                };
+                static HI: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0\xD0\x02\xC0\x03\xB0\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0C\0\x1B\0$\0\xE0\xA4\xB8\xE0\xA5\x81\xE0\xA4\xAC\xE0\xA4\xB9\xE0\xA4\xA6\xE0\xA5\x8B\xE0\xA4\xAA\xE0\xA4\xB9\xE0\xA4\xB0\xE0\xA4\xB6\xE0\xA4\xBE\xE0\xA4\xAE\xE0\xA4\xB0\xE0\xA4\xBE\xE0\xA4\xA4") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("मध\u{94d}यरात\u{94d}रि")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0\xD0\x02\xC0\x03\xB0\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0C\0\x1B\0$\0\xE0\xA4\xB8\xE0\xA5\x81\xE0\xA4\xAC\xE0\xA4\xB9\xE0\xA4\xA6\xE0\xA5\x8B\xE0\xA4\xAA\xE0\xA4\xB9\xE0\xA4\xB0\xE0\xA4\xB6\xE0\xA4\xBE\xE0\xA4\xAE\xE0\xA4\xB0\xE0\xA4\xBE\xE0\xA4\xA4") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("मध\u{94d}यरात\u{94d}रि")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0\xD0\x02\xC0\x03\xB0\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0C\0\x1B\0$\0\xE0\xA4\xB8\xE0\xA5\x81\xE0\xA4\xAC\xE0\xA4\xB9\xE0\xA4\xA6\xE0\xA5\x8B\xE0\xA4\xAA\xE0\xA4\xB9\xE0\xA4\xB0\xE0\xA4\xB6\xE0\xA4\xBE\xE0\xA4\xAE\xE0\xA4\xB0\xE0\xA4\xBE\xE0\xA4\xA4") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("मध\u{94d}यरात\u{94d}रि")),
+                        },
+                    },
+                };
+                static GU: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0\xD0\x02\xC0\x03\xB0\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0F\0\x1E\0-\0\xE0\xAA\xB8\xE0\xAA\xB5\xE0\xAA\xBE\xE0\xAA\xB0\xE0\xAB\x87\xE0\xAA\xAC\xE0\xAA\xAA\xE0\xAB\x8B\xE0\xAA\xB0\xE0\xAB\x87\xE0\xAA\xB8\xE0\xAA\xBE\xE0\xAA\x82\xE0\xAA\x9C\xE0\xAB\x87\xE0\xAA\xB0\xE0\xAA\xBE\xE0\xAA\xA4\xE0\xAB\x8D\xE0\xAA\xB0\xE0\xAB\x87") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("મધ\u{acd}યરાત\u{acd}રિ")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0\xD0\x02\xC0\x03\xB0\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0F\0\x1E\0-\0\xE0\xAA\xB8\xE0\xAA\xB5\xE0\xAA\xBE\xE0\xAA\xB0\xE0\xAB\x87\xE0\xAA\xAC\xE0\xAA\xAA\xE0\xAB\x8B\xE0\xAA\xB0\xE0\xAB\x87\xE0\xAA\xB8\xE0\xAA\xBE\xE0\xAA\x82\xE0\xAA\x9C\xE0\xAB\x87\xE0\xAA\xB0\xE0\xAA\xBE\xE0\xAA\xA4\xE0\xAB\x8D\xE0\xAA\xB0\xE0\xAB\x87") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("મ.રાત\u{acd}રિ")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0\xD0\x02\xC0\x03\xB0\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0F\0\x1E\0-\0\xE0\xAA\xB8\xE0\xAA\xB5\xE0\xAA\xBE\xE0\xAA\xB0\xE0\xAB\x87\xE0\xAA\xAC\xE0\xAA\xAA\xE0\xAB\x8B\xE0\xAA\xB0\xE0\xAB\x87\xE0\xAA\xB8\xE0\xAA\xBE\xE0\xAA\x82\xE0\xAA\x9C\xE0\xAB\x87\xE0\xAA\xB0\xE0\xAA\xBE\xE0\xAA\xA4\xE0\xAB\x8D\xE0\xAA\xB0\xE0\xAB\x87") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("મધ\u{acd}યરાત\u{acd}રિ")),
+                        },
+                    },
+                };
+                static PA: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0\xD0\x02\xC0\x03\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0F\0$\x006\0\xE0\xA8\xB8\xE0\xA8\xB5\xE0\xA9\x87\xE0\xA8\xB0\xE0\xA9\x87\xE0\xA8\xA6\xE0\xA9\x81\xE0\xA8\xAA\xE0\xA8\xB9\xE0\xA8\xBF\xE0\xA8\xB0\xE0\xA9\x87\xE0\xA8\xB8\xE0\xA8\xBC\xE0\xA8\xBE\xE0\xA8\xAE\xE0\xA9\x80\xE0\xA8\x82\xE0\xA8\xB0\xE0\xA8\xBE\xE0\xA8\xA4\xE0\xA9\x80\xE0\xA8\x82") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("ਅ\u{a71}ਧੀ ਰਾਤ")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0\xD0\x02\xC0\x03\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0F\0$\x006\0\xE0\xA8\xB8\xE0\xA8\xB5\xE0\xA9\x87\xE0\xA8\xB0\xE0\xA9\x87\xE0\xA8\xA6\xE0\xA9\x81\xE0\xA8\xAA\xE0\xA8\xB9\xE0\xA8\xBF\xE0\xA8\xB0\xE0\xA9\x87\xE0\xA8\xB8\xE0\xA8\xBC\xE0\xA8\xBE\xE0\xA8\xAE\xE0\xA9\x80\xE0\xA8\x82\xE0\xA8\xB0\xE0\xA8\xBE\xE0\xA8\xA4\xE0\xA9\x80\xE0\xA8\x82") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("ਅ\u{a71}ਧੀ ਰਾਤ")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0\xD0\x02\xC0\x03\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0F\0$\x006\0\xE0\xA8\xB8\xE0\xA8\xB5\xE0\xA9\x87\xE0\xA8\xB0\xE0\xA9\x87\xE0\xA8\xA6\xE0\xA9\x81\xE0\xA8\xAA\xE0\xA8\xB9\xE0\xA8\xBF\xE0\xA8\xB0\xE0\xA9\x87\xE0\xA8\xB8\xE0\xA8\xBC\xE0\xA8\xBE\xE0\xA8\xAE\xE0\xA9\x80\xE0\xA8\x82\xE0\xA8\xB0\xE0\xA8\xBE\xE0\xA8\xA4\xE0\xA9\x80\xE0\xA8\x82") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("ਅ\u{a71}ਧੀ ਰਾਤ")),
+                        },
+                    },
+                };
+                static NE: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0\xD0\x02\xC0\x03t\x04(\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x0F\0$\x000\0B\0\xE0\xA4\xAC\xE0\xA4\xBF\xE0\xA4\xB9\xE0\xA4\xBE\xE0\xA4\xA8\xE0\xA4\x85\xE0\xA4\xAA\xE0\xA4\xB0\xE0\xA4\xBE\xE0\xA4\xA8\xE0\xA5\x8D\xE0\xA4\xB9\xE0\xA4\xB8\xE0\xA4\xBE\xE0\xA4\x81\xE0\xA4\x9D\xE0\xA4\xAC\xE0\xA5\x87\xE0\xA4\xB2\xE0\xA5\x81\xE0\xA4\x95\xE0\xA5\x80\xE0\xA4\xB0\xE0\xA4\xBE\xE0\xA4\xA4") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("मध\u{94d}यान\u{94d}ह")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("मध\u{94d}यरात")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0\xD0\x02\xC0\x03t\x04(\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x0F\0$\x000\0B\0\xE0\xA4\xAC\xE0\xA4\xBF\xE0\xA4\xB9\xE0\xA4\xBE\xE0\xA4\xA8\xE0\xA4\x85\xE0\xA4\xAA\xE0\xA4\xB0\xE0\xA4\xBE\xE0\xA4\xA8\xE0\xA5\x8D\xE0\xA4\xB9\xE0\xA4\xB8\xE0\xA4\xBE\xE0\xA4\x81\xE0\xA4\x9D\xE0\xA4\xAC\xE0\xA5\x87\xE0\xA4\xB2\xE0\xA5\x81\xE0\xA4\x95\xE0\xA5\x80\xE0\xA4\xB0\xE0\xA4\xBE\xE0\xA4\xA4") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("मध\u{94d}यान\u{94d}ह")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("मध\u{94d}यरात")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0\xD0\x02\xC0\x03t\x04(\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x0F\0$\x000\0B\0\xE0\xA4\xAC\xE0\xA4\xBF\xE0\xA4\xB9\xE0\xA4\xBE\xE0\xA4\xA8\xE0\xA4\x85\xE0\xA4\xAA\xE0\xA4\xB0\xE0\xA4\xBE\xE0\xA4\xA8\xE0\xA5\x8D\xE0\xA4\xB9\xE0\xA4\xB8\xE0\xA4\xBE\xE0\xA4\x81\xE0\xA4\x9D\xE0\xA4\xAC\xE0\xA5\x87\xE0\xA4\xB2\xE0\xA5\x81\xE0\xA4\x95\xE0\xA5\x80\xE0\xA4\xB0\xE0\xA4\xBE\xE0\xA4\xA4") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("मध\u{94d}यान\u{94d}ह")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("मध\u{94d}यरात")),
+                        },
+                    },
+                };
+                static JA: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0\xD0\x02\xC0\x03t\x04d\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x03\0\x06\0\x0C\0\x0F\0\xE6\x9C\x9D\xE6\x98\xBC\xE5\xA4\x95\xE6\x96\xB9\xE5\xA4\x9C\xE5\xA4\x9C\xE4\xB8\xAD") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("正午")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("真夜中")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0\xD0\x02\xC0\x03t\x04d\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x03\0\x06\0\x0C\0\x0F\0\xE6\x9C\x9D\xE6\x98\xBC\xE5\xA4\x95\xE6\x96\xB9\xE5\xA4\x9C\xE5\xA4\x9C\xE4\xB8\xAD") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("正午")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("真夜中")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0\xD0\x02\xC0\x03t\x04d\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x03\0\x06\0\x0C\0\x0F\0\xE6\x9C\x9D\xE6\x98\xBC\xE5\xA4\x95\xE6\x96\xB9\xE5\xA4\x9C\xE5\xA4\x9C\xE4\xB8\xAD") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("正午")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("真夜中")),
+                        },
+                    },
+                };
+                static EL: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0\xD0\x02\xFC\x03\xB0\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x08\0\x13\0\x1C\0\xCF\x80\xCF\x81\xCF\x89\xCE\xAF\xCE\xBC\xCE\xB5\xCF\x83\xCE\xB7\xCE\xBC.\xCE\xB1\xCF\x80\xCF\x8C\xCE\xB3.\xCE\xB2\xCF\x81\xCE\xAC\xCE\xB4\xCF\x85") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0\xD0\x02\xFC\x03\xB0\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x08\0\x13\0\x1C\0\xCF\x80\xCF\x81\xCF\x89\xCE\xAF\xCE\xBC\xCE\xB5\xCF\x83\xCE\xB7\xCE\xBC.\xCE\xB1\xCF\x80\xCF\x8C\xCE\xB3.\xCE\xB2\xCF\x81\xCE\xAC\xCE\xB4\xCF\x85") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0\xD0\x02\xFC\x03\xB0\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\r\0\"\x007\0\xCF\x84\xCE\xBF \xCF\x80\xCF\x81\xCF\x89\xCE\xAF\xCF\x84\xCE\xBF \xCE\xBC\xCE\xB5\xCF\x83\xCE\xB7\xCE\xBC\xCE\xAD\xCF\x81\xCE\xB9\xCF\x84\xCE\xBF \xCE\xB1\xCF\x80\xCF\x8C\xCE\xB3\xCE\xB5\xCF\x85\xCE\xBC\xCE\xB1\xCF\x84\xCE\xBF \xCE\xB2\xCF\x81\xCE\xAC\xCE\xB4\xCF\x85") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                    },
+                };
+                static HU: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0h\x01\x1C\x02\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x06\0\x0C\0\x0F\0\x12\0\x16\0hajnalreggelde.du.este\xC3\xA9jjel") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("dél")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("éjfél")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0h\x01\x1C\x02\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x06\0\x0C\0\x0F\0\x12\0\x16\0hajnalreggelde.du.este\xC3\xA9jjel") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("dél")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("éjfél")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0h\x01\x1C\x02\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x06\0\x0C\0\x16\0\x1F\0#\0hajnalreggeld\xC3\xA9lel\xC5\x91ttd\xC3\xA9lut\xC3\xA1neste\xC3\xA9jjel") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("dél")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("éjfél")),
+                        },
+                    },
+                };
+                static BN_IN: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0h\x01\xD0\x02\xC0\x038\x04\xB0\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\t\0\x15\0$\x003\0H\0\xE0\xA6\xAD\xE0\xA7\x8B\xE0\xA6\xB0\xE0\xA6\xB8\xE0\xA6\x95\xE0\xA6\xBE\xE0\xA6\xB2\xE0\xA6\xA6\xE0\xA7\x81\xE0\xA6\xAA\xE0\xA7\x81\xE0\xA6\xB0\xE0\xA6\xAC\xE0\xA6\xBF\xE0\xA6\x95\xE0\xA6\xBE\xE0\xA6\xB2\xE0\xA6\xB8\xE0\xA6\xA8\xE0\xA7\x8D\xE0\xA6\xA7\xE0\xA7\x8D\xE0\xA6\xAF\xE0\xA6\xBE\xE0\xA6\xB0\xE0\xA6\xBE\xE0\xA6\xA4\xE0\xA7\x8D\xE0\xA6\xB0\xE0\xA6\xBF") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0h\x01\xD0\x02\xC0\x038\x04\xB0\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\t\0\x15\0$\x003\0H\0\xE0\xA6\xAD\xE0\xA7\x8B\xE0\xA6\xB0\xE0\xA6\xB8\xE0\xA6\x95\xE0\xA6\xBE\xE0\xA6\xB2\xE0\xA6\xA6\xE0\xA7\x81\xE0\xA6\xAA\xE0\xA7\x81\xE0\xA6\xB0\xE0\xA6\xAC\xE0\xA6\xBF\xE0\xA6\x95\xE0\xA6\xBE\xE0\xA6\xB2\xE0\xA6\xB8\xE0\xA6\xA8\xE0\xA7\x8D\xE0\xA6\xA7\xE0\xA7\x8D\xE0\xA6\xAF\xE0\xA6\xBE\xE0\xA6\xB0\xE0\xA6\xBE\xE0\xA6\xA4\xE0\xA7\x8D\xE0\xA6\xB0\xE0\xA6\xBF") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0h\x01\xD0\x02\xC0\x038\x04\xB0\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x1B\09\0Z\0i\0\x90\0\xE0\xA6\xAD\xE0\xA7\x8B\xE0\xA6\xB0\xE0\xA6\xAC\xE0\xA7\x87\xE0\xA6\xB2\xE0\xA6\xBE\xE0\xA6\xAF\xE0\xA6\xBC\xE0\xA6\xB8\xE0\xA6\x95\xE0\xA6\xBE\xE0\xA6\xB2\xE0\xA6\xAC\xE0\xA7\x87\xE0\xA6\xB2\xE0\xA6\xBE\xE0\xA6\xAF\xE0\xA6\xBC\xE0\xA6\xA6\xE0\xA7\x81\xE0\xA6\xAA\xE0\xA7\x81\xE0\xA6\xB0\xE0\xA6\xAC\xE0\xA7\x87\xE0\xA6\xB2\xE0\xA6\xBE\xE0\xA6\xAF\xE0\xA6\xBC\xE0\xA6\xAC\xE0\xA6\xBF\xE0\xA6\x95\xE0\xA6\xBE\xE0\xA6\xB2\xE0\xA6\xB8\xE0\xA6\xA8\xE0\xA7\x8D\xE0\xA6\xA7\xE0\xA7\x8D\xE0\xA6\xAF\xE0\xA6\xBE\xE0\xA6\xAC\xE0\xA7\x87\xE0\xA6\xB2\xE0\xA6\xBE\xE0\xA6\xAF\xE0\xA6\xBC\xE0\xA6\xB0\xE0\xA6\xBE\xE0\xA6\xA4\xE0\xA7\x8D\xE0\xA6\xB0\xE0\xA6\xBF") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                    },
+                };
+                static BN: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0h\x01\xD0\x02\xC0\x038\x04\xB0\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\t\0\x15\0$\x003\0H\0\xE0\xA6\xAD\xE0\xA7\x8B\xE0\xA6\xB0\xE0\xA6\xB8\xE0\xA6\x95\xE0\xA6\xBE\xE0\xA6\xB2\xE0\xA6\xA6\xE0\xA7\x81\xE0\xA6\xAA\xE0\xA7\x81\xE0\xA6\xB0\xE0\xA6\xAC\xE0\xA6\xBF\xE0\xA6\x95\xE0\xA6\xBE\xE0\xA6\xB2\xE0\xA6\xB8\xE0\xA6\xA8\xE0\xA7\x8D\xE0\xA6\xA7\xE0\xA7\x8D\xE0\xA6\xAF\xE0\xA6\xBE\xE0\xA6\xB0\xE0\xA6\xBE\xE0\xA6\xA4\xE0\xA7\x8D\xE0\xA6\xB0\xE0\xA6\xBF") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0h\x01\xD0\x02\xC0\x038\x04\xB0\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\t\0\x15\0$\x003\0H\0\xE0\xA6\xAD\xE0\xA7\x8B\xE0\xA6\xB0\xE0\xA6\xB8\xE0\xA6\x95\xE0\xA6\xBE\xE0\xA6\xB2\xE0\xA6\xA6\xE0\xA7\x81\xE0\xA6\xAA\xE0\xA7\x81\xE0\xA6\xB0\xE0\xA6\xAC\xE0\xA6\xBF\xE0\xA6\x95\xE0\xA6\xBE\xE0\xA6\xB2\xE0\xA6\xB8\xE0\xA6\xA8\xE0\xA7\x8D\xE0\xA6\xA7\xE0\xA7\x8D\xE0\xA6\xAF\xE0\xA6\xBE\xE0\xA6\xB0\xE0\xA6\xBE\xE0\xA6\xA4\xE0\xA7\x8D\xE0\xA6\xB0\xE0\xA6\xBF") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0h\x01\xD0\x02\xC0\x038\x04\xB0\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x1B\09\0Z\0i\0\x90\0\xE0\xA6\xAD\xE0\xA7\x8B\xE0\xA6\xB0\xE0\xA6\xAC\xE0\xA7\x87\xE0\xA6\xB2\xE0\xA6\xBE\xE0\xA6\xAF\xE0\xA6\xBC\xE0\xA6\xB8\xE0\xA6\x95\xE0\xA6\xBE\xE0\xA6\xB2\xE0\xA6\xAC\xE0\xA7\x87\xE0\xA6\xB2\xE0\xA6\xBE\xE0\xA6\xAF\xE0\xA6\xBC\xE0\xA6\xA6\xE0\xA7\x81\xE0\xA6\xAA\xE0\xA7\x81\xE0\xA6\xB0\xE0\xA6\xAC\xE0\xA7\x87\xE0\xA6\xB2\xE0\xA6\xBE\xE0\xA6\xAF\xE0\xA6\xBC\xE0\xA6\xAC\xE0\xA6\xBF\xE0\xA6\x95\xE0\xA6\xBE\xE0\xA6\xB2\xE0\xA6\xB8\xE0\xA6\xA8\xE0\xA7\x8D\xE0\xA6\xA7\xE0\xA7\x8D\xE0\xA6\xAF\xE0\xA6\xBE\xE0\xA6\xAC\xE0\xA7\x87\xE0\xA6\xB2\xE0\xA6\xBE\xE0\xA6\xAF\xE0\xA6\xBC\xE0\xA6\xB0\xE0\xA6\xBE\xE0\xA6\xA4\xE0\xA7\x8D\xE0\xA6\xB0\xE0\xA6\xBF\xE0\xA6\xAC\xE0\xA7\x87\xE0\xA6\xB2\xE0\xA6\xBE\xE0\xA6\xAF\xE0\xA6\xBC") })
+                            },
+                            noon: None,
+                            midnight: None,
+                        },
+                    },
+                };
+                static MR: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0h\x01\xD0\x02\xC0\x03\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x0C\0\x18\0'\0B\0\xE0\xA4\xAA\xE0\xA4\xB9\xE0\xA4\xBE\xE0\xA4\x9F\xE0\xA4\xB8\xE0\xA4\x95\xE0\xA4\xBE\xE0\xA4\xB3\xE0\xA4\xA6\xE0\xA5\x81\xE0\xA4\xAA\xE0\xA4\xBE\xE0\xA4\xB0\xE0\xA4\xB8\xE0\xA4\x82\xE0\xA4\xA7\xE0\xA5\x8D\xE0\xA4\xAF\xE0\xA4\xBE\xE0\xA4\x95\xE0\xA4\xBE\xE0\xA4\xB3\xE0\xA4\xB0\xE0\xA4\xBE\xE0\xA4\xA4\xE0\xA5\x8D\xE0\xA4\xB0") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("मध\u{94d}यान\u{94d}ह")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("मध\u{94d}यरात\u{94d}र")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0h\x01\xD0\x02\xC0\x03\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x03\0\x06\0\x0C\0\x12\0\xE0\xA4\xAA\xE0\xA4\xB8\xE0\xA4\xA6\xE0\xA5\x81\xE0\xA4\xB8\xE0\xA4\x82\xE0\xA4\xB0\xE0\xA4\xBE") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("द\u{941}")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("म.रा.")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\xF0\0h\x01\xD0\x02\xC0\x03\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x0C\0\x18\0'\0B\0\xE0\xA4\xAA\xE0\xA4\xB9\xE0\xA4\xBE\xE0\xA4\x9F\xE0\xA4\xB8\xE0\xA4\x95\xE0\xA4\xBE\xE0\xA4\xB3\xE0\xA4\xA6\xE0\xA5\x81\xE0\xA4\xAA\xE0\xA4\xBE\xE0\xA4\xB0\xE0\xA4\xB8\xE0\xA4\x82\xE0\xA4\xA7\xE0\xA5\x8D\xE0\xA4\xAF\xE0\xA4\xBE\xE0\xA4\x95\xE0\xA4\xBE\xE0\xA4\xB3\xE0\xA4\xB0\xE0\xA4\xBE\xE0\xA4\xA4\xE0\xA5\x8D\xE0\xA4\xB0") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("मध\u{94d}यान\u{94d}ह")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("मध\u{94d}यरात\u{94d}र")),
+                        },
+                    },
+                };
+                static SL: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01X\x02\xD0\x028\x04(\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x05\0\t\0\r\0\x13\0zjut.dop.pop.zve\xC4\x8D.pono\xC4\x8Di") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("opold.")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("opoln.")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01X\x02\xD0\x028\x04(\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x02\0\x03\0\x04\0\x06\0zjdpzvpo") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("12.00")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("24.00")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01X\x02\xD0\x028\x04(\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x07\0\x0F\0\x17\0\x1E\0zjutrajdopoldanpopoldanzve\xC4\x8Derpono\xC4\x8Di") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("opoldne")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("opolnoči")),
+                        },
+                    },
+                };
+                static PL: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01X\x02\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x04\0\x14\0 \0)\0ranoprzed po\xC5\x82udniempo po\xC5\x82udniuwieczoremw nocy") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("w południe")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("o północy")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01X\x02\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x04\0\x0F\0\x17\0\x1D\0ranoprzed po\xC5\x82.po po\xC5\x82.wiecz.w nocy") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("w poł.")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("o półn.")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01X\x02\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x04\0\x14\0 \0)\0ranoprzed po\xC5\x82udniempo po\xC5\x82udniuwieczoremw nocy") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("w południe")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("o północy")),
+                        },
+                    },
+                };
+                static UZ: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\x94\x028\x04(\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x07\0\x0E\0\x17\0ertalabkunduzikechqurunkechasi") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("tush payti")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("yarim tun")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\x94\x028\x04(\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x07\0\x0E\0\x17\0ertalabkunduzikechqurunkechasi") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("tush payti")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("yarim tun")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\x94\x028\x04(\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x07\0\x0E\0\x17\0ertalabkunduzikechqurunkechasi") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("tush payti")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("yarim tun")),
+                        },
+                    },
+                };
+                static UZ_CYRL: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\x94\x028\x04(\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0E\0\x1C\0,\0\xD1\x8D\xD1\x80\xD1\x82\xD0\xB0\xD0\xBB\xD0\xB0\xD0\xB1\xD0\xBA\xD1\x83\xD0\xBD\xD0\xB4\xD1\x83\xD0\xB7\xD0\xB8\xD0\xBA\xD0\xB5\xD1\x87\xD2\x9B\xD1\x83\xD1\x80\xD1\x83\xD0\xBD\xD0\xBA\xD0\xB5\xD1\x87\xD0\xB0\xD1\x81\xD0\xB8") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("туш пайти")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("ярим тун")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\x94\x028\x04(\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0E\0\x1C\0,\0\xD1\x8D\xD1\x80\xD1\x82\xD0\xB0\xD0\xBB\xD0\xB0\xD0\xB1\xD0\xBA\xD1\x83\xD0\xBD\xD0\xB4\xD1\x83\xD0\xB7\xD0\xB8\xD0\xBA\xD0\xB5\xD1\x87\xD2\x9B\xD1\x83\xD1\x80\xD1\x83\xD0\xBD\xD0\xBA\xD0\xB5\xD1\x87\xD0\xB0\xD1\x81\xD0\xB8") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("туш пайти")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("ярим тун")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\x94\x028\x04(\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0E\0\x1C\0,\0\xD1\x8D\xD1\x80\xD1\x82\xD0\xB0\xD0\xBB\xD0\xB0\xD0\xB1\xD0\xBA\xD1\x83\xD0\xBD\xD0\xB4\xD1\x83\xD0\xB7\xD0\xB8\xD0\xBA\xD0\xB5\xD1\x87\xD2\x9B\xD1\x83\xD1\x80\xD1\x83\xD0\xBD\xD0\xBA\xD0\xB5\xD1\x87\xD0\xB0\xD1\x81\xD0\xB8") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("туш пайти")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("ярим тун")),
+                        },
+                    },
+                };
+                static TR: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\x94\x02\xD0\x028\x04t\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x05\0\x14\0#\0/\x005\0sabah\xC3\xB6\xC4\x9Fleden \xC3\xB6nce\xC3\xB6\xC4\x9Fleden sonraak\xC5\x9Fam\xC3\xBCst\xC3\xBCak\xC5\x9Famgece") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("öğle")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("gece yarısı")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\x94\x02\xD0\x028\x04t\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x05\0\x14\0#\0/\x005\0sabah\xC3\xB6\xC4\x9Fleden \xC3\xB6nce\xC3\xB6\xC4\x9Fleden sonraak\xC5\x9Fam\xC3\xBCst\xC3\xBCak\xC5\x9Famgece") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("ö")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("gece")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\x94\x02\xD0\x028\x04t\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x05\0\x14\0#\0/\x005\0sabah\xC3\xB6\xC4\x9Fleden \xC3\xB6nce\xC3\xB6\xC4\x9Fleden sonraak\xC5\x9Fam\xC3\xBCst\xC3\xBCak\xC5\x9Famgece") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("öğle")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("gece yarısı")),
+                        },
+                    },
+                };
+                static KK: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\n\0%\0/\0\xD1\x82\xD0\xB0\xD2\xA3\xD2\x93\xD1\x8B\xD1\x82\xD2\xAF\xD1\x81\xD1\x82\xD0\xB5\xD0\xBD \xD0\xBA\xD0\xB5\xD0\xB9\xD1\x96\xD0\xBD\xD0\xB3\xD1\x96\xD0\xBA\xD0\xB5\xD1\x88\xD0\xBA\xD1\x96\xD1\x82\xD2\xAF\xD0\xBD\xD0\xB3\xD1\x96") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("түскі")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("түн жарымы")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\n\0%\0/\0\xD1\x82\xD0\xB0\xD2\xA3\xD2\x93\xD1\x8B\xD1\x82\xD2\xAF\xD1\x81\xD1\x82\xD0\xB5\xD0\xBD \xD0\xBA\xD0\xB5\xD0\xB9\xD1\x96\xD0\xBD\xD0\xB3\xD1\x96\xD0\xBA\xD0\xB5\xD1\x88\xD0\xBA\xD1\x96\xD1\x82\xD2\xAF\xD0\xBD\xD0\xB3\xD1\x96") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("түскі")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("түнгі")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\n\0%\0/\0\xD1\x82\xD0\xB0\xD2\xA3\xD2\x93\xD1\x8B\xD1\x82\xD2\xAF\xD1\x81\xD1\x82\xD0\xB5\xD0\xBD \xD0\xBA\xD0\xB5\xD0\xB9\xD1\x96\xD0\xBD\xD0\xB3\xD1\x96\xD0\xBA\xD0\xB5\xD1\x88\xD0\xBA\xD1\x96\xD1\x82\xD2\xAF\xD0\xBD\xD0\xB3\xD1\x96") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("түскі")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("түн жарымы")),
+                        },
+                    },
+                };
+                static MN: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\n\0\x12\0\x1A\0\xD3\xA9\xD0\xB3\xD0\xBB\xD3\xA9\xD3\xA9\xD3\xA9\xD0\xB4\xD3\xA9\xD1\x80\xD0\xBE\xD1\x80\xD0\xBE\xD0\xB9\xD1\x88\xD3\xA9\xD0\xBD\xD3\xA9") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("үд дунд")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("шөнө дунд")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\n\0\x12\0\x1A\0\xD3\xA9\xD0\xB3\xD0\xBB\xD3\xA9\xD3\xA9\xD3\xA9\xD0\xB4\xD3\xA9\xD1\x80\xD0\xBE\xD1\x80\xD0\xBE\xD0\xB9\xD1\x88\xD3\xA9\xD0\xBD\xD3\xA9") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("үд дунд")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("шөнө дунд")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\n\0\x12\0\x1A\0\xD3\xA9\xD0\xB3\xD0\xBB\xD3\xA9\xD3\xA9\xD3\xA9\xD0\xB4\xD3\xA9\xD1\x80\xD0\xBE\xD1\x80\xD0\xBE\xD0\xB9\xD1\x88\xD3\xA9\xD0\xBD\xD3\xA9") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("үд дунд")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("шөнө дунд")),
+                        },
+                    },
+                };
+                static SR_XK: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\n\0\x16\0 \0\xD1\x98\xD1\x83\xD1\x82\xD1\x80\xD0\xBE\xD0\xBF\xD0\xBE \xD0\xBF\xD0\xBE\xD0\xB4.\xD1\x83\xD0\xB2\xD0\xB5\xD1\x87\xD0\xB5\xD0\xBD\xD0\xBE\xD1\x9B\xD1\x83") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("подне")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("поноћ")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\n\0\x16\0\x1E\0\xD1\x98\xD1\x83\xD1\x82\xD1\x80\xD0\xBE\xD0\xBF\xD0\xBE \xD0\xBF\xD0\xBE\xD0\xB4.\xD0\xB2\xD0\xB5\xD1\x87\xD0\xB5\xD0\xBD\xD0\xBE\xD1\x9B") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("подне")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("поноћ")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0C\0\x1B\0%\0\xD1\x83\xD1\x98\xD1\x83\xD1\x82\xD1\x80\xD0\xBE\xD0\xBF\xD0\xBE \xD0\xBF\xD0\xBE\xD0\xB4\xD0\xBD\xD0\xB5\xD1\x83\xD0\xB2\xD0\xB5\xD1\x87\xD0\xB5\xD0\xBD\xD0\xBE\xD1\x9B\xD1\x83") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("подне")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("поноћ")),
+                        },
+                    },
+                };
+                static SR_ME: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x05\0\x0C\0\x11\0jutropo pod.ve\xC4\x8Deno\xC4\x87u") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("podne")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("ponoć")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x05\0\x0C\0\x11\0jutropo pod.ve\xC4\x8Deno\xC4\x87") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("podne")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("ponoć")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x06\0\x0E\0\x14\0ujutropo podneuve\xC4\x8Deno\xC4\x87u") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("podne")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("ponoć")),
+                        },
+                    },
+                };
+                static SR_LATN_XK: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x05\0\x0C\0\x12\0jutropo pod.uve\xC4\x8Deno\xC4\x87u") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("podne")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("ponoć")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x05\0\x0C\0\x11\0jutropo pod.ve\xC4\x8Deno\xC4\x87") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("podne")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("ponoć")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x06\0\x0E\0\x14\0ujutropo podneuve\xC4\x8Deno\xC4\x87u") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("podne")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("ponoć")),
+                        },
+                    },
+                };
+                static SR_LATN_BA: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x06\0\x0E\0\x14\0ujutropo podneuve\xC4\x8Deno\xC4\x87u") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("podne")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("ponoć")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x06\0\x0E\0\x14\0ujutropo podneuve\xC4\x8Deno\xC4\x87u") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("podne")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("ponoć")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x06\0\x0E\0\x14\0ujutropo podneuve\xC4\x8Deno\xC4\x87u") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("podne")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("ponoć")),
+                        },
+                    },
+                };
+                static SR_LATN: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x06\0\x0E\0\x14\0ujutropo podneuve\xC4\x8Deno\xC4\x87u") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("podne")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("ponoć")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x06\0\x0E\0\x14\0ujutrupo podneuve\xC4\x8Deno\xC4\x87u") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("podne")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("ponoć")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x06\0\x0E\0\x14\0ujutropo podneuve\xC4\x8Deno\xC4\x87u") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("podne")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("ponoć")),
+                        },
+                    },
+                };
+                static EN_AU: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x07\0\x10\0\x17\0morningafternooneveningnight") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("midday")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("midnight")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x07\0\x10\0\x17\0morningafternooneveningnight") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("midday")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("midnight")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0E\0\x1E\0,\0in the morningin the afternoonin the eveningat night") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("midday")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("midnight")),
+                        },
+                    },
+                };
+                static TE: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0C\0'\0?\0\xE0\xB0\x89\xE0\xB0\xA6\xE0\xB0\xAF\xE0\xB0\x82\xE0\xB0\xAE\xE0\xB0\xA7\xE0\xB1\x8D\xE0\xB0\xAF\xE0\xB0\xBE\xE0\xB0\xB9\xE0\xB1\x8D\xE0\xB0\xA8\xE0\xB0\x82\xE0\xB0\xB8\xE0\xB0\xBE\xE0\xB0\xAF\xE0\xB0\x82\xE0\xB0\xA4\xE0\xB1\x8D\xE0\xB0\xB0\xE0\xB0\x82\xE0\xB0\xB0\xE0\xB0\xBE\xE0\xB0\xA4\xE0\xB1\x8D\xE0\xB0\xB0\xE0\xB0\xBF") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("అర\u{c4d}ధర\u{c3e}త\u{c4d}ర\u{c3f}")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0C\0'\0?\0\xE0\xB0\x89\xE0\xB0\xA6\xE0\xB0\xAF\xE0\xB0\x82\xE0\xB0\xAE\xE0\xB0\xA7\xE0\xB1\x8D\xE0\xB0\xAF\xE0\xB0\xBE\xE0\xB0\xB9\xE0\xB1\x8D\xE0\xB0\xA8\xE0\xB0\x82\xE0\xB0\xB8\xE0\xB0\xBE\xE0\xB0\xAF\xE0\xB0\x82\xE0\xB0\xA4\xE0\xB1\x8D\xE0\xB0\xB0\xE0\xB0\x82\xE0\xB0\xB0\xE0\xB0\xBE\xE0\xB0\xA4\xE0\xB1\x8D\xE0\xB0\xB0\xE0\xB0\xBF") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("అర\u{c4d}ధర\u{c3e}త\u{c4d}ర\u{c3f}")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0C\0'\0?\0\xE0\xB0\x89\xE0\xB0\xA6\xE0\xB0\xAF\xE0\xB0\x82\xE0\xB0\xAE\xE0\xB0\xA7\xE0\xB1\x8D\xE0\xB0\xAF\xE0\xB0\xBE\xE0\xB0\xB9\xE0\xB1\x8D\xE0\xB0\xA8\xE0\xB0\x82\xE0\xB0\xB8\xE0\xB0\xBE\xE0\xB0\xAF\xE0\xB0\x82\xE0\xB0\xA4\xE0\xB1\x8D\xE0\xB0\xB0\xE0\xB0\x82\xE0\xB0\xB0\xE0\xB0\xBE\xE0\xB0\xA4\xE0\xB1\x8D\xE0\xB0\xB0\xE0\xB0\xBF") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("అర\u{c4d}ధర\u{c3e}త\u{c4d}ర\u{c3f}")),
+                        },
+                    },
+                };
+                static SR_BA: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0C\0\x1B\0%\0\xD1\x83\xD1\x98\xD1\x83\xD1\x82\xD1\x80\xD0\xBE\xD0\xBF\xD0\xBE \xD0\xBF\xD0\xBE\xD0\xB4\xD0\xBD\xD0\xB5\xD1\x83\xD0\xB2\xD0\xB5\xD1\x87\xD0\xB5\xD0\xBD\xD0\xBE\xD1\x9B\xD1\x83") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("подне")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("поноћ")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0C\0\x1B\0%\0\xD1\x83\xD1\x98\xD1\x83\xD1\x82\xD1\x80\xD0\xBE\xD0\xBF\xD0\xBE \xD0\xBF\xD0\xBE\xD0\xB4\xD0\xBD\xD0\xB5\xD1\x83\xD0\xB2\xD0\xB5\xD1\x87\xD0\xB5\xD0\xBD\xD0\xBE\xD1\x9B\xD1\x83") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("подне")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("поноћ")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0C\0\x1B\0%\0\xD1\x83\xD1\x98\xD1\x83\xD1\x82\xD1\x80\xD0\xBE\xD0\xBF\xD0\xBE \xD0\xBF\xD0\xBE\xD0\xB4\xD0\xBD\xD0\xB5\xD1\x83\xD0\xB2\xD0\xB5\xD1\x87\xD0\xB5\xD0\xBD\xD0\xBE\xD1\x9B\xD1\x83") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("подне")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("поноћ")),
+                        },
+                    },
+                };
+                static SR: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0C\0\x1B\0%\0\xD1\x83\xD1\x98\xD1\x83\xD1\x82\xD1\x80\xD0\xBE\xD0\xBF\xD0\xBE \xD0\xBF\xD0\xBE\xD0\xB4\xD0\xBD\xD0\xB5\xD1\x83\xD0\xB2\xD0\xB5\xD1\x87\xD0\xB5\xD0\xBD\xD0\xBE\xD1\x9B\xD1\x83") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("подне")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("поноћ")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0C\0\x1B\0%\0\xD1\x83\xD1\x98\xD1\x83\xD1\x82\xD1\x80\xD1\x83\xD0\xBF\xD0\xBE \xD0\xBF\xD0\xBE\xD0\xB4\xD0\xBD\xD0\xB5\xD1\x83\xD0\xB2\xD0\xB5\xD1\x87\xD0\xB5\xD0\xBD\xD0\xBE\xD1\x9B\xD1\x83") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("подне")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("поноћ")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0C\0\x1B\0%\0\xD1\x83\xD1\x98\xD1\x83\xD1\x82\xD1\x80\xD0\xBE\xD0\xBF\xD0\xBE \xD0\xBF\xD0\xBE\xD0\xB4\xD0\xBD\xD0\xB5\xD1\x83\xD0\xB2\xD0\xB5\xD1\x87\xD0\xB5\xD0\xBD\xD0\xBE\xD1\x9B\xD1\x83") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("подне")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("поноћ")),
+                        },
+                    },
+                };
+                static EN_CA: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0E\0\x1E\0,\0in the morningin the afternoonin the eveningat night") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("noon")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("midnight")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x03\0\x06\0\t\0moraftevenight") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("n")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("mid")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0E\0\x1E\0,\0in the morningin the afternoonin the eveningat night") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("noon")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("midnight")),
+                        },
+                    },
+                };
+                static EN: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0E\0\x1E\0,\0in the morningin the afternoonin the eveningat night") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("noon")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("midnight")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0E\0\x1E\0,\0in the morningin the afternoonin the eveningat night") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("n")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("mi")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x0E\0\x1E\0,\0in the morningin the afternoonin the eveningat night") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("noon")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("midnight")),
+                        },
+                    },
+                };
+                static KY: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x15\0,\0:\0\xD1\x8D\xD1\x80\xD1\x82\xD0\xB5\xD2\xA3 \xD0\xBC\xD0\xB5\xD0\xBD\xD0\xB5\xD0\xBD\xD1\x82\xD2\xAF\xD1\x88\xD1\x82\xD3\xA9\xD0\xBD \xD0\xBA\xD0\xB8\xD0\xB9\xD0\xB8\xD0\xBD\xD0\xBA\xD0\xB5\xD1\x87\xD0\xB8\xD0\xBD\xD0\xB4\xD0\xB5\xD1\x82\xD2\xAF\xD0\xBD \xD0\xB8\xD1\x87\xD0\xB8\xD0\xBD\xD0\xB4\xD0\xB5") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("чак түш")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("түн ортосу")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\r\0\x1C\0$\0\xD1\x8D\xD1\x80\xD1\x82\xD2\xA3 \xD0\xBC\xD0\xBD\xD1\x82\xD2\xAF\xD1\x88\xD1\x82 \xD0\xBA\xD0\xB9\xD0\xBD\xD0\xBA\xD0\xB5\xD1\x87\xD0\xBA\xD1\x82\xD2\xAF\xD0\xBD") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("чт")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("түн орт")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x15\0,\0:\0\xD1\x8D\xD1\x80\xD1\x82\xD0\xB5\xD2\xA3 \xD0\xBC\xD0\xB5\xD0\xBD\xD0\xB5\xD0\xBD\xD1\x82\xD2\xAF\xD1\x88\xD1\x82\xD3\xA9\xD0\xBD \xD0\xBA\xD0\xB8\xD0\xB9\xD0\xB8\xD0\xBD\xD0\xBA\xD0\xB5\xD1\x87\xD0\xB8\xD0\xBD\xD0\xB4\xD0\xB5\xD1\x82\xD2\xAF\xD0\xBD \xD0\xB8\xD1\x87\xD0\xB8\xD0\xBD\xD0\xB4\xD0\xB5") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("чак түш")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("түн ортосу")),
+                        },
+                    },
+                };
+                static KN: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x15\0-\09\0\xE0\xB2\xAC\xE0\xB3\x86\xE0\xB2\xB3\xE0\xB2\x97\xE0\xB3\x8D\xE0\xB2\x97\xE0\xB3\x86\xE0\xB2\xAE\xE0\xB2\xA7\xE0\xB3\x8D\xE0\xB2\xAF\xE0\xB2\xBE\xE0\xB2\xB9\xE0\xB3\x8D\xE0\xB2\xA8\xE0\xB2\xB8\xE0\xB2\x82\xE0\xB2\x9C\xE0\xB3\x86\xE0\xB2\xB0\xE0\xB2\xBE\xE0\xB2\xA4\xE0\xB3\x8D\xE0\xB2\xB0\xE0\xB2\xBF") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("ಮಧ\u{ccd}ಯ ರಾತ\u{ccd}ರ\u{cbf}")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x15\0-\09\0\xE0\xB2\xAC\xE0\xB3\x86\xE0\xB2\xB3\xE0\xB2\x97\xE0\xB3\x8D\xE0\xB2\x97\xE0\xB3\x86\xE0\xB2\xAE\xE0\xB2\xA7\xE0\xB3\x8D\xE0\xB2\xAF\xE0\xB2\xBE\xE0\xB2\xB9\xE0\xB3\x8D\xE0\xB2\xA8\xE0\xB2\xB8\xE0\xB2\x82\xE0\xB2\x9C\xE0\xB3\x86\xE0\xB2\xB0\xE0\xB2\xBE\xE0\xB2\xA4\xE0\xB3\x8D\xE0\xB2\xB0\xE0\xB2\xBF") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("ಮಧ\u{ccd}ಯರಾತ\u{ccd}ರ\u{cbf}")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\xD0\x028\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x15\0-\09\0\xE0\xB2\xAC\xE0\xB3\x86\xE0\xB2\xB3\xE0\xB2\x97\xE0\xB3\x8D\xE0\xB2\x97\xE0\xB3\x86\xE0\xB2\xAE\xE0\xB2\xA7\xE0\xB3\x8D\xE0\xB2\xAF\xE0\xB2\xBE\xE0\xB2\xB9\xE0\xB3\x8D\xE0\xB2\xA8\xE0\xB2\xB8\xE0\xB2\x82\xE0\xB2\x9C\xE0\xB3\x86\xE0\xB2\xB0\xE0\xB2\xBE\xE0\xB2\xA4\xE0\xB3\x8D\xE0\xB2\xB0\xE0\xB2\xBF") })
+                            },
+                            noon: None,
+                            midnight: Some(alloc::borrow::Cow::Borrowed("ಮಧ\u{ccd}ಯ ರಾತ\u{ccd}ರ\u{cbf}")),
+                        },
+                    },
+                };
+                static LV: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\xD0\x028\x04d\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x08\0\x11\0\x18\0no r\xC4\xABtap\xC4\x93cpusd.vakar\xC4\x81nakt\xC4\xAB") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("pusd.")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("pusnaktī")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\xD0\x028\x04d\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x08\0\x11\0\x18\0no r\xC4\xABtap\xC4\x93cpusd.vakar\xC4\x81nakt\xC4\xAB") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("pusd.")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("pusnaktī")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\xD0\x028\x04d\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x08\0\x15\0\x1C\0no r\xC4\xABtap\xC4\x93cpusdien\xC4\x81vakar\xC4\x81nakt\xC4\xAB") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("pusdienlaikā")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("pusnaktī")),
+                        },
+                    },
+                };
+                static TH: <icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::FlexibleDayPeriodsV1 {
+                    day_periods: icu::datetime::provider::calendar::flexible_day_periods::FormatWidthsV1 {
+                        abbreviated: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\xD0\x02\x0C\x03\xC0\x038\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x1B\x006\0B\0]\0f\0\xE0\xB9\x83\xE0\xB8\x99\xE0\xB8\x95\xE0\xB8\xAD\xE0\xB8\x99\xE0\xB9\x80\xE0\xB8\x8A\xE0\xB9\x89\xE0\xB8\xB2\xE0\xB9\x83\xE0\xB8\x99\xE0\xB8\x95\xE0\xB8\xAD\xE0\xB8\x99\xE0\xB8\x9A\xE0\xB9\x88\xE0\xB8\xB2\xE0\xB8\xA2\xE0\xB8\x9A\xE0\xB9\x88\xE0\xB8\xB2\xE0\xB8\xA2\xE0\xB9\x83\xE0\xB8\x99\xE0\xB8\x95\xE0\xB8\xAD\xE0\xB8\x99\xE0\xB9\x80\xE0\xB8\xA2\xE0\xB9\x87\xE0\xB8\x99\xE0\xB8\x84\xE0\xB9\x88\xE0\xB8\xB3\xE0\xB8\x81\xE0\xB8\xA5\xE0\xB8\xB2\xE0\xB8\x87\xE0\xB8\x84\xE0\xB8\xB7\xE0\xB8\x99") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("เท\u{e35}\u{e48}ยง")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("เท\u{e35}\u{e48}ยงค\u{e37}น")),
+                        },
+                        narrow: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\xD0\x02\x0C\x03\xC0\x038\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x0C\0\x1E\0*\x006\0?\0\xE0\xB9\x80\xE0\xB8\x8A\xE0\xB9\x89\xE0\xB8\xB2\xE0\xB9\x80\xE0\xB8\x97\xE0\xB8\xB5\xE0\xB9\x88\xE0\xB8\xA2\xE0\xB8\x87\xE0\xB8\x9A\xE0\xB9\x88\xE0\xB8\xB2\xE0\xB8\xA2\xE0\xB9\x80\xE0\xB8\xA2\xE0\xB9\x87\xE0\xB8\x99\xE0\xB8\x84\xE0\xB9\x88\xE0\xB8\xB3\xE0\xB8\x81\xE0\xB8\xA5\xE0\xB8\xB2\xE0\xB8\x87\xE0\xB8\x84\xE0\xB8\xB7\xE0\xB8\x99") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("เท\u{e35}\u{e48}ยง")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("เท\u{e35}\u{e48}ยงค\u{e37}น")),
+                        },
+                        short: None,
+                        wide: icu::datetime::provider::calendar::flexible_day_periods::SymbolsV1 {
+                            periods: unsafe {
+                                #[allow(unused_unsafe)]
+                                zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"h\x01\xD0\x02\x0C\x03\xC0\x038\x04\xEC\x04") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x1B\x006\0B\0]\0f\0\xE0\xB9\x83\xE0\xB8\x99\xE0\xB8\x95\xE0\xB8\xAD\xE0\xB8\x99\xE0\xB9\x80\xE0\xB8\x8A\xE0\xB9\x89\xE0\xB8\xB2\xE0\xB9\x83\xE0\xB8\x99\xE0\xB8\x95\xE0\xB8\xAD\xE0\xB8\x99\xE0\xB8\x9A\xE0\xB9\x88\xE0\xB8\xB2\xE0\xB8\xA2\xE0\xB8\x9A\xE0\xB9\x88\xE0\xB8\xB2\xE0\xB8\xA2\xE0\xB9\x83\xE0\xB8\x99\xE0\xB8\x95\xE0\xB8\xAD\xE0\xB8\x99\xE0\xB9\x80\xE0\xB8\xA2\xE0\xB9\x87\xE0\xB8\x99\xE0\xB8\x84\xE0\xB9\x88\xE0\xB8\xB3\xE0\xB8\x81\xE0\xB8\xA5\xE0\xB8\xB2\xE0\xB8\x87\xE0\xB8\x84\xE0\xB8\xB7\xE0\xB8\x99") })
+                            },
+                            noon: Some(alloc::borrow::Cow::Borrowed("เท\u{e35}\u{e48}ยง")),
+                            midnight: Some(alloc::borrow::Cow::Borrowed("เท\u{e35}\u{e48}ยงค\u{e37}น")),
+                        },
+                    },
+                };
+                static VALUES: [&<icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::DataMarker>::Yokeable; 151usize] = [&AF, &AM, &AR, &AR_LY, &AR_LY, &AS, &AST, &AZ, &BE, &BG, &BGC, &BHO, &BN, &BN_IN, &BR, &BRX, &BS, &BS_CYRL, &CA, &CEB, &CHR, &CS, &CY, &DA, &DE, &DOI, &DSB, &EL, &EL_POLYTON, &EN, &EN_AU, &EN_CA, &CEB, &ES, &ES_AR, &ES_CO, &ES_DO, &ES_MX, &ES_PA, &ET, &EU, &FA, &FA_AF, &FF_ADLM, &FI, &FIL, &FR, &FR_CA, &FR_CD, &FR_CH, &FR_RE, &FR_SN, &GA, &GD, &GL, &GU, &HA, &HE, &HI, &HI_LATN, &HR, &HSB, &HU, &HY, &ID, &IG, &IS, &IT, &JA, &JV, &KA, &KEA, &KGP, &KK, &KM, &KN, &KO, &KOK, &KS, &KS_DEVA, &KY, &LO, &LT, &LV, &MAI, &MK, &ML, &MN, &MNI, &MR, &MS, &MY, &NE, &NL, &NN, &NO, &OR, &PA, &PCM, &PL, &PS, &PT, &PT_PT, &QU, &RAJ, &RO, &RO_MD, &RU, &SA, &SAT, &SC, &SD, &SD_DEVA, &SI, &SK, &SL, &SO, &SQ, &SR, &SR_BA, &SR_LATN, &SR_LATN_BA, &SR_LATN_XK, &SR_ME, &SR_XK, &SV, &SW, &TA, &TE, &TH, &TI, &TK, &TO, &TR, &UK, &UND, &UR, &UZ, &UZ_CYRL, &VI, &WO, &YO, &YO_BJ, &YRL, &YUE, &YUE_HANS, &ZH, &ZH_HK, &ZH_HANT, &ZH_HK, &ZU];
+                static KEYS: [&str; 151usize] = ["af", "am", "ar", "ar-LY", "ar-SA", "as", "ast", "az", "be", "bg", "bgc", "bho", "bn", "bn-IN", "br", "brx", "bs", "bs-Cyrl", "ca", "ceb", "chr", "cs", "cy", "da", "de", "doi", "dsb", "el", "el-polyton", "en", "en-AU", "en-CA", "en-US-posix", "es", "es-AR", "es-CO", "es-DO", "es-MX", "es-PA", "et", "eu", "fa", "fa-AF", "ff-Adlm", "fi", "fil", "fr", "fr-CA", "fr-CD", "fr-CH", "fr-RE", "fr-SN", "ga", "gd", "gl", "gu", "ha", "he", "hi", "hi-Latn", "hr", "hsb", "hu", "hy", "id", "ig", "is", "it", "ja", "jv", "ka", "kea", "kgp", "kk", "km", "kn", "ko", "kok", "ks", "ks-Deva", "ky", "lo", "lt", "lv", "mai", "mk", "ml", "mn", "mni", "mr", "ms", "my", "ne", "nl", "nn", "no", "or", "pa", "pcm", "pl", "ps", "pt", "pt-PT", "qu", "raj", "ro", "ro-MD", "ru", "sa", "sat", "sc", "sd", "sd-Deva", "si", "sk", "sl", "so", "sq", "sr", "sr-BA", "sr-Latn", "sr-Latn-BA", "sr-Latn-XK", "sr-ME", "sr-XK", "sv", "sw", "ta", "te", "th", "ti", "tk", "to", "tr", "uk", "und", "ur", "uz", "uz-Cyrl", "vi", "wo", "yo", "yo-BJ", "yrl", "yue", "yue-Hans", "zh", "zh-HK", "zh-Hant", "zh-MO", "zu"];
+                let mut metadata = icu_provider::DataResponseMetadata::default();
+                let payload = if let Ok(payload) = KEYS.binary_search_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse()).map(|i| *unsafe { VALUES.get_unchecked(i) }) {
+                    payload
+                } else {
+                    const FALLBACKER: icu::locid_transform::fallback::LocaleFallbackerWithConfig<'static> = icu::locid_transform::fallback::LocaleFallbacker::new().for_config(<icu::datetime::provider::calendar::FlexibleDayPeriodsV1Marker as icu_provider::KeyedDataMarker>::KEY.fallback_config());
+                    let mut fallback_iterator = FALLBACKER.fallback_for(req.locale.clone());
+                    loop {
+                        if let Ok(payload) = KEYS.binary_search_by(|k| fallback_iterator.get().strict_cmp(k.as_bytes()).reverse()).map(|i| *unsafe { VALUES.get_unchecked(i) }) {
+                            metadata.locale = Some(fallback_iterator.take());
+                            break payload;
+                        }
+                        fallback_iterator.step();
+                    }
+                };
+                Ok(icu_provider::DataResponse { payload: Some(icu_provider::DataPayload::from_static_ref(payload)), metadata })
+            }
+        }
+    };
+}
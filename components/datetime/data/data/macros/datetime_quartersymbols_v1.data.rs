@@ -0,0 +1,167 @@
+// @generated
+/// Implement `DataProvider<QuarterSymbolsV1Marker>` on the given struct using the data
+/// hardcoded in this file. This allows the struct to be used with
+/// `icu`'s `_unstable` constructors.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_datetime_quartersymbols_v1 {
+    ($ provider : path) => {
+        #[clippy::msrv = "1.66"]
+        impl icu_provider::DataProvider<icu::datetime::provider::calendar::QuarterSymbolsV1Marker> for $provider {
+            fn load(&self, req: icu_provider::DataRequest) -> Result<icu_provider::DataResponse<icu::datetime::provider::calendar::QuarterSymbolsV1Marker>, icu_provider::DataError> {
+                static TT: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1 нче кв."), alloc::borrow::Cow::Borrowed("2 нче кв."), alloc::borrow::Cow::Borrowed("3 нче кв."), alloc::borrow::Cow::Borrowed("4 нче кв.")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1 нче квартал"), alloc::borrow::Cow::Borrowed("2 нче квартал"), alloc::borrow::Cow::Borrowed("3 нче квартал"), alloc::borrow::Cow::Borrowed("4 нче квартал")]) }, stand_alone: None } };
+                static UZ: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1-ch"), alloc::borrow::Cow::Borrowed("2-ch"), alloc::borrow::Cow::Borrowed("3-ch"), alloc::borrow::Cow::Borrowed("4-ch")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1-chorak"), alloc::borrow::Cow::Borrowed("2-chorak"), alloc::borrow::Cow::Borrowed("3-chorak"), alloc::borrow::Cow::Borrowed("4-chorak")]) }, stand_alone: None } };
+                static AZ: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1-ci kv."), alloc::borrow::Cow::Borrowed("2-ci kv."), alloc::borrow::Cow::Borrowed("3-cü kv."), alloc::borrow::Cow::Borrowed("4-cü kv.")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1-ci kvartal"), alloc::borrow::Cow::Borrowed("2-ci kvartal"), alloc::borrow::Cow::Borrowed("3-cü kvartal"), alloc::borrow::Cow::Borrowed("4-cü kvartal")]) }, stand_alone: None } };
+                static RU: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1-й кв."), alloc::borrow::Cow::Borrowed("2-й кв."), alloc::borrow::Cow::Borrowed("3-й кв."), alloc::borrow::Cow::Borrowed("4-й кв.")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1-й квартал"), alloc::borrow::Cow::Borrowed("2-й квартал"), alloc::borrow::Cow::Borrowed("3-й квартал"), alloc::borrow::Cow::Borrowed("4-й квартал")]) }, stand_alone: None } };
+                static CV: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1-мӗш кв."), alloc::borrow::Cow::Borrowed("2-мӗш кв."), alloc::borrow::Cow::Borrowed("3-мӗш кв."), alloc::borrow::Cow::Borrowed("4-мӗш кв.")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1-мӗш квартал"), alloc::borrow::Cow::Borrowed("2-мӗш квартал"), alloc::borrow::Cow::Borrowed("3-мӗш квартал"), alloc::borrow::Cow::Borrowed("4-мӗш квартал")]) }, stand_alone: None } };
+                static UZ_CYRL: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1-ч"), alloc::borrow::Cow::Borrowed("2-ч"), alloc::borrow::Cow::Borrowed("3-ч"), alloc::borrow::Cow::Borrowed("4-ч")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1-чорак"), alloc::borrow::Cow::Borrowed("2-чорак"), alloc::borrow::Cow::Borrowed("3-чорак"), alloc::borrow::Cow::Borrowed("4-чорак")]) }, stand_alone: None } };
+                static KY: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1-чей."), alloc::borrow::Cow::Borrowed("2-чей."), alloc::borrow::Cow::Borrowed("3-чей."), alloc::borrow::Cow::Borrowed("4-чей.")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1-чейрек"), alloc::borrow::Cow::Borrowed("2-чейрек"), alloc::borrow::Cow::Borrowed("3-чейрек"), alloc::borrow::Cow::Borrowed("4-чейрек")]) }, stand_alone: Some(icu::datetime::provider::calendar::quarters::StandAloneWidthsV1 { abbreviated: Some(icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1-ч."), alloc::borrow::Cow::Borrowed("2-ч."), alloc::borrow::Cow::Borrowed("3-ч."), alloc::borrow::Cow::Borrowed("4-ч.")])), narrow: None, short: None, wide: None }) } };
+                static BE: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1-шы кв."), alloc::borrow::Cow::Borrowed("2-гі кв."), alloc::borrow::Cow::Borrowed("3-ці кв."), alloc::borrow::Cow::Borrowed("4-ты кв.")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1-шы квартал"), alloc::borrow::Cow::Borrowed("2-гі квартал"), alloc::borrow::Cow::Borrowed("3-ці квартал"), alloc::borrow::Cow::Borrowed("4-ты квартал")]) }, stand_alone: None } };
+                static HY: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1-ին եռմս."), alloc::borrow::Cow::Borrowed("2-րդ եռմս."), alloc::borrow::Cow::Borrowed("3-րդ եռմս."), alloc::borrow::Cow::Borrowed("4-րդ եռմս.")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1-ին եռամսյակ"), alloc::borrow::Cow::Borrowed("2-րդ եռամսյակ"), alloc::borrow::Cow::Borrowed("3-րդ եռամսյակ"), alloc::borrow::Cow::Borrowed("4-րդ եռամսյակ")]) }, stand_alone: None } };
+                static HR_BA: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1. kv."), alloc::borrow::Cow::Borrowed("2. kv."), alloc::borrow::Cow::Borrowed("3. kv."), alloc::borrow::Cow::Borrowed("4. kv.")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1."), alloc::borrow::Cow::Borrowed("2."), alloc::borrow::Cow::Borrowed("3."), alloc::borrow::Cow::Borrowed("4.")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1. kvartal"), alloc::borrow::Cow::Borrowed("2. kvartal"), alloc::borrow::Cow::Borrowed("3. kvartal"), alloc::borrow::Cow::Borrowed("4. kvartal")]) }, stand_alone: None } };
+                static SR_LATN: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1. kv."), alloc::borrow::Cow::Borrowed("2. kv."), alloc::borrow::Cow::Borrowed("3. kv."), alloc::borrow::Cow::Borrowed("4. kv.")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1."), alloc::borrow::Cow::Borrowed("2."), alloc::borrow::Cow::Borrowed("3."), alloc::borrow::Cow::Borrowed("4.")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("prvi kvartal"), alloc::borrow::Cow::Borrowed("drugi kvartal"), alloc::borrow::Cow::Borrowed("treći kvartal"), alloc::borrow::Cow::Borrowed("četvrti kvartal")]) }, stand_alone: None } };
+                static DA: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1. kvt."), alloc::borrow::Cow::Borrowed("2. kvt."), alloc::borrow::Cow::Borrowed("3. kvt."), alloc::borrow::Cow::Borrowed("4. kvt.")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1. kvartal"), alloc::borrow::Cow::Borrowed("2. kvartal"), alloc::borrow::Cow::Borrowed("3. kvartal"), alloc::borrow::Cow::Borrowed("4. kvartal")]) }, stand_alone: None } };
+                static FI: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1. nelj."), alloc::borrow::Cow::Borrowed("2. nelj."), alloc::borrow::Cow::Borrowed("3. nelj."), alloc::borrow::Cow::Borrowed("4. nelj.")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1. neljännes"), alloc::borrow::Cow::Borrowed("2. neljännes"), alloc::borrow::Cow::Borrowed("3. neljännes"), alloc::borrow::Cow::Borrowed("4. neljännes")]) }, stand_alone: None } };
+                static FO: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1. ársfj."), alloc::borrow::Cow::Borrowed("2. ársfj."), alloc::borrow::Cow::Borrowed("3. ársfj."), alloc::borrow::Cow::Borrowed("4. ársfj.")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1. ársfjórðingur"), alloc::borrow::Cow::Borrowed("2. ársfjórðingur"), alloc::borrow::Cow::Borrowed("3. ársfjórðingur"), alloc::borrow::Cow::Borrowed("4. ársfjórðingur")]) }, stand_alone: None } };
+                static SL: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1. čet."), alloc::borrow::Cow::Borrowed("2. čet."), alloc::borrow::Cow::Borrowed("3. čet."), alloc::borrow::Cow::Borrowed("4. čet.")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1. četrtletje"), alloc::borrow::Cow::Borrowed("2. četrtletje"), alloc::borrow::Cow::Borrowed("3. četrtletje"), alloc::borrow::Cow::Borrowed("4. četrtletje")]) }, stand_alone: None } };
+                static SR: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1. кв."), alloc::borrow::Cow::Borrowed("2. кв."), alloc::borrow::Cow::Borrowed("3. кв."), alloc::borrow::Cow::Borrowed("4. кв.")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1."), alloc::borrow::Cow::Borrowed("2."), alloc::borrow::Cow::Borrowed("3."), alloc::borrow::Cow::Borrowed("4.")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("први квартал"), alloc::borrow::Cow::Borrowed("други квартал"), alloc::borrow::Cow::Borrowed("трећи квартал"), alloc::borrow::Cow::Borrowed("четврти квартал")]) }, stand_alone: None } };
+                static BG: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1. трим."), alloc::borrow::Cow::Borrowed("2. трим."), alloc::borrow::Cow::Borrowed("3. трим."), alloc::borrow::Cow::Borrowed("4. трим.")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1. тримесечие"), alloc::borrow::Cow::Borrowed("2. тримесечие"), alloc::borrow::Cow::Borrowed("3. тримесечие"), alloc::borrow::Cow::Borrowed("4. тримесечие")]) }, stand_alone: None } };
+                static LV: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1.\u{a0}cet."), alloc::borrow::Cow::Borrowed("2.\u{a0}cet."), alloc::borrow::Cow::Borrowed("3.\u{a0}cet."), alloc::borrow::Cow::Borrowed("4.\u{a0}cet.")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1."), alloc::borrow::Cow::Borrowed("2."), alloc::borrow::Cow::Borrowed("3."), alloc::borrow::Cow::Borrowed("4.")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1. ceturksnis"), alloc::borrow::Cow::Borrowed("2. ceturksnis"), alloc::borrow::Cow::Borrowed("3. ceturksnis"), alloc::borrow::Cow::Borrowed("4. ceturksnis")]) }, stand_alone: None } };
+                static EU: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1Hh"), alloc::borrow::Cow::Borrowed("2Hh"), alloc::borrow::Cow::Borrowed("3Hh"), alloc::borrow::Cow::Borrowed("4Hh")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1. hiruhilekoa"), alloc::borrow::Cow::Borrowed("2. hiruhilekoa"), alloc::borrow::Cow::Borrowed("3. hiruhilekoa"), alloc::borrow::Cow::Borrowed("4. hiruhilekoa")]) }, stand_alone: None } };
+                static AST: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1T"), alloc::borrow::Cow::Borrowed("2T"), alloc::borrow::Cow::Borrowed("3T"), alloc::borrow::Cow::Borrowed("4T")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1er trimestre"), alloc::borrow::Cow::Borrowed("2u trimestre"), alloc::borrow::Cow::Borrowed("3er trimestre"), alloc::borrow::Cow::Borrowed("4u trimestre")]) }, stand_alone: None } };
+                static CA: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1T"), alloc::borrow::Cow::Borrowed("2T"), alloc::borrow::Cow::Borrowed("3T"), alloc::borrow::Cow::Borrowed("4T")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1r trimestre"), alloc::borrow::Cow::Borrowed("2n trimestre"), alloc::borrow::Cow::Borrowed("3r trimestre"), alloc::borrow::Cow::Borrowed("4t trimestre")]) }, stand_alone: None } };
+                static BR: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1añ trim."), alloc::borrow::Cow::Borrowed("2l trim."), alloc::borrow::Cow::Borrowed("3e trim."), alloc::borrow::Cow::Borrowed("4e trim.")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1añ trimiziad"), alloc::borrow::Cow::Borrowed("2l trimiziad"), alloc::borrow::Cow::Borrowed("3e trimiziad"), alloc::borrow::Cow::Borrowed("4e trimiziad")]) }, stand_alone: None } };
+                static WO: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1er Tri"), alloc::borrow::Cow::Borrowed("2e Tri"), alloc::borrow::Cow::Borrowed("3e Tri"), alloc::borrow::Cow::Borrowed("4e Tri")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1er Trimestar"), alloc::borrow::Cow::Borrowed("2e Trimestar"), alloc::borrow::Cow::Borrowed("3e Trimestar"), alloc::borrow::Cow::Borrowed("4e Trimestar")]) }, stand_alone: None } };
+                static HR: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1kv"), alloc::borrow::Cow::Borrowed("2kv"), alloc::borrow::Cow::Borrowed("3kv"), alloc::borrow::Cow::Borrowed("4kv")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1."), alloc::borrow::Cow::Borrowed("2."), alloc::borrow::Cow::Borrowed("3."), alloc::borrow::Cow::Borrowed("4.")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1. kvartal"), alloc::borrow::Cow::Borrowed("2. kvartal"), alloc::borrow::Cow::Borrowed("3. kvartal"), alloc::borrow::Cow::Borrowed("4. kvartal")]) }, stand_alone: Some(icu::datetime::provider::calendar::quarters::StandAloneWidthsV1 { abbreviated: Some(icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1. kv."), alloc::borrow::Cow::Borrowed("2. kv."), alloc::borrow::Cow::Borrowed("3. kv."), alloc::borrow::Cow::Borrowed("4. kv.")])), narrow: None, short: None, wide: None }) } };
+                static TK: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1Ç"), alloc::borrow::Cow::Borrowed("2Ç"), alloc::borrow::Cow::Borrowed("3Ç"), alloc::borrow::Cow::Borrowed("4Ç")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1-nji çärýek"), alloc::borrow::Cow::Borrowed("2-nji çärýek"), alloc::borrow::Cow::Borrowed("3-nji çärýek"), alloc::borrow::Cow::Borrowed("4-nji çärýek")]) }, stand_alone: None } };
+                static OR: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1ମ ତ\u{b4d}ରୟମ\u{b3e}ସ"), alloc::borrow::Cow::Borrowed("2ୟ ତ\u{b4d}ରୟମ\u{b3e}ସ"), alloc::borrow::Cow::Borrowed("3ୟ ତ\u{b4d}ରୟମ\u{b3e}ସ"), alloc::borrow::Cow::Borrowed("4ର\u{b4d}ଥ ତ\u{b4d}ରୟମ\u{b3e}ସ")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1ମ ତ\u{b4d}ରୟମ\u{b3e}ସ"), alloc::borrow::Cow::Borrowed("2ୟ ତ\u{b4d}ରୟମ\u{b3e}ସ"), alloc::borrow::Cow::Borrowed("3ୟ ତ\u{b4d}ରୟମ\u{b3e}ସ"), alloc::borrow::Cow::Borrowed("4ର\u{b4d}ଥ ତ\u{b4d}ରୟମ\u{b3e}ସ")]) }, stand_alone: Some(icu::datetime::provider::calendar::quarters::StandAloneWidthsV1 { abbreviated: Some(icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("Q1"), alloc::borrow::Cow::Borrowed("Q2"), alloc::borrow::Cow::Borrowed("Q3"), alloc::borrow::Cow::Borrowed("Q4")])), narrow: None, short: None, wide: None }) } };
+                static ZH: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1季度"), alloc::borrow::Cow::Borrowed("2季度"), alloc::borrow::Cow::Borrowed("3季度"), alloc::borrow::Cow::Borrowed("4季度")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("第一季度"), alloc::borrow::Cow::Borrowed("第二季度"), alloc::borrow::Cow::Borrowed("第三季度"), alloc::borrow::Cow::Borrowed("第四季度")]) }, stand_alone: None } };
+                static KO: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1분기"), alloc::borrow::Cow::Borrowed("2분기"), alloc::borrow::Cow::Borrowed("3분기"), alloc::borrow::Cow::Borrowed("4분기")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("제 1/4분기"), alloc::borrow::Cow::Borrowed("제 2/4분기"), alloc::borrow::Cow::Borrowed("제 3/4분기"), alloc::borrow::Cow::Borrowed("제 4/4분기")]) }, stand_alone: None } };
+                static GD: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("C1"), alloc::borrow::Cow::Borrowed("C2"), alloc::borrow::Cow::Borrowed("C3"), alloc::borrow::Cow::Borrowed("C4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1d chairteal"), alloc::borrow::Cow::Borrowed("2na cairteal"), alloc::borrow::Cow::Borrowed("3s cairteal"), alloc::borrow::Cow::Borrowed("4mh cairteal")]) }, stand_alone: None } };
+                static CY: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("Ch1"), alloc::borrow::Cow::Borrowed("Ch2"), alloc::borrow::Cow::Borrowed("Ch3"), alloc::borrow::Cow::Borrowed("Ch4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("chwarter 1af"), alloc::borrow::Cow::Borrowed("2il chwarter"), alloc::borrow::Cow::Borrowed("3ydd chwarter"), alloc::borrow::Cow::Borrowed("4ydd chwarter")]) }, stand_alone: None } };
+                static IS: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("F1"), alloc::borrow::Cow::Borrowed("F2"), alloc::borrow::Cow::Borrowed("F3"), alloc::borrow::Cow::Borrowed("F4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1. fjórðungur"), alloc::borrow::Cow::Borrowed("2. fjórðungur"), alloc::borrow::Cow::Borrowed("3. fjórðungur"), alloc::borrow::Cow::Borrowed("4. fjórðungur")]) }, stand_alone: None } };
+                static MI: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("HW1"), alloc::borrow::Cow::Borrowed("HW2"), alloc::borrow::Cow::Borrowed("HW3"), alloc::borrow::Cow::Borrowed("HW4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("Hauwhā tuatahi"), alloc::borrow::Cow::Borrowed("Hauwhā tuarua"), alloc::borrow::Cow::Borrowed("Hauwhā tuatoru"), alloc::borrow::Cow::Borrowed("Hauwhā tuawhā")]) }, stand_alone: None } };
+                static LT: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("I k."), alloc::borrow::Cow::Borrowed("II k."), alloc::borrow::Cow::Borrowed("III k."), alloc::borrow::Cow::Borrowed("IV k.")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("I ketvirtis"), alloc::borrow::Cow::Borrowed("II ketvirtis"), alloc::borrow::Cow::Borrowed("III ketvirtis"), alloc::borrow::Cow::Borrowed("IV ketvirtis")]) }, stand_alone: Some(icu::datetime::provider::calendar::quarters::StandAloneWidthsV1 { abbreviated: Some(icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("I ketv."), alloc::borrow::Cow::Borrowed("II ketv."), alloc::borrow::Cow::Borrowed("III ketv."), alloc::borrow::Cow::Borrowed("IV ketv.")])), narrow: None, short: None, wide: None }) } };
+                static PL: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("I kw."), alloc::borrow::Cow::Borrowed("II kw."), alloc::borrow::Cow::Borrowed("III kw."), alloc::borrow::Cow::Borrowed("IV kw.")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("I kwartał"), alloc::borrow::Cow::Borrowed("II kwartał"), alloc::borrow::Cow::Borrowed("III kwartał"), alloc::borrow::Cow::Borrowed("IV kwartał")]) }, stand_alone: None } };
+                static MN: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("I улирал"), alloc::borrow::Cow::Borrowed("II улирал"), alloc::borrow::Cow::Borrowed("III улирал"), alloc::borrow::Cow::Borrowed("IV улирал")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("I"), alloc::borrow::Cow::Borrowed("II"), alloc::borrow::Cow::Borrowed("III"), alloc::borrow::Cow::Borrowed("IV")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1-р улирал"), alloc::borrow::Cow::Borrowed("2-р улирал"), alloc::borrow::Cow::Borrowed("3-р улирал"), alloc::borrow::Cow::Borrowed("4-р улирал")]) }, stand_alone: None } };
+                static KA: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("I კვ."), alloc::borrow::Cow::Borrowed("II კვ."), alloc::borrow::Cow::Borrowed("III კვ."), alloc::borrow::Cow::Borrowed("IV კვ.")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("I კვარტალი"), alloc::borrow::Cow::Borrowed("II კვარტალი"), alloc::borrow::Cow::Borrowed("III კვარტალი"), alloc::borrow::Cow::Borrowed("IV კვარტალი")]) }, stand_alone: None } };
+                static HU: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("I. n.év"), alloc::borrow::Cow::Borrowed("II. n.év"), alloc::borrow::Cow::Borrowed("III. n.év"), alloc::borrow::Cow::Borrowed("IV. n.év")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("I."), alloc::borrow::Cow::Borrowed("II."), alloc::borrow::Cow::Borrowed("III."), alloc::borrow::Cow::Borrowed("IV.")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("I. negyedév"), alloc::borrow::Cow::Borrowed("II. negyedév"), alloc::borrow::Cow::Borrowed("III. negyedév"), alloc::borrow::Cow::Borrowed("IV. negyedév")]) }, stand_alone: Some(icu::datetime::provider::calendar::quarters::StandAloneWidthsV1 { abbreviated: Some(icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1. n.év"), alloc::borrow::Cow::Borrowed("2. n.év"), alloc::borrow::Cow::Borrowed("3. n.év"), alloc::borrow::Cow::Borrowed("4. n.év")])), narrow: Some(icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1."), alloc::borrow::Cow::Borrowed("2."), alloc::borrow::Cow::Borrowed("3."), alloc::borrow::Cow::Borrowed("4.")])), short: None, wide: Some(icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1. negyedév"), alloc::borrow::Cow::Borrowed("2. negyedév"), alloc::borrow::Cow::Borrowed("3. negyedév"), alloc::borrow::Cow::Borrowed("4. negyedév")])) }) } };
+                static NN: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("K1"), alloc::borrow::Cow::Borrowed("K2"), alloc::borrow::Cow::Borrowed("K3"), alloc::borrow::Cow::Borrowed("K4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1. kvartal"), alloc::borrow::Cow::Borrowed("2. kvartal"), alloc::borrow::Cow::Borrowed("3. kvartal"), alloc::borrow::Cow::Borrowed("4. kvartal")]) }, stand_alone: None } };
+                static ET: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("K1"), alloc::borrow::Cow::Borrowed("K2"), alloc::borrow::Cow::Borrowed("K3"), alloc::borrow::Cow::Borrowed("K4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1. kvartal"), alloc::borrow::Cow::Borrowed("2. kvartal"), alloc::borrow::Cow::Borrowed("3. kvartal"), alloc::borrow::Cow::Borrowed("4. kvartal")]) }, stand_alone: Some(icu::datetime::provider::calendar::quarters::StandAloneWidthsV1 { abbreviated: None, narrow: Some(icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1."), alloc::borrow::Cow::Borrowed("2."), alloc::borrow::Cow::Borrowed("3."), alloc::borrow::Cow::Borrowed("4.")])), short: None, wide: None }) } };
+                static SV: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("K1"), alloc::borrow::Cow::Borrowed("K2"), alloc::borrow::Cow::Borrowed("K3"), alloc::borrow::Cow::Borrowed("K4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1:a kvartalet"), alloc::borrow::Cow::Borrowed("2:a kvartalet"), alloc::borrow::Cow::Borrowed("3:e kvartalet"), alloc::borrow::Cow::Borrowed("4:e kvartalet")]) }, stand_alone: None } };
+                static NL: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("K1"), alloc::borrow::Cow::Borrowed("K2"), alloc::borrow::Cow::Borrowed("K3"), alloc::borrow::Cow::Borrowed("K4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1e kwartaal"), alloc::borrow::Cow::Borrowed("2e kwartaal"), alloc::borrow::Cow::Borrowed("3e kwartaal"), alloc::borrow::Cow::Borrowed("4e kwartaal")]) }, stand_alone: None } };
+                static AF: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("K1"), alloc::borrow::Cow::Borrowed("K2"), alloc::borrow::Cow::Borrowed("K3"), alloc::borrow::Cow::Borrowed("K4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1ste kwartaal"), alloc::borrow::Cow::Borrowed("2de kwartaal"), alloc::borrow::Cow::Borrowed("3de kwartaal"), alloc::borrow::Cow::Borrowed("4de kwartaal")]) }, stand_alone: None } };
+                static PCM: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("K1"), alloc::borrow::Cow::Borrowed("K2"), alloc::borrow::Cow::Borrowed("K3"), alloc::borrow::Cow::Borrowed("K4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("Fẹ\u{301}st Kwọ\u{301}ta"), alloc::borrow::Cow::Borrowed("Sẹ\u{301}kọ\u{301}n Kwọ\u{301}ta"), alloc::borrow::Cow::Borrowed("Tọ\u{301}d Kwọ\u{301}ta"), alloc::borrow::Cow::Borrowed("Fọ\u{301}t Kwọ\u{301}ta")]) }, stand_alone: None } };
+                static ID: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("K1"), alloc::borrow::Cow::Borrowed("K2"), alloc::borrow::Cow::Borrowed("K3"), alloc::borrow::Cow::Borrowed("K4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("Kuartal ke-1"), alloc::borrow::Cow::Borrowed("Kuartal ke-2"), alloc::borrow::Cow::Borrowed("Kuartal ke-3"), alloc::borrow::Cow::Borrowed("Kuartal ke-4")]) }, stand_alone: None } };
+                static HA: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("K1"), alloc::borrow::Cow::Borrowed("K2"), alloc::borrow::Cow::Borrowed("K3"), alloc::borrow::Cow::Borrowed("K4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("Kwata na ɗaya"), alloc::borrow::Cow::Borrowed("Kwata na biyu"), alloc::borrow::Cow::Borrowed("Kwata na uku"), alloc::borrow::Cow::Borrowed("Kwata na huɗu")]) }, stand_alone: None } };
+                static SU: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("K1"), alloc::borrow::Cow::Borrowed("K2"), alloc::borrow::Cow::Borrowed("K3"), alloc::borrow::Cow::Borrowed("K4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("kuartal ka-1"), alloc::borrow::Cow::Borrowed("kuartal ka-2"), alloc::borrow::Cow::Borrowed("kuartal ka-3"), alloc::borrow::Cow::Borrowed("kuartal ka-4")]) }, stand_alone: Some(icu::datetime::provider::calendar::quarters::StandAloneWidthsV1 { abbreviated: None, narrow: None, short: None, wide: Some(icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("kuartal ka-1"), alloc::borrow::Cow::Borrowed("kuartal ka-2"), alloc::borrow::Cow::Borrowed("kuartal ka-3"), alloc::borrow::Cow::Borrowed("kuartal-ka 4")])) }) } };
+                static TO: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("K1"), alloc::borrow::Cow::Borrowed("K2"), alloc::borrow::Cow::Borrowed("K3"), alloc::borrow::Cow::Borrowed("K4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("kuata ʻuluaki"), alloc::borrow::Cow::Borrowed("kuata ua"), alloc::borrow::Cow::Borrowed("kuata tolu"), alloc::borrow::Cow::Borrowed("kuata fā")]) }, stand_alone: Some(icu::datetime::provider::calendar::quarters::StandAloneWidthsV1 { abbreviated: None, narrow: None, short: None, wide: Some(icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("kuata 1"), alloc::borrow::Cow::Borrowed("kuata 2"), alloc::borrow::Cow::Borrowed("kuata 3"), alloc::borrow::Cow::Borrowed("kuata 4")])) }) } };
+                static NO: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("K1"), alloc::borrow::Cow::Borrowed("K2"), alloc::borrow::Cow::Borrowed("K3"), alloc::borrow::Cow::Borrowed("K4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1."), alloc::borrow::Cow::Borrowed("2."), alloc::borrow::Cow::Borrowed("3."), alloc::borrow::Cow::Borrowed("4.")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1. kvartal"), alloc::borrow::Cow::Borrowed("2. kvartal"), alloc::borrow::Cow::Borrowed("3. kvartal"), alloc::borrow::Cow::Borrowed("4. kvartal")]) }, stand_alone: None } };
+                static BS: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("KV1"), alloc::borrow::Cow::Borrowed("KV2"), alloc::borrow::Cow::Borrowed("KV3"), alloc::borrow::Cow::Borrowed("KV4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1."), alloc::borrow::Cow::Borrowed("2."), alloc::borrow::Cow::Borrowed("3."), alloc::borrow::Cow::Borrowed("4.")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("Prvi kvartal"), alloc::borrow::Cow::Borrowed("Drugi kvartal"), alloc::borrow::Cow::Borrowed("Treći kvartal"), alloc::borrow::Cow::Borrowed("Četvrti kvartal")]) }, stand_alone: None } };
+                static XH: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("Kota 1"), alloc::borrow::Cow::Borrowed("Kota 2"), alloc::borrow::Cow::Borrowed("Kota 3"), alloc::borrow::Cow::Borrowed("Kota 4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("ikota yoku-1"), alloc::borrow::Cow::Borrowed("ikota yesi-2"), alloc::borrow::Cow::Borrowed("ikota yesi-3"), alloc::borrow::Cow::Borrowed("ikota yesi-4")]) }, stand_alone: None } };
+                static YRL: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("M1"), alloc::borrow::Cow::Borrowed("M2"), alloc::borrow::Cow::Borrowed("M3"), alloc::borrow::Cow::Borrowed("M4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("yepésáwa musapíri-yasí"), alloc::borrow::Cow::Borrowed("mukũisawa musapíri-yasí"), alloc::borrow::Cow::Borrowed("musapírisawa musapíri-yasí"), alloc::borrow::Cow::Borrowed("irũdisawa musapíri-yasí")]) }, stand_alone: None } };
+                static DE: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("Q1"), alloc::borrow::Cow::Borrowed("Q2"), alloc::borrow::Cow::Borrowed("Q3"), alloc::borrow::Cow::Borrowed("Q4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1. Quartal"), alloc::borrow::Cow::Borrowed("2. Quartal"), alloc::borrow::Cow::Borrowed("3. Quartal"), alloc::borrow::Cow::Borrowed("4. Quartal")]) }, stand_alone: None } };
+                static RM: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("Q1"), alloc::borrow::Cow::Borrowed("Q2"), alloc::borrow::Cow::Borrowed("Q3"), alloc::borrow::Cow::Borrowed("Q4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1. quartal"), alloc::borrow::Cow::Borrowed("2. quartal"), alloc::borrow::Cow::Borrowed("3. quartal"), alloc::borrow::Cow::Borrowed("4. quartal")]) }, stand_alone: None } };
+                static CS: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("Q1"), alloc::borrow::Cow::Borrowed("Q2"), alloc::borrow::Cow::Borrowed("Q3"), alloc::borrow::Cow::Borrowed("Q4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1. čtvrtletí"), alloc::borrow::Cow::Borrowed("2. čtvrtletí"), alloc::borrow::Cow::Borrowed("3. čtvrtletí"), alloc::borrow::Cow::Borrowed("4. čtvrtletí")]) }, stand_alone: None } };
+                static SK: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("Q1"), alloc::borrow::Cow::Borrowed("Q2"), alloc::borrow::Cow::Borrowed("Q3"), alloc::borrow::Cow::Borrowed("Q4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1. štvrťrok"), alloc::borrow::Cow::Borrowed("2. štvrťrok"), alloc::borrow::Cow::Borrowed("3. štvrťrok"), alloc::borrow::Cow::Borrowed("4. štvrťrok")]) }, stand_alone: None } };
+                static ES_DO: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("Q1"), alloc::borrow::Cow::Borrowed("Q2"), alloc::borrow::Cow::Borrowed("Q3"), alloc::borrow::Cow::Borrowed("Q4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1.º trimestre"), alloc::borrow::Cow::Borrowed("2.º trimestre"), alloc::borrow::Cow::Borrowed("3.º trimestre"), alloc::borrow::Cow::Borrowed("4.º trimestre")]) }, stand_alone: None } };
+                static SV_FI: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("Q1"), alloc::borrow::Cow::Borrowed("Q2"), alloc::borrow::Cow::Borrowed("Q3"), alloc::borrow::Cow::Borrowed("Q4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1:a kvartalet"), alloc::borrow::Cow::Borrowed("2:a kvartalet"), alloc::borrow::Cow::Borrowed("3:e kvartalet"), alloc::borrow::Cow::Borrowed("4:e kvartalet")]) }, stand_alone: None } };
+                static EN: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("Q1"), alloc::borrow::Cow::Borrowed("Q2"), alloc::borrow::Cow::Borrowed("Q3"), alloc::borrow::Cow::Borrowed("Q4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1st quarter"), alloc::borrow::Cow::Borrowed("2nd quarter"), alloc::borrow::Cow::Borrowed("3rd quarter"), alloc::borrow::Cow::Borrowed("4th quarter")]) }, stand_alone: None } };
+                static KS_DEVA: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("Q1"), alloc::borrow::Cow::Borrowed("Q2"), alloc::borrow::Cow::Borrowed("Q3"), alloc::borrow::Cow::Borrowed("Q4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1st सह माह"), alloc::borrow::Cow::Borrowed("2nd सह माह"), alloc::borrow::Cow::Borrowed("3rd सह माह"), alloc::borrow::Cow::Borrowed("4th सह माह")]) }, stand_alone: None } };
+                static CHR: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("Q1"), alloc::borrow::Cow::Borrowed("Q2"), alloc::borrow::Cow::Borrowed("Q3"), alloc::borrow::Cow::Borrowed("Q4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1st ᎩᏄᏙᏗ"), alloc::borrow::Cow::Borrowed("2nd ᎩᏄᏙᏗ"), alloc::borrow::Cow::Borrowed("3rd ᎩᏄᏙᏗ"), alloc::borrow::Cow::Borrowed("4th ᎩᏄᏙᏗ")]) }, stand_alone: None } };
+                static KOK: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("Q1"), alloc::borrow::Cow::Borrowed("Q2"), alloc::borrow::Cow::Borrowed("Q3"), alloc::borrow::Cow::Borrowed("Q4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1ल\u{947}\u{902} त\u{94d}र\u{948}मासीक"), alloc::borrow::Cow::Borrowed("2र\u{947}\u{902} त\u{94d}र\u{948}मासीक"), alloc::borrow::Cow::Borrowed("3र\u{947}\u{902} त\u{94d}र\u{948}मासीक"), alloc::borrow::Cow::Borrowed("4थ\u{947}\u{902} त\u{94d}र\u{948}मासीक")]) }, stand_alone: None } };
+                static GU: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("Q1"), alloc::borrow::Cow::Borrowed("Q2"), alloc::borrow::Cow::Borrowed("Q3"), alloc::borrow::Cow::Borrowed("Q4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1લો ત\u{acd}રિમાસ"), alloc::borrow::Cow::Borrowed("2જો ત\u{acd}રિમાસ"), alloc::borrow::Cow::Borrowed("3જો ત\u{acd}રિમાસ"), alloc::borrow::Cow::Borrowed("4થો ત\u{acd}રિમાસ")]) }, stand_alone: None } };
+                static UND: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("Q1"), alloc::borrow::Cow::Borrowed("Q2"), alloc::borrow::Cow::Borrowed("Q3"), alloc::borrow::Cow::Borrowed("Q4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("Q1"), alloc::borrow::Cow::Borrowed("Q2"), alloc::borrow::Cow::Borrowed("Q3"), alloc::borrow::Cow::Borrowed("Q4")]) }, stand_alone: None } };
+                static VI: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("Q1"), alloc::borrow::Cow::Borrowed("Q2"), alloc::borrow::Cow::Borrowed("Q3"), alloc::borrow::Cow::Borrowed("Q4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("Quý 1"), alloc::borrow::Cow::Borrowed("Quý 2"), alloc::borrow::Cow::Borrowed("Quý 3"), alloc::borrow::Cow::Borrowed("Quý 4")]) }, stand_alone: Some(icu::datetime::provider::calendar::quarters::StandAloneWidthsV1 { abbreviated: None, narrow: None, short: None, wide: Some(icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("quý 1"), alloc::borrow::Cow::Borrowed("quý 2"), alloc::borrow::Cow::Borrowed("quý 3"), alloc::borrow::Cow::Borrowed("quý 4")])) }) } };
+                static FIL: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("Q1"), alloc::borrow::Cow::Borrowed("Q2"), alloc::borrow::Cow::Borrowed("Q3"), alloc::borrow::Cow::Borrowed("Q4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("ika-1 quarter"), alloc::borrow::Cow::Borrowed("ika-2 quarter"), alloc::borrow::Cow::Borrowed("ika-3 quarter"), alloc::borrow::Cow::Borrowed("ika-4 na quarter")]) }, stand_alone: None } };
+                static ZU: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("Q1"), alloc::borrow::Cow::Borrowed("Q2"), alloc::borrow::Cow::Borrowed("Q3"), alloc::borrow::Cow::Borrowed("Q4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("ikota yesi-1"), alloc::borrow::Cow::Borrowed("ikota yesi-2"), alloc::borrow::Cow::Borrowed("ikota yesi-3"), alloc::borrow::Cow::Borrowed("ikota yesi-4")]) }, stand_alone: None } };
+                static CEB: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("Q1"), alloc::borrow::Cow::Borrowed("Q2"), alloc::borrow::Cow::Borrowed("Q3"), alloc::borrow::Cow::Borrowed("Q4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("unang quarter"), alloc::borrow::Cow::Borrowed("ika-2 nga quarter"), alloc::borrow::Cow::Borrowed("ika-3 nga quarter"), alloc::borrow::Cow::Borrowed("ika-4 nga quarter")]) }, stand_alone: None } };
+                static HE: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("Q1"), alloc::borrow::Cow::Borrowed("Q2"), alloc::borrow::Cow::Borrowed("Q3"), alloc::borrow::Cow::Borrowed("Q4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("רבעון 1"), alloc::borrow::Cow::Borrowed("רבעון 2"), alloc::borrow::Cow::Borrowed("רבעון 3"), alloc::borrow::Cow::Borrowed("רבעון 4")]) }, stand_alone: None } };
+                static JA: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("Q1"), alloc::borrow::Cow::Borrowed("Q2"), alloc::borrow::Cow::Borrowed("Q3"), alloc::borrow::Cow::Borrowed("Q4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("第1四半期"), alloc::borrow::Cow::Borrowed("第2四半期"), alloc::borrow::Cow::Borrowed("第3四半期"), alloc::borrow::Cow::Borrowed("第4四半期")]) }, stand_alone: None } };
+                static ZH_HK: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("Q1"), alloc::borrow::Cow::Borrowed("Q2"), alloc::borrow::Cow::Borrowed("Q3"), alloc::borrow::Cow::Borrowed("Q4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("第1季"), alloc::borrow::Cow::Borrowed("第2季"), alloc::borrow::Cow::Borrowed("第3季"), alloc::borrow::Cow::Borrowed("第4季")]) }, stand_alone: None } };
+                static SD: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("Q1"), alloc::borrow::Cow::Borrowed("Q2"), alloc::borrow::Cow::Borrowed("Q3"), alloc::borrow::Cow::Borrowed("Q5")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("پهرين ٽي ماهي"), alloc::borrow::Cow::Borrowed("ٻين ٽي ماهي"), alloc::borrow::Cow::Borrowed("ٽين ٽي ماهي"), alloc::borrow::Cow::Borrowed("چوٿين ٽي ماهي")]) }, stand_alone: Some(icu::datetime::provider::calendar::quarters::StandAloneWidthsV1 { abbreviated: Some(icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("Q1"), alloc::borrow::Cow::Borrowed("Q2"), alloc::borrow::Cow::Borrowed("Q3"), alloc::borrow::Cow::Borrowed("Q4")])), narrow: None, short: None, wide: None }) } };
+                static GA: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("R1"), alloc::borrow::Cow::Borrowed("R2"), alloc::borrow::Cow::Borrowed("R3"), alloc::borrow::Cow::Borrowed("R4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1ú ráithe"), alloc::borrow::Cow::Borrowed("2ú ráithe"), alloc::borrow::Cow::Borrowed("3ú ráithe"), alloc::borrow::Cow::Borrowed("4ú ráithe")]) }, stand_alone: None } };
+                static SO: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("R1"), alloc::borrow::Cow::Borrowed("R2"), alloc::borrow::Cow::Borrowed("R3"), alloc::borrow::Cow::Borrowed("R4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("Rubaca 1aad"), alloc::borrow::Cow::Borrowed("Rubaca 2aad"), alloc::borrow::Cow::Borrowed("Rubaca 3aad"), alloc::borrow::Cow::Borrowed("Rubaca 4aad")]) }, stand_alone: None } };
+                static SW: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("Robo ya 1"), alloc::borrow::Cow::Borrowed("Robo ya 2"), alloc::borrow::Cow::Borrowed("Robo ya 3"), alloc::borrow::Cow::Borrowed("Robo ya 4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("Robo ya 1"), alloc::borrow::Cow::Borrowed("Robo ya 2"), alloc::borrow::Cow::Borrowed("Robo ya 3"), alloc::borrow::Cow::Borrowed("Robo ya 4")]) }, stand_alone: None } };
+                static MS: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("S1"), alloc::borrow::Cow::Borrowed("S2"), alloc::borrow::Cow::Borrowed("S3"), alloc::borrow::Cow::Borrowed("S4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("Suku pertama"), alloc::borrow::Cow::Borrowed("Suku Ke-2"), alloc::borrow::Cow::Borrowed("Suku Ke-3"), alloc::borrow::Cow::Borrowed("Suku Ke-4")]) }, stand_alone: None } };
+                static ES: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("T1"), alloc::borrow::Cow::Borrowed("T2"), alloc::borrow::Cow::Borrowed("T3"), alloc::borrow::Cow::Borrowed("T4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1.er trimestre"), alloc::borrow::Cow::Borrowed("2.º trimestre"), alloc::borrow::Cow::Borrowed("3.er trimestre"), alloc::borrow::Cow::Borrowed("4.º trimestre")]) }, stand_alone: None } };
+                static ES_MX: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("T1"), alloc::borrow::Cow::Borrowed("T2"), alloc::borrow::Cow::Borrowed("T3"), alloc::borrow::Cow::Borrowed("T4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1.er trimestre"), alloc::borrow::Cow::Borrowed("2.º trimestre"), alloc::borrow::Cow::Borrowed("3.er trimestre"), alloc::borrow::Cow::Borrowed("4.º trimestre")]) }, stand_alone: Some(icu::datetime::provider::calendar::quarters::StandAloneWidthsV1 { abbreviated: None, narrow: Some(icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1T"), alloc::borrow::Cow::Borrowed("2T"), alloc::borrow::Cow::Borrowed("3T"), alloc::borrow::Cow::Borrowed("4T")])), short: None, wide: None }) } };
+                static ES_419: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("T1"), alloc::borrow::Cow::Borrowed("T2"), alloc::borrow::Cow::Borrowed("T3"), alloc::borrow::Cow::Borrowed("T4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1.º trimestre"), alloc::borrow::Cow::Borrowed("2.º trimestre"), alloc::borrow::Cow::Borrowed("3.º trimestre"), alloc::borrow::Cow::Borrowed("4.º trimestre")]) }, stand_alone: None } };
+                static ES_CL: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("T1"), alloc::borrow::Cow::Borrowed("T2"), alloc::borrow::Cow::Borrowed("T3"), alloc::borrow::Cow::Borrowed("T4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1.º trimestre"), alloc::borrow::Cow::Borrowed("2.º trimestre"), alloc::borrow::Cow::Borrowed("3.º trimestre"), alloc::borrow::Cow::Borrowed("4.º trimestre")]) }, stand_alone: Some(icu::datetime::provider::calendar::quarters::StandAloneWidthsV1 { abbreviated: None, narrow: None, short: None, wide: Some(icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1.° trimestre"), alloc::borrow::Cow::Borrowed("2.° trimestre"), alloc::borrow::Cow::Borrowed("3.° trimestre"), alloc::borrow::Cow::Borrowed("4.º trimestre")])) }) } };
+                static ES_US: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("T1"), alloc::borrow::Cow::Borrowed("T2"), alloc::borrow::Cow::Borrowed("T3"), alloc::borrow::Cow::Borrowed("T4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1er trimestre"), alloc::borrow::Cow::Borrowed("2.º trimestre"), alloc::borrow::Cow::Borrowed("3.º trimestre"), alloc::borrow::Cow::Borrowed("4.º trimestre")]) }, stand_alone: None } };
+                static ES_VE: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("T1"), alloc::borrow::Cow::Borrowed("T2"), alloc::borrow::Cow::Borrowed("T3"), alloc::borrow::Cow::Borrowed("T4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1er trimestre"), alloc::borrow::Cow::Borrowed("2do trimestre"), alloc::borrow::Cow::Borrowed("3er trimestre"), alloc::borrow::Cow::Borrowed("4to trimestre")]) }, stand_alone: None } };
+                static FR: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("T1"), alloc::borrow::Cow::Borrowed("T2"), alloc::borrow::Cow::Borrowed("T3"), alloc::borrow::Cow::Borrowed("T4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1er trimestre"), alloc::borrow::Cow::Borrowed("2e trimestre"), alloc::borrow::Cow::Borrowed("3e trimestre"), alloc::borrow::Cow::Borrowed("4e trimestre")]) }, stand_alone: None } };
+                static ES_PA: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("T1"), alloc::borrow::Cow::Borrowed("T2"), alloc::borrow::Cow::Borrowed("T3"), alloc::borrow::Cow::Borrowed("T4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1er. trimestre"), alloc::borrow::Cow::Borrowed("2do. trimestre"), alloc::borrow::Cow::Borrowed("3er. trimestre"), alloc::borrow::Cow::Borrowed("4.º trimestre")]) }, stand_alone: None } };
+                static IA: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("T1"), alloc::borrow::Cow::Borrowed("T2"), alloc::borrow::Cow::Borrowed("T3"), alloc::borrow::Cow::Borrowed("T4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1me trimestre"), alloc::borrow::Cow::Borrowed("2nde trimestre"), alloc::borrow::Cow::Borrowed("3tie trimestre"), alloc::borrow::Cow::Borrowed("4te trimestre")]) }, stand_alone: None } };
+                static SC: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("T1"), alloc::borrow::Cow::Borrowed("T2"), alloc::borrow::Cow::Borrowed("T3"), alloc::borrow::Cow::Borrowed("T4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1u trimestre"), alloc::borrow::Cow::Borrowed("2u trimestre"), alloc::borrow::Cow::Borrowed("3u trimestre"), alloc::borrow::Cow::Borrowed("4u trimestre")]) }, stand_alone: None } };
+                static IT: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("T1"), alloc::borrow::Cow::Borrowed("T2"), alloc::borrow::Cow::Borrowed("T3"), alloc::borrow::Cow::Borrowed("T4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1º trimestre"), alloc::borrow::Cow::Borrowed("2º trimestre"), alloc::borrow::Cow::Borrowed("3º trimestre"), alloc::borrow::Cow::Borrowed("4º trimestre")]) }, stand_alone: None } };
+                static KEA: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("T1"), alloc::borrow::Cow::Borrowed("T2"), alloc::borrow::Cow::Borrowed("T3"), alloc::borrow::Cow::Borrowed("T4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1º trimestri"), alloc::borrow::Cow::Borrowed("2º trimestri"), alloc::borrow::Cow::Borrowed("3º trimestri"), alloc::borrow::Cow::Borrowed("4º trimestri")]) }, stand_alone: None } };
+                static KGP: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("T1"), alloc::borrow::Cow::Borrowed("T2"), alloc::borrow::Cow::Borrowed("T3"), alloc::borrow::Cow::Borrowed("T4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1ⁿ kysã-tẽgtũ"), alloc::borrow::Cow::Borrowed("2ᵍᵉ kysã-tẽgtũ"), alloc::borrow::Cow::Borrowed("3ⁿʰ kysã-tẽgtũ"), alloc::borrow::Cow::Borrowed("4ⁿ kysã-tẽgtũ")]) }, stand_alone: None } };
+                static FR_ML: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("T1"), alloc::borrow::Cow::Borrowed("T2"), alloc::borrow::Cow::Borrowed("T3"), alloc::borrow::Cow::Borrowed("T4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("le 1er trimestre"), alloc::borrow::Cow::Borrowed("le 2ème trimestre"), alloc::borrow::Cow::Borrowed("le 3ème trimestre"), alloc::borrow::Cow::Borrowed("le 4ème trimestre")]) }, stand_alone: Some(icu::datetime::provider::calendar::quarters::StandAloneWidthsV1 { abbreviated: None, narrow: None, short: None, wide: Some(icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1er trimestre"), alloc::borrow::Cow::Borrowed("2ème trimestre"), alloc::borrow::Cow::Borrowed("3ème trimestre"), alloc::borrow::Cow::Borrowed("4ème trimestre")])) }) } };
+                static JV: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("TW1"), alloc::borrow::Cow::Borrowed("TW2"), alloc::borrow::Cow::Borrowed("TW3"), alloc::borrow::Cow::Borrowed("TW4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("triwulan kaping pisan"), alloc::borrow::Cow::Borrowed("triwulan kaping loro"), alloc::borrow::Cow::Borrowed("triwulan kaping telu"), alloc::borrow::Cow::Borrowed("triwulan kaping papat")]) }, stand_alone: None } };
+                static DSB: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("kw1"), alloc::borrow::Cow::Borrowed("kw2"), alloc::borrow::Cow::Borrowed("kw3"), alloc::borrow::Cow::Borrowed("kw4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1. kwartal"), alloc::borrow::Cow::Borrowed("2. kwartal"), alloc::borrow::Cow::Borrowed("3. kwartal"), alloc::borrow::Cow::Borrowed("4. kwartal")]) }, stand_alone: None } };
+                static SQ: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("tremujori I"), alloc::borrow::Cow::Borrowed("tremujori II"), alloc::borrow::Cow::Borrowed("tremujori III"), alloc::borrow::Cow::Borrowed("tremujori IV")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("tremujori i parë"), alloc::borrow::Cow::Borrowed("tremujori i dytë"), alloc::borrow::Cow::Borrowed("tremujori i tretë"), alloc::borrow::Cow::Borrowed("tremujori i katërt")]) }, stand_alone: Some(icu::datetime::provider::calendar::quarters::StandAloneWidthsV1 { abbreviated: Some(icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("Tremujori I"), alloc::borrow::Cow::Borrowed("Tremujori II"), alloc::borrow::Cow::Borrowed("Tremujori III"), alloc::borrow::Cow::Borrowed("Tremujori IV")])), narrow: None, short: None, wide: Some(icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("Tremujori i 1-rë"), alloc::borrow::Cow::Borrowed("Tremujori i 2-të"), alloc::borrow::Cow::Borrowed("Tremujori i 3-të"), alloc::borrow::Cow::Borrowed("Tremujori i 4-t")])) }) } };
+                static RO_MD: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("trim. 1"), alloc::borrow::Cow::Borrowed("trim. 2"), alloc::borrow::Cow::Borrowed("trim. 3"), alloc::borrow::Cow::Borrowed("trim. 4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("I"), alloc::borrow::Cow::Borrowed("II"), alloc::borrow::Cow::Borrowed("III"), alloc::borrow::Cow::Borrowed("IV")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("trimestrul 1"), alloc::borrow::Cow::Borrowed("trimestrul 2"), alloc::borrow::Cow::Borrowed("trimestrul 3"), alloc::borrow::Cow::Borrowed("trimestrul 4")]) }, stand_alone: Some(icu::datetime::provider::calendar::quarters::StandAloneWidthsV1 { abbreviated: Some(icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("Trim. 1"), alloc::borrow::Cow::Borrowed("Trim. 2"), alloc::borrow::Cow::Borrowed("Trim. 3"), alloc::borrow::Cow::Borrowed("Trim. 4")])), narrow: None, short: None, wide: Some(icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("Trimestrul 1"), alloc::borrow::Cow::Borrowed("Trimestrul 2"), alloc::borrow::Cow::Borrowed("Trimestrul 3"), alloc::borrow::Cow::Borrowed("Trimestrul 4")])) }) } };
+                static RO: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("trim. I"), alloc::borrow::Cow::Borrowed("trim. II"), alloc::borrow::Cow::Borrowed("trim. III"), alloc::borrow::Cow::Borrowed("trim. IV")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("I"), alloc::borrow::Cow::Borrowed("II"), alloc::borrow::Cow::Borrowed("III"), alloc::borrow::Cow::Borrowed("IV")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("trimestrul I"), alloc::borrow::Cow::Borrowed("trimestrul al II-lea"), alloc::borrow::Cow::Borrowed("trimestrul al III-lea"), alloc::borrow::Cow::Borrowed("trimestrul al IV-lea")]) }, stand_alone: None } };
+                static TR: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("Ç1"), alloc::borrow::Cow::Borrowed("Ç2"), alloc::borrow::Cow::Borrowed("Ç3"), alloc::borrow::Cow::Borrowed("Ç4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1."), alloc::borrow::Cow::Borrowed("2."), alloc::borrow::Cow::Borrowed("3."), alloc::borrow::Cow::Borrowed("4.")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1. çeyrek"), alloc::borrow::Cow::Borrowed("2. çeyrek"), alloc::borrow::Cow::Borrowed("3. çeyrek"), alloc::borrow::Cow::Borrowed("4. çeyrek")]) }, stand_alone: None } };
+                static YO_BJ: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("Ìdámɛ\u{301}rin kíní"), alloc::borrow::Cow::Borrowed("Ìdámɛ\u{301}rin Kejì"), alloc::borrow::Cow::Borrowed("Ìdámɛ\u{301}rin Kɛta"), alloc::borrow::Cow::Borrowed("Ìdámɛ\u{301}rin Kɛrin")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("kíní"), alloc::borrow::Cow::Borrowed("Kejì"), alloc::borrow::Cow::Borrowed("Kɛta"), alloc::borrow::Cow::Borrowed("Kɛin")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("Ìdámɛ\u{301}rin kíní"), alloc::borrow::Cow::Borrowed("Ìdámɛ\u{301}rin Kejì"), alloc::borrow::Cow::Borrowed("Ìdámɛ\u{301}rin Kɛta"), alloc::borrow::Cow::Borrowed("Ìdámɛ\u{301}rin Kɛrin")]) }, stand_alone: Some(icu::datetime::provider::calendar::quarters::StandAloneWidthsV1 { abbreviated: None, narrow: Some(icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("kí"), alloc::borrow::Cow::Borrowed("Ke"), alloc::borrow::Cow::Borrowed("Kɛt"), alloc::borrow::Cow::Borrowed("Kɛr")])), short: None, wide: None }) } };
+                static YO: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("Ìdámẹ\u{301}rin kíní"), alloc::borrow::Cow::Borrowed("Ìdámẹ\u{301}rin Kejì"), alloc::borrow::Cow::Borrowed("Ìdámẹ\u{301}rin Kẹta"), alloc::borrow::Cow::Borrowed("Ìdámẹ\u{301}rin Kẹrin")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("kíní"), alloc::borrow::Cow::Borrowed("Kejì"), alloc::borrow::Cow::Borrowed("Kẹta"), alloc::borrow::Cow::Borrowed("Kẹin")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("Ìdámẹ\u{301}rin kíní"), alloc::borrow::Cow::Borrowed("Ìdámẹ\u{301}rin Kejì"), alloc::borrow::Cow::Borrowed("Ìdámẹ\u{301}rin Kẹta"), alloc::borrow::Cow::Borrowed("Ìdámẹ\u{301}rin Kẹrin")]) }, stand_alone: Some(icu::datetime::provider::calendar::quarters::StandAloneWidthsV1 { abbreviated: None, narrow: Some(icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("kí"), alloc::borrow::Cow::Borrowed("Ke"), alloc::borrow::Cow::Borrowed("Kẹt"), alloc::borrow::Cow::Borrowed("Kẹr")])), short: None, wide: None }) } };
+                static EL: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("Τ1"), alloc::borrow::Cow::Borrowed("Τ2"), alloc::borrow::Cow::Borrowed("Τ3"), alloc::borrow::Cow::Borrowed("Τ4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1ο τρίμηνο"), alloc::borrow::Cow::Borrowed("2ο τρίμηνο"), alloc::borrow::Cow::Borrowed("3ο τρίμηνο"), alloc::borrow::Cow::Borrowed("4ο τρίμηνο")]) }, stand_alone: None } };
+                static KK: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("І тқс."), alloc::borrow::Cow::Borrowed("ІІ тқс."), alloc::borrow::Cow::Borrowed("ІІІ тқс."), alloc::borrow::Cow::Borrowed("IV тқс.")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("I"), alloc::borrow::Cow::Borrowed("II"), alloc::borrow::Cow::Borrowed("III"), alloc::borrow::Cow::Borrowed("IV")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("І тоқсан"), alloc::borrow::Cow::Borrowed("ІІ тоқсан"), alloc::borrow::Cow::Borrowed("ІІІ тоқсан"), alloc::borrow::Cow::Borrowed("IV тоқсан")]) }, stand_alone: None } };
+                static BS_CYRL: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("К1"), alloc::borrow::Cow::Borrowed("К2"), alloc::borrow::Cow::Borrowed("К3"), alloc::borrow::Cow::Borrowed("К4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1."), alloc::borrow::Cow::Borrowed("2."), alloc::borrow::Cow::Borrowed("3."), alloc::borrow::Cow::Borrowed("4.")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("Прво тромесечје"), alloc::borrow::Cow::Borrowed("Друго тромесечје"), alloc::borrow::Cow::Borrowed("Треће тромесечје"), alloc::borrow::Cow::Borrowed("Четврто тромесечје")]) }, stand_alone: None } };
+                static TG: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("Ч1"), alloc::borrow::Cow::Borrowed("Ч2"), alloc::borrow::Cow::Borrowed("Ч3"), alloc::borrow::Cow::Borrowed("Ч4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("Ч1"), alloc::borrow::Cow::Borrowed("Ч2"), alloc::borrow::Cow::Borrowed("Ч3"), alloc::borrow::Cow::Borrowed("Ч4")]) }, stand_alone: None } };
+                static MK: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("јан – мар"), alloc::borrow::Cow::Borrowed("апр – јун"), alloc::borrow::Cow::Borrowed("јул – септ"), alloc::borrow::Cow::Borrowed("окт – дек")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("прво тримесечје"), alloc::borrow::Cow::Borrowed("второ тримесечје"), alloc::borrow::Cow::Borrowed("трето тримесечје"), alloc::borrow::Cow::Borrowed("четврто тримесечје")]) }, stand_alone: None } };
+                static AR: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("الربع الأول"), alloc::borrow::Cow::Borrowed("الربع الثاني"), alloc::borrow::Cow::Borrowed("الربع الثالث"), alloc::borrow::Cow::Borrowed("الربع الرابع")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("١"), alloc::borrow::Cow::Borrowed("٢"), alloc::borrow::Cow::Borrowed("٣"), alloc::borrow::Cow::Borrowed("٤")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("الربع الأول"), alloc::borrow::Cow::Borrowed("الربع الثاني"), alloc::borrow::Cow::Borrowed("الربع الثالث"), alloc::borrow::Cow::Borrowed("الربع الرابع")]) }, stand_alone: None } };
+                static FA_AF: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("ر۱"), alloc::borrow::Cow::Borrowed("ر۲"), alloc::borrow::Cow::Borrowed("ر۳"), alloc::borrow::Cow::Borrowed("ر۴")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("۱"), alloc::borrow::Cow::Borrowed("۲"), alloc::borrow::Cow::Borrowed("۳"), alloc::borrow::Cow::Borrowed("۴")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("ربع اول"), alloc::borrow::Cow::Borrowed("ربع دوم"), alloc::borrow::Cow::Borrowed("ربع سوم"), alloc::borrow::Cow::Borrowed("ربع چهارم")]) }, stand_alone: None } };
+                static FA: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("س\u{200c}م۱"), alloc::borrow::Cow::Borrowed("س\u{200c}م۲"), alloc::borrow::Cow::Borrowed("س\u{200c}م۳"), alloc::borrow::Cow::Borrowed("س\u{200c}م۴")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("۱"), alloc::borrow::Cow::Borrowed("۲"), alloc::borrow::Cow::Borrowed("۳"), alloc::borrow::Cow::Borrowed("۴")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("سه\u{200c}ماهه\u{654} اول"), alloc::borrow::Cow::Borrowed("سه\u{200c}ماهه\u{654} دوم"), alloc::borrow::Cow::Borrowed("سه\u{200c}ماهه\u{654} سوم"), alloc::borrow::Cow::Borrowed("سه\u{200c}ماهه\u{654} چهارم")]) }, stand_alone: None } };
+                static PS: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("لومړۍ ربعه"), alloc::borrow::Cow::Borrowed("۲مه ربعه"), alloc::borrow::Cow::Borrowed("۳مه ربعه"), alloc::borrow::Cow::Borrowed("۴مه ربعه")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("لومړۍ ربعه"), alloc::borrow::Cow::Borrowed("۲مه ربعه"), alloc::borrow::Cow::Borrowed("۳مه ربعه"), alloc::borrow::Cow::Borrowed("۴مه ربعه")]) }, stand_alone: None } };
+                static UR: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("پہلی سہ ماہی"), alloc::borrow::Cow::Borrowed("دوسری سہ ماہی"), alloc::borrow::Cow::Borrowed("تیسری سہ ماہی"), alloc::borrow::Cow::Borrowed("چوتهی سہ ماہی")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("پہلی سہ ماہی"), alloc::borrow::Cow::Borrowed("دوسری سہ ماہی"), alloc::borrow::Cow::Borrowed("تیسری سہ ماہی"), alloc::borrow::Cow::Borrowed("چوتهی سہ ماہی")]) }, stand_alone: None } };
+                static KS: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("کیو 1"), alloc::borrow::Cow::Borrowed("کیو 2"), alloc::borrow::Cow::Borrowed("کیو 3"), alloc::borrow::Cow::Borrowed("کیو 4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("گۄڑنی\u{64f}ک ژۄباگ"), alloc::borrow::Cow::Borrowed("دۆی\u{650}م ژۄباگ"), alloc::borrow::Cow::Borrowed("تری\u{650}م ژۄباگ"), alloc::borrow::Cow::Borrowed("ژو\u{657}ر\u{650}م ژۄباگ")]) }, stand_alone: None } };
+                static BRX: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("किउ 1"), alloc::borrow::Cow::Borrowed("किउ 2"), alloc::borrow::Cow::Borrowed("किउ 3"), alloc::borrow::Cow::Borrowed("किउ 4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("ब\u{94d}र\u{948} बाहागोनि स\u{947}थि खोन\u{94d}दो"), alloc::borrow::Cow::Borrowed("ब\u{94d}र\u{948} बाहागोनि न\u{948}थि खोन\u{94d}दो"), alloc::borrow::Cow::Borrowed("ब\u{94d}र\u{948} बाहागोनि थामथि खोन\u{94d}दो"), alloc::borrow::Cow::Borrowed("ब\u{94d}र\u{948} बाहागोनि ब\u{94d}र\u{948}थि खोन\u{94d}दो")]) }, stand_alone: None } };
+                static DOI: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("त\u{94d}र.1"), alloc::borrow::Cow::Borrowed("त\u{94d}र.2"), alloc::borrow::Cow::Borrowed("त\u{94d}र.3"), alloc::borrow::Cow::Borrowed("त\u{94d}र.4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("प\u{948}हली त\u{94d}रमाही"), alloc::borrow::Cow::Borrowed("द\u{942}ई त\u{94d}रमाही"), alloc::borrow::Cow::Borrowed("त\u{94d}री त\u{94d}रमाही"), alloc::borrow::Cow::Borrowed("चौथी त\u{94d}रमाही")]) }, stand_alone: None } };
+                static SA: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("त\u{94d}र\u{948}मासिक1"), alloc::borrow::Cow::Borrowed("त\u{94d}र\u{948}मासिक2"), alloc::borrow::Cow::Borrowed("त\u{94d}र\u{948}मासिक3"), alloc::borrow::Cow::Borrowed("त\u{94d}र\u{948}मासिक4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("प\u{94d}रथम त\u{94d}र\u{948}मासिक"), alloc::borrow::Cow::Borrowed("द\u{94d}वितीय त\u{94d}र\u{948}मासिक"), alloc::borrow::Cow::Borrowed("त\u{943}तीय त\u{94d}र\u{948}मासिक"), alloc::borrow::Cow::Borrowed("चत\u{941}र\u{94d}थ त\u{94d}र\u{948}मासिक")]) }, stand_alone: None } };
+                static HI: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("ति1"), alloc::borrow::Cow::Borrowed("ति2"), alloc::borrow::Cow::Borrowed("ति3"), alloc::borrow::Cow::Borrowed("ति4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("पहली तिमाही"), alloc::borrow::Cow::Borrowed("द\u{942}सरी तिमाही"), alloc::borrow::Cow::Borrowed("तीसरी तिमाही"), alloc::borrow::Cow::Borrowed("चौथी तिमाही")]) }, stand_alone: None } };
+                static MAI: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("ति1"), alloc::borrow::Cow::Borrowed("ति2"), alloc::borrow::Cow::Borrowed("ति3"), alloc::borrow::Cow::Borrowed("ति4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("पहिल तिमाही"), alloc::borrow::Cow::Borrowed("दोसर तिमाही"), alloc::borrow::Cow::Borrowed("त\u{947}सर तिमाही"), alloc::borrow::Cow::Borrowed("चारिम तिमाही")]) }, stand_alone: None } };
+                static MR: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("ति१"), alloc::borrow::Cow::Borrowed("ति२"), alloc::borrow::Cow::Borrowed("ति३"), alloc::borrow::Cow::Borrowed("ति४")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("१"), alloc::borrow::Cow::Borrowed("२"), alloc::borrow::Cow::Borrowed("३"), alloc::borrow::Cow::Borrowed("४")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("प\u{94d}रथम तिमाही"), alloc::borrow::Cow::Borrowed("द\u{94d}वितीय तिमाही"), alloc::borrow::Cow::Borrowed("त\u{943}तीय तिमाही"), alloc::borrow::Cow::Borrowed("चत\u{941}र\u{94d}थ तिमाही")]) }, stand_alone: None } };
+                static SD_DEVA: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("पहि\u{902}री टिमाही"), alloc::borrow::Cow::Borrowed("बी\u{902}\u{952} टिमाही"), alloc::borrow::Cow::Borrowed("टी\u{902} टिमाही"), alloc::borrow::Cow::Borrowed("चोथी\u{902} टिमाही")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("पहि\u{902}री टिमाही"), alloc::borrow::Cow::Borrowed("बी\u{902}\u{952} टिमाही"), alloc::borrow::Cow::Borrowed("टी\u{902} टिमाही"), alloc::borrow::Cow::Borrowed("चोथी\u{902} टिमाही")]) }, stand_alone: Some(icu::datetime::provider::calendar::quarters::StandAloneWidthsV1 { abbreviated: Some(icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("पहिरी\u{902} टिमाही"), alloc::borrow::Cow::Borrowed("बी\u{902}\u{952} टिमाही"), alloc::borrow::Cow::Borrowed("टी\u{902} टिमाही"), alloc::borrow::Cow::Borrowed("चोथी\u{902} टिमाही")])), narrow: None, short: None, wide: None }) } };
+                static NE: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("पहिलो सत\u{94d}र"), alloc::borrow::Cow::Borrowed("दोस\u{94d}रो सत\u{94d}र"), alloc::borrow::Cow::Borrowed("त\u{947}स\u{94d}रो सत\u{94d}र"), alloc::borrow::Cow::Borrowed("चौथो सत\u{94d}र")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("१"), alloc::borrow::Cow::Borrowed("२"), alloc::borrow::Cow::Borrowed("३"), alloc::borrow::Cow::Borrowed("४")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("पहिलो सत\u{94d}र"), alloc::borrow::Cow::Borrowed("दोस\u{94d}रो सत\u{94d}र"), alloc::borrow::Cow::Borrowed("त\u{947}स\u{94d}रो सत\u{94d}र"), alloc::borrow::Cow::Borrowed("चौथो सत\u{94d}र")]) }, stand_alone: None } };
+                static MNI: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("অহ\u{9be}নব\u{9be} মস\u{9c1}ং"), alloc::borrow::Cow::Borrowed("অনীশ\u{9c1}ব\u{9be} মস\u{9c1}ং"), alloc::borrow::Cow::Borrowed("অহ\u{9c1}মশ\u{9c1}ব\u{9be} মস\u{9c1}ং"), alloc::borrow::Cow::Borrowed("মরীশ\u{9c1}ব\u{9be} মস\u{9c1}ং")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("অহ\u{9be}নব\u{9be} মস\u{9c1}ং"), alloc::borrow::Cow::Borrowed("অনীশ\u{9c1}ব\u{9be} মস\u{9c1}ং"), alloc::borrow::Cow::Borrowed("অহ\u{9c1}মশ\u{9c1}ব\u{9be} মস\u{9c1}ং"), alloc::borrow::Cow::Borrowed("মরীশ\u{9c1}ব\u{9be} মস\u{9c1}ং")]) }, stand_alone: None } };
+                static BN: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("ত\u{9cd}রৈম\u{9be}সিক"), alloc::borrow::Cow::Borrowed("দ\u{9cd}বিতীয\u{9bc} ত\u{9cd}রৈম\u{9be}সিক"), alloc::borrow::Cow::Borrowed("ত\u{9c3}তীয\u{9bc} ত\u{9cd}রৈম\u{9be}সিক"), alloc::borrow::Cow::Borrowed("চত\u{9c1}র\u{9cd}থ ত\u{9cd}রৈম\u{9be}সিক")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("১"), alloc::borrow::Cow::Borrowed("২"), alloc::borrow::Cow::Borrowed("৩"), alloc::borrow::Cow::Borrowed("৪")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("ত\u{9cd}রৈম\u{9be}সিক"), alloc::borrow::Cow::Borrowed("দ\u{9cd}বিতীয\u{9bc} ত\u{9cd}রৈম\u{9be}সিক"), alloc::borrow::Cow::Borrowed("ত\u{9c3}তীয\u{9bc} ত\u{9cd}রৈম\u{9be}সিক"), alloc::borrow::Cow::Borrowed("চত\u{9c1}র\u{9cd}থ ত\u{9cd}রৈম\u{9be}সিক")]) }, stand_alone: Some(icu::datetime::provider::calendar::quarters::StandAloneWidthsV1 { abbreviated: Some(icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("Q1"), alloc::borrow::Cow::Borrowed("Q2"), alloc::borrow::Cow::Borrowed("Q3"), alloc::borrow::Cow::Borrowed("Q4")])), narrow: None, short: None, wide: None }) } };
+                static AS: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("১মঃ তিঃ"), alloc::borrow::Cow::Borrowed("২য\u{9bc}ঃ তিঃ"), alloc::borrow::Cow::Borrowed("৩য\u{9bc}ঃ তিঃ"), alloc::borrow::Cow::Borrowed("৪ৰ\u{9cd}থঃ তিঃ")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("১"), alloc::borrow::Cow::Borrowed("২"), alloc::borrow::Cow::Borrowed("৩"), alloc::borrow::Cow::Borrowed("৪")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("প\u{9cd}ৰথম তিনিম\u{9be}হ"), alloc::borrow::Cow::Borrowed("দ\u{9cd}বিতীয\u{9bc} তিনিম\u{9be}হ"), alloc::borrow::Cow::Borrowed("ত\u{9c3}তীয\u{9bc} তিনিম\u{9be}হ"), alloc::borrow::Cow::Borrowed("চত\u{9c1}ৰ\u{9cd}থ তিনিম\u{9be}হ")]) }, stand_alone: None } };
+                static PA: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("ਤਿਮਾਹੀ1"), alloc::borrow::Cow::Borrowed("ਤਿਮਾਹੀ2"), alloc::borrow::Cow::Borrowed("ਤਿਮਾਹੀ3"), alloc::borrow::Cow::Borrowed("ਤਿਮਾਹੀ4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("ਪਹਿਲੀ ਤਿਮਾਹੀ"), alloc::borrow::Cow::Borrowed("ਦ\u{a42}ਜੀ ਤਿਮਾਹੀ"), alloc::borrow::Cow::Borrowed("ਤੀਜੀ ਤਿਮਾਹੀ"), alloc::borrow::Cow::Borrowed("ਚ\u{a4c}ਥੀ ਤਿਮਾਹੀ")]) }, stand_alone: None } };
+                static TA: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("க\u{bbe}ல\u{bbe}.1"), alloc::borrow::Cow::Borrowed("க\u{bbe}ல\u{bbe}.2"), alloc::borrow::Cow::Borrowed("க\u{bbe}ல\u{bbe}.3"), alloc::borrow::Cow::Borrowed("க\u{bbe}ல\u{bbe}.4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("ஒன\u{bcd}ற\u{bbe}ம\u{bcd} க\u{bbe}ல\u{bbe}ண\u{bcd}டு"), alloc::borrow::Cow::Borrowed("இரண\u{bcd}ட\u{bbe}ம\u{bcd} க\u{bbe}ல\u{bbe}ண\u{bcd}டு"), alloc::borrow::Cow::Borrowed("மூன\u{bcd}ற\u{bbe}ம\u{bcd} க\u{bbe}ல\u{bbe}ண\u{bcd}டு"), alloc::borrow::Cow::Borrowed("ந\u{bbe}ன\u{bcd}க\u{bbe}ம\u{bcd} க\u{bbe}ல\u{bbe}ண\u{bcd}டு")]) }, stand_alone: None } };
+                static TE: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("త\u{c4d}ర\u{c48}1"), alloc::borrow::Cow::Borrowed("త\u{c4d}ర\u{c48}2"), alloc::borrow::Cow::Borrowed("త\u{c4d}ర\u{c48}3"), alloc::borrow::Cow::Borrowed("త\u{c4d}ర\u{c48}4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1వ త\u{c4d}ర\u{c48}మ\u{c3e}స\u{c3f}కం"), alloc::borrow::Cow::Borrowed("2వ త\u{c4d}ర\u{c48}మ\u{c3e}స\u{c3f}కం"), alloc::borrow::Cow::Borrowed("3వ త\u{c4d}ర\u{c48}మ\u{c3e}స\u{c3f}కం"), alloc::borrow::Cow::Borrowed("4వ త\u{c4d}ర\u{c48}మ\u{c3e}స\u{c3f}కం")]) }, stand_alone: None } };
+                static KN: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("ತ\u{ccd}ರ\u{cc8} 1"), alloc::borrow::Cow::Borrowed("ತ\u{ccd}ರ\u{cc8} 2"), alloc::borrow::Cow::Borrowed("ತ\u{ccd}ರ\u{cc8} 3"), alloc::borrow::Cow::Borrowed("ತ\u{ccd}ರ\u{cc8} 4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1ನ\u{cc7} ತ\u{ccd}ರ\u{cc8}ಮಾಸ\u{cbf}ಕ"), alloc::borrow::Cow::Borrowed("2ನ\u{cc7} ತ\u{ccd}ರ\u{cc8}ಮಾಸ\u{cbf}ಕ"), alloc::borrow::Cow::Borrowed("3ನ\u{cc7} ತ\u{ccd}ರ\u{cc8}ಮಾಸ\u{cbf}ಕ"), alloc::borrow::Cow::Borrowed("4ನ\u{cc7} ತ\u{ccd}ರ\u{cc8}ಮಾಸ\u{cbf}ಕ")]) }, stand_alone: None } };
+                static ML: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("ഒന\u{d4d}ന\u{d3e}ം പ\u{d3e}ദം"), alloc::borrow::Cow::Borrowed("രണ\u{d4d}ട\u{d3e}ം പ\u{d3e}ദം"), alloc::borrow::Cow::Borrowed("മ\u{d42}ന\u{d4d}ന\u{d3e}ം പ\u{d3e}ദം"), alloc::borrow::Cow::Borrowed("ന\u{d3e}ല\u{d3e}ം പ\u{d3e}ദം")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("ഒന\u{d4d}ന\u{d3e}ം പ\u{d3e}ദം"), alloc::borrow::Cow::Borrowed("രണ\u{d4d}ട\u{d3e}ം പ\u{d3e}ദം"), alloc::borrow::Cow::Borrowed("മ\u{d42}ന\u{d4d}ന\u{d3e}ം പ\u{d3e}ദം"), alloc::borrow::Cow::Borrowed("ന\u{d3e}ല\u{d3e}ം പ\u{d3e}ദം")]) }, stand_alone: None } };
+                static SI: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("ක\u{dcf}ර\u{dca}:1"), alloc::borrow::Cow::Borrowed("ක\u{dcf}ර\u{dca}:2"), alloc::borrow::Cow::Borrowed("ක\u{dcf}ර\u{dca}:3"), alloc::borrow::Cow::Borrowed("ක\u{dcf}ර\u{dca}:4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1 වන ක\u{dcf}ර\u{dca}ත\u{dd4}ව"), alloc::borrow::Cow::Borrowed("2 වන ක\u{dcf}ර\u{dca}ත\u{dd4}ව"), alloc::borrow::Cow::Borrowed("3 වන ක\u{dcf}ර\u{dca}ත\u{dd4}ව"), alloc::borrow::Cow::Borrowed("4 වන ක\u{dcf}ර\u{dca}ත\u{dd4}ව")]) }, stand_alone: None } };
+                static TH: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("ไตรมาส 1"), alloc::borrow::Cow::Borrowed("ไตรมาส 2"), alloc::borrow::Cow::Borrowed("ไตรมาส 3"), alloc::borrow::Cow::Borrowed("ไตรมาส 4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("ไตรมาส 1"), alloc::borrow::Cow::Borrowed("ไตรมาส 2"), alloc::borrow::Cow::Borrowed("ไตรมาส 3"), alloc::borrow::Cow::Borrowed("ไตรมาส 4")]) }, stand_alone: None } };
+                static LO: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("ຕມ1"), alloc::borrow::Cow::Borrowed("ຕມ2"), alloc::borrow::Cow::Borrowed("ຕມ3"), alloc::borrow::Cow::Borrowed("ຕມ4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("ໄຕຣມາດ 1"), alloc::borrow::Cow::Borrowed("ໄຕຣມາດ 2"), alloc::borrow::Cow::Borrowed("ໄຕຣມາດ 3"), alloc::borrow::Cow::Borrowed("ໄຕຣມາດ 4")]) }, stand_alone: Some(icu::datetime::provider::calendar::quarters::StandAloneWidthsV1 { abbreviated: Some(icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("ຕ1"), alloc::borrow::Cow::Borrowed("ຕ2"), alloc::borrow::Cow::Borrowed("ຕ3"), alloc::borrow::Cow::Borrowed("ຕ4")])), narrow: None, short: None, wide: None }) } };
+                static MY: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("ပထမ သ\u{102f}\u{1036}းလပတ\u{103a}"), alloc::borrow::Cow::Borrowed("ဒ\u{102f}တ\u{102d}ယ သ\u{102f}\u{1036}းလပတ\u{103a}"), alloc::borrow::Cow::Borrowed("တတ\u{102d}ယ သ\u{102f}\u{1036}းလပတ\u{103a}"), alloc::borrow::Cow::Borrowed("စတ\u{102f}တ\u{1039}ထ သ\u{102f}\u{1036}းလပတ\u{103a}")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("ပ"), alloc::borrow::Cow::Borrowed("ဒ\u{102f}"), alloc::borrow::Cow::Borrowed("တ"), alloc::borrow::Cow::Borrowed("စ")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("ပထမ သ\u{102f}\u{1036}းလပတ\u{103a}"), alloc::borrow::Cow::Borrowed("ဒ\u{102f}တ\u{102d}ယ သ\u{102f}\u{1036}းလပတ\u{103a}"), alloc::borrow::Cow::Borrowed("တတ\u{102d}ယ သ\u{102f}\u{1036}းလပတ\u{103a}"), alloc::borrow::Cow::Borrowed("စတ\u{102f}တ\u{1039}ထ သ\u{102f}\u{1036}းလပတ\u{103a}")]) }, stand_alone: None } };
+                static AM: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("ሩብ1"), alloc::borrow::Cow::Borrowed("ሩብ2"), alloc::borrow::Cow::Borrowed("ሩብ3"), alloc::borrow::Cow::Borrowed("ሩብ4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1ኛው ሩብ"), alloc::borrow::Cow::Borrowed("2ኛው ሩብ"), alloc::borrow::Cow::Borrowed("3ኛው ሩብ"), alloc::borrow::Cow::Borrowed("4ኛው ሩብ")]) }, stand_alone: None } };
+                static TI: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("ር1"), alloc::borrow::Cow::Borrowed("ር2"), alloc::borrow::Cow::Borrowed("ር3"), alloc::borrow::Cow::Borrowed("ር4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1ይ ርብዒ"), alloc::borrow::Cow::Borrowed("2ይ ርብዒ"), alloc::borrow::Cow::Borrowed("3ይ ርብዒ"), alloc::borrow::Cow::Borrowed("4ይ ርብዒ")]) }, stand_alone: None } };
+                static KM: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("ត\u{17d2}រ\u{17b8}មាសទ\u{17b8} 1"), alloc::borrow::Cow::Borrowed("ត\u{17d2}រ\u{17b8}មាសទ\u{17b8} 2"), alloc::borrow::Cow::Borrowed("ត\u{17d2}រ\u{17b8}មាសទ\u{17b8} 3"), alloc::borrow::Cow::Borrowed("ត\u{17d2}រ\u{17b8}មាសទ\u{17b8} 4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("ត\u{17d2}រ\u{17b8}មាសទ\u{17b8} 1"), alloc::borrow::Cow::Borrowed("ត\u{17d2}រ\u{17b8}មាសទ\u{17b8} 2"), alloc::borrow::Cow::Borrowed("ត\u{17d2}រ\u{17b8}មាសទ\u{17b8} 3"), alloc::borrow::Cow::Borrowed("ត\u{17d2}រ\u{17b8}មាសទ\u{17b8} 4")]) }, stand_alone: None } };
+                static SAT: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("᱑ᱟᱜ ᱯᱮ ᱪᱟᱸᱫᱚᱠᱤᱭᱟᱹ"), alloc::borrow::Cow::Borrowed("᱒ᱟᱜ ᱯᱮ ᱪᱟᱸᱫᱚᱠᱤᱭᱟᱹ"), alloc::borrow::Cow::Borrowed("᱓ᱭᱟᱜ ᱯᱮ ᱪᱟᱸᱫᱚᱠᱤᱭᱟᱹ"), alloc::borrow::Cow::Borrowed("᱔ᱟᱜ ᱯᱮ ᱪᱟᱸᱫᱚᱠᱤᱭᱟᱹ")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("᱑"), alloc::borrow::Cow::Borrowed("᱒"), alloc::borrow::Cow::Borrowed("᱓"), alloc::borrow::Cow::Borrowed("᱔")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("᱑ᱟᱜ ᱯᱮ ᱪᱟᱸᱫᱚᱠᱤᱭᱟᱹ"), alloc::borrow::Cow::Borrowed("᱒ᱟᱜ ᱯᱮ ᱪᱟᱸᱫᱚᱠᱤᱭᱟᱹ"), alloc::borrow::Cow::Borrowed("᱓ᱭᱟᱜ ᱯᱮ ᱪᱟᱸᱫᱚᱠᱤᱭᱟᱹ"), alloc::borrow::Cow::Borrowed("᱔ᱟᱜ ᱯᱮ ᱪᱟᱸᱫᱚᱠᱤᱭᱟᱹ")]) }, stand_alone: None } };
+                static IG: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("Ọ1"), alloc::borrow::Cow::Borrowed("Ọ2"), alloc::borrow::Cow::Borrowed("Ọ3"), alloc::borrow::Cow::Borrowed("Ọ4")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("Ọkara 1"), alloc::borrow::Cow::Borrowed("Ọkara 2"), alloc::borrow::Cow::Borrowed("Ọkara 3"), alloc::borrow::Cow::Borrowed("Ọkara 4")]) }, stand_alone: None } };
+                static YUE: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("第1季"), alloc::borrow::Cow::Borrowed("第2季"), alloc::borrow::Cow::Borrowed("第3季"), alloc::borrow::Cow::Borrowed("第4季")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("1"), alloc::borrow::Cow::Borrowed("2"), alloc::borrow::Cow::Borrowed("3"), alloc::borrow::Cow::Borrowed("4")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("第1季"), alloc::borrow::Cow::Borrowed("第2季"), alloc::borrow::Cow::Borrowed("第3季"), alloc::borrow::Cow::Borrowed("第4季")]) }, stand_alone: None } };
+                static FF_ADLM: <icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::QuarterSymbolsV1 { quarters: icu::datetime::provider::calendar::quarters::ContextsV1 { format: icu::datetime::provider::calendar::quarters::FormatWidthsV1 { abbreviated: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("𞤐𞥑"), alloc::borrow::Cow::Borrowed("𞤐𞥒"), alloc::borrow::Cow::Borrowed("𞤐𞥓"), alloc::borrow::Cow::Borrowed("𞤐𞥔")]), narrow: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("𞥑"), alloc::borrow::Cow::Borrowed("𞥒"), alloc::borrow::Cow::Borrowed("𞥓"), alloc::borrow::Cow::Borrowed("𞥔")]), short: None, wide: icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("𞤐𞥑"), alloc::borrow::Cow::Borrowed("𞤐𞥒"), alloc::borrow::Cow::Borrowed("𞤐𞥓"), alloc::borrow::Cow::Borrowed("𞤐𞥔")]) }, stand_alone: Some(icu::datetime::provider::calendar::quarters::StandAloneWidthsV1 { abbreviated: None, narrow: None, short: None, wide: Some(icu::datetime::provider::calendar::quarters::SymbolsV1([alloc::borrow::Cow::Borrowed("𞤐𞤢𞤴𞤩𞤭\u{1e945}𞤪𞤫 𞥑𞤪𞤫"), alloc::borrow::Cow::Borrowed("𞤐𞤢𞤴𞤩𞤭\u{1e945}𞤪𞤫 𞥒𞤪𞤫"), alloc::borrow::Cow::Borrowed("𞤐𞤢𞤴𞤩𞤭\u{1e945}𞤪𞤫 𞥓𞤪𞤫"), alloc::borrow::Cow::Borrowed("𞤐𞤢𞤴𞤩𞤭\u{1e945}𞤪𞤫 𞥔𞤪𞤫")])) }) } };
+                static VALUES: [&<icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::DataMarker>::Yokeable; 143usize] = [&AF, &AM, &AR, &AS, &AST, &AZ, &BE, &BG, &BN, &BR, &BRX, &BS, &BS_CYRL, &CA, &CEB, &CHR, &CS, &CV, &CY, &DA, &DE, &DOI, &DSB, &EL, &EN, &ES, &ES_419, &ES_CL, &ES_DO, &ES_MX, &ES_PA, &ES_US, &ES_VE, &ET, &EU, &FA, &FA_AF, &FF_ADLM, &FI, &FIL, &FO, &FR, &FR_ML, &GA, &GD, &ES_419, &GU, &HA, &HE, &HI, &HR, &HR_BA, &DSB, &HU, &HY, &IA, &ID, &IG, &IS, &IT, &JA, &JV, &KA, &KEA, &KGP, &KK, &KM, &KN, &KO, &KOK, &KS, &KS_DEVA, &KY, &LO, &LT, &LV, &MAI, &MI, &MK, &ML, &MN, &MNI, &MR, &MS, &MY, &NE, &NL, &NN, &NO, &OR, &PA, &PCM, &PL, &PS, &IT, &ES_419, &RM, &RO, &RO_MD, &RU, &SA, &SAT, &SC, &SD, &SD_DEVA, &SI, &SK, &SL, &SO, &SQ, &SR, &SR_LATN, &SU, &SV, &SV_FI, &SW, &TA, &TE, &TG, &TH, &TI, &TK, &TO, &TR, &TT, &RU, &UND, &UR, &UZ, &UZ_CYRL, &VI, &WO, &XH, &YO, &YO_BJ, &YRL, &YUE, &YUE, &ZH, &ZH_HK, &YUE, &ZH_HK, &ZU];
+                static KEYS: [&str; 143usize] = ["af", "am", "ar", "as", "ast", "az", "be", "bg", "bn", "br", "brx", "bs", "bs-Cyrl", "ca", "ceb", "chr", "cs", "cv", "cy", "da", "de", "doi", "dsb", "el", "en", "es", "es-419", "es-CL", "es-DO", "es-MX", "es-PA", "es-US", "es-VE", "et", "eu", "fa", "fa-AF", "ff-Adlm", "fi", "fil", "fo", "fr", "fr-ML", "ga", "gd", "gl", "gu", "ha", "he", "hi", "hr", "hr-BA", "hsb", "hu", "hy", "ia", "id", "ig", "is", "it", "ja", "jv", "ka", "kea", "kgp", "kk", "km", "kn", "ko", "kok", "ks", "ks-Deva", "ky", "lo", "lt", "lv", "mai", "mi", "mk", "ml", "mn", "mni", "mr", "ms", "my", "ne", "nl", "nn", "no", "or", "pa", "pcm", "pl", "ps", "pt", "pt-PT", "rm", "ro", "ro-MD", "ru", "sa", "sat", "sc", "sd", "sd-Deva", "si", "sk", "sl", "so", "sq", "sr", "sr-Latn", "su", "sv", "sv-FI", "sw", "ta", "te", "tg", "th", "ti", "tk", "to", "tr", "tt", "uk", "und", "ur", "uz", "uz-Cyrl", "vi", "wo", "xh", "yo", "yo-BJ", "yrl", "yue", "yue-Hans", "zh", "zh-HK", "zh-Hant", "zh-MO", "zu"];
+                let mut metadata = icu_provider::DataResponseMetadata::default();
+                let payload = if let Ok(payload) = KEYS.binary_search_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse()).map(|i| *unsafe { VALUES.get_unchecked(i) }) {
+                    payload
+                } else {
+                    const FALLBACKER: icu::locid_transform::fallback::LocaleFallbackerWithConfig<'static> = icu::locid_transform::fallback::LocaleFallbacker::new().for_config(<icu::datetime::provider::calendar::QuarterSymbolsV1Marker as icu_provider::KeyedDataMarker>::KEY.fallback_config());
+                    let mut fallback_iterator = FALLBACKER.fallback_for(req.locale.clone());
+                    loop {
+                        if let Ok(payload) = KEYS.binary_search_by(|k| fallback_iterator.get().strict_cmp(k.as_bytes()).reverse()).map(|i| *unsafe { VALUES.get_unchecked(i) }) {
+                            metadata.locale = Some(fallback_iterator.take());
+                            break payload;
+                        }
+                        fallback_iterator.step();
+                    }
+                };
+                Ok(icu_provider::DataResponse { payload: Some(icu_provider::DataPayload::from_static_ref(payload)), metadata })
+            }
+        }
+    };
+}
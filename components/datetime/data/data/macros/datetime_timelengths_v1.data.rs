@@ -9,9 +9,9 @@ macro_rules! __impl_datetime_timelengths_v1 {
         #[clippy::msrv = "1.66"]
         impl icu_provider::DataProvider<icu::datetime::provider::calendar::TimeLengthsV1Marker> for $provider {
             fn load(&self, req: icu_provider::DataRequest) -> Result<icu_provider::DataResponse<icu::datetime::provider::calendar::TimeLengthsV1Marker>, icu_provider::DataError> {
-                static ZH_HANT: <icu::datetime::provider::calendar::TimeLengthsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::TimeLengthsV1 { time_h11_h12: icu::datetime::provider::calendar::patterns::LengthPatternsV1 { full: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0B\x80q\x01\0\0:\x80\x80\x02\0\0:\x80\x90\x02\0\0 \0\0[\x80\xA0\x04\0\0]") }, time_granularity: icu::datetime::pattern::TimeGranularity::Seconds }, long: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0B\x80q\x01\0\0:\x80\x80\x02\0\0:\x80\x90\x02\0\0 \0\0[\x80\xA0\x01\0\0]") }, time_granularity: icu::datetime::pattern::TimeGranularity::Seconds }, medium: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0B\x80q\x01\0\0:\x80\x80\x02\0\0:\x80\x90\x02") }, time_granularity: icu::datetime::pattern::TimeGranularity::Seconds }, short: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0B\x80q\x01\0\0:\x80\x80\x02") }, time_granularity: icu::datetime::pattern::TimeGranularity::Minutes } }, time_h23_h24: icu::datetime::provider::calendar::patterns::LengthPatternsV1 { full: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80r\x02\0\0:\x80\x80\x02\0\0:\x80\x90\x02\0\0 \0\0[\x80\xA3\x01\0\0]") }, time_granularity: icu::datetime::pattern::TimeGranularity::Seconds }, long: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80r\x02\0\0:\x80\x80\x02\0\0:\x80\x90\x02\0\0 \0\0[\x80\xA3\x01\0\0]") }, time_granularity: icu::datetime::pattern::TimeGranularity::Seconds }, medium: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80r\x02\0\0:\x80\x80\x02\0\0:\x80\x90\x02") }, time_granularity: icu::datetime::pattern::TimeGranularity::Seconds }, short: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80r\x02\0\0:\x80\x80\x02") }, time_granularity: icu::datetime::pattern::TimeGranularity::Minutes } }, preferred_hour_cycle: icu::datetime::pattern::CoarseHourCycle::H11H12 };
+                static ZH_HANT: <icu::datetime::provider::calendar::TimeLengthsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::TimeLengthsV1 { time_h11_h12: icu::datetime::provider::calendar::patterns::LengthPatternsV1 { full: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80b\x01\x80q\x01\0\0:\x80\x80\x02\0\0:\x80\x90\x02\0\0 \0\0[\x80\xA0\x04\0\0]") }, time_granularity: icu::datetime::pattern::TimeGranularity::Seconds }, long: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80b\x01\x80q\x01\0\0:\x80\x80\x02\0\0:\x80\x90\x02\0\0 \0\0[\x80\xA0\x01\0\0]") }, time_granularity: icu::datetime::pattern::TimeGranularity::Seconds }, medium: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80b\x01\x80q\x01\0\0:\x80\x80\x02\0\0:\x80\x90\x02") }, time_granularity: icu::datetime::pattern::TimeGranularity::Seconds }, short: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80b\x01\x80q\x01\0\0:\x80\x80\x02") }, time_granularity: icu::datetime::pattern::TimeGranularity::Minutes } }, time_h23_h24: icu::datetime::provider::calendar::patterns::LengthPatternsV1 { full: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80r\x02\0\0:\x80\x80\x02\0\0:\x80\x90\x02\0\0 \0\0[\x80\xA3\x01\0\0]") }, time_granularity: icu::datetime::pattern::TimeGranularity::Seconds }, long: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80r\x02\0\0:\x80\x80\x02\0\0:\x80\x90\x02\0\0 \0\0[\x80\xA3\x01\0\0]") }, time_granularity: icu::datetime::pattern::TimeGranularity::Seconds }, medium: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80r\x02\0\0:\x80\x80\x02\0\0:\x80\x90\x02") }, time_granularity: icu::datetime::pattern::TimeGranularity::Seconds }, short: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80r\x02\0\0:\x80\x80\x02") }, time_granularity: icu::datetime::pattern::TimeGranularity::Minutes } }, preferred_hour_cycle: icu::datetime::pattern::CoarseHourCycle::H11H12 };
                 static ZH_SG: <icu::datetime::provider::calendar::TimeLengthsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::TimeLengthsV1 { time_h11_h12: icu::datetime::provider::calendar::patterns::LengthPatternsV1 { full: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\xA0\x04\0\0 \x80`\x01\x80q\x01\0\0:\x80\x80\x02\0\0:\x80\x90\x02") }, time_granularity: icu::datetime::pattern::TimeGranularity::Seconds }, long: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\xA0\x01\0\0 \x80`\x01\x80q\x01\0\0:\x80\x80\x02\0\0:\x80\x90\x02") }, time_granularity: icu::datetime::pattern::TimeGranularity::Seconds }, medium: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80`\x01\x80q\x01\0\0:\x80\x80\x02\0\0:\x80\x90\x02") }, time_granularity: icu::datetime::pattern::TimeGranularity::Seconds }, short: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80`\x01\x80q\x01\0\0:\x80\x80\x02") }, time_granularity: icu::datetime::pattern::TimeGranularity::Minutes } }, time_h23_h24: icu::datetime::provider::calendar::patterns::LengthPatternsV1 { full: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\xA3\x01\0\0 \x80r\x02\0\0:\x80\x80\x02\0\0:\x80\x90\x02") }, time_granularity: icu::datetime::pattern::TimeGranularity::Seconds }, long: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\xA3\x01\0\0 \x80r\x02\0\0:\x80\x80\x02\0\0:\x80\x90\x02") }, time_granularity: icu::datetime::pattern::TimeGranularity::Seconds }, medium: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80r\x02\0\0:\x80\x80\x02\0\0:\x80\x90\x02") }, time_granularity: icu::datetime::pattern::TimeGranularity::Seconds }, short: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80r\x02\0\0:\x80\x80\x02") }, time_granularity: icu::datetime::pattern::TimeGranularity::Minutes } }, preferred_hour_cycle: icu::datetime::pattern::CoarseHourCycle::H11H12 };
-                static MY: <icu::datetime::provider::calendar::TimeLengthsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::TimeLengthsV1 { time_h11_h12: icu::datetime::provider::calendar::patterns::LengthPatternsV1 { full: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\xA3\x01\0\0 \x80`\x01\0\0 \x80q\x01\0\0:\x80\x80\x02\0\0:\x80\x90\x02") }, time_granularity: icu::datetime::pattern::TimeGranularity::Seconds }, long: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\xA3\x01\0\0 \x80`\x01\0\0 \x80q\x01\0\0:\x80\x80\x02\0\0:\x80\x90\x02") }, time_granularity: icu::datetime::pattern::TimeGranularity::Seconds }, medium: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0B\0\0 \x80q\x01\0\0:\x80\x80\x02\0\0:\x80\x90\x02") }, time_granularity: icu::datetime::pattern::TimeGranularity::Seconds }, short: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0B\0\0 \x80q\x01\0\0:\x80\x80\x02") }, time_granularity: icu::datetime::pattern::TimeGranularity::Minutes } }, time_h23_h24: icu::datetime::provider::calendar::patterns::LengthPatternsV1 { full: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\xA0\x04\0\0 \x80r\x02\0\0:\x80\x80\x02\0\0:\x80\x90\x02") }, time_granularity: icu::datetime::pattern::TimeGranularity::Seconds }, long: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\xA0\x01\0\0 \x80r\x02\0\0:\x80\x80\x02\0\0:\x80\x90\x02") }, time_granularity: icu::datetime::pattern::TimeGranularity::Seconds }, medium: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80r\x01\0\0:\x80\x80\x02\0\0:\x80\x90\x02") }, time_granularity: icu::datetime::pattern::TimeGranularity::Seconds }, short: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80r\x01\0\0:\x80\x80\x02") }, time_granularity: icu::datetime::pattern::TimeGranularity::Minutes } }, preferred_hour_cycle: icu::datetime::pattern::CoarseHourCycle::H23H24 };
+                static MY: <icu::datetime::provider::calendar::TimeLengthsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::TimeLengthsV1 { time_h11_h12: icu::datetime::provider::calendar::patterns::LengthPatternsV1 { full: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\xA3\x01\0\0 \x80`\x01\0\0 \x80q\x01\0\0:\x80\x80\x02\0\0:\x80\x90\x02") }, time_granularity: icu::datetime::pattern::TimeGranularity::Seconds }, long: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\xA3\x01\0\0 \x80`\x01\0\0 \x80q\x01\0\0:\x80\x80\x02\0\0:\x80\x90\x02") }, time_granularity: icu::datetime::pattern::TimeGranularity::Seconds }, medium: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80b\x01\0\0 \x80q\x01\0\0:\x80\x80\x02\0\0:\x80\x90\x02") }, time_granularity: icu::datetime::pattern::TimeGranularity::Seconds }, short: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80b\x01\0\0 \x80q\x01\0\0:\x80\x80\x02") }, time_granularity: icu::datetime::pattern::TimeGranularity::Minutes } }, time_h23_h24: icu::datetime::provider::calendar::patterns::LengthPatternsV1 { full: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\xA0\x04\0\0 \x80r\x02\0\0:\x80\x80\x02\0\0:\x80\x90\x02") }, time_granularity: icu::datetime::pattern::TimeGranularity::Seconds }, long: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\xA0\x01\0\0 \x80r\x02\0\0:\x80\x80\x02\0\0:\x80\x90\x02") }, time_granularity: icu::datetime::pattern::TimeGranularity::Seconds }, medium: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80r\x01\0\0:\x80\x80\x02\0\0:\x80\x90\x02") }, time_granularity: icu::datetime::pattern::TimeGranularity::Seconds }, short: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80r\x01\0\0:\x80\x80\x02") }, time_granularity: icu::datetime::pattern::TimeGranularity::Minutes } }, preferred_hour_cycle: icu::datetime::pattern::CoarseHourCycle::H23H24 };
                 static YUE_HANS: <icu::datetime::provider::calendar::TimeLengthsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::TimeLengthsV1 { time_h11_h12: icu::datetime::provider::calendar::patterns::LengthPatternsV1 { full: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\xA3\x01\0\0 \x80`\x01\x80q\x01\0\0:\x80\x80\x02\0\0:\x80\x90\x02") }, time_granularity: icu::datetime::pattern::TimeGranularity::Seconds }, long: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\xA3\x01\0\0 \x80`\x01\x80q\x01\0\0:\x80\x80\x02\0\0:\x80\x90\x02") }, time_granularity: icu::datetime::pattern::TimeGranularity::Seconds }, medium: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80`\x01\x80q\x01\0\0:\x80\x80\x02\0\0:\x80\x90\x02") }, time_granularity: icu::datetime::pattern::TimeGranularity::Seconds }, short: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80`\x01\x80q\x01\0\0:\x80\x80\x02") }, time_granularity: icu::datetime::pattern::TimeGranularity::Minutes } }, time_h23_h24: icu::datetime::provider::calendar::patterns::LengthPatternsV1 { full: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\xA0\x04\0\0 \x80r\x02\0\0:\x80\x80\x02\0\0:\x80\x90\x02") }, time_granularity: icu::datetime::pattern::TimeGranularity::Seconds }, long: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\xA0\x01\0\0 \x80r\x02\0\0:\x80\x80\x02\0\0:\x80\x90\x02") }, time_granularity: icu::datetime::pattern::TimeGranularity::Seconds }, medium: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80r\x02\0\0:\x80\x80\x02\0\0:\x80\x90\x02") }, time_granularity: icu::datetime::pattern::TimeGranularity::Seconds }, short: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80r\x02\0\0:\x80\x80\x02") }, time_granularity: icu::datetime::pattern::TimeGranularity::Minutes } }, preferred_hour_cycle: icu::datetime::pattern::CoarseHourCycle::H23H24 };
                 static HU: <icu::datetime::provider::calendar::TimeLengthsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::TimeLengthsV1 { time_h11_h12: icu::datetime::provider::calendar::patterns::LengthPatternsV1 { full: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80`\x01\0 /\x80q\x01\0\0:\x80\x80\x02\0\0:\x80\x90\x02\0\0 \x80\xA3\x01") }, time_granularity: icu::datetime::pattern::TimeGranularity::Seconds }, long: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80`\x01\0 /\x80q\x01\0\0:\x80\x80\x02\0\0:\x80\x90\x02\0\0 \x80\xA3\x01") }, time_granularity: icu::datetime::pattern::TimeGranularity::Seconds }, medium: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80`\x01\0 /\x80q\x01\0\0:\x80\x80\x02\0\0:\x80\x90\x02") }, time_granularity: icu::datetime::pattern::TimeGranularity::Seconds }, short: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80`\x01\0 /\x80q\x01\0\0:\x80\x80\x02") }, time_granularity: icu::datetime::pattern::TimeGranularity::Minutes } }, time_h23_h24: icu::datetime::provider::calendar::patterns::LengthPatternsV1 { full: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80r\x01\0\0:\x80\x80\x02\0\0:\x80\x90\x02\0\0 \x80\xA0\x04") }, time_granularity: icu::datetime::pattern::TimeGranularity::Seconds }, long: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80r\x01\0\0:\x80\x80\x02\0\0:\x80\x90\x02\0\0 \x80\xA0\x01") }, time_granularity: icu::datetime::pattern::TimeGranularity::Seconds }, medium: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80r\x01\0\0:\x80\x80\x02\0\0:\x80\x90\x02") }, time_granularity: icu::datetime::pattern::TimeGranularity::Seconds }, short: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80r\x01\0\0:\x80\x80\x02") }, time_granularity: icu::datetime::pattern::TimeGranularity::Minutes } }, preferred_hour_cycle: icu::datetime::pattern::CoarseHourCycle::H23H24 };
                 static TR: <icu::datetime::provider::calendar::TimeLengthsV1Marker as icu_provider::DataMarker>::Yokeable = icu::datetime::provider::calendar::TimeLengthsV1 { time_h11_h12: icu::datetime::provider::calendar::patterns::LengthPatternsV1 { full: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80`\x01\0 /\x80q\x01\0\0:\x80\x80\x02\0\0:\x80\x90\x02\0\0 \x80\xA3\x01") }, time_granularity: icu::datetime::pattern::TimeGranularity::Seconds }, long: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80`\x01\0 /\x80q\x01\0\0:\x80\x80\x02\0\0:\x80\x90\x02\0\0 \x80\xA3\x01") }, time_granularity: icu::datetime::pattern::TimeGranularity::Seconds }, medium: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80`\x01\0 /\x80q\x01\0\0:\x80\x80\x02\0\0:\x80\x90\x02") }, time_granularity: icu::datetime::pattern::TimeGranularity::Seconds }, short: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80`\x01\0 /\x80q\x01\0\0:\x80\x80\x02") }, time_granularity: icu::datetime::pattern::TimeGranularity::Minutes } }, time_h23_h24: icu::datetime::provider::calendar::patterns::LengthPatternsV1 { full: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80r\x02\0\0:\x80\x80\x02\0\0:\x80\x90\x02\0\0 \x80\xA0\x04") }, time_granularity: icu::datetime::pattern::TimeGranularity::Seconds }, long: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80r\x02\0\0:\x80\x80\x02\0\0:\x80\x90\x02\0\0 \x80\xA0\x01") }, time_granularity: icu::datetime::pattern::TimeGranularity::Seconds }, medium: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80r\x02\0\0:\x80\x80\x02\0\0:\x80\x90\x02") }, time_granularity: icu::datetime::pattern::TimeGranularity::Seconds }, short: icu::datetime::pattern::runtime::Pattern { items: unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80r\x02\0\0:\x80\x80\x02") }, time_granularity: icu::datetime::pattern::TimeGranularity::Minutes } }, preferred_hour_cycle: icu::datetime::pattern::CoarseHourCycle::H23H24 };
@@ -242,6 +242,60 @@ impl DateFormatter {
         Ok(self.format(value)?.write_to_string().into_owned())
     }
 
+    /// Parses a localized string in the format of this [`DateFormatter`] back into a [`Date`]
+    /// of its calendar.
+    ///
+    /// See the [`parse`](crate::parse) module for how leniently the input is read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::Date;
+    /// use icu::datetime::{options::length, parse::Leniency, DateFormatter};
+    /// use icu::locid::locale;
+    ///
+    /// let df = DateFormatter::try_new_with_length(
+    ///     &locale!("en-u-ca-gregory").into(),
+    ///     length::Date::Short,
+    /// )
+    /// .expect("Failed to create DateFormatter instance.");
+    ///
+    /// let date = df
+    ///     .parse("5/3/24", Leniency::Strict.into())
+    ///     .expect("Input is in the format of the formatter");
+    /// assert_eq!(date.to_iso(), Date::try_new_iso_date(2024, 5, 3).unwrap());
+    ///
+    /// // Four-digit years are only accepted in lenient mode.
+    /// assert!(df.parse("05/03/2024", Leniency::Strict.into()).is_err());
+    /// let date = df
+    ///     .parse("05/03/2024", Leniency::Lenient.into())
+    ///     .expect("Input is close enough to the format of the formatter");
+    /// assert_eq!(date.to_iso(), Date::try_new_iso_date(2024, 5, 3).unwrap());
+    /// ```
+    ///
+    /// <div class="stab unstable">
+    /// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+    /// including in SemVer minor releases. It can be enabled with the "experimental" Cargo feature
+    /// of the icu meta-crate. Use with caution.
+    /// </div>
+    #[cfg(feature = "experimental")]
+    pub fn parse(
+        &self,
+        input: &str,
+        options: crate::parse::ParseOptions,
+    ) -> Result<Date<AnyCalendar>, DateTimeError> {
+        let (date, _) = crate::parse::DateTimeParser {
+            patterns: &self.0.patterns.get().0,
+            date_symbols: self.0.symbols.as_ref().map(|s| s.get()),
+            time_symbols: None,
+            fixed_decimal_format: &self.0.fixed_decimal_format,
+            calendar: &self.1,
+            options,
+        }
+        .parse(input)?;
+        Ok(date)
+    }
+
     /// Converts a date to the correct calendar if necessary
     ///
     /// Returns Err if the date is not ISO or compatible with the current calendar, returns Ok(None)
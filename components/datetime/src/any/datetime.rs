@@ -2,7 +2,6 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-#[cfg(feature = "experimental")]
 use crate::options::components;
 use crate::provider::{calendar::*, date_time::PatternSelector};
 use crate::{calendar, options::DateTimeFormatterOptions, raw, DateFormatter, TimeFormatter};
@@ -211,10 +210,9 @@ impl DateTimeFormatter {
         Self::try_new_unstable(&deserializing, locale, options)
     }
 
-    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::try_new_experimental)]
-    #[cfg(feature = "experimental")]
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::try_new_with_components)]
     #[inline(never)]
-    pub fn try_new_experimental_unstable<P>(
+    pub fn try_new_with_components_unstable<P>(
         provider: &P,
         locale: &DataLocale,
         options: DateTimeFormatterOptions,
@@ -222,7 +220,10 @@ impl DateTimeFormatter {
     where
         P: DataProvider<TimeSymbolsV1Marker>
             + DataProvider<TimeLengthsV1Marker>
+            + DataProvider<QuarterSymbolsV1Marker>
+            + DataProvider<FlexibleDayPeriodsV1Marker>
             + DataProvider<crate::provider::calendar::DateSkeletonPatternsV1Marker>
+            + DataProvider<crate::provider::calendar::AppendItemsV1Marker>
             + DataProvider<OrdinalV1Marker>
             + DataProvider<WeekDataV1Marker>
             + DataProvider<DecimalSymbolsV1Marker>
@@ -278,19 +279,12 @@ impl DateTimeFormatter {
         ))
     }
 
-    /// Constructor that supports experimental options with compiled data.
+    /// Constructor that also supports [`components::Bag`] options, using compiled data.
     ///
     /// ✨ *Enabled with the `compiled_data` Cargo feature.*
     ///
     /// [📚 Help choosing a constructor](icu_provider::constructors)
     ///
-    /// <div class="stab unstable">
-    /// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
-    /// including in SemVer minor releases. It can be enabled with the "experimental" Cargo feature
-    /// of the icu meta-crate. Use with caution.
-    /// <a href="https://github.com/unicode-org/icu4x/issues/1317">#1317</a>
-    /// </div>
-    ///
     /// # Examples
     ///
     /// ```
@@ -306,7 +300,7 @@ impl DateTimeFormatter {
     /// options.year = Some(components::Year::Numeric);
     /// options.month = Some(components::Month::Long);
     ///
-    /// let dtf = DateTimeFormatter::try_new_experimental(
+    /// let dtf = DateTimeFormatter::try_new_with_components(
     ///     &locale!("en-u-ca-gregory").into(),
     ///     options.into(),
     /// )
@@ -321,10 +315,9 @@ impl DateTimeFormatter {
     ///     "September 2020"
     /// );
     /// ```
-    #[cfg(feature = "experimental")]
     #[cfg(feature = "compiled_data")]
     #[inline(never)]
-    pub fn try_new_experimental(
+    pub fn try_new_with_components(
         locale: &DataLocale,
         options: DateTimeFormatterOptions,
     ) -> Result<Self, DateTimeError> {
@@ -355,6 +348,71 @@ impl DateTimeFormatter {
         ))
     }
 
+    /// Alias to [`DateTimeFormatter::try_new_with_components`].
+    #[deprecated(since = "1.3.0", note = "renamed to `try_new_with_components`")]
+    #[inline]
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new_experimental(
+        locale: &DataLocale,
+        options: DateTimeFormatterOptions,
+    ) -> Result<Self, DateTimeError> {
+        Self::try_new_with_components(locale, options)
+    }
+
+    /// Alias to [`DateTimeFormatter::try_new_with_components_unstable`].
+    #[deprecated(
+        since = "1.3.0",
+        note = "renamed to `try_new_with_components_unstable`"
+    )]
+    #[inline]
+    pub fn try_new_experimental_unstable<P>(
+        provider: &P,
+        locale: &DataLocale,
+        options: DateTimeFormatterOptions,
+    ) -> Result<Self, DateTimeError>
+    where
+        P: DataProvider<TimeSymbolsV1Marker>
+            + DataProvider<TimeLengthsV1Marker>
+            + DataProvider<QuarterSymbolsV1Marker>
+            + DataProvider<FlexibleDayPeriodsV1Marker>
+            + DataProvider<crate::provider::calendar::DateSkeletonPatternsV1Marker>
+            + DataProvider<crate::provider::calendar::AppendItemsV1Marker>
+            + DataProvider<OrdinalV1Marker>
+            + DataProvider<WeekDataV1Marker>
+            + DataProvider<DecimalSymbolsV1Marker>
+            + DataProvider<BuddhistDateLengthsV1Marker>
+            + DataProvider<BuddhistDateSymbolsV1Marker>
+            + DataProvider<ChineseDateLengthsV1Marker>
+            + DataProvider<ChineseDateSymbolsV1Marker>
+            + DataProvider<CopticDateLengthsV1Marker>
+            + DataProvider<CopticDateSymbolsV1Marker>
+            + DataProvider<DangiDateLengthsV1Marker>
+            + DataProvider<DangiDateSymbolsV1Marker>
+            + DataProvider<EthiopianDateLengthsV1Marker>
+            + DataProvider<EthiopianDateSymbolsV1Marker>
+            + DataProvider<GregorianDateLengthsV1Marker>
+            + DataProvider<GregorianDateSymbolsV1Marker>
+            + DataProvider<HebrewDateLengthsV1Marker>
+            + DataProvider<HebrewDateSymbolsV1Marker>
+            + DataProvider<IndianDateLengthsV1Marker>
+            + DataProvider<IndianDateSymbolsV1Marker>
+            + DataProvider<IslamicDateLengthsV1Marker>
+            + DataProvider<IslamicDateSymbolsV1Marker>
+            + DataProvider<JapaneseDateLengthsV1Marker>
+            + DataProvider<JapaneseDateSymbolsV1Marker>
+            + DataProvider<JapaneseErasV1Marker>
+            + DataProvider<JapaneseExtendedDateLengthsV1Marker>
+            + DataProvider<JapaneseExtendedDateSymbolsV1Marker>
+            + DataProvider<JapaneseExtendedErasV1Marker>
+            + DataProvider<PersianDateLengthsV1Marker>
+            + DataProvider<PersianDateSymbolsV1Marker>
+            + DataProvider<RocDateLengthsV1Marker>
+            + DataProvider<RocDateSymbolsV1Marker>
+            + ?Sized,
+    {
+        Self::try_new_with_components_unstable(provider, locale, options)
+    }
+
     #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
     #[inline(never)]
     pub fn try_new_unstable<P>(
@@ -365,6 +423,8 @@ impl DateTimeFormatter {
     where
         P: DataProvider<TimeSymbolsV1Marker>
             + DataProvider<TimeLengthsV1Marker>
+            + DataProvider<QuarterSymbolsV1Marker>
+            + DataProvider<FlexibleDayPeriodsV1Marker>
             + DataProvider<OrdinalV1Marker>
             + DataProvider<WeekDataV1Marker>
             + DataProvider<DecimalSymbolsV1Marker>
@@ -505,6 +565,60 @@ where {
         Ok(self.format(value)?.write_to_string().into_owned())
     }
 
+    /// Parses a localized string in the format of this [`DateTimeFormatter`] back into a
+    /// [`DateTime`] of its calendar.
+    ///
+    /// See the [`parse`](crate::parse) module for how leniently the input is read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::DateTime;
+    /// use icu::datetime::{options::length, parse::Leniency, DateTimeFormatter};
+    /// use icu::locid::locale;
+    ///
+    /// let options = length::Bag::from_date_time_style(
+    ///     length::Date::Long,
+    ///     length::Time::Short,
+    /// );
+    /// let dtf = DateTimeFormatter::try_new(
+    ///     &locale!("en-u-ca-gregory").into(),
+    ///     options.into(),
+    /// )
+    /// .expect("Failed to create DateTimeFormatter instance.");
+    ///
+    /// let datetime = dtf
+    ///     .parse("March 5, 2024, 3:00\u{202f}PM", Leniency::Strict.into())
+    ///     .expect("Input is in the format of the formatter");
+    /// assert_eq!(
+    ///     datetime.to_iso(),
+    ///     DateTime::try_new_iso_datetime(2024, 3, 5, 15, 0, 0).unwrap()
+    /// );
+    /// ```
+    ///
+    /// <div class="stab unstable">
+    /// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+    /// including in SemVer minor releases. It can be enabled with the "experimental" Cargo feature
+    /// of the icu meta-crate. Use with caution.
+    /// </div>
+    #[cfg(feature = "experimental")]
+    pub fn parse(
+        &self,
+        input: &str,
+        options: crate::parse::ParseOptions,
+    ) -> Result<DateTime<AnyCalendar>, DateTimeError> {
+        let (date, time) = crate::parse::DateTimeParser {
+            patterns: &self.0.patterns.get().0,
+            date_symbols: self.0.date_symbols.as_ref().map(|s| s.get()),
+            time_symbols: self.0.time_symbols.as_ref().map(|s| s.get()),
+            fixed_decimal_format: &self.0.fixed_decimal_format,
+            calendar: &self.1,
+            options,
+        }
+        .parse(input)?;
+        Ok(DateTime::new(date, time))
+    }
+
     /// Returns a [`components::Bag`] that represents the resolved components for the
     /// options that were provided to the [`DateTimeFormatter`]. The developer may request
     /// a certain set of options for a [`DateTimeFormatter`] but the locale and resolution
@@ -536,7 +650,6 @@ where {
     ///
     /// assert_eq!(dtf.resolve_components(), expected_components_bag);
     /// ```
-    #[cfg(feature = "experimental")]
     pub fn resolve_components(&self) -> components::Bag {
         self.0.resolve_components()
     }
@@ -150,7 +150,7 @@ impl ZonedDateTimeFormatter {
     /// options.minute = Some(components::Numeric::Numeric);
     /// options.time_zone_name = Some(components::TimeZoneName::GmtOffset);
     ///
-    /// let zdtf = ZonedDateTimeFormatter::try_new_experimental(
+    /// let zdtf = ZonedDateTimeFormatter::try_new_with_components(
     ///     &locale!("en-u-ca-gregory").into(),
     ///     options.into(),
     ///     Default::default(),
@@ -164,12 +164,11 @@ impl ZonedDateTimeFormatter {
     ///
     /// assert_writeable_eq!(
     ///     zdtf.format(&any_datetime, &time_zone).unwrap(),
-    ///     "April 2021, 16:12 GMT-07:00"
+    ///     "April 2021, 4:12\u{202f}PM GMT-07:00"
     /// );
     /// ```
-    #[cfg(feature = "experimental")]
     #[cfg(feature = "compiled_data")]
-    pub fn try_new_experimental(
+    pub fn try_new_with_components(
         locale: &DataLocale,
         date_time_format_options: DateTimeFormatterOptions,
         time_zone_format_options: TimeZoneFormatterOptions,
@@ -202,11 +201,10 @@ impl ZonedDateTimeFormatter {
         ))
     }
 
-    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::try_new_experimental)]
-    #[cfg(feature = "experimental")]
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::try_new_with_components)]
     #[inline]
     #[allow(clippy::too_many_arguments)]
-    pub fn try_new_experimental_unstable<P>(
+    pub fn try_new_with_components_unstable<P>(
         provider: &P,
         locale: &DataLocale,
         date_time_format_options: DateTimeFormatterOptions,
@@ -215,7 +213,10 @@ impl ZonedDateTimeFormatter {
     where
         P: DataProvider<TimeSymbolsV1Marker>
             + DataProvider<TimeLengthsV1Marker>
+            + DataProvider<QuarterSymbolsV1Marker>
+            + DataProvider<FlexibleDayPeriodsV1Marker>
             + DataProvider<crate::provider::calendar::DateSkeletonPatternsV1Marker>
+            + DataProvider<crate::provider::calendar::AppendItemsV1Marker>
             + DataProvider<WeekDataV1Marker>
             + DataProvider<provider::time_zones::TimeZoneFormatsV1Marker>
             + DataProvider<provider::time_zones::ExemplarCitiesV1Marker>
@@ -278,6 +279,84 @@ impl ZonedDateTimeFormatter {
         ))
     }
 
+    /// Alias to [`ZonedDateTimeFormatter::try_new_with_components`].
+    #[deprecated(since = "1.3.0", note = "renamed to `try_new_with_components`")]
+    #[inline]
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new_experimental(
+        locale: &DataLocale,
+        date_time_format_options: DateTimeFormatterOptions,
+        time_zone_format_options: TimeZoneFormatterOptions,
+    ) -> Result<Self, DateTimeError> {
+        Self::try_new_with_components(locale, date_time_format_options, time_zone_format_options)
+    }
+
+    /// Alias to [`ZonedDateTimeFormatter::try_new_with_components_unstable`].
+    #[deprecated(
+        since = "1.3.0",
+        note = "renamed to `try_new_with_components_unstable`"
+    )]
+    #[inline]
+    pub fn try_new_experimental_unstable<P>(
+        provider: &P,
+        locale: &DataLocale,
+        date_time_format_options: DateTimeFormatterOptions,
+        time_zone_format_options: TimeZoneFormatterOptions,
+    ) -> Result<Self, DateTimeError>
+    where
+        P: DataProvider<TimeSymbolsV1Marker>
+            + DataProvider<TimeLengthsV1Marker>
+            + DataProvider<QuarterSymbolsV1Marker>
+            + DataProvider<FlexibleDayPeriodsV1Marker>
+            + DataProvider<crate::provider::calendar::DateSkeletonPatternsV1Marker>
+            + DataProvider<crate::provider::calendar::AppendItemsV1Marker>
+            + DataProvider<WeekDataV1Marker>
+            + DataProvider<provider::time_zones::TimeZoneFormatsV1Marker>
+            + DataProvider<provider::time_zones::ExemplarCitiesV1Marker>
+            + DataProvider<provider::time_zones::MetazoneGenericNamesLongV1Marker>
+            + DataProvider<provider::time_zones::MetazoneGenericNamesShortV1Marker>
+            + DataProvider<provider::time_zones::MetazoneSpecificNamesLongV1Marker>
+            + DataProvider<provider::time_zones::MetazoneSpecificNamesShortV1Marker>
+            + DataProvider<OrdinalV1Marker>
+            + DataProvider<DecimalSymbolsV1Marker>
+            + DataProvider<BuddhistDateLengthsV1Marker>
+            + DataProvider<BuddhistDateSymbolsV1Marker>
+            + DataProvider<ChineseDateLengthsV1Marker>
+            + DataProvider<ChineseDateSymbolsV1Marker>
+            + DataProvider<CopticDateLengthsV1Marker>
+            + DataProvider<CopticDateSymbolsV1Marker>
+            + DataProvider<DangiDateLengthsV1Marker>
+            + DataProvider<DangiDateSymbolsV1Marker>
+            + DataProvider<EthiopianDateLengthsV1Marker>
+            + DataProvider<EthiopianDateSymbolsV1Marker>
+            + DataProvider<GregorianDateLengthsV1Marker>
+            + DataProvider<GregorianDateSymbolsV1Marker>
+            + DataProvider<HebrewDateLengthsV1Marker>
+            + DataProvider<HebrewDateSymbolsV1Marker>
+            + DataProvider<IndianDateLengthsV1Marker>
+            + DataProvider<IndianDateSymbolsV1Marker>
+            + DataProvider<IslamicDateLengthsV1Marker>
+            + DataProvider<IslamicDateSymbolsV1Marker>
+            + DataProvider<JapaneseDateLengthsV1Marker>
+            + DataProvider<JapaneseDateSymbolsV1Marker>
+            + DataProvider<JapaneseErasV1Marker>
+            + DataProvider<JapaneseExtendedDateLengthsV1Marker>
+            + DataProvider<JapaneseExtendedDateSymbolsV1Marker>
+            + DataProvider<JapaneseExtendedErasV1Marker>
+            + DataProvider<PersianDateLengthsV1Marker>
+            + DataProvider<PersianDateSymbolsV1Marker>
+            + DataProvider<RocDateLengthsV1Marker>
+            + DataProvider<RocDateSymbolsV1Marker>
+            + ?Sized,
+    {
+        Self::try_new_with_components_unstable(
+            provider,
+            locale,
+            date_time_format_options,
+            time_zone_format_options,
+        )
+    }
+
     /// Constructor that takes a selected [`DataLocale`] and a list of [`DateTimeFormatterOptions`] and uses compiled data.
     /// It collects all data necessary to format zoned datetime values into the given locale.
     ///
@@ -368,6 +447,8 @@ impl ZonedDateTimeFormatter {
     where
         P: DataProvider<TimeSymbolsV1Marker>
             + DataProvider<TimeLengthsV1Marker>
+            + DataProvider<QuarterSymbolsV1Marker>
+            + DataProvider<FlexibleDayPeriodsV1Marker>
             + DataProvider<WeekDataV1Marker>
             + DataProvider<provider::time_zones::TimeZoneFormatsV1Marker>
             + DataProvider<provider::time_zones::ExemplarCitiesV1Marker>
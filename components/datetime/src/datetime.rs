@@ -7,7 +7,10 @@
 
 use crate::{
     options::{length, preferences, DateTimeFormatterOptions},
-    provider::calendar::{TimeLengthsV1Marker, TimeSymbolsV1Marker},
+    provider::calendar::{
+        FlexibleDayPeriodsV1Marker, QuarterSymbolsV1Marker, TimeLengthsV1Marker,
+        TimeSymbolsV1Marker,
+    },
     provider::date_time::PatternSelector,
     raw,
 };
@@ -24,7 +27,6 @@ use crate::{
     DateTimeError, FormattedDateTime,
 };
 
-#[cfg(feature = "experimental")]
 use crate::options::components;
 
 /// [`TimeFormatter`] is a structure of the [`icu_datetime`] component that provides time formatting only.
@@ -122,6 +124,7 @@ impl TimeFormatter {
     where
         D: DataProvider<TimeLengthsV1Marker>
             + DataProvider<TimeSymbolsV1Marker>
+            + DataProvider<FlexibleDayPeriodsV1Marker>
             + DataProvider<DecimalSymbolsV1Marker>
             + ?Sized,
     {
@@ -577,6 +580,8 @@ where {
             + DataProvider<<C as CldrCalendar>::DateLengthsV1Marker>
             + DataProvider<TimeSymbolsV1Marker>
             + DataProvider<TimeLengthsV1Marker>
+            + DataProvider<QuarterSymbolsV1Marker>
+            + DataProvider<FlexibleDayPeriodsV1Marker>
             + DataProvider<DecimalSymbolsV1Marker>
             + DataProvider<OrdinalV1Marker>
             + DataProvider<WeekDataV1Marker>
@@ -599,18 +604,125 @@ where {
         ))
     }
 
-    /// Constructor that supports experimental options using compiled data.
+    /// Constructor that takes a selected locale and a pattern string, such as
+    /// `"EEE, d MMM y HH:mm"`, then collects all compiled data necessary to format date and
+    /// time values with that pattern into the given locale.
+    ///
+    /// The pattern uses the [date field symbols of UTS 35]. Only the symbol data that the
+    /// pattern refers to is loaded: a pattern without month or weekday names, for example, does
+    /// not load any date symbols.
+    ///
+    /// Fractional seconds have to directly follow the seconds, as in `"HH:mm:ssSSS"`; they are
+    /// written with the decimal separator of the locale.
+    ///
+    /// Patterns containing time zone fields are rejected with
+    /// [`DateTimeError::UnsupportedField`]; use
+    /// [`TypedZonedDateTimeFormatter::try_new_with_pattern`](crate::TypedZonedDateTimeFormatter::try_new_with_pattern)
+    /// for those.
     ///
     /// ✨ *Enabled with the `compiled_data` Cargo feature.*
     ///
     /// [📚 Help choosing a constructor](icu_provider::constructors)
     ///
-    /// <div class="stab unstable">
-    /// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
-    /// including in SemVer minor releases. It can be enabled with the "experimental" Cargo feature
-    /// of the icu meta-crate. Use with caution.
-    /// <a href="https://github.com/unicode-org/icu4x/issues/1317">#1317</a>
-    /// </div>
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::{DateTime, Gregorian};
+    /// use icu::datetime::{DateTimeError, TypedDateTimeFormatter};
+    /// use icu::locid::locale;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let dtf = TypedDateTimeFormatter::<Gregorian>::try_new_with_pattern(
+    ///     &locale!("en").into(),
+    ///     "EEE, d MMM y HH:mm",
+    /// )
+    /// .unwrap();
+    ///
+    /// let datetime =
+    ///     DateTime::try_new_gregorian_datetime(2022, 8, 31, 1, 2, 3).unwrap();
+    ///
+    /// assert_writeable_eq!(dtf.format(&datetime), "Wed, 31 Aug 2022 01:02");
+    ///
+    /// // Time zones cannot be formatted by a `TypedDateTimeFormatter`.
+    /// assert!(matches!(
+    ///     TypedDateTimeFormatter::<Gregorian>::try_new_with_pattern(
+    ///         &locale!("en").into(),
+    ///         "HH:mm zzzz",
+    ///     ),
+    ///     Err(DateTimeError::UnsupportedField(_))
+    /// ));
+    /// ```
+    ///
+    /// [date field symbols of UTS 35]: https://unicode.org/reports/tr35/tr35-dates.html#Date_Field_Symbol_Table
+    /// [data provider]: icu_provider
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new_with_pattern(locale: &DataLocale, pattern: &str) -> Result<Self, DateTimeError>
+    where
+        crate::provider::Baked: DataProvider<<C as CldrCalendar>::DateSymbolsV1Marker>,
+    {
+        Ok(Self(
+            raw::DateTimeFormatter::try_new(
+                crate::provider::date_time::pattern_for_string(pattern)?,
+                || {
+                    calendar::load_symbols_for_cldr_calendar::<C, _>(
+                        &crate::provider::Baked,
+                        locale,
+                    )
+                },
+                locale,
+            )?,
+            PhantomData,
+        ))
+    }
+
+    icu_provider::gen_any_buffer_data_constructors!(
+        locale: include,
+        pattern: &str,
+        error: DateTimeError,
+        #[cfg(skip)]
+        functions: [
+            try_new_with_pattern,
+            try_new_with_pattern_with_any_provider,
+            try_new_with_pattern_with_buffer_provider,
+            try_new_with_pattern_unstable,
+            Self
+        ]
+    );
+
+    #[inline]
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::try_new_with_pattern)]
+    pub fn try_new_with_pattern_unstable<D>(
+        provider: &D,
+        locale: &DataLocale,
+        pattern: &str,
+    ) -> Result<Self, DateTimeError>
+    where
+        D: DataProvider<<C as CldrCalendar>::DateSymbolsV1Marker>
+            + DataProvider<TimeSymbolsV1Marker>
+            + DataProvider<TimeLengthsV1Marker>
+            + DataProvider<QuarterSymbolsV1Marker>
+            + DataProvider<FlexibleDayPeriodsV1Marker>
+            + DataProvider<DecimalSymbolsV1Marker>
+            + DataProvider<OrdinalV1Marker>
+            + DataProvider<WeekDataV1Marker>
+            + ?Sized,
+    {
+        Ok(Self(
+            raw::DateTimeFormatter::try_new_unstable(
+                provider,
+                crate::provider::date_time::pattern_for_string(pattern)?,
+                || calendar::load_symbols_for_cldr_calendar::<C, _>(provider, locale),
+                locale,
+            )?,
+            PhantomData,
+        ))
+    }
+
+    /// Constructor that also supports [`components::Bag`] options, using compiled data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
     ///
     /// # Examples
     ///
@@ -626,7 +738,7 @@ where {
     /// options.month = Some(components::Month::Long);
     ///
     /// let dtf =
-    ///     TypedDateTimeFormatter::<Gregorian>::try_new_experimental(
+    ///     TypedDateTimeFormatter::<Gregorian>::try_new_with_components(
     ///         &locale!("en").into(),
     ///         options.into(),
     ///     )
@@ -639,10 +751,9 @@ where {
     /// ```
     ///
     /// [data provider]: icu_provider
-    #[cfg(feature = "experimental")]
     #[inline]
     #[cfg(feature = "compiled_data")]
-    pub fn try_new_experimental(
+    pub fn try_new_with_components(
         locale: &DataLocale,
         options: DateTimeFormatterOptions,
     ) -> Result<Self, DateTimeError>
@@ -672,14 +783,7 @@ where {
         ))
     }
 
-    /// Constructor that supports experimental options.
-    ///
-    /// <div class="stab unstable">
-    /// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
-    /// including in SemVer minor releases. It can be enabled with the "experimental" Cargo feature
-    /// of the icu meta-crate. Use with caution.
-    /// <a href="https://github.com/unicode-org/icu4x/issues/1317">#1317</a>
-    /// </div>
+    /// Constructor that also supports [`components::Bag`] options.
     ///
     /// # Examples
     ///
@@ -695,7 +799,7 @@ where {
     /// options.month = Some(components::Month::Long);
     ///
     /// let dtf =
-    ///     TypedDateTimeFormatter::<Gregorian>::try_new_experimental(
+    ///     TypedDateTimeFormatter::<Gregorian>::try_new_with_components(
     ///         &locale!("en").into(),
     ///         options.into(),
     ///     )
@@ -708,9 +812,8 @@ where {
     /// ```
     ///
     /// [data provider]: icu_provider
-    #[cfg(feature = "experimental")]
     #[inline]
-    pub fn try_new_experimental_unstable<D>(
+    pub fn try_new_with_components_unstable<D>(
         provider: &D,
         locale: &DataLocale,
         options: DateTimeFormatterOptions,
@@ -720,7 +823,10 @@ where {
             + DataProvider<<C as CldrCalendar>::DateLengthsV1Marker>
             + DataProvider<TimeSymbolsV1Marker>
             + DataProvider<TimeLengthsV1Marker>
+            + DataProvider<QuarterSymbolsV1Marker>
+            + DataProvider<FlexibleDayPeriodsV1Marker>
             + DataProvider<crate::provider::calendar::DateSkeletonPatternsV1Marker>
+            + DataProvider<crate::provider::calendar::AppendItemsV1Marker>
             + DataProvider<DecimalSymbolsV1Marker>
             + DataProvider<OrdinalV1Marker>
             + DataProvider<WeekDataV1Marker>
@@ -744,6 +850,49 @@ where {
         ))
     }
 
+    /// Alias to [`TypedDateTimeFormatter::try_new_with_components`].
+    #[deprecated(since = "1.3.0", note = "renamed to `try_new_with_components`")]
+    #[inline]
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new_experimental(
+        locale: &DataLocale,
+        options: DateTimeFormatterOptions,
+    ) -> Result<Self, DateTimeError>
+    where
+        crate::provider::Baked: DataProvider<<C as CldrCalendar>::DateLengthsV1Marker>,
+        crate::provider::Baked: DataProvider<<C as CldrCalendar>::DateSymbolsV1Marker>,
+    {
+        Self::try_new_with_components(locale, options)
+    }
+
+    /// Alias to [`TypedDateTimeFormatter::try_new_with_components_unstable`].
+    #[deprecated(
+        since = "1.3.0",
+        note = "renamed to `try_new_with_components_unstable`"
+    )]
+    #[inline]
+    pub fn try_new_experimental_unstable<D>(
+        provider: &D,
+        locale: &DataLocale,
+        options: DateTimeFormatterOptions,
+    ) -> Result<Self, DateTimeError>
+    where
+        D: DataProvider<<C as CldrCalendar>::DateSymbolsV1Marker>
+            + DataProvider<<C as CldrCalendar>::DateLengthsV1Marker>
+            + DataProvider<TimeSymbolsV1Marker>
+            + DataProvider<TimeLengthsV1Marker>
+            + DataProvider<QuarterSymbolsV1Marker>
+            + DataProvider<FlexibleDayPeriodsV1Marker>
+            + DataProvider<crate::provider::calendar::DateSkeletonPatternsV1Marker>
+            + DataProvider<crate::provider::calendar::AppendItemsV1Marker>
+            + DataProvider<DecimalSymbolsV1Marker>
+            + DataProvider<OrdinalV1Marker>
+            + DataProvider<WeekDataV1Marker>
+            + ?Sized,
+    {
+        Self::try_new_with_components_unstable(provider, locale, options)
+    }
+
     /// Takes a [`DateTimeInput`] implementer and returns an instance of a [`FormattedDateTime`]
     /// that contains all information necessary to display a formatted date and operate on it.
     ///
@@ -822,7 +971,6 @@ where {
     ///
     /// assert_eq!(dtf.resolve_components(), expected_components_bag);
     /// ```
-    #[cfg(feature = "experimental")]
     pub fn resolve_components(&self) -> components::Bag {
         self.0.resolve_components()
     }
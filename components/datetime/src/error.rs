@@ -5,7 +5,6 @@
 use crate::fields::FieldSymbol;
 use crate::input::CalendarError;
 use crate::pattern::PatternError;
-#[cfg(feature = "experimental")]
 use crate::skeleton::SkeletonError;
 use displaydoc::Display;
 use icu_calendar::any_calendar::AnyCalendarKind;
@@ -38,7 +37,6 @@ pub enum DateTimeError {
     MissingInputField(Option<&'static str>),
     /// An error originating from skeleton matching.
     #[displaydoc("{0}")]
-    #[cfg(feature = "experimental")]
     Skeleton(SkeletonError),
     /// An error originating from an unsupported field in a datetime format.
     #[displaydoc("Unsupported field: {0:?}")]
@@ -76,6 +74,10 @@ pub enum DateTimeError {
     /// ordinal_rules must be set for PatternPlurals::MultipleVariants
     #[displaydoc("ordinal_rules must be set for PatternPlurals::MultipleVariants")]
     MissingOrdinalRules,
+    /// An error originating from parsing a datetime, with the byte index in the input at which
+    /// parsing failed.
+    #[displaydoc("Could not parse input at index {0}")]
+    Parse(usize),
 }
 
 impl From<PatternError> for DateTimeError {
@@ -96,7 +98,6 @@ impl From<core::fmt::Error> for DateTimeError {
     }
 }
 
-#[cfg(feature = "experimental")]
 impl From<SkeletonError> for DateTimeError {
     fn from(e: SkeletonError) -> Self {
         DateTimeError::Skeleton(e)
@@ -55,11 +55,11 @@ pub struct Field {
 }
 
 impl Field {
-    #[cfg(any(feature = "datagen", feature = "experimental"))] // only referenced in experimental code
     pub(crate) fn get_length_type(&self) -> TextOrNumeric {
         match self.symbol {
             FieldSymbol::Era => TextOrNumeric::Text,
             FieldSymbol::Year(year) => year.get_length_type(self.length),
+            FieldSymbol::Quarter(quarter) => quarter.get_length_type(self.length),
             FieldSymbol::Month(month) => month.get_length_type(self.length),
             FieldSymbol::Week(week) => week.get_length_type(self.length),
             FieldSymbol::Day(day) => day.get_length_type(self.length),
@@ -112,7 +112,7 @@ impl TryFrom<(FieldSymbol, usize)> for Field {
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::fields::{Field, FieldLength, FieldSymbol, Second, Year};
+    use crate::fields::{Field, FieldLength, FieldSymbol, Quarter, Second, Year};
     use zerovec::ule::{AsULE, ULE};
 
     #[test]
@@ -143,6 +143,13 @@ mod test {
                     FieldLength::One.idx(),
                 ],
             ),
+            (
+                Field::from((FieldSymbol::Quarter(Quarter::StandAlone), FieldLength::Wide)),
+                [
+                    FieldSymbol::Quarter(Quarter::StandAlone).idx(),
+                    FieldLength::Wide.idx(),
+                ],
+            ),
         ];
 
         for (ref_field, ref_bytes) in samples {
@@ -58,6 +58,10 @@ pub enum FieldSymbol {
     Second(Second),
     /// Time zone as a name, a zone ID, or a ISO 8601 numerical offset.
     TimeZone(TimeZone),
+    /// Quarter number or quarter name.
+    ///
+    /// This variant comes last to keep the serialized indices of the other variants stable.
+    Quarter(Quarter),
 }
 
 impl FieldSymbol {
@@ -109,6 +113,7 @@ impl FieldSymbol {
             FieldSymbol::Minute => (8, 0),
             FieldSymbol::Second(second) => (9, second.idx()),
             FieldSymbol::TimeZone(tz) => (10, tz.idx()),
+            FieldSymbol::Quarter(quarter) => (11, quarter.idx()),
         };
         let result = high << 4;
         result | low
@@ -133,31 +138,31 @@ impl FieldSymbol {
             8 if low == 0 => Self::Minute,
             9 => Self::Second(Second::from_idx(low)?),
             10 => Self::TimeZone(TimeZone::from_idx(low)?),
+            11 => Self::Quarter(Quarter::from_idx(low)?),
             _ => return Err(SymbolError::InvalidIndex(idx)),
         })
     }
 
     /// Returns the index associated with this FieldSymbol.
-    #[cfg(any(feature = "datagen", feature = "experimental"))] // only referenced in experimental code
     fn discriminant_idx(&self) -> u8 {
         match self {
             FieldSymbol::Era => 0,
             FieldSymbol::Year(_) => 1,
-            FieldSymbol::Month(_) => 2,
-            FieldSymbol::Week(_) => 3,
-            FieldSymbol::Day(_) => 4,
-            FieldSymbol::Weekday(_) => 5,
-            FieldSymbol::DayPeriod(_) => 6,
-            FieldSymbol::Hour(_) => 7,
-            FieldSymbol::Minute => 8,
-            FieldSymbol::Second(_) => 9,
-            FieldSymbol::TimeZone(_) => 10,
+            FieldSymbol::Quarter(_) => 2,
+            FieldSymbol::Month(_) => 3,
+            FieldSymbol::Week(_) => 4,
+            FieldSymbol::Day(_) => 5,
+            FieldSymbol::Weekday(_) => 6,
+            FieldSymbol::DayPeriod(_) => 7,
+            FieldSymbol::Hour(_) => 8,
+            FieldSymbol::Minute => 9,
+            FieldSymbol::Second(_) => 10,
+            FieldSymbol::TimeZone(_) => 11,
         }
     }
 
     /// Compares this enum with other solely based on the enum variant,
     /// ignoring the enum's data.
-    #[cfg(any(feature = "datagen", feature = "experimental"))] // only referenced in experimental code
     pub(crate) fn discriminant_cmp(&self, other: &Self) -> Ordering {
         self.discriminant_idx().cmp(&other.discriminant_idx())
     }
@@ -235,34 +240,37 @@ impl FieldSymbol {
             Self::Year(Year::WeekOf) => 2,
             Self::Year(Year::Cyclic) => 3,
             Self::Year(Year::RelatedIso) => 4,
-            Self::Month(Month::Format) => 5,
-            Self::Month(Month::StandAlone) => 6,
-            Self::Week(Week::WeekOfYear) => 7,
-            Self::Week(Week::WeekOfMonth) => 8,
-            Self::Day(Day::DayOfMonth) => 9,
-            Self::Day(Day::DayOfYear) => 10,
-            Self::Day(Day::DayOfWeekInMonth) => 11,
-            Self::Day(Day::ModifiedJulianDay) => 12,
-            Self::Weekday(Weekday::Format) => 13,
-            Self::Weekday(Weekday::Local) => 14,
-            Self::Weekday(Weekday::StandAlone) => 15,
-            Self::DayPeriod(DayPeriod::AmPm) => 16,
-            Self::DayPeriod(DayPeriod::NoonMidnight) => 17,
-            Self::Hour(Hour::H11) => 18,
-            Self::Hour(Hour::H12) => 19,
-            Self::Hour(Hour::H23) => 20,
-            Self::Hour(Hour::H24) => 21,
-            Self::Minute => 22,
-            Self::Second(Second::Second) => 23,
-            Self::Second(Second::FractionalSecond) => 24,
-            Self::Second(Second::Millisecond) => 25,
-            Self::TimeZone(TimeZone::LowerZ) => 26,
-            Self::TimeZone(TimeZone::UpperZ) => 27,
-            Self::TimeZone(TimeZone::UpperO) => 28,
-            Self::TimeZone(TimeZone::LowerV) => 29,
-            Self::TimeZone(TimeZone::UpperV) => 30,
-            Self::TimeZone(TimeZone::LowerX) => 31,
-            Self::TimeZone(TimeZone::UpperX) => 32,
+            Self::Quarter(Quarter::Format) => 5,
+            Self::Quarter(Quarter::StandAlone) => 6,
+            Self::Month(Month::Format) => 7,
+            Self::Month(Month::StandAlone) => 8,
+            Self::Week(Week::WeekOfYear) => 9,
+            Self::Week(Week::WeekOfMonth) => 10,
+            Self::Day(Day::DayOfMonth) => 11,
+            Self::Day(Day::DayOfYear) => 12,
+            Self::Day(Day::DayOfWeekInMonth) => 13,
+            Self::Day(Day::ModifiedJulianDay) => 14,
+            Self::Weekday(Weekday::Format) => 15,
+            Self::Weekday(Weekday::Local) => 16,
+            Self::Weekday(Weekday::StandAlone) => 17,
+            Self::DayPeriod(DayPeriod::AmPm) => 18,
+            Self::DayPeriod(DayPeriod::NoonMidnight) => 19,
+            Self::DayPeriod(DayPeriod::Flexible) => 20,
+            Self::Hour(Hour::H11) => 21,
+            Self::Hour(Hour::H12) => 22,
+            Self::Hour(Hour::H23) => 23,
+            Self::Hour(Hour::H24) => 24,
+            Self::Minute => 25,
+            Self::Second(Second::Second) => 26,
+            Self::Second(Second::FractionalSecond) => 27,
+            Self::Second(Second::Millisecond) => 28,
+            Self::TimeZone(TimeZone::LowerZ) => 29,
+            Self::TimeZone(TimeZone::UpperZ) => 30,
+            Self::TimeZone(TimeZone::UpperO) => 31,
+            Self::TimeZone(TimeZone::LowerV) => 32,
+            Self::TimeZone(TimeZone::UpperV) => 33,
+            Self::TimeZone(TimeZone::LowerX) => 34,
+            Self::TimeZone(TimeZone::UpperX) => 35,
         }
     }
 }
@@ -280,6 +288,7 @@ impl TryFrom<char> for FieldSymbol {
             Err(SymbolError::Unknown(ch))
         })
         .or_else(|_| Year::try_from(ch).map(Self::Year))
+        .or_else(|_| Quarter::try_from(ch).map(Self::Quarter))
         .or_else(|_| Month::try_from(ch).map(Self::Month))
         .or_else(|_| Week::try_from(ch).map(Self::Week))
         .or_else(|_| Day::try_from(ch).map(Self::Day))
@@ -303,6 +312,7 @@ impl From<FieldSymbol> for char {
         match symbol {
             FieldSymbol::Era => 'G',
             FieldSymbol::Year(year) => year.into(),
+            FieldSymbol::Quarter(quarter) => quarter.into(),
             FieldSymbol::Month(month) => month.into(),
             FieldSymbol::Week(week) => week.into(),
             FieldSymbol::Day(day) => day.into(),
@@ -457,6 +467,34 @@ field_type! (
     YearULE
 );
 
+field_type!(
+    /// An enum for the possible symbols of a quarter field in a date pattern.
+    Quarter; {
+        /// Field symbol for quarter number or name in a pattern that contains multiple fields.
+        'Q' => Format = 0,
+        /// Field symbol for a "stand-alone" quarter number or name.
+        ///
+        /// The stand-alone quarter name is used when the quarter is displayed by itself. This may differ from the standard form based on the language and context.
+        'q' => StandAlone = 1,
+}; QuarterULE);
+
+impl LengthType for Quarter {
+    fn get_length_type(&self, length: FieldLength) -> TextOrNumeric {
+        match length {
+            FieldLength::One => TextOrNumeric::Numeric,
+            FieldLength::TwoDigit => TextOrNumeric::Numeric,
+            FieldLength::Abbreviated => TextOrNumeric::Text,
+            FieldLength::Wide => TextOrNumeric::Text,
+            FieldLength::Narrow => TextOrNumeric::Text,
+            FieldLength::Six => TextOrNumeric::Text,
+            FieldLength::Fixed(_) => {
+                debug_assert!(false, "Fixed field length is only supported for seconds");
+                TextOrNumeric::Text
+            }
+        }
+    }
+}
+
 field_type!(
     /// An enum for the possible symbols of a month field in a date pattern.
     Month; {
@@ -589,6 +627,10 @@ field_type!(
         'a' => AmPm = 0,
         /// Field symbol for the am, pm, noon, midnight day period.
         'b' => NoonMidnight = 1,
+        /// Field symbol for flexible day periods, such as "at night" or "in the afternoon".
+        ///
+        /// The day periods and the times at which they start are locale-dependent.
+        'B' => Flexible = 2,
     };
     Text;
     DayPeriodULE
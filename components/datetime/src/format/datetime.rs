@@ -3,7 +3,7 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use crate::error::DateTimeError as Error;
-use crate::fields::{self, Field, FieldLength, FieldSymbol, Second, Week, Year};
+use crate::fields::{self, DayPeriod, Field, FieldLength, FieldSymbol, Second, Week, Year};
 use crate::input::{
     DateTimeInput, DateTimeInputWithWeekConfig, ExtractedDateTimeInput, LocalizedDateTimeInput,
 };
@@ -13,15 +13,154 @@ use crate::pattern::{
 };
 use crate::provider;
 use crate::provider::calendar::patterns::PatternPluralsFromPatternsV1Marker;
-use crate::provider::date_time::{DateSymbols, TimeSymbols};
+use crate::provider::date_time::{
+    DateSymbols, FlexibleDayPeriodSymbols, QuarterSymbols, TimeSymbols,
+};
 
-use core::fmt;
+use core::fmt::{self, Write};
+use core::iter::Peekable;
 use fixed_decimal::FixedDecimal;
 use icu_calendar::provider::WeekDataV1;
 use icu_decimal::FixedDecimalFormatter;
 use icu_plurals::PluralRules;
 use icu_provider::DataPayload;
-use writeable::Writeable;
+use writeable::{Part, PartsWrite, Writeable};
+
+/// The [`Part`](writeable::Part)s used by [`FormattedDateTime`] and
+/// [`FormattedZonedDateTime`](crate::FormattedZonedDateTime).
+///
+/// The values match the part types of `Intl.DateTimeFormat.prototype.formatToParts` in ECMA-402.
+/// Numeric fields additionally contain the parts of
+/// [`icu_decimal::parts`](icu_decimal::parts).
+pub mod parts {
+    use writeable::Part;
+
+    /// The [`Part`] used to mark the era.
+    ///
+    /// * `category`: `"datetime"`
+    /// * `value`: `"era"`
+    pub const ERA: Part = Part {
+        category: "datetime",
+        value: "era",
+    };
+
+    /// The [`Part`] used to mark the year.
+    ///
+    /// * `category`: `"datetime"`
+    /// * `value`: `"year"`
+    pub const YEAR: Part = Part {
+        category: "datetime",
+        value: "year",
+    };
+
+    /// The [`Part`] used to mark the related ISO year of a cyclic calendar.
+    ///
+    /// * `category`: `"datetime"`
+    /// * `value`: `"relatedYear"`
+    pub const RELATED_YEAR: Part = Part {
+        category: "datetime",
+        value: "relatedYear",
+    };
+
+    /// The [`Part`] used to mark the quarter of the year.
+    ///
+    /// * `category`: `"datetime"`
+    /// * `value`: `"quarter"`
+    pub const QUARTER: Part = Part {
+        category: "datetime",
+        value: "quarter",
+    };
+
+    /// The [`Part`] used to mark the month.
+    ///
+    /// * `category`: `"datetime"`
+    /// * `value`: `"month"`
+    pub const MONTH: Part = Part {
+        category: "datetime",
+        value: "month",
+    };
+
+    /// The [`Part`] used to mark the week of the year or of the month.
+    ///
+    /// * `category`: `"datetime"`
+    /// * `value`: `"week"`
+    pub const WEEK: Part = Part {
+        category: "datetime",
+        value: "week",
+    };
+
+    /// The [`Part`] used to mark the day of the week.
+    ///
+    /// * `category`: `"datetime"`
+    /// * `value`: `"weekday"`
+    pub const WEEKDAY: Part = Part {
+        category: "datetime",
+        value: "weekday",
+    };
+
+    /// The [`Part`] used to mark the day.
+    ///
+    /// * `category`: `"datetime"`
+    /// * `value`: `"day"`
+    pub const DAY: Part = Part {
+        category: "datetime",
+        value: "day",
+    };
+
+    /// The [`Part`] used to mark the day period, such as "AM", "PM" or "in the afternoon".
+    ///
+    /// * `category`: `"datetime"`
+    /// * `value`: `"dayPeriod"`
+    pub const DAY_PERIOD: Part = Part {
+        category: "datetime",
+        value: "dayPeriod",
+    };
+
+    /// The [`Part`] used to mark the hour.
+    ///
+    /// * `category`: `"datetime"`
+    /// * `value`: `"hour"`
+    pub const HOUR: Part = Part {
+        category: "datetime",
+        value: "hour",
+    };
+
+    /// The [`Part`] used to mark the minute.
+    ///
+    /// * `category`: `"datetime"`
+    /// * `value`: `"minute"`
+    pub const MINUTE: Part = Part {
+        category: "datetime",
+        value: "minute",
+    };
+
+    /// The [`Part`] used to mark the second, including fractional digits.
+    ///
+    /// * `category`: `"datetime"`
+    /// * `value`: `"second"`
+    pub const SECOND: Part = Part {
+        category: "datetime",
+        value: "second",
+    };
+
+    /// The [`Part`] used to mark the time zone.
+    ///
+    /// * `category`: `"datetime"`
+    /// * `value`: `"timeZoneName"`
+    pub const TIME_ZONE_NAME: Part = Part {
+        category: "datetime",
+        value: "timeZoneName",
+    };
+
+    /// The [`Part`] used to mark literal text from the pattern.
+    ///
+    /// * `category`: `"datetime"`
+    /// * `value`: `"literal"`
+    pub const LITERAL: Part = Part {
+        category: "datetime",
+        value: "literal",
+    };
+}
 
 /// [`FormattedDateTime`] is a intermediate structure which can be retrieved as
 /// an output from [`TypedDateTimeFormatter`](crate::TypedDateTimeFormatter).
@@ -54,6 +193,8 @@ pub struct FormattedDateTime<'l> {
     pub(crate) patterns: &'l DataPayload<PatternPluralsFromPatternsV1Marker>,
     pub(crate) date_symbols: Option<&'l provider::calendar::DateSymbolsV1<'l>>,
     pub(crate) time_symbols: Option<&'l provider::calendar::TimeSymbolsV1<'l>>,
+    pub(crate) quarter_symbols: Option<&'l provider::calendar::QuarterSymbolsV1<'l>>,
+    pub(crate) flexible_day_periods: Option<&'l provider::calendar::FlexibleDayPeriodsV1<'l>>,
     pub(crate) datetime: ExtractedDateTimeInput,
     pub(crate) week_data: Option<&'l WeekDataV1>,
     pub(crate) ordinal_rules: Option<&'l PluralRules>,
@@ -61,11 +202,13 @@ pub struct FormattedDateTime<'l> {
 }
 
 impl<'l> Writeable for FormattedDateTime<'l> {
-    fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+    fn write_to_parts<W: PartsWrite + ?Sized>(&self, sink: &mut W) -> fmt::Result {
         write_pattern_plurals(
             &self.patterns.get().0,
             self.date_symbols,
             self.time_symbols,
+            self.quarter_symbols,
+            self.flexible_day_periods,
             &self.datetime,
             self.week_data,
             self.ordinal_rules,
@@ -84,6 +227,14 @@ impl<'l> fmt::Display for FormattedDateTime<'l> {
     }
 }
 
+/// Whether `weekday` is formatted as the local day-of-week number rather than as text.
+///
+/// This is the case for the one and two letter forms of the local and stand-alone weekday fields.
+fn is_numeric_weekday(weekday: fields::Weekday, length: FieldLength) -> bool {
+    !matches!(weekday, fields::Weekday::Format)
+        && matches!(length, FieldLength::One | FieldLength::TwoDigit)
+}
+
 // Apply length to input number and write to result using fixed_decimal_format.
 fn format_number<W>(
     result: &mut W,
@@ -92,7 +243,7 @@ fn format_number<W>(
     length: FieldLength,
 ) -> fmt::Result
 where
-    W: fmt::Write + ?Sized,
+    W: PartsWrite + ?Sized,
 {
     match length {
         FieldLength::One => {}
@@ -119,35 +270,86 @@ where
     }
 
     let formatted = fixed_decimal_format.format(&num);
-    formatted.write_to(result)
+    formatted.write_to_parts(result)
 }
 
+/// Returns the [`Part`] that marks the output of a field with the given symbol.
+pub(super) fn field_part(symbol: FieldSymbol) -> Part {
+    match symbol {
+        FieldSymbol::Era => parts::ERA,
+        FieldSymbol::Year(Year::RelatedIso) => parts::RELATED_YEAR,
+        FieldSymbol::Year(_) => parts::YEAR,
+        FieldSymbol::Quarter(_) => parts::QUARTER,
+        FieldSymbol::Month(_) => parts::MONTH,
+        FieldSymbol::Week(_) => parts::WEEK,
+        FieldSymbol::Day(_) => parts::DAY,
+        FieldSymbol::Weekday(_) => parts::WEEKDAY,
+        FieldSymbol::DayPeriod(_) => parts::DAY_PERIOD,
+        FieldSymbol::Hour(_) => parts::HOUR,
+        FieldSymbol::Minute => parts::MINUTE,
+        FieldSymbol::Second(_) => parts::SECOND,
+        FieldSymbol::TimeZone(_) => parts::TIME_ZONE_NAME,
+    }
+}
+
+/// Writes `first` and all literals directly following it in `items` as a single
+/// [`parts::LITERAL`].
+pub(super) fn write_literals<I, W>(first: char, items: &mut Peekable<I>, w: &mut W) -> fmt::Result
+where
+    I: Iterator<Item = PatternItem>,
+    W: PartsWrite + ?Sized,
+{
+    w.with_part(parts::LITERAL, |w| {
+        w.write_char(first)?;
+        while let Some(PatternItem::Literal(ch)) =
+            items.next_if(|item| matches!(item, PatternItem::Literal(_)))
+        {
+            w.write_char(ch)?;
+        }
+        Ok(())
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
 fn write_pattern<T, W>(
     pattern: &crate::pattern::runtime::Pattern,
     date_symbols: Option<&provider::calendar::DateSymbolsV1>,
     time_symbols: Option<&provider::calendar::TimeSymbolsV1>,
-    loc_datetime: &impl LocalizedDateTimeInput<T>,
+    quarter_symbols: Option<&provider::calendar::QuarterSymbolsV1>,
+    flexible_day_periods: Option<&provider::calendar::FlexibleDayPeriodsV1>,
+    loc_datetime: &DateTimeInputWithWeekConfig<T>,
     fixed_decimal_format: &FixedDecimalFormatter,
     w: &mut W,
 ) -> Result<(), Error>
 where
     T: DateTimeInput,
-    W: fmt::Write + ?Sized,
+    W: PartsWrite + ?Sized,
 {
     let mut iter = pattern.items.iter().peekable();
     loop {
         match iter.next() {
-            Some(PatternItem::Field(field)) => write_field(
-                pattern,
-                field,
-                iter.peek(),
-                date_symbols,
-                time_symbols,
-                loc_datetime,
-                fixed_decimal_format,
-                w,
-            )?,
-            Some(PatternItem::Literal(ch)) => w.write_char(ch)?,
+            Some(PatternItem::Field(field)) => {
+                let next_item = iter.peek();
+                // `with_part` only propagates `fmt::Error`s, so keep the original error.
+                let mut result = Ok(());
+                w.with_part(field_part(field.symbol), |w| {
+                    result = write_field(
+                        pattern,
+                        field,
+                        next_item,
+                        date_symbols,
+                        time_symbols,
+                        quarter_symbols,
+                        flexible_day_periods,
+                        loc_datetime,
+                        fixed_decimal_format,
+                        w,
+                    );
+                    Ok(())
+                })?;
+                result?
+            }
+            Some(PatternItem::Literal(ch)) => write_literals(ch, &mut iter, w)?,
             None => break,
         }
     }
@@ -159,6 +361,8 @@ pub fn write_pattern_plurals<T, W>(
     patterns: &PatternPlurals,
     date_symbols: Option<&provider::calendar::DateSymbolsV1>,
     time_symbols: Option<&provider::calendar::TimeSymbolsV1>,
+    quarter_symbols: Option<&provider::calendar::QuarterSymbolsV1>,
+    flexible_day_periods: Option<&provider::calendar::FlexibleDayPeriodsV1>,
     datetime: &T,
     week_data: Option<&WeekDataV1>,
     ordinal_rules: Option<&PluralRules>,
@@ -167,7 +371,7 @@ pub fn write_pattern_plurals<T, W>(
 ) -> Result<(), Error>
 where
     T: DateTimeInput,
-    W: fmt::Write + ?Sized,
+    W: PartsWrite + ?Sized,
 {
     let loc_datetime = DateTimeInputWithWeekConfig::new(datetime, week_data.map(|v| v.into()));
     let pattern = patterns.select(&loc_datetime, ordinal_rules)?;
@@ -175,6 +379,8 @@ where
         pattern,
         date_symbols,
         time_symbols,
+        quarter_symbols,
+        flexible_day_periods,
         &loc_datetime,
         fixed_decimal_format,
         w,
@@ -193,13 +399,15 @@ pub(super) fn write_field<T, W>(
     next_item: Option<&PatternItem>,
     date_symbols: Option<&crate::provider::calendar::DateSymbolsV1>,
     time_symbols: Option<&crate::provider::calendar::TimeSymbolsV1>,
-    datetime: &impl LocalizedDateTimeInput<T>,
+    quarter_symbols: Option<&crate::provider::calendar::QuarterSymbolsV1>,
+    flexible_day_periods: Option<&crate::provider::calendar::FlexibleDayPeriodsV1>,
+    datetime: &DateTimeInputWithWeekConfig<T>,
     fixed_decimal_format: &FixedDecimalFormatter,
     w: &mut W,
 ) -> Result<(), Error>
 where
     T: DateTimeInput,
-    W: fmt::Write + ?Sized,
+    W: PartsWrite + ?Sized,
 {
     match field.symbol {
         FieldSymbol::Era => {
@@ -265,6 +473,29 @@ where
                 )?;
             }
         },
+        FieldSymbol::Quarter(quarter) => {
+            let ordinal = datetime
+                .datetime()
+                .month()
+                .ok_or(Error::MissingInputField(Some("month")))?
+                .ordinal;
+            // The thirteenth month of a calendar belongs to the fourth quarter.
+            let idx = (ordinal.saturating_sub(1) / 3).min(3) as usize;
+            match field.length {
+                FieldLength::One | FieldLength::TwoDigit => format_number(
+                    w,
+                    fixed_decimal_format,
+                    FixedDecimal::from(idx + 1),
+                    field.length,
+                )?,
+                length => {
+                    let symbol = quarter_symbols
+                        .ok_or(Error::MissingDateSymbols)?
+                        .get_symbol_for_quarter(quarter, length, idx)?;
+                    w.write_str(symbol)?
+                }
+            }
+        }
         FieldSymbol::Month(month) => match field.length {
             FieldLength::One | FieldLength::TwoDigit => format_number(
                 w,
@@ -318,6 +549,14 @@ where
                 field.length,
             )?,
         },
+        FieldSymbol::Weekday(weekday) if is_numeric_weekday(weekday, field.length) => {
+            format_number(
+                w,
+                fixed_decimal_format,
+                FixedDecimal::from(datetime.local_day_of_week()?),
+                field.length,
+            )?
+        }
         FieldSymbol::Weekday(weekday) => {
             let dow = datetime
                 .datetime()
@@ -433,6 +672,24 @@ where
         field @ FieldSymbol::Second(Second::Millisecond) => {
             return Err(Error::UnsupportedField(field))
         }
+        FieldSymbol::DayPeriod(DayPeriod::Flexible) => {
+            let symbol = flexible_day_periods
+                .ok_or(Error::MissingTimeSymbols)?
+                .get_symbol_for_flexible_day_period(
+                    field.length,
+                    datetime
+                        .datetime()
+                        .hour()
+                        .ok_or(Error::MissingInputField(Some("hour")))?,
+                    datetime.datetime().minute().unwrap_or_default(),
+                    pattern.time_granularity.is_top_of_hour(
+                        datetime.datetime().minute().map(u8::from).unwrap_or(0),
+                        datetime.datetime().second().map(u8::from).unwrap_or(0),
+                        datetime.datetime().nanosecond().map(u32::from).unwrap_or(0),
+                    ),
+                )?;
+            w.write_str(symbol)?
+        }
         FieldSymbol::DayPeriod(period) => {
             let symbol = time_symbols
                 .ok_or(Error::MissingTimeSymbols)?
@@ -465,6 +722,10 @@ pub struct RequiredData {
     pub time_symbols_data: bool,
     // WeekDataV1 is required.
     pub week_data: bool,
+    // QuarterSymbolsV1 is required.
+    pub quarter_symbols_data: bool,
+    // FlexibleDayPeriodsV1 is required.
+    pub flexible_day_periods_data: bool,
 }
 
 impl RequiredData {
@@ -489,23 +750,42 @@ impl RequiredData {
                     FieldSymbol::Month(_) => {
                         !matches!(field.length, FieldLength::One | FieldLength::TwoDigit)
                     }
-                    FieldSymbol::Weekday(_) => true,
+                    FieldSymbol::Weekday(weekday) => !is_numeric_weekday(weekday, field.length),
                     _ => false,
                 }
             }
             if !self.time_symbols_data {
-                self.time_symbols_data = matches!(field.symbol, FieldSymbol::DayPeriod(_));
+                self.time_symbols_data = matches!(
+                    field.symbol,
+                    FieldSymbol::DayPeriod(DayPeriod::AmPm | DayPeriod::NoonMidnight)
+                );
+            }
+
+            if !self.quarter_symbols_data {
+                self.quarter_symbols_data = matches!(field.symbol, FieldSymbol::Quarter(_))
+                    && !matches!(field.length, FieldLength::One | FieldLength::TwoDigit);
+            }
+
+            if !self.flexible_day_periods_data {
+                self.flexible_day_periods_data =
+                    field.symbol == FieldSymbol::DayPeriod(DayPeriod::Flexible);
             }
 
             if !self.week_data {
-                self.week_data = matches!(
-                    field.symbol,
-                    FieldSymbol::Year(Year::WeekOf) | FieldSymbol::Week(_)
-                )
+                self.week_data = match field.symbol {
+                    FieldSymbol::Year(Year::WeekOf) | FieldSymbol::Week(_) => true,
+                    FieldSymbol::Weekday(weekday) => is_numeric_weekday(weekday, field.length),
+                    _ => false,
+                }
             }
 
             if supports_time_zones {
-                if self.date_symbols_data && self.time_symbols_data && self.week_data {
+                if self.date_symbols_data
+                    && self.time_symbols_data
+                    && self.week_data
+                    && self.quarter_symbols_data
+                    && self.flexible_day_periods_data
+                {
                     // If we support time zones, and require everything else, we
                     // know all we need to return already.
                     return Ok(true);
@@ -564,6 +844,42 @@ mod tests {
         writeable::assert_writeable_eq!(dtf.format(&date).unwrap(), "Sep 1, 12 kansei-1789")
     }
 
+    #[test]
+    fn test_parts() {
+        use icu::calendar::{DateTime, Gregorian};
+        use icu::datetime::options::length;
+        use icu::datetime::TypedDateTimeFormatter;
+
+        let dtf = TypedDateTimeFormatter::<Gregorian>::try_new(
+            &icu_locid::locale!("en").into(),
+            length::Bag::from_date_time_style(length::Date::Medium, length::Time::Short).into(),
+        )
+        .unwrap();
+        let datetime = DateTime::try_new_gregorian_datetime(2023, 4, 5, 16, 7, 8).unwrap();
+
+        writeable::assert_writeable_parts_eq!(
+            dtf.format(&datetime),
+            "Apr 5, 2023, 4:07\u{202f}PM",
+            [
+                (0, 3, parts::MONTH),
+                (3, 4, parts::LITERAL),
+                (4, 5, parts::DAY),
+                (4, 5, icu_decimal::parts::INTEGER),
+                (5, 7, parts::LITERAL),
+                (7, 11, parts::YEAR),
+                (7, 11, icu_decimal::parts::INTEGER),
+                (11, 13, parts::LITERAL),
+                (13, 14, parts::HOUR),
+                (13, 14, icu_decimal::parts::INTEGER),
+                (14, 15, parts::LITERAL),
+                (15, 17, parts::MINUTE),
+                (15, 17, icu_decimal::parts::INTEGER),
+                (17, 20, parts::LITERAL),
+                (20, 22, parts::DAY_PERIOD),
+            ]
+        );
+    }
+
     #[test]
     #[cfg(feature = "serde")]
     fn test_basic() {
@@ -597,9 +913,11 @@ mod tests {
             &pattern,
             Some(date_data.get()),
             Some(time_data.get()),
+            None,
+            None,
             &loc_datetime,
             &fixed_decimal_format,
-            &mut sink,
+            &mut writeable::CoreWriteAsPartsWrite(&mut sink),
         )
         .unwrap();
         println!("{sink}");
@@ -630,7 +948,7 @@ mod tests {
             for (value, expected) in values.iter().zip(expected) {
                 let mut s = String::new();
                 format_number(
-                    &mut s,
+                    &mut writeable::CoreWriteAsPartsWrite(&mut s),
                     &fixed_decimal_format,
                     FixedDecimal::from(*value),
                     *length,
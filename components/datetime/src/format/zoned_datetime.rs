@@ -8,12 +8,12 @@ use crate::error::DateTimeError as Error;
 use crate::fields::{self, FieldSymbol};
 use crate::input::{
     DateTimeInput, DateTimeInputWithWeekConfig, ExtractedDateTimeInput, ExtractedTimeZoneInput,
-    LocalizedDateTimeInput, TimeZoneInput,
+    TimeZoneInput,
 };
 use crate::pattern::{runtime, PatternItem};
 use crate::{raw, FormattedTimeZone};
 use core::fmt;
-use writeable::Writeable;
+use writeable::{PartsWrite, Writeable};
 
 use super::datetime;
 
@@ -30,7 +30,7 @@ pub struct FormattedZonedDateTime<'l> {
 }
 
 impl<'l> Writeable for FormattedZonedDateTime<'l> {
-    fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+    fn write_to_parts<W: PartsWrite + ?Sized>(&self, sink: &mut W) -> fmt::Result {
         write_pattern(
             self.zoned_datetime_format,
             &self.datetime,
@@ -45,13 +45,7 @@ impl<'l> Writeable for FormattedZonedDateTime<'l> {
 
 impl<'l> fmt::Display for FormattedZonedDateTime<'l> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write_pattern(
-            self.zoned_datetime_format,
-            &self.datetime,
-            &self.time_zone,
-            f,
-        )
-        .map_err(|_| core::fmt::Error)
+        self.write_to(f)
     }
 }
 
@@ -64,7 +58,7 @@ pub(crate) fn write_pattern<D, Z, W>(
 where
     D: DateTimeInput,
     Z: TimeZoneInput,
-    W: fmt::Write + ?Sized,
+    W: PartsWrite + ?Sized,
 {
     let patterns = &zoned_datetime_format.datetime_format.patterns;
     let loc_datetime = DateTimeInputWithWeekConfig::new(
@@ -84,16 +78,24 @@ where
     let mut iter = pattern.items.iter().peekable();
     loop {
         match iter.next() {
-            Some(PatternItem::Field(field)) => write_field(
-                pattern,
-                field,
-                iter.peek(),
-                zoned_datetime_format,
-                &loc_datetime,
-                time_zone,
-                w,
-            )?,
-            Some(PatternItem::Literal(ch)) => w.write_char(ch)?,
+            Some(PatternItem::Field(field)) => {
+                let next_item = iter.peek();
+                let mut result = Ok(());
+                w.with_part(datetime::field_part(field.symbol), |w| {
+                    result = write_field(
+                        pattern,
+                        field,
+                        next_item,
+                        zoned_datetime_format,
+                        &loc_datetime,
+                        time_zone,
+                        w,
+                    );
+                    Ok(())
+                })?;
+                result?
+            }
+            Some(PatternItem::Literal(ch)) => datetime::write_literals(ch, &mut iter, w)?,
             None => break,
         }
     }
@@ -105,14 +107,14 @@ fn write_field<D, Z, W>(
     field: fields::Field,
     next_item: Option<&PatternItem>,
     zoned_datetime_format: &raw::ZonedDateTimeFormatter,
-    loc_datetime: &impl LocalizedDateTimeInput<D>,
+    loc_datetime: &DateTimeInputWithWeekConfig<D>,
     time_zone: &Z,
     w: &mut W,
 ) -> Result<(), Error>
 where
     D: DateTimeInput,
     Z: TimeZoneInput,
-    W: fmt::Write + ?Sized,
+    W: PartsWrite + ?Sized,
 {
     let date_symbols = zoned_datetime_format
         .datetime_format
@@ -126,6 +128,18 @@ where
         .as_ref()
         .map(|s| s.get());
 
+    let quarter_symbols = zoned_datetime_format
+        .datetime_format
+        .quarter_symbols
+        .as_ref()
+        .map(|s| s.get());
+
+    let flexible_day_periods = zoned_datetime_format
+        .datetime_format
+        .flexible_day_periods
+        .as_ref()
+        .map(|s| s.get());
+
     match field.symbol {
         FieldSymbol::TimeZone(_time_zone) => FormattedTimeZone {
             time_zone_format: &zoned_datetime_format.time_zone_format,
@@ -138,6 +152,8 @@ where
             next_item,
             date_symbols,
             time_symbols,
+            quarter_symbols,
+            flexible_day_periods,
             loc_datetime,
             &zoned_datetime_format.datetime_format.fixed_decimal_format,
             w,
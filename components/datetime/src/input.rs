@@ -267,6 +267,18 @@ impl<'data, T: DateTimeInput> DateTimeInputWithWeekConfig<'data, T> {
     pub(crate) fn new(data: &'data T, calendar: Option<WeekCalculator>) -> Self {
         Self { data, calendar }
     }
+
+    /// The 1-based day of the week relative to the locale's first day of the week.
+    ///
+    /// For example, Monday is the 2nd day of the week in en-US, but the 1st in de-DE.
+    pub(crate) fn local_day_of_week(&self) -> Result<u8, CalendarError> {
+        let config = self.calendar.ok_or(CalendarError::MissingCalendar)?;
+        let iso_weekday = self
+            .data
+            .iso_weekday()
+            .ok_or(CalendarError::MissingInput("DateTimeInput::iso_weekday"))?;
+        Ok(config.local_day_of_week(iso_weekday))
+    }
 }
 
 impl<'data, T: DateTimeInput> LocalizedDateTimeInput<T> for DateTimeInputWithWeekConfig<'data, T> {
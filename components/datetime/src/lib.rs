@@ -140,13 +140,14 @@ pub mod fields;
 mod format;
 pub mod input;
 pub mod options;
+#[cfg(feature = "experimental")]
+pub mod parse;
 #[doc(hidden)]
 pub mod pattern;
 pub mod provider;
 pub(crate) mod raw;
 #[doc(hidden)]
 #[allow(clippy::exhaustive_structs, clippy::exhaustive_enums)] // private-ish module
-#[cfg(any(feature = "datagen", feature = "experimental"))]
 pub mod skeleton;
 pub mod time_zone;
 mod zoned_datetime;
@@ -157,7 +158,7 @@ pub use any::{DateFormatter, DateTimeFormatter, ZonedDateTimeFormatter};
 pub use calendar::CldrCalendar;
 pub use datetime::{TimeFormatter, TypedDateFormatter, TypedDateTimeFormatter};
 pub use error::DateTimeError;
-pub use format::datetime::FormattedDateTime;
+pub use format::datetime::{parts, FormattedDateTime};
 pub use format::time_zone::FormattedTimeZone;
 pub use format::zoned_datetime::FormattedZonedDateTime;
 pub use options::DateTimeFormatterOptions;
@@ -205,21 +206,19 @@ mod tests {
     #[test]
     fn check_sizes() {
         check_size_of!(5800 | 4632, DateFormatter);
-        check_size_of!(6792 | 5504, DateTimeFormatter);
-        check_size_of!(7904 | 6552, ZonedDateTimeFormatter);
-        check_size_of!(1496 | 1344, TimeFormatter);
+        check_size_of!(7992 | 6704, DateTimeFormatter);
+        check_size_of!(9104 | 7752, ZonedDateTimeFormatter);
+        check_size_of!(1904 | 1752, TimeFormatter);
         check_size_of!(1112 | 1048, TimeZoneFormatter);
         check_size_of!(5752 | 4584, TypedDateFormatter::<Gregorian>);
-        check_size_of!(6744 | 5456, TypedDateTimeFormatter::<Gregorian>);
+        check_size_of!(7944 | 6656, TypedDateTimeFormatter::<Gregorian>);
 
         check_size_of!(88, DateTimeError);
-        check_size_of!(200, FormattedDateTime);
+        check_size_of!(216, FormattedDateTime);
         check_size_of!(16, FormattedTimeZone::<CustomTimeZone>);
         check_size_of!(184, FormattedZonedDateTime);
 
-        if cfg!(feature = "experimental") {
-            check_size_of!(13, DateTimeFormatterOptions);
-        }
+        check_size_of!(15, DateTimeFormatterOptions);
 
         type DP<M> = DataPayload<M>;
         check_size_of!(216, DP::<PatternPluralsFromPatternsV1Marker>);
@@ -2,28 +2,21 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-//! 🚧 \[Experimental\] Options for constructing DateTimeFormatter objects by each component style.
-//!
-//! <div class="stab unstable">
-//! 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
-//! including in SemVer minor releases. It can be enabled with the "experimental" Cargo feature
-//! of the icu meta-crate. Use with caution.
-//! <a href="https://github.com/unicode-org/icu4x/issues/1317">#1317</a>
-//! </div>
+//! Options for constructing DateTimeFormatter objects by each component style.
 //!
 //! # Implementation status
 //!
-//! This module is available by enabling the `"experimental"` Cargo feature.
-//! It may change in breaking ways, including across minor releases.
-//!
-//! This is currently only a partial implementation of the UTS-35 skeleton matching algorithm.
+//! The bag is matched with the UTS-35 skeleton matching algorithm.
 //!
 //! | Algorithm step | Status |
 //! |----------------|--------|
 //! | Match skeleton fields according to a ranking             | Implemented |
 //! | Adjust the matched pattern to have certain widths        | Implemented |
 //! | Match date and times separately, and them combine them   | Implemented |
-//! | Use appendItems to fill in a pattern with missing fields | Not yet, and may not be fully implemented. See [issue #586](https://github.com/unicode-org/icu4x/issues/586) |
+//! | Use appendItems to fill in a pattern with missing fields | Implemented |
+//! | Use the hour cycle of the locale, or of its `-u-hc` keyword | Implemented |
+//! | Match eras, weeks of the year and fractional seconds     | Implemented |
+//! | Match quarters and flexible day periods                  | Implemented |
 //!
 //! # Description
 //!
@@ -85,7 +78,7 @@ use crate::{
     pattern::{runtime::PatternPlurals, PatternItem},
 };
 
-#[cfg(feature = "experimental")]
+use crate::pattern::CoarseHourCycle;
 use alloc::vec::Vec;
 
 use super::preferences;
@@ -93,13 +86,6 @@ use super::preferences;
 use serde::{Deserialize, Serialize};
 
 /// See the [module-level](./index.html) docs for more information.
-///
-/// <div class="stab unstable">
-/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
-/// including in SemVer minor releases. It can be enabled with the "experimental" Cargo feature
-/// of the icu meta-crate. Use with caution.
-/// <a href="https://github.com/unicode-org/icu4x/issues/1317">#1317</a>
-/// </div>
 #[derive(Debug, Clone, PartialEq, Default, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[non_exhaustive]
@@ -108,6 +94,8 @@ pub struct Bag {
     pub era: Option<Text>,
     /// Include the year, such as "1970" or "70".
     pub year: Option<Year>,
+    /// Include the quarter, such as "Q2" or "2nd quarter".
+    pub quarter: Option<Quarter>,
     /// Include the month, such as "April" or "Apr".
     pub month: Option<Month>,
     /// Include the week number, such as "51st" or "51" for week 51.
@@ -117,6 +105,8 @@ pub struct Bag {
     /// Include the weekday, such as "Wednesday" or "Wed".
     pub weekday: Option<Text>,
 
+    /// Include the flexible day period, such as "in the afternoon" or "at night".
+    pub day_period: Option<Text>,
     /// Include the hour such as "2" or "14".
     pub hour: Option<Numeric>,
     /// Include the minute such as "3" or "03".
@@ -145,8 +135,10 @@ impl Bag {
     /// Converts the components::Bag into a Vec<Field>. The fields will be ordered in from most
     /// significant field to least significant. This is the order the fields are listed in
     /// the UTS 35 table - https://unicode.org/reports/tr35/tr35-dates.html#Date_Field_Symbol_Table
-    #[cfg(any(test, feature = "experimental"))] // only used in test and experimental code
-    pub(crate) fn to_vec_fields(&self) -> Vec<Field> {
+    ///
+    /// The hour uses `default_hour_cycle`, the hour cycle preferred by the locale, unless the
+    /// preferences set an hour cycle.
+    pub(crate) fn to_vec_fields(&self, default_hour_cycle: CoarseHourCycle) -> Vec<Field> {
         let mut fields = Vec::new();
         if let Some(era) = self.era {
             fields.push(Field {
@@ -187,9 +179,24 @@ impl Bag {
             });
         }
 
-        // TODO(#501) - Unimplemented quarter fields:
-        // Q - Quarter number/name
-        // q - Stand-alone quarter
+        if let Some(quarter) = self.quarter {
+            fields.push(Field {
+                // Always choose Quarter::Format as Quarter::StandAlone is not used in skeletons.
+                symbol: FieldSymbol::Quarter(fields::Quarter::Format),
+                length: match quarter {
+                    // Q      2          Numeric: 1 digit
+                    // QQ     02         Numeric: 2 digits + zero pad
+                    // QQQ    Q2         Abbreviated
+                    // QQQQ   2nd quarter  Wide
+                    // QQQQQ  2          Narrow
+                    Quarter::Numeric => FieldLength::One,
+                    Quarter::TwoDigit => FieldLength::TwoDigit,
+                    Quarter::Long => FieldLength::Wide,
+                    Quarter::Short => FieldLength::Abbreviated,
+                    Quarter::Narrow => FieldLength::Narrow,
+                },
+            });
+        }
 
         if let Some(month) = self.month {
             fields.push(Field {
@@ -266,7 +273,21 @@ impl Bag {
         // The period fields are not included in skeletons:
         // a - AM, PM
         // b - am, pm, noon, midnight
-        // c - flexible day periods
+        if let Some(day_period) = self.day_period {
+            fields.push(Field {
+                symbol: FieldSymbol::DayPeriod(fields::DayPeriod::Flexible),
+                length: match day_period {
+                    // Flexible day period, format length.
+                    //
+                    // B..BBB   in the afternoon  Abbreviated
+                    // BBBB     in the afternoon  Wide
+                    // BBBBB    in the afternoon  Narrow
+                    Text::Short => FieldLength::One,
+                    Text::Long => FieldLength::Wide,
+                    Text::Narrow => FieldLength::Narrow,
+                },
+            });
+        }
 
         if let Some(hour) = self.hour {
             // fields::Hour::H11
@@ -295,11 +316,10 @@ impl Bag {
                             fields::Hour::H23
                         }
                     },
-                    // TODO(#594) - This should default should be the locale default, which is
-                    // region-based (h12 for US, h23 for GB, etc). This is in CLDR, but we need
-                    // to load it as well as think about the best architecture for where that
-                    // data loading code should reside.
-                    _ => fields::Hour::H23,
+                    _ => match default_hour_cycle {
+                        CoarseHourCycle::H11H12 => fields::Hour::H12,
+                        CoarseHourCycle::H23H24 => fields::Hour::H23,
+                    },
                 }),
                 length: match hour {
                     // Example for h: (note that this is the same for k, K, and H)
@@ -366,13 +386,6 @@ impl Bag {
 
 /// A numeric component for the `components::`[`Bag`]. It is used for the year, day, hour, minute,
 /// and second.
-///
-/// <div class="stab unstable">
-/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
-/// including in SemVer minor releases. It can be enabled with the "experimental" Cargo feature
-/// of the icu meta-crate. Use with caution.
-/// <a href="https://github.com/unicode-org/icu4x/issues/1317">#1317</a>
-/// </div>
 #[derive(Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(
     feature = "serde",
@@ -388,13 +401,6 @@ pub enum Numeric {
 }
 
 /// A text component for the `components::`[`Bag`]. It is used for the era and weekday.
-///
-/// <div class="stab unstable">
-/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
-/// including in SemVer minor releases. It can be enabled with the "experimental" Cargo feature
-/// of the icu meta-crate. Use with caution.
-/// <a href="https://github.com/unicode-org/icu4x/issues/1317">#1317</a>
-/// </div>
 #[derive(Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(
     feature = "serde",
@@ -412,13 +418,6 @@ pub enum Text {
 }
 
 /// Options for displaying a Year for the `components::`[`Bag`].
-///
-/// <div class="stab unstable">
-/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
-/// including in SemVer minor releases. It can be enabled with the "experimental" Cargo feature
-/// of the icu meta-crate. Use with caution.
-/// <a href="https://github.com/unicode-org/icu4x/issues/1317">#1317</a>
-/// </div>
 #[derive(Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(
     feature = "serde",
@@ -440,13 +439,6 @@ pub enum Year {
 }
 
 /// Options for displaying a Month for the `components::`[`Bag`].
-///
-/// <div class="stab unstable">
-/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
-/// including in SemVer minor releases. It can be enabled with the "experimental" Cargo feature
-/// of the icu meta-crate. Use with caution.
-/// <a href="https://github.com/unicode-org/icu4x/issues/1317">#1317</a>
-/// </div>
 #[derive(Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(
     feature = "serde",
@@ -467,19 +459,33 @@ pub enum Month {
     Narrow,
 }
 
+/// Options for displaying a Quarter for the `components::`[`Bag`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+#[non_exhaustive]
+pub enum Quarter {
+    /// The numeric value of the quarter, such as "2".
+    Numeric,
+    /// The two-digit value of the quarter, such as "02".
+    TwoDigit,
+    /// The long value of the quarter, such as "2nd quarter".
+    Long,
+    /// The short value of the quarter, such as "Q2".
+    Short,
+    /// The narrow value of the quarter, such as "2".
+    Narrow,
+}
+
 // Each enum variant is documented with the UTS 35 field information from:
 // https://unicode.org/reports/tr35/tr35-dates.html#Date_Field_Symbol_Table
 
 /// Options for displaying the current week number for the `components::`[`Bag`].
 ///
 /// Week numbers are relative to either a month or year, e.g. 'week 3 of January' or 'week 40 of 2000'.
-///
-/// <div class="stab unstable">
-/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
-/// including in SemVer minor releases. It can be enabled with the "experimental" Cargo feature
-/// of the icu meta-crate. Use with caution.
-/// <a href="https://github.com/unicode-org/icu4x/issues/1317">#1317</a>
-/// </div>
 #[derive(Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(
     feature = "serde",
@@ -497,13 +503,6 @@ pub enum Week {
 }
 
 /// Options for displaying the current day of the month or year.
-///
-/// <div class="stab unstable">
-/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
-/// including in SemVer minor releases. It can be enabled with the "experimental" Cargo feature
-/// of the icu meta-crate. Use with caution.
-/// <a href="https://github.com/unicode-org/icu4x/issues/1317">#1317</a>
-/// </div>
 #[derive(Debug, Clone, PartialEq, Copy)]
 #[cfg_attr(
     feature = "serde",
@@ -524,13 +523,6 @@ pub enum Day {
 ///
 /// Note that the initial implementation is focusing on only supporting ECMA-402 compatible
 /// options.
-///
-/// <div class="stab unstable">
-/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
-/// including in SemVer minor releases. It can be enabled with the "experimental" Cargo feature
-/// of the icu meta-crate. Use with caution.
-/// <a href="https://github.com/unicode-org/icu4x/issues/1317">#1317</a>
-/// </div>
 #[derive(Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(
     feature = "serde",
@@ -641,6 +633,19 @@ impl<'data> From<&PatternPlurals<'data>> for Bag {
                         _ => todo!("TODO(#3762): Add support for U and r"),
                     });
                 }
+                FieldSymbol::Quarter(_) => {
+                    // `Quarter::StandAlone` is only relevant in the pattern, so only differentiate
+                    // on the field length.
+                    bag.quarter = Some(match field.length {
+                        FieldLength::One => Quarter::Numeric,
+                        FieldLength::TwoDigit => Quarter::TwoDigit,
+                        FieldLength::Abbreviated => Quarter::Short,
+                        FieldLength::Wide => Quarter::Long,
+                        FieldLength::Narrow | FieldLength::Six | FieldLength::Fixed(_) => {
+                            Quarter::Narrow
+                        }
+                    });
+                }
                 FieldSymbol::Month(_) => {
                     // `Month::StandAlone` is only relevant in the pattern, so only differentiate
                     // on the field length.
@@ -719,8 +724,15 @@ impl<'data> From<&PatternPlurals<'data>> for Bag {
                         fields::Weekday::Local => unimplemented!("fields::Weekday::Local"),
                     });
                 }
+                FieldSymbol::DayPeriod(fields::DayPeriod::Flexible) => {
+                    bag.day_period = Some(match field.length {
+                        FieldLength::Wide => Text::Long,
+                        FieldLength::Narrow => Text::Narrow,
+                        _ => Text::Short,
+                    });
+                }
                 FieldSymbol::DayPeriod(_) => {
-                    // Day period does not affect the resolved components.
+                    // The AM/PM day periods do not affect the resolved components.
                 }
                 FieldSymbol::Hour(hour) => {
                     bag.hour = Some(match field.length {
@@ -810,7 +822,7 @@ mod test {
             ..Default::default()
         };
         assert_eq!(
-            bag.to_vec_fields(),
+            bag.to_vec_fields(CoarseHourCycle::H23H24),
             [
                 (Symbol::Year(fields::Year::Calendar), Length::One).into(),
                 (Symbol::Month(fields::Month::Format), Length::Wide).into(),
@@ -837,7 +849,7 @@ mod test {
             ..Default::default()
         };
         assert_eq!(
-            bag.to_vec_fields(),
+            bag.to_vec_fields(CoarseHourCycle::H23H24),
             [
                 (Symbol::Year(fields::Year::Calendar), Length::One).into(),
                 (Symbol::Month(fields::Month::Format), Length::TwoDigit).into(),
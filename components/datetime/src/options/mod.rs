@@ -23,17 +23,14 @@
 //! );
 //! ```
 //!
-//! At the moment only the [`length::Bag`] works, and we plan to extend that to support
-//! `ECMA402`-like components bag later.
+//! The `ECMA402`-like [`components::Bag`] selects the fields to display instead, and needs
+//! the constructors that support it, such as
+//! [`TypedDateTimeFormatter::try_new_with_components`](crate::TypedDateTimeFormatter::try_new_with_components).
 
-#[cfg(any(feature = "datagen", feature = "experimental"))]
 pub mod components;
 pub mod length;
 
-#[cfg(any(feature = "datagen", feature = "experimental"))]
 pub mod preferences;
-#[cfg(not(any(feature = "datagen", feature = "experimental")))]
-pub(crate) mod preferences;
 
 /// A bag of options which, together with [`Locale`](icu_locid::Locale), defines how
 /// dates will be formatted with a [`TypedDateTimeFormatter`](crate::TypedDateTimeFormatter) instance.
@@ -53,22 +50,14 @@ pub(crate) mod preferences;
 /// );
 /// ```
 ///
-/// At the moment only the [`length::Bag`] works, and we plan to extend that to support
-/// `ECMA402` like components bag later.
+/// A [`components::Bag`] needs the constructors that support it, such as
+/// [`TypedDateTimeFormatter::try_new_with_components`](crate::TypedDateTimeFormatter::try_new_with_components).
 #[derive(Debug, Clone)]
 #[non_exhaustive]
 pub enum DateTimeFormatterOptions {
     /// Bag of lengths for date and time.
     Length(length::Bag),
     /// Bag of components describing which fields and how should be displayed.
-    ///
-    /// <div class="stab unstable">
-    /// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
-    /// including in SemVer minor releases. It can be enabled with the "experimental" Cargo feature
-    /// of the icu meta-crate. Use with caution.
-    /// <a href="https://github.com/unicode-org/icu4x/issues/1317">#1317</a>
-    /// </div>
-    #[cfg(feature = "experimental")]
     Components(components::Bag),
 }
 
@@ -84,7 +73,6 @@ impl From<length::Bag> for DateTimeFormatterOptions {
     }
 }
 
-#[cfg(feature = "experimental")]
 impl From<components::Bag> for DateTimeFormatterOptions {
     fn from(input: components::Bag) -> Self {
         Self::Components(input)
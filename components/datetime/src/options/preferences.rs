@@ -2,7 +2,7 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-//! 🚧 \[Experimental\] Types to hold user preferences to configure a DateTimeFormatter.
+//! Types to hold user preferences to configure a DateTimeFormatter.
 //!
 //! Preferences is a bag of options to be associated with either [`length::Bag`] or [`components::Bag`]
 //! which provides information on user preferences that can affect the result of the formatting.
@@ -10,13 +10,6 @@
 //! [`length::Bag`]: crate::options::length::Bag
 //! [`components::Bag`]: crate::options::components::Bag
 //!
-//! <div class="stab unstable">
-//! 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
-//! including in SemVer minor releases. It can be enabled with the "experimental" Cargo feature
-//! of the icu meta-crate. Use with caution.
-//! <a href="https://github.com/unicode-org/icu4x/issues/1317">#1317</a>
-//! </div>
-//!
 //! # Unicode Extensions
 //! User preferences will often be stored as part of the [`Locale`] identified as `Unicode Extensions`, but
 //! for scenarios where the application stores information about user preferences they can be also provided via
@@ -43,13 +36,6 @@ use tinystr::TinyAsciiStr;
 
 /// Stores user preferences which may affect the result of date and time formatting.
 ///
-/// <div class="stab unstable">
-/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
-/// including in SemVer minor releases. It can be enabled with the "experimental" Cargo feature
-/// of the icu meta-crate. Use with caution.
-/// <a href="https://github.com/unicode-org/icu4x/issues/1317">#1317</a>
-/// </div>
-///
 /// # Examples
 ///
 /// ```
@@ -70,7 +56,6 @@ pub struct Bag {
 
 impl Bag {
     /// Construct a [`Bag`] with a given [`HourCycle`]
-    #[cfg(feature = "experimental")]
     pub fn from_hour_cycle(h: HourCycle) -> Self {
         Self {
             hour_cycle: Some(h),
@@ -98,13 +83,6 @@ impl Bag {
 }
 
 /// A user preference for adjusting how the hour component is displayed.
-///
-/// <div class="stab unstable">
-/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
-/// including in SemVer minor releases. It can be enabled with the "experimental" Cargo feature
-/// of the icu meta-crate. Use with caution.
-/// <a href="https://github.com/unicode-org/icu4x/issues/1317">#1317</a>
-/// </div>
 #[derive(Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[allow(clippy::exhaustive_enums)] // this type is stable
@@ -0,0 +1,1025 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! 🚧 \[Experimental\] Options for parsing dates and times from localized strings.
+//!
+//! [`DateTimeFormatter::parse`](crate::DateTimeFormatter::parse) and
+//! [`DateFormatter::parse`](crate::DateFormatter::parse) read a date and time in the format of
+//! the formatter, using the same pattern and symbols data that are used for formatting.
+//!
+//! <div class="stab unstable">
+//! 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+//! including in SemVer minor releases. It can be enabled with the "experimental" Cargo feature
+//! of the icu meta-crate. Use with caution.
+//! </div>
+//!
+//! # Leniency
+//!
+//! In [`Leniency::Strict`] mode, the input has to match the pattern exactly, as it would be
+//! formatted: literals, the widths of names and the number of digits have to be the same, and
+//! the day of the week, if any, has to be the day of the week of the date.
+//!
+//! As when formatting, a month number is the position of the month in its year, which depends
+//! on the year in calendars with leap months: the seventh month of a Hebrew leap year is Adar
+//! II, and the seventh month of a common year is Nisan.
+//!
+//! In [`Leniency::Lenient`] mode, which is the default:
+//!
+//! * Names of months, days of the week, eras and day periods match in any width and case, and
+//!   the trailing period of an abbreviation is optional.
+//! * Numeric months may be given by name, and named months by number.
+//! * Numbers may have any number of digits, and may use ASCII digits as well as the digits of
+//!   the locale.
+//! * Any whitespace matches any whitespace, any punctuation matches any punctuation, and words
+//!   of the pattern may be left out. Words that are not in the pattern, such as "at", may come
+//!   before numbers.
+//! * The day of the week, if any, is not checked against the date.
+//!
+//! # Examples
+//!
+//! ```
+//! use icu::calendar::DateTime;
+//! use icu::datetime::{options::length, parse::Leniency, DateTimeFormatter};
+//! use icu::locid::locale;
+//!
+//! let options = length::Bag::from_date_time_style(
+//!     length::Date::Long,
+//!     length::Time::Short,
+//! );
+//! let dtf = DateTimeFormatter::try_new(
+//!     &locale!("en-u-ca-gregory").into(),
+//!     options.into(),
+//! )
+//! .expect("Failed to create DateTimeFormatter instance.");
+//!
+//! let datetime = dtf
+//!     .parse("march 5, 2024 3:00 pm", Leniency::Lenient.into())
+//!     .expect("Input should be lenient enough");
+//! assert_eq!(
+//!     datetime.to_iso(),
+//!     DateTime::try_new_iso_datetime(2024, 3, 5, 15, 0, 0).unwrap()
+//! );
+//!
+//! assert!(dtf
+//!     .parse("march 5, 2024 3:00 pm", Leniency::Strict.into())
+//!     .is_err());
+//! ```
+
+use crate::error::DateTimeError as Error;
+use crate::fields::{self, Field, FieldLength, FieldSymbol, Second, Year};
+use crate::pattern::{
+    runtime::{Pattern, PatternPlurals},
+    PatternItem,
+};
+use crate::provider::calendar::{months, DateSymbolsV1, TimeSymbolsV1};
+use crate::provider::date_time::{DateSymbols, TimeSymbols};
+use alloc::string::String;
+use core::iter::Peekable;
+use either::Either;
+use fixed_decimal::FixedDecimal;
+use icu_calendar::types::{Era, IsoHour, IsoWeekday, MonthCode, Time};
+use icu_calendar::{AnyCalendar, CalendarError, Date, DateDuration, Ref};
+use icu_decimal::FixedDecimalFormatter;
+use tinystr::{tinystr, TinyStr16, TinyStr4};
+use writeable::Writeable;
+use zerovec::ule::AsULE;
+
+/// How strictly the input of a parser has to match its pattern.
+///
+/// See the [module-level documentation](self) for the differences between the modes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum Leniency {
+    /// The input has to be in the format of the formatter.
+    Strict,
+    /// The input may deviate from the format of the formatter, as long as it is unambiguous.
+    #[default]
+    Lenient,
+}
+
+/// A bag of options to define how dates and times are parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub struct ParseOptions {
+    /// How strictly the input has to match the pattern.
+    pub leniency: Leniency,
+    /// The first year of the century that two-digit years are in, such as 1950 for the years
+    /// from 1950 to 2049.
+    ///
+    /// If this is `None`, the century starts 50 years before the year 2000 of the ISO calendar,
+    /// in the year numbering of the calendar of the formatter.
+    pub two_digit_year_start: Option<i32>,
+}
+
+impl From<Leniency> for ParseOptions {
+    fn from(leniency: Leniency) -> Self {
+        Self {
+            leniency,
+            ..Default::default()
+        }
+    }
+}
+
+/// The ISO year that years without an era, and two-digit years, are relative to.
+const REFERENCE_ISO_YEAR: i32 = 2000;
+
+/// The digits and the decimal separator of the locale, as written by a [`FixedDecimalFormatter`].
+struct NumberSymbols {
+    digits: [char; 10],
+    decimal_separator: String,
+}
+
+impl NumberSymbols {
+    fn new(fixed_decimal_format: &FixedDecimalFormatter) -> Self {
+        let mut digits = ['0'; 10];
+        for (value, digit) in (0u8..).zip(digits.iter_mut()) {
+            if let Some(c) = fixed_decimal_format
+                .format(&FixedDecimal::from(value))
+                .write_to_string()
+                .chars()
+                .next()
+            {
+                *digit = c;
+            }
+        }
+        // Formats "0.5" and removes the digits.
+        let mut half = FixedDecimal::from(5);
+        half.multiply_pow10(-1);
+        let half = fixed_decimal_format.format_to_string(&half);
+        let decimal_separator = half
+            .strip_prefix(digits[0])
+            .and_then(|rest| rest.strip_suffix(digits[5]))
+            .unwrap_or(".")
+            .into();
+        Self {
+            digits,
+            decimal_separator,
+        }
+    }
+}
+
+/// The fields read from the input.
+#[derive(Default)]
+struct ParsedFields {
+    era: Option<Era>,
+    /// The year, and whether it has to be completed with the century.
+    year: Option<(i32, bool)>,
+    month: Option<ParsedMonth>,
+    day: Option<u8>,
+    /// The day of the week, and its index in the input.
+    weekday: Option<(IsoWeekday, usize)>,
+    is_pm: Option<bool>,
+    /// The hour, its hour cycle, and its index in the input.
+    hour: Option<(u8, fields::Hour, usize)>,
+    minute: Option<u8>,
+    second: Option<u8>,
+    nanosecond: Option<u32>,
+}
+
+/// A month read from the input.
+#[derive(Clone, Copy)]
+enum ParsedMonth {
+    /// A month given by name.
+    Code(MonthCode),
+    /// A month given by number, whose code depends on the year in calendars with leap months.
+    Ordinal(u8),
+}
+
+/// Reads dates and times with the patterns and symbols of a formatter.
+pub(crate) struct DateTimeParser<'a> {
+    pub patterns: &'a PatternPlurals<'a>,
+    pub date_symbols: Option<&'a DateSymbolsV1<'a>>,
+    pub time_symbols: Option<&'a TimeSymbolsV1<'a>>,
+    pub fixed_decimal_format: &'a FixedDecimalFormatter,
+    pub calendar: &'a AnyCalendar,
+    pub options: ParseOptions,
+}
+
+impl<'a> DateTimeParser<'a> {
+    /// Parses the input with the first pattern that matches it.
+    pub fn parse(&self, input: &str) -> Result<(Date<AnyCalendar>, Time), Error> {
+        let numbers = NumberSymbols::new(self.fixed_decimal_format);
+        let mut error = Error::Parse(0);
+        for pattern in self.patterns.patterns_iter() {
+            match self.parse_pattern(pattern, input, &numbers) {
+                Ok(fields) => return self.resolve(fields),
+                // Reports the error of the pattern that matched the longest part of the input.
+                Err(Error::Parse(index)) if matches!(error, Error::Parse(i) if i <= index) => {
+                    error = Error::Parse(index)
+                }
+                Err(Error::Parse(_)) => {}
+                Err(e) => error = e,
+            }
+        }
+        Err(error)
+    }
+
+    fn is_lenient(&self) -> bool {
+        self.options.leniency == Leniency::Lenient
+    }
+
+    fn parse_pattern(
+        &self,
+        pattern: &Pattern,
+        input: &str,
+        numbers: &NumberSymbols,
+    ) -> Result<ParsedFields, Error> {
+        let mut fields = ParsedFields::default();
+        let mut index = self.skip_whitespace(input, 0);
+        let mut items = pattern.items.iter().peekable();
+        while let Some(item) = items.next() {
+            match item {
+                PatternItem::Literal(c) => {
+                    let mut literal = String::from(c);
+                    while let Some(PatternItem::Literal(c)) = items.peek() {
+                        literal.push(*c);
+                        items.next();
+                    }
+                    index = self.parse_literal(&literal, input, index)?;
+                }
+                PatternItem::Field(field) => {
+                    index = self.skip_words_before_field(field, input, index);
+                    index =
+                        self.parse_field(field, &mut items, input, index, numbers, &mut fields)?;
+                }
+            }
+        }
+        index = self.skip_whitespace(input, index);
+        if index != input.len() {
+            return Err(Error::Parse(index));
+        }
+        Ok(fields)
+    }
+
+    /// Skips whitespace in lenient mode.
+    fn skip_whitespace(&self, input: &str, index: usize) -> usize {
+        if !self.is_lenient() {
+            return index;
+        }
+        let rest = input.get(index..).unwrap_or_default();
+        index + rest.len() - rest.trim_start().len()
+    }
+
+    /// Skips whitespace in lenient mode, as well as words before numeric fields, such as "at" in
+    /// "March 5, 2024 at 3:00 PM" for a pattern without it.
+    fn skip_words_before_field(&self, field: Field, input: &str, index: usize) -> usize {
+        let index = self.skip_whitespace(input, index);
+        if !self.is_lenient()
+            || !matches!(
+                field.symbol,
+                FieldSymbol::Year(_)
+                    | FieldSymbol::Day(_)
+                    | FieldSymbol::Hour(_)
+                    | FieldSymbol::Minute
+                    | FieldSymbol::Second(_)
+            )
+        {
+            return index;
+        }
+        let rest = input.get(index..).unwrap_or_default();
+        match rest.find(|c: char| !c.is_alphabetic()) {
+            Some(word_len)
+                if word_len > 0
+                    && rest
+                        .get(word_len..)
+                        .map_or(false, |r| r.starts_with(char::is_whitespace)) =>
+            {
+                self.skip_whitespace(input, index + word_len)
+            }
+            _ => index,
+        }
+    }
+
+    fn parse_literal(&self, literal: &str, input: &str, mut index: usize) -> Result<usize, Error> {
+        let rest = input.get(index..).unwrap_or_default();
+        if rest.starts_with(literal) {
+            return Ok(index + literal.len());
+        }
+        if !self.is_lenient() {
+            return Err(Error::Parse(index));
+        }
+        let mut literal = literal;
+        while let Some(c) = literal.chars().next() {
+            let rest = input.get(index..).unwrap_or_default();
+            if c.is_whitespace() {
+                literal = literal.trim_start();
+                index = self.skip_whitespace(input, index);
+            } else if c.is_alphanumeric() {
+                // A word, which is optional.
+                let word_len = literal
+                    .find(|c: char| !c.is_alphanumeric())
+                    .unwrap_or(literal.len());
+                let (word, after) = literal.split_at(word_len);
+                if let Some(len) = match_text(rest, word, true) {
+                    index += len;
+                }
+                literal = after;
+            } else {
+                // Punctuation, which matches any punctuation, or nothing.
+                literal = literal.get(c.len_utf8()..).unwrap_or_default();
+                if let Some(d) = rest
+                    .chars()
+                    .next()
+                    .filter(|d| !d.is_alphanumeric() && !d.is_whitespace())
+                {
+                    index += d.len_utf8();
+                }
+            }
+        }
+        Ok(index)
+    }
+
+    fn parse_field(
+        &self,
+        field: Field,
+        items: &mut Peekable<impl Iterator<Item = PatternItem>>,
+        input: &str,
+        index: usize,
+        numbers: &NumberSymbols,
+        fields: &mut ParsedFields,
+    ) -> Result<usize, Error> {
+        let rest = input.get(index..).unwrap_or_default();
+        let lenient = self.is_lenient();
+        let not_found = Error::Parse(index);
+        let len = match field.symbol {
+            FieldSymbol::Era => {
+                let symbols = self.date_symbols.ok_or(Error::MissingDateSymbols)?;
+                let eras = match field.length {
+                    _ if lenient => Either::Left(
+                        [
+                            &symbols.eras.names,
+                            &symbols.eras.abbr,
+                            &symbols.eras.narrow,
+                        ]
+                        .into_iter()
+                        .flat_map(|eras| eras.iter())
+                        // The formatter falls back to the era code.
+                        .chain(
+                            symbols
+                                .eras
+                                .names
+                                .iter_keys()
+                                .filter_map(|code| Some((code, code.try_as_str().ok()?))),
+                        ),
+                    ),
+                    FieldLength::Wide => Either::Right(symbols.eras.names.iter()),
+                    FieldLength::Narrow => Either::Right(symbols.eras.narrow.iter()),
+                    _ => Either::Right(symbols.eras.abbr.iter()),
+                };
+                let (era, len) = self
+                    .match_symbol(
+                        rest,
+                        eras.filter_map(|(code, name)| {
+                            Some((Era(TinyStr16::from_bytes(code).ok()?), name))
+                        }),
+                    )
+                    .ok_or(not_found)?;
+                fields.era = Some(era);
+                len
+            }
+            FieldSymbol::Year(Year::Calendar) => {
+                let (min, max) = match field.length {
+                    FieldLength::TwoDigit if !lenient => (2, 2),
+                    _ if lenient => (1, 9),
+                    length => (numeric_width(length), 9),
+                };
+                let (year, digits, len) =
+                    parse_number(rest, numbers, min, max, lenient).ok_or(not_found)?;
+                let is_two_digit = field.length == FieldLength::TwoDigit && digits <= 2;
+                fields.year = Some((year as i32, is_two_digit));
+                len
+            }
+            FieldSymbol::Month(month) => {
+                let symbols = self.date_symbols;
+                let numeric = matches!(field.length, FieldLength::One | FieldLength::TwoDigit);
+                let number = if numeric || lenient {
+                    parse_number(rest, numbers, self.min_digits(field.length), 2, lenient)
+                        .and_then(|(ordinal, _, len)| Some((u8::try_from(ordinal).ok()?, len)))
+                } else {
+                    None
+                };
+                let (month, len) = match (number, symbols) {
+                    // The month code of a number depends on the year in calendars with leap
+                    // months.
+                    (Some((ordinal, len)), _) => (ParsedMonth::Ordinal(ordinal), len),
+                    (None, Some(symbols)) if !numeric || lenient => {
+                        let months = if lenient {
+                            Either::Left(all_month_symbols(&symbols.months))
+                        } else {
+                            Either::Right(core::iter::once(
+                                symbols.get_symbols_for_month(month, field.length)?,
+                            ))
+                        };
+                        let (code, len) = self
+                            .match_symbol(rest, months.flat_map(month_symbols))
+                            .ok_or(not_found)?;
+                        // The formatter marks leap months without a name of their own.
+                        match rest.get(len..).and_then(|rest| {
+                            match_text(rest, "(leap)", lenient).filter(|_| !numeric)
+                        }) {
+                            Some(leap_len) => (
+                                ParsedMonth::Code(leap_month_code(code).ok_or(not_found)?),
+                                len + leap_len,
+                            ),
+                            None => (ParsedMonth::Code(code), len),
+                        }
+                    }
+                    (None, None) if !numeric => return Err(Error::MissingDateSymbols),
+                    (None, _) => return Err(not_found),
+                };
+                fields.month = Some(month);
+                len
+            }
+            FieldSymbol::Weekday(weekday) => {
+                let symbols = self.date_symbols.ok_or(Error::MissingDateSymbols)?;
+                let (weekday, len) = if lenient {
+                    self.match_symbol(
+                        rest,
+                        all_weekday_symbols(&symbols.weekdays).flat_map(|symbols| {
+                            (0..)
+                                .map(IsoWeekday::from)
+                                .zip(symbols.0.iter().map(|s| &**s))
+                        }),
+                    )
+                } else {
+                    let mut names = [("", IsoWeekday::Sunday); 7];
+                    for (i, name) in names.iter_mut().enumerate() {
+                        let day = IsoWeekday::from(i);
+                        *name = (
+                            symbols.get_symbol_for_weekday(weekday, field.length, day)?,
+                            day,
+                        );
+                    }
+                    self.match_symbol(rest, names.into_iter().map(|(name, day)| (day, name)))
+                }
+                .ok_or(not_found)?;
+                fields.weekday = Some((weekday, index));
+                len
+            }
+            FieldSymbol::Day(fields::Day::DayOfMonth) => {
+                let (day, _, len) =
+                    parse_number(rest, numbers, self.min_digits(field.length), 2, lenient)
+                        .ok_or(not_found)?;
+                fields.day = Some(day as u8);
+                len
+            }
+            // Flexible day periods are not parsed, see the `UnsupportedField` arm below.
+            FieldSymbol::DayPeriod(day_period) if day_period != fields::DayPeriod::Flexible => {
+                let symbols = self.time_symbols.ok_or(Error::MissingTimeSymbols)?;
+                let (is_pm, len) = if lenient {
+                    let widths = &symbols.day_periods.format;
+                    self.match_symbol(
+                        rest,
+                        [
+                            Some(&widths.wide),
+                            Some(&widths.abbreviated),
+                            widths.short.as_ref(),
+                            Some(&widths.narrow),
+                        ]
+                        .into_iter()
+                        .flatten()
+                        .flat_map(day_period_symbols),
+                    )
+                } else {
+                    // Looks up the symbols of the width of the field.
+                    let am = symbols.get_symbol_for_day_period(
+                        day_period,
+                        field.length,
+                        IsoHour::try_from(0u8)?,
+                        false,
+                    )?;
+                    let pm = symbols.get_symbol_for_day_period(
+                        day_period,
+                        field.length,
+                        IsoHour::try_from(13u8)?,
+                        false,
+                    )?;
+                    let (midnight, noon) = match day_period {
+                        fields::DayPeriod::NoonMidnight => (
+                            symbols.get_symbol_for_day_period(
+                                day_period,
+                                field.length,
+                                IsoHour::try_from(0u8)?,
+                                true,
+                            )?,
+                            symbols.get_symbol_for_day_period(
+                                day_period,
+                                field.length,
+                                IsoHour::try_from(12u8)?,
+                                true,
+                            )?,
+                        ),
+                        _ => (am, pm),
+                    };
+                    self.match_symbol(
+                        rest,
+                        [(false, am), (true, pm), (false, midnight), (true, noon)],
+                    )
+                }
+                .ok_or(not_found)?;
+                fields.is_pm = Some(is_pm);
+                len
+            }
+            FieldSymbol::Hour(hour) => {
+                let (value, _, len) =
+                    parse_number(rest, numbers, self.min_digits(field.length), 2, lenient)
+                        .ok_or(not_found)?;
+                fields.hour = Some((value as u8, hour, index));
+                len
+            }
+            FieldSymbol::Minute => {
+                let (minute, _, len) =
+                    parse_number(rest, numbers, self.min_digits(field.length), 2, lenient)
+                        .ok_or(not_found)?;
+                fields.minute = Some(minute as u8);
+                len
+            }
+            FieldSymbol::Second(Second::Second) => {
+                let (second, _, mut len) =
+                    parse_number(rest, numbers, self.min_digits(field.length), 2, lenient)
+                        .ok_or(not_found)?;
+                fields.second = Some(second as u8);
+                // The fractional seconds are formatted along with the seconds.
+                if let Some(PatternItem::Field(next_field)) = items.peek() {
+                    if next_field.symbol == FieldSymbol::Second(Second::FractionalSecond) {
+                        let (min, max) = match next_field.length {
+                            FieldLength::Fixed(p) if !lenient => (p, p),
+                            _ => (1, 9),
+                        };
+                        let after = rest.get(len..).unwrap_or_default();
+                        let fraction = after.strip_prefix(numbers.decimal_separator.as_str()).map(
+                            |fraction| parse_number(fraction, numbers, min, max.min(9), lenient),
+                        );
+                        match fraction {
+                            Some(Some((fraction, digits, fraction_len))) => {
+                                fields.nanosecond = Some(fraction * 10u32.pow(9 - digits as u32));
+                                len += numbers.decimal_separator.len() + fraction_len;
+                            }
+                            None if lenient => {}
+                            _ => return Err(Error::Parse(index + len)),
+                        }
+                    }
+                }
+                len
+            }
+            FieldSymbol::Second(Second::FractionalSecond) => {
+                // Fractional seconds are parsed along with the seconds.
+                0
+            }
+            symbol => return Err(Error::UnsupportedField(symbol)),
+        };
+        Ok(index + len)
+    }
+
+    /// The minimum number of digits of a numeric field.
+    fn min_digits(&self, length: FieldLength) -> u8 {
+        if self.is_lenient() {
+            1
+        } else {
+            numeric_width(length)
+        }
+    }
+
+    /// Returns the value of the longest symbol at the start of the input, and its length in the
+    /// input.
+    fn match_symbol<'s, T>(
+        &self,
+        input: &str,
+        symbols: impl IntoIterator<Item = (T, &'s str)>,
+    ) -> Option<(T, usize)> {
+        symbols
+            .into_iter()
+            .filter_map(|(value, symbol)| {
+                match_text(input, symbol, self.is_lenient()).map(|len| (value, len))
+            })
+            .max_by_key(|&(_, len)| len)
+    }
+
+    /// Builds the date and time from the parsed fields.
+    fn resolve(&self, fields: ParsedFields) -> Result<(Date<AnyCalendar>, Time), Error> {
+        let reference = Date::try_new_iso_date(REFERENCE_ISO_YEAR, 1, 1)?
+            .to_calendar(Ref(self.calendar))
+            .year();
+        let (year, is_two_digit) = fields.year.ok_or(Error::MissingInputField(Some("year")))?;
+        let year = if is_two_digit {
+            let start = self
+                .options
+                .two_digit_year_start
+                .unwrap_or(reference.number - 50);
+            start + (year - start).rem_euclid(100)
+        } else {
+            year
+        };
+        let era = fields.era.unwrap_or(reference.era);
+        let month = match fields
+            .month
+            .ok_or(Error::MissingInputField(Some("month")))?
+        {
+            ParsedMonth::Code(code) => code,
+            ParsedMonth::Ordinal(ordinal) => self.ordinal_month_code(era, year, ordinal)?,
+        };
+        let date = Date::try_new_from_codes(
+            era,
+            year,
+            month,
+            fields
+                .day
+                .ok_or(Error::MissingInputField(Some("day_of_month")))?,
+            self.calendar.clone(),
+        )?;
+        if let Some((weekday, index)) = fields.weekday {
+            if !self.is_lenient() && date.day_of_week() != weekday {
+                return Err(Error::Parse(index));
+            }
+        }
+
+        let hour = match fields.hour {
+            Some((hour, hour_cycle, index)) => {
+                let pm_offset = if fields.is_pm == Some(true) { 12 } else { 0 };
+                match hour_cycle {
+                    fields::Hour::H11 if hour < 12 => hour + pm_offset,
+                    fields::Hour::H12 if (1..=12).contains(&hour) => hour % 12 + pm_offset,
+                    fields::Hour::H23 if hour < 24 => hour,
+                    fields::Hour::H24 if (1..=24).contains(&hour) => hour % 24,
+                    _ => return Err(Error::Parse(index)),
+                }
+            }
+            None => 0,
+        };
+        let time = Time::try_new(
+            hour,
+            fields.minute.unwrap_or_default(),
+            fields.second.unwrap_or_default(),
+            fields.nanosecond.unwrap_or_default(),
+        )?;
+        Ok((date, time))
+    }
+
+    /// Returns the code of the month with the given number in a year.
+    ///
+    /// This is not [`month_code`] in calendars with leap months, where for example the sixth
+    /// month of a Hebrew leap year is Adar I (`M05L`).
+    fn ordinal_month_code(&self, era: Era, year: i32, ordinal: u8) -> Result<MonthCode, Error> {
+        let mut date = Date::try_new_from_codes(
+            era,
+            year,
+            MonthCode(tinystr!(4, "M01")),
+            1,
+            Ref(self.calendar),
+        )?;
+        let months_in_year = date.months_in_year();
+        if !(1..=months_in_year).contains(&ordinal) {
+            return Err(CalendarError::Overflow {
+                field: "month",
+                max: months_in_year.into(),
+            }
+            .into());
+        }
+        while date.month().ordinal < ordinal.into() {
+            // Go to the first day of the next month.
+            let days = DateDuration::new(0, 0, 0, date.days_in_month().into());
+            date = date.to_iso().added(days).to_calendar(Ref(self.calendar));
+        }
+        Ok(date.month().code)
+    }
+}
+
+/// The number of digits that a numeric field of this length is padded to.
+fn numeric_width(length: FieldLength) -> u8 {
+    match length {
+        FieldLength::One => 1,
+        FieldLength::TwoDigit => 2,
+        FieldLength::Abbreviated => 3,
+        FieldLength::Wide => 4,
+        FieldLength::Narrow => 5,
+        FieldLength::Six => 6,
+        FieldLength::Fixed(p) => p,
+    }
+}
+
+/// Parses a number of `min` to `max` digits, returning its value, its number of digits and its
+/// length in the input.
+fn parse_number(
+    input: &str,
+    numbers: &NumberSymbols,
+    min: u8,
+    max: u8,
+    lenient: bool,
+) -> Option<(u32, u8, usize)> {
+    let mut value: u32 = 0;
+    let mut digits = 0;
+    let mut len = 0;
+    for c in input.chars().take(max as usize) {
+        let digit = match numbers.digits.iter().position(|&d| d == c) {
+            Some(digit) => digit as u32,
+            None => match c.to_digit(10).filter(|_| lenient && c.is_ascii()) {
+                Some(digit) => digit,
+                None => break,
+            },
+        };
+        value = value * 10 + digit;
+        digits += 1;
+        len += c.len_utf8();
+    }
+    if digits == 0 || digits < min {
+        return None;
+    }
+    Some((value, digits, len))
+}
+
+/// Returns the length of `symbol` at the start of the input, if it is there.
+///
+/// In lenient mode, the comparison ignores case and the kind of whitespace, and a period after
+/// the symbol is optional, whether the symbol ends with one or not.
+fn match_text(input: &str, symbol: &str, lenient: bool) -> Option<usize> {
+    if symbol.is_empty() {
+        return None;
+    }
+    if !lenient {
+        return input.starts_with(symbol).then_some(symbol.len());
+    }
+    let mut len = 0;
+    let mut input_chars = input.chars();
+    let mut symbol_chars = symbol.chars();
+    while let Some(s) = symbol_chars.next() {
+        let matches = input_chars.clone().next().filter(|&c| {
+            c.to_lowercase().eq(s.to_lowercase()) || (c.is_whitespace() && s.is_whitespace())
+        });
+        match matches {
+            Some(c) => {
+                input_chars.next();
+                len += c.len_utf8();
+            }
+            None if s == '.' && symbol_chars.as_str().is_empty() => return Some(len),
+            None => return None,
+        }
+    }
+    if !symbol.ends_with('.') && input_chars.as_str().starts_with('.') {
+        len += 1;
+    }
+    Some(len)
+}
+
+/// Returns the code of the month with the given number in a calendar without leap months.
+fn month_code(ordinal: u32) -> Option<MonthCode> {
+    let ordinal = u8::try_from(ordinal)
+        .ok()
+        .filter(|o| (1..100).contains(o))?;
+    TinyStr4::from_bytes(&[b'M', b'0' + ordinal / 10, b'0' + ordinal % 10])
+        .ok()
+        .map(MonthCode)
+}
+
+/// Returns the code of the leap month after the given month.
+fn leap_month_code(code: MonthCode) -> Option<MonthCode> {
+    let mut bytes = [b'L'; 4];
+    bytes
+        .get_mut(..3)?
+        .copy_from_slice(code.0.as_bytes().get(..3)?);
+    TinyStr4::from_bytes(&bytes).ok().map(MonthCode)
+}
+
+fn month_symbols<'s>(
+    symbols: &'s months::SymbolsV1<'s>,
+) -> impl Iterator<Item = (MonthCode, &'s str)> {
+    match symbols {
+        months::SymbolsV1::SolarTwelve(names) => Either::Left(
+            (1..)
+                .filter_map(month_code)
+                .zip(names.iter().map(|name| &**name)),
+        ),
+        months::SymbolsV1::Other(names) => Either::Right(
+            names
+                .iter()
+                .map(|(code, name)| (MonthCode::from_unaligned(*code), name)),
+        ),
+    }
+}
+
+fn all_month_symbols<'s>(
+    contexts: &'s months::ContextsV1<'s>,
+) -> impl Iterator<Item = &'s months::SymbolsV1<'s>> {
+    let format = &contexts.format;
+    [
+        Some(&format.wide),
+        Some(&format.abbreviated),
+        format.short.as_ref(),
+        Some(&format.narrow),
+    ]
+    .into_iter()
+    .chain(contexts.stand_alone.iter().flat_map(|stand_alone| {
+        [
+            stand_alone.wide.as_ref(),
+            stand_alone.abbreviated.as_ref(),
+            stand_alone.short.as_ref(),
+            stand_alone.narrow.as_ref(),
+        ]
+    }))
+    .flatten()
+}
+
+fn all_weekday_symbols<'s>(
+    contexts: &'s crate::provider::calendar::weekdays::ContextsV1<'s>,
+) -> impl Iterator<Item = &'s crate::provider::calendar::weekdays::SymbolsV1<'s>> {
+    let format = &contexts.format;
+    [
+        Some(&format.wide),
+        Some(&format.abbreviated),
+        format.short.as_ref(),
+        Some(&format.narrow),
+    ]
+    .into_iter()
+    .chain(contexts.stand_alone.iter().flat_map(|stand_alone| {
+        [
+            stand_alone.wide.as_ref(),
+            stand_alone.abbreviated.as_ref(),
+            stand_alone.short.as_ref(),
+            stand_alone.narrow.as_ref(),
+        ]
+    }))
+    .flatten()
+}
+
+/// Returns the day periods, and whether they are after noon.
+fn day_period_symbols<'s>(
+    symbols: &'s crate::provider::calendar::day_periods::SymbolsV1<'s>,
+) -> impl Iterator<Item = (bool, &'s str)> {
+    [
+        (false, Some(&*symbols.am)),
+        (true, Some(&*symbols.pm)),
+        (false, symbols.midnight.as_deref()),
+        (true, symbols.noon.as_deref()),
+    ]
+    .into_iter()
+    .filter_map(|(is_pm, symbol)| Some((is_pm, symbol?)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{options::length, DateFormatter, DateTimeFormatter};
+    use icu::calendar::DateTime;
+    use icu::locid::{locale, Locale};
+
+    fn formatter(locale: &Locale) -> DateTimeFormatter {
+        let options = length::Bag::from_date_time_style(length::Date::Full, length::Time::Medium);
+        DateTimeFormatter::try_new(&locale.into(), options.into()).unwrap()
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let iso = DateTime::try_new_iso_datetime(2023, 10, 19, 16, 5, 9).unwrap();
+        for locale in [
+            locale!("en"),
+            locale!("fr"),
+            locale!("ar-EG"),
+            locale!("th"),
+            locale!("en-u-ca-buddhist"),
+            locale!("en-u-ca-coptic"),
+            locale!("en-u-ca-indian"),
+        ] {
+            let dtf = formatter(&locale);
+            let formatted = dtf.format_to_string(&iso.to_any()).unwrap();
+            for leniency in [Leniency::Strict, Leniency::Lenient] {
+                assert_eq!(
+                    dtf.parse(&formatted, leniency.into()).map(|dt| dt.to_iso()),
+                    Ok(iso),
+                    "{locale}: {formatted}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_leap_months() {
+        let df = DateFormatter::try_new_with_length(
+            &locale!("en-u-ca-hebrew").into(),
+            length::Date::Medium,
+        )
+        .unwrap();
+        let parse = |input| {
+            df.parse(input, Leniency::Lenient.into())
+                .map(|date| date.to_iso())
+        };
+        let date = |year, month, day| Date::try_new_iso_date(year, month, day).map_err(Error::from);
+        // 5784 is a leap year, whose sixth and seventh months are Adar I and Adar II.
+        assert_eq!(parse("10 Adar II 5784"), date(2024, 3, 20));
+        assert_eq!(parse("10 7 5784"), date(2024, 3, 20));
+        assert_eq!(parse("10 6 5784"), date(2024, 2, 19));
+        // The seventh month of 5783 is Nisan.
+        assert_eq!(parse("10 7 5783"), date(2023, 4, 1));
+        assert_eq!(
+            parse("10 13 5783"),
+            Err(CalendarError::Overflow {
+                field: "month",
+                max: 12
+            }
+            .into())
+        );
+    }
+
+    #[test]
+    fn test_native_digits() {
+        let dtf = formatter(&locale!("ar-EG"));
+        let formatted = dtf
+            .format_to_string(
+                &DateTime::try_new_iso_datetime(2023, 10, 19, 16, 5, 9)
+                    .unwrap()
+                    .to_any(),
+            )
+            .unwrap();
+        assert!(formatted.contains('٢'), "{formatted}");
+        let ascii: String = formatted
+            .chars()
+            .map(|c| match c {
+                '٠'..='٩' => char::from(b'0' + (c as u32 - '٠' as u32) as u8),
+                c => c,
+            })
+            .collect();
+        assert!(dtf.parse(&ascii, Leniency::Strict.into()).is_err());
+        assert_eq!(
+            dtf.parse(&ascii, Leniency::Lenient.into())
+                .map(|dt| dt.to_iso()),
+            DateTime::try_new_iso_datetime(2023, 10, 19, 16, 5, 9).map_err(Error::from)
+        );
+    }
+
+    #[test]
+    fn test_lenient() {
+        let options = length::Bag::from_date_time_style(length::Date::Long, length::Time::Short);
+        let dtf = DateTimeFormatter::try_new(&locale!("en").into(), options.into()).unwrap();
+        let expected = DateTime::try_new_iso_datetime(2024, 3, 5, 15, 0, 0).unwrap();
+        for input in [
+            "March 5, 2024, 3:00\u{202f}PM",
+            "march 5, 2024 at 3:00 pm",
+            "  Mar. 5 2024, 3:00 p  ",
+            "MARCH 05, 2024 3:00PM",
+            "3 5, 2024 at 3:00 pm",
+        ] {
+            assert_eq!(
+                dtf.parse(input, Leniency::Lenient.into())
+                    .map(|dt| dt.to_iso()),
+                Ok(expected),
+                "{input}"
+            );
+        }
+        for input in [
+            "march 5, 2024, 3:00 pm",
+            "Mar 5, 2024, 3:00\u{202f}PM",
+            "March 5, 2024 at 3:00\u{202f}PM",
+        ] {
+            assert!(
+                dtf.parse(input, Leniency::Strict.into()).is_err(),
+                "{input}"
+            );
+        }
+        assert_eq!(
+            dtf.parse(
+                "March 5, 2024 at 3:00 PM tomorrow",
+                Leniency::Lenient.into()
+            ),
+            Err(Error::Parse(25))
+        );
+    }
+
+    #[test]
+    fn test_two_digit_years() {
+        let df =
+            DateFormatter::try_new_with_length(&locale!("en").into(), length::Date::Short).unwrap();
+        let parse = |input, two_digit_year_start| {
+            let mut options = ParseOptions::from(Leniency::Strict);
+            options.two_digit_year_start = two_digit_year_start;
+            df.parse(input, options).map(|date| date.to_iso())
+        };
+        let date = |year| Date::try_new_iso_date(year, 5, 3).map_err(Error::from);
+        assert_eq!(parse("5/3/49", None), date(2049));
+        assert_eq!(parse("5/3/50", None), date(1950));
+        assert_eq!(parse("5/3/24", Some(1900)), date(1924));
+        assert_eq!(parse("5/3/2024", None), Err(Error::Parse(6)));
+    }
+
+    #[test]
+    fn test_strict_weekday() {
+        let dtf = formatter(&locale!("en"));
+        let input = "Friday, October 19, 2023, 4:05:09\u{202f}PM";
+        assert_eq!(
+            dtf.parse(input, Leniency::Strict.into()),
+            Err(Error::Parse(0))
+        );
+        assert_eq!(
+            dtf.parse(input, Leniency::Lenient.into()).map(|dt| dt.time),
+            Time::try_new(16, 5, 9, 0).map_err(Error::from)
+        );
+    }
+
+    #[test]
+    fn test_missing_fields() {
+        let options = length::Bag::from_time_style(length::Time::Short);
+        let dtf = DateTimeFormatter::try_new(&locale!("en").into(), options.into()).unwrap();
+        assert_eq!(
+            dtf.parse("3:00\u{202f}PM", Leniency::Strict.into()),
+            Err(Error::MissingInputField(Some("year")))
+        );
+    }
+}
@@ -97,6 +97,7 @@ impl CoarseHourCycle {
         match skeleton::create_best_pattern_for_fields(
             skeletons,
             date_time,
+            None,
             skeleton.as_slice(),
             &Default::default(),
             // Prefer using the matched pattern directly, rather than mutating it to match the
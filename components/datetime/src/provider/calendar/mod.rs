@@ -4,13 +4,11 @@
 
 //! Data structs for calendar-specific symbols and patterns.
 
-#[cfg(any(feature = "datagen", feature = "experimental"))]
 mod skeletons;
 mod symbols;
 
 use crate::pattern;
 use icu_provider::prelude::*;
-#[cfg(any(feature = "datagen", feature = "experimental"))]
 pub use skeletons::*;
 pub use symbols::*;
 
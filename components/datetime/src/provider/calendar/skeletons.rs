@@ -3,20 +3,14 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use crate::{
-    pattern::runtime::PatternPlurals,
+    fields::FieldSymbol,
+    pattern::runtime::{GenericPattern, PatternPlurals},
     skeleton::{reference::Skeleton, SkeletonError},
 };
 use core::convert::TryFrom;
 use icu_provider::prelude::*;
 use litemap::LiteMap;
 
-// Manually implement DataMarker so that we can keep it in the proper experimental feature
-// #[icu_provider::data_struct(marker(
-//     DateSkeletonPatternsV1Marker,
-//     "datetime/skeletons@1",
-//     extension_key = "ca"
-// ))]
-//
 /// Skeleton data for dates and times, along with the corresponding plural pattern
 /// information.
 ///
@@ -110,3 +104,79 @@ impl databake::Bake for DateSkeletonPatternsV1Marker {
         }
     }
 }
+
+/// Patterns for appending fields to a skeleton pattern that does not contain them, taken from
+/// the CLDR `appendItems`.
+///
+/// In each pattern, `{0}` is the pattern being appended to, and `{1}` is the appended field.
+/// The display name of the field (`{2}` in CLDR) is already substituted.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[icu_provider::data_struct(marker(AppendItemsV1Marker, "datetime/appenditems@1"))]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_datetime::provider::calendar),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct AppendItemsV1<'data> {
+    /// The pattern for appending an era.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub era: GenericPattern<'data>,
+    /// The pattern for appending a year.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub year: GenericPattern<'data>,
+    /// The pattern for appending a quarter.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub quarter: GenericPattern<'data>,
+    /// The pattern for appending a month.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub month: GenericPattern<'data>,
+    /// The pattern for appending a week.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub week: GenericPattern<'data>,
+    /// The pattern for appending a day.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub day: GenericPattern<'data>,
+    /// The pattern for appending a weekday.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub weekday: GenericPattern<'data>,
+    /// The pattern for appending an hour.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub hour: GenericPattern<'data>,
+    /// The pattern for appending a minute.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub minute: GenericPattern<'data>,
+    /// The pattern for appending a second.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub second: GenericPattern<'data>,
+    /// The pattern for appending a time zone.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub time_zone: GenericPattern<'data>,
+}
+
+impl<'data> AppendItemsV1<'data> {
+    /// Returns the pattern for appending a field with the given symbol, if there is one.
+    pub(crate) fn pattern_for(&self, symbol: FieldSymbol) -> Option<&GenericPattern<'data>> {
+        Some(match symbol {
+            FieldSymbol::Era => &self.era,
+            FieldSymbol::Year(_) => &self.year,
+            FieldSymbol::Quarter(_) => &self.quarter,
+            FieldSymbol::Month(_) => &self.month,
+            FieldSymbol::Week(_) => &self.week,
+            FieldSymbol::Day(_) => &self.day,
+            FieldSymbol::Weekday(_) => &self.weekday,
+            FieldSymbol::Hour(_) => &self.hour,
+            FieldSymbol::Minute => &self.minute,
+            FieldSymbol::Second(_) => &self.second,
+            FieldSymbol::TimeZone(_) => &self.time_zone,
+            // Day periods are only displayed together with hours.
+            FieldSymbol::DayPeriod(_) => return None,
+        })
+    }
+}
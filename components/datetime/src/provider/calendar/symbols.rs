@@ -88,6 +88,59 @@ pub struct TimeSymbolsV1<'data> {
     pub day_periods: day_periods::ContextsV1<'data>,
 }
 
+/// Symbol data for the quarters of a year.
+///
+/// The quarter names are not calendar-specific: CLDR aliases the quarters of every calendar
+/// to those of the Gregorian calendar.
+///
+/// For more information on date time symbols, see [`FieldSymbol`](crate::fields::FieldSymbol).
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[icu_provider::data_struct(marker(QuarterSymbolsV1Marker, "datetime/quartersymbols@1",))]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_datetime::provider::calendar),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct QuarterSymbolsV1<'data> {
+    /// Symbol data for quarters.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub quarters: quarters::ContextsV1<'data>,
+}
+
+/// Symbol data for the flexible day periods needed to format a time, such as "in the morning".
+///
+/// Flexible day periods only have a "format" style; there is no stand-alone field symbol for them.
+///
+/// For more information on date time symbols, see [`FieldSymbol`](crate::fields::FieldSymbol).
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[icu_provider::data_struct(marker(FlexibleDayPeriodsV1Marker, "datetime/flexibledayperiods@1",))]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_datetime::provider::calendar),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct FlexibleDayPeriodsV1<'data> {
+    /// Symbol data for flexible day periods.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub day_periods: flexible_day_periods::FormatWidthsV1<'data>,
+}
+
 /// String data for the name, abbreviation, and narrow form of a date's era.
 ///
 /// Keys of the map represent era codes, and the values are the display names.
@@ -375,6 +428,54 @@ symbols!(
     }
 );
 
+symbols!(
+    quarters,
+    Quarter,
+    #[derive(Default)]
+    pub struct SymbolsV1<'data>(
+        #[cfg_attr(
+            feature = "serde",
+            serde(
+                borrow,
+                deserialize_with = "icu_provider::serde::borrow_de_utils::array_of_cow"
+            )
+        )]
+        pub [Cow<'data, str>; 4],
+    );
+);
+
+symbols!(
+    flexible_day_periods,
+    DayPeriod,
+    #[derive(Default)]
+    pub struct SymbolsV1<'data> {
+        /// Names of the flexible day periods, keyed by the minute of the day at which they start.
+        ///
+        /// A day period lasts until the start of the next one; the last day period wraps
+        /// around midnight if there is no day period starting at minute 0.
+        #[cfg_attr(feature = "serde", serde(borrow))]
+        pub periods: ZeroMap<'data, u16, str>,
+        #[cfg_attr(
+            feature = "serde",
+            serde(
+                borrow,
+                deserialize_with = "icu_provider::serde::borrow_de_utils::option_of_cow"
+            )
+        )]
+        /// Day period for noon, in locales whose day period rules have it.
+        pub noon: Option<Cow<'data, str>>,
+        #[cfg_attr(
+            feature = "serde",
+            serde(
+                borrow,
+                deserialize_with = "icu_provider::serde::borrow_de_utils::option_of_cow"
+            )
+        )]
+        /// Day period for midnight, in locales whose day period rules have it.
+        pub midnight: Option<Cow<'data, str>>,
+    }
+);
+
 #[cfg(all(test, feature = "datagen"))]
 mod test {
     use super::*;
@@ -16,8 +16,10 @@ use crate::provider::calendar::{
     patterns::GenericPatternV1Marker, patterns::PatternPluralsFromPatternsV1Marker,
     ErasedDateLengthsV1Marker, TimeLengthsV1Marker,
 };
-#[cfg(feature = "experimental")]
-use crate::{options::components, provider::calendar::DateSkeletonPatternsV1Marker};
+use crate::{
+    options::components,
+    provider::calendar::{AppendItemsV1Marker, DateSkeletonPatternsV1Marker},
+};
 use icu_calendar::types::Era;
 use icu_locid::extensions::unicode::Value;
 use icu_provider::prelude::*;
@@ -113,6 +115,14 @@ pub(crate) fn pattern_for_date_length(
     })
 }
 
+/// Parse a pattern string, such as `"EEE, d MMM y HH:mm"`, into a `Pattern`.
+pub(crate) fn pattern_for_string(
+    pattern: &str,
+) -> Result<DataPayload<PatternPluralsFromPatternsV1Marker>> {
+    let pattern: crate::pattern::runtime::Pattern = pattern.parse()?;
+    Ok(DataPayload::from_owned(PatternPluralsV1(pattern.into())))
+}
+
 /// Determine the appropriate `Pattern` for a given `options::length::Date` bag.
 pub(crate) fn generic_pattern_for_date_length(
     length: length::Date,
@@ -140,7 +150,6 @@ pub struct PatternSelector<'a, D: ?Sized> {
     data_provider: &'a D,
     date_patterns_data: DataPayload<ErasedDateLengthsV1Marker>,
     locale: &'a DataLocale,
-    #[allow(dead_code)] // non-experimental mode
     cal_val: Option<&'a Value>,
 }
 
@@ -163,9 +172,8 @@ where
         match options {
             DateTimeFormatterOptions::Length(bag) => selector
                 .pattern_for_length_bag(bag, Some(preferences::Bag::from_data_locale(locale))),
-            #[cfg(feature = "experimental")]
-            #[allow(clippy::panic)] // explicit panic in experimental mode
-            _ => panic!("Non-experimental constructor cannot handle experimental options"),
+            // Components bags need the skeleton data, which only `for_options_experimental` loads.
+            DateTimeFormatterOptions::Components(_) => Err(DateTimeError::UnsupportedOptions),
         }
     }
 
@@ -226,10 +234,12 @@ where
     }
 }
 
-#[cfg(feature = "experimental")]
 impl<D> PatternSelector<'_, D>
 where
-    D: DataProvider<TimeLengthsV1Marker> + DataProvider<DateSkeletonPatternsV1Marker> + ?Sized,
+    D: DataProvider<TimeLengthsV1Marker>
+        + DataProvider<DateSkeletonPatternsV1Marker>
+        + DataProvider<AppendItemsV1Marker>
+        + ?Sized,
 {
     pub(crate) fn for_options_experimental<'a>(
         data_provider: &'a D,
@@ -258,13 +268,43 @@ where
     ) -> Result<DataPayload<PatternPluralsFromPatternsV1Marker>> {
         use crate::skeleton;
         let skeletons_data = self.skeleton_data_payload()?;
-        // Not all skeletons are currently supported.
-        let requested_fields = components.to_vec_fields();
+        let append_items_data: DataPayload<AppendItemsV1Marker> = self
+            .data_provider
+            .load(DataRequest {
+                locale: self.locale,
+                metadata: Default::default(),
+            })?
+            .take_payload()?;
+
+        // The hour cycle of the components bag takes precedence over the -u-hc keyword of the
+        // locale, which takes precedence over the hour cycle preferred by the locale.
+        let mut components = *components;
+        if !matches!(
+            components.preferences,
+            Some(preferences::Bag {
+                hour_cycle: Some(_)
+            })
+        ) {
+            let locale_preferences = preferences::Bag::from_data_locale(self.locale);
+            if locale_preferences.hour_cycle.is_some() {
+                components.preferences = Some(locale_preferences);
+            }
+        }
+        let default_hour_cycle = if components.hour.is_some() {
+            time_patterns_data_payload(self.data_provider, self.locale)?
+                .get()
+                .preferred_hour_cycle
+        } else {
+            Default::default()
+        };
+
+        let requested_fields = components.to_vec_fields(default_hour_cycle);
         let patterns = match skeleton::create_best_pattern_for_fields(
             skeletons_data.get(),
             &self.date_patterns_data.get().length_combinations,
+            Some(append_items_data.get()),
             &requested_fields,
-            components,
+            &components,
             false, // Prefer the requested fields over the matched pattern.
         ) {
             skeleton::BestSkeleton::AllFieldsMatch(pattern)
@@ -277,12 +317,11 @@ where
         )))
     }
 
-    #[cfg(feature = "experimental")]
     fn skeleton_data_payload(&self) -> Result<DataPayload<DateSkeletonPatternsV1Marker>> {
         use icu_locid::extensions::unicode::{key, value};
         use tinystr::tinystr;
         let mut locale = self.locale.clone();
-        #[allow(clippy::expect_used)] // experimental
+        #[allow(clippy::expect_used)] // set by `for_options_experimental`
         let cal_val = self.cal_val.expect("should be present for components bag");
         // Skeleton data for ethioaa is stored under ethiopic
         if cal_val == &value!("ethioaa") {
@@ -332,7 +371,9 @@ impl<'data> DateSymbols<'data> for provider::calendar::DateSymbolsV1<'data> {
         day: input::IsoWeekday,
     ) -> Result<&str> {
         let widths = match weekday {
-            fields::Weekday::Format => &self.weekdays.format,
+            // Numeric local weekdays are formatted without symbols; the text forms
+            // are the same as for the format weekday.
+            fields::Weekday::Format | fields::Weekday::Local => &self.weekdays.format,
             fields::Weekday::StandAlone => {
                 if let Some(ref widths) = self.weekdays.stand_alone {
                     let symbols = match length {
@@ -357,7 +398,6 @@ impl<'data> DateSymbols<'data> for provider::calendar::DateSymbolsV1<'data> {
                     return self.get_symbol_for_weekday(fields::Weekday::Format, length, day);
                 }
             }
-            fields::Weekday::Local => unimplemented!(),
         };
         let symbols = match length {
             fields::FieldLength::Wide => &widths.wide,
@@ -454,3 +494,102 @@ impl<'data> TimeSymbols for provider::calendar::TimeSymbolsV1<'data> {
         })
     }
 }
+
+pub trait QuarterSymbols {
+    fn get_symbol_for_quarter(
+        &self,
+        quarter: fields::Quarter,
+        length: fields::FieldLength,
+        idx: usize,
+    ) -> Result<&str>;
+}
+
+impl<'data> QuarterSymbols for provider::calendar::QuarterSymbolsV1<'data> {
+    fn get_symbol_for_quarter(
+        &self,
+        quarter: fields::Quarter,
+        length: fields::FieldLength,
+        idx: usize,
+    ) -> Result<&str> {
+        let widths = match quarter {
+            fields::Quarter::Format => &self.quarters.format,
+            fields::Quarter::StandAlone => {
+                let symbols = self
+                    .quarters
+                    .stand_alone
+                    .as_ref()
+                    .and_then(|widths| match length {
+                        fields::FieldLength::Wide => widths.wide.as_ref(),
+                        fields::FieldLength::Narrow => widths.narrow.as_ref(),
+                        _ => widths.abbreviated.as_ref(),
+                    });
+                if let Some(symbols) = symbols {
+                    return symbols
+                        .0
+                        .get(idx)
+                        .map(|x| &**x)
+                        .ok_or(DateTimeError::MissingDateSymbols);
+                } else {
+                    return self.get_symbol_for_quarter(fields::Quarter::Format, length, idx);
+                }
+            }
+        };
+        let symbols = match length {
+            fields::FieldLength::Wide => &widths.wide,
+            fields::FieldLength::Narrow => &widths.narrow,
+            _ => &widths.abbreviated,
+        };
+        symbols
+            .0
+            .get(idx)
+            .map(|x| &**x)
+            .ok_or(DateTimeError::MissingDateSymbols)
+    }
+}
+
+pub trait FlexibleDayPeriodSymbols {
+    fn get_symbol_for_flexible_day_period(
+        &self,
+        length: fields::FieldLength,
+        hour: input::IsoHour,
+        minute: input::IsoMinute,
+        is_top_of_hour: bool,
+    ) -> Result<&str>;
+}
+
+impl<'data> FlexibleDayPeriodSymbols for provider::calendar::FlexibleDayPeriodsV1<'data> {
+    fn get_symbol_for_flexible_day_period(
+        &self,
+        length: fields::FieldLength,
+        hour: input::IsoHour,
+        minute: input::IsoMinute,
+        is_top_of_hour: bool,
+    ) -> Result<&str> {
+        use fields::FieldLength;
+        let widths = &self.day_periods;
+        let symbols = match length {
+            FieldLength::Wide => &widths.wide,
+            FieldLength::Narrow => &widths.narrow,
+            _ => &widths.abbreviated,
+        };
+        let at = match (u8::from(hour), is_top_of_hour) {
+            (00, true) => symbols.midnight.as_deref(),
+            (12, true) => symbols.noon.as_deref(),
+            _ => None,
+        };
+        if let Some(symbol) = at {
+            return Ok(symbol);
+        }
+        let minute_of_day = u16::from(u8::from(hour)) * 60 + u16::from(u8::from(minute));
+        // The day period that started last before `minute_of_day`, or the one
+        // that started last on the previous day.
+        symbols
+            .periods
+            .iter()
+            .take_while(|(start, _)| start.as_unsigned_int() <= minute_of_day)
+            .last()
+            .or_else(|| symbols.periods.iter().last())
+            .map(|(_, name)| name)
+            .ok_or(DateTimeError::MissingTimeSymbols)
+    }
+}
@@ -30,6 +30,7 @@ const _: () = {
         pub use crate as datetime;
         pub use icu_locid_transform as locid_transform;
     }
+    icu_datetime_data::impl_datetime_appenditems_v1!(Baked);
     icu_datetime_data::impl_datetime_buddhist_datelengths_v1!(Baked);
     icu_datetime_data::impl_datetime_buddhist_datesymbols_v1!(Baked);
     icu_datetime_data::impl_datetime_chinese_datelengths_v1!(Baked);
@@ -40,6 +41,7 @@ const _: () = {
     icu_datetime_data::impl_datetime_dangi_datesymbols_v1!(Baked);
     icu_datetime_data::impl_datetime_ethiopic_datelengths_v1!(Baked);
     icu_datetime_data::impl_datetime_ethiopic_datesymbols_v1!(Baked);
+    icu_datetime_data::impl_datetime_flexibledayperiods_v1!(Baked);
     icu_datetime_data::impl_datetime_gregory_datelengths_v1!(Baked);
     icu_datetime_data::impl_datetime_gregory_datesymbols_v1!(Baked);
     icu_datetime_data::impl_datetime_hebrew_datelengths_v1!(Baked);
@@ -54,9 +56,9 @@ const _: () = {
     icu_datetime_data::impl_datetime_japanext_datesymbols_v1!(Baked);
     icu_datetime_data::impl_datetime_persian_datelengths_v1!(Baked);
     icu_datetime_data::impl_datetime_persian_datesymbols_v1!(Baked);
+    icu_datetime_data::impl_datetime_quartersymbols_v1!(Baked);
     icu_datetime_data::impl_datetime_roc_datelengths_v1!(Baked);
     icu_datetime_data::impl_datetime_roc_datesymbols_v1!(Baked);
-    #[cfg(feature = "experimental")]
     icu_datetime_data::impl_datetime_skeletons_v1!(Baked);
     icu_datetime_data::impl_datetime_timelengths_v1!(Baked);
     icu_datetime_data::impl_datetime_timesymbols_v1!(Baked);
@@ -5,7 +5,6 @@
 //! The collection of code that is needed for handling formatting operations for DateTimes.
 //! Central to this is the [`DateTimeFormatter`].
 
-#[cfg(feature = "experimental")]
 use crate::options::components;
 use crate::{
     format::datetime,
@@ -16,8 +15,8 @@ use crate::{
         self,
         calendar::{
             patterns::GenericPatternV1Marker, patterns::PatternPluralsFromPatternsV1Marker,
-            ErasedDateLengthsV1Marker, ErasedDateSymbolsV1Marker, TimeLengthsV1Marker,
-            TimeSymbolsV1Marker,
+            ErasedDateLengthsV1Marker, ErasedDateSymbolsV1Marker, FlexibleDayPeriodsV1Marker,
+            QuarterSymbolsV1Marker, TimeLengthsV1Marker, TimeSymbolsV1Marker,
         },
     },
     DateTimeError, FormattedDateTime,
@@ -36,6 +35,7 @@ use icu_provider::prelude::*;
 pub(crate) struct TimeFormatter {
     pub patterns: DataPayload<PatternPluralsFromPatternsV1Marker>,
     pub symbols: Option<DataPayload<TimeSymbolsV1Marker>>,
+    pub flexible_day_periods: Option<DataPayload<FlexibleDayPeriodsV1Marker>>,
     pub fixed_decimal_format: FixedDecimalFormatter,
 }
 
@@ -70,6 +70,19 @@ impl TimeFormatter {
             None
         };
 
+        let flexible_day_periods_data = if required.flexible_day_periods_data {
+            Some(
+                crate::provider::Baked
+                    .load(DataRequest {
+                        locale,
+                        metadata: Default::default(),
+                    })?
+                    .take_payload()?,
+            )
+        } else {
+            None
+        };
+
         let mut fixed_decimal_format_options = FixedDecimalFormatterOptions::default();
         fixed_decimal_format_options.grouping_strategy = GroupingStrategy::Never;
 
@@ -77,7 +90,12 @@ impl TimeFormatter {
             FixedDecimalFormatter::try_new(locale, fixed_decimal_format_options)
                 .map_err(DateTimeError::FixedDecimalFormatter)?;
 
-        Ok(Self::new(patterns, symbols_data, fixed_decimal_format))
+        Ok(Self::new(
+            patterns,
+            symbols_data,
+            flexible_day_periods_data,
+            fixed_decimal_format,
+        ))
     }
 
     #[inline(never)]
@@ -90,6 +108,7 @@ impl TimeFormatter {
     where
         D: DataProvider<TimeLengthsV1Marker>
             + DataProvider<TimeSymbolsV1Marker>
+            + DataProvider<FlexibleDayPeriodsV1Marker>
             + DataProvider<DecimalSymbolsV1Marker>
             + ?Sized,
     {
@@ -112,6 +131,19 @@ impl TimeFormatter {
             None
         };
 
+        let flexible_day_periods_data = if required.flexible_day_periods_data {
+            Some(
+                provider
+                    .load(DataRequest {
+                        locale,
+                        metadata: Default::default(),
+                    })?
+                    .take_payload()?,
+            )
+        } else {
+            None
+        };
+
         let mut fixed_decimal_format_options = FixedDecimalFormatterOptions::default();
         fixed_decimal_format_options.grouping_strategy = GroupingStrategy::Never;
 
@@ -119,18 +151,25 @@ impl TimeFormatter {
             FixedDecimalFormatter::try_new_unstable(provider, locale, fixed_decimal_format_options)
                 .map_err(DateTimeError::FixedDecimalFormatter)?;
 
-        Ok(Self::new(patterns, symbols_data, fixed_decimal_format))
+        Ok(Self::new(
+            patterns,
+            symbols_data,
+            flexible_day_periods_data,
+            fixed_decimal_format,
+        ))
     }
 
     /// Creates a new [`TimeFormatter`] regardless of whether there are time-zone symbols in the pattern.
     pub fn new(
         patterns: DataPayload<PatternPluralsFromPatternsV1Marker>,
         symbols: Option<DataPayload<TimeSymbolsV1Marker>>,
+        flexible_day_periods: Option<DataPayload<FlexibleDayPeriodsV1Marker>>,
         fixed_decimal_format: FixedDecimalFormatter,
     ) -> Self {
         Self {
             patterns,
             symbols,
+            flexible_day_periods,
             fixed_decimal_format,
         }
     }
@@ -146,6 +185,8 @@ impl TimeFormatter {
             patterns: &self.patterns,
             date_symbols: None,
             time_symbols: self.symbols.as_ref().map(|s| s.get()),
+            quarter_symbols: None,
+            flexible_day_periods: self.flexible_day_periods.as_ref().map(|s| s.get()),
             datetime: ExtractedDateTimeInput::extract_from_time(value),
             week_data: None,
             ordinal_rules: None,
@@ -313,6 +354,8 @@ impl DateFormatter {
             patterns: &self.patterns,
             date_symbols: self.symbols.as_ref().map(|s| s.get()),
             time_symbols: None,
+            quarter_symbols: None,
+            flexible_day_periods: None,
             datetime: ExtractedDateTimeInput::extract_from_date(value),
             week_data: None,
             ordinal_rules: None,
@@ -328,6 +371,8 @@ pub(crate) struct DateTimeFormatter {
     pub patterns: DataPayload<PatternPluralsFromPatternsV1Marker>,
     pub date_symbols: Option<DataPayload<ErasedDateSymbolsV1Marker>>,
     pub time_symbols: Option<DataPayload<TimeSymbolsV1Marker>>,
+    pub quarter_symbols: Option<DataPayload<QuarterSymbolsV1Marker>>,
+    pub flexible_day_periods: Option<DataPayload<FlexibleDayPeriodsV1Marker>>,
     pub week_data: Option<DataPayload<WeekDataV1Marker>>,
     pub ordinal_rules: Option<PluralRules>,
     pub fixed_decimal_format: FixedDecimalFormatter,
@@ -367,6 +412,8 @@ impl DateTimeFormatter {
             patterns,
             date_symbols: date.symbols,
             time_symbols: time.symbols,
+            quarter_symbols: None,
+            flexible_day_periods: time.flexible_day_periods,
             week_data: date.week_data,
             ordinal_rules: date.ordinal_rules,
             fixed_decimal_format: date.fixed_decimal_format,
@@ -412,6 +459,18 @@ impl DateTimeFormatter {
             None
         };
 
+        let quarter_symbols_data = if required.quarter_symbols_data {
+            Some(crate::provider::Baked.load(req)?.take_payload()?)
+        } else {
+            None
+        };
+
+        let flexible_day_periods_data = if required.flexible_day_periods_data {
+            Some(crate::provider::Baked.load(req)?.take_payload()?)
+        } else {
+            None
+        };
+
         let mut fixed_decimal_format_options = FixedDecimalFormatterOptions::default();
         fixed_decimal_format_options.grouping_strategy = GroupingStrategy::Never;
 
@@ -423,6 +482,8 @@ impl DateTimeFormatter {
             patterns,
             date_symbols_data,
             time_symbols_data,
+            quarter_symbols_data,
+            flexible_day_periods_data,
             week_data,
             ordinal_rules,
             fixed_decimal_format,
@@ -439,6 +500,8 @@ impl DateTimeFormatter {
     where
         D: DataProvider<TimeSymbolsV1Marker>
             + DataProvider<TimeLengthsV1Marker>
+            + DataProvider<QuarterSymbolsV1Marker>
+            + DataProvider<FlexibleDayPeriodsV1Marker>
             + DataProvider<DecimalSymbolsV1Marker>
             + DataProvider<OrdinalV1Marker>
             + DataProvider<WeekDataV1Marker>
@@ -476,6 +539,18 @@ impl DateTimeFormatter {
             None
         };
 
+        let quarter_symbols_data = if required.quarter_symbols_data {
+            Some(provider.load(req)?.take_payload()?)
+        } else {
+            None
+        };
+
+        let flexible_day_periods_data = if required.flexible_day_periods_data {
+            Some(provider.load(req)?.take_payload()?)
+        } else {
+            None
+        };
+
         let mut fixed_decimal_format_options = FixedDecimalFormatterOptions::default();
         fixed_decimal_format_options.grouping_strategy = GroupingStrategy::Never;
 
@@ -487,6 +562,8 @@ impl DateTimeFormatter {
             patterns,
             date_symbols_data,
             time_symbols_data,
+            quarter_symbols_data,
+            flexible_day_periods_data,
             week_data,
             ordinal_rules,
             fixed_decimal_format,
@@ -494,10 +571,13 @@ impl DateTimeFormatter {
     }
 
     /// Creates a new [`DateTimeFormatter`] regardless of whether there are time-zone symbols in the pattern.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         patterns: DataPayload<PatternPluralsFromPatternsV1Marker>,
         date_symbols: Option<DataPayload<ErasedDateSymbolsV1Marker>>,
         time_symbols: Option<DataPayload<TimeSymbolsV1Marker>>,
+        quarter_symbols: Option<DataPayload<QuarterSymbolsV1Marker>>,
+        flexible_day_periods: Option<DataPayload<FlexibleDayPeriodsV1Marker>>,
         week_data: Option<DataPayload<WeekDataV1Marker>>,
         ordinal_rules: Option<PluralRules>,
         fixed_decimal_format: FixedDecimalFormatter,
@@ -506,6 +586,8 @@ impl DateTimeFormatter {
             patterns,
             date_symbols,
             time_symbols,
+            quarter_symbols,
+            flexible_day_periods,
             week_data,
             ordinal_rules,
             fixed_decimal_format,
@@ -524,6 +606,8 @@ impl DateTimeFormatter {
             patterns: &self.patterns,
             date_symbols: self.date_symbols.as_ref().map(|s| s.get()),
             time_symbols: self.time_symbols.as_ref().map(|s| s.get()),
+            quarter_symbols: self.quarter_symbols.as_ref().map(|s| s.get()),
+            flexible_day_periods: self.flexible_day_periods.as_ref().map(|s| s.get()),
             datetime: ExtractedDateTimeInput::extract_from(value),
             week_data: self.week_data.as_ref().map(|s| s.get()),
             ordinal_rules: self.ordinal_rules.as_ref(),
@@ -533,7 +617,6 @@ impl DateTimeFormatter {
 
     /// Returns a [`components::Bag`] that represents the resolved components for the
     /// options that were provided to the [`DateTimeFormatter`].
-    #[cfg(feature = "experimental")]
     pub fn resolve_components(&self) -> components::Bag {
         components::Bag::from(&self.patterns.get().0)
     }
@@ -20,7 +20,8 @@ use crate::{
         self,
         calendar::{
             patterns::PatternPluralsFromPatternsV1Marker, ErasedDateSymbolsV1Marker,
-            TimeLengthsV1Marker, TimeSymbolsV1Marker,
+            FlexibleDayPeriodsV1Marker, QuarterSymbolsV1Marker, TimeLengthsV1Marker,
+            TimeSymbolsV1Marker,
         },
     },
     raw,
@@ -77,6 +78,18 @@ impl ZonedDateTimeFormatter {
             None
         };
 
+        let quarter_symbols_data = if required.quarter_symbols_data {
+            Some(crate::provider::Baked.load(req)?.take_payload()?)
+        } else {
+            None
+        };
+
+        let flexible_day_periods_data = if required.flexible_day_periods_data {
+            Some(crate::provider::Baked.load(req)?.take_payload()?)
+        } else {
+            None
+        };
+
         let mut fixed_decimal_format_options = FixedDecimalFormatterOptions::default();
         fixed_decimal_format_options.grouping_strategy = GroupingStrategy::Never;
 
@@ -88,6 +101,8 @@ impl ZonedDateTimeFormatter {
             patterns,
             date_symbols_data,
             time_symbols_data,
+            quarter_symbols_data,
+            flexible_day_periods_data,
             week_data,
             ordinal_rules,
             fixed_decimal_format,
@@ -120,6 +135,8 @@ impl ZonedDateTimeFormatter {
     where
         P: DataProvider<TimeSymbolsV1Marker>
             + DataProvider<TimeLengthsV1Marker>
+            + DataProvider<QuarterSymbolsV1Marker>
+            + DataProvider<FlexibleDayPeriodsV1Marker>
             + DataProvider<WeekDataV1Marker>
             + DataProvider<provider::time_zones::TimeZoneFormatsV1Marker>
             + DataProvider<provider::time_zones::ExemplarCitiesV1Marker>
@@ -163,6 +180,18 @@ impl ZonedDateTimeFormatter {
             None
         };
 
+        let quarter_symbols_data = if required.quarter_symbols_data {
+            Some(provider.load(req)?.take_payload()?)
+        } else {
+            None
+        };
+
+        let flexible_day_periods_data = if required.flexible_day_periods_data {
+            Some(provider.load(req)?.take_payload()?)
+        } else {
+            None
+        };
+
         let mut fixed_decimal_format_options = FixedDecimalFormatterOptions::default();
         fixed_decimal_format_options.grouping_strategy = GroupingStrategy::Never;
 
@@ -174,6 +203,8 @@ impl ZonedDateTimeFormatter {
             patterns,
             date_symbols_data,
             time_symbols_data,
+            quarter_symbols_data,
+            flexible_day_periods_data,
             week_data,
             ordinal_rules,
             fixed_decimal_format,
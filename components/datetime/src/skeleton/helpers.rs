@@ -14,7 +14,9 @@ use crate::{
         runtime::{self, PatternPlurals},
         PatternItem, TimeGranularity,
     },
-    provider::calendar::{patterns::GenericLengthPatternsV1, DateSkeletonPatternsV1},
+    provider::calendar::{
+        patterns::GenericLengthPatternsV1, AppendItemsV1, DateSkeletonPatternsV1,
+    },
 };
 
 // The following scalar values are for testing the suitability of a skeleton's field for the
@@ -77,9 +79,9 @@ const SKELETON_EXTRA_SYMBOL: u32 = 1000;
 const REQUESTED_SYMBOL_MISSING: u32 = 10000;
 
 /// According to the [UTS 35 skeleton matching algorithm](https://unicode.org/reports/tr35/tr35-dates.html#Matching_Skeletons)
-/// there will be a guaranteed match for a skeleton. However, with this initial implementation,
-/// there is no attempt to add on missing fields. This enum encodes the variants for the current
-/// search for a best skeleton.
+/// there will be a guaranteed match for a skeleton. Missing fields are only added on when
+/// `appendItems` data is available, so this enum encodes the variants for the current search
+/// for a best skeleton.
 #[derive(Debug, PartialEq, Clone)]
 pub enum BestSkeleton<T> {
     AllFieldsMatch(T),
@@ -119,6 +121,8 @@ fn naively_apply_time_zone_name(
 ///
 /// * `skeletons` - The skeletons that will be matched against
 /// * `length_patterns` - Contains information on how to combine date and time patterns.
+/// * `append_items` - Contains information on how to append fields that are missing from the
+///         matched patterns. If it is `None`, missing fields are left out.
 /// * `fields` - The desired fields to match against.
 /// * `prefer_matched_pattern` - This algorithm does some extra steps of trying to respect
 ///         the desired fields, even if the provider data doesn't completely match. This
@@ -127,6 +131,7 @@ fn naively_apply_time_zone_name(
 pub fn create_best_pattern_for_fields<'data>(
     skeletons: &DateSkeletonPatternsV1<'data>,
     length_patterns: &GenericLengthPatternsV1<'data>,
+    append_items: Option<&AppendItemsV1>,
     fields: &[Field],
     components: &components::Bag,
     prefer_matched_pattern: bool,
@@ -151,6 +156,11 @@ pub fn create_best_pattern_for_fields<'data>(
                 unreachable!("Logic error in implementation. AllFieldsMatch handled above.")
             }
             BestSkeleton::MissingOrExtraFields(mut pattern_plurals) => {
+                if let Some(append_items) = append_items {
+                    pattern_plurals.for_each_mut(|pattern| {
+                        append_missing_fields(pattern, skeletons, append_items, fields);
+                    });
+                }
                 if date.is_empty() {
                     pattern_plurals.for_each_mut(|pattern| {
                         hour_cycle::naively_apply_preferences(pattern, &components.preferences);
@@ -168,7 +178,14 @@ pub fn create_best_pattern_for_fields<'data>(
     // an optional values of the results, and a boolean value.
     let (date_patterns, date_missing_or_extra): (Option<PatternPlurals<'data>>, bool) =
         match get_best_available_format_pattern(skeletons, &date, prefer_matched_pattern) {
-            BestSkeleton::MissingOrExtraFields(fields) => (Some(fields), true),
+            BestSkeleton::MissingOrExtraFields(mut fields) => {
+                if let Some(append_items) = append_items {
+                    fields.for_each_mut(|pattern| {
+                        append_missing_fields(pattern, skeletons, append_items, &date);
+                    });
+                }
+                (Some(fields), true)
+            }
             BestSkeleton::AllFieldsMatch(fields) => (Some(fields), false),
             BestSkeleton::NoMatch => (None, true),
         };
@@ -182,6 +199,9 @@ pub fn create_best_pattern_for_fields<'data>(
     let time_pattern: Option<runtime::Pattern<'data>> = time_patterns.map(|pattern_plurals| {
         let mut pattern =
             pattern_plurals.expect_pattern("Only date patterns can contain plural variants");
+        if let (true, Some(append_items)) = (time_missing_or_extra, append_items) {
+            append_missing_fields(&mut pattern, skeletons, append_items, &time);
+        }
         hour_cycle::naively_apply_preferences(&mut pattern, &components.preferences);
         naively_apply_time_zone_name(&mut pattern, &components.time_zone_name);
         append_fractional_seconds(&mut pattern, &time);
@@ -278,6 +298,7 @@ fn group_fields_by_type(fields: &[Field]) -> FieldsByType {
             //  - Solo example: "E"
             FieldSymbol::Era
             | FieldSymbol::Year(_)
+            | FieldSymbol::Quarter(_)
             | FieldSymbol::Month(_)
             | FieldSymbol::Week(_)
             | FieldSymbol::Day(_)
@@ -350,6 +371,68 @@ fn append_fractional_seconds(pattern: &mut runtime::Pattern, fields: &[Field]) {
     }
 }
 
+/// Alters given Pattern so that it contains every field in `fields`, by appending the fields that
+/// are missing with the CLDR appendItems.
+///
+/// Each missing field is appended with the best available format pattern for that field alone,
+/// so that an hour is appended along with its day period.
+/// (see <https://unicode.org/reports/tr35/tr35-dates.html#Missing_Skeleton_Fields>)
+fn append_missing_fields(
+    pattern: &mut runtime::Pattern,
+    skeletons: &DateSkeletonPatternsV1,
+    append_items: &AppendItemsV1,
+    fields: &[Field],
+) {
+    for field in fields {
+        // Fractional seconds are appended to the seconds instead.
+        if field.symbol == FieldSymbol::Second(fields::Second::FractionalSecond)
+            || pattern.items.iter().any(|item| match item {
+                PatternItem::Field(pattern_field) => {
+                    pattern_field.symbol.discriminant_cmp(&field.symbol).is_eq()
+                }
+                PatternItem::Literal(_) => false,
+            })
+        {
+            continue;
+        }
+        let append_item = match append_items.pattern_for(field.symbol) {
+            Some(append_item) => append_item,
+            None => continue,
+        };
+        let mut field_pattern =
+            match get_best_available_format_pattern(skeletons, core::slice::from_ref(field), false)
+            {
+                BestSkeleton::AllFieldsMatch(PatternPlurals::SinglePattern(pattern))
+                | BestSkeleton::MissingOrExtraFields(PatternPlurals::SinglePattern(pattern)) => {
+                    pattern
+                }
+                // Patterns with plural variants, such as week-of patterns, can't be appended to
+                // a single pattern, so only the field is appended.
+                _ => runtime::Pattern::from(vec![PatternItem::Field(*field)]),
+            };
+        // The pattern may use a stand-alone symbol, such as "ccc" for "E", which isn't adjusted
+        // to the requested width when matching, so the requested field is used instead.
+        runtime::helpers::maybe_replace(&mut field_pattern, |item| match item {
+            PatternItem::Field(pattern_field)
+                if core::mem::discriminant(&pattern_field.symbol)
+                    == core::mem::discriminant(&field.symbol) =>
+            {
+                Some(PatternItem::Field(*field))
+            }
+            _ => None,
+        });
+        // The appendItems have the pattern so far as `{0}` and the field as `{1}`, like the
+        // time and the date of a date time glue pattern.
+        // TODO(#2626) - Since this is fallible, we should make this method fallible.
+        #[allow(clippy::expect_used)] // The appendItems only contain `{0}` and `{1}`.
+        let appended = append_item
+            .clone()
+            .combined(field_pattern, pattern.clone())
+            .expect("Failed to append a field");
+        *pattern = appended;
+    }
+}
+
 /// A partial implementation of the [UTS 35 skeleton matching algorithm](https://unicode.org/reports/tr35/tr35-dates.html#Matching_Skeletons).
 ///
 /// The following is implemented:
@@ -361,11 +444,7 @@ fn append_fractional_seconds(pattern: &mut runtime::Pattern, fields: &[Field]) {
 ///      then be modified to use the requested length to produce a pattern "d MMMM y".
 ///      However, fields should not be changed from numeric to text.
 ///
-/// The following is not implemented:
-///
-///  * 2.6.2.2 Missing Skeleton Fields
-///    - TODO(#586) - Using the CLDR appendItems field. Note: There is not agreement yet on how
-///      much of this step to implement. See the issue for more information.
+/// Missing fields are not added to the pattern here; see [`create_best_pattern_for_fields`].
 ///
 /// # Panics
 ///
@@ -13,7 +13,7 @@ mod serde;
 pub use error::*;
 pub use helpers::*;
 
-#[cfg(all(test, feature = "datagen", feature = "experimental"))]
+#[cfg(all(test, feature = "datagen"))]
 mod test {
     use super::reference::Skeleton;
     use super::*;
@@ -23,10 +23,10 @@ mod test {
     use crate::{
         fields::{Day, Field, FieldLength, Month, Weekday},
         options::components,
-        pattern::runtime,
+        pattern::{runtime, CoarseHourCycle},
         provider::calendar::{
-            DateSkeletonPatternsV1, DateSkeletonPatternsV1Marker, GregorianDateLengthsV1Marker,
-            SkeletonV1,
+            AppendItemsV1Marker, DateSkeletonPatternsV1, DateSkeletonPatternsV1Marker,
+            GregorianDateLengthsV1Marker, SkeletonV1,
         },
     };
     use core::convert::TryFrom;
@@ -75,7 +75,7 @@ mod test {
 
             ..Default::default()
         };
-        let requested_fields = components.to_vec_fields();
+        let requested_fields = components.to_vec_fields(CoarseHourCycle::H23H24);
         let (_, skeletons) = get_data_payload();
 
         match get_best_available_format_pattern(skeletons.get(), &requested_fields, false) {
@@ -102,7 +102,7 @@ mod test {
             weekday: Some(components::Text::Short),
             ..Default::default()
         };
-        let requested_fields = components.to_vec_fields();
+        let requested_fields = components.to_vec_fields(CoarseHourCycle::H23H24);
         let (_, skeletons) = get_data_payload();
 
         match get_best_available_format_pattern(skeletons.get(), &requested_fields, false) {
@@ -121,36 +121,48 @@ mod test {
         };
     }
 
-    // TODO(#586) - Append items support needs to be added.
     #[test]
-    #[should_panic]
-    fn test_missing_append_items_support() {
+    fn test_append_items() {
         let components = components::Bag {
             year: Some(components::Year::Numeric),
-            month: Some(components::Month::Long),
+            month: Some(components::Month::Short),
             day: Some(components::Day::NumericDayOfMonth),
-            // This will be appended.
-            time_zone_name: Some(components::TimeZoneName::LongSpecific),
+            weekday: Some(components::Text::Long),
             ..Default::default()
         };
-        let requested_fields = components.to_vec_fields();
-        let (patterns, skeletons) = get_data_payload();
+        let requested_fields = components.to_vec_fields(CoarseHourCycle::H23H24);
+        // Construct a set of skeletons that do not use the day nor weekday symbols.
+        let mut skeletons = LiteMap::new();
+        skeletons.insert(
+            SkeletonV1::try_from("yMMM").unwrap(),
+            runtime::Pattern::from_str("MMM y").unwrap().into(),
+        );
+        let skeletons = DateSkeletonPatternsV1(skeletons);
+        let (patterns, _) = get_data_payload();
+        let append_items: DataPayload<AppendItemsV1Marker> = crate::provider::Baked
+            .load(DataRequest {
+                locale: &icu_locid::locale!("en").into(),
+                metadata: Default::default(),
+            })
+            .expect("Failed to load payload")
+            .take_payload()
+            .expect("Failed to retrieve payload");
 
         match create_best_pattern_for_fields(
-            skeletons.get(),
+            &skeletons,
             &patterns.get().length_combinations,
+            Some(append_items.get()),
             &requested_fields,
-            &Default::default(),
+            &components,
             false,
         ) {
-            BestSkeleton::AllFieldsMatch(available_format_pattern) => {
-                // TODO - Append items are needed here.
+            BestSkeleton::MissingOrExtraFields(available_format_pattern) => {
                 assert_eq!(
                     available_format_pattern
                         .expect_pattern("pattern should not have plural variants")
                         .to_string()
                         .as_str(),
-                    "MMMM d, y vvvv"
+                    "MMM y '(day:' d) EEEE"
                 )
             }
             best => panic!("Unexpected {best:?}"),
@@ -160,7 +172,7 @@ mod test {
     #[test]
     fn test_skeleton_empty_bag() {
         let components: components::Bag = Default::default();
-        let requested_fields = components.to_vec_fields();
+        let requested_fields = components.to_vec_fields(CoarseHourCycle::H23H24);
         let (_, skeletons) = get_data_payload();
 
         assert_eq!(
@@ -177,7 +189,7 @@ mod test {
             time_zone_name: Some(components::TimeZoneName::LongSpecific),
             ..Default::default()
         };
-        let requested_fields = components.to_vec_fields();
+        let requested_fields = components.to_vec_fields(CoarseHourCycle::H23H24);
         // Construct a set of skeletons that do not use the hour nor time zone symbols.
         let mut skeletons = LiteMap::new();
         skeletons.insert(
@@ -208,16 +220,9 @@ mod test {
         "Gy", "GyM", "GyMMM", "GyMMMdEEEE", "GyMMMdE", "GyMMMM", "GyMMMMdE", "GyMMMMd", "GyMMMd",
         // Time zones
         "HHmmZ", "Hmsv", "Hmsvvvv", "Hmv", "Hmvvvv", "hmsv", "hmsvvvv", "hmv", "hmvvvv",
-    ];
-
-    // NOTE: If you are moving this to the SUPPORTED section, make sure to remove the match
-    //       on your symbol from impl From<fields::SymbolError> for SkeletonError
-    //       and then regenerate baked data (`cargo make bakeddata components/datetime`)
-    #[rustfmt::skip]
-    const UNSUPPORTED_STRING_SKELETONS: &[&str] = &[
-        // TODO(#487) - Flexible day periods
+        // Flexible day periods
         "Bh", "Bhm", "Bhms", "EBhm", "EBhms",
-        // TODO(#501) - Quarters
+        // Quarters
         "yQ", "yQQQ", "yQQQQ",
     ];
 
@@ -235,26 +240,6 @@ mod test {
         }
     }
 
-    #[test]
-    fn test_unsupported_skeletons_skeletons_err() {
-        for string_skeleton in UNSUPPORTED_STRING_SKELETONS {
-            match Skeleton::try_from(*string_skeleton) {
-                Ok(_) => {
-                    panic!(
-                        "An unsupported field is now supported, consider moving {string_skeleton:?} to the \
-                         supported skeletons, and ensure the skeleton is properly implemented."
-                    )
-                }
-                Err(err) => match err {
-                    SkeletonError::SymbolUnimplemented(_) => {
-                        // Every skeleton should return this error.
-                    }
-                    _ => panic!("{err}"),
-                },
-            }
-        }
-    }
-
     #[test]
     fn test_skeleton_deserialization() {
         assert_eq!(
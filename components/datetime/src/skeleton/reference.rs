@@ -83,13 +83,14 @@ impl From<&Pattern> for Skeleton {
                     FieldSymbol::Month(_) => FieldSymbol::Month(fields::Month::Format),
                     FieldSymbol::Weekday(_) => FieldSymbol::Weekday(fields::Weekday::Format),
 
+                    FieldSymbol::Quarter(_) => FieldSymbol::Quarter(fields::Quarter::Format),
+
                     // Only flexible day periods are used in skeletons, ignore all others.
                     FieldSymbol::DayPeriod(fields::DayPeriod::AmPm)
                     | FieldSymbol::DayPeriod(fields::DayPeriod::NoonMidnight) => continue,
-                    // TODO(#487) - Flexible day periods should be included here.
-                    // FieldSymbol::DayPeriod(fields::DayPeriod::Flexible) => {
-                    //     FieldSymbol::DayPeriod(fields::DayPeriod::Flexible)
-                    // }
+                    FieldSymbol::DayPeriod(fields::DayPeriod::Flexible) => {
+                        FieldSymbol::DayPeriod(fields::DayPeriod::Flexible)
+                    }
 
                     // Only the H12 and H23 symbols are used in skeletons, while the patterns may
                     // contain H11 or H23 depending on the localization.
@@ -17,7 +17,10 @@ use crate::{
     options::DateTimeFormatterOptions,
     provider::{
         self,
-        calendar::{TimeLengthsV1Marker, TimeSymbolsV1Marker},
+        calendar::{
+            FlexibleDayPeriodsV1Marker, QuarterSymbolsV1Marker, TimeLengthsV1Marker,
+            TimeSymbolsV1Marker,
+        },
         date_time::PatternSelector,
     },
     raw,
@@ -85,13 +88,6 @@ impl<C: CldrCalendar> TypedZonedDateTimeFormatter<C> {
     ///
     /// [📚 Help choosing a constructor](icu_provider::constructors)
     ///
-    /// <div class="stab unstable">
-    /// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
-    /// including in SemVer minor releases. It can be enabled with the "experimental" Cargo feature
-    /// of the icu meta-crate. Use with caution.
-    /// <a href="https://github.com/unicode-org/icu4x/issues/1317">#1317</a>
-    /// </div>
-    ///
     /// # Examples
     ///
     /// ```
@@ -110,7 +106,7 @@ impl<C: CldrCalendar> TypedZonedDateTimeFormatter<C> {
     /// options.minute = Some(components::Numeric::Numeric);
     /// options.time_zone_name = Some(components::TimeZoneName::GmtOffset);
     ///
-    /// let zdtf = TypedZonedDateTimeFormatter::<Gregorian>::try_new_experimental(
+    /// let zdtf = TypedZonedDateTimeFormatter::<Gregorian>::try_new_with_components(
     ///     &locale!("en").into(),
     ///     options.into(),
     ///     TimeZoneFormatterOptions::default(),
@@ -120,15 +116,14 @@ impl<C: CldrCalendar> TypedZonedDateTimeFormatter<C> {
     ///
     /// assert_writeable_eq!(
     ///     zdtf.format(&datetime, &CustomTimeZone::utc()),
-    ///     "August 2022, 01:02 GMT",
+    ///     "August 2022, 1:02\u{202f}AM GMT",
     /// );
     /// ```
     ///
     /// [data provider]: icu_provider
-    #[cfg(feature = "experimental")]
     #[cfg(feature = "compiled_data")]
     #[inline]
-    pub fn try_new_experimental(
+    pub fn try_new_with_components(
         locale: &DataLocale,
         date_time_format_options: DateTimeFormatterOptions,
         time_zone_format_options: TimeZoneFormatterOptions,
@@ -160,10 +155,9 @@ impl<C: CldrCalendar> TypedZonedDateTimeFormatter<C> {
         ))
     }
 
-    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::try_new_experimental)]
-    #[cfg(feature = "experimental")]
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::try_new_with_components)]
     #[inline]
-    pub fn try_new_experimental_unstable<P>(
+    pub fn try_new_with_components_unstable<P>(
         provider: &P,
         locale: &DataLocale,
         date_time_format_options: DateTimeFormatterOptions,
@@ -174,7 +168,10 @@ impl<C: CldrCalendar> TypedZonedDateTimeFormatter<C> {
             + DataProvider<<C as CldrCalendar>::DateLengthsV1Marker>
             + DataProvider<TimeSymbolsV1Marker>
             + DataProvider<TimeLengthsV1Marker>
+            + DataProvider<QuarterSymbolsV1Marker>
+            + DataProvider<FlexibleDayPeriodsV1Marker>
             + DataProvider<crate::provider::calendar::DateSkeletonPatternsV1Marker>
+            + DataProvider<crate::provider::calendar::AppendItemsV1Marker>
             + DataProvider<WeekDataV1Marker>
             + DataProvider<provider::time_zones::TimeZoneFormatsV1Marker>
             + DataProvider<provider::time_zones::ExemplarCitiesV1Marker>
@@ -205,6 +202,179 @@ impl<C: CldrCalendar> TypedZonedDateTimeFormatter<C> {
         ))
     }
 
+    /// Alias to [`TypedZonedDateTimeFormatter::try_new_with_components`].
+    #[deprecated(since = "1.3.0", note = "renamed to `try_new_with_components`")]
+    #[cfg(feature = "compiled_data")]
+    #[inline]
+    pub fn try_new_experimental(
+        locale: &DataLocale,
+        date_time_format_options: DateTimeFormatterOptions,
+        time_zone_format_options: TimeZoneFormatterOptions,
+    ) -> Result<Self, DateTimeError>
+    where
+        crate::provider::Baked:
+            DataProvider<C::DateLengthsV1Marker> + DataProvider<C::DateSymbolsV1Marker>,
+    {
+        Self::try_new_with_components(locale, date_time_format_options, time_zone_format_options)
+    }
+
+    /// Alias to [`TypedZonedDateTimeFormatter::try_new_with_components_unstable`].
+    #[deprecated(
+        since = "1.3.0",
+        note = "renamed to `try_new_with_components_unstable`"
+    )]
+    #[inline]
+    pub fn try_new_experimental_unstable<P>(
+        provider: &P,
+        locale: &DataLocale,
+        date_time_format_options: DateTimeFormatterOptions,
+        time_zone_format_options: TimeZoneFormatterOptions,
+    ) -> Result<Self, DateTimeError>
+    where
+        P: DataProvider<<C as CldrCalendar>::DateSymbolsV1Marker>
+            + DataProvider<<C as CldrCalendar>::DateLengthsV1Marker>
+            + DataProvider<TimeSymbolsV1Marker>
+            + DataProvider<TimeLengthsV1Marker>
+            + DataProvider<QuarterSymbolsV1Marker>
+            + DataProvider<FlexibleDayPeriodsV1Marker>
+            + DataProvider<crate::provider::calendar::DateSkeletonPatternsV1Marker>
+            + DataProvider<crate::provider::calendar::AppendItemsV1Marker>
+            + DataProvider<WeekDataV1Marker>
+            + DataProvider<provider::time_zones::TimeZoneFormatsV1Marker>
+            + DataProvider<provider::time_zones::ExemplarCitiesV1Marker>
+            + DataProvider<provider::time_zones::MetazoneGenericNamesLongV1Marker>
+            + DataProvider<provider::time_zones::MetazoneGenericNamesShortV1Marker>
+            + DataProvider<provider::time_zones::MetazoneSpecificNamesLongV1Marker>
+            + DataProvider<provider::time_zones::MetazoneSpecificNamesShortV1Marker>
+            + DataProvider<OrdinalV1Marker>
+            + DataProvider<DecimalSymbolsV1Marker>
+            + ?Sized,
+    {
+        Self::try_new_with_components_unstable(
+            provider,
+            locale,
+            date_time_format_options,
+            time_zone_format_options,
+        )
+    }
+
+    /// Constructor that takes a selected locale and a pattern string, such as
+    /// `"d MMM y, HH:mm zzzz"`, then collects all compiled data necessary to format zoned
+    /// datetime values with that pattern into the given locale.
+    ///
+    /// The pattern uses the [date field symbols of UTS 35]. Only the symbol data that the
+    /// pattern refers to is loaded, including the time zone names of its time zone fields.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::{DateTime, Gregorian};
+    /// use icu::datetime::time_zone::TimeZoneFormatterOptions;
+    /// use icu::datetime::TypedZonedDateTimeFormatter;
+    /// use icu::locid::locale;
+    /// use icu::timezone::CustomTimeZone;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let zdtf = TypedZonedDateTimeFormatter::<Gregorian>::try_new_with_pattern(
+    ///     &locale!("en").into(),
+    ///     "d MMM y, HH:mm zzzz",
+    ///     TimeZoneFormatterOptions::default(),
+    /// )
+    /// .unwrap();
+    ///
+    /// let datetime =
+    ///     DateTime::try_new_gregorian_datetime(2022, 8, 31, 1, 2, 3).unwrap();
+    ///
+    /// assert_writeable_eq!(
+    ///     zdtf.format(&datetime, &CustomTimeZone::utc()),
+    ///     "31 Aug 2022, 01:02 GMT",
+    /// );
+    /// ```
+    ///
+    /// [date field symbols of UTS 35]: https://unicode.org/reports/tr35/tr35-dates.html#Date_Field_Symbol_Table
+    /// [data provider]: icu_provider
+    #[inline]
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new_with_pattern(
+        locale: &DataLocale,
+        pattern: &str,
+        time_zone_format_options: TimeZoneFormatterOptions,
+    ) -> Result<Self, DateTimeError>
+    where
+        crate::provider::Baked: DataProvider<C::DateSymbolsV1Marker>,
+    {
+        Ok(Self(
+            raw::ZonedDateTimeFormatter::try_new(
+                provider::date_time::pattern_for_string(pattern)?,
+                || {
+                    calendar::load_symbols_for_cldr_calendar::<C, _>(
+                        &crate::provider::Baked,
+                        locale,
+                    )
+                },
+                locale,
+                time_zone_format_options,
+            )?,
+            PhantomData,
+        ))
+    }
+
+    icu_provider::gen_any_buffer_data_constructors!(
+        locale: include,
+        pattern: &str,
+        time_zone_format_options: TimeZoneFormatterOptions,
+        error: DateTimeError,
+        #[cfg(skip)]
+        functions: [
+            try_new_with_pattern,
+            try_new_with_pattern_with_any_provider,
+            try_new_with_pattern_with_buffer_provider,
+            try_new_with_pattern_unstable,
+            Self
+        ]
+    );
+
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::try_new_with_pattern)]
+    #[inline]
+    pub fn try_new_with_pattern_unstable<P>(
+        provider: &P,
+        locale: &DataLocale,
+        pattern: &str,
+        time_zone_format_options: TimeZoneFormatterOptions,
+    ) -> Result<Self, DateTimeError>
+    where
+        P: DataProvider<<C as CldrCalendar>::DateSymbolsV1Marker>
+            + DataProvider<TimeSymbolsV1Marker>
+            + DataProvider<TimeLengthsV1Marker>
+            + DataProvider<QuarterSymbolsV1Marker>
+            + DataProvider<FlexibleDayPeriodsV1Marker>
+            + DataProvider<WeekDataV1Marker>
+            + DataProvider<provider::time_zones::TimeZoneFormatsV1Marker>
+            + DataProvider<provider::time_zones::ExemplarCitiesV1Marker>
+            + DataProvider<provider::time_zones::MetazoneGenericNamesLongV1Marker>
+            + DataProvider<provider::time_zones::MetazoneGenericNamesShortV1Marker>
+            + DataProvider<provider::time_zones::MetazoneSpecificNamesLongV1Marker>
+            + DataProvider<provider::time_zones::MetazoneSpecificNamesShortV1Marker>
+            + DataProvider<OrdinalV1Marker>
+            + DataProvider<DecimalSymbolsV1Marker>
+            + ?Sized,
+    {
+        Ok(Self(
+            raw::ZonedDateTimeFormatter::try_new_unstable(
+                provider,
+                provider::date_time::pattern_for_string(pattern)?,
+                || calendar::load_symbols_for_cldr_calendar::<C, _>(provider, locale),
+                locale,
+                time_zone_format_options,
+            )?,
+            PhantomData,
+        ))
+    }
+
     /// Constructor that takes a selected locale and a list of [`DateTimeFormatterOptions`].
     /// It collects all data necessary to format zoned datetime values into the given locale.
     ///
@@ -298,6 +468,8 @@ impl<C: CldrCalendar> TypedZonedDateTimeFormatter<C> {
             + DataProvider<<C as CldrCalendar>::DateLengthsV1Marker>
             + DataProvider<TimeSymbolsV1Marker>
             + DataProvider<TimeLengthsV1Marker>
+            + DataProvider<QuarterSymbolsV1Marker>
+            + DataProvider<FlexibleDayPeriodsV1Marker>
             + DataProvider<WeekDataV1Marker>
             + DataProvider<provider::time_zones::TimeZoneFormatsV1Marker>
             + DataProvider<provider::time_zones::ExemplarCitiesV1Marker>
@@ -67,10 +67,7 @@ fn test_fixture(fixture_name: &str) {
         let japanext = JapaneseExtended::new();
         let options_base = match fixtures::get_options(&fx.input.options) {
             Some(o) => o,
-            #[cfg(feature = "experimental")]
             None => unreachable!(),
-            #[cfg(not(feature = "experimental"))]
-            None => continue,
         };
         let input_value = mock::parse_gregorian_from_str(&fx.input.value).unwrap();
         let input_buddhist = input_value.to_calendar(Buddhist);
@@ -263,26 +260,17 @@ fn assert_fixture_element<A>(
 {
     let any_input = input_value.to_any();
     let iso_any_input = input_iso.to_any();
-    #[cfg(feature = "experimental")]
     let (dtf, any_dtf) = {
         (
-            TypedDateTimeFormatter::<A::Calendar>::try_new_experimental(
+            TypedDateTimeFormatter::<A::Calendar>::try_new_with_components(
                 &locale.into(),
                 options.clone(),
             )
             .expect(description),
-            DateTimeFormatter::try_new_experimental(&locale.into(), options.clone())
+            DateTimeFormatter::try_new_with_components(&locale.into(), options.clone())
                 .expect(description),
         )
     };
-    #[cfg(not(feature = "experimental"))]
-    let (dtf, any_dtf) = {
-        (
-            TypedDateTimeFormatter::<A::Calendar>::try_new(&locale.into(), options.clone())
-                .expect(description),
-            DateTimeFormatter::try_new(&locale.into(), options.clone()).expect(description),
-        )
-    };
 
     assert_writeable_eq!(dtf.format(input_value), output_value, "{}", description);
 
@@ -334,10 +322,7 @@ fn test_fixture_with_time_zones(fixture_name: &str, config: TimeZoneConfig) {
     {
         let options = match fixtures::get_options(&fx.input.options) {
             Some(o) => o,
-            #[cfg(feature = "experimental")]
             None => unreachable!(),
-            #[cfg(not(feature = "experimental"))]
-            None => continue,
         };
 
         let (input_date, mut time_zone) =
@@ -354,18 +339,8 @@ fn test_fixture_with_time_zones(fixture_name: &str, config: TimeZoneConfig) {
         };
         for (locale, output_value) in fx.output.values {
             let locale: Locale = locale.parse().unwrap();
-            #[cfg(feature = "experimental")]
-            let dtf = {
-                TypedZonedDateTimeFormatter::<Gregorian>::try_new_experimental(
-                    &locale.into(),
-                    options.clone(),
-                    TimeZoneFormatterOptions::default(),
-                )
-                .unwrap()
-            };
-            #[cfg(not(feature = "experimental"))]
             let dtf = {
-                TypedZonedDateTimeFormatter::<Gregorian>::try_new(
+                TypedZonedDateTimeFormatter::<Gregorian>::try_new_with_components(
                     &locale.into(),
                     options.clone(),
                     TimeZoneFormatterOptions::default(),
@@ -425,7 +400,6 @@ fn test_dayperiod_patterns() {
             .unwrap()
             .take_payload()
             .unwrap();
-        #[cfg(feature = "experimental")]
         let skeleton_data: DataPayload<DateSkeletonPatternsV1Marker> =
             icu_datetime::provider::Baked
                 .load(req)
@@ -464,7 +438,6 @@ fn test_dayperiod_patterns() {
                             AnyPayloadProvider::from_payload::<TimeSymbolsV1Marker>(
                                 time_symbols_data.clone(), //
                             ),
-                            #[cfg(feature = "experimental")]
                             AnyPayloadProvider::from_payload::<DateSkeletonPatternsV1Marker>(
                                 skeleton_data.clone(), //
                             ),
@@ -606,7 +579,6 @@ fn test_time_zone_patterns() {
                 .unwrap()
                 .take_payload()
                 .unwrap();
-        #[cfg(feature = "experimental")]
         let skeleton_data: DataPayload<DateSkeletonPatternsV1Marker> =
             icu_datetime::provider::Baked
                 .load(req)
@@ -687,7 +659,6 @@ fn test_time_zone_patterns() {
                     AnyPayloadProvider::from_payload::<GregorianDateSymbolsV1Marker>(
                         symbols_data.clone(), //
                     ),
-                    #[cfg(feature = "experimental")]
                     AnyPayloadProvider::from_payload::<DateSkeletonPatternsV1Marker>(
                         skeleton_data.clone(), //
                     ),
@@ -841,3 +812,113 @@ fn constructing_datetime_format_with_time_zone_pattern_symbols_is_err() {
 
     assert!(result.is_err());
 }
+
+#[test]
+fn test_pattern_strings() {
+    let datetime = DateTime::try_new_gregorian_datetime(2020, 9, 1, 12, 34, 28).unwrap();
+
+    for (locale, pattern, expected) in [
+        (
+            locale!("en"),
+            "EEE, d MMM yyyy HH:mm",
+            "Tue, 1 Sep 2020 12:34",
+        ),
+        (
+            locale!("en"),
+            "EEEE 'at' h:mm:ss a",
+            "Tuesday at 12:34:28 PM",
+        ),
+        (
+            locale!("en"),
+            "y-MM-dd'T'HH:mm:ssSSS",
+            "2020-09-01T12:34:28.000",
+        ),
+        (
+            locale!("fr"),
+            "EEEE d MMMM y G",
+            "mardi 1 septembre 2020 ap. J.-C.",
+        ),
+    ] {
+        let dtf =
+            TypedDateTimeFormatter::<Gregorian>::try_new_with_pattern(&locale.into(), pattern)
+                .unwrap();
+        assert_writeable_eq!(dtf.format(&datetime), expected, "{pattern}");
+    }
+
+    // Invalid patterns are rejected.
+    assert!(matches!(
+        TypedDateTimeFormatter::<Gregorian>::try_new_with_pattern(
+            &locale!("en").into(),
+            "EEE 'unclosed quote"
+        ),
+        Err(icu_datetime::DateTimeError::Pattern(_))
+    ));
+
+    // Time zone fields need a zoned formatter.
+    assert!(matches!(
+        TypedDateTimeFormatter::<Gregorian>::try_new_with_pattern(&locale!("en").into(), "HH:mm z"),
+        Err(icu_datetime::DateTimeError::UnsupportedField(_))
+    ));
+    let zdtf = TypedZonedDateTimeFormatter::<Gregorian>::try_new_with_pattern(
+        &locale!("en").into(),
+        "HH:mm z",
+        TimeZoneFormatterOptions::default(),
+    )
+    .unwrap();
+    assert_writeable_eq!(
+        zdtf.format(&datetime, &CustomTimeZone::from_str("-07:00").unwrap()),
+        "12:34 GMT-07:00"
+    );
+}
+
+#[test]
+fn test_week_fields() {
+    // 2021-01-01 is a Friday. The US counts it in the first week of 2021 with weeks
+    // starting on Sunday; Germany follows ISO 8601 and counts it in the last week of 2020.
+    let datetime = DateTime::try_new_gregorian_datetime(2021, 1, 1, 12, 0, 0).unwrap();
+
+    for (locale, pattern, expected) in [
+        (locale!("en-US"), "Y-'W'ww-e", "2021-W01-6"),
+        (locale!("de"), "Y-'W'ww-e", "2020-W53-5"),
+        (locale!("en-US"), "'week' w 'of' Y", "week 1 of 2021"),
+        (locale!("de"), "'week' w 'of' Y", "week 53 of 2020"),
+        (locale!("de"), "'week' W 'of' MMMM", "week 1 of Januar"),
+        (locale!("en-US"), "ee cc", "06 06"),
+        (
+            locale!("en-US"),
+            "eee eeee ccc cccc",
+            "Fri Friday Fri Friday",
+        ),
+        (locale!("de"), "YY ww", "20 53"),
+    ] {
+        let dtf =
+            TypedDateTimeFormatter::<Gregorian>::try_new_with_pattern(&locale.into(), pattern)
+                .unwrap();
+        assert_writeable_eq!(dtf.format(&datetime), expected, "{pattern}");
+    }
+}
+
+#[test]
+fn test_pattern_strings_load_only_needed_symbols() {
+    let data_locale: DataLocale = locale!("en").into();
+    let decimal_data: DataPayload<DecimalSymbolsV1Marker> = icu_decimal::provider::Baked
+        .load(DataRequest {
+            locale: &data_locale,
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    // A numeric pattern needs neither date nor time symbols.
+    let provider = MultiForkByKeyProvider::new(vec![AnyPayloadProvider::from_payload::<
+        DecimalSymbolsV1Marker,
+    >(decimal_data)]);
+    let dtf = TypedDateTimeFormatter::<Gregorian>::try_new_with_pattern_with_any_provider(
+        &provider,
+        &data_locale,
+        "dd.MM.y HH:mm",
+    )
+    .unwrap();
+    let datetime = DateTime::try_new_gregorian_datetime(2020, 9, 1, 12, 34, 28).unwrap();
+    assert_writeable_eq!(dtf.format(&datetime), "01.09.2020 12:34");
+}
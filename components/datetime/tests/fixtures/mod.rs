@@ -20,9 +20,6 @@ pub fn get_fixture(name: &str) -> std::io::Result<structs::Fixture> {
 pub fn get_options(input: &structs::TestOptions) -> Option<DateTimeFormatterOptions> {
     match input {
         structs::TestOptions::Length(bag) => Some((*bag).into()),
-        #[cfg(feature = "experimental")]
         structs::TestOptions::Components(bag) => Some((*bag).into()),
-        #[cfg(not(feature = "experimental"))]
-        structs::TestOptions::Components(_) => None,
     }
 }
@@ -31,11 +31,7 @@ pub enum TestOptions {
     #[serde(rename = "length")]
     Length(icu_datetime::options::length::Bag),
     #[serde(rename = "components")]
-    #[cfg(feature = "experimental")]
     Components(icu_datetime::options::components::Bag),
-    #[serde(rename = "components")]
-    #[cfg(not(feature = "experimental"))]
-    Components(serde_json::Value),
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -1,5 +1,10 @@
 // @generated
 #[macro_use]
+#[path = "macros/decimal_notation_v1.data.rs"]
+mod decimal_notation_v1;
+#[doc(inline)]
+pub use __impl_decimal_notation_v1 as impl_decimal_notation_v1;
+#[macro_use]
 #[path = "macros/decimal_symbols_v1.data.rs"]
 mod decimal_symbols_v1;
 #[doc(inline)]
@@ -0,0 +1,50 @@
+// @generated
+/// Implement `DataProvider<DecimalNotationSymbolsV1Marker>` on the given struct using the data
+/// hardcoded in this file. This allows the struct to be used with
+/// `icu`'s `_unstable` constructors.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_decimal_notation_v1 {
+    ($ provider : path) => {
+        #[clippy::msrv = "1.66"]
+        impl icu_provider::DataProvider<icu::decimal::provider::DecimalNotationSymbolsV1Marker> for $provider {
+            fn load(&self, req: icu_provider::DataRequest) -> Result<icu_provider::DataResponse<icu::decimal::provider::DecimalNotationSymbolsV1Marker>, icu_provider::DataError> {
+                static BRX: <icu::decimal::provider::DecimalNotationSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::decimal::provider::DecimalNotationSymbolsV1 { percent_affixes: icu::decimal::provider::AffixesV1 { prefix: alloc::borrow::Cow::Borrowed(""), suffix: alloc::borrow::Cow::Borrowed("%") }, per_mille_affixes: icu::decimal::provider::AffixesV1 { prefix: alloc::borrow::Cow::Borrowed(""), suffix: alloc::borrow::Cow::Borrowed("‰") }, exponential: alloc::borrow::Cow::Borrowed("E"), superscripting_exponent: alloc::borrow::Cow::Borrowed("x") };
+                static DE_CH: <icu::decimal::provider::DecimalNotationSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::decimal::provider::DecimalNotationSymbolsV1 { percent_affixes: icu::decimal::provider::AffixesV1 { prefix: alloc::borrow::Cow::Borrowed(""), suffix: alloc::borrow::Cow::Borrowed("%") }, per_mille_affixes: icu::decimal::provider::AffixesV1 { prefix: alloc::borrow::Cow::Borrowed(""), suffix: alloc::borrow::Cow::Borrowed("‰") }, exponential: alloc::borrow::Cow::Borrowed("E"), superscripting_exponent: alloc::borrow::Cow::Borrowed("·") };
+                static UND: <icu::decimal::provider::DecimalNotationSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::decimal::provider::DecimalNotationSymbolsV1 { percent_affixes: icu::decimal::provider::AffixesV1 { prefix: alloc::borrow::Cow::Borrowed(""), suffix: alloc::borrow::Cow::Borrowed("%") }, per_mille_affixes: icu::decimal::provider::AffixesV1 { prefix: alloc::borrow::Cow::Borrowed(""), suffix: alloc::borrow::Cow::Borrowed("‰") }, exponential: alloc::borrow::Cow::Borrowed("E"), superscripting_exponent: alloc::borrow::Cow::Borrowed("×") };
+                static EL: <icu::decimal::provider::DecimalNotationSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::decimal::provider::DecimalNotationSymbolsV1 { percent_affixes: icu::decimal::provider::AffixesV1 { prefix: alloc::borrow::Cow::Borrowed(""), suffix: alloc::borrow::Cow::Borrowed("%") }, per_mille_affixes: icu::decimal::provider::AffixesV1 { prefix: alloc::borrow::Cow::Borrowed(""), suffix: alloc::borrow::Cow::Borrowed("‰") }, exponential: alloc::borrow::Cow::Borrowed("e"), superscripting_exponent: alloc::borrow::Cow::Borrowed("×") };
+                static ET: <icu::decimal::provider::DecimalNotationSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::decimal::provider::DecimalNotationSymbolsV1 { percent_affixes: icu::decimal::provider::AffixesV1 { prefix: alloc::borrow::Cow::Borrowed(""), suffix: alloc::borrow::Cow::Borrowed("%") }, per_mille_affixes: icu::decimal::provider::AffixesV1 { prefix: alloc::borrow::Cow::Borrowed(""), suffix: alloc::borrow::Cow::Borrowed("‰") }, exponential: alloc::borrow::Cow::Borrowed("×10^"), superscripting_exponent: alloc::borrow::Cow::Borrowed("×") };
+                static UR_IN: <icu::decimal::provider::DecimalNotationSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::decimal::provider::DecimalNotationSymbolsV1 { percent_affixes: icu::decimal::provider::AffixesV1 { prefix: alloc::borrow::Cow::Borrowed(""), suffix: alloc::borrow::Cow::Borrowed("%") }, per_mille_affixes: icu::decimal::provider::AffixesV1 { prefix: alloc::borrow::Cow::Borrowed(""), suffix: alloc::borrow::Cow::Borrowed("‰") }, exponential: alloc::borrow::Cow::Borrowed("×۱۰^"), superscripting_exponent: alloc::borrow::Cow::Borrowed("×") };
+                static UK: <icu::decimal::provider::DecimalNotationSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::decimal::provider::DecimalNotationSymbolsV1 { percent_affixes: icu::decimal::provider::AffixesV1 { prefix: alloc::borrow::Cow::Borrowed(""), suffix: alloc::borrow::Cow::Borrowed("%") }, per_mille_affixes: icu::decimal::provider::AffixesV1 { prefix: alloc::borrow::Cow::Borrowed(""), suffix: alloc::borrow::Cow::Borrowed("‰") }, exponential: alloc::borrow::Cow::Borrowed("Е"), superscripting_exponent: alloc::borrow::Cow::Borrowed("×") };
+                static FF_ADLM: <icu::decimal::provider::DecimalNotationSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::decimal::provider::DecimalNotationSymbolsV1 { percent_affixes: icu::decimal::provider::AffixesV1 { prefix: alloc::borrow::Cow::Borrowed(""), suffix: alloc::borrow::Cow::Borrowed("%") }, per_mille_affixes: icu::decimal::provider::AffixesV1 { prefix: alloc::borrow::Cow::Borrowed(""), suffix: alloc::borrow::Cow::Borrowed("‰") }, exponential: alloc::borrow::Cow::Borrowed("𞤉"), superscripting_exponent: alloc::borrow::Cow::Borrowed("×") };
+                static AR_AE: <icu::decimal::provider::DecimalNotationSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::decimal::provider::DecimalNotationSymbolsV1 { percent_affixes: icu::decimal::provider::AffixesV1 { prefix: alloc::borrow::Cow::Borrowed(""), suffix: alloc::borrow::Cow::Borrowed("\u{200e}%\u{200e}") }, per_mille_affixes: icu::decimal::provider::AffixesV1 { prefix: alloc::borrow::Cow::Borrowed(""), suffix: alloc::borrow::Cow::Borrowed("‰") }, exponential: alloc::borrow::Cow::Borrowed("E"), superscripting_exponent: alloc::borrow::Cow::Borrowed("×") };
+                static DE: <icu::decimal::provider::DecimalNotationSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::decimal::provider::DecimalNotationSymbolsV1 { percent_affixes: icu::decimal::provider::AffixesV1 { prefix: alloc::borrow::Cow::Borrowed(""), suffix: alloc::borrow::Cow::Borrowed("\u{a0}%") }, per_mille_affixes: icu::decimal::provider::AffixesV1 { prefix: alloc::borrow::Cow::Borrowed(""), suffix: alloc::borrow::Cow::Borrowed("\u{a0}‰") }, exponential: alloc::borrow::Cow::Borrowed("E"), superscripting_exponent: alloc::borrow::Cow::Borrowed("·") };
+                static BE: <icu::decimal::provider::DecimalNotationSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::decimal::provider::DecimalNotationSymbolsV1 { percent_affixes: icu::decimal::provider::AffixesV1 { prefix: alloc::borrow::Cow::Borrowed(""), suffix: alloc::borrow::Cow::Borrowed("\u{a0}%") }, per_mille_affixes: icu::decimal::provider::AffixesV1 { prefix: alloc::borrow::Cow::Borrowed(""), suffix: alloc::borrow::Cow::Borrowed("\u{a0}‰") }, exponential: alloc::borrow::Cow::Borrowed("E"), superscripting_exponent: alloc::borrow::Cow::Borrowed("×") };
+                static SK: <icu::decimal::provider::DecimalNotationSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::decimal::provider::DecimalNotationSymbolsV1 { percent_affixes: icu::decimal::provider::AffixesV1 { prefix: alloc::borrow::Cow::Borrowed(""), suffix: alloc::borrow::Cow::Borrowed("\u{a0}%") }, per_mille_affixes: icu::decimal::provider::AffixesV1 { prefix: alloc::borrow::Cow::Borrowed(""), suffix: alloc::borrow::Cow::Borrowed("\u{a0}‰") }, exponential: alloc::borrow::Cow::Borrowed("e"), superscripting_exponent: alloc::borrow::Cow::Borrowed("×") };
+                static EN_SE: <icu::decimal::provider::DecimalNotationSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::decimal::provider::DecimalNotationSymbolsV1 { percent_affixes: icu::decimal::provider::AffixesV1 { prefix: alloc::borrow::Cow::Borrowed(""), suffix: alloc::borrow::Cow::Borrowed("\u{a0}%") }, per_mille_affixes: icu::decimal::provider::AffixesV1 { prefix: alloc::borrow::Cow::Borrowed(""), suffix: alloc::borrow::Cow::Borrowed("\u{a0}‰") }, exponential: alloc::borrow::Cow::Borrowed("×10^"), superscripting_exponent: alloc::borrow::Cow::Borrowed("×") };
+                static FA: <icu::decimal::provider::DecimalNotationSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::decimal::provider::DecimalNotationSymbolsV1 { percent_affixes: icu::decimal::provider::AffixesV1 { prefix: alloc::borrow::Cow::Borrowed(""), suffix: alloc::borrow::Cow::Borrowed("٪") }, per_mille_affixes: icu::decimal::provider::AffixesV1 { prefix: alloc::borrow::Cow::Borrowed(""), suffix: alloc::borrow::Cow::Borrowed("؉") }, exponential: alloc::borrow::Cow::Borrowed("×۱۰^"), superscripting_exponent: alloc::borrow::Cow::Borrowed("×") };
+                static AR_SA_U_NU_LATN: <icu::decimal::provider::DecimalNotationSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::decimal::provider::DecimalNotationSymbolsV1 { percent_affixes: icu::decimal::provider::AffixesV1 { prefix: alloc::borrow::Cow::Borrowed(""), suffix: alloc::borrow::Cow::Borrowed("٪") }, per_mille_affixes: icu::decimal::provider::AffixesV1 { prefix: alloc::borrow::Cow::Borrowed(""), suffix: alloc::borrow::Cow::Borrowed("‰") }, exponential: alloc::borrow::Cow::Borrowed("E"), superscripting_exponent: alloc::borrow::Cow::Borrowed("×") };
+                static AR: <icu::decimal::provider::DecimalNotationSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::decimal::provider::DecimalNotationSymbolsV1 { percent_affixes: icu::decimal::provider::AffixesV1 { prefix: alloc::borrow::Cow::Borrowed(""), suffix: alloc::borrow::Cow::Borrowed("٪\u{61c}") }, per_mille_affixes: icu::decimal::provider::AffixesV1 { prefix: alloc::borrow::Cow::Borrowed(""), suffix: alloc::borrow::Cow::Borrowed("؉") }, exponential: alloc::borrow::Cow::Borrowed("اس"), superscripting_exponent: alloc::borrow::Cow::Borrowed("×") };
+                static TR: <icu::decimal::provider::DecimalNotationSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::decimal::provider::DecimalNotationSymbolsV1 { percent_affixes: icu::decimal::provider::AffixesV1 { prefix: alloc::borrow::Cow::Borrowed("%"), suffix: alloc::borrow::Cow::Borrowed("") }, per_mille_affixes: icu::decimal::provider::AffixesV1 { prefix: alloc::borrow::Cow::Borrowed("‰"), suffix: alloc::borrow::Cow::Borrowed("") }, exponential: alloc::borrow::Cow::Borrowed("E"), superscripting_exponent: alloc::borrow::Cow::Borrowed("×") };
+                static EU: <icu::decimal::provider::DecimalNotationSymbolsV1Marker as icu_provider::DataMarker>::Yokeable = icu::decimal::provider::DecimalNotationSymbolsV1 { percent_affixes: icu::decimal::provider::AffixesV1 { prefix: alloc::borrow::Cow::Borrowed("%\u{a0}"), suffix: alloc::borrow::Cow::Borrowed("") }, per_mille_affixes: icu::decimal::provider::AffixesV1 { prefix: alloc::borrow::Cow::Borrowed("‰\u{a0}"), suffix: alloc::borrow::Cow::Borrowed("") }, exponential: alloc::borrow::Cow::Borrowed("E"), superscripting_exponent: alloc::borrow::Cow::Borrowed("×") };
+                static VALUES: [&<icu::decimal::provider::DecimalNotationSymbolsV1Marker as icu_provider::DataMarker>::Yokeable; 62usize] = [&AR, &AR_AE, &AR_AE, &AR_AE, &AR_AE, &AR_AE, &AR_SA_U_NU_LATN, &AR_SA_U_NU_LATN, &AR_AE, &AR_AE, &BE, &BE, &BRX, &BE, &BE, &BE, &BE, &BE, &DE, &DE_CH, &DE_CH, &DE, &EL, &DE, &EL, &DE_CH, &DE, &DE, &BE, &EN_SE, &EL, &BE, &ET, &EU, &FA, &FF_ADLM, &BE, &BE, &BE, &BE, &BE, &DE, &FA, &EN_SE, &BE, &BE, &FA, &BE, &BE, &BE, &BE, &AR, &SK, &SK, &EN_SE, &BE, &TR, &BE, &UK, &UND, &UR_IN, &UR_IN];
+                static KEYS: [&str; 62usize] = ["ar", "ar-AE", "ar-DZ", "ar-EH", "ar-LY", "ar-MA", "ar-SA-u-nu-latn", "ar-SO-u-nu-latn", "ar-TN", "ar-u-nu-latn", "be", "br", "brx", "bs", "ca", "cs", "cv", "da", "de", "de-CH", "de-LI", "dsb", "el", "en-AT", "en-AU", "en-CH", "en-DE", "en-DK", "en-FI", "en-SE", "en-SI", "es", "et", "eu", "fa", "ff-Adlm", "fi", "fo", "fr", "gl", "hr", "hsb", "ks", "lt", "mk", "no", "ps", "qu", "rm", "ro", "ru", "sd", "sk", "sl", "sv", "tk", "tr", "tt", "uk", "und", "ur-IN", "ur-u-nu-arabext"];
+                let mut metadata = icu_provider::DataResponseMetadata::default();
+                let payload = if let Ok(payload) = KEYS.binary_search_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse()).map(|i| *unsafe { VALUES.get_unchecked(i) }) {
+                    payload
+                } else {
+                    const FALLBACKER: icu::locid_transform::fallback::LocaleFallbackerWithConfig<'static> = icu::locid_transform::fallback::LocaleFallbacker::new().for_config(<icu::decimal::provider::DecimalNotationSymbolsV1Marker as icu_provider::KeyedDataMarker>::KEY.fallback_config());
+                    let mut fallback_iterator = FALLBACKER.fallback_for(req.locale.clone());
+                    loop {
+                        if let Ok(payload) = KEYS.binary_search_by(|k| fallback_iterator.get().strict_cmp(k.as_bytes()).reverse()).map(|i| *unsafe { VALUES.get_unchecked(i) }) {
+                            metadata.locale = Some(fallback_iterator.take());
+                            break payload;
+                        }
+                        fallback_iterator.step();
+                    }
+                };
+                Ok(icu_provider::DataResponse { payload: Some(icu_provider::DataPayload::from_static_ref(payload)), metadata })
+            }
+        }
+    };
+}
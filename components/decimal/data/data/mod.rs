@@ -13,6 +13,7 @@ include!("macros.rs");
 #[macro_export]
 macro_rules! __impl_data_provider {
     ($ provider : path) => {
+        impl_decimal_notation_v1!($provider);
         impl_decimal_symbols_v1!($provider);
     };
 }
@@ -35,6 +36,7 @@ macro_rules! __impl_any_provider {
         impl icu_provider::AnyProvider for $provider {
             fn load_any(&self, key: icu_provider::DataKey, req: icu_provider::DataRequest) -> Result<icu_provider::AnyResponse, icu_provider::DataError> {
                 match key.hashed() {
+                    h if h == <icu::decimal::provider::DecimalNotationSymbolsV1Marker as icu_provider::KeyedDataMarker>::KEY.hashed() => icu_provider::DataProvider::<icu::decimal::provider::DecimalNotationSymbolsV1Marker>::load(self, req).map(icu_provider::DataResponse::wrap_into_any_response),
                     h if h == <icu::decimal::provider::DecimalSymbolsV1Marker as icu_provider::KeyedDataMarker>::KEY.hashed() => icu_provider::DataProvider::<icu::decimal::provider::DecimalSymbolsV1Marker>::load(self, req).map(icu_provider::DataResponse::wrap_into_any_response),
                     _ => Err(icu_provider::DataErrorKind::MissingDataKey.with_req(key, req)),
                 }
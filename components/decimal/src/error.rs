@@ -15,6 +15,14 @@ pub enum DecimalError {
     /// An error originating inside of the [data provider](icu_provider).
     #[displaydoc("error loading data: {0}")]
     Data(icu_provider::DataError),
+    /// The input could not be parsed as a number. Contains the byte offset in the input at
+    /// which parsing failed.
+    #[displaydoc("could not parse number at byte offset {0}")]
+    Parse(usize),
+    /// The digit options are out of range: a minimum is greater than its maximum, or a
+    /// number of significant digits is zero.
+    #[displaydoc("invalid digit options")]
+    InvalidDigitOptions,
 }
 
 #[cfg(feature = "std")]
@@ -6,61 +6,561 @@
 
 use crate::grouper;
 use crate::options::*;
+use crate::precision;
 use crate::provider::*;
+use alloc::borrow::Cow;
+use core::fmt::Write;
 use fixed_decimal::FixedDecimal;
 use fixed_decimal::Sign;
-use writeable::Writeable;
+use writeable::{Part, PartsWrite, Writeable};
+
+/// The [`Part`](writeable::Part)s used by [`FormattedFixedDecimal`].
+///
+/// The values match the part types of `Intl.NumberFormat.prototype.formatToParts` in ECMA-402.
+pub mod parts {
+    use writeable::Part;
+
+    /// The [`Part`] used to mark the digits of the integer part, between grouping separators.
+    ///
+    /// * `category`: `"decimal"`
+    /// * `value`: `"integer"`
+    pub const INTEGER: Part = Part {
+        category: "decimal",
+        value: "integer",
+    };
+
+    /// The [`Part`] used to mark a grouping separator.
+    ///
+    /// * `category`: `"decimal"`
+    /// * `value`: `"group"`
+    pub const GROUP: Part = Part {
+        category: "decimal",
+        value: "group",
+    };
+
+    /// The [`Part`] used to mark the decimal separator.
+    ///
+    /// * `category`: `"decimal"`
+    /// * `value`: `"decimal"`
+    pub const DECIMAL: Part = Part {
+        category: "decimal",
+        value: "decimal",
+    };
+
+    /// The [`Part`] used to mark the digits of the fraction part.
+    ///
+    /// * `category`: `"decimal"`
+    /// * `value`: `"fraction"`
+    pub const FRACTION: Part = Part {
+        category: "decimal",
+        value: "fraction",
+    };
+
+    /// The [`Part`] used to mark the minus sign affixes.
+    ///
+    /// * `category`: `"decimal"`
+    /// * `value`: `"minusSign"`
+    pub const MINUS_SIGN: Part = Part {
+        category: "decimal",
+        value: "minusSign",
+    };
+
+    /// The [`Part`] used to mark the plus sign affixes.
+    ///
+    /// * `category`: `"decimal"`
+    /// * `value`: `"plusSign"`
+    pub const PLUS_SIGN: Part = Part {
+        category: "decimal",
+        value: "plusSign",
+    };
+
+    /// The [`Part`] used to mark the percent sign affixes.
+    ///
+    /// * `category`: `"decimal"`
+    /// * `value`: `"percentSign"`
+    pub const PERCENT_SIGN: Part = Part {
+        category: "decimal",
+        value: "percentSign",
+    };
+
+    /// The [`Part`] used to mark the per-mille sign affixes.
+    ///
+    /// * `category`: `"decimal"`
+    /// * `value`: `"perMilleSign"`
+    pub const PER_MILLE_SIGN: Part = Part {
+        category: "decimal",
+        value: "perMilleSign",
+    };
+
+    /// The [`Part`] used to mark the exponent separator, such as "E" or "×10".
+    ///
+    /// * `category`: `"decimal"`
+    /// * `value`: `"exponentSeparator"`
+    pub const EXPONENT_SEPARATOR: Part = Part {
+        category: "decimal",
+        value: "exponentSeparator",
+    };
+
+    /// The [`Part`] used to mark the minus sign of a negative exponent.
+    ///
+    /// * `category`: `"decimal"`
+    /// * `value`: `"exponentMinusSign"`
+    pub const EXPONENT_MINUS_SIGN: Part = Part {
+        category: "decimal",
+        value: "exponentMinusSign",
+    };
+
+    /// The [`Part`] used to mark the digits of the exponent.
+    ///
+    /// * `category`: `"decimal"`
+    /// * `value`: `"exponentInteger"`
+    pub const EXPONENT_INTEGER: Part = Part {
+        category: "decimal",
+        value: "exponentInteger",
+    };
+}
 
 /// An intermediate structure returned by [`FixedDecimalFormatter`](crate::FixedDecimalFormatter).
 /// Use [`Writeable`][Writeable] to render the formatted decimal to a string or buffer.
 #[derive(Debug, PartialEq, Clone)]
 pub struct FormattedFixedDecimal<'l> {
-    pub(crate) value: &'l FixedDecimal,
+    pub(crate) value: Cow<'l, FixedDecimal>,
+    pub(crate) exponent: Option<i16>,
     pub(crate) options: &'l FixedDecimalFormatterOptions,
+    pub(crate) notation: Notation,
     pub(crate) symbols: &'l DecimalSymbolsV1<'l>,
+    pub(crate) notation_symbols: Option<&'l DecimalNotationSymbolsV1<'l>>,
 }
 
+const SUPERSCRIPT_DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+
 impl<'l> FormattedFixedDecimal<'l> {
-    fn get_affixes(&self) -> Option<&AffixesV1> {
+    /// Scales the value for the notation and applies the precision options, cloning the value
+    /// only if it changes.
+    pub(crate) fn new(
+        value: &'l FixedDecimal,
+        options: &'l FixedDecimalFormatterOptions,
+        notation: Notation,
+        symbols: &'l DecimalSymbolsV1<'l>,
+        notation_symbols: Option<&'l DecimalNotationSymbolsV1<'l>>,
+    ) -> Self {
+        let mut exponent = None;
+        let value = match notation {
+            Notation::Standard if !precision::is_needed(value, options) => Cow::Borrowed(value),
+            Notation::Standard => {
+                let mut value = value.clone();
+                precision::apply(&mut value, options);
+                Cow::Owned(value)
+            }
+            Notation::Percent | Notation::PerMille => {
+                let shift = if notation == Notation::Percent { 2 } else { 3 };
+                // Keep any leading zeros of the value, but do not turn the zero before the
+                // decimal separator into a leading zero, as in "050%".
+                let upper = *value.magnitude_range().end();
+                let mut value = value.clone().multiplied_pow10(shift);
+                value.trim_start();
+                value.pad_start(upper.saturating_add(1));
+                precision::apply(&mut value, options);
+                Cow::Owned(value)
+            }
+            Notation::Scientific | Notation::Engineering => {
+                let mut mantissa = value.clone();
+                let mut e = normalize(&mut mantissa, notation);
+                // Trailing zeros in the integer part are not significant.
+                if *value.magnitude_range().start() >= 0 {
+                    mantissa.trim_end();
+                }
+                precision::apply(&mut mantissa, options);
+                // Rounding may carry into a new integer digit, as in 9.99 to 10.0.
+                let carry = normalize(&mut mantissa, notation);
+                if carry != 0 {
+                    precision::apply(&mut mantissa, options);
+                    e = e.saturating_add(carry);
+                }
+                exponent = Some(e);
+                Cow::Owned(mantissa)
+            }
+        };
+        Self {
+            value,
+            exponent,
+            options,
+            notation,
+            symbols,
+            notation_symbols,
+        }
+    }
+
+    /// Returns the number as it is displayed: scaled for [`Notation::Percent`] and
+    /// [`Notation::PerMille`], and rounded and padded according to the options.
+    ///
+    /// For [`Notation::Scientific`] and [`Notation::Engineering`], this is the significand;
+    /// see [`exponent`](Self::exponent).
+    ///
+    /// Use this value to select the plural form of a formatted number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    /// use icu::decimal::options;
+    /// use icu::decimal::FixedDecimalFormatter;
+    /// use icu::locid::locale;
+    ///
+    /// let mut options: options::FixedDecimalFormatterOptions = Default::default();
+    /// options.maximum_fraction_digits = Some(0);
+    /// let fdf = FixedDecimalFormatter::try_new(&locale!("en").into(), options)
+    ///     .expect("locale should be present");
+    ///
+    /// let value = FixedDecimal::from(12).multiplied_pow10(-1);
+    /// assert_eq!(fdf.format(&value).value(), &FixedDecimal::from(1));
+    /// ```
+    pub fn value(&self) -> &FixedDecimal {
+        &self.value
+    }
+
+    /// Returns the exponent of the displayed number in [`Notation::Scientific`] and
+    /// [`Notation::Engineering`], or `None` for the other notations.
+    pub fn exponent(&self) -> Option<i16> {
+        self.exponent
+    }
+
+    fn get_affixes(&self) -> Option<(Part, &AffixesV1)> {
         match self.value.sign() {
             Sign::None => None,
-            Sign::Negative => Some(&self.symbols.minus_sign_affixes),
-            Sign::Positive => Some(&self.symbols.plus_sign_affixes),
+            Sign::Negative => Some((parts::MINUS_SIGN, &self.symbols.minus_sign_affixes)),
+            Sign::Positive => Some((parts::PLUS_SIGN, &self.symbols.plus_sign_affixes)),
         }
     }
+
+    fn get_notation_affixes(&self) -> Option<(Part, &AffixesV1)> {
+        let notation_symbols = self.notation_symbols?;
+        match self.notation {
+            Notation::Percent => Some((parts::PERCENT_SIGN, &notation_symbols.percent_affixes)),
+            Notation::PerMille => {
+                Some((parts::PER_MILLE_SIGN, &notation_symbols.per_mille_affixes))
+            }
+            _ => None,
+        }
+    }
+
+    fn write_exponent<W>(&self, exponent: i16, sink: &mut W) -> core::fmt::Result
+    where
+        W: PartsWrite + ?Sized,
+    {
+        let notation_symbols = match self.notation_symbols {
+            Some(notation_symbols) => notation_symbols,
+            None => return Ok(()),
+        };
+        let minus_sign_affixes = &self.symbols.minus_sign_affixes;
+        match self.options.exponent_style {
+            ExponentStyle::Superscript => {
+                sink.with_part(parts::EXPONENT_SEPARATOR, |w| {
+                    w.write_str(&notation_symbols.superscripting_exponent)?;
+                    w.write_char(self.symbols.digits[1])?;
+                    w.write_char(self.symbols.digits[0])
+                })?;
+                if exponent < 0 {
+                    sink.with_part(parts::EXPONENT_MINUS_SIGN, |w| w.write_char('⁻'))?;
+                }
+                sink.with_part(parts::EXPONENT_INTEGER, |w| {
+                    write_digits(exponent.unsigned_abs(), &SUPERSCRIPT_DIGITS, w)
+                })
+            }
+            ExponentStyle::Symbol => {
+                sink.with_part(parts::EXPONENT_SEPARATOR, |w| {
+                    w.write_str(&notation_symbols.exponential)
+                })?;
+                if exponent < 0 {
+                    sink.with_part(parts::EXPONENT_MINUS_SIGN, |w| {
+                        w.write_str(&minus_sign_affixes.prefix)
+                    })?;
+                }
+                sink.with_part(parts::EXPONENT_INTEGER, |w| {
+                    write_digits(exponent.unsigned_abs(), &self.symbols.digits, w)
+                })?;
+                if exponent < 0 {
+                    sink.with_part(parts::EXPONENT_MINUS_SIGN, |w| {
+                        w.write_str(&minus_sign_affixes.suffix)
+                    })?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Divides the value by the power of ten that leaves one integer digit, or one to three integer
+/// digits in engineering notation, returning the exponent of that power.
+fn normalize(value: &mut FixedDecimal, notation: Notation) -> i16 {
+    let start = value.nonzero_magnitude_start();
+    let exponent = if notation == Notation::Engineering {
+        // Near the bottom of the magnitude range, the multiple of three may not fit in an i16;
+        // fall back to the scientific exponent there.
+        start.checked_sub(start.rem_euclid(3)).unwrap_or(start)
+    } else {
+        start
+    };
+    match exponent.checked_neg() {
+        Some(shift) => value.multiply_pow10(shift),
+        // -i16::MIN does not fit in an i16, so shift in two steps
+        None => {
+            value.multiply_pow10(i16::MAX);
+            value.trim_start();
+            value.multiply_pow10(1);
+        }
+    }
+    value.trim_start();
+    exponent
+}
+
+fn write_digits<W>(value: u16, digits: &[char; 10], sink: &mut W) -> core::fmt::Result
+where
+    W: core::fmt::Write + ?Sized,
+{
+    let mut divisor = 1;
+    while value / divisor >= 10 {
+        divisor *= 10;
+    }
+    loop {
+        #[allow(clippy::indexing_slicing)] // digit in 0..=9
+        sink.write_char(digits[(value / divisor % 10) as usize])?;
+        if divisor == 1 {
+            return Ok(());
+        }
+        divisor /= 10;
+    }
 }
 
 impl<'l> Writeable for FormattedFixedDecimal<'l> {
-    fn write_to<W>(&self, sink: &mut W) -> core::result::Result<(), core::fmt::Error>
+    fn write_to_parts<W>(&self, sink: &mut W) -> core::result::Result<(), core::fmt::Error>
     where
-        W: core::fmt::Write + ?Sized,
+        W: PartsWrite + ?Sized,
     {
         let affixes = self.get_affixes();
-        if let Some(affixes) = affixes {
-            sink.write_str(&affixes.prefix)?;
+        let notation_affixes = self.get_notation_affixes();
+        if let Some((part, affixes)) = affixes {
+            sink.with_part(part, |w| w.write_str(&affixes.prefix))?;
+        }
+        if let Some((part, notation_affixes)) = notation_affixes {
+            sink.with_part(part, |w| w.write_str(&notation_affixes.prefix))?;
         }
+
         let range = self.value.magnitude_range();
         let upper_magnitude = *range.end();
-        for m in range.rev() {
-            if m == -1 {
-                sink.write_str(&self.symbols.decimal_separator)?;
+        let lower_magnitude = *range.start();
+        // Write the integer digits in runs between grouping separators.
+        let mut m = upper_magnitude;
+        loop {
+            let mut end = m;
+            while end > 0
+                && !grouper::check(
+                    upper_magnitude,
+                    end,
+                    self.options.grouping_strategy,
+                    &self.symbols.grouping_sizes,
+                )
+            {
+                end -= 1;
             }
-            #[allow(clippy::indexing_slicing)] // digit_at in 0..=9
-            sink.write_char(self.symbols.digits[self.value.digit_at(m) as usize])?;
-            if grouper::check(
-                upper_magnitude,
-                m,
-                self.options.grouping_strategy,
-                &self.symbols.grouping_sizes,
-            ) {
-                sink.write_str(&self.symbols.grouping_separator)?;
+            sink.with_part(parts::INTEGER, |w| {
+                for m in (end..=m).rev() {
+                    let digit = self.value.digit_at(m);
+                    #[allow(clippy::indexing_slicing)] // digit_at in 0..=9
+                    w.write_char(self.symbols.digits[digit as usize])?;
+                }
+                Ok(())
+            })?;
+            if end <= 0 {
+                break;
             }
+            sink.with_part(parts::GROUP, |w| {
+                w.write_str(&self.symbols.grouping_separator)
+            })?;
+            m = end - 1;
         }
-        if let Some(affixes) = affixes {
-            sink.write_str(&affixes.suffix)?;
+        if lower_magnitude < 0 {
+            sink.with_part(parts::DECIMAL, |w| {
+                w.write_str(&self.symbols.decimal_separator)
+            })?;
+            sink.with_part(parts::FRACTION, |w| {
+                for m in (lower_magnitude..0).rev() {
+                    let digit = self.value.digit_at(m);
+                    #[allow(clippy::indexing_slicing)] // digit_at in 0..=9
+                    w.write_char(self.symbols.digits[digit as usize])?;
+                }
+                Ok(())
+            })?;
+        }
+
+        if let Some(exponent) = self.exponent {
+            self.write_exponent(exponent, sink)?;
+        }
+        if let Some((part, notation_affixes)) = notation_affixes {
+            sink.with_part(part, |w| w.write_str(&notation_affixes.suffix))?;
+        }
+        if let Some((part, affixes)) = affixes {
+            sink.with_part(part, |w| w.write_str(&affixes.suffix))?;
         }
         Ok(())
     }
 }
 
 writeable::impl_display_with_writeable!(FormattedFixedDecimal<'_>);
+
+#[test]
+fn test_notation() {
+    use crate::FixedDecimalFormatter;
+    use icu_locid::locale;
+    use writeable::assert_writeable_eq;
+
+    #[derive(Debug)]
+    struct TestCase {
+        pub notation: Notation,
+        pub exponent_style: ExponentStyle,
+        pub input: &'static str,
+        pub expected: &'static str,
+    }
+    let cases = [
+        TestCase {
+            notation: Notation::Percent,
+            exponent_style: ExponentStyle::Symbol,
+            input: "0",
+            expected: "0%",
+        },
+        TestCase {
+            notation: Notation::Percent,
+            exponent_style: ExponentStyle::Symbol,
+            input: "-0.05",
+            expected: "-5%",
+        },
+        TestCase {
+            notation: Notation::Percent,
+            exponent_style: ExponentStyle::Symbol,
+            input: "000.5",
+            expected: "050%",
+        },
+        TestCase {
+            notation: Notation::PerMille,
+            exponent_style: ExponentStyle::Symbol,
+            input: "1234.5",
+            expected: "1,234,500‰",
+        },
+        TestCase {
+            notation: Notation::Scientific,
+            exponent_style: ExponentStyle::Symbol,
+            input: "0",
+            expected: "0E0",
+        },
+        TestCase {
+            notation: Notation::Scientific,
+            exponent_style: ExponentStyle::Symbol,
+            input: "-1000",
+            expected: "-1E3",
+        },
+        TestCase {
+            notation: Notation::Scientific,
+            exponent_style: ExponentStyle::Symbol,
+            input: "1000.0",
+            expected: "1.0000E3",
+        },
+        TestCase {
+            notation: Notation::Scientific,
+            exponent_style: ExponentStyle::Symbol,
+            input: "0.000120",
+            expected: "1.20E-4",
+        },
+        TestCase {
+            notation: Notation::Engineering,
+            exponent_style: ExponentStyle::Symbol,
+            input: "0.000120",
+            expected: "120E-6",
+        },
+        TestCase {
+            notation: Notation::Engineering,
+            exponent_style: ExponentStyle::Superscript,
+            input: "12345678901",
+            expected: "12.345678901×10⁹",
+        },
+        TestCase {
+            notation: Notation::Scientific,
+            exponent_style: ExponentStyle::Superscript,
+            input: "0.000000000001",
+            expected: "1×10⁻¹²",
+        },
+    ];
+    for cas in &cases {
+        let options = FixedDecimalFormatterOptions {
+            exponent_style: cas.exponent_style,
+            ..Default::default()
+        };
+        let fdf = FixedDecimalFormatter::try_new_with_notation(
+            &locale!("en").into(),
+            cas.notation,
+            options,
+        )
+        .unwrap();
+        let input: FixedDecimal = cas.input.parse().unwrap();
+        assert_writeable_eq!(fdf.format(&input), cas.expected, "{:?}", cas);
+    }
+
+    // Engineering exponents at the bottom of the magnitude range do not overflow
+    let fdf = FixedDecimalFormatter::try_new_with_notation(
+        &locale!("en").into(),
+        Notation::Engineering,
+        Default::default(),
+    )
+    .unwrap();
+    for (magnitude, expected) in [
+        (-32766, "1E-32766"),
+        (-32767, "1E-32767"),
+        (-32768, "1E-32768"),
+    ] {
+        let input = FixedDecimal::from(1).multiplied_pow10(magnitude);
+        assert_writeable_eq!(fdf.format(&input), expected);
+    }
+}
+
+#[test]
+fn test_parts() {
+    use crate::FixedDecimalFormatter;
+    use icu_locid::locale;
+    use writeable::assert_writeable_parts_eq;
+
+    let fdf = FixedDecimalFormatter::try_new(&locale!("en").into(), Default::default()).unwrap();
+    let input: FixedDecimal = "-1234567.89".parse().unwrap();
+    assert_writeable_parts_eq!(
+        fdf.format(&input),
+        "-1,234,567.89",
+        [
+            (0, 1, parts::MINUS_SIGN),
+            (1, 2, parts::INTEGER),
+            (2, 3, parts::GROUP),
+            (3, 6, parts::INTEGER),
+            (6, 7, parts::GROUP),
+            (7, 10, parts::INTEGER),
+            (10, 11, parts::DECIMAL),
+            (11, 13, parts::FRACTION),
+        ]
+    );
+
+    let fdf = FixedDecimalFormatter::try_new_with_notation(
+        &locale!("en").into(),
+        Notation::Scientific,
+        Default::default(),
+    )
+    .unwrap();
+    let input: FixedDecimal = "0.000120".parse().unwrap();
+    assert_writeable_parts_eq!(
+        fdf.format(&input),
+        "1.20E-4",
+        [
+            (0, 1, parts::INTEGER),
+            (1, 2, parts::DECIMAL),
+            (2, 4, parts::FRACTION),
+            (4, 5, parts::EXPONENT_SEPARATOR),
+            (5, 6, parts::EXPONENT_MINUS_SIGN),
+            (6, 7, parts::EXPONENT_INTEGER),
+        ]
+    );
+}
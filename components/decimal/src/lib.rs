@@ -74,6 +74,52 @@
 //! assert_writeable_eq!(fdf.format(&fixed_decimal), "๑,๐๐๐,๐๐๗");
 //! ```
 //!
+//! ## Format a number as a percentage
+//!
+//! A [`Notation`](options::Notation) scales the number and adds the locale's percent or
+//! per-mille sign, or renders it in scientific or engineering notation.
+//!
+//! ```
+//! use fixed_decimal::FixedDecimal;
+//! use icu::decimal::options;
+//! use icu::decimal::FixedDecimalFormatter;
+//! use icu::locid::locale;
+//! use writeable::assert_writeable_eq;
+//!
+//! let fdf = FixedDecimalFormatter::try_new_with_notation(
+//!     &locale!("tr").into(),
+//!     options::Notation::Percent,
+//!     Default::default(),
+//! )
+//! .expect("locale should be present");
+//!
+//! let fixed_decimal = FixedDecimal::from(125).multiplied_pow10(-3);
+//!
+//! assert_writeable_eq!(fdf.format(&fixed_decimal), "%13");
+//! ```
+//!
+//! ## Round a number to a number of significant digits
+//!
+//! The precision options follow ECMA-402: they round the number to a maximum, and pad it to a
+//! minimum, number of fraction digits or significant digits.
+//!
+//! ```
+//! use fixed_decimal::FixedDecimal;
+//! use icu::decimal::options;
+//! use icu::decimal::FixedDecimalFormatter;
+//! use icu::locid::locale;
+//! use writeable::assert_writeable_eq;
+//!
+//! let mut options: options::FixedDecimalFormatterOptions = Default::default();
+//! options.maximum_significant_digits = Some(3);
+//! let fdf = FixedDecimalFormatter::try_new(&locale!("en").into(), options)
+//!     .expect("locale should be present");
+//!
+//! let fixed_decimal = FixedDecimal::from(1234567);
+//!
+//! assert_writeable_eq!(fdf.format(&fixed_decimal), "1,230,000");
+//! ```
+//!
 //! [`FixedDecimalFormatter`]: FixedDecimalFormatter
 
 // https://github.com/unicode-org/icu4x/blob/main/docs/process/boilerplate.md#library-annotations
@@ -98,10 +144,14 @@ mod error;
 mod format;
 mod grouper;
 pub mod options;
+mod parse;
+mod precision;
 pub mod provider;
 
 pub use error::DecimalError;
+pub use format::parts;
 pub use format::FormattedFixedDecimal;
+pub use parse::FixedDecimalParser;
 
 #[doc(no_inline)]
 pub use DecimalError as Error;
@@ -118,6 +168,7 @@ use writeable::Writeable;
 /// 1. Rendering in the local numbering system
 /// 2. Locale-sensitive grouping separator positions
 /// 3. Locale-sensitive plus and minus signs
+/// 4. Percent, per-mille, scientific, and engineering notation
 ///
 /// Read more about the options in the [`options`] module.
 ///
@@ -125,7 +176,9 @@ use writeable::Writeable;
 #[derive(Debug)]
 pub struct FixedDecimalFormatter {
     options: options::FixedDecimalFormatterOptions,
+    notation: options::Notation,
     symbols: DataPayload<provider::DecimalSymbolsV1Marker>,
+    notation_symbols: Option<DataPayload<provider::DecimalNotationSymbolsV1Marker>>,
 }
 
 impl FixedDecimalFormatter {
@@ -146,22 +199,100 @@ impl FixedDecimalFormatter {
         locale: &DataLocale,
         options: options::FixedDecimalFormatterOptions,
     ) -> Result<Self, DecimalError> {
+        options.validate()?;
         let symbols = provider
             .load(DataRequest {
                 locale,
                 metadata: Default::default(),
             })?
             .take_payload()?;
-        Ok(Self { options, symbols })
+        Ok(Self {
+            options,
+            notation: options::Notation::Standard,
+            symbols,
+            notation_symbols: None,
+        })
+    }
+
+    icu_provider::gen_any_buffer_data_constructors!(
+        locale: include,
+        notation: options::Notation,
+        options: options::FixedDecimalFormatterOptions,
+        error: DecimalError,
+        /// Creates a new [`FixedDecimalFormatter`] that renders numbers in the given
+        /// [`Notation`](options::Notation), from compiled locale data and an options bag.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use fixed_decimal::FixedDecimal;
+        /// use icu::decimal::options;
+        /// use icu::decimal::FixedDecimalFormatter;
+        /// use icu::locid::locale;
+        /// use writeable::assert_writeable_eq;
+        ///
+        /// let fdf = FixedDecimalFormatter::try_new_with_notation(
+        ///     &locale!("en").into(),
+        ///     options::Notation::PerMille,
+        ///     Default::default(),
+        /// )
+        /// .expect("locale should be present");
+        ///
+        /// let fixed_decimal = FixedDecimal::from(25).multiplied_pow10(-3);
+        ///
+        /// assert_writeable_eq!(fdf.format(&fixed_decimal), "25‰");
+        /// ```
+        functions: [
+            try_new_with_notation,
+            try_new_with_notation_with_any_provider,
+            try_new_with_notation_with_buffer_provider,
+            try_new_with_notation_unstable,
+            Self,
+        ]
+    );
+
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::try_new_with_notation)]
+    pub fn try_new_with_notation_unstable<D>(
+        provider: &D,
+        locale: &DataLocale,
+        notation: options::Notation,
+        options: options::FixedDecimalFormatterOptions,
+    ) -> Result<Self, DecimalError>
+    where
+        D: DataProvider<provider::DecimalSymbolsV1Marker>
+            + DataProvider<provider::DecimalNotationSymbolsV1Marker>
+            + ?Sized,
+    {
+        let mut formatter = Self::try_new_unstable(provider, locale, options)?;
+        if notation == options::Notation::Percent {
+            formatter.options.set_percent_defaults();
+        }
+        // Notation symbols are only needed (and only loaded) for non-standard notations.
+        if notation != options::Notation::Standard {
+            formatter.notation = notation;
+            formatter.notation_symbols = Some(
+                DataProvider::<provider::DecimalNotationSymbolsV1Marker>::load(
+                    provider,
+                    DataRequest {
+                        locale,
+                        metadata: Default::default(),
+                    },
+                )?
+                .take_payload()?,
+            );
+        }
+        Ok(formatter)
     }
 
     /// Formats a [`FixedDecimal`], returning a [`FormattedFixedDecimal`].
     pub fn format<'l>(&'l self, value: &'l FixedDecimal) -> FormattedFixedDecimal<'l> {
-        FormattedFixedDecimal {
+        FormattedFixedDecimal::new(
             value,
-            options: &self.options,
-            symbols: self.symbols.get(),
-        }
+            &self.options,
+            self.notation,
+            self.symbols.get(),
+            self.notation_symbols.as_ref().map(DataPayload::get),
+        )
     }
 
     /// Formats a [`FixedDecimal`], returning a [`String`].
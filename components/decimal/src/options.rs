@@ -4,18 +4,179 @@
 
 //! Options for [`FixedDecimalFormatter`](crate::FixedDecimalFormatter).
 
+pub use fixed_decimal::RoundingIncrement;
+pub use fixed_decimal::RoundingMode;
+
+use crate::DecimalError;
+
 /// A bag of options defining how numbers will be formatted by
 /// [`FixedDecimalFormatter`](crate::FixedDecimalFormatter).
+///
+/// The precision options follow the `Intl.NumberFormat` options of ECMA-402. They are applied
+/// to the number as it is displayed, that is, after scaling it for [`Notation::Percent`] or
+/// [`Notation::PerMille`], and to the digits before the exponent in [`Notation::Scientific`]
+/// and [`Notation::Engineering`]. If any of the digit options are set, trailing zeros of the
+/// input are rendered only as far as the minimum digits require.
+///
+/// The constructors of [`FixedDecimalFormatter`](crate::FixedDecimalFormatter) return
+/// [`DecimalError::InvalidDigitOptions`] if a minimum number of digits is greater than the
+/// corresponding maximum, or if a number of significant digits is zero.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+/// use icu_decimal::options;
+/// use icu_decimal::FixedDecimalFormatter;
+/// use icu_locid::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let mut options: options::FixedDecimalFormatterOptions = Default::default();
+/// options.minimum_fraction_digits = Some(2);
+/// options.maximum_fraction_digits = Some(2);
+/// options.rounding_increment = options::RoundingIncrement::MultiplesOf5;
+/// let fdf = FixedDecimalFormatter::try_new(&locale!("en").into(), options)
+///     .expect("locale should be present");
+///
+/// let value = FixedDecimal::from(1234567).multiplied_pow10(-3);
+/// assert_writeable_eq!(fdf.format(&value), "1,234.55");
+///
+/// let value = FixedDecimal::from(7);
+/// assert_writeable_eq!(fdf.format(&value), "7.00");
+/// ```
 #[derive(Debug, Eq, PartialEq, Clone, Default)]
 #[non_exhaustive]
 pub struct FixedDecimalFormatterOptions {
     /// When to render grouping separators.
     pub grouping_strategy: GroupingStrategy,
+    /// How to render the exponent in scientific and engineering notation.
+    pub exponent_style: ExponentStyle,
+    /// The minimum number of fraction digits to render, padding with zeros if necessary.
+    pub minimum_fraction_digits: Option<u8>,
+    /// The maximum number of fraction digits to render, rounding if necessary.
+    pub maximum_fraction_digits: Option<u8>,
+    /// The minimum number of significant digits to render, padding with zeros if necessary.
+    pub minimum_significant_digits: Option<u8>,
+    /// The maximum number of significant digits to render, rounding if necessary.
+    pub maximum_significant_digits: Option<u8>,
+    /// Whether fraction digits or significant digits take precedence when both are set.
+    pub rounding_priority: RoundingPriority,
+    /// The increment to which the last fraction digit is rounded when rounding to
+    /// [`maximum_fraction_digits`](Self::maximum_fraction_digits).
+    pub rounding_increment: RoundingIncrement,
+    /// How to round numbers that have more digits than allowed.
+    pub rounding_mode: RoundingMode,
+    /// Whether to render fraction digits of integers.
+    pub trailing_zero_display: TrailingZeroDisplay,
 }
 
 impl From<GroupingStrategy> for FixedDecimalFormatterOptions {
     fn from(grouping_strategy: GroupingStrategy) -> Self {
-        Self { grouping_strategy }
+        Self {
+            grouping_strategy,
+            ..Default::default()
+        }
+    }
+}
+
+impl FixedDecimalFormatterOptions {
+    /// Returns whether any of the digit options are set.
+    pub(crate) fn has_digit_options(&self) -> bool {
+        self.minimum_fraction_digits.is_some()
+            || self.maximum_fraction_digits.is_some()
+            || self.minimum_significant_digits.is_some()
+            || self.maximum_significant_digits.is_some()
+    }
+
+    /// Returns an error if a minimum number of digits is greater than its maximum, or if a
+    /// number of significant digits is zero.
+    pub(crate) fn validate(&self) -> Result<(), DecimalError> {
+        let is_valid = |min: Option<u8>, max: Option<u8>| match (min, max) {
+            (Some(min), Some(max)) => min <= max,
+            _ => true,
+        };
+        if !is_valid(self.minimum_fraction_digits, self.maximum_fraction_digits)
+            || !is_valid(
+                self.minimum_significant_digits,
+                self.maximum_significant_digits,
+            )
+            || self.minimum_significant_digits == Some(0)
+            || self.maximum_significant_digits == Some(0)
+        {
+            return Err(DecimalError::InvalidDigitOptions);
+        }
+        Ok(())
+    }
+
+    /// Defaults the maximum fraction digits for [`Notation::Percent`] to the minimum fraction
+    /// digits, or 0, unless precision is set otherwise, as in CLDR and ECMA-402.
+    pub(crate) fn set_percent_defaults(&mut self) {
+        if self.maximum_fraction_digits.is_none()
+            && self.minimum_significant_digits.is_none()
+            && self.maximum_significant_digits.is_none()
+        {
+            self.maximum_fraction_digits = Some(self.minimum_fraction_digits.unwrap_or(0));
+        }
+    }
+}
+
+/// A bag of options defining how numbers will be parsed by
+/// [`FixedDecimalParser`](crate::FixedDecimalParser).
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+#[non_exhaustive]
+pub struct FixedDecimalParserOptions {
+    /// Which inputs to accept.
+    pub strictness: ParseStrictness,
+}
+
+impl From<ParseStrictness> for FixedDecimalParserOptions {
+    fn from(strictness: ParseStrictness) -> Self {
+        Self { strictness }
+    }
+}
+
+/// Configuration for which inputs [`FixedDecimalParser`](crate::FixedDecimalParser) accepts.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+/// use icu_decimal::options;
+/// use icu_decimal::DecimalError;
+/// use icu_decimal::FixedDecimalParser;
+/// use icu_locid::locale;
+///
+/// let strict = FixedDecimalParser::try_new(
+///     &locale!("en").into(),
+///     options::ParseStrictness::Strict.into(),
+/// )
+/// .expect("locale should be present");
+/// let lenient = FixedDecimalParser::try_new(
+///     &locale!("en").into(),
+///     options::ParseStrictness::Lenient.into(),
+/// )
+/// .expect("locale should be present");
+///
+/// assert_eq!(strict.parse("12,34,567"), Err(DecimalError::Parse(2)));
+/// assert_eq!(lenient.parse(" 12,34,567 "), Ok(FixedDecimal::from(1234567)));
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum ParseStrictness {
+    /// Accept only strings that [`FixedDecimalFormatter`](crate::FixedDecimalFormatter)
+    /// could produce with [`GroupingStrategy::Auto`] or [`GroupingStrategy::Never`].
+    Strict,
+
+    /// Also accept common variations in user-typed numbers: surrounding whitespace, ASCII
+    /// digits and signs, missing bidi marks, any space as a space-like grouping separator,
+    /// grouping separators in any position, and a missing integer or fraction part around
+    /// the decimal separator.
+    Lenient,
+}
+
+impl Default for ParseStrictness {
+    fn default() -> Self {
+        Self::Lenient
     }
 }
 
@@ -71,3 +232,200 @@ impl Default for GroupingStrategy {
         Self::Auto
     }
 }
+
+/// Configuration for how to scale a number and whether to render it with an exponent.
+///
+/// Pass it to [`FixedDecimalFormatter::try_new_with_notation`](crate::FixedDecimalFormatter::try_new_with_notation).
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+/// use icu_decimal::options;
+/// use icu_decimal::FixedDecimalFormatter;
+/// use icu_locid::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let format = |notation: options::Notation, value: &FixedDecimal| {
+///     FixedDecimalFormatter::try_new_with_notation(
+///         &locale!("en").into(),
+///         notation,
+///         Default::default(),
+///     )
+///     .expect("locale should be present")
+///         .format_to_string(value)
+/// };
+///
+/// let value = FixedDecimal::from(123456).multiplied_pow10(-4);
+/// assert_eq!(format(options::Notation::Standard, &value), "12.3456");
+/// assert_eq!(format(options::Notation::Percent, &value), "1,235%");
+/// assert_eq!(format(options::Notation::PerMille, &value), "12,345.6‰");
+/// assert_eq!(format(options::Notation::Scientific, &value), "1.23456E1");
+/// assert_eq!(format(options::Notation::Engineering, &value), "12.3456E0");
+///
+/// let value = FixedDecimal::from(123456);
+/// assert_eq!(format(options::Notation::Scientific, &value), "1.23456E5");
+/// assert_eq!(format(options::Notation::Engineering, &value), "123.456E3");
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum Notation {
+    /// Render the number as it is.
+    Standard,
+
+    /// Multiply the number by 100 and render it with the locale's percent sign and pattern.
+    ///
+    /// Unless the significant digits or the maximum fraction digits are set, the number is
+    /// rounded to the minimum fraction digits, or to an integer.
+    Percent,
+
+    /// Multiply the number by 1000 and render it with the locale's per-mille sign.
+    PerMille,
+
+    /// Render the number with exactly one nonzero integer digit, followed by an exponent.
+    ///
+    /// Trailing zeros in the integer part of the number are not significant and are dropped;
+    /// trailing zeros in the fraction part are kept.
+    Scientific,
+
+    /// Like [`Notation::Scientific`], but with an exponent that is a multiple of 3, so that
+    /// the number has one to three integer digits.
+    Engineering,
+}
+
+impl Default for Notation {
+    fn default() -> Self {
+        Self::Standard
+    }
+}
+
+/// Configuration for how to render the exponent in [`Notation::Scientific`] and
+/// [`Notation::Engineering`].
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+/// use icu_decimal::options;
+/// use icu_decimal::FixedDecimalFormatter;
+/// use icu_locid::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let mut options: options::FixedDecimalFormatterOptions = Default::default();
+/// options.exponent_style = options::ExponentStyle::Superscript;
+/// let fdf = FixedDecimalFormatter::try_new_with_notation(
+///     &locale!("en").into(),
+///     options::Notation::Scientific,
+///     options,
+/// )
+/// .expect("locale should be present");
+///
+/// let value = FixedDecimal::from(17).multiplied_pow10(-5);
+/// assert_writeable_eq!(fdf.format(&value), "1.7×10⁻⁴");
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum ExponentStyle {
+    /// Render the exponent after the locale's exponential symbol, such as `1.2E3`.
+    Symbol,
+
+    /// Render the exponent as a superscripted power of ten, using the locale's
+    /// superscripting exponent symbol, such as `1.2×10³`.
+    Superscript,
+}
+
+impl Default for ExponentStyle {
+    fn default() -> Self {
+        Self::Symbol
+    }
+}
+
+/// Configuration for whether fraction digits or significant digits determine the precision
+/// when both are set.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+/// use icu_decimal::options;
+/// use icu_decimal::FixedDecimalFormatter;
+/// use icu_locid::locale;
+///
+/// let format = |rounding_priority: options::RoundingPriority, value: &FixedDecimal| {
+///     let mut options: options::FixedDecimalFormatterOptions = Default::default();
+///     options.maximum_fraction_digits = Some(1);
+///     options.maximum_significant_digits = Some(2);
+///     options.rounding_priority = rounding_priority;
+///     FixedDecimalFormatter::try_new(&locale!("en").into(), options)
+///         .expect("locale should be present")
+///         .format_to_string(value)
+/// };
+///
+/// let value = FixedDecimal::from(4321).multiplied_pow10(-3);
+/// assert_eq!(format(options::RoundingPriority::Auto, &value), "4.3");
+/// assert_eq!(format(options::RoundingPriority::MorePrecision, &value), "4.3");
+/// assert_eq!(format(options::RoundingPriority::LessPrecision, &value), "4.3");
+///
+/// let value = FixedDecimal::from(4321).multiplied_pow10(-5);
+/// assert_eq!(format(options::RoundingPriority::Auto, &value), "0.043");
+/// assert_eq!(format(options::RoundingPriority::MorePrecision, &value), "0.043");
+/// assert_eq!(format(options::RoundingPriority::LessPrecision, &value), "0");
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum RoundingPriority {
+    /// Use the significant digit options if either of them is set, and the fraction digit
+    /// options otherwise.
+    Auto,
+
+    /// Use whichever of the fraction digit and significant digit options keeps more digits.
+    MorePrecision,
+
+    /// Use whichever of the fraction digit and significant digit options keeps fewer digits.
+    LessPrecision,
+}
+
+impl Default for RoundingPriority {
+    fn default() -> Self {
+        Self::Auto
+    }
+}
+
+/// Configuration for whether to render the fraction digits of integers.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+/// use icu_decimal::options;
+/// use icu_decimal::FixedDecimalFormatter;
+/// use icu_locid::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let mut options: options::FixedDecimalFormatterOptions = Default::default();
+/// options.minimum_fraction_digits = Some(2);
+/// options.trailing_zero_display = options::TrailingZeroDisplay::StripIfInteger;
+/// let fdf = FixedDecimalFormatter::try_new(&locale!("en").into(), options)
+///     .expect("locale should be present");
+///
+/// let five = FixedDecimal::from(5);
+/// assert_writeable_eq!(fdf.format(&five), "5");
+///
+/// let five_and_a_half = FixedDecimal::from(55).multiplied_pow10(-1);
+/// assert_writeable_eq!(fdf.format(&five_and_a_half), "5.50");
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum TrailingZeroDisplay {
+    /// Render trailing zeros as required by the other options.
+    Auto,
+
+    /// Do not render any fraction digits if they are all zero.
+    StripIfInteger,
+}
+
+impl Default for TrailingZeroDisplay {
+    fn default() -> Self {
+        Self::Auto
+    }
+}
@@ -0,0 +1,512 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Parsing of localized decimal numbers.
+
+use crate::grouper;
+use crate::options::*;
+use crate::provider::*;
+use crate::DecimalError;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::str::FromStr;
+use fixed_decimal::FixedDecimal;
+use fixed_decimal::Sign;
+use icu_provider::prelude::*;
+
+/// A parser for localized decimal numbers, the inverse of
+/// [`FixedDecimalFormatter`](crate::FixedDecimalFormatter).
+///
+/// [`FixedDecimalParser`] understands the locale's decimal and grouping separators, plus and
+/// minus signs, and the digits of the numbering system selected by the `-u-nu` subtag.
+///
+/// In [`ParseStrictness::Strict`] mode, only strings that
+/// [`FixedDecimalFormatter`](crate::FixedDecimalFormatter) could produce with
+/// [`GroupingStrategy::Auto`] or [`GroupingStrategy::Never`] are accepted.
+/// [`ParseStrictness::Lenient`] mode additionally accepts user-typed variations, such as
+/// ASCII digits and signs, surrounding whitespace, missing bidi marks, and grouping
+/// separators in unexpected places.
+///
+/// On failure, [`DecimalError::Parse`] contains the byte offset in the input at which
+/// parsing failed.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+/// use icu_decimal::DecimalError;
+/// use icu_decimal::FixedDecimalParser;
+/// use icu_locid::locale;
+///
+/// let parser =
+///     FixedDecimalParser::try_new(&locale!("es").into(), Default::default())
+///         .expect("locale should be present");
+///
+/// assert_eq!(
+///     parser.parse("-1.234,50"),
+///     Ok(FixedDecimal::from(-123450).multiplied_pow10(-2))
+/// );
+/// assert_eq!(parser.parse("1.234,5x"), Err(DecimalError::Parse(7)));
+///
+/// let parser =
+///     FixedDecimalParser::try_new(&locale!("ar-EG").into(), Default::default())
+///         .expect("locale should be present");
+///
+/// assert_eq!(
+///     parser.parse("١٢٣٫٤"),
+///     Ok(FixedDecimal::from(1234).multiplied_pow10(-1))
+/// );
+/// ```
+#[derive(Debug)]
+pub struct FixedDecimalParser {
+    options: FixedDecimalParserOptions,
+    symbols: DataPayload<DecimalSymbolsV1Marker>,
+}
+
+impl FixedDecimalParser {
+    icu_provider::gen_any_buffer_data_constructors!(
+        locale: include,
+        options: FixedDecimalParserOptions,
+        error: DecimalError,
+        /// Creates a new [`FixedDecimalParser`] from compiled locale data and an options bag.
+        ///
+        /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+        ///
+        /// [📚 Help choosing a constructor](icu_provider::constructors)
+    );
+
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<D: DataProvider<DecimalSymbolsV1Marker> + ?Sized>(
+        provider: &D,
+        locale: &DataLocale,
+        options: FixedDecimalParserOptions,
+    ) -> Result<Self, DecimalError> {
+        let symbols = provider
+            .load(DataRequest {
+                locale,
+                metadata: Default::default(),
+            })?
+            .take_payload()?;
+        Ok(Self { options, symbols })
+    }
+
+    /// Parses a localized number into a [`FixedDecimal`].
+    ///
+    /// Leading and trailing zeros are retained in the result, so that `"1,50"` parses to a
+    /// [`FixedDecimal`] that formats as `"1,50"` again.
+    pub fn parse(&self, input: &str) -> Result<FixedDecimal, DecimalError> {
+        let symbols = self.symbols.get();
+        let lenient = self.options.strictness == ParseStrictness::Lenient;
+
+        let (mut start, mut end) = (0, input.len());
+        if lenient {
+            let trimmed = input.trim_start();
+            start = input.len() - trimmed.len();
+            end = start + trimmed.trim_end().len();
+        }
+
+        // Find the sign affixes that surround the number.
+        let mut sign = Sign::None;
+        let mut candidates: Vec<(Sign, &str, &str)> = Vec::with_capacity(5);
+        candidates.push((
+            Sign::Negative,
+            &*symbols.minus_sign_affixes.prefix,
+            &*symbols.minus_sign_affixes.suffix,
+        ));
+        candidates.push((
+            Sign::Positive,
+            &*symbols.plus_sign_affixes.prefix,
+            &*symbols.plus_sign_affixes.suffix,
+        ));
+        if lenient {
+            candidates.push((Sign::Negative, "-", ""));
+            candidates.push((Sign::Negative, "\u{2212}", ""));
+            candidates.push((Sign::Positive, "+", ""));
+        }
+        for (candidate_sign, prefix, suffix) in candidates {
+            if prefix.is_empty() && suffix.is_empty() {
+                continue;
+            }
+            let body_start = strip_prefix(input, start, end, prefix, lenient);
+            let body_end = body_start.and_then(|s| strip_suffix(input, s, end, suffix, lenient));
+            if let (Some(body_start), Some(body_end)) = (body_start, body_end) {
+                sign = candidate_sign;
+                start = body_start;
+                end = body_end;
+                break;
+            }
+        }
+
+        let mut ascii = String::with_capacity(end - start + 2);
+        // Byte offsets of grouping separators, and the number of integer digits before each.
+        let mut separators: Vec<(usize, usize)> = Vec::new();
+        let mut integer_digits = 0;
+        let mut fraction_digits = 0;
+        let mut decimal_position = None;
+        let mut pending_separator = None;
+        let mut i = start;
+        while i < end {
+            let rest = input.get(i..end).unwrap_or_default();
+            let ch = match rest.chars().next() {
+                Some(ch) => ch,
+                None => break,
+            };
+            let digit = symbols.digits.iter().position(|&d| d == ch).or_else(|| {
+                ch.to_digit(10)
+                    .filter(|_| lenient && ch.is_ascii())
+                    .map(|d| d as usize)
+            });
+            if let Some(digit) = digit {
+                ascii.push(char::from(b'0' + digit as u8));
+                if decimal_position.is_some() {
+                    fraction_digits += 1;
+                } else {
+                    integer_digits += 1;
+                }
+                pending_separator = None;
+                i += ch.len_utf8();
+            } else if decimal_position.is_none()
+                && pending_separator.is_none()
+                && rest.starts_with(&*symbols.decimal_separator)
+            {
+                decimal_position = Some(i);
+                ascii.push('.');
+                i += symbols.decimal_separator.len();
+            } else if let Some(len) =
+                match_grouping_separator(rest, &symbols.grouping_separator, lenient).filter(|_| {
+                    decimal_position.is_none() && pending_separator.is_none() && integer_digits > 0
+                })
+            {
+                separators.push((i, integer_digits));
+                pending_separator = Some(i);
+                i += len;
+            } else if lenient && is_bidi_control(ch) {
+                i += ch.len_utf8();
+            } else {
+                return Err(DecimalError::Parse(i));
+            }
+        }
+
+        // A grouping separator must be followed by a digit.
+        if let Some(position) = pending_separator {
+            return Err(DecimalError::Parse(position));
+        }
+        if integer_digits + fraction_digits == 0 {
+            return Err(DecimalError::Parse(start));
+        }
+        if let Some(position) = decimal_position {
+            if integer_digits == 0 {
+                if !lenient {
+                    return Err(DecimalError::Parse(position));
+                }
+                ascii.insert(0, '0');
+            }
+            if fraction_digits == 0 {
+                if !lenient {
+                    return Err(DecimalError::Parse(position));
+                }
+                ascii.pop();
+            }
+        }
+
+        if !lenient && !separators.is_empty() {
+            check_grouping(&separators, integer_digits, &symbols.grouping_sizes)?;
+        }
+
+        let mut value = FixedDecimal::from_str(&ascii).map_err(|_| DecimalError::Parse(start))?;
+        value.set_sign(sign);
+        Ok(value)
+    }
+}
+
+/// Returns the position after `prefix` if the input starts with it at `start`.
+fn strip_prefix(
+    input: &str,
+    start: usize,
+    end: usize,
+    prefix: &str,
+    lenient: bool,
+) -> Option<usize> {
+    let mut i = start;
+    for expected in prefix.chars() {
+        if lenient && is_bidi_control(expected) {
+            continue;
+        }
+        loop {
+            let ch = input.get(i..end)?.chars().next()?;
+            i += ch.len_utf8();
+            if ch == expected {
+                break;
+            }
+            if !(lenient && is_bidi_control(ch)) {
+                return None;
+            }
+        }
+    }
+    Some(i)
+}
+
+/// Returns the position before `suffix` if the input ends with it at `end`.
+fn strip_suffix(
+    input: &str,
+    start: usize,
+    end: usize,
+    suffix: &str,
+    lenient: bool,
+) -> Option<usize> {
+    let mut i = end;
+    for expected in suffix.chars().rev() {
+        if lenient && is_bidi_control(expected) {
+            continue;
+        }
+        loop {
+            let ch = input.get(start..i)?.chars().next_back()?;
+            i -= ch.len_utf8();
+            if ch == expected {
+                break;
+            }
+            if !(lenient && is_bidi_control(ch)) {
+                return None;
+            }
+        }
+    }
+    Some(i)
+}
+
+/// Returns the length of the grouping separator at the start of `rest`, if any.
+///
+/// In lenient mode, any space character is accepted if the locale's separator is a space.
+fn match_grouping_separator(rest: &str, separator: &str, lenient: bool) -> Option<usize> {
+    if !separator.is_empty() && rest.starts_with(separator) {
+        return Some(separator.len());
+    }
+    if lenient && separator.chars().all(is_space) {
+        return rest
+            .chars()
+            .next()
+            .filter(|&c| is_space(c))
+            .map(char::len_utf8);
+    }
+    None
+}
+
+/// Checks that grouping separators appear exactly where
+/// [`FixedDecimalFormatter`](crate::FixedDecimalFormatter) would render them.
+fn check_grouping(
+    separators: &[(usize, usize)],
+    integer_digits: usize,
+    sizes: &GroupingSizesV1,
+) -> Result<(), DecimalError> {
+    let upper_magnitude = integer_digits as i16 - 1;
+    let mut separators = separators.iter().peekable();
+    // The first position at which a separator is missing, if any.
+    let mut missing = None;
+    for digits_before in 1..integer_digits {
+        let magnitude = (integer_digits - digits_before) as i16;
+        let expected = grouper::check(upper_magnitude, magnitude, GroupingStrategy::Auto, sizes);
+        match separators.peek() {
+            Some(&&(position, n)) if n == digits_before => {
+                if !expected {
+                    return Err(DecimalError::Parse(position));
+                }
+                separators.next();
+            }
+            Some(&&(position, _)) if expected => {
+                missing.get_or_insert(position);
+            }
+            _ => {}
+        }
+    }
+    match missing {
+        Some(position) => Err(DecimalError::Parse(position)),
+        None => Ok(()),
+    }
+}
+
+fn is_bidi_control(c: char) -> bool {
+    matches!(c, '\u{061C}' | '\u{200E}' | '\u{200F}')
+}
+
+fn is_space(c: char) -> bool {
+    matches!(c, ' ' | '\u{00A0}' | '\u{202F}')
+}
+
+#[test]
+fn test_parse() {
+    use icu_locid::locale;
+
+    #[derive(Debug)]
+    struct TestCase {
+        pub locale: DataLocale,
+        pub strictness: ParseStrictness,
+        pub input: &'static str,
+        pub expected: Result<&'static str, usize>,
+    }
+    let cases = [
+        TestCase {
+            locale: locale!("en").into(),
+            strictness: ParseStrictness::Strict,
+            input: "1,234,567.890",
+            expected: Ok("1234567.890"),
+        },
+        TestCase {
+            locale: locale!("en").into(),
+            strictness: ParseStrictness::Strict,
+            input: "1234567",
+            expected: Ok("1234567"),
+        },
+        TestCase {
+            locale: locale!("en").into(),
+            strictness: ParseStrictness::Strict,
+            input: "12,34,567",
+            expected: Err(2),
+        },
+        TestCase {
+            locale: locale!("en").into(),
+            strictness: ParseStrictness::Strict,
+            input: "1234,567",
+            expected: Err(4),
+        },
+        TestCase {
+            locale: locale!("en").into(),
+            strictness: ParseStrictness::Lenient,
+            input: "12,34,567",
+            expected: Ok("1234567"),
+        },
+        TestCase {
+            locale: locale!("en").into(),
+            strictness: ParseStrictness::Strict,
+            input: " 1",
+            expected: Err(0),
+        },
+        TestCase {
+            locale: locale!("en").into(),
+            strictness: ParseStrictness::Lenient,
+            input: " \u{2212}1 ",
+            expected: Ok("-1"),
+        },
+        TestCase {
+            locale: locale!("en").into(),
+            strictness: ParseStrictness::Strict,
+            input: ".5",
+            expected: Err(0),
+        },
+        TestCase {
+            locale: locale!("en").into(),
+            strictness: ParseStrictness::Lenient,
+            input: ".5",
+            expected: Ok("0.5"),
+        },
+        TestCase {
+            locale: locale!("en").into(),
+            strictness: ParseStrictness::Lenient,
+            input: "5.",
+            expected: Ok("5"),
+        },
+        TestCase {
+            locale: locale!("en").into(),
+            strictness: ParseStrictness::Lenient,
+            input: "1,,000",
+            expected: Err(2),
+        },
+        TestCase {
+            locale: locale!("en").into(),
+            strictness: ParseStrictness::Lenient,
+            input: "1,000,",
+            expected: Err(5),
+        },
+        TestCase {
+            locale: locale!("en").into(),
+            strictness: ParseStrictness::Lenient,
+            input: "",
+            expected: Err(0),
+        },
+        TestCase {
+            locale: locale!("en").into(),
+            strictness: ParseStrictness::Lenient,
+            input: "-",
+            expected: Err(1),
+        },
+        TestCase {
+            locale: locale!("en").into(),
+            strictness: ParseStrictness::Strict,
+            input: "+007",
+            expected: Ok("+007"),
+        },
+        TestCase {
+            locale: locale!("en").into(),
+            strictness: ParseStrictness::Strict,
+            input: "1.2.3",
+            expected: Err(3),
+        },
+        TestCase {
+            locale: locale!("es").into(),
+            strictness: ParseStrictness::Strict,
+            input: "1234,5",
+            expected: Ok("1234.5"),
+        },
+        TestCase {
+            locale: locale!("es").into(),
+            strictness: ParseStrictness::Strict,
+            input: "1.234,5",
+            expected: Err(1),
+        },
+        TestCase {
+            locale: locale!("bn").into(),
+            strictness: ParseStrictness::Strict,
+            input: "১০,০০,০০৭",
+            expected: Ok("1000007"),
+        },
+        TestCase {
+            locale: locale!("bn").into(),
+            strictness: ParseStrictness::Strict,
+            input: "1000007",
+            expected: Err(0),
+        },
+        TestCase {
+            locale: locale!("bn").into(),
+            strictness: ParseStrictness::Lenient,
+            input: "1000007",
+            expected: Ok("1000007"),
+        },
+        TestCase {
+            locale: locale!("ar-EG").into(),
+            strictness: ParseStrictness::Strict,
+            input: "\u{061C}-١٢٣٫٤",
+            expected: Ok("-123.4"),
+        },
+        TestCase {
+            locale: locale!("ar-EG").into(),
+            strictness: ParseStrictness::Strict,
+            input: "-١٢٣٫٤",
+            expected: Err(0),
+        },
+        TestCase {
+            locale: locale!("ar-EG").into(),
+            strictness: ParseStrictness::Lenient,
+            input: "-١٢٣٫٤",
+            expected: Ok("-123.4"),
+        },
+        TestCase {
+            locale: locale!("fr").into(),
+            strictness: ParseStrictness::Lenient,
+            input: "1 234 567,8",
+            expected: Ok("1234567.8"),
+        },
+    ];
+    for cas in &cases {
+        let parser = FixedDecimalParser::try_new(
+            &cas.locale,
+            FixedDecimalParserOptions {
+                strictness: cas.strictness,
+            },
+        )
+        .unwrap();
+        let expected = cas
+            .expected
+            .map(|s| FixedDecimal::from_str(s).unwrap())
+            .map_err(DecimalError::Parse);
+        assert_eq!(parser.parse(cas.input), expected, "{cas:?}");
+    }
+}
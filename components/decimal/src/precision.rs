@@ -0,0 +1,327 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Algorithms to round and pad numbers to the configured precision.
+
+use crate::options::*;
+use fixed_decimal::FixedDecimal;
+
+/// Returns whether [`apply`] may change the given number.
+pub fn is_needed(value: &FixedDecimal, options: &FixedDecimalFormatterOptions) -> bool {
+    options.has_digit_options()
+        || (options.trailing_zero_display == TrailingZeroDisplay::StripIfInteger
+            && *value.magnitude_range().start() < 0)
+}
+
+/// Rounds and pads the number according to the digit options, then applies the trailing
+/// zero display option.
+pub fn apply(value: &mut FixedDecimal, options: &FixedDecimalFormatterOptions) {
+    let has_fraction_options =
+        options.minimum_fraction_digits.is_some() || options.maximum_fraction_digits.is_some();
+    let has_significant_options = options.minimum_significant_digits.is_some()
+        || options.maximum_significant_digits.is_some();
+
+    let use_significant = match options.rounding_priority {
+        _ if !has_fraction_options || !has_significant_options => has_significant_options,
+        RoundingPriority::Auto => true,
+        RoundingPriority::MorePrecision => {
+            significant_position(value, options) <= fraction_position(options)
+        }
+        RoundingPriority::LessPrecision => {
+            significant_position(value, options) > fraction_position(options)
+        }
+    };
+
+    if use_significant {
+        if options.maximum_significant_digits.is_some() {
+            value.round_with_mode(significant_position(value, options), options.rounding_mode);
+        }
+        value.trim_end();
+        let min = options.minimum_significant_digits.unwrap_or(1);
+        value.pad_end(
+            value
+                .nonzero_magnitude_start()
+                .saturating_sub(i16::from(min))
+                .saturating_add(1),
+        );
+    } else if has_fraction_options {
+        if options.maximum_fraction_digits.is_some() {
+            value.round_with_mode_and_increment(
+                fraction_position(options),
+                options.rounding_mode,
+                options.rounding_increment,
+            );
+        }
+        value.trim_end();
+        value.pad_end(-i16::from(options.minimum_fraction_digits.unwrap_or(0)));
+    }
+
+    if options.trailing_zero_display == TrailingZeroDisplay::StripIfInteger
+        && (value.is_zero() || value.nonzero_magnitude_end() >= 0)
+    {
+        value.trim_end();
+    }
+}
+
+/// Returns the magnitude at which the significant digit options round the number, or
+/// [`i16::MIN`] if they do not round it.
+fn significant_position(value: &FixedDecimal, options: &FixedDecimalFormatterOptions) -> i16 {
+    match options.maximum_significant_digits {
+        Some(max) => value
+            .nonzero_magnitude_start()
+            .saturating_sub(i16::from(max))
+            .saturating_add(1),
+        None => i16::MIN,
+    }
+}
+
+/// Returns the magnitude at which the fraction digit options round the number, or
+/// [`i16::MIN`] if they do not round it.
+fn fraction_position(options: &FixedDecimalFormatterOptions) -> i16 {
+    match options.maximum_fraction_digits {
+        Some(max) => -i16::from(max),
+        None => i16::MIN,
+    }
+}
+
+#[test]
+fn test_precision() {
+    use crate::FixedDecimalFormatter;
+    use icu_locid::locale;
+    use writeable::assert_writeable_eq;
+
+    #[derive(Debug)]
+    struct TestCase {
+        pub notation: Notation,
+        pub options: FixedDecimalFormatterOptions,
+        pub input: &'static str,
+        pub expected: &'static str,
+    }
+    let fraction = |min: Option<u8>, max: Option<u8>| FixedDecimalFormatterOptions {
+        minimum_fraction_digits: min,
+        maximum_fraction_digits: max,
+        ..Default::default()
+    };
+    let significant = |min: Option<u8>, max: Option<u8>| FixedDecimalFormatterOptions {
+        minimum_significant_digits: min,
+        maximum_significant_digits: max,
+        ..Default::default()
+    };
+    let cases = [
+        TestCase {
+            notation: Notation::Standard,
+            options: Default::default(),
+            input: "1.500",
+            expected: "1.500",
+        },
+        TestCase {
+            notation: Notation::Standard,
+            options: fraction(None, Some(2)),
+            input: "1.500",
+            expected: "1.5",
+        },
+        TestCase {
+            notation: Notation::Standard,
+            options: fraction(Some(3), None),
+            input: "1.5",
+            expected: "1.500",
+        },
+        TestCase {
+            notation: Notation::Standard,
+            options: fraction(Some(0), Some(0)),
+            input: "2.5",
+            expected: "3",
+        },
+        TestCase {
+            notation: Notation::Standard,
+            options: fraction(None, Some(0)),
+            input: "-2.5",
+            expected: "-3",
+        },
+        TestCase {
+            notation: Notation::Standard,
+            options: FixedDecimalFormatterOptions {
+                rounding_mode: RoundingMode::HalfEven,
+                ..fraction(None, Some(0))
+            },
+            input: "2.5",
+            expected: "2",
+        },
+        TestCase {
+            notation: Notation::Standard,
+            options: FixedDecimalFormatterOptions {
+                rounding_mode: RoundingMode::Floor,
+                ..fraction(None, Some(1))
+            },
+            input: "-0.01",
+            expected: "-0.1",
+        },
+        TestCase {
+            notation: Notation::Standard,
+            options: FixedDecimalFormatterOptions {
+                rounding_increment: RoundingIncrement::MultiplesOf25,
+                ..fraction(Some(2), Some(2))
+            },
+            input: "3.8",
+            expected: "3.75",
+        },
+        TestCase {
+            notation: Notation::Standard,
+            options: significant(None, Some(3)),
+            input: "123456",
+            expected: "123,000",
+        },
+        TestCase {
+            notation: Notation::Standard,
+            options: significant(None, Some(2)),
+            input: "0.09999",
+            expected: "0.1",
+        },
+        TestCase {
+            notation: Notation::Standard,
+            options: significant(Some(3), None),
+            input: "0",
+            expected: "0.00",
+        },
+        TestCase {
+            notation: Notation::Standard,
+            options: significant(Some(4), Some(4)),
+            input: "9.9999",
+            expected: "10.00",
+        },
+        TestCase {
+            notation: Notation::Standard,
+            options: FixedDecimalFormatterOptions {
+                maximum_significant_digits: Some(2),
+                ..fraction(Some(2), Some(2))
+            },
+            input: "1234.5",
+            expected: "1,200",
+        },
+        TestCase {
+            notation: Notation::Standard,
+            options: FixedDecimalFormatterOptions {
+                maximum_significant_digits: Some(2),
+                rounding_priority: RoundingPriority::MorePrecision,
+                ..fraction(Some(2), Some(2))
+            },
+            input: "1234.5",
+            expected: "1,234.50",
+        },
+        TestCase {
+            notation: Notation::Standard,
+            options: FixedDecimalFormatterOptions {
+                maximum_significant_digits: Some(2),
+                rounding_priority: RoundingPriority::LessPrecision,
+                ..fraction(Some(2), Some(2))
+            },
+            input: "1234.5",
+            expected: "1,200",
+        },
+        TestCase {
+            notation: Notation::Standard,
+            options: FixedDecimalFormatterOptions {
+                trailing_zero_display: TrailingZeroDisplay::StripIfInteger,
+                ..Default::default()
+            },
+            input: "100.000",
+            expected: "100",
+        },
+        TestCase {
+            notation: Notation::Standard,
+            options: FixedDecimalFormatterOptions {
+                trailing_zero_display: TrailingZeroDisplay::StripIfInteger,
+                ..fraction(Some(2), Some(2))
+            },
+            input: "0.999",
+            expected: "1",
+        },
+        TestCase {
+            notation: Notation::Percent,
+            options: fraction(None, Some(1)),
+            input: "0.12345",
+            expected: "12.3%",
+        },
+        TestCase {
+            notation: Notation::Percent,
+            options: Default::default(),
+            input: "0.123",
+            expected: "12%",
+        },
+        TestCase {
+            notation: Notation::Percent,
+            options: fraction(Some(1), None),
+            input: "0.12345",
+            expected: "12.3%",
+        },
+        TestCase {
+            notation: Notation::Percent,
+            options: significant(None, Some(4)),
+            input: "0.12345",
+            expected: "12.35%",
+        },
+        TestCase {
+            notation: Notation::Scientific,
+            options: significant(Some(3), Some(3)),
+            input: "120",
+            expected: "1.20E2",
+        },
+        TestCase {
+            notation: Notation::Scientific,
+            options: fraction(None, Some(1)),
+            input: "99.96",
+            expected: "1E2",
+        },
+        TestCase {
+            notation: Notation::Engineering,
+            options: significant(None, Some(2)),
+            input: "999999",
+            expected: "1E6",
+        },
+    ];
+    for cas in &cases {
+        let fdf = FixedDecimalFormatter::try_new_with_notation(
+            &locale!("en").into(),
+            cas.notation,
+            cas.options.clone(),
+        )
+        .unwrap();
+        let input: FixedDecimal = cas.input.parse().unwrap();
+        assert_writeable_eq!(fdf.format(&input), cas.expected, "{:?}", cas);
+    }
+}
+
+#[test]
+fn test_invalid_options() {
+    use crate::{DecimalError, FixedDecimalFormatter};
+    use icu_locid::locale;
+
+    let invalid = [
+        FixedDecimalFormatterOptions {
+            minimum_fraction_digits: Some(3),
+            maximum_fraction_digits: Some(2),
+            ..Default::default()
+        },
+        FixedDecimalFormatterOptions {
+            minimum_significant_digits: Some(3),
+            maximum_significant_digits: Some(2),
+            ..Default::default()
+        },
+        FixedDecimalFormatterOptions {
+            maximum_significant_digits: Some(0),
+            ..Default::default()
+        },
+        FixedDecimalFormatterOptions {
+            minimum_significant_digits: Some(0),
+            ..Default::default()
+        },
+    ];
+    for options in invalid {
+        assert_eq!(
+            FixedDecimalFormatter::try_new(&locale!("en").into(), options.clone()).err(),
+            Some(DecimalError::InvalidDigitOptions),
+            "{options:?}"
+        );
+    }
+}
@@ -30,6 +30,7 @@ const _: () = {
         pub use crate as decimal;
         pub use icu_locid_transform as locid_transform;
     }
+    icu_decimal_data::impl_decimal_notation_v1!(Baked);
     icu_decimal_data::impl_decimal_symbols_v1!(Baked);
 };
 
@@ -154,3 +155,61 @@ impl Default for DecimalSymbolsV1<'static> {
         }
     }
 }
+
+/// Symbols required for formatting a [`FixedDecimal`](crate::FixedDecimal) in percent, per-mille,
+/// scientific, or engineering notation.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[icu_provider::data_struct(marker(
+    DecimalNotationSymbolsV1Marker,
+    "decimal/notation@1",
+    extension_key = "nu"
+))]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_decimal::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct DecimalNotationSymbolsV1<'data> {
+    /// Prefix and suffix to apply to a number formatted as a percentage, including the
+    /// localized percent sign.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub percent_affixes: AffixesV1<'data>,
+
+    /// Prefix and suffix to apply to a number formatted as per-mille, including the
+    /// localized per-mille sign.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub per_mille_affixes: AffixesV1<'data>,
+
+    /// String separating the significand from the exponent, such as `E` in `1.2E3`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub exponential: Cow<'data, str>,
+
+    /// String separating the significand from a superscripted power of ten, such as `×`
+    /// in `1.2×10³`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub superscripting_exponent: Cow<'data, str>,
+}
+
+impl Default for DecimalNotationSymbolsV1<'static> {
+    fn default() -> Self {
+        Self {
+            percent_affixes: AffixesV1 {
+                prefix: Cow::Borrowed(""),
+                suffix: Cow::Borrowed("%"),
+            },
+            per_mille_affixes: AffixesV1 {
+                prefix: Cow::Borrowed(""),
+                suffix: Cow::Borrowed("‰"),
+            },
+            exponential: "E".into(),
+            superscripting_exponent: "×".into(),
+        }
+    }
+}
@@ -51,6 +51,11 @@
 //! * [`Many`](PluralCategory::Many): `5 miesięcy`
 //! * [`Other`](PluralCategory::Other): `1.5 miesiąca`
 //!
+//! ## Plural Ranges
+//!
+//! [`PluralRulesWithRanges`] additionally determines the [`PluralCategory`] of a range of
+//! numbers, such as "1–2 days", from the categories of its start and end.
+//!
 //! ## `PluralRuleType`
 //!
 //! Plural rules depend on the use case. This crate supports two types of plural rules:
@@ -90,6 +95,7 @@ pub use operands::PluralOperands;
 use provider::CardinalV1Marker;
 use provider::ErasedPluralRulesV1Marker;
 use provider::OrdinalV1Marker;
+use provider::PluralRangesV1Marker;
 use rules::runtime::test_rule;
 
 #[doc(no_inline)]
@@ -560,3 +566,147 @@ impl PluralRules {
             .chain(Some(PluralCategory::Other))
     }
 }
+
+/// A [`PluralRules`] that can also determine the [`Plural Category`] of a range of numbers,
+/// such as "1–2 days".
+///
+/// The category of a range depends on the categories of its start and its end, and is not
+/// always the category of either of them.
+///
+/// # Examples
+///
+/// ```
+/// use icu::locid::locale;
+/// use icu::plurals::{PluralCategory, PluralRulesWithRanges};
+///
+/// let pr = PluralRulesWithRanges::try_new_cardinal(&locale!("ar").into())
+///     .expect("locale should be present");
+///
+/// assert_eq!(pr.rules().category_for(0_usize), PluralCategory::Zero);
+/// assert_eq!(pr.rules().category_for(1_usize), PluralCategory::One);
+/// assert_eq!(pr.category_for_range(0_usize, 1_usize), PluralCategory::Zero);
+/// ```
+///
+/// [`Plural Category`]: PluralCategory
+#[derive(Debug)]
+pub struct PluralRulesWithRanges {
+    rules: PluralRules,
+    ranges: DataPayload<PluralRangesV1Marker>,
+}
+
+impl PluralRulesWithRanges {
+    icu_provider::gen_any_buffer_data_constructors!(
+        locale: include,
+        options: skip,
+        error: PluralsError,
+        /// Constructs a new `PluralRulesWithRanges` for a given locale for cardinal numbers using
+        /// compiled data.
+        ///
+        /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+        ///
+        /// [📚 Help choosing a constructor](icu_provider::constructors)
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use icu::locid::locale;
+        /// use icu::plurals::{PluralCategory, PluralRulesWithRanges};
+        ///
+        /// let rules = PluralRulesWithRanges::try_new_cardinal(&locale!("ru").into())
+        ///     .expect("locale should be present");
+        ///
+        /// assert_eq!(rules.category_for_range(1_usize, 2_usize), PluralCategory::Few);
+        /// ```
+        functions: [
+            try_new_cardinal,
+            try_new_cardinal_with_any_provider,
+            try_new_cardinal_with_buffer_provider,
+            try_new_cardinal_unstable,
+            Self,
+        ]
+    );
+
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::try_new_cardinal)]
+    pub fn try_new_cardinal_unstable(
+        provider: &(impl DataProvider<CardinalV1Marker> + DataProvider<PluralRangesV1Marker> + ?Sized),
+        locale: &DataLocale,
+    ) -> Result<Self, PluralsError> {
+        let rules = PluralRules::try_new_cardinal_unstable(provider, locale)?;
+        let ranges = provider
+            .load(DataRequest {
+                locale,
+                metadata: Default::default(),
+            })?
+            .take_payload()?;
+        Ok(Self { rules, ranges })
+    }
+
+    /// Returns the [`PluralRules`] used to determine the categories of the start and the end of
+    /// a range.
+    pub fn rules(&self) -> &PluralRules {
+        &self.rules
+    }
+
+    /// Returns the [`Plural Category`] appropriate for the range of numbers from `start` to
+    /// `end`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locid::locale;
+    /// use icu::plurals::{PluralCategory, PluralOperands, PluralRulesWithRanges};
+    ///
+    /// let pr = PluralRulesWithRanges::try_new_cardinal(&locale!("ru").into())
+    ///     .expect("locale should be present");
+    ///
+    /// // "1–2 дня"
+    /// assert_eq!(pr.category_for_range(1_usize, 2_usize), PluralCategory::Few);
+    /// // "0–1 день"
+    /// assert_eq!(pr.category_for_range(0_usize, 1_usize), PluralCategory::One);
+    ///
+    /// let start: PluralOperands = "1.5".parse().expect("valid operands");
+    /// // "1,5–5 дней"
+    /// assert_eq!(pr.category_for_range(start, 5_usize), PluralCategory::Many);
+    /// ```
+    ///
+    /// [`Plural Category`]: PluralCategory
+    pub fn category_for_range<S: Into<PluralOperands>, E: Into<PluralOperands>>(
+        &self,
+        start: S,
+        end: E,
+    ) -> PluralCategory {
+        self.resolve_range(self.rules.category_for(start), self.rules.category_for(end))
+    }
+
+    /// Returns the [`Plural Category`] appropriate for a range of numbers whose start and end
+    /// have the given categories.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locid::locale;
+    /// use icu::plurals::{PluralCategory, PluralRulesWithRanges};
+    ///
+    /// let pr = PluralRulesWithRanges::try_new_cardinal(&locale!("ar").into())
+    ///     .expect("locale should be present");
+    ///
+    /// assert_eq!(
+    ///     pr.resolve_range(PluralCategory::Other, PluralCategory::One),
+    ///     PluralCategory::Other
+    /// );
+    /// assert_eq!(
+    ///     pr.resolve_range(PluralCategory::Other, PluralCategory::Few),
+    ///     PluralCategory::Few
+    /// );
+    /// ```
+    ///
+    /// [`Plural Category`]: PluralCategory
+    pub fn resolve_range(&self, start: PluralCategory, end: PluralCategory) -> PluralCategory {
+        self.ranges
+            .get()
+            .ranges
+            .get_copied_2d(&start.into(), &end.into())
+            .map(PluralCategory::from)
+            .unwrap_or(end)
+    }
+}
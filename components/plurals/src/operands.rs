@@ -6,7 +6,7 @@ use core::isize;
 use core::num::ParseIntError;
 use core::str::FromStr;
 use displaydoc::Display;
-use fixed_decimal::FixedDecimal;
+use fixed_decimal::{CompactDecimal, FixedDecimal};
 
 /// A full plural operands representation of a number. See [CLDR Plural Rules](http://unicode.org/reports/tr35/tr35-numbers.html#Language_Plural_Rules) for complete operands description.
 /// Plural operands in compliance with [CLDR Plural Rules](http://unicode.org/reports/tr35/tr35-numbers.html#Language_Plural_Rules).
@@ -272,3 +272,21 @@ impl From<&FixedDecimal> for PluralOperands {
         }
     }
 }
+
+impl From<&CompactDecimal> for PluralOperands {
+    /// Converts a [`fixed_decimal::CompactDecimal`] to [`PluralOperands`].
+    ///
+    /// As in [CLDR](https://unicode.org/reports/tr35/tr35-numbers.html#Operands), the
+    /// operands other than `c` are those of the full value, so that 1.2c3 has the integer
+    /// value 1200. Retains at most 18 digits each from the integer and fraction parts.
+    fn from(compact: &CompactDecimal) -> Self {
+        let value = compact
+            .significand()
+            .clone()
+            .multiplied_pow10(compact.exponent());
+        Self {
+            c: usize::from(compact.exponent().unsigned_abs()),
+            ..Self::from(&value)
+        }
+    }
+}
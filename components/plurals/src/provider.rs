@@ -16,8 +16,10 @@
 //! Read more about data providers: [`icu_provider`]
 
 use crate::rules::runtime::ast::Rule;
+use crate::PluralCategory;
 use icu_provider::prelude::*;
 use icu_provider::DataMarker;
+use zerovec::ZeroMap2d;
 
 #[cfg(feature = "compiled_data")]
 #[derive(Debug)]
@@ -32,11 +34,9 @@ const _: () = {
     }
     icu_plurals_data::impl_plurals_ordinal_v1!(Baked);
     icu_plurals_data::impl_plurals_cardinal_v1!(Baked);
+    icu_plurals_data::impl_plurals_ranges_v1!(Baked);
 };
 
-#[cfg(doc)]
-use crate::PluralCategory;
-
 /// Plural rule strings conforming to UTS 35 syntax. Includes separate fields for five of the six
 /// standard plural forms. If none of the rules match, the "other" category is assumed.
 ///
@@ -81,3 +81,88 @@ pub(crate) struct ErasedPluralRulesV1Marker;
 impl DataMarker for ErasedPluralRulesV1Marker {
     type Yokeable = PluralRulesV1<'static>;
 }
+
+/// Plural categories of ranges of numbers, such as "1–2 days", by the categories of their start
+/// and end.
+///
+/// Only pairs whose range category differs from the category of the end of the range are
+/// stored; for all other pairs, the range has the category of its end.
+///
+/// More information: <https://unicode.org/reports/tr35/tr35-numbers.html#Plural_Ranges>
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[icu_provider::data_struct(PluralRangesV1Marker = "plurals/ranges@1")]
+#[derive(Default, Clone, PartialEq, Debug)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_plurals::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct PluralRangesV1<'data> {
+    /// Map from the categories of the start and the end of a range to the category of the range.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub ranges: ZeroMap2d<'data, RawPluralCategory, RawPluralCategory, RawPluralCategory>,
+}
+
+/// A [`PluralCategory`] that can be stored in a [`ZeroMap2d`].
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Ord, PartialOrd)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_plurals::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[zerovec::make_ule(RawPluralCategoryULE)]
+#[repr(u8)]
+pub enum RawPluralCategory {
+    /// [`PluralCategory::Zero`]
+    Zero = 0,
+    /// [`PluralCategory::One`]
+    One = 1,
+    /// [`PluralCategory::Two`]
+    Two = 2,
+    /// [`PluralCategory::Few`]
+    Few = 3,
+    /// [`PluralCategory::Many`]
+    Many = 4,
+    /// [`PluralCategory::Other`]
+    Other = 5,
+}
+
+impl From<PluralCategory> for RawPluralCategory {
+    fn from(category: PluralCategory) -> Self {
+        match category {
+            PluralCategory::Zero => Self::Zero,
+            PluralCategory::One => Self::One,
+            PluralCategory::Two => Self::Two,
+            PluralCategory::Few => Self::Few,
+            PluralCategory::Many => Self::Many,
+            PluralCategory::Other => Self::Other,
+        }
+    }
+}
+
+impl From<RawPluralCategory> for PluralCategory {
+    fn from(category: RawPluralCategory) -> Self {
+        match category {
+            RawPluralCategory::Zero => Self::Zero,
+            RawPluralCategory::One => Self::One,
+            RawPluralCategory::Two => Self::Two,
+            RawPluralCategory::Few => Self::Few,
+            RawPluralCategory::Many => Self::Many,
+            RawPluralCategory::Other => Self::Other,
+        }
+    }
+}
@@ -7,7 +7,7 @@ mod helpers;
 
 use std::convert::TryInto;
 
-use fixed_decimal::FixedDecimal;
+use fixed_decimal::{CompactDecimal, FixedDecimal};
 #[cfg(feature = "experimental")]
 use icu_plurals::rules::RawPluralOperands;
 use icu_plurals::PluralOperands;
@@ -74,3 +74,18 @@ fn test_from_fixed_decimals() {
         );
     }
 }
+
+#[test]
+fn test_from_compact_decimals() {
+    for (input, expected) in [
+        ("1c3", "1000e3"),
+        ("1.2c3", "1200e3"),
+        ("1.25c1", "12.5e1"),
+        ("1c6", "1000000e6"),
+        ("15", "15"),
+    ] {
+        let compact: CompactDecimal = input.parse().expect("Failed to parse a compact decimal");
+        let expected: PluralOperands = expected.parse().expect("Failed to parse operands");
+        assert_eq!(PluralOperands::from(&compact), expected, "{input}");
+    }
+}
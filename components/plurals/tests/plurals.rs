@@ -3,7 +3,9 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use icu_locid::locale;
-use icu_plurals::{provider::CardinalV1Marker, PluralCategory, PluralRuleType, PluralRules};
+use icu_plurals::{
+    provider::CardinalV1Marker, PluralCategory, PluralRuleType, PluralRules, PluralRulesWithRanges,
+};
 use icu_provider::prelude::*;
 
 #[test]
@@ -30,6 +32,30 @@ fn test_static_load_works() {
     .expect("Failed to retrieve payload");
 }
 
+#[test]
+fn test_plural_ranges_fallback() {
+    // Locales whose ranges all have the category of their end fall back to the root locale.
+    for locale in [locale!("de"), locale!("it"), locale!("ko")] {
+        let pr = PluralRulesWithRanges::try_new_cardinal(&locale.clone().into())
+            .unwrap_or_else(|e| panic!("{locale}: {e}"));
+        assert_eq!(
+            pr.resolve_range(PluralCategory::One, PluralCategory::Other),
+            PluralCategory::Other
+        );
+        assert_eq!(
+            pr.resolve_range(PluralCategory::Other, PluralCategory::One),
+            PluralCategory::One
+        );
+    }
+
+    // Regional locales inherit the ranges of their parent locale.
+    let pr = PluralRulesWithRanges::try_new_cardinal(&locale!("en-GB").into()).unwrap();
+    assert_eq!(
+        pr.resolve_range(PluralCategory::Other, PluralCategory::One),
+        PluralCategory::Other
+    );
+}
+
 #[test]
 fn test_plural_category_all() {
     let categories: Vec<PluralCategory> = PluralCategory::all().collect();
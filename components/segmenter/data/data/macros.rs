@@ -15,6 +15,11 @@ mod segmenter_grapheme_v1;
 #[doc(inline)]
 pub use __impl_segmenter_grapheme_v1 as impl_segmenter_grapheme_v1;
 #[macro_use]
+#[path = "macros/segmenter_line_override_v1.data.rs"]
+mod segmenter_line_override_v1;
+#[doc(inline)]
+pub use __impl_segmenter_line_override_v1 as impl_segmenter_line_override_v1;
+#[macro_use]
 #[path = "macros/segmenter_line_v1.data.rs"]
 mod segmenter_line_v1;
 #[doc(inline)]
@@ -25,11 +30,26 @@ mod segmenter_lstm_wl_auto_v1;
 #[doc(inline)]
 pub use __impl_segmenter_lstm_wl_auto_v1 as impl_segmenter_lstm_wl_auto_v1;
 #[macro_use]
+#[path = "macros/segmenter_sentence_override_v1.data.rs"]
+mod segmenter_sentence_override_v1;
+#[doc(inline)]
+pub use __impl_segmenter_sentence_override_v1 as impl_segmenter_sentence_override_v1;
+#[macro_use]
+#[path = "macros/segmenter_sentence_suppressions_v1.data.rs"]
+mod segmenter_sentence_suppressions_v1;
+#[doc(inline)]
+pub use __impl_segmenter_sentence_suppressions_v1 as impl_segmenter_sentence_suppressions_v1;
+#[macro_use]
 #[path = "macros/segmenter_sentence_v1.data.rs"]
 mod segmenter_sentence_v1;
 #[doc(inline)]
 pub use __impl_segmenter_sentence_v1 as impl_segmenter_sentence_v1;
 #[macro_use]
+#[path = "macros/segmenter_word_override_v1.data.rs"]
+mod segmenter_word_override_v1;
+#[doc(inline)]
+pub use __impl_segmenter_word_override_v1 as impl_segmenter_word_override_v1;
+#[macro_use]
 #[path = "macros/segmenter_word_v1.data.rs"]
 mod segmenter_word_v1;
 #[doc(inline)]
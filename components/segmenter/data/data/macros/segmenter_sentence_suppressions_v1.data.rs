@@ -0,0 +1,40 @@
+// @generated
+/// Implement `DataProvider<SentenceBreakSuppressionsV1Marker>` on the given struct using the data
+/// hardcoded in this file. This allows the struct to be used with
+/// `icu`'s `_unstable` constructors.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_segmenter_sentence_suppressions_v1 {
+    ($ provider : path) => {
+        #[clippy::msrv = "1.66"]
+        impl icu_provider::DataProvider<icu::segmenter::provider::SentenceBreakSuppressionsV1Marker> for $provider {
+            fn load(&self, req: icu_provider::DataRequest) -> Result<icu_provider::DataResponse<icu::segmenter::provider::SentenceBreakSuppressionsV1Marker>, icu_provider::DataError> {
+                static IT: <icu::segmenter::provider::SentenceBreakSuppressionsV1Marker as icu_provider::DataMarker>::Yokeable = icu::segmenter::provider::SentenceBreakSuppressionsV1 { suppressions: unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"-\0\0\0\0\0\x04\0\x08\0\x0C\0\x10\0\x16\0\x1C\0 \0$\0)\0/\x005\09\0?\0D\0I\0M\0S\0W\0[\0_\0c\0f\0j\0p\0s\0x\0|\0\x80\0\x83\0\x87\0\x8A\0\x8C\0\x90\0\x9A\0\x9E\0\xA2\0\xA6\0\xAA\0\xAC\0\xB2\0\xB6\0\xBA\0\xC0\0\xC4\0N.B.div.a.C.fig.d.p.R.c.c.p.Cfr.vol.Geom.O.d.G.S.p.A.ver.N.d.A.dott.arch.d.C.N.d.T.rag.Sig.Mod.pag.dr.tav.N.d.E.DC.mitt.Ing.int.on.C.P.ag.L.U.S.S.M.A.R.T.p.i.tab.Ltd.Liv.D.U.S.A.sez.avv.S.A.R.all.p.") } };
+                static FR: <icu::segmenter::provider::SentenceBreakSuppressionsV1Marker as icu_provider::DataMarker>::Yokeable = icu::segmenter::provider::SentenceBreakSuppressionsV1 { suppressions: unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"R\0\0\0\0\0\x04\0\x0B\0\x12\0\x14\0\x18\0\x1F\0#\0(\0-\x000\x004\08\0=\0B\0G\0M\0Q\0V\0X\0\\\0a\0d\0j\0n\0r\0v\0|\0\x80\0\x86\0\x8A\0\x90\0\x95\0\x9A\0\x9F\0\xA4\0\xAA\0\xB0\0\xB3\0\xBA\0\xC2\0\xC9\0\xCD\0\xCF\0\xD3\0\xD7\0\xE1\0\xE6\0\xEA\0\xEE\0\xF3\0\xFB\0\xFF\0\x04\x01\x0B\x01\x0F\x01\x14\x01\x18\x01\x1A\x01\x1E\x01\"\x01&\x01(\x01/\x013\x019\x01>\x01B\x01F\x01L\x01P\x01W\x01\\\x01`\x01b\x01h\x01m\x01v\x01x\x01{\x01\x84\x01\x89\x01aux.config.collab.M.dim.imprim.oct.syst.bull.MM.doc.P.O.h\xC3\xB4p.Mart.juil.broch.adr.symb.C.anc.voit.Jr.graph.dir.\xC3\xA9d.fig.\xC3\xA9dit.niv.quart.cam.\xC3\xA9val.anon.r\xC3\xA9f.Comm.Prof.f\xC3\xA9vr.indus.DC.\xC3\xA9quiv.illustr.acoust.nov.L.All.U.S.S.M.A.R.T.sept.avr.jeu.dest.P.-D. G.ill.coll.encycl.mer.Desc.ven.P.lun.Inc.sam.D.append.Var.categ.janv.S.A.imm.U.S.A.mar.exempl.d\xC3\xA9c.ann.U.synth.dict.av. J.-C.W.Op.ap. J.-C.gouv.trav. publ.") } };
+                static RU: <icu::segmenter::provider::SentenceBreakSuppressionsV1Marker as icu_provider::DataMarker>::Yokeable = icu::segmenter::provider::SentenceBreakSuppressionsV1 { suppressions: unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x12\0\0\0\0\0\x07\0\x0E\0\x1A\0#\0*\x001\0:\0C\0J\0Q\0X\0_\0f\0o\0u\0z\0\x7F\0\xD1\x80\xD1\x83\xD0\xB1.\xD1\x8F\xD0\xBD\xD0\xB2.\xD0\xB4\xD0\xBE \xD0\xBD. \xD1\x8D.\xD1\x81\xD0\xB5\xD0\xBD\xD1\x82.\xD1\x82\xD0\xB5\xD0\xBB.\xD0\xB4\xD0\xB5\xD0\xBA.\xD1\x84\xD0\xB5\xD0\xB2\xD1\x80.\xD0\xBD\xD0\xBE\xD1\x8F\xD0\xB1.\xD0\xB0\xD0\xBF\xD1\x80.\xD0\xBD. \xD1\x8D.\xD0\xBE\xD0\xBA\xD1\x82.\xD1\x82\xD1\x8B\xD1\x81.\xD0\xB0\xD0\xB2\xD0\xB3.\xD0\xBF\xD1\x80\xD0\xBE\xD1\x84.\xD0\xBD.\xD1\x8D.\xD0\xBA\xD0\xB2.\xD1\x83\xD0\xBB.\xD0\xBE\xD1\x82\xD0\xB4.") } };
+                static EN: <icu::segmenter::provider::SentenceBreakSuppressionsV1Marker as icu_provider::DataMarker>::Yokeable = icu::segmenter::provider::SentenceBreakSuppressionsV1 { suppressions: unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x97\0\0\0\0\0\x04\0\x08\0\x0F\0\x13\0\x15\0\x19\0\x1E\0\"\0&\0*\0/\x003\08\0;\0@\0D\0F\0K\0N\0R\0V\0Y\0]\0`\0d\0h\0l\0p\0r\0v\0y\0}\0\x81\0\x87\0\x8A\0\x8E\0\x91\0\x96\0\x98\0\x9D\0\xA0\0\xA4\0\xA7\0\xAA\0\xAC\0\xAE\0\xB1\0\xB5\0\xB9\0\xBD\0\xC2\0\xC6\0\xCA\0\xCF\0\xD3\0\xD7\0\xDC\0\xE0\0\xE4\0\xE6\0\xE9\0\xEC\0\xEF\0\xF3\0\xF7\0\xFB\0\xFF\0\x02\x01\x06\x01\n\x01\r\x01\x11\x01\x16\x01\x1A\x01\x1E\x01!\x01#\x01)\x01,\x01.\x012\x018\x01=\x01A\x01D\x01H\x01L\x01O\x01S\x01W\x01[\x01_\x01a\x01f\x01i\x01m\x01r\x01u\x01x\x01{\x01\x7F\x01\x83\x01\x86\x01\x8A\x01\x8E\x01\x94\x01\x98\x01\x9F\x01\xA3\x01\xA5\x01\xA8\x01\xAC\x01\xAF\x01\xB2\x01\xB7\x01\xBA\x01\xBE\x01\xC3\x01\xCB\x01\xCD\x01\xD0\x01\xD3\x01\xD7\x01\xDB\x01\xDE\x01\xE1\x01\xE3\x01\xE9\x01\xEB\x01\xEF\x01\xF4\x01\xF7\x01\xFA\x01\xFC\x01\0\x02\x03\x02\x07\x02\t\x02\r\x02\x11\x02\x13\x02\x17\x02\x1B\x02\x1F\x02#\x02)\x02-\x020\x024\x027\x02L.P.Alt.Approx.E.G.O.Maj.Misc.P.O.J.D.Jam.Card.Dec.Sept.MR.Long.Hat.G.Link.DC.D.C.M.T.Hz.Mrs.By.Act.Var.N.V.Aug.B.S.A.Up.Job.Num.M.I.T.Ok.Org.Ex.Cont.U.Mart.Fn.Abs.Lt.OK.Z.E.Kb.Est.A.M.L.A.Prof.U.S.Nov.Ph.D.Mar.I.T.exec.Jan.N.Y.X.Md.Op.vs.D.A.A.D.R.L.P.M.Or.M.R.Cap.PC.Feb.Exec.I.e.Sep.Gb.K.U.S.C.Mt.S.A.S.C.O.D.Capt.Col.In.C.F.Adj.AD.I.D.Mgr.R.T.B.V.M.Conn.Yr.Rev.Phys.pp.Ms.To.Sgt.J.K.Nr.Jun.Fri.S.A.R.Lev.Lt.Cdr.Def.F.Do.Joe.Id.Mr.Dept.Is.Pvt.Diff.Hon.B.A.Q.Mb.On.Min.J.B.Ed.AB.A.S.p.A.I.a.m.Comm.Go.VS.L.All.PP.P.V.T.K.R.Etc.D.Adv.Lib.E.g.Pro.U.S.A.S.E.AA.Rep.Sq.As.") } };
+                static ES: <icu::segmenter::provider::SentenceBreakSuppressionsV1Marker as icu_provider::DataMarker>::Yokeable = icu::segmenter::provider::SentenceBreakSuppressionsV1 { suppressions: unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\xA4\0\0\0\0\0\x05\0\x0B\0\x10\0\x14\0\x18\0\x1E\0\"\0&\0+\0/\x005\0:\0A\0E\0I\0O\0R\0V\0[\0^\0d\0g\0l\0p\0t\0x\0~\0\x82\0\x86\0\x8D\0\x92\0\x96\0\x99\0\x9D\0\xA0\0\xA4\0\xA9\0\xAD\0\xB2\0\xB5\0\xB9\0\xBE\0\xC2\0\xC5\0\xCB\0\xD0\0\xD4\0\xD9\0\xDD\0\xE0\0\xE5\0\xEA\0\xEE\0\xF2\0\xF6\0\xFB\0\xFF\0\x04\x01\t\x01\x0E\x01\x12\x01\x18\x01\x1C\x01 \x01%\x01)\x01.\x013\x016\x01<\x01@\x01C\x01G\x01K\x01P\x01V\x01[\x01a\x01f\x01l\x01o\x01q\x01v\x01y\x01~\x01\x83\x01\x88\x01\x8D\x01\x8F\x01\x93\x01\x99\x01\x9D\x01\xA2\x01\xA5\x01\xA9\x01\xAF\x01\xB3\x01\xB7\x01\xB9\x01\xBB\x01\xBF\x01\xC3\x01\xCA\x01\xCD\x01\xD2\x01\xD8\x01\xDC\x01\xE2\x01\xE7\x01\xEB\x01\xF1\x01\xF5\x01\xF9\x01\xFD\x01\x02\x02\x06\x02\n\x02\x10\x02\x14\x02\x17\x02\x1C\x02!\x02'\x02+\x020\x024\x028\x02<\x02C\x02H\x02L\x02P\x02U\x02X\x02[\x02_\x02c\x02j\x02n\x02s\x02w\x02{\x02\x80\x02\x84\x02\x89\x02\x8B\x02\x8F\x02\x92\x02\x97\x02\x9C\x02\xA1\x02\xA5\x02\xAA\x02\xAF\x02\xB5\x02\xBD\x02\xC2\x02\xC8\x02\xCB\x02\xCF\x02\xD2\x02\xD7\x02\xDB\x02Rdos.JJ.OO.Sres.fig.may.RR.HH.oct.cap.mi\xC3\xA9.doc.Excmo.Trab.Excmos.Kit.Inc.FF.CC.DC.ago.trad.SA.Rvdos.ed.Exmo.jul.col.RAM.Srtas.ene.Rol.Fabric.Comm.vid.Da.dic.ss.abr.ntra.Sra.dtor.cf.dom.prov.Emm.Sr.licdo.p.ej.bol.figs.Vda.Dr.ntro.Desv.O.M.Ldo.Drs.s\xC3\xA1b.feb.Ltda.Lcda.Exma.C.V.SS.MM.Lda.U.S.hnos.R.D.Korn.v.gr.vs.Ilmas.Rdo.ej.vie.jue.a. C.Ilmos.e. c.Excma.afma.licda.Em.K.sras.MM.fund.Mons.Lcdo.afmo.C.A.C.dptos.Col.Srta.Av.Ant.depto.Var.H.P.D.M.C.P.Rev.Rvdmos.Fr.Ilmo.afmos.Ltd.afmas.prof.lun.SS.AA.Sol.nov.mss.D\xC3\xB1a.Seg.mar.Rvdmo.Reg.ms.Sras.sres.U.S.A.Sta.Sdad.Dra.srs.R.U.deptos.dpto.jun.bco.C\xC3\xADa.Id.Mr.e.g.C.S.Excmas.D\xC2\xAA.Rvdo.Lic.cfr.Corp.Dto.Ilma.L.All.PP.d. C.Ltdo.mtro.Mrs.Desc.Avda.Exmas.a. e. c.Bien.Exmos.AA.Sto.CA.sept.Exc.c/c.") } };
+                static PT: <icu::segmenter::provider::SentenceBreakSuppressionsV1Marker as icu_provider::DataMarker>::Yokeable = icu::segmenter::provider::SentenceBreakSuppressionsV1 { suppressions: unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\xAC\0\0\0\0\0\x07\0\x0B\0\x11\0\x15\0\x19\0\x1D\0$\0)\x000\x005\0>\0C\0G\0K\0P\0W\0[\0^\0b\0g\0k\0n\0s\0w\0y\0\x80\0\x83\0\x85\0\x89\0\x8D\0\x91\0\x97\0\x9B\0\x9F\0\xA2\0\xA6\0\xAA\0\xAE\0\xB2\0\xB8\0\xBC\0\xC0\0\xC5\0\xC9\0\xCD\0\xD2\0\xD4\0\xD8\0\xDD\0\xE3\0\xE7\0\xEC\0\xF1\0\xF6\0\xFB\0\0\x01\x05\x01\t\x01\x0E\x01\x15\x01\x19\x01\x1F\x01\"\x01&\x01,\x010\x015\x018\x01<\x01@\x01F\x01L\x01T\x01X\x01\\\x01b\x01f\x01k\x01o\x01t\x01x\x01|\x01\x80\x01\x82\x01\x87\x01\x8F\x01\x93\x01\x97\x01\x9B\x01\xA0\x01\xA3\x01\xA8\x01\xAC\x01\xAF\x01\xB5\x01\xBA\x01\xC0\x01\xC8\x01\xCD\x01\xD0\x01\xD5\x01\xD9\x01\xDC\x01\xE0\x01\xE4\x01\xE9\x01\xEE\x01\xF1\x01\xF5\x01\xFB\x01\0\x02\x06\x02\n\x02\x0E\x02\x11\x02\x15\x02\x19\x02\x1E\x02\"\x02(\x02,\x020\x022\x026\x02:\x02@\x02D\x02I\x02M\x02P\x02U\x02X\x02`\x02f\x02l\x02n\x02t\x02x\x02|\x02\x82\x02\x85\x02\x88\x02\x8C\x02\x90\x02\x97\x02\x9C\x02\xA0\x02\xA5\x02\xA9\x02\xAD\x02\xB1\x02\xB6\x02\xBB\x02\xBE\x02\xC3\x02\xC8\x02\xCD\x02\xD3\x02\xD8\x02\xDC\x02\xE0\x02\xE4\x02\xE7\x02\xEB\x02\xEF\x02\xF6\x02\xFA\x02\xFE\x02\x03\x03\r\x03\x12\x03psicol.fig.compl.rep.cap.doc.fisiol.dipl.astron.port.eletr\xC3\xB4n.geom.mov.ago.trad.arquit.dez.ed.apt.Exmo.col.ff.univ.res.R.transp.D.Cl.des.fev.abr.liter.lat.Dir.cf.adm.fot.p.m.P.M.cr\xC3\xA9d.jur.com.anat.dir.end.f\xC3\xADs.E.Est.cont.matem.Drs.g\xC3\xAAn.neol.p\xC3\xA1g.\xC3\xADnd.Ltda.Exma.esp.ingl.tecnol.Mar.s\xC3\xADmb.Pe.pal.filos.V.T.fasc.vs.mai.S.A.profa.N.Sra.r.s.v.p.cel.mat.abrev.out.long.aux.arit.aer.jul.lin.S.m\xC3\xA9d.odontol.org.A.C.jun.d\xC3\xA9b.Av.\xC3\xA1lg.sup.fl.odont.caps.relat.organiz.hist.Fr.Ilmo.fem.ap.Ltd.pol.s\xC3\xA9c.prof.cx.nov.qu\xC3\xADm.m\xC3\xBAs.agric.mar.W.C.fr.cat.jan.pron.rel.autom.Sta.Dra.p.tel.div.p. ex.a.C.bras.Alm.Dr.comp.pq.arqueol.n\xC3\xA1ut.biogr.f.c\xC3\xADrc.fac.d.C.apart.ex.Jr.set.tec.sociol.gram.ind.Ilma.vol.eng.rod.Ph.D.Dras.pp.elem.m\xC3\xA1q.c\xC3\xB3d.eletr.prod.ref.fil.a.m.A.Mobs.N.T.contab.Sto.lit.educ.remententedesc.pr\xC3\xB3x.") } };
+                static DE: <icu::segmenter::provider::SentenceBreakSuppressionsV1Marker as icu_provider::DataMarker>::Yokeable = icu::segmenter::provider::SentenceBreakSuppressionsV1 { suppressions: unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\xF1\0\0\0\0\0\x05\0\t\0\x0C\0\x10\0\x14\0\x1B\0 \0$\0(\0,\x002\x005\09\0>\0C\0F\0J\0N\0R\0W\0[\0^\0a\0e\0h\0l\0r\0v\0z\0|\0\x81\0\x89\0\x8C\0\x93\0\x97\0\x9B\0\xA0\0\xA5\0\xA9\0\xAD\0\xB1\0\xB5\0\xB9\0\xBB\0\xC0\0\xC8\0\xCC\0\xD0\0\xDA\0\xDC\0\xDE\0\xE3\0\xE5\0\xE8\0\xEB\0\xF1\0\xF4\0\xF8\0\xFC\0\xFF\0\x03\x01\x07\x01\x0F\x01\x14\x01\x19\x01\x1B\x01\x1D\x01!\x01'\x01+\x01.\x011\x014\x018\x01;\x01@\x01F\x01H\x01L\x01S\x01Y\x01_\x01d\x01j\x01n\x01r\x01v\x01z\x01\x7F\x01\x83\x01\x87\x01\x89\x01\x8E\x01\x92\x01\x95\x01\x9B\x01\x9F\x01\xA3\x01\xA7\x01\xAC\x01\xB0\x01\xB2\x01\xB6\x01\xB9\x01\xBD\x01\xC3\x01\xC9\x01\xCB\x01\xCF\x01\xD6\x01\xDA\x01\xDE\x01\xE2\x01\xE6\x01\xEB\x01\xF3\x01\xF7\x01\xFB\x01\0\x02\x04\x02\x06\x02\x0B\x02\x0F\x02\x13\x02\x17\x02\x1D\x02%\x02)\x02.\x022\x026\x02:\x02>\x02C\x02G\x02K\x02P\x02U\x02[\x02`\x02h\x02l\x02n\x02x\x02|\x02\x81\x02\x84\x02\x89\x02\x8B\x02\x8D\x02\x91\x02\x95\x02\x9A\x02\x9E\x02\xA3\x02\xA8\x02\xAA\x02\xB0\x02\xB5\x02\xB8\x02\xBC\x02\xC5\x02\xC9\x02\xD0\x02\xD4\x02\xD8\x02\xDE\x02\xE3\x02\xE7\x02\xEB\x02\xEF\x02\xF4\x02\xF8\x02\xFB\x02\0\x03\x04\x03\t\x03\r\x03\x11\x03\x16\x03\x1A\x03\x1C\x03!\x03&\x03)\x03-\x033\x039\x03?\x03A\x03F\x03K\x03P\x03T\x03X\x03\\\x03`\x03f\x03j\x03m\x03q\x03v\x03\x7F\x03\x82\x03\x88\x03\x8C\x03\x91\x03\x95\x03\x97\x03\x9F\x03\xA5\x03\xAA\x03\xAE\x03\xB2\x03\xB4\x03\xB9\x03\xBD\x03\xC1\x03\xC5\x03\xC9\x03\xCE\x03\xD1\x03\xD3\x03\xD8\x03\xDA\x03\xE5\x03\xF0\x03\xF4\x03\xF9\x03\xFD\x03\xFF\x03\x03\x04\x07\x04\x0B\x04\r\x04\x13\x04\x18\x04!\x04%\x04+\x04Port.Alt.Di.Ges.frz.entspr.Gebr.erw.Frl.Inh.k.u.k.Ca.J.D.Ausg.evtl.So.i.B.s.a.kgl.Sept.o.B.Sa.ev.Dez.am.i.R.eigtl.i.J.u.U.G.z.Hd.u.A.w.g.Kl.Spezif.Obj.Ing.D. h.Folg.Akt.i.A.Msp.U.U.Chr.R.Einh.schw\xC3\xA4b.Vgl.Aug.Dipl.-Ing.W.B.U. U.J.Fa.Mo.n.u.Z.Op.Mrd.e.h.Hr.Hrn.Ztr.k. u. k.Bibl.d.\xC3\x84.b.M.i.H.v.R.w.o.A.St.Dr.Fn.Abs.Rd.Dtzd.Jahrh.Z.Std.n. Chr.m\xC3\xB6bl.t\xC3\xA4gl.gest.gesch.z.B.Hbf.Abt.A.M.e.Wz.v.T.Nov.z.Prot.U.S.Wg.u.v.a.Adr.App.ggf.ggfs.Jan.O.Rel.od.Pfd.a.a.O.p.Adr.P.Gem.v. Chr.Art.z.Z.S.A.i.V.verh.Ausschl.m.W.Dir.Verf.Sek.r.Chin.Feb.Int.Sep.Gesch.schweiz.Bed.a.Rh.jew.vgl.a.M.Str.exkl.gek.Erf.u.\xC3\x84.ehem.n\xC3\xA4ml.u. Z.v. u. Z.sog.C.Dipl.-Kfm.mtl.Hrsg.Qu.r\xC3\xB6m.u.U.Adj.Kap.hpts.a.D.gedr.Best.N.v.u.Z.Phys.Fr.d.J.Reg.-Bez.m.E.schles.Max.Ltd.s\xC3\xBCdd.inkl.geb.Ggf.Inc.kath.kfm.Nr.Proz.Dim.verw.Reg.Dat.Evtl.led.F.Test.Schr.Do.PIN.Z. Zt.v.Chr.T\xC3\xA4gl.s.amtl.Temp.Mind.e.V.Abw.P.M.F.f.a.a.S.Mod.Co.Min.Allg.Geograph.Jr.Urspr.Apr.Z. B.v.H.A.einschl.Trans.zzgl.StR.Fam.I.jhrl.u.a.Ben.o.g.Kfm.Konv.Mi.L.beil.T.Urspr\xC3\xBCngl.r\xC3\xB6m.-kath.Okt.u.\xC3\xA4.Tel.D.Ber.Kop.Mio.Y.U.S.A.v. H.Forts. f.Rep.Hptst.\xC3\xB6sterr.") } };
+                static UND: <icu::segmenter::provider::SentenceBreakSuppressionsV1Marker as icu_provider::DataMarker>::Yokeable = icu::segmenter::provider::SentenceBreakSuppressionsV1 { suppressions: zerovec::VarZeroVec::new() };
+                static VALUES: [&<icu::segmenter::provider::SentenceBreakSuppressionsV1Marker as icu_provider::DataMarker>::Yokeable; 8usize] = [&DE, &EN, &ES, &FR, &IT, &PT, &RU, &UND];
+                static KEYS: [&str; 8usize] = ["de", "en", "es", "fr", "it", "pt", "ru", "und"];
+                let mut metadata = icu_provider::DataResponseMetadata::default();
+                let payload = if let Ok(payload) = KEYS.binary_search_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse()).map(|i| *unsafe { VALUES.get_unchecked(i) }) {
+                    payload
+                } else {
+                    const FALLBACKER: icu::locid_transform::fallback::LocaleFallbackerWithConfig<'static> = icu::locid_transform::fallback::LocaleFallbacker::new().for_config(<icu::segmenter::provider::SentenceBreakSuppressionsV1Marker as icu_provider::KeyedDataMarker>::KEY.fallback_config());
+                    let mut fallback_iterator = FALLBACKER.fallback_for(req.locale.clone());
+                    loop {
+                        if let Ok(payload) = KEYS.binary_search_by(|k| fallback_iterator.get().strict_cmp(k.as_bytes()).reverse()).map(|i| *unsafe { VALUES.get_unchecked(i) }) {
+                            metadata.locale = Some(fallback_iterator.take());
+                            break payload;
+                        }
+                        fallback_iterator.step();
+                    }
+                };
+                Ok(icu_provider::DataResponse { payload: Some(icu_provider::DataPayload::from_static_ref(payload)), metadata })
+            }
+        }
+    };
+}
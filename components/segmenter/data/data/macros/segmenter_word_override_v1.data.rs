@@ -0,0 +1,44 @@
+// @generated
+/// Implement `DataProvider<WordBreakDataOverrideV1Marker>` on the given struct using the data
+/// hardcoded in this file. This allows the struct to be used with
+/// `icu`'s `_unstable` constructors.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_segmenter_word_override_v1 {
+    ($ provider : path) => {
+        #[clippy::msrv = "1.66"]
+        impl icu_provider::DataProvider<icu::segmenter::provider::WordBreakDataOverrideV1Marker> for $provider {
+            fn load(&self, req: icu_provider::DataRequest) -> Result<icu_provider::DataResponse<icu::segmenter::provider::WordBreakDataOverrideV1Marker>, icu_provider::DataError> {
+                static UND: <icu::segmenter::provider::WordBreakDataOverrideV1Marker as icu_provider::DataMarker>::Yokeable = icu::segmenter::provider::RuleBreakDataOverrideV1 {
+                    property_overrides: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b":\0\0U\xFE\0\x1A\xFF\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\0") })
+                    },
+                };
+                static FI: <icu::segmenter::provider::WordBreakDataOverrideV1Marker as icu_provider::DataMarker>::Yokeable = icu::segmenter::provider::RuleBreakDataOverrideV1 {
+                    property_overrides: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b":\0\0U\xFE\0\x1A\xFF\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x0E\x0E\x0E") })
+                    },
+                };
+                static VALUES: [&<icu::segmenter::provider::WordBreakDataOverrideV1Marker as icu_provider::DataMarker>::Yokeable; 3usize] = [&FI, &FI, &UND];
+                static KEYS: [&str; 3usize] = ["fi", "sv", "und"];
+                let mut metadata = icu_provider::DataResponseMetadata::default();
+                let payload = if let Ok(payload) = KEYS.binary_search_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse()).map(|i| *unsafe { VALUES.get_unchecked(i) }) {
+                    payload
+                } else {
+                    const FALLBACKER: icu::locid_transform::fallback::LocaleFallbackerWithConfig<'static> = icu::locid_transform::fallback::LocaleFallbacker::new().for_config(<icu::segmenter::provider::WordBreakDataOverrideV1Marker as icu_provider::KeyedDataMarker>::KEY.fallback_config());
+                    let mut fallback_iterator = FALLBACKER.fallback_for(req.locale.clone());
+                    loop {
+                        if let Ok(payload) = KEYS.binary_search_by(|k| fallback_iterator.get().strict_cmp(k.as_bytes()).reverse()).map(|i| *unsafe { VALUES.get_unchecked(i) }) {
+                            metadata.locale = Some(fallback_iterator.take());
+                            break payload;
+                        }
+                        fallback_iterator.step();
+                    }
+                };
+                Ok(icu_provider::DataResponse { payload: Some(icu_provider::DataPayload::from_static_ref(payload)), metadata })
+            }
+        }
+    };
+}
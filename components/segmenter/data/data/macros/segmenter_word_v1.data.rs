@@ -9,7 +9,7 @@ macro_rules! __impl_segmenter_word_v1 {
         #[clippy::msrv = "1.66"]
         impl $provider {
             #[doc(hidden)]
-            pub const SINGLETON_SEGMENTER_WORD_V1: &'static <icu::segmenter::provider::WordBreakDataV1Marker as icu_provider::DataMarker>::Yokeable = &icu::segmenter::provider::RuleBreakDataV1 { property_table: icu::segmenter::provider::RuleBreakPropertyTable(icu::collections::codepointtrie::CodePointTrie::from_parts(icu::collections::codepointtrie::CodePointTrieHeader { high_start: 918016u32, shifted12_high_start: 225u16, index3_null_offset: 1130u16, data_null_offset: 14u32, null_value: 0u32, trie_type: icu::collections::codepointtrie::TrieType::Small }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0@\0{\0\xBB\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\x1B\x01[\x01k\x01\xAB\x01\xCE\x01\xF3\0\xF3\0\x0C\x02\xF3\0\x1C\x02M\x02\x84\x02\xC2\x02\x02\x037\x03\xF3\0h\x03\xA8\x03\xDD\x03\xF7\x037\x04w\x04\xB7\x04\xEF\x04%\x05a\x05\x9F\x05\xDE\x05\x1C\x06[\x06\x99\x06\xD8\x06\x16\x07V\x07\x94\x07\xD2\x07\x10\x08P\x08\x8E\x08\xCE\x08\x0C\tL\t\x8A\t\xCA\t\n\nI\n\x89\n\xC8\n\x08\x0BH\x0B\x88\x0B\xC3\x0B\0\x0C\r\x0B'\x0B7\x0BM\x0Bm\x0B\x8B\x0B\xA3\x0B\xC2\x0B\xE2\x0B\xE2\x0B\xEF\x0B\x0C\x0C,\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C^\x0C\0\0\x10\0 \x000\0@\0P\0`\0p\0{\0\x8B\0\x9B\0\xAB\0\xBB\0\xCB\0\xDB\0\xEB\0\xF3\0\x03\x01\x13\x01#\x01\xF3\0\x03\x01\x13\x01#\x01\xF3\0\x03\x01\x13\x01#\x01\xF3\0\x03\x01\x13\x01#\x01\x1B\x01+\x01;\x01K\x01[\x01k\x01{\x01\x8B\x01k\x01{\x01\x8B\x01\x9B\x01\xAB\x01\xBB\x01\xCB\x01\xDB\x01\xCE\x01\xDE\x01\xEE\x01\xFE\x01\xF3\0\x03\x01\x13\x01#\x01\xF3\0\x03\x01\x13\x01#\x01\x0C\x02\x1C\x02,\x02<\x02\xF3\0\x03\x01\x13\x01#\x01\x1C\x02,\x02<\x02L\x02M\x02]\x02m\x02}\x02\x84\x02\x94\x02\xA4\x02\xB4\x02\xC2\x02\xD2\x02\xE2\x02\xF2\x02\x02\x03\x12\x03\"\x032\x037\x03G\x03W\x03g\x03\xF3\0\x03\x01\x13\x01#\x01h\x03x\x03\x88\x03\x98\x03\xA8\x03\xB8\x03\xC8\x03\xD8\x03\xDD\x03\xED\x03\xFD\x03\r\x04\xF7\x03\x07\x04\x17\x04'\x047\x04G\x04W\x04g\x04w\x04\x87\x04\x97\x04\xA7\x04\xB7\x04\xC7\x04\xD7\x04\xE7\x04\xEF\x04\xFF\x04\x0F\x05\x1F\x05%\x055\x05E\x05U\x05a\x05q\x05\x81\x05\x91\x05\x9F\x05\xAF\x05\xBF\x05\xCF\x05\xDE\x05\xEE\x05\xFE\x05\x0E\x06\x1C\x06,\x06<\x06L\x06[\x06k\x06{\x06\x8B\x06\x99\x06\xA9\x06\xB9\x06\xC9\x06\xD8\x06\xE8\x06\xF8\x06\x08\x07\x16\x07&\x076\x07F\x07V\x07f\x07v\x07\x86\x07\x94\x07\xA4\x07\xB4\x07\xC4\x07\xD2\x07\xE2\x07\xF2\x07\x02\x08\x10\x08 \x080\x08@\x08P\x08`\x08p\x08\x80\x08\x8E\x08\x9E\x08\xAE\x08\xBE\x08\xCE\x08\xDE\x08\xEE\x08\xFE\x08\x0C\t\x1C\t,\t<\tL\t\\\tl\t|\t\x8A\t\x9A\t\xAA\t\xBA\t\xCA\t\xDA\t\xEA\t\xFA\t\n\n\x1A\n*\n:\nI\nY\ni\ny\n\x89\n\x99\n\xA9\n\xB9\n\xC8\n\xD8\n\xE8\n\xF8\n\x08\x0B\x18\x0B(\x0B8\x0BH\x0BX\x0Bh\x0Bx\x0B\x88\x0B\x98\x0B\xA8\x0B\xB8\x0B\xC3\x0B\xD3\x0B\xE3\x0B\xF3\x0B\0\x0C\x10\x0C \x0C0\x0CJ\nJ\nJ\nJ\n<\x07J\nJ\nJ\nJ\n@\x0C\xF3\0\xF3\0P\x0C\xF3\0\xF3\0\xC7\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0`\x0Cp\x0C\xF3\0\xF3\0`\x0C\xF3\0\xF3\0h\x0Cx\x0C\xCB\0\xF3\0\xF3\0\xF3\0x\x0C\xF3\0\xF3\0\xF3\0\x80\x0C\x0E\0\x0E\0\xF3\0\x0E\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\x90\x0C@\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0W\x02\xF3\0\xA0\x0Cp\0\xF3\0\xF3\0\xF3\0\xF3\0\xD6\t\xA7\x0C\xF3\0\xB7\x0C\xF3\0\xC6\x0C\xF3\0\xD6\x0C\xC5\0\xE6\x0CJ\nJ\nJ\nJ\nJ\n\xF6\x0C<\x07\x0E\0\x04\r<\x07\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xA7\x0C\x14\r\xF3\0\x1B\r\xF3\0\xF3\0\xF3\0\xF3\0+\r\xF3\0L\0\xF4\x0B\xF4\x0B1\x04J\nv\n;\rJ\nJ\nx\nJ\nK\r[\r\x0E\0\x0E\0\xF3\0k\rJ\nJ\nJ\nu\nJ\n{\r<\x07<\x07v\n[\x01\xB3\x02\x0E\0\x0E\0\x0E\0\x11\x02\xF3\0\xF3\0W\x01\x8A\r<\x07E\x08\xA1\x04\x13\x02\xF3\0\x9A\r7\x04\xF3\0\xF3\0U\x01\xA1\x04\xF3\0\xF3\0W\x01\xAA\r\xBA\r7\x04\xF3\0M\0\xA7\x0C\xF3\0\xF3\0Y\x02\x0E\0\xD8\x0B\xCA\r\xD3\r\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0[\x01[\x01[\x01[\x01\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\x90\x0C\xF3\0\xF3\0\x90\x0C\xE3\r\xF3\0M\0\xF3\0\xF3\0\xF3\0\x02\x06\xF3\r\x03\x0EN\0\xF3\r\x13\x0E#\x0E/\x0E?\x0EN\x0EZ\x0Ej\x0Ez\x0E\x0E\0N\0\x0E\0\x0E\0\x0E\0[\x01[\x01\xA4\x04\x87\x0E\x93\x0E\xA1\x0E\xB0\x0E\xC0\x0E\x0E\0\xF3\0\xF3\0\xA7\x0C\xCF\x0E\xD9\x0E\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xE4\x0E\xF0\x0E\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xF0\x0E\x0E\0\x0E\0\x0E\0\x95\0\x0E\0\xF9\x0E\x05\x0F\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\x003\x01\x15\x0F\xF3\0)\x01\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xE4\x0E\xF2\x0E%\x0F\x0E\0\x0E\0*\x0F:\x0F=\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FK\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0F[\x0Fh\x0Fm\x0Fz\x0F\x86\x0F\x95\x0F\xA2\x0F\x0E\0\x0E\0\xAD\x0F\xBC\x0F\xBD\x0F\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xDE\x0E\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xAD\x0F\xC1\x0F\x0E\0\x0E\0\x0E\0\xCD\x0F\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xDD\x0F\xEC\x0F\xF3\0\xF3\0P\x0C\xF3\0\xF3\0\xF3\0\xA8\x0CA\x08\xF3\0\xFC\x0F\0\x06\0\x06\0\x06\0\x06[\x01[\x01\x0E\0\x0E\0\xA8\x04\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0J\n\x0C\x10J\nJ\nJ\nJ\nJ\n$\x0BJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\n\x17\x10\x0E\0\x0E\0'\x10\x0E\x006\x10F\x10I\nJ\nJ\nJ\nJ\nV\x10f\x10f\x10f\x10f\x10f\x10k\x10<\0\xF3\0\xF3\0@\0\xF3\0\xF3\0\xF3\0\xF3\0L\0\x0E\0\xF3\0\xF3\0\x0E\0\x0E\0\x0E\0f\x10\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0{\x10\x0E\0\x0E\0\x0E\0f\x10f\x10g\x10f\x10f\x10f\x10f\x10f\x10\x8B\x10\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0J\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\n\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0N\0\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0\xF3\0M\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0N\0\xF3\0B\x06\x0E\0\xF3\0\xF3\0L\x01\x9B\x10\xF3\0M\x01\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xA3\x04\xAF\x04\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0p\0\xAA\x10\x0E\0?\0\xBA\x10\xF3\0\xC7\x10\x0E\0\xF3\0\xF3\0\xF3\0\xD7\x10\x14\x02\xF3\0\xF3\0W\x01\xE7\x10<\x07[\x01\xF7\x107\x04\xF3\0\x07\x11\xF3\0T\x01\xA1\x04\xF3\0N\0\x12\x02\xF3\0\xF3\0X\x01\x14\x11<\x07J\n$\x11\xF3\0\xF3\0R\x01\x0E\x114\x11<\x07J\nJ\nJ\nJ\nJ\nJ\n%\x0B?\nP\x01B\x11Q\x11*\r\0\x06\xF3\0\xF3\0\xF3\0)\x01\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0a\x11<\x07\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xD7\x10\xF3\0q\x11\xF3\0\xF3\0O\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0J\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nv\nJ\nJ\nJ\nJ\nJ\nJ\nK\r\x0E\0\x0E\0\xFC\x0Fy\x11\x88\x11\x92\x11\xA2\x11\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xB2\x11\x0E\0>\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0M\0\x0E\0\xF3\0\xF3\0\xF3\0\xF3\0?\0\xF3\0\xF3\0\xC2\x11\x0E\0\x0E\0O\0[\x01\xD2\x11[\x01\xDF\x11\xE4\x11\xF4\x11\x0E\0\xCD\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\x04\x12\x14\x120\0@\0P\0@\0p\0#\x12f\x10f\x10)\x12\xF3\0L\0P\x119\x12\x0E\0F\x12\xC6\0\xF3\0\xCB\0V\x12M\0M\0\x0E\0\x0E\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0p\0\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0\xF3\0\xF3\0e\x12\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0j\x12\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0N\0\xF3\0\xF3\0\xF3\0\xA7\x03\xA4\x04\x0E\0\xF3\0\xF3\0\xAA\x04\xF3\0p\0\xF3\0\xF3\0z\x12\xF3\0M\0\xF3\0\xF3\0\x8A\x12d\x12\x0E\0\x0E\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0M\0<\x07\xF3\0\xF3\0\x8A\x12\xF3\0O\0\xF3\0\xF3\0\xC2\x11\xF3\0\xF3\0\xF3\0\xD7\x10\xC7\0\xC7\0\x97\x12\xD0\0\xA5\x12\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xFC\x0F\xF3\0+\r\xC2\x11\x0E\0\xCC\0\xF3\0\xF3\0\xB5\x12\x0E\0\x0E\0\x0E\0\x0E\0\xC5\x12\xF3\0\xF3\0\xCF\x12\xF3\0+\r\xF3\0\xFC\x0F\xF3\0L\0\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0\xDE\x12\xF3\0+\r\xF3\0)\x01\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0\xF3\0\xF3\0+\x01\x0E\0\x0E\0\x0E\0\x0E\0\xEE\x12\xE2\x06\xF3\0\xFE\x12\x0E\0\x0E\0\xF3\0N\0\xF3\0N\0\x0E\0\x0E\0\xCA\0\xF3\0\x0E\x13\x0E\0\xF3\0\xF3\0\xF3\0+\r\xF3\0+\r\xF3\0g\x12\xF3\0\xB2\x11\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0\xF3\0\xF3\0\xF3\0\xA7\x0C\x0E\0\x0E\0\x0E\0\xF3\0\xF3\0\xF3\0g\x12\xF3\0\xF3\0\xF3\0g\x12\xF3\0\xF3\0\x1E\x13<\x07\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0\xF3\0.\x13\xB2\x11\x0E\0\x0E\0\x0E\0C\x08\xF3\0N\0A\x0B\xF3\0U\x01\xA4\x04\x0E\0\xF3\0>\x13\x0E\0\x0E\0\xF3\0e\x12\x0E\0\xF3\0\xFC\x0F\x13\x02\xF3\0\xF3\0S\x01\x0E\x11\x0E\x001\x04N\x13\x13\x02\xF3\0\xF3\0]\x13k\x13\xF3\0\xA7\x0C<\x07\x13\x02\xF3\0T\x01{\x13\x8B\x13\xF3\0\xF3\0\x9B\x13\x13\x02\xF3\0\xF3\0X\x01\xAB\x13\xBB\x13\x0E\0\x0E\0\xF3\0\xD0\0O\x01\xCB\x13\xDA\x13\x0E\0\x0E\0\x0E\0\xEA\x13\xC4\0\xA7\x0C\xF3\0\xF3\0L\x01\xF5\x0B<\x07\xFA\x13\xEE\x05\xC9\0\t\x14\x94\x07\x19\x14'\x14\xA0\x04\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0\xF3\0\xF3\0V\x017\x14G\x14\xB2\x11\x0E\0\xF3\0\xF3\0\xF3\0[\x01W\x14<\x07\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0\xF3\0L\x01g\x14\xA4\x04w\x14\x0E\0\x0E\0\xF3\0\xF3\0\xF3\0[\x01\x84\x14<\x07\x0E\0\x0E\0\xF3\0\xF3\0P\x01 \x04<\x07\x0E\0\x0E\0\x0E\0J\n\xFB\nx\n\x94\x14\xA3\x14\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0\xF3\0O\x01\xF5\x0B\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0\xF3\0\xF3\0\xF3\0<\x07\xA8\x04\xB3\x14\x96\x12\xF3\0\xC3\x14\xD1\x14<\x07\x0E\0\x0E\0\x0E\0\x0E\0\xE1\x14\xF3\0\xF3\0\xF0\x14\xFF\x14\x0E\0\x0F\x15\xF3\0\xF3\0\x1C\x15+\x15;\x15\xF3\0\xF3\0Q\x01K\x15\x0E\0\xF3\0\xF3\0\xF3\0\xF3\0\xA7\x0C\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xC9\0\xF3\0L\x01\xD4\x0B\xA7\x03<\x07\x0E\0?\0\xF3\0\x93\x02\xD3\x0B\x0E\x11\x0E\0\x0E\0\x0E\0\x0E\0\x02\x07\xF3\0\xF3\0[\x15j\x15<\x07\x94\x12\xF3\0z\x15\x87\x15<\x07\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0\x97\x15\xA7\x15\xD1\0\xF3\0\xB3\x15\xA2\x04<\x07\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xA7\x03\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0)\x01\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0L\0\x0E\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xD7\x10\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xA7\x03\xF3\0\xF3\0\xF3\0\xC3\x15\xD3\x15\xE7\x10\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0\xF3\0\xF3\0\xF3\0\xFC\x0F\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0\xF3\0\xF3\0\xA7\x0C\xF3\0L\0<\x07\xF3\0\xF3\0\xF3\0\xF3\0L\0<\x07\xF3\0M\0\xA0\x04\xF3\0\xF3\0\xF3\0\x0E\x11\xD7\x10<\x07>\0\xDA\x04\xF3\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0\xF3\0\xF3\0\xF3\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0\xF3\0\xF3\0\xF3\0\x01\x08Z\x01[\x01[\x01\xDB\x15\x13\x02\x0E\0\x0E\0\x0E\0\x0E\0\xEB\x15&\x0B\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xFB\x15\x0B\x16J\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\n\x1B\x16)\x16\x0E\09\x16E\x16\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0p\0N\0\xA7\x0CU\x16e\x16\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0[\x01[\x01\xDA\x03[\x01\x0E\x11\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0p\x16}\x16\x8A\x16\x0E\0\x96\x16\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xA4\x16\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xCD\0\xF3\0\xF3\0\xF3\0\xC5\0\xB2\x16\xC0\x16\xCE\0\xF3\0\xF3\0\xF3\0c\x0C\x7F\x06\xF3\0\xCD\x16\xDD\x16\xD1\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xE7\x16\xF3\0\xD1\0\xC7\0\xF3\0\xC7\0\xF3\0\xCD\0\xF3\0\xCD\0L\0\xF3\0L\0\xF3\0\xC9\0\xF3\0\xC9\0\xF3\0\xF4\x16\x02\x17\x02\x17\x02\x17[\x01[\x01[\x01\x12\x17[\x01[\x01\xF3\x0B\"\x08#\x08E\x08\x94\x02\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0L\0&\r\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xD4\x0B\x1D\x17+\x17\xF3\0\xF3\0\xF3\0M\0\x0E\0A\x08\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0\xF3\0N\0;\x17K\x17\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0[\x17\x0E\0\xF3\0\xF3\0O\x01<\x07\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0O\x01<\x07\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0k\x17L\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0e\x12\x0E\x11\x0E\0\x0E\0\xF3\0\xF3\0\xF3\0\xF3\0{\x17<\x07\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xCE\0\xF3\0\x8A\x17\x97\x17\xA5\x17\xB5\x17\xC3\x17\xCB\x17\xC8\0O\0\xDA\x17O\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0A\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0F(\x0F\x0E\0\x95\0\xF3\0)\x01\xF3\0\xE5\x17\xF3\x17\x03\x18\x12\x18(\x0FA\x0FA\x0FA\x0F\"\x18(\x188\x18H\x18\x95\0V\x18\xF9\x0EA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0Fb\x18A\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FC\x0Fr\x18A\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0F\x0E\0\x0E\0\x0E\0A\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0F\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0t\x18\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0s\x18A\x0FA\x0F)\x0F\x0E\0\x0E\0\x0E\0\x84\x18Q\x0F\x0E\0\x0E\0\x84\x18\x0E\0'\x0FA\x0FA\x0FA\x0FA\x0FA\x0F)\x0FA\x0FA\x0F\x8C\x18:\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0F\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0<\x07A\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FC\x0FJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\n\x0E\0\x0E\0J\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nK\rJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nv\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\n&\x0BJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\n'\x0B\x0E\0J\nv\n\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0J\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\ny\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\n\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x9C\x18\x0E\0[\x01[\x01[\x01[\x01[\x01[\x01\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0[\x01[\x01[\x01[\x01[\x01[\x01[\x01[\x01[\x01[\x01[\x01[\x01[\x01[\x01[\x01\x0E\0y\0\x8D\0\xAD\0\xCD\0\xED\0\r\x01-\x01M\x01m\x01\x89\x01\xA9\x01\xC3\x01\xE3\x01\x03\x02#\x02C\x02c\x02~\x02\x9E\x02\xBE\x02\xDA\x02\xEE\x02\x0E\x03.\x03N\x03n\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x92\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\xB2\x03\xB2\x03\xCA\x03\xEA\x03\n\x04*\x04\xB2\x03\xB2\x03\xB2\x03\xB2\x03\xB2\x03\xB2\x03\xB2\x03\xB2\x03\xB2\x03\xB2\x03\xB2\x03J\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04z\x04\x94\x04\xB2\x04\xD2\x04\xF2\x04\x12\x052\x05R\x05r\x05\x92\x05\xB2\x05\xCA\x05\xEA\x05\n\x06*\x06J\x06j\x06\x8A\x06\xAA\x06\xC5\x06\xB2\x03\xE5\x06\x05\x07j\x04j\x04j\x04j\x04\x1A\x07\xB2\x03\xB2\x03:\x07j\x04j\x04j\x04j\x04j\x04\xB2\x03Z\x07j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04\xB2\x03z\x07j\x04\x96\x07j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04\xB6\x07\xD6\x07j\x04j\x04j\x04j\x04j\x04\xF6\x07j\x04j\x04j\x04j\x04j\x04j\x04j\x04\x06\x08\x1B\x087\x08W\x08m\x08j\x04\x8D\x08j\x04\x9D\x08\xBD\x08\xD4\x08\xE7\x08\xF7\x08\x17\tj\x04j\x047\tW\tw\t\x97\t\xB2\t\xD2\t\xE7\t\x07\n\x08\n\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03(\n\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x038\nW\n\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03m\n\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03x\nj\x04j\x04j\x04j\x04j\x04j\x04\x8E\x03\x98\nj\x04j\x04\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\xB8\n\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\xCD\nj\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04\xED\n") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\0\0\0\0\0\0\0\0\x02\x03\x03\x01\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x12\0\x0C\0\0\0\0\x0B\0\0\0\0\x0F\0\r\0\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x0E\x0F\0\0\0\0\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\0\0\0\0\x11\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\0\0\0\0\0\x03\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x13\n\0\0\x07\x13\0\0\0\0\0\0\n\0\x0E\0\0\n\0\0\0\0\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\0\0\0\0\0\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\n\n\n\n\n\0\n\n\0\0\n\n\n\n\x0F\n\0\0\0\0\0\0\n\x0E\n\n\n\0\n\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\0\n\n\n\n\n\n\n\n\n\0\x04\x04\x04\x04\x04\x04\x04\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\0\0\n\n\n\n\0\n\x0E\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\x0F\n\0\0\0\0\0\0\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\0\x04\x04\0\x04\x04\0\x04\0\0\0\0\0\0\0\0\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\0\0\0\0\t\t\t\t\n\x0E\0\0\0\0\0\0\0\0\0\0\0\x07\x07\x07\x07\x07\x07\0\0\0\0\0\0\x0F\x0F\0\0\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\0\x07\0\0\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\0\x10\x0F\0\n\n\x04\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\0\n\x04\x04\x04\x04\x04\x04\x04\x07\0\x04\x04\x04\x04\x04\x04\n\n\x04\x04\0\x04\x04\x04\x04\n\n\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\n\n\n\0\0\n\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x07\n\x04\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\0\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\n\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\x04\x04\x04\x04\x04\x04\x04\x04\x04\n\n\0\0\x0F\0\n\0\0\x04\0\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\x04\x04\x04\x04\n\x04\x04\x04\x04\x04\x04\x04\x04\x04\n\x04\x04\x04\n\x04\x04\x04\x04\x04\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\x04\x04\x04\0\0\0\0\n\n\n\n\n\n\n\n\n\n\n\0\0\0\0\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\0\n\n\n\n\n\n\0\x07\x07\0\0\0\0\0\0\x04\x04\x04\x04\x04\x04\x04\x04\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x07\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\x04\x04\x04\n\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\n\x04\x04\x04\x04\x04\x04\x04\n\n\n\n\n\n\n\n\n\n\x04\x04\0\0\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\x04\x04\x04\0\n\n\n\n\n\n\n\n\0\0\n\n\0\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\0\n\n\n\n\n\n\n\0\n\0\0\0\n\n\n\n\0\0\x04\n\x04\x04\x04\x04\x04\0\0\x04\x04\0\0\x04\x04\x04\n\0\0\0\0\0\0\0\0\x04\0\0\0\0\n\n\0\n\n\n\x04\x04\0\0\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\n\n\0\0\0\0\0\0\0\0\0\0\n\0\x04\0\x04\x04\x04\0\n\n\n\n\n\n\0\0\0\0\n\n\0\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\0\n\n\n\n\n\n\n\0\n\n\0\n\n\0\n\n\0\0\x04\0\x04\x04\x04\0\0\0\0\x04\x04\0\0\x04\x04\x04\0\0\0\x04\0\0\0\0\0\0\0\n\n\n\n\0\n\0\0\0\0\0\0\0\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x04\x04\n\n\n\x04\0\0\0\0\0\0\0\0\0\0\x04\x04\x04\0\n\n\n\n\n\n\n\n\n\0\n\n\n\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\0\n\n\n\n\n\n\n\0\n\n\0\n\n\n\n\n\0\0\x04\n\x04\x04\x04\x04\x04\x04\0\x04\x04\x04\0\x04\x04\x04\0\0\n\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\n\n\x04\x04\0\0\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\0\0\0\0\0\0\0\0\0\n\x04\x04\x04\x04\x04\x04\0\x04\x04\x04\0\n\n\n\n\n\n\n\n\0\0\n\n\0\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\0\n\n\n\n\n\n\n\0\n\n\0\n\n\n\n\n\0\0\x04\n\x04\x04\x04\x04\x04\0\0\x04\x04\0\0\x04\x04\x04\0\0\0\0\0\0\0\x04\x04\x04\0\0\0\0\n\n\0\n\n\n\x04\x04\0\0\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\0\n\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x04\n\0\n\n\n\n\n\n\0\0\0\n\n\n\0\n\n\n\n\0\0\0\n\n\0\n\0\n\n\0\0\0\n\n\0\0\0\n\n\n\0\0\0\n\n\n\n\n\n\n\n\n\n\n\n\0\0\0\0\x04\x04\x04\0\0\0\x04\x04\x04\0\x04\x04\x04\x04\0\0\n\0\0\0\0\0\0\x04\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x04\x04\x04\x04\x04\n\n\n\n\n\n\n\n\0\n\n\n\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\0\0\x04\n\x04\x04\x04\x04\x04\0\x04\x04\x04\0\x04\x04\x04\x04\0\0\0\0\0\0\0\x04\x04\0\n\n\n\0\0\n\0\0\n\n\x04\x04\0\0\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\n\x04\x04\x04\0\n\n\n\n\n\n\n\n\0\n\n\n\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\0\n\n\n\n\n\n\n\n\n\n\0\n\n\n\n\n\0\0\x04\n\x04\x04\x04\x04\x04\0\x04\x04\x04\0\x04\x04\x04\x04\0\0\0\0\0\0\0\x04\x04\0\0\0\0\0\0\n\n\0\n\n\x04\x04\0\0\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\0\n\n\x04\0\0\0\0\0\0\0\0\0\0\0\0\x04\x04\x04\x04\n\n\n\n\n\n\n\n\n\0\n\n\n\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\x04\x04\n\x04\x04\x04\x04\x04\0\x04\x04\x04\0\x04\x04\x04\x04\n\0\0\0\0\0\n\n\n\x04\0\0\0\0\0\0\0\n\n\n\x04\x04\0\0\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\0\0\0\0\0\0\0\0\0\0\n\n\n\n\n\n\0\x04\x04\x04\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\0\0\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\0\n\n\n\n\n\n\n\n\n\0\n\0\0\n\n\n\n\n\n\n\0\0\0\x04\0\0\0\0\x04\x04\x04\x04\x04\x04\0\x04\0\x04\x04\x04\x04\x04\x04\x04\x04\0\0\0\0\0\0\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\0\0\x04\x04\0\0\0\0\0\0\0\0\0\0\0\0\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\0\0\0\0\0\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\0\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x14\x14\0\x14\0\x14\x14\x14\x14\x14\0\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\0\x14\0\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\0\0\x14\x14\x14\x14\x14\0\x14\0\x14\x14\x14\x14\x14\x14\x14\0\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\0\0\x14\x14\x14\x14\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\n\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x04\x04\0\0\0\0\0\0\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\0\0\0\0\0\0\0\0\0\0\0\x04\0\x04\0\x04\0\0\0\0\x04\x04\n\n\n\n\n\n\n\n\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\0\0\0\0\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\0\x04\x04\n\n\n\n\n\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\0\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\0\0\0\0\0\0\x04\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x14\x14\x14\x14\x14\x14\n\n\n\n\n\n\0\n\0\0\0\0\0\n\0\0\n\n\n\n\n\n\n\n\n\0\n\n\n\n\0\0\n\n\n\n\n\n\n\0\n\0\n\n\n\n\0\0\n\n\n\n\n\n\n\n\n\n\n\0\0\x04\x04\x04\n\n\n\n\n\n\0\0\n\n\n\n\n\n\0\0\x12\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\0\0\0\0\0\0\0\n\n\x04\x04\x04\x04\0\0\0\0\0\0\0\0\0\n\n\x04\x04\x04\0\0\0\0\0\0\0\0\0\0\0\n\n\x04\x04\0\0\0\0\0\0\0\0\0\0\0\0\n\0\x04\x04\0\0\0\0\0\0\0\0\0\0\0\0\x14\x14\x14\x14\0\0\0\x14\0\0\0\0\x14\x14\0\0\0\0\0\0\0\0\0\0\0\x04\x04\x04\x07\x04\n\n\n\n\n\x04\x04\n\n\n\n\n\n\n\n\n\x04\n\0\0\0\0\0\n\n\n\n\n\n\0\0\0\0\0\0\0\0\0\0\x14\x14\x14\x14\x14\0\0\0\0\0\0\0\0\0\0\0\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\0\0\0\0\0\0\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x14\0\0\0\x14\x14\n\n\n\n\n\n\n\x04\x04\x04\x04\x04\0\0\0\0\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\0\0\x04\x04\x04\x04\x04\n\n\n\n\n\n\n\n\0\0\0\n\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\n\n\x04\x04\x04\x04\x04\x04\x04\x04\0\0\0\0\0\0\0\0\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\0\0\0\n\n\n\x04\x04\x04\x04\x04\x04\x04\x04\x04\n\n\n\n\x04\n\n\x04\x04\x04\n\0\0\0\0\0\n\n\n\n\n\n\n\n\0\n\0\n\0\n\0\n\0\0\n\n\n\0\n\n\n\n\n\n\n\0\0\0\n\n\n\n\0\0\n\n\n\n\n\n\0\0\0\0\x12\x12\x12\x12\x12\x12\x12\0\x12\x12\x12\0\x04\x05\x07\x07\0\0\0\0\0\0\0\0\r\r\0\0\0\0\0\0\r\0\0\x0E\x03\x03\x07\x07\x07\x07\x07\x11\0\0\0\0\0\0\0\0\0\0\0\0\x13\0\0\x11\0\0\0\x0F\0\0\0\0\x13\0\0\0\0\0\0\x11\0\0\0\0\0\0\0\0\0\0\x12\x07\x07\x07\x07\x07\0\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\0\n\0\0\0\0\0\0\0\0\0\0\0\0\0\n\0\0\0\0\n\0\0\n\n\n\n\n\n\0\n\0\0\0\n\n\n\n\n\0\0\x13\0\n\0\n\0\n\0\n\n\n\n\0\n\n\n\n\n\n\n\n\n\x13\0\0\n\n\n\n\0\0\0\0\0\n\n\n\n\n\0\0\0\0\n\0\0\0\0\x13\x13\x13\x13\x13\x13\0\0\0\0\0\0\0\0\0\x13\x13\0\0\0\0\0\0\0\0\0\0\x13\x13\0\0\0\0\0\0\0\0\x13\0\0\0\0\0\0\0\0\0\x13\x13\x13\x13\x13\x13\x13\0\0\0\0\x13\x13\x13\0\0\0\0\0\n\n\x13\n\n\n\n\n\n\n\n\n\n\n\n\n\x13\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x13\x13\x13\x13\0\x13\x13\x13\x13\x13\x13\0\x13\x13\x13\x13\x13\x13\x13\x13\x13\x13\x13\x13\x13\x13\x13\x13\0\0\0\0\0\0\0\0\0\0\x13\x13\x13\x13\x13\x13\0\0\x13\x13\x13\x13\x13\x13\x13\x13\0\x13\0\x13\0\0\0\0\0\0\x13\0\0\0\0\0\0\0\x13\x13\0\0\0\0\0\0\0\0\0\0\0\x13\0\0\x13\0\0\0\0\x13\0\x13\0\0\0\x13\x13\x13\0\x13\0\0\0\0\0\0\0\0\x13\x13\x13\x13\x13\0\0\0\0\0\0\0\0\x13\x13\x13\0\0\0\0\0\0\0\0\x13\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x13\x13\0\0\0\0\x13\0\0\0\0\0\0\0\0\0\0\n\n\n\n\n\0\0\0\0\0\0\n\n\n\n\x04\x04\n\n\0\0\0\0\0\0\0\0\0\0\0\0\n\n\n\n\n\n\n\0\0\0\0\0\0\0\0\0\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\0\x14\x14\x14\x14\x14\x14\0\0\0\0\0\0\0\0\0\0\x12\0\0\0\0\x14\0\x14\0\0\0\0\0\0\0\0\x14\x14\x14\x14\x14\x14\x14\x14\x14\x04\x04\x04\x04\x04\x04\x13\x08\x08\x08\x08\x08\0\0\x14\x14\x14\x14\n\x13\0\0\x14\x14\x14\x14\x14\x14\x14\0\0\x04\x04\x08\x08\x14\x14\x14\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\0\x08\x08\x08\x08\0\0\0\0\0\0\0\x13\0\x13\0\0\0\0\0\0\x08\x08\x08\x08\x08\x08\x08\x08\0\0\0\0\0\0\0\0\x04\x04\x04\0\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\0\n\n\0\n\0\n\n\n\n\n\0\0\0\0\0\0\n\n\x04\n\n\n\x04\n\n\n\n\x04\n\n\n\n\x04\x04\x04\x04\x04\0\0\0\0\x04\0\0\0\n\n\n\n\0\0\0\0\0\0\0\0\0\0\0\0\x04\x04\x04\x04\x04\x04\0\0\0\0\0\0\0\0\0\0\x04\x04\n\n\n\n\n\n\0\0\0\n\0\n\n\x04\n\n\n\n\n\n\x04\x04\x04\x04\x04\x04\x04\x04\0\0\0\0\0\0\0\0\0\0\0\0\0\0\n\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x14\x14\x14\x14\x14\0\n\n\n\x04\n\n\n\n\n\n\n\n\x04\x04\0\0\n\n\n\x04\x04\0\0\0\0\0\0\0\0\0\n\n\n\n\n\n\0\0\n\n\n\n\n\n\0\n\n\n\x04\x04\x04\x04\x04\x04\x04\x04\0\x04\x04\0\0\n\n\n\n\n\n\n\0\0\0\0\n\n\n\n\n\0\0\0\0\0\t\x04\t\t\t\t\t\t\t\t\t\0\t\t\t\t\t\t\t\0\t\t\t\t\t\0\t\0\t\t\0\t\t\0\t\t\t\t\t\t\t\t\t\t\n\n\0\0\0\0\0\0\0\0\0\0\0\0\0\0\n\n\n\n\n\n\n\n\0\0\0\0\0\0\0\0\x0F\0\0\x0E\x0F\0\0\0\0\0\0\0\0\0\0\0\x11\x11\0\0\0\0\0\0\0\0\0\0\0\0\0\x11\x11\x11\x0F\0\r\0\x0F\x0E\0\0\0\0\0\0\0\0\0\0\n\n\n\n\n\n\n\n\n\n\n\n\n\0\0\x07\0\0\0\0\0\0\0\r\0\0\0\0\x0F\0\r\0\0\0\0\0\0\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x04\x04\0\0\n\n\n\n\n\n\0\0\n\n\n\0\0\0\0\0\0\0\0\0\x07\x07\x07\0\0\0\0\n\n\n\n\n\n\n\n\n\n\n\0\n\n\0\n\n\n\n\n\0\0\0\0\0\0\0\0\0\0\0\0\0\x04\0\0\n\n\n\n\n\n\x04\x04\x04\x04\x04\0\0\0\0\0\n\n\n\n\0\0\0\0\n\n\n\n\n\n\n\n\0\n\n\0\n\n\n\n\n\n\n\n\n\0\n\n\n\n\n\n\n\0\n\n\0\0\0\n\0\n\n\n\n\n\n\n\n\n\0\0\0\0\0\n\n\n\n\n\n\0\0\n\0\n\n\n\n\n\n\0\n\n\0\0\0\n\0\0\n\n\n\0\n\n\0\0\0\0\0\0\0\0\0\0\n\x04\x04\x04\0\x04\x04\0\0\0\0\0\x04\x04\x04\x04\n\n\n\n\n\n\0\0\x04\x04\x04\0\0\0\0\x04\n\n\n\n\n\x04\x04\0\0\0\0\0\0\0\0\0\n\n\n\n\x04\x04\x04\x04\0\0\0\0\0\0\0\0\n\n\n\n\n\n\n\n\n\n\0\x04\x04\0\0\0\n\n\x04\x04\x04\x04\0\0\0\0\0\0\0\0\0\0\x04\n\n\x04\x04\n\0\0\0\0\0\0\0\0\0\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\0\0\x07\0\0\x04\0\0\0\0\0\0\0\0\0\0\x07\0\0\x04\x04\x04\x04\x04\0\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\0\0\0\0\n\x04\x04\n\0\0\0\0\0\0\0\0\n\n\n\x04\0\0\n\0\0\0\0\0\0\0\0\0\x04\n\n\n\n\0\0\0\0\x04\x04\x04\x04\0\x04\x04\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\n\0\n\0\0\0\x04\x04\x04\x04\x04\x04\x04\x04\0\0\0\0\0\0\x04\n\x04\0\0\0\0\0\0\0\0\0\0\0\0\0\0\n\n\n\n\n\n\n\0\n\0\n\n\n\n\0\n\x04\x04\x04\x04\0\n\n\n\n\n\n\n\n\0\0\n\0\n\n\0\n\n\n\n\n\0\x04\x04\n\x04\x04\n\0\0\0\0\0\0\x04\0\0\0\0\0\n\n\n\x04\x04\0\0\x04\x04\x04\x04\x04\x04\x04\0\0\0\x04\x04\x04\x04\x04\x04\x04\n\n\n\n\0\0\0\0\0\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\0\0\0\0\x04\n\x04\x04\x04\x04\n\n\0\n\0\0\0\0\0\0\0\0\x04\x04\x04\x04\x04\x04\0\0\x04\x04\x04\x04\x04\x04\x04\x04\0\0\0\0\0\0\0\0\n\n\n\n\x04\x04\0\0\0\n\0\0\0\0\0\0\0\0\0\0\0\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x14\x14\0\0\0\x14\x14\x14\x14\x14\x14\x14\0\0\0\0\0\0\0\0\0\n\n\n\n\n\n\n\0\0\n\0\0\n\n\n\n\x04\x04\x04\x04\x04\x04\0\x04\x04\0\0\x04\x04\x04\x04\n\x04\x04\0\0\0\0\0\0\0\0\0\0\0\0\n\n\n\n\n\n\n\n\0\0\n\n\n\n\n\n\x04\x04\x04\x04\x04\x04\x04\0\0\x04\x04\x04\x04\x04\x04\n\0\n\x04\0\0\0\0\0\0\0\0\0\0\0\n\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\n\n\n\n\n\x04\x04\x04\x04\x04\x04\x04\n\x04\x04\x04\x04\0\0\0\0\0\0\0\x04\0\0\0\0\0\0\0\0\n\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\n\n\n\n\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\0\0\0\n\0\0\n\x04\x04\x04\x04\x04\x04\0\0\0\x04\0\x04\x04\0\x04\x04\x04\x04\x04\x04\n\x04\0\0\0\0\0\0\0\0\n\n\n\n\n\n\n\n\n\n\x04\x04\x04\x04\x04\0\x04\x04\x04\x04\x04\n\0\0\0\0\0\0\0\n\n\n\x04\x04\x04\x04\0\0\0\0\0\0\0\0\0\x04\x04\n\x04\n\n\n\n\n\n\n\n\n\n\n\n\x04\x04\x04\x04\x04\x04\x04\0\0\0\x04\x04\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x04\n\n\n\n\n\n\x04\x04\x04\x04\x04\x04\x04\x04\x04\0\0\0\0\0\0\0\x04\n\n\x14\x14\x04\0\0\0\0\0\0\0\0\0\0\0\x08\x08\x08\x08\0\x08\x08\x08\x08\x08\x08\x08\0\x08\x08\0\x08\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x08\x08\x08\0\0\0\0\0\0\0\0\0\0\0\0\0\x14\0\0\0\0\0\0\0\0\0\0\0\0\0\x14\x14\x14\0\0\x08\0\0\0\0\0\0\0\0\0\0\x08\x08\x08\x08\0\0\0\0\0\0\0\0\n\n\n\n\n\n\n\n\n\n\0\0\0\x04\x04\0\x07\x07\x07\x07\0\0\0\0\0\0\0\0\0\0\0\0\x04\x04\x04\x04\x04\0\0\0\x04\x04\x04\x07\x07\x07\x07\x07\x07\x07\x07\x04\x04\x04\x04\x04\0\0\x04\x04\x04\x04\x04\x04\x04\0\0\0\0\0\0\0\0\0\0\x04\x04\x04\x04\0\0\x04\x04\x04\0\0\0\0\0\0\0\0\0\0\0\n\0\0\n\n\0\0\n\n\n\n\0\n\n\n\n\n\n\n\n\n\n\0\n\0\n\n\n\n\n\n\n\n\n\n\0\n\n\n\n\0\n\n\n\n\n\0\n\0\0\0\n\n\n\n\n\n\0\0\n\n\n\n\n\n\n\n\0\n\n\n\n\n\n\n\n\0\0\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x04\x04\x04\x04\x04\x04\x04\0\0\0\0\x04\x04\x04\x04\x04\x04\x04\x04\x04\0\0\x04\x04\x04\x04\x04\0\x04\x04\0\x04\x04\x04\x04\x04\0\0\0\0\0\x04\x04\x04\x04\x04\x04\x04\n\n\n\n\n\n\n\0\0\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\0\0\0\0\n\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\x04\0\n\n\n\n\n\n\n\0\n\n\n\n\0\n\n\0\n\n\n\n\x04\x04\x04\x04\x04\x04\x04\n\0\0\0\0\n\n\0\n\0\0\n\0\n\n\n\n\n\n\n\0\n\n\n\n\0\n\0\n\0\0\0\0\n\0\0\0\0\n\0\n\0\n\0\n\n\n\0\n\n\0\n\0\0\n\0\n\0\n\0\n\0\n\n\0\n\0\0\n\n\n\n\0\n\n\n\n\0\n\n\n\n\0\n\0\n\n\n\0\n\n\n\n\n\0\n\n\n\n\n\n\n\n\n\n\0\0\x13\x13\x13\x13\n\n\n\n\n\n\n\n\n\n\n\n\x13\x13\n\n\n\n\n\n\n\n\n\n\0\0\0\0\x13\0\x13\x13\x13\x13\x13\x13\x13\x13\x13\x13\0\0\0\0\0\x13\x13\x13\x13\x13\x13\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x14\x13\x13\x13\x13\x13\x13\x13\x13\x13\x13\x13\x13\x13\x13\x13\0\0\0\0\0\0\0\0\0\0\x13\0\0\0\0\0\x13\x13\x13\x13\x13\x13\x13\x13\x13\0\x13\x13\x13\x13\x13\x13\x13\x13\x13\x13\x13\x04\x04\x04\x04\x04\0\0\0\0\0\0\x13\x13\x13\x13\x13\x13\x13\x13\x13\x13\x13\x13\0\0\0\0\0\0\0\0\x13\x13\x13\x13\x13\x13\x13\x13\x13\x13\x13\0\x13\x13\x13\x13\0\x07\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0") }, 0u8)), break_state_table: icu::segmenter::provider::RuleBreakStateTable(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\x80\x80\x80\0\x19\x80\0\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\xFF\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x04\x04\x80\x04\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x1B\x05\x80\x1C\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\xFF\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x06\x06'\x06\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x07\x07\x80\x07\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x08\x08\x80\x08\xFF\x80\x80\x80\x80\x80\x80\x80\x80\xFF\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\t\t\x80\t\x80\xFF\xFF%&$#\x80\xFF\xFF\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\n\x15\x80\n\x80\xFF\xFF\x1E\x80\"\x1D\x80\xFF\xFF\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x0B\x0B\x80\x0B\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x0C\x0C\x80\x0C\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\r\r\x80\r\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x0E\x0E\x80\x0E\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x0F\x0F\x80\x0F\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x10\x10\x80\x10\x80\xFF\xFF\x1F\x80!\x80 \xFF\xFF\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x11\x11\x80\x11\xFF\xFF\xFF\x80\x80\x80\x80\x80\xFF\xFF\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x16\x18\x80\x17\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\xFF\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x13\x1A\x80\x13\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\xFE\xFE\xFE\xFE\n\x15\xFE\n\xFE\xFF\xFF\x1E\xFE\"\x1D\xFE\xFF\xFF\x80\xFF\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\x80\x80\x80\x80\x80\x16\x18\x80\x17\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x16\x18\x80\x17\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x16\x18\x80\x17\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFF\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\x80\xFE\xFE\xFE\xFE\x13\xFE\xFE\x13\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFF\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\x80\x80\x80\x80\x80\x1B\x05\x80\x1C\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x1B\x05\x80\x1C\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\xFE\xFE\xFE\xFE\x1D\xFE\xFE\x1D\xFE\xFF\xFF\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\x1E\xFE\xFE\x1E\xFE\xFF\xFF\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\x1F\xFE\xFE\x1F\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFF\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE \xFE\xFE \xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFF\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE!\xFE\xFE!\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFF\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFF\xFF\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFF\xFF\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFF\xFF\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFF\xFF\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\x80\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFF\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\x80\x80\x80\x80\x80(\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80") }), rule_status_table: icu::segmenter::provider::RuleStatusTable(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\0\0\0\0\0\x02\x02\x02\0\0\0\0\0\x01\x02\0\0\x02\x02\x02\x02\x02\0\0\x01\x01\x01\x02\x02\x02\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x02\x02\x02\0\0\0\x02\x02\x02\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0") }), property_count: 43u8, last_codepoint_property: 20i8, sot_property: 41u8, eot_property: 42u8, complex_property: 20u8 };
+            pub const SINGLETON_SEGMENTER_WORD_V1: &'static <icu::segmenter::provider::WordBreakDataV1Marker as icu_provider::DataMarker>::Yokeable = &icu::segmenter::provider::RuleBreakDataV1 { property_table: icu::segmenter::provider::RuleBreakPropertyTable(icu::collections::codepointtrie::CodePointTrie::from_parts(icu::collections::codepointtrie::CodePointTrieHeader { high_start: 918016u32, shifted12_high_start: 225u16, index3_null_offset: 1130u16, data_null_offset: 14u32, null_value: 0u32, trie_type: icu::collections::codepointtrie::TrieType::Small }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0@\0{\0\xBB\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\x1B\x01[\x01k\x01\xAB\x01\xCE\x01\xF3\0\xF3\0\x0C\x02\xF3\0\x1C\x02M\x02\x84\x02\xC2\x02\x02\x037\x03\xF3\0h\x03\xA8\x03\xDD\x03\xF7\x037\x04w\x04\xB7\x04\xEF\x04%\x05a\x05\x9F\x05\xDE\x05\x1C\x06[\x06\x99\x06\xD8\x06\x16\x07V\x07\x94\x07\xD2\x07\x10\x08P\x08\x8E\x08\xCE\x08\x0C\tL\t\x8A\t\xCA\t\n\nI\n\x89\n\xC8\n\x08\x0BH\x0B\x88\x0B\xC3\x0B\0\x0C\r\x0B'\x0B7\x0BM\x0Bm\x0B\x8B\x0B\xA3\x0B\xC2\x0B\xE2\x0B\xE2\x0B\xEF\x0B\x0C\x0C,\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C>\x0C^\x0C\0\0\x10\0 \x000\0@\0P\0`\0p\0{\0\x8B\0\x9B\0\xAB\0\xBB\0\xCB\0\xDB\0\xEB\0\xF3\0\x03\x01\x13\x01#\x01\xF3\0\x03\x01\x13\x01#\x01\xF3\0\x03\x01\x13\x01#\x01\xF3\0\x03\x01\x13\x01#\x01\x1B\x01+\x01;\x01K\x01[\x01k\x01{\x01\x8B\x01k\x01{\x01\x8B\x01\x9B\x01\xAB\x01\xBB\x01\xCB\x01\xDB\x01\xCE\x01\xDE\x01\xEE\x01\xFE\x01\xF3\0\x03\x01\x13\x01#\x01\xF3\0\x03\x01\x13\x01#\x01\x0C\x02\x1C\x02,\x02<\x02\xF3\0\x03\x01\x13\x01#\x01\x1C\x02,\x02<\x02L\x02M\x02]\x02m\x02}\x02\x84\x02\x94\x02\xA4\x02\xB4\x02\xC2\x02\xD2\x02\xE2\x02\xF2\x02\x02\x03\x12\x03\"\x032\x037\x03G\x03W\x03g\x03\xF3\0\x03\x01\x13\x01#\x01h\x03x\x03\x88\x03\x98\x03\xA8\x03\xB8\x03\xC8\x03\xD8\x03\xDD\x03\xED\x03\xFD\x03\r\x04\xF7\x03\x07\x04\x17\x04'\x047\x04G\x04W\x04g\x04w\x04\x87\x04\x97\x04\xA7\x04\xB7\x04\xC7\x04\xD7\x04\xE7\x04\xEF\x04\xFF\x04\x0F\x05\x1F\x05%\x055\x05E\x05U\x05a\x05q\x05\x81\x05\x91\x05\x9F\x05\xAF\x05\xBF\x05\xCF\x05\xDE\x05\xEE\x05\xFE\x05\x0E\x06\x1C\x06,\x06<\x06L\x06[\x06k\x06{\x06\x8B\x06\x99\x06\xA9\x06\xB9\x06\xC9\x06\xD8\x06\xE8\x06\xF8\x06\x08\x07\x16\x07&\x076\x07F\x07V\x07f\x07v\x07\x86\x07\x94\x07\xA4\x07\xB4\x07\xC4\x07\xD2\x07\xE2\x07\xF2\x07\x02\x08\x10\x08 \x080\x08@\x08P\x08`\x08p\x08\x80\x08\x8E\x08\x9E\x08\xAE\x08\xBE\x08\xCE\x08\xDE\x08\xEE\x08\xFE\x08\x0C\t\x1C\t,\t<\tL\t\\\tl\t|\t\x8A\t\x9A\t\xAA\t\xBA\t\xCA\t\xDA\t\xEA\t\xFA\t\n\n\x1A\n*\n:\nI\nY\ni\ny\n\x89\n\x99\n\xA9\n\xB9\n\xC8\n\xD8\n\xE8\n\xF8\n\x08\x0B\x18\x0B(\x0B8\x0BH\x0BX\x0Bh\x0Bx\x0B\x88\x0B\x98\x0B\xA8\x0B\xB8\x0B\xC3\x0B\xD3\x0B\xE3\x0B\xF3\x0B\0\x0C\x10\x0C \x0C0\x0CJ\nJ\nJ\nJ\n<\x07J\nJ\nJ\nJ\n@\x0C\xF3\0\xF3\0P\x0C\xF3\0\xF3\0\xC7\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0`\x0Cp\x0C\xF3\0\xF3\0`\x0C\xF3\0\xF3\0h\x0Cx\x0C\xCB\0\xF3\0\xF3\0\xF3\0x\x0C\xF3\0\xF3\0\xF3\0\x80\x0C\x0E\0\x0E\0\xF3\0\x0E\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\x90\x0C@\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0W\x02\xF3\0\xA0\x0Cp\0\xF3\0\xF3\0\xF3\0\xF3\0\xD6\t\xA7\x0C\xF3\0\xB7\x0C\xF3\0\xC6\x0C\xF3\0\xD6\x0C\xC5\0\xE6\x0CJ\nJ\nJ\nJ\nJ\n\xF6\x0C<\x07\x0E\0\x04\r<\x07\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xA7\x0C\x14\r\xF3\0\x1B\r\xF3\0\xF3\0\xF3\0\xF3\0+\r\xF3\0L\0\xF4\x0B\xF4\x0B1\x04J\nv\n;\rJ\nJ\nx\nJ\nK\r[\r\x0E\0\x0E\0\xF3\0k\rJ\nJ\nJ\nu\nJ\n{\r<\x07<\x07v\n[\x01\xB3\x02\x0E\0\x0E\0\x0E\0\x11\x02\xF3\0\xF3\0W\x01\x8A\r<\x07E\x08\xA1\x04\x13\x02\xF3\0\x9A\r7\x04\xF3\0\xF3\0U\x01\xA1\x04\xF3\0\xF3\0W\x01\xAA\r\xBA\r7\x04\xF3\0M\0\xA7\x0C\xF3\0\xF3\0Y\x02\x0E\0\xD8\x0B\xCA\r\xD3\r\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0[\x01[\x01[\x01[\x01\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\x90\x0C\xF3\0\xF3\0\x90\x0C\xE3\r\xF3\0M\0\xF3\0\xF3\0\xF3\0\x02\x06\xF3\r\x03\x0EN\0\xF3\r\x13\x0E#\x0E/\x0E?\x0EN\x0EZ\x0Ej\x0Ez\x0E\x0E\0N\0\x0E\0\x0E\0\x0E\0[\x01[\x01\xA4\x04\x87\x0E\x93\x0E\xA1\x0E\xB0\x0E\xC0\x0E\x0E\0\xF3\0\xF3\0\xA7\x0C\xCF\x0E\xD9\x0E\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xE4\x0E\xF0\x0E\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xF0\x0E\x0E\0\x0E\0\x0E\0\x95\0\x0E\0\xF9\x0E\x05\x0F\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\x003\x01\x15\x0F\xF3\0)\x01\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xE4\x0E\xF2\x0E%\x0F\x0E\0\x0E\0*\x0F:\x0F=\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FK\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0F[\x0Fh\x0Fm\x0Fz\x0F\x86\x0F\x95\x0F\xA2\x0F\x0E\0\x0E\0\xAD\x0F\xBC\x0F\xBD\x0F\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xDE\x0E\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xAD\x0F\xC1\x0F\x0E\0\x0E\0\x0E\0\xCD\x0F\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xDD\x0F\xEC\x0F\xF3\0\xF3\0P\x0C\xF3\0\xF3\0\xF3\0\xA8\x0CA\x08\xF3\0\xFC\x0F\0\x06\0\x06\0\x06\0\x06[\x01[\x01\x0E\0\x0E\0\xA8\x04\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0J\n\x0C\x10J\nJ\nJ\nJ\nJ\n$\x0BJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\n\x17\x10\x0E\0\x0E\0'\x10\x0E\x006\x10F\x10I\nJ\nJ\nJ\nJ\nV\x10f\x10f\x10f\x10f\x10f\x10k\x10<\0\xF3\0\xF3\0@\0\xF3\0\xF3\0\xF3\0\xF3\0L\0\x0E\0\xF3\0\xF3\0\x0E\0\x0E\0\x0E\0f\x10\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0{\x10\x0E\0\x0E\0\x0E\0f\x10f\x10g\x10f\x10f\x10f\x10f\x10f\x10\x8B\x10\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0J\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\n\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0N\0\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0\xF3\0M\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0N\0\xF3\0B\x06\x0E\0\xF3\0\xF3\0L\x01\x9B\x10\xF3\0M\x01\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xA3\x04\xAF\x04\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0p\0\xAA\x10\x0E\0?\0\xBA\x10\xF3\0\xC7\x10\x0E\0\xF3\0\xF3\0\xF3\0\xD7\x10\x14\x02\xF3\0\xF3\0W\x01\xE7\x10<\x07[\x01\xF7\x107\x04\xF3\0\x07\x11\xF3\0T\x01\xA1\x04\xF3\0N\0\x12\x02\xF3\0\xF3\0X\x01\x14\x11<\x07J\n$\x11\xF3\0\xF3\0R\x01\x0E\x114\x11<\x07J\nJ\nJ\nJ\nJ\nJ\n%\x0B?\nP\x01B\x11Q\x11*\r\0\x06\xF3\0\xF3\0\xF3\0)\x01\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0a\x11<\x07\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xD7\x10\xF3\0q\x11\xF3\0\xF3\0O\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0J\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nv\nJ\nJ\nJ\nJ\nJ\nJ\nK\r\x0E\0\x0E\0\xFC\x0Fy\x11\x88\x11\x92\x11\xA2\x11\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xB2\x11\x0E\0>\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0M\0\x0E\0\xF3\0\xF3\0\xF3\0\xF3\0?\0\xF3\0\xF3\0\xC2\x11\x0E\0\x0E\0O\0[\x01\xD2\x11[\x01\xDF\x11\xE4\x11\xF4\x11\x0E\0\xCD\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\x04\x12\x14\x120\0@\0P\0@\0p\0#\x12f\x10f\x10)\x12\xF3\0L\0P\x119\x12\x0E\0F\x12\xC6\0\xF3\0\xCB\0V\x12M\0M\0\x0E\0\x0E\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0p\0\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0\xF3\0\xF3\0e\x12\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0j\x12\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0N\0\xF3\0\xF3\0\xF3\0\xA7\x03\xA4\x04\x0E\0\xF3\0\xF3\0\xAA\x04\xF3\0p\0\xF3\0\xF3\0z\x12\xF3\0M\0\xF3\0\xF3\0\x8A\x12d\x12\x0E\0\x0E\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0M\0<\x07\xF3\0\xF3\0\x8A\x12\xF3\0O\0\xF3\0\xF3\0\xC2\x11\xF3\0\xF3\0\xF3\0\xD7\x10\xC7\0\xC7\0\x97\x12\xD0\0\xA5\x12\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xFC\x0F\xF3\0+\r\xC2\x11\x0E\0\xCC\0\xF3\0\xF3\0\xB5\x12\x0E\0\x0E\0\x0E\0\x0E\0\xC5\x12\xF3\0\xF3\0\xCF\x12\xF3\0+\r\xF3\0\xFC\x0F\xF3\0L\0\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0\xDE\x12\xF3\0+\r\xF3\0)\x01\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0\xF3\0\xF3\0+\x01\x0E\0\x0E\0\x0E\0\x0E\0\xEE\x12\xE2\x06\xF3\0\xFE\x12\x0E\0\x0E\0\xF3\0N\0\xF3\0N\0\x0E\0\x0E\0\xCA\0\xF3\0\x0E\x13\x0E\0\xF3\0\xF3\0\xF3\0+\r\xF3\0+\r\xF3\0g\x12\xF3\0\xB2\x11\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0\xF3\0\xF3\0\xF3\0\xA7\x0C\x0E\0\x0E\0\x0E\0\xF3\0\xF3\0\xF3\0g\x12\xF3\0\xF3\0\xF3\0g\x12\xF3\0\xF3\0\x1E\x13<\x07\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0\xF3\0.\x13\xB2\x11\x0E\0\x0E\0\x0E\0C\x08\xF3\0N\0A\x0B\xF3\0U\x01\xA4\x04\x0E\0\xF3\0>\x13\x0E\0\x0E\0\xF3\0e\x12\x0E\0\xF3\0\xFC\x0F\x13\x02\xF3\0\xF3\0S\x01\x0E\x11\x0E\x001\x04N\x13\x13\x02\xF3\0\xF3\0]\x13k\x13\xF3\0\xA7\x0C<\x07\x13\x02\xF3\0T\x01{\x13\x8B\x13\xF3\0\xF3\0\x9B\x13\x13\x02\xF3\0\xF3\0X\x01\xAB\x13\xBB\x13\x0E\0\x0E\0\xF3\0\xD0\0O\x01\xCB\x13\xDA\x13\x0E\0\x0E\0\x0E\0\xEA\x13\xC4\0\xA7\x0C\xF3\0\xF3\0L\x01\xF5\x0B<\x07\xFA\x13\xEE\x05\xC9\0\t\x14\x94\x07\x19\x14'\x14\xA0\x04\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0\xF3\0\xF3\0V\x017\x14G\x14\xB2\x11\x0E\0\xF3\0\xF3\0\xF3\0[\x01W\x14<\x07\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0\xF3\0L\x01g\x14\xA4\x04w\x14\x0E\0\x0E\0\xF3\0\xF3\0\xF3\0[\x01\x84\x14<\x07\x0E\0\x0E\0\xF3\0\xF3\0P\x01 \x04<\x07\x0E\0\x0E\0\x0E\0J\n\xFB\nx\n\x94\x14\xA3\x14\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0\xF3\0O\x01\xF5\x0B\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0\xF3\0\xF3\0\xF3\0<\x07\xA8\x04\xB3\x14\x96\x12\xF3\0\xC3\x14\xD1\x14<\x07\x0E\0\x0E\0\x0E\0\x0E\0\xE1\x14\xF3\0\xF3\0\xF0\x14\xFF\x14\x0E\0\x0F\x15\xF3\0\xF3\0\x1C\x15+\x15;\x15\xF3\0\xF3\0Q\x01K\x15\x0E\0\xF3\0\xF3\0\xF3\0\xF3\0\xA7\x0C\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xC9\0\xF3\0L\x01\xD4\x0B\xA7\x03<\x07\x0E\0?\0\xF3\0\x93\x02\xD3\x0B\x0E\x11\x0E\0\x0E\0\x0E\0\x0E\0\x02\x07\xF3\0\xF3\0[\x15j\x15<\x07\x94\x12\xF3\0z\x15\x87\x15<\x07\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0\x97\x15\xA7\x15\xD1\0\xF3\0\xB3\x15\xA2\x04<\x07\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xA7\x03\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0)\x01\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0L\0\x0E\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xD7\x10\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xA7\x03\xF3\0\xF3\0\xF3\0\xC3\x15\xD3\x15\xE7\x10\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0\xF3\0\xF3\0\xF3\0\xFC\x0F\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0\xF3\0\xF3\0\xA7\x0C\xF3\0L\0<\x07\xF3\0\xF3\0\xF3\0\xF3\0L\0<\x07\xF3\0M\0\xA0\x04\xF3\0\xF3\0\xF3\0\x0E\x11\xD7\x10<\x07>\0\xDA\x04\xF3\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0\xF3\0\xF3\0\xF3\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0\xF3\0\xF3\0\xF3\0\x01\x08Z\x01[\x01[\x01\xDB\x15\x13\x02\x0E\0\x0E\0\x0E\0\x0E\0\xEB\x15&\x0B\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xFB\x15\x0B\x16J\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\n\x1B\x16)\x16\x0E\09\x16E\x16\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0p\0N\0\xA7\x0CU\x16e\x16\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0[\x01[\x01\xDA\x03[\x01\x0E\x11\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0p\x16}\x16\x8A\x16\x0E\0\x96\x16\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xA4\x16\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xCD\0\xF3\0\xF3\0\xF3\0\xC5\0\xB2\x16\xC0\x16\xCE\0\xF3\0\xF3\0\xF3\0c\x0C\x7F\x06\xF3\0\xCD\x16\xDD\x16\xD1\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xE7\x16\xF3\0\xD1\0\xC7\0\xF3\0\xC7\0\xF3\0\xCD\0\xF3\0\xCD\0L\0\xF3\0L\0\xF3\0\xC9\0\xF3\0\xC9\0\xF3\0\xF4\x16\x02\x17\x02\x17\x02\x17[\x01[\x01[\x01\x12\x17[\x01[\x01\xF3\x0B\"\x08#\x08E\x08\x94\x02\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0L\0&\r\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xD4\x0B\x1D\x17+\x17\xF3\0\xF3\0\xF3\0M\0\x0E\0A\x08\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0\xF3\0N\0;\x17K\x17\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0[\x17\x0E\0\xF3\0\xF3\0O\x01<\x07\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xF3\0O\x01<\x07\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0k\x17L\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0\xF3\0e\x12\x0E\x11\x0E\0\x0E\0\xF3\0\xF3\0\xF3\0\xF3\0{\x17<\x07\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\xCE\0\xF3\0\x8A\x17\x97\x17\xA5\x17\xB5\x17\xC3\x17\xCB\x17\xC8\0O\0\xDA\x17O\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0A\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0F(\x0F\x0E\0\x95\0\xF3\0)\x01\xF3\0\xE5\x17\xF3\x17\x03\x18\x12\x18(\x0FA\x0FA\x0FA\x0F\"\x18(\x188\x18H\x18\x95\0V\x18\xF9\x0EA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0Fb\x18A\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FC\x0Fr\x18A\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0F\x0E\0\x0E\0\x0E\0A\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0F\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0t\x18\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0s\x18A\x0FA\x0F)\x0F\x0E\0\x0E\0\x0E\0\x84\x18Q\x0F\x0E\0\x0E\0\x84\x18\x0E\0'\x0FA\x0FA\x0FA\x0FA\x0FA\x0F)\x0FA\x0FA\x0F\x8C\x18:\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0F\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0<\x07A\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FA\x0FC\x0FJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\n\x0E\0\x0E\0J\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nK\rJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nv\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\n&\x0BJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\n'\x0B\x0E\0J\nv\n\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0J\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\ny\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\nJ\n\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x9C\x18\x0E\0[\x01[\x01[\x01[\x01[\x01[\x01\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0\x0E\0[\x01[\x01[\x01[\x01[\x01[\x01[\x01[\x01[\x01[\x01[\x01[\x01[\x01[\x01[\x01\x0E\0y\0\x8D\0\xAD\0\xCD\0\xED\0\r\x01-\x01M\x01m\x01\x89\x01\xA9\x01\xC3\x01\xE3\x01\x03\x02#\x02C\x02c\x02~\x02\x9E\x02\xBE\x02\xDA\x02\xEE\x02\x0E\x03.\x03N\x03n\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x92\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\xB2\x03\xB2\x03\xCA\x03\xEA\x03\n\x04*\x04\xB2\x03\xB2\x03\xB2\x03\xB2\x03\xB2\x03\xB2\x03\xB2\x03\xB2\x03\xB2\x03\xB2\x03\xB2\x03J\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04z\x04\x94\x04\xB2\x04\xD2\x04\xF2\x04\x12\x052\x05R\x05r\x05\x92\x05\xB2\x05\xCA\x05\xEA\x05\n\x06*\x06J\x06j\x06\x8A\x06\xAA\x06\xC5\x06\xB2\x03\xE5\x06\x05\x07j\x04j\x04j\x04j\x04\x1A\x07\xB2\x03\xB2\x03:\x07j\x04j\x04j\x04j\x04j\x04\xB2\x03Z\x07j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04\xB2\x03z\x07j\x04\x96\x07j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04\xB6\x07\xD6\x07j\x04j\x04j\x04j\x04j\x04\xF6\x07j\x04j\x04j\x04j\x04j\x04j\x04j\x04\x06\x08\x1B\x087\x08W\x08m\x08j\x04\x8D\x08j\x04\x9D\x08\xBD\x08\xD4\x08\xE7\x08\xF7\x08\x17\tj\x04j\x047\tW\tw\t\x97\t\xB2\t\xD2\t\xE7\t\x07\n\x08\n\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03(\n\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x038\nW\n\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03m\n\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03x\nj\x04j\x04j\x04j\x04j\x04j\x04\x8E\x03\x98\nj\x04j\x04\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\xB8\n\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\x8E\x03\xCD\nj\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04j\x04\xED\n") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\0\0\0\0\0\0\0\0\x02\x03\x03\x01\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x12\0\x0C\0\0\0\0\x0B\0\0\0\0\x0F\0\r\0\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x0E\x0F\0\0\0\0\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\0\0\0\0\x11\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\0\0\0\0\0\x03\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x13\n\0\0\x07\x13\0\0\0\0\0\0\n\0\x0E\0\0\n\0\0\0\0\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\0\0\0\0\0\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\n\n\n\n\n\0\n\n\0\0\n\n\n\n\x0F\n\0\0\0\0\0\0\n\x0E\n\n\n\0\n\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\0\n\n\n\n\n\n\n\n\n\0\x04\x04\x04\x04\x04\x04\x04\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\0\0\n\n\n\n\0\n\x0E\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\x0F\n\0\0\0\0\0\0\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\0\x04\x04\0\x04\x04\0\x04\0\0\0\0\0\0\0\0\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\0\0\0\0\t\t\t\t\n\x0E\0\0\0\0\0\0\0\0\0\0\0\x07\x07\x07\x07\x07\x07\0\0\0\0\0\0\x0F\x0F\0\0\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\0\x07\0\0\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\0\x10\x0F\0\n\n\x04\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\0\n\x04\x04\x04\x04\x04\x04\x04\x07\0\x04\x04\x04\x04\x04\x04\n\n\x04\x04\0\x04\x04\x04\x04\n\n\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\n\n\n\0\0\n\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x07\n\x04\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\0\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\n\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\x04\x04\x04\x04\x04\x04\x04\x04\x04\n\n\0\0\x0F\0\n\0\0\x04\0\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\x04\x04\x04\x04\n\x04\x04\x04\x04\x04\x04\x04\x04\x04\n\x04\x04\x04\n\x04\x04\x04\x04\x04\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\x04\x04\x04\0\0\0\0\n\n\n\n\n\n\n\n\n\n\n\0\0\0\0\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\0\n\n\n\n\n\n\0\x07\x07\0\0\0\0\0\0\x04\x04\x04\x04\x04\x04\x04\x04\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x07\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\x04\x04\x04\n\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\n\x04\x04\x04\x04\x04\x04\x04\n\n\n\n\n\n\n\n\n\n\x04\x04\0\0\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\x04\x04\x04\0\n\n\n\n\n\n\n\n\0\0\n\n\0\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\0\n\n\n\n\n\n\n\0\n\0\0\0\n\n\n\n\0\0\x04\n\x04\x04\x04\x04\x04\0\0\x04\x04\0\0\x04\x04\x04\n\0\0\0\0\0\0\0\0\x04\0\0\0\0\n\n\0\n\n\n\x04\x04\0\0\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\n\n\0\0\0\0\0\0\0\0\0\0\n\0\x04\0\x04\x04\x04\0\n\n\n\n\n\n\0\0\0\0\n\n\0\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\0\n\n\n\n\n\n\n\0\n\n\0\n\n\0\n\n\0\0\x04\0\x04\x04\x04\0\0\0\0\x04\x04\0\0\x04\x04\x04\0\0\0\x04\0\0\0\0\0\0\0\n\n\n\n\0\n\0\0\0\0\0\0\0\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x04\x04\n\n\n\x04\0\0\0\0\0\0\0\0\0\0\x04\x04\x04\0\n\n\n\n\n\n\n\n\n\0\n\n\n\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\0\n\n\n\n\n\n\n\0\n\n\0\n\n\n\n\n\0\0\x04\n\x04\x04\x04\x04\x04\x04\0\x04\x04\x04\0\x04\x04\x04\0\0\n\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\n\n\x04\x04\0\0\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\0\0\0\0\0\0\0\0\0\n\x04\x04\x04\x04\x04\x04\0\x04\x04\x04\0\n\n\n\n\n\n\n\n\0\0\n\n\0\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\0\n\n\n\n\n\n\n\0\n\n\0\n\n\n\n\n\0\0\x04\n\x04\x04\x04\x04\x04\0\0\x04\x04\0\0\x04\x04\x04\0\0\0\0\0\0\0\x04\x04\x04\0\0\0\0\n\n\0\n\n\n\x04\x04\0\0\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\0\n\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x04\n\0\n\n\n\n\n\n\0\0\0\n\n\n\0\n\n\n\n\0\0\0\n\n\0\n\0\n\n\0\0\0\n\n\0\0\0\n\n\n\0\0\0\n\n\n\n\n\n\n\n\n\n\n\n\0\0\0\0\x04\x04\x04\0\0\0\x04\x04\x04\0\x04\x04\x04\x04\0\0\n\0\0\0\0\0\0\x04\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x04\x04\x04\x04\x04\n\n\n\n\n\n\n\n\0\n\n\n\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\0\0\x04\n\x04\x04\x04\x04\x04\0\x04\x04\x04\0\x04\x04\x04\x04\0\0\0\0\0\0\0\x04\x04\0\n\n\n\0\0\n\0\0\n\n\x04\x04\0\0\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\n\x04\x04\x04\0\n\n\n\n\n\n\n\n\0\n\n\n\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\0\n\n\n\n\n\n\n\n\n\n\0\n\n\n\n\n\0\0\x04\n\x04\x04\x04\x04\x04\0\x04\x04\x04\0\x04\x04\x04\x04\0\0\0\0\0\0\0\x04\x04\0\0\0\0\0\0\n\n\0\n\n\x04\x04\0\0\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\0\n\n\x04\0\0\0\0\0\0\0\0\0\0\0\0\x04\x04\x04\x04\n\n\n\n\n\n\n\n\n\0\n\n\n\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\x04\x04\n\x04\x04\x04\x04\x04\0\x04\x04\x04\0\x04\x04\x04\x04\n\0\0\0\0\0\n\n\n\x04\0\0\0\0\0\0\0\n\n\n\x04\x04\0\0\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\0\0\0\0\0\0\0\0\0\0\n\n\n\n\n\n\0\x04\x04\x04\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\0\0\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\0\n\n\n\n\n\n\n\n\n\0\n\0\0\n\n\n\n\n\n\n\0\0\0\x04\0\0\0\0\x04\x04\x04\x04\x04\x04\0\x04\0\x04\x04\x04\x04\x04\x04\x04\x04\0\0\0\0\0\0\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\0\0\x04\x04\0\0\0\0\0\0\0\0\0\0\0\0\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\0\0\0\0\0\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\0\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x14\x14\0\x14\0\x14\x14\x14\x14\x14\0\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\0\x14\0\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\0\0\x14\x14\x14\x14\x14\0\x14\0\x14\x14\x14\x14\x14\x14\x14\0\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\0\0\x14\x14\x14\x14\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\n\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x04\x04\0\0\0\0\0\0\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\0\0\0\0\0\0\0\0\0\0\0\x04\0\x04\0\x04\0\0\0\0\x04\x04\n\n\n\n\n\n\n\n\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\0\0\0\0\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\0\x04\x04\n\n\n\n\n\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\0\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\0\0\0\0\0\0\x04\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x14\x14\x14\x14\x14\x14\n\n\n\n\n\n\0\n\0\0\0\0\0\n\0\0\n\n\n\n\n\n\n\n\n\0\n\n\n\n\0\0\n\n\n\n\n\n\n\0\n\0\n\n\n\n\0\0\n\n\n\n\n\n\n\n\n\n\n\0\0\x04\x04\x04\n\n\n\n\n\n\0\0\n\n\n\n\n\n\0\0\x12\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\0\0\0\0\0\0\0\n\n\x04\x04\x04\x04\0\0\0\0\0\0\0\0\0\n\n\x04\x04\x04\0\0\0\0\0\0\0\0\0\0\0\n\n\x04\x04\0\0\0\0\0\0\0\0\0\0\0\0\n\0\x04\x04\0\0\0\0\0\0\0\0\0\0\0\0\x14\x14\x14\x14\0\0\0\x14\0\0\0\0\x14\x14\0\0\0\0\0\0\0\0\0\0\0\x04\x04\x04\x07\x04\n\n\n\n\n\x04\x04\n\n\n\n\n\n\n\n\n\x04\n\0\0\0\0\0\n\n\n\n\n\n\0\0\0\0\0\0\0\0\0\0\x14\x14\x14\x14\x14\0\0\0\0\0\0\0\0\0\0\0\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\0\0\0\0\0\0\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x14\0\0\0\x14\x14\n\n\n\n\n\n\n\x04\x04\x04\x04\x04\0\0\0\0\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\0\0\x04\x04\x04\x04\x04\n\n\n\n\n\n\n\n\0\0\0\n\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\n\n\x04\x04\x04\x04\x04\x04\x04\x04\0\0\0\0\0\0\0\0\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\0\0\0\n\n\n\x04\x04\x04\x04\x04\x04\x04\x04\x04\n\n\n\n\x04\n\n\x04\x04\x04\n\0\0\0\0\0\n\n\n\n\n\n\n\n\0\n\0\n\0\n\0\n\0\0\n\n\n\0\n\n\n\n\n\n\n\0\0\0\n\n\n\n\0\0\n\n\n\n\n\n\0\0\0\0\x12\x12\x12\x12\x12\x12\x12\0\x12\x12\x12\0\x04\x05\x07\x07\0\0\0\0\0\0\0\0\r\r\0\0\0\0\0\0\r\0\0\x0E\x03\x03\x07\x07\x07\x07\x07\x11\0\0\0\0\0\0\0\0\0\0\0\0\x13\0\0\x11\0\0\0\x0F\0\0\0\0\x13\0\0\0\0\0\0\x11\0\0\0\0\0\0\0\0\0\0\x12\x07\x07\x07\x07\x07\0\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\0\n\0\0\0\0\0\0\0\0\0\0\0\0\0\n\0\0\0\0\n\0\0\n\n\n\n\n\n\0\n\0\0\0\n\n\n\n\n\0\0\x13\0\n\0\n\0\n\0\n\n\n\n\0\n\n\n\n\n\n\n\n\n\x13\0\0\n\n\n\n\0\0\0\0\0\n\n\n\n\n\0\0\0\0\n\0\0\0\0\x13\x13\x13\x13\x13\x13\0\0\0\0\0\0\0\0\0\x13\x13\0\0\0\0\0\0\0\0\0\0\x13\x13\0\0\0\0\0\0\0\0\x13\0\0\0\0\0\0\0\0\0\x13\x13\x13\x13\x13\x13\x13\0\0\0\0\x13\x13\x13\0\0\0\0\0\n\n\x13\n\n\n\n\n\n\n\n\n\n\n\n\n\x13\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x13\x13\x13\x13\0\x13\x13\x13\x13\x13\x13\0\x13\x13\x13\x13\x13\x13\x13\x13\x13\x13\x13\x13\x13\x13\x13\x13\0\0\0\0\0\0\0\0\0\0\x13\x13\x13\x13\x13\x13\0\0\x13\x13\x13\x13\x13\x13\x13\x13\0\x13\0\x13\0\0\0\0\0\0\x13\0\0\0\0\0\0\0\x13\x13\0\0\0\0\0\0\0\0\0\0\0\x13\0\0\x13\0\0\0\0\x13\0\x13\0\0\0\x13\x13\x13\0\x13\0\0\0\0\0\0\0\0\x13\x13\x13\x13\x13\0\0\0\0\0\0\0\0\x13\x13\x13\0\0\0\0\0\0\0\0\x13\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x13\x13\0\0\0\0\x13\0\0\0\0\0\0\0\0\0\0\n\n\n\n\n\0\0\0\0\0\0\n\n\n\n\x04\x04\n\n\0\0\0\0\0\0\0\0\0\0\0\0\n\n\n\n\n\n\n\0\0\0\0\0\0\0\0\0\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\0\x14\x14\x14\x14\x14\x14\0\0\0\0\0\0\0\0\0\0\x12\0\0\0\0\x14\0\x14\0\0\0\0\0\0\0\0\x14\x14\x14\x14\x14\x14\x14\x14\x14\x04\x04\x04\x04\x04\x04\x13\x08\x08\x08\x08\x08\0\0\x14\x14\x14\x14\n\x13\0\0\x14\x14\x14\x14\x14\x14\x14\0\0\x04\x04\x08\x08\x14\x14\x14\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\0\x08\x08\x08\x08\0\0\0\0\0\0\0\x13\0\x13\0\0\0\0\0\0\x08\x08\x08\x08\x08\x08\x08\x08\0\0\0\0\0\0\0\0\x04\x04\x04\0\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\0\n\n\0\n\0\n\n\n\n\n\0\0\0\0\0\0\n\n\x04\n\n\n\x04\n\n\n\n\x04\n\n\n\n\x04\x04\x04\x04\x04\0\0\0\0\x04\0\0\0\n\n\n\n\0\0\0\0\0\0\0\0\0\0\0\0\x04\x04\x04\x04\x04\x04\0\0\0\0\0\0\0\0\0\0\x04\x04\n\n\n\n\n\n\0\0\0\n\0\n\n\x04\n\n\n\n\n\n\x04\x04\x04\x04\x04\x04\x04\x04\0\0\0\0\0\0\0\0\0\0\0\0\0\0\n\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x14\x14\x14\x14\x14\0\n\n\n\x04\n\n\n\n\n\n\n\n\x04\x04\0\0\n\n\n\x04\x04\0\0\0\0\0\0\0\0\0\n\n\n\n\n\n\0\0\n\n\n\n\n\n\0\n\n\n\x04\x04\x04\x04\x04\x04\x04\x04\0\x04\x04\0\0\n\n\n\n\n\n\n\0\0\0\0\n\n\n\n\n\0\0\0\0\0\t\x04\t\t\t\t\t\t\t\t\t\0\t\t\t\t\t\t\t\0\t\t\t\t\t\0\t\0\t\t\0\t\t\0\t\t\t\t\t\t\t\t\t\t\n\n\0\0\0\0\0\0\0\0\0\0\0\0\0\0\n\n\n\n\n\n\n\n\0\0\0\0\0\0\0\0\x0F\0\0\x0E\x0F\0\0\0\0\0\0\0\0\0\0\0\x11\x11\0\0\0\0\0\0\0\0\0\0\0\0\0\x11\x11\x11\x0F\0\r\0\x0F\x0E\0\0\0\0\0\0\0\0\0\0\n\n\n\n\n\n\n\n\n\n\n\n\n\0\0\x07\0\0\0\0\0\0\0\r\0\0\0\0\x0F\0\r\0\0\0\0\0\0\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x04\x04\0\0\n\n\n\n\n\n\0\0\n\n\n\0\0\0\0\0\0\0\0\0\x07\x07\x07\0\0\0\0\n\n\n\n\n\n\n\n\n\n\n\0\n\n\0\n\n\n\n\n\0\0\0\0\0\0\0\0\0\0\0\0\0\x04\0\0\n\n\n\n\n\n\x04\x04\x04\x04\x04\0\0\0\0\0\n\n\n\n\0\0\0\0\n\n\n\n\n\n\n\n\0\n\n\0\n\n\n\n\n\n\n\n\n\0\n\n\n\n\n\n\n\0\n\n\0\0\0\n\0\n\n\n\n\n\n\n\n\n\0\0\0\0\0\n\n\n\n\n\n\0\0\n\0\n\n\n\n\n\n\0\n\n\0\0\0\n\0\0\n\n\n\0\n\n\0\0\0\0\0\0\0\0\0\0\n\x04\x04\x04\0\x04\x04\0\0\0\0\0\x04\x04\x04\x04\n\n\n\n\n\n\0\0\x04\x04\x04\0\0\0\0\x04\n\n\n\n\n\x04\x04\0\0\0\0\0\0\0\0\0\n\n\n\n\x04\x04\x04\x04\0\0\0\0\0\0\0\0\n\n\n\n\n\n\n\n\n\n\0\x04\x04\0\0\0\n\n\x04\x04\x04\x04\0\0\0\0\0\0\0\0\0\0\x04\n\n\x04\x04\n\0\0\0\0\0\0\0\0\0\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\0\0\x07\0\0\x04\0\0\0\0\0\0\0\0\0\0\x07\0\0\x04\x04\x04\x04\x04\0\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\0\0\0\0\n\x04\x04\n\0\0\0\0\0\0\0\0\n\n\n\x04\0\0\n\0\0\0\0\0\0\0\0\0\x04\n\n\n\n\0\0\0\0\x04\x04\x04\x04\0\x04\x04\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\n\0\n\0\0\0\x04\x04\x04\x04\x04\x04\x04\x04\0\0\0\0\0\0\x04\n\x04\0\0\0\0\0\0\0\0\0\0\0\0\0\0\n\n\n\n\n\n\n\0\n\0\n\n\n\n\0\n\x04\x04\x04\x04\0\n\n\n\n\n\n\n\n\0\0\n\0\n\n\0\n\n\n\n\n\0\x04\x04\n\x04\x04\n\0\0\0\0\0\0\x04\0\0\0\0\0\n\n\n\x04\x04\0\0\x04\x04\x04\x04\x04\x04\x04\0\0\0\x04\x04\x04\x04\x04\x04\x04\n\n\n\n\0\0\0\0\0\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\0\0\0\0\x04\n\x04\x04\x04\x04\n\n\0\n\0\0\0\0\0\0\0\0\x04\x04\x04\x04\x04\x04\0\0\x04\x04\x04\x04\x04\x04\x04\x04\0\0\0\0\0\0\0\0\n\n\n\n\x04\x04\0\0\0\n\0\0\0\0\0\0\0\0\0\0\0\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x14\x14\0\0\0\x14\x14\x14\x14\x14\x14\x14\0\0\0\0\0\0\0\0\0\n\n\n\n\n\n\n\0\0\n\0\0\n\n\n\n\x04\x04\x04\x04\x04\x04\0\x04\x04\0\0\x04\x04\x04\x04\n\x04\x04\0\0\0\0\0\0\0\0\0\0\0\0\n\n\n\n\n\n\n\n\0\0\n\n\n\n\n\n\x04\x04\x04\x04\x04\x04\x04\0\0\x04\x04\x04\x04\x04\x04\n\0\n\x04\0\0\0\0\0\0\0\0\0\0\0\n\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\n\n\n\n\n\x04\x04\x04\x04\x04\x04\x04\n\x04\x04\x04\x04\0\0\0\0\0\0\0\x04\0\0\0\0\0\0\0\0\n\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\n\n\n\n\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\0\0\0\n\0\0\n\x04\x04\x04\x04\x04\x04\0\0\0\x04\0\x04\x04\0\x04\x04\x04\x04\x04\x04\n\x04\0\0\0\0\0\0\0\0\n\n\n\n\n\n\n\n\n\n\x04\x04\x04\x04\x04\0\x04\x04\x04\x04\x04\n\0\0\0\0\0\0\0\n\n\n\x04\x04\x04\x04\0\0\0\0\0\0\0\0\0\x04\x04\n\x04\n\n\n\n\n\n\n\n\n\n\n\n\x04\x04\x04\x04\x04\x04\x04\0\0\0\x04\x04\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x04\n\n\n\n\n\n\x04\x04\x04\x04\x04\x04\x04\x04\x04\0\0\0\0\0\0\0\x04\n\n\x14\x14\x04\0\0\0\0\0\0\0\0\0\0\0\x08\x08\x08\x08\0\x08\x08\x08\x08\x08\x08\x08\0\x08\x08\0\x08\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x14\x08\x08\x08\0\0\0\0\0\0\0\0\0\0\0\0\0\x14\0\0\0\0\0\0\0\0\0\0\0\0\0\x14\x14\x14\0\0\x08\0\0\0\0\0\0\0\0\0\0\x08\x08\x08\x08\0\0\0\0\0\0\0\0\n\n\n\n\n\n\n\n\n\n\0\0\0\x04\x04\0\x07\x07\x07\x07\0\0\0\0\0\0\0\0\0\0\0\0\x04\x04\x04\x04\x04\0\0\0\x04\x04\x04\x07\x07\x07\x07\x07\x07\x07\x07\x04\x04\x04\x04\x04\0\0\x04\x04\x04\x04\x04\x04\x04\0\0\0\0\0\0\0\0\0\0\x04\x04\x04\x04\0\0\x04\x04\x04\0\0\0\0\0\0\0\0\0\0\0\n\0\0\n\n\0\0\n\n\n\n\0\n\n\n\n\n\n\n\n\n\n\0\n\0\n\n\n\n\n\n\n\n\n\n\0\n\n\n\n\0\n\n\n\n\n\0\n\0\0\0\n\n\n\n\n\n\0\0\n\n\n\n\n\n\n\n\0\n\n\n\n\n\n\n\n\0\0\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x04\x04\x04\x04\x04\x04\x04\0\0\0\0\x04\x04\x04\x04\x04\x04\x04\x04\x04\0\0\x04\x04\x04\x04\x04\0\x04\x04\0\x04\x04\x04\x04\x04\0\0\0\0\0\x04\x04\x04\x04\x04\x04\x04\n\n\n\n\n\n\n\0\0\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\0\0\0\0\n\0\n\n\n\n\n\n\n\n\n\n\n\n\n\n\x04\0\n\n\n\n\n\n\n\0\n\n\n\n\0\n\n\0\n\n\n\n\x04\x04\x04\x04\x04\x04\x04\n\0\0\0\0\n\n\0\n\0\0\n\0\n\n\n\n\n\n\n\0\n\n\n\n\0\n\0\n\0\0\0\0\n\0\0\0\0\n\0\n\0\n\0\n\n\n\0\n\n\0\n\0\0\n\0\n\0\n\0\n\0\n\n\0\n\0\0\n\n\n\n\0\n\n\n\n\0\n\n\n\n\0\n\0\n\n\n\0\n\n\n\n\n\0\n\n\n\n\n\n\n\n\n\n\0\0\x13\x13\x13\x13\n\n\n\n\n\n\n\n\n\n\n\n\x13\x13\n\n\n\n\n\n\n\n\n\n\0\0\0\0\x13\0\x13\x13\x13\x13\x13\x13\x13\x13\x13\x13\0\0\0\0\0\x13\x13\x13\x13\x13\x13\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x14\x13\x13\x13\x13\x13\x13\x13\x13\x13\x13\x13\x13\x13\x13\x13\0\0\0\0\0\0\0\0\0\0\x13\0\0\0\0\0\x13\x13\x13\x13\x13\x13\x13\x13\x13\0\x13\x13\x13\x13\x13\x13\x13\x13\x13\x13\x13\x04\x04\x04\x04\x04\0\0\0\0\0\0\x13\x13\x13\x13\x13\x13\x13\x13\x13\x13\x13\x13\0\0\0\0\0\0\0\0\x13\x13\x13\x13\x13\x13\x13\x13\x13\x13\x13\0\x13\x13\x13\x13\0\x07\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0") }, 0u8)), break_state_table: icu::segmenter::provider::RuleBreakStateTable(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x80\x80\x80\x80\0\x19\x80\0\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\xFF\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x04\x04\x80\x04\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x1B\x05\x80\x1C\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\xFF\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x06\x06'\x06\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x07\x07\x80\x07\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x08\x08\x80\x08\xFF\x80\x80\x80\x80\x80\x80\x80\x80\xFF\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\t\t\x80\t\x80\xFF\xFF%&$#\x80\xFF\xFF\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\n\x15\x80\n\x80\xFF\xFF\x1E\x80\"\x1D\x80\xFF\xFF\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x0B\x0B\x80\x0B\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x0C\x0C\x80\x0C\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\r\r\x80\r\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x0E\x0E\x80\x0E\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x0F\x0F\x80\x0F\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x10\x10\x80\x10\x80\xFF\xFF\x1F\x80!\x80 \xFF\xFF\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x11\x11\x80\x11\xFF\xFF\xFF\x80\x80\x80\x80\x80\xFF\xFF\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x16\x18\x80\x17\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\xFF\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x13\x1A\x80\x13\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\xFE\xFE\xFE\xFE\n\x15\xFE\n\xFE\xFF\xFF\x1E\xFE\"\x1D\xFE\xFF\xFF\x80\xFF\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\x80\x80\x80\x80\x80\x16\x18\x80\x17\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x16\x18\x80\x17\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x16\x18\x80\x17\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFF\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\x80\xFE\xFE\xFE\xFE\x13\xFE\xFE\x13\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFF\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\x80\x80\x80\x80\x80\x1B\x05\x80\x1C\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x1B\x05\x80\x1C\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\xFE\xFE\xFE\xFE\x1D\xFE\xFE\x1D\xFE\xFF\xFF\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\x1E\xFE\xFE\x1E\xFE\xFF\xFF\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\x1F\xFE\xFE\x1F\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFF\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE \xFE\xFE \xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFF\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE!\xFE\xFE!\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFF\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFF\xFF\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFF\xFF\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFF\xFF\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFF\xFF\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\x80\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFF\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\xFE\x80\x80\x80\x80\x80(\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80") }), rule_status_table: icu::segmenter::provider::RuleStatusTable(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\0\0\0\0\0\0\x02\x02\x02\0\0\0\0\0\x01\x02\0\0\x02\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0") }), property_count: 43u8, last_codepoint_property: 20i8, sot_property: 41u8, eot_property: 42u8, complex_property: 20u8 };
         }
         #[clippy::msrv = "1.66"]
         impl icu_provider::DataProvider<icu::segmenter::provider::WordBreakDataV1Marker> for $provider {
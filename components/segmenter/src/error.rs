@@ -18,6 +18,9 @@ pub enum SegmenterError {
     /// An error originating inside of the [data provider](icu_provider).
     #[displaydoc("{0}")]
     Data(DataError),
+    /// The segmentation rules could not be compiled.
+    #[displaydoc("Invalid segmentation rules: {0}")]
+    InvalidRules(&'static str),
 }
 
 impl From<DataError> for SegmenterError {
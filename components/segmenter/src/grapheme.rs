@@ -5,8 +5,9 @@
 use alloc::vec::Vec;
 use icu_provider::prelude::*;
 
-use crate::indices::{Latin1Indices, Utf16Indices};
+use crate::indices::{ChunkedCharIndices, ChunkedUtf16Indices, Latin1Indices, Utf16Indices};
 use crate::iterator_helpers::derive_usize_iterator_with_type;
+use crate::random_access;
 use crate::rule_segmenter::*;
 use crate::{provider::*, SegmenterError};
 use utf8_iter::Utf8CharIndices;
@@ -54,6 +55,18 @@ pub type GraphemeClusterBreakIteratorLatin1<'l, 's> =
 pub type GraphemeClusterBreakIteratorUtf16<'l, 's> =
     GraphemeClusterBreakIterator<'l, 's, RuleBreakTypeUtf16>;
 
+/// Grapheme cluster break iterator for an `str` (a UTF-8 string) split into chunks.
+///
+/// For examples of use, see [`GraphemeClusterSegmenter`].
+pub type GraphemeClusterBreakIteratorChunkedUtf8<'l, 's, I> =
+    GraphemeClusterBreakIterator<'l, 's, RuleBreakTypeChunkedUtf8<I>>;
+
+/// Grapheme cluster break iterator for a UTF-16 string split into chunks.
+///
+/// For examples of use, see [`GraphemeClusterSegmenter`].
+pub type GraphemeClusterBreakIteratorChunkedUtf16<'l, 's, I> =
+    GraphemeClusterBreakIterator<'l, 's, RuleBreakTypeChunkedUtf16<I>>;
+
 /// Segments a string into grapheme clusters.
 ///
 /// Supports loading grapheme cluster break data, and creating grapheme cluster break iterators for
@@ -195,6 +208,7 @@ impl GraphemeClusterSegmenter {
             current_pos_data: None,
             result_cache: Vec::new(),
             data: payload,
+            locale_override: None,
             complex: None,
             boundary_property: 0,
         })
@@ -215,6 +229,7 @@ impl GraphemeClusterSegmenter {
             current_pos_data: None,
             result_cache: Vec::new(),
             data: self.payload.get(),
+            locale_override: None,
             complex: None,
             boundary_property: 0,
         })
@@ -232,6 +247,7 @@ impl GraphemeClusterSegmenter {
             current_pos_data: None,
             result_cache: Vec::new(),
             data: self.payload.get(),
+            locale_override: None,
             complex: None,
             boundary_property: 0,
         })
@@ -258,10 +274,147 @@ impl GraphemeClusterSegmenter {
             current_pos_data: None,
             result_cache: Vec::new(),
             data: payload,
+            locale_override: None,
+            complex: None,
+            boundary_property: 0,
+        })
+    }
+
+    /// Creates a grapheme cluster break iterator for an `str` (a UTF-8 string) split into chunks,
+    /// such as the chunks of a rope.
+    ///
+    /// The breakpoints are offsets into the concatenation of the chunks, and are the same as
+    /// for the concatenated string.
+    ///
+    /// `chunks` can be any iterator over the chunks; it is cloned, so it should be cheap to clone.
+    pub fn segment_str_chunks<'l, 's, I>(
+        &'l self,
+        chunks: I,
+    ) -> GraphemeClusterBreakIteratorChunkedUtf8<'l, 's, I::IntoIter>
+    where
+        I: IntoIterator<Item = &'s str>,
+        I::IntoIter: Clone,
+    {
+        let chunks = chunks.into_iter();
+        GraphemeClusterBreakIterator(RuleBreakIterator {
+            iter: ChunkedCharIndices::new(chunks.clone()),
+            len: chunks.map(str::len).sum(),
+            current_pos_data: None,
+            result_cache: Vec::new(),
+            data: self.payload.get(),
+            locale_override: None,
             complex: None,
             boundary_property: 0,
         })
     }
+
+    /// Creates a grapheme cluster break iterator for a UTF-16 string split into chunks.
+    ///
+    /// The breakpoints are offsets into the concatenation of the chunks, and are the same as
+    /// for the concatenated string. Surrogate pairs may be split across chunks.
+    ///
+    /// `chunks` can be any iterator over the chunks; it is cloned, so it should be cheap to clone.
+    pub fn segment_utf16_chunks<'l, 's, I>(
+        &'l self,
+        chunks: I,
+    ) -> GraphemeClusterBreakIteratorChunkedUtf16<'l, 's, I::IntoIter>
+    where
+        I: IntoIterator<Item = &'s [u16]>,
+        I::IntoIter: Clone,
+    {
+        let chunks = chunks.into_iter();
+        GraphemeClusterBreakIterator(RuleBreakIterator {
+            iter: ChunkedUtf16Indices::new(chunks.clone()),
+            len: chunks.map(<[u16]>::len).sum(),
+            current_pos_data: None,
+            result_cache: Vec::new(),
+            data: self.payload.get(),
+            locale_override: None,
+            complex: None,
+            boundary_property: 0,
+        })
+    }
+
+    /// Returns the first grapheme cluster boundary after `offset` in an `str` (a UTF-8 string),
+    /// or `None` if `offset` is at or past the end of the text.
+    ///
+    /// Only the text around `offset` is segmented.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use icu::segmenter::GraphemeClusterSegmenter;
+    /// let segmenter = GraphemeClusterSegmenter::new();
+    ///
+    /// let text = "e\u{301}a";
+    /// assert_eq!(segmenter.following_str(text, 0), Some(3));
+    /// assert_eq!(segmenter.following_str(text, 1), Some(3));
+    /// assert_eq!(segmenter.preceding_str(text, 3), Some(0));
+    /// assert_eq!(segmenter.preceding_str(text, 4), Some(3));
+    /// assert!(!segmenter.is_boundary_str(text, 1));
+    /// assert!(segmenter.is_boundary_str(text, 3));
+    /// ```
+    pub fn following_str(&self, input: &str, offset: usize) -> Option<usize> {
+        let data = self.payload.get();
+        random_access::following(input, offset, is_restart_code_point(data, None), |start| {
+            Self::new_and_segment_str(input.get(start..).unwrap_or_default(), data)
+                .map(move |b| b + start)
+        })
+    }
+
+    /// Returns the last grapheme cluster boundary before `offset` in an `str` (a UTF-8 string),
+    /// or `None` if `offset` is 0.
+    ///
+    /// See [`Self::following_str`] for an example.
+    pub fn preceding_str(&self, input: &str, offset: usize) -> Option<usize> {
+        let data = self.payload.get();
+        random_access::preceding(input, offset, is_restart_code_point(data, None), |start| {
+            Self::new_and_segment_str(input.get(start..).unwrap_or_default(), data)
+                .map(move |b| b + start)
+        })
+    }
+
+    /// Returns whether `offset` is a grapheme cluster boundary in an `str` (a UTF-8 string).
+    ///
+    /// See [`Self::following_str`] for an example.
+    pub fn is_boundary_str(&self, input: &str, offset: usize) -> bool {
+        let data = self.payload.get();
+        random_access::is_boundary(input, offset, is_restart_code_point(data, None), |start| {
+            Self::new_and_segment_str(input.get(start..).unwrap_or_default(), data)
+                .map(move |b| b + start)
+        })
+    }
+
+    /// Returns the first grapheme cluster boundary after `offset` in a UTF-16 string, or `None`
+    /// if `offset` is at or past the end of the text.
+    ///
+    /// Only the text around `offset` is segmented.
+    pub fn following_utf16(&self, input: &[u16], offset: usize) -> Option<usize> {
+        let data = self.payload.get();
+        random_access::following(input, offset, is_restart_code_point(data, None), |start| {
+            Self::new_and_segment_utf16(input.get(start..).unwrap_or_default(), data)
+                .map(move |b| b + start)
+        })
+    }
+
+    /// Returns the last grapheme cluster boundary before `offset` in a UTF-16 string, or `None`
+    /// if `offset` is 0.
+    pub fn preceding_utf16(&self, input: &[u16], offset: usize) -> Option<usize> {
+        let data = self.payload.get();
+        random_access::preceding(input, offset, is_restart_code_point(data, None), |start| {
+            Self::new_and_segment_utf16(input.get(start..).unwrap_or_default(), data)
+                .map(move |b| b + start)
+        })
+    }
+
+    /// Returns whether `offset` is a grapheme cluster boundary in a UTF-16 string.
+    pub fn is_boundary_utf16(&self, input: &[u16], offset: usize) -> bool {
+        let data = self.payload.get();
+        random_access::is_boundary(input, offset, is_restart_code_point(data, None), |start| {
+            Self::new_and_segment_utf16(input.get(start..).unwrap_or_default(), data)
+                .map(move |b| b + start)
+        })
+    }
 }
 
 #[test]
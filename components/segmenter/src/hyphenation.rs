@@ -0,0 +1,425 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::indices::Utf16Indices;
+use crate::provider::*;
+use crate::SegmenterError;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::char;
+use icu_provider::prelude::*;
+
+/// Options to tailor hyphenation.
+#[non_exhaustive]
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub struct HyphenatorOptions {
+    /// The minimum number of characters before a hyphenation point, or `None` to use the
+    /// default of the locale's patterns.
+    pub left_min: Option<usize>,
+
+    /// The minimum number of characters after a hyphenation point, or `None` to use the
+    /// default of the locale's patterns.
+    pub right_min: Option<usize>,
+}
+
+/// Finds hyphenation points within words, using Liang's algorithm with TeX hyphenation
+/// patterns.
+///
+/// Words are maximal runs of alphabetic characters. A hyphenation point is the offset
+/// between two characters of a word at which it can be broken, with a hyphen added at the
+/// end of the line.
+///
+/// Compiled data does not include hyphenation patterns; they have to be loaded from a data
+/// provider, using data generated by `icu_datagen` from the TeX hyphenation patterns of the
+/// [hyph-utf8](https://github.com/hyphenation/tex-hyphen) project. Locale fallback is the
+/// responsibility of the data provider, for example a
+/// [`LocaleFallbackProvider`](https://docs.rs/icu_provider_adapters/latest/icu_provider_adapters/fallback/struct.LocaleFallbackProvider.html).
+///
+/// # Examples
+///
+/// Hyphenate with the patterns from Liang's thesis:
+///
+/// ```rust
+/// use icu::locid::locale;
+/// use icu_provider_adapters::any_payload::AnyPayloadProvider;
+/// use icu_segmenter::provider::*;
+/// use icu_segmenter::{Hyphenator, LineSegmenter};
+/// use zerovec::{ZeroMap, ZeroSlice};
+///
+/// let patterns: ZeroMap<str, ZeroSlice<u8>> = [
+///     ("hyph", &[0, 0, 3, 0, 0][..]), // hy3ph
+///     ("hen", &[0, 0, 2, 0]),         // he2n
+///     ("hena", &[0, 0, 0, 0, 4]),     // hena4
+///     ("henat", &[0, 0, 0, 5, 0, 0]), // hen5at
+///     ("na", &[1, 0, 0]),             // 1na
+///     ("nat", &[0, 2, 0, 0]),         // n2at
+///     ("tio", &[1, 0, 0, 0]),         // 1tio
+///     ("io", &[2, 0, 0]),             // 2io
+///     ("on", &[0, 2, 0]),             // o2n
+/// ]
+/// .into_iter()
+/// .map(|(k, v)| (k, ZeroSlice::<u8>::from_ule_slice(v)))
+/// .collect();
+/// let provider =
+///     AnyPayloadProvider::from_owned::<HyphenationPatternsV1Marker>(
+///         HyphenationPatternsV1 {
+///             patterns,
+///             exceptions: ZeroMap::new(),
+///             left_min: 2,
+///             right_min: 3,
+///         },
+///     );
+///
+/// let hyphenator = Hyphenator::try_new_with_any_provider(
+///     &provider,
+///     &locale!("en").into(),
+///     Default::default(),
+/// )
+/// .expect("data is present");
+///
+/// let text = "Hyphenation is fun";
+/// assert_eq!(hyphenator.hyphenate_str(text), [2, 6]);
+///
+/// // Hyphenation points in between line break opportunities:
+/// let segmenter = LineSegmenter::new_auto();
+/// let mut iter =
+///     hyphenator.hyphenate_line_breaks_str(text, segmenter.segment_str(text));
+/// let mut breakpoints = Vec::new();
+/// while let Some(breakpoint) = iter.next() {
+///     breakpoints.push((breakpoint, iter.is_hyphenation_point()));
+/// }
+/// assert_eq!(
+///     breakpoints,
+///     [
+///         (0, false),
+///         (2, true),
+///         (6, true),
+///         (12, false),
+///         (15, false),
+///         (18, false)
+///     ]
+/// );
+/// ```
+#[derive(Debug)]
+pub struct Hyphenator {
+    payload: DataPayload<HyphenationPatternsV1Marker>,
+    left_min: usize,
+    right_min: usize,
+    /// The number of characters in the longest pattern.
+    max_pattern_len: usize,
+}
+
+impl Hyphenator {
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(ANY, Self::try_new_unstable)]
+    pub fn try_new_with_any_provider(
+        provider: &(impl AnyProvider + ?Sized),
+        locale: &DataLocale,
+        options: HyphenatorOptions,
+    ) -> Result<Self, SegmenterError> {
+        Self::try_new_unstable(&provider.as_downcasting(), locale, options)
+    }
+
+    #[cfg(feature = "serde")]
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(BUFFER, Self::try_new_unstable)]
+    pub fn try_new_with_buffer_provider(
+        provider: &(impl BufferProvider + ?Sized),
+        locale: &DataLocale,
+        options: HyphenatorOptions,
+    ) -> Result<Self, SegmenterError> {
+        Self::try_new_unstable(&provider.as_deserializing(), locale, options)
+    }
+
+    /// Constructs a [`Hyphenator`] with the hyphenation patterns for the given locale, loaded
+    /// from a [`DataProvider`].
+    ///
+    /// There is no version of this constructor using compiled data, see [`Hyphenator`].
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    ///
+    /// <div class="stab unstable">⚠️ The bounds on <tt>provider</tt> may change over time, including in SemVer minor releases.</div>
+    pub fn try_new_unstable<D>(
+        provider: &D,
+        locale: &DataLocale,
+        options: HyphenatorOptions,
+    ) -> Result<Self, SegmenterError>
+    where
+        D: DataProvider<HyphenationPatternsV1Marker> + ?Sized,
+    {
+        let payload: DataPayload<HyphenationPatternsV1Marker> = provider
+            .load(DataRequest {
+                locale,
+                metadata: Default::default(),
+            })?
+            .take_payload()?;
+        let data = payload.get();
+        let max_pattern_len = data
+            .patterns
+            .iter_keys()
+            .map(|pattern| pattern.chars().count())
+            .max()
+            .unwrap_or_default();
+        Ok(Self {
+            left_min: options.left_min.unwrap_or(data.left_min as usize),
+            right_min: options.right_min.unwrap_or(data.right_min as usize),
+            max_pattern_len,
+            payload,
+        })
+    }
+
+    /// Returns the hyphenation points of all words in an `str` (a UTF-8 string), as byte
+    /// offsets.
+    pub fn hyphenate_str(&self, input: &str) -> Vec<usize> {
+        let mut points = Vec::new();
+        self.hyphenate_text(input.char_indices(), &mut points);
+        points
+    }
+
+    /// Returns the hyphenation points of all words in a UTF-16 string, as code unit offsets.
+    pub fn hyphenate_utf16(&self, input: &[u16]) -> Vec<usize> {
+        let mut points = Vec::new();
+        self.hyphenate_text(utf16_char_indices(input, 0), &mut points);
+        points
+    }
+
+    /// Merges the hyphenation points of an `str` (a UTF-8 string) into its line break
+    /// opportunities.
+    ///
+    /// `line_breaks` are the line break opportunities of `input`, as returned by
+    /// [`LineSegmenter::segment_str`](crate::LineSegmenter::segment_str). The returned
+    /// iterator yields them together with the hyphenation points of the words in between.
+    pub fn hyphenate_line_breaks_str<'l, 's, I: Iterator<Item = usize>>(
+        &'l self,
+        input: &'s str,
+        line_breaks: I,
+    ) -> HyphenatedLineBreakIteratorUtf8<'l, 's, I> {
+        HyphenatedLineBreakIterator::new(self, input, line_breaks)
+    }
+
+    /// Merges the hyphenation points of a UTF-16 string into its line break opportunities.
+    ///
+    /// `line_breaks` are the line break opportunities of `input`, as returned by
+    /// [`LineSegmenter::segment_utf16`](crate::LineSegmenter::segment_utf16). The returned
+    /// iterator yields them together with the hyphenation points of the words in between.
+    pub fn hyphenate_line_breaks_utf16<'l, 's, I: Iterator<Item = usize>>(
+        &'l self,
+        input: &'s [u16],
+        line_breaks: I,
+    ) -> HyphenatedLineBreakIteratorUtf16<'l, 's, I> {
+        HyphenatedLineBreakIterator::new(self, input, line_breaks)
+    }
+
+    /// Appends the hyphenation points of all words in the text given by its characters and
+    /// their offsets to `points`.
+    fn hyphenate_text(
+        &self,
+        char_indices: impl Iterator<Item = (usize, char)>,
+        points: &mut Vec<usize>,
+    ) {
+        let mut word = Vec::new();
+        for (i, c) in char_indices {
+            if c.is_alphabetic() {
+                word.push((i, c));
+            } else if !word.is_empty() {
+                self.hyphenate_word(&word, points);
+                word.clear();
+            }
+        }
+        self.hyphenate_word(&word, points);
+    }
+
+    /// Appends the hyphenation points of a word given by its characters and their offsets to
+    /// `points`.
+    fn hyphenate_word(&self, word: &[(usize, char)], points: &mut Vec<usize>) {
+        let len = word.len();
+        let first = self.left_min.max(1);
+        let last = len.saturating_sub(self.right_min.max(1));
+        if first > last {
+            return;
+        }
+        let data = self.payload.get();
+
+        // Characters that do not lowercase to a single character are matched as they are.
+        let lowercase = |c: char| {
+            let mut lower = c.to_lowercase();
+            match (lower.next(), lower.next()) {
+                (Some(l), None) => l,
+                _ => c,
+            }
+        };
+
+        // `levels[i]` is the level before the `i`th character.
+        let mut levels = alloc::vec![0u8; len + 1];
+        let lower: String = word.iter().map(|&(_, c)| lowercase(c)).collect();
+        if let Some(exception) = data.exceptions.get(&lower) {
+            for point in exception.iter() {
+                if let Some(level) = levels.get_mut(point as usize) {
+                    *level = 1;
+                }
+            }
+        } else {
+            let dotted: String = ['.']
+                .into_iter()
+                .chain(lower.chars())
+                .chain(['.'])
+                .collect();
+            let offsets: Vec<usize> = dotted
+                .char_indices()
+                .map(|(i, _)| i)
+                .chain([dotted.len()])
+                .collect();
+            for (start, &start_offset) in offsets.iter().enumerate() {
+                for (&end_offset, pattern_len) in
+                    offsets.iter().skip(start + 1).zip(1..=self.max_pattern_len)
+                {
+                    let Some(pattern) = dotted
+                        .get(start_offset..end_offset)
+                        .and_then(|letters| data.patterns.get(letters))
+                    else {
+                        continue;
+                    };
+                    for (k, pattern_level) in pattern.iter().take(pattern_len + 1).enumerate() {
+                        // The dot at the start of the word shifts dotted positions by one.
+                        if let Some(level) =
+                            (start + k).checked_sub(1).and_then(|i| levels.get_mut(i))
+                        {
+                            *level = (*level).max(pattern_level);
+                        }
+                    }
+                }
+            }
+        }
+
+        points.extend(
+            (first..=last)
+                .filter(|&i| levels.get(i).map_or(false, |level| level % 2 == 1))
+                .filter_map(|i| word.get(i).map(|&(offset, _)| offset)),
+        );
+    }
+}
+
+fn utf16_char_indices(input: &[u16], offset: usize) -> impl Iterator<Item = (usize, char)> + '_ {
+    Utf16Indices::new(input).map(move |(i, c)| {
+        (
+            i + offset,
+            char::from_u32(c).unwrap_or(char::REPLACEMENT_CHARACTER),
+        )
+    })
+}
+
+/// A type that can be hyphenated by [`HyphenatedLineBreakIterator`].
+pub trait HyphenationType: Copy {
+    /// Appends the hyphenation points of all words between `start` and `end` to `points`.
+    fn hyphenate_range(
+        self,
+        hyphenator: &Hyphenator,
+        start: usize,
+        end: usize,
+        points: &mut Vec<usize>,
+    );
+}
+
+impl HyphenationType for &str {
+    fn hyphenate_range(
+        self,
+        hyphenator: &Hyphenator,
+        start: usize,
+        end: usize,
+        points: &mut Vec<usize>,
+    ) {
+        if let Some(range) = self.get(start..end) {
+            hyphenator.hyphenate_text(range.char_indices().map(|(i, c)| (i + start, c)), points);
+        }
+    }
+}
+
+impl HyphenationType for &[u16] {
+    fn hyphenate_range(
+        self,
+        hyphenator: &Hyphenator,
+        start: usize,
+        end: usize,
+        points: &mut Vec<usize>,
+    ) {
+        if let Some(range) = self.get(start..end) {
+            hyphenator.hyphenate_text(utf16_char_indices(range, start), points);
+        }
+    }
+}
+
+/// Implements the [`Iterator`] trait over line break opportunities and the hyphenation
+/// points in between.
+///
+/// Lifetimes:
+///
+/// - `'l` = lifetime of the hyphenator object from which this iterator was created
+/// - `'s` = lifetime of the string being hyphenated
+///
+/// The [`Iterator::Item`] is an [`usize`] representing index of a code unit
+/// _after_ the breakpoint.
+#[derive(Debug)]
+pub struct HyphenatedLineBreakIterator<'l, I, Y: HyphenationType> {
+    hyphenator: &'l Hyphenator,
+    input: Y,
+    line_breaks: I,
+    /// Hyphenation points before `pending_break`.
+    points: alloc::vec::IntoIter<usize>,
+    pending_break: Option<usize>,
+    last_break: Option<usize>,
+    is_hyphenation_point: bool,
+}
+
+impl<'l, I: Iterator<Item = usize>, Y: HyphenationType> Iterator
+    for HyphenatedLineBreakIterator<'l, I, Y>
+{
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        loop {
+            if let Some(point) = self.points.next() {
+                self.is_hyphenation_point = true;
+                return Some(point);
+            }
+            if let Some(line_break) = self.pending_break.take() {
+                self.is_hyphenation_point = false;
+                self.last_break = Some(line_break);
+                return Some(line_break);
+            }
+            let line_break = self.line_breaks.next()?;
+            if let Some(start) = self.last_break {
+                let mut points = Vec::new();
+                self.input
+                    .hyphenate_range(self.hyphenator, start, line_break, &mut points);
+                self.points = points.into_iter();
+            }
+            self.pending_break = Some(line_break);
+        }
+    }
+}
+
+impl<'l, I: Iterator<Item = usize>, Y: HyphenationType> HyphenatedLineBreakIterator<'l, I, Y> {
+    fn new(hyphenator: &'l Hyphenator, input: Y, line_breaks: I) -> Self {
+        Self {
+            hyphenator,
+            input,
+            line_breaks,
+            points: Vec::new().into_iter(),
+            pending_break: None,
+            last_break: None,
+            is_hyphenation_point: false,
+        }
+    }
+
+    /// Returns whether the last breakpoint returned by the iterator is a hyphenation point,
+    /// as opposed to a line break opportunity.
+    pub fn is_hyphenation_point(&self) -> bool {
+        self.is_hyphenation_point
+    }
+}
+
+/// Hyphenated line break iterator for an `str` (a UTF-8 string).
+pub type HyphenatedLineBreakIteratorUtf8<'l, 's, I> = HyphenatedLineBreakIterator<'l, I, &'s str>;
+
+/// Hyphenated line break iterator for a UTF-16 string.
+pub type HyphenatedLineBreakIteratorUtf16<'l, 's, I> =
+    HyphenatedLineBreakIterator<'l, I, &'s [u16]>;
@@ -82,6 +82,130 @@ impl<'a> Iterator for Utf16Indices<'a> {
     }
 }
 
+/// Similar to [`core::str::CharIndices`] for a string split into several chunks.
+///
+/// The first element of the [`Iterator::Item`] is an offset into the concatenation of the chunks.
+#[derive(Clone)]
+pub struct ChunkedCharIndices<'s, I> {
+    /// The offset of `current` in the concatenation of the chunks.
+    chunk_offset: usize,
+    current: core::str::CharIndices<'s>,
+    current_len: usize,
+    chunks: I,
+}
+
+impl<'s, I: Iterator<Item = &'s str>> ChunkedCharIndices<'s, I> {
+    pub fn new(chunks: I) -> Self {
+        Self {
+            chunk_offset: 0,
+            current: "".char_indices(),
+            current_len: 0,
+            chunks,
+        }
+    }
+}
+
+// Not derived, so that the chunk iterator does not need to implement `Debug`.
+impl<'s, I> core::fmt::Debug for ChunkedCharIndices<'s, I> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ChunkedCharIndices")
+            .field("chunk_offset", &self.chunk_offset)
+            .field("current", &self.current)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'s, I: Iterator<Item = &'s str>> Iterator for ChunkedCharIndices<'s, I> {
+    type Item = (usize, char);
+
+    #[inline]
+    fn next(&mut self) -> Option<(usize, char)> {
+        loop {
+            if let Some((index, ch)) = self.current.next() {
+                return Some((self.chunk_offset + index, ch));
+            }
+            let chunk = self.chunks.next()?;
+            self.chunk_offset += self.current_len;
+            self.current = chunk.char_indices();
+            self.current_len = chunk.len();
+        }
+    }
+}
+
+/// Similar to [`Utf16Indices`] for a UTF-16 string split into several chunks.
+///
+/// The first element of the [`Iterator::Item`] is an offset into the concatenation of the
+/// chunks. Surrogate pairs may be split across chunks.
+#[derive(Clone)]
+pub struct ChunkedUtf16Indices<'s, I> {
+    /// The offset of `current` in the concatenation of the chunks.
+    chunk_offset: usize,
+    /// The offset of the next code unit in `current`.
+    front_offset: usize,
+    current: &'s [u16],
+    chunks: I,
+}
+
+impl<'s, I: Iterator<Item = &'s [u16]>> ChunkedUtf16Indices<'s, I> {
+    pub fn new(chunks: I) -> Self {
+        Self {
+            chunk_offset: 0,
+            front_offset: 0,
+            current: &[],
+            chunks,
+        }
+    }
+
+    fn next_code_unit(&mut self) -> Option<(usize, u16)> {
+        loop {
+            if let Some(ch) = self.current.get(self.front_offset) {
+                self.front_offset += 1;
+                return Some((self.chunk_offset + self.front_offset - 1, *ch));
+            }
+            let chunk = self.chunks.next()?;
+            self.chunk_offset += self.current.len();
+            self.front_offset = 0;
+            self.current = chunk;
+        }
+    }
+}
+
+// Not derived, so that the chunk iterator does not need to implement `Debug`.
+impl<'s, I> core::fmt::Debug for ChunkedUtf16Indices<'s, I> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ChunkedUtf16Indices")
+            .field("chunk_offset", &self.chunk_offset)
+            .field("front_offset", &self.front_offset)
+            .field("current", &self.current)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'s, I: Iterator<Item = &'s [u16]> + Clone> Iterator for ChunkedUtf16Indices<'s, I> {
+    type Item = (usize, u32);
+
+    #[inline]
+    fn next(&mut self) -> Option<(usize, u32)> {
+        let (index, ch) = self.next_code_unit()?;
+
+        let mut ch = ch as u32;
+        if (ch & 0xfc00) != 0xd800 {
+            return Some((index, ch));
+        }
+
+        let mut next_iter = self.clone();
+        if let Some((_, next)) = next_iter.next_code_unit() {
+            let next = next as u32;
+            if (next & 0xfc00) == 0xdc00 {
+                // Combine low and high surrogates to UTF-32 code point.
+                ch = ((ch & 0x3ff) << 10) + (next & 0x3ff) + 0x10000;
+                *self = next_iter;
+            }
+        }
+        Some((index, ch))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::indices::*;
@@ -126,4 +250,26 @@ mod tests {
         let n = indices.next();
         assert_eq!(n, None);
     }
+
+    #[test]
+    fn chunked_char_indices() {
+        let chunks = ["", "a\u{e9}", "", "\u{1f603}b", ""];
+        let indices: Vec<_> = ChunkedCharIndices::new(chunks.into_iter()).collect();
+        assert_eq!(
+            indices,
+            [(0, 'a'), (1, '\u{e9}'), (3, '\u{1f603}'), (7, 'b')]
+        );
+    }
+
+    #[test]
+    fn chunked_utf16_indices() {
+        let utf16 = [0xd83d, 0xde03, 0x0020, 0xd83c, 0xdf00, 0xd800, 0x0020];
+        let expected: Vec<_> = Utf16Indices::new(&utf16).collect();
+        // Split at every position, including in the middle of surrogate pairs.
+        for i in 0..=utf16.len() {
+            let chunks = [&utf16[..i], &[], &utf16[i..]];
+            let indices: Vec<_> = ChunkedUtf16Indices::new(chunks.into_iter()).collect();
+            assert_eq!(indices, expected, "split at {i}");
+        }
+    }
 }
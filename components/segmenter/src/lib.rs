@@ -123,8 +123,12 @@ extern crate alloc;
 
 mod complex;
 mod error;
+mod hyphenation;
 mod indices;
 mod iterator_helpers;
+mod random_access;
+#[cfg(any(feature = "rule_builder", feature = "rule_builder_icu4c"))]
+mod rule_builder;
 mod rule_segmenter;
 
 mod grapheme;
@@ -141,6 +145,8 @@ pub mod symbols;
 // Main Segmenter and BreakIterator public types
 pub use crate::grapheme::GraphemeClusterBreakIterator;
 pub use crate::grapheme::GraphemeClusterSegmenter;
+pub use crate::hyphenation::HyphenatedLineBreakIterator;
+pub use crate::hyphenation::Hyphenator;
 pub use crate::line::LineBreakIterator;
 pub use crate::line::LineSegmenter;
 pub use crate::sentence::SentenceBreakIterator;
@@ -148,25 +154,39 @@ pub use crate::sentence::SentenceSegmenter;
 pub use crate::word::WordBreakIterator;
 pub use crate::word::WordSegmenter;
 
+#[cfg(any(feature = "rule_builder", feature = "rule_builder_icu4c"))]
+pub use crate::rule_builder::RuleBreakDataBuilder;
+
 // Options structs and enums
+pub use crate::hyphenation::HyphenatorOptions;
 pub use crate::line::LineBreakOptions;
 pub use crate::line::LineBreakStrictness;
 pub use crate::line::LineBreakWordOption;
 pub use crate::word::WordType;
 
 // Typedefs
+pub use crate::grapheme::GraphemeClusterBreakIteratorChunkedUtf16;
+pub use crate::grapheme::GraphemeClusterBreakIteratorChunkedUtf8;
 pub use crate::grapheme::GraphemeClusterBreakIteratorLatin1;
 pub use crate::grapheme::GraphemeClusterBreakIteratorPotentiallyIllFormedUtf8;
 pub use crate::grapheme::GraphemeClusterBreakIteratorUtf16;
 pub use crate::grapheme::GraphemeClusterBreakIteratorUtf8;
+pub use crate::hyphenation::HyphenatedLineBreakIteratorUtf16;
+pub use crate::hyphenation::HyphenatedLineBreakIteratorUtf8;
+pub use crate::line::LineBreakIteratorChunkedUtf16;
+pub use crate::line::LineBreakIteratorChunkedUtf8;
 pub use crate::line::LineBreakIteratorLatin1;
 pub use crate::line::LineBreakIteratorPotentiallyIllFormedUtf8;
 pub use crate::line::LineBreakIteratorUtf16;
 pub use crate::line::LineBreakIteratorUtf8;
+pub use crate::sentence::SentenceBreakIteratorChunkedUtf16;
+pub use crate::sentence::SentenceBreakIteratorChunkedUtf8;
 pub use crate::sentence::SentenceBreakIteratorLatin1;
 pub use crate::sentence::SentenceBreakIteratorPotentiallyIllFormedUtf8;
 pub use crate::sentence::SentenceBreakIteratorUtf16;
 pub use crate::sentence::SentenceBreakIteratorUtf8;
+pub use crate::word::WordBreakIteratorChunkedUtf16;
+pub use crate::word::WordBreakIteratorChunkedUtf8;
 pub use crate::word::WordBreakIteratorLatin1;
 pub use crate::word::WordBreakIteratorPotentiallyIllFormedUtf8;
 pub use crate::word::WordBreakIteratorUtf16;
@@ -5,13 +5,18 @@
 use crate::complex::*;
 use crate::indices::*;
 use crate::provider::*;
+use crate::random_access;
+use crate::rule_segmenter::restart_properties;
 use crate::symbols::*;
 use crate::SegmenterError;
 use alloc::string::String;
 use alloc::vec;
 use alloc::vec::Vec;
 use core::char;
+use core::marker::PhantomData;
 use core::str::CharIndices;
+use icu_locid::extensions::unicode::{key, value};
+use icu_locid::subtags::language;
 use icu_provider::prelude::*;
 use utf8_iter::Utf8CharIndices;
 
@@ -100,6 +105,52 @@ impl Default for LineBreakOptions {
     }
 }
 
+impl LineBreakOptions {
+    /// Returns the line break options for the given locale.
+    ///
+    /// [`Self::ja_zh`] is set for Chinese and Japanese, and [`Self::strictness`] and
+    /// [`Self::word_option`] are read from the `-u-lb` and `-u-lw` keywords if present.
+    ///
+    /// These are the options used by [`LineSegmenter::try_new_auto_for_locale`] and the other
+    /// locale-aware constructors, which also apply the line break tailorings of the locale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locid::locale;
+    /// use icu::segmenter::{LineBreakOptions, LineBreakStrictness, LineSegmenter};
+    ///
+    /// let options = LineBreakOptions::for_locale(&locale!("ja-u-lb-loose").into());
+    /// assert!(options.ja_zh);
+    /// assert_eq!(options.strictness, LineBreakStrictness::Loose);
+    ///
+    /// let segmenter = LineSegmenter::new_auto_with_options(options);
+    /// let breakpoints: Vec<usize> =
+    ///     segmenter.segment_str("ラーメン〜です").collect();
+    /// assert_eq!(&breakpoints, &[0, 3, 6, 9, 12, 15, 18, 21]);
+    /// ```
+    pub fn for_locale(locale: &DataLocale) -> Self {
+        let mut options = Self::default();
+        let language = locale.language();
+        options.ja_zh = language == language!("ja") || language == language!("zh");
+        if let Some(lb) = locale.get_unicode_ext(&key!("lb")) {
+            if lb == value!("loose") {
+                options.strictness = LineBreakStrictness::Loose;
+            } else if lb == value!("normal") {
+                options.strictness = LineBreakStrictness::Normal;
+            }
+        }
+        if let Some(lw) = locale.get_unicode_ext(&key!("lw")) {
+            if lw == value!("breakall") {
+                options.word_option = LineBreakWordOption::BreakAll;
+            } else if lw == value!("keepall") {
+                options.word_option = LineBreakWordOption::KeepAll;
+            }
+        }
+        options
+    }
+}
+
 /// Line break iterator for an `str` (a UTF-8 string).
 ///
 /// For examples of use, see [`LineSegmenter`].
@@ -121,6 +172,18 @@ pub type LineBreakIteratorLatin1<'l, 's> = LineBreakIterator<'l, 's, LineBreakTy
 /// For examples of use, see [`LineSegmenter`].
 pub type LineBreakIteratorUtf16<'l, 's> = LineBreakIterator<'l, 's, LineBreakTypeUtf16>;
 
+/// Line break iterator for an `str` (a UTF-8 string) split into chunks.
+///
+/// For examples of use, see [`LineSegmenter`].
+pub type LineBreakIteratorChunkedUtf8<'l, 's, I> =
+    LineBreakIterator<'l, 's, LineBreakTypeChunkedUtf8<I>>;
+
+/// Line break iterator for a UTF-16 string split into chunks.
+///
+/// For examples of use, see [`LineSegmenter`].
+pub type LineBreakIteratorChunkedUtf16<'l, 's, I> =
+    LineBreakIterator<'l, 's, LineBreakTypeChunkedUtf16<I>>;
+
 /// Supports loading line break data, and creating line break iterators for different string
 /// encodings.
 ///
@@ -232,6 +295,7 @@ pub struct LineSegmenter {
     options: LineBreakOptions,
     payload: DataPayload<LineBreakDataV1Marker>,
     complex: ComplexPayloads,
+    payload_locale_override: Option<DataPayload<LineBreakDataOverrideV1Marker>>,
 }
 
 impl LineSegmenter {
@@ -429,6 +493,7 @@ impl LineSegmenter {
                 crate::provider::Baked::SINGLETON_SEGMENTER_LINE_V1,
             ),
             complex: ComplexPayloads::new_lstm(),
+            payload_locale_override: None,
         }
     }
 
@@ -463,6 +528,7 @@ impl LineSegmenter {
             options,
             payload: provider.load(Default::default())?.take_payload()?,
             complex: ComplexPayloads::try_new_lstm(provider)?,
+            payload_locale_override: None,
         })
     }
 
@@ -491,6 +557,7 @@ impl LineSegmenter {
             // [1]: https://www.unicode.org/reports/tr14/#ID
             // [2]: https://www.unicode.org/reports/tr14/#SA
             complex: ComplexPayloads::new_southeast_asian(),
+            payload_locale_override: None,
         }
     }
 
@@ -529,6 +596,155 @@ impl LineSegmenter {
             // [1]: https://www.unicode.org/reports/tr14/#ID
             // [2]: https://www.unicode.org/reports/tr14/#SA
             complex: ComplexPayloads::try_new_southeast_asian(provider)?,
+            payload_locale_override: None,
+        })
+    }
+
+    #[cfg(feature = "auto")]
+    icu_provider::gen_any_buffer_data_constructors!(
+        locale: include,
+        options: skip,
+        error: SegmenterError,
+        /// Constructs a [`LineSegmenter`] with the line break tailorings and
+        /// [`LineBreakOptions::for_locale`] for the given locale, and the best available compiled
+        /// data for complex scripts (Khmer, Lao, Myanmar, and Thai).
+        ///
+        /// See [`Self::new_auto`] for the handling of complex scripts.
+        ///
+        /// # Examples
+        ///
+        /// In Chinese and Japanese, the curly quotation marks behave like brackets:
+        ///
+        /// ```
+        /// use icu::locid::locale;
+        /// use icu::segmenter::LineSegmenter;
+        ///
+        /// let text = "彼は“はい”と言った";
+        ///
+        /// let segmenter = LineSegmenter::new_auto();
+        /// let breakpoints: Vec<usize> = segmenter.segment_str(text).collect();
+        /// assert_eq!(&breakpoints, &[0, 3, 12, 21, 27, 30]);
+        ///
+        /// let segmenter =
+        ///     LineSegmenter::try_new_auto_for_locale(&locale!("ja").into())
+        ///         .expect("locale should be present");
+        /// let breakpoints: Vec<usize> = segmenter.segment_str(text).collect();
+        /// assert_eq!(&breakpoints, &[0, 3, 6, 12, 18, 21, 27, 30]);
+        /// ```
+        functions: [
+            try_new_auto_for_locale,
+            try_new_auto_for_locale_with_any_provider,
+            try_new_auto_for_locale_with_buffer_provider,
+            try_new_auto_for_locale_unstable,
+            Self,
+        ]
+    );
+
+    #[cfg(feature = "auto")]
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::try_new_auto_for_locale)]
+    pub fn try_new_auto_for_locale_unstable<D>(
+        provider: &D,
+        locale: &DataLocale,
+    ) -> Result<Self, SegmenterError>
+    where
+        D: DataProvider<LineBreakDataV1Marker>
+            + DataProvider<LineBreakDataOverrideV1Marker>
+            + DataProvider<LstmForWordLineAutoV1Marker>
+            + DataProvider<GraphemeClusterBreakDataV1Marker>
+            + ?Sized,
+    {
+        Self::try_new_lstm_for_locale_unstable(provider, locale)
+    }
+
+    #[cfg(feature = "lstm")]
+    icu_provider::gen_any_buffer_data_constructors!(
+        locale: include,
+        options: skip,
+        error: SegmenterError,
+        /// Constructs a [`LineSegmenter`] with the line break tailorings and
+        /// [`LineBreakOptions::for_locale`] for the given locale, and compiled LSTM data for
+        /// complex scripts (Khmer, Lao, Myanmar, and Thai).
+        ///
+        /// See [`Self::new_lstm`] for the handling of complex scripts.
+        functions: [
+            try_new_lstm_for_locale,
+            try_new_lstm_for_locale_with_any_provider,
+            try_new_lstm_for_locale_with_buffer_provider,
+            try_new_lstm_for_locale_unstable,
+            Self,
+        ]
+    );
+
+    #[cfg(feature = "lstm")]
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::try_new_lstm_for_locale)]
+    pub fn try_new_lstm_for_locale_unstable<D>(
+        provider: &D,
+        locale: &DataLocale,
+    ) -> Result<Self, SegmenterError>
+    where
+        D: DataProvider<LineBreakDataV1Marker>
+            + DataProvider<LineBreakDataOverrideV1Marker>
+            + DataProvider<LstmForWordLineAutoV1Marker>
+            + DataProvider<GraphemeClusterBreakDataV1Marker>
+            + ?Sized,
+    {
+        Ok(Self {
+            options: LineBreakOptions::for_locale(locale),
+            payload: provider.load(Default::default())?.take_payload()?,
+            complex: ComplexPayloads::try_new_lstm(provider)?,
+            payload_locale_override: Some(
+                provider
+                    .load(DataRequest {
+                        locale,
+                        metadata: Default::default(),
+                    })?
+                    .take_payload()?,
+            ),
+        })
+    }
+
+    icu_provider::gen_any_buffer_data_constructors!(
+        locale: include,
+        options: skip,
+        error: SegmenterError,
+        /// Constructs a [`LineSegmenter`] with the line break tailorings and
+        /// [`LineBreakOptions::for_locale`] for the given locale, and compiled dictionary data for
+        /// complex scripts (Khmer, Lao, Myanmar, and Thai).
+        ///
+        /// See [`Self::new_dictionary`] for the handling of complex scripts.
+        functions: [
+            try_new_dictionary_for_locale,
+            try_new_dictionary_for_locale_with_any_provider,
+            try_new_dictionary_for_locale_with_buffer_provider,
+            try_new_dictionary_for_locale_unstable,
+            Self,
+        ]
+    );
+
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::try_new_dictionary_for_locale)]
+    pub fn try_new_dictionary_for_locale_unstable<D>(
+        provider: &D,
+        locale: &DataLocale,
+    ) -> Result<Self, SegmenterError>
+    where
+        D: DataProvider<LineBreakDataV1Marker>
+            + DataProvider<LineBreakDataOverrideV1Marker>
+            + DataProvider<DictionaryForWordLineExtendedV1Marker>
+            + DataProvider<GraphemeClusterBreakDataV1Marker>
+            + ?Sized,
+    {
+        Ok(Self {
+            options: LineBreakOptions::for_locale(locale),
+            payload: provider.load(Default::default())?.take_payload()?,
+            complex: ComplexPayloads::try_new_southeast_asian(provider)?,
+            payload_locale_override: Some(
+                provider
+                    .load(DataRequest {
+                        locale,
+                        metadata: Default::default(),
+                    })?
+                    .take_payload()?,
+            ),
         })
     }
 
@@ -542,6 +758,7 @@ impl LineSegmenter {
             current_pos_data: None,
             result_cache: Vec::new(),
             data: self.payload.get(),
+            locale_override: self.payload_locale_override.as_ref().map(|p| p.get()),
             options: &self.options,
             complex: &self.complex,
         }
@@ -561,6 +778,7 @@ impl LineSegmenter {
             current_pos_data: None,
             result_cache: Vec::new(),
             data: self.payload.get(),
+            locale_override: self.payload_locale_override.as_ref().map(|p| p.get()),
             options: &self.options,
             complex: &self.complex,
         }
@@ -575,6 +793,7 @@ impl LineSegmenter {
             current_pos_data: None,
             result_cache: Vec::new(),
             data: self.payload.get(),
+            locale_override: self.payload_locale_override.as_ref().map(|p| p.get()),
             options: &self.options,
             complex: &self.complex,
         }
@@ -590,10 +809,159 @@ impl LineSegmenter {
             current_pos_data: None,
             result_cache: Vec::new(),
             data: self.payload.get(),
+            locale_override: self.payload_locale_override.as_ref().map(|p| p.get()),
             options: &self.options,
             complex: &self.complex,
         }
     }
+
+    /// Creates a line break iterator for an `str` (a UTF-8 string) split into chunks, such as
+    /// the chunks of a rope.
+    ///
+    /// The break opportunities are offsets into the concatenation of the chunks, and are the
+    /// same as for the concatenated string, including in complex scripts.
+    ///
+    /// `chunks` can be any iterator over the chunks; it is cloned, so it should be cheap to clone.
+    pub fn segment_str_chunks<'l, 's, I>(
+        &'l self,
+        chunks: I,
+    ) -> LineBreakIteratorChunkedUtf8<'l, 's, I::IntoIter>
+    where
+        I: IntoIterator<Item = &'s str>,
+        I::IntoIter: Clone,
+    {
+        let chunks = chunks.into_iter();
+        LineBreakIterator {
+            iter: ChunkedCharIndices::new(chunks.clone()),
+            len: chunks.map(str::len).sum(),
+            current_pos_data: None,
+            result_cache: Vec::new(),
+            data: self.payload.get(),
+            locale_override: self.payload_locale_override.as_ref().map(|p| p.get()),
+            options: &self.options,
+            complex: &self.complex,
+        }
+    }
+
+    /// Creates a line break iterator for a UTF-16 string split into chunks.
+    ///
+    /// The break opportunities are offsets into the concatenation of the chunks, and are the
+    /// same as for the concatenated string. Surrogate pairs may be split across chunks.
+    ///
+    /// `chunks` can be any iterator over the chunks; it is cloned, so it should be cheap to clone.
+    pub fn segment_utf16_chunks<'l, 's, I>(
+        &'l self,
+        chunks: I,
+    ) -> LineBreakIteratorChunkedUtf16<'l, 's, I::IntoIter>
+    where
+        I: IntoIterator<Item = &'s [u16]>,
+        I::IntoIter: Clone,
+    {
+        let chunks = chunks.into_iter();
+        LineBreakIterator {
+            iter: ChunkedUtf16Indices::new(chunks.clone()),
+            len: chunks.map(<[u16]>::len).sum(),
+            current_pos_data: None,
+            result_cache: Vec::new(),
+            data: self.payload.get(),
+            locale_override: self.payload_locale_override.as_ref().map(|p| p.get()),
+            options: &self.options,
+            complex: &self.complex,
+        }
+    }
+
+    /// Returns the first line break opportunity after `offset` in an `str` (a UTF-8 string), or
+    /// `None` if `offset` is at or past the end of the text.
+    ///
+    /// Only the text around `offset` is segmented.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use icu::segmenter::LineSegmenter;
+    /// let segmenter = LineSegmenter::new_auto();
+    ///
+    /// let text = "Hello World";
+    /// assert_eq!(segmenter.following_str(text, 2), Some(6));
+    /// assert_eq!(segmenter.preceding_str(text, 8), Some(6));
+    /// assert!(segmenter.is_boundary_str(text, 6));
+    /// assert!(!segmenter.is_boundary_str(text, 5));
+    /// ```
+    pub fn following_str(&self, input: &str, offset: usize) -> Option<usize> {
+        random_access::following(input, offset, self.is_restart_code_point(), |start| {
+            self.segment_str(input.get(start..).unwrap_or_default())
+                .map(move |b| b + start)
+        })
+    }
+
+    /// Returns the last line break opportunity before `offset` in an `str` (a UTF-8 string), or
+    /// `None` if `offset` is 0.
+    ///
+    /// See [`Self::following_str`] for an example.
+    pub fn preceding_str(&self, input: &str, offset: usize) -> Option<usize> {
+        random_access::preceding(input, offset, self.is_restart_code_point(), |start| {
+            self.segment_str(input.get(start..).unwrap_or_default())
+                .map(move |b| b + start)
+        })
+    }
+
+    /// Returns whether `offset` is a line break opportunity in an `str` (a UTF-8 string).
+    ///
+    /// See [`Self::following_str`] for an example.
+    pub fn is_boundary_str(&self, input: &str, offset: usize) -> bool {
+        random_access::is_boundary(input, offset, self.is_restart_code_point(), |start| {
+            self.segment_str(input.get(start..).unwrap_or_default())
+                .map(move |b| b + start)
+        })
+    }
+
+    /// Returns the first line break opportunity after `offset` in a UTF-16 string, or `None`
+    /// if `offset` is at or past the end of the text.
+    ///
+    /// Only the text around `offset` is segmented.
+    pub fn following_utf16(&self, input: &[u16], offset: usize) -> Option<usize> {
+        random_access::following(input, offset, self.is_restart_code_point(), |start| {
+            self.segment_utf16(input.get(start..).unwrap_or_default())
+                .map(move |b| b + start)
+        })
+    }
+
+    /// Returns the last line break opportunity before `offset` in a UTF-16 string, or `None`
+    /// if `offset` is 0.
+    pub fn preceding_utf16(&self, input: &[u16], offset: usize) -> Option<usize> {
+        random_access::preceding(input, offset, self.is_restart_code_point(), |start| {
+            self.segment_utf16(input.get(start..).unwrap_or_default())
+                .map(move |b| b + start)
+        })
+    }
+
+    /// Returns whether `offset` is a line break opportunity in a UTF-16 string.
+    pub fn is_boundary_utf16(&self, input: &[u16], offset: usize) -> bool {
+        random_access::is_boundary(input, offset, self.is_restart_code_point(), |start| {
+            self.segment_utf16(input.get(start..).unwrap_or_default())
+                .map(move |b| b + start)
+        })
+    }
+
+    fn is_restart_code_point(&self) -> impl Fn(u32) -> bool + '_ {
+        let data = self.payload.get();
+        let locale_override = self.payload_locale_override.as_ref().map(|p| p.get());
+        let restart_properties = restart_properties(data);
+        move |codepoint| {
+            let prop =
+                get_linebreak_property_override(locale_override, codepoint).unwrap_or_else(|| {
+                    get_linebreak_property_utf32_with_rule(
+                        &data.property_table,
+                        codepoint,
+                        self.options.strictness,
+                        self.options.word_option,
+                    )
+                });
+            !use_complex_breaking_utf32(&data.property_table, codepoint)
+                && prop < u64::BITS as u8
+                && restart_properties & 1 << prop != 0
+        }
+    }
 }
 
 fn get_linebreak_property_utf32_with_rule(
@@ -620,6 +988,15 @@ fn get_linebreak_property_utf32_with_rule(
     prop
 }
 
+#[inline]
+fn get_linebreak_property_override(
+    locale_override: Option<&RuleBreakDataOverrideV1<'_>>,
+    codepoint: u32,
+) -> Option<u8> {
+    locale_override
+        .and_then(|o| char::from_u32(codepoint).and_then(|c| o.property_overrides.get_copied(&c)))
+}
+
 #[inline]
 fn get_linebreak_property_latin1(property_table: &RuleBreakPropertyTable<'_>, codepoint: u8) -> u8 {
     // Note: Default value is 0 == UNKNOWN
@@ -833,6 +1210,7 @@ pub struct LineBreakIterator<'l, 's, Y: LineBreakType<'l, 's> + ?Sized> {
     current_pos_data: Option<(usize, Y::CharType)>,
     result_cache: Vec<usize>,
     data: &'l RuleBreakDataV1<'l>,
+    locale_override: Option<&'l RuleBreakDataOverrideV1<'l>>,
     options: &'l LineBreakOptions,
     complex: &'l ComplexPayloads,
 }
@@ -1025,7 +1403,8 @@ impl<'l, 's, Y: LineBreakType<'l, 's>> LineBreakIterator<'l, 's, Y> {
     }
 
     fn get_linebreak_property(&self, codepoint: Y::CharType) -> u8 {
-        Y::get_linebreak_property_with_rule(self, codepoint)
+        get_linebreak_property_override(self.locale_override, codepoint.into())
+            .unwrap_or_else(|| Y::get_linebreak_property_with_rule(self, codepoint))
     }
 
     fn get_current_linebreak_property(&self) -> Option<u8> {
@@ -1089,6 +1468,41 @@ impl<'l, 's> LineBreakType<'l, 's> for LineBreakTypeUtf8 {
     }
 }
 
+#[derive(Debug)]
+pub struct LineBreakTypeChunkedUtf8<I>(PhantomData<I>);
+
+impl<'l, 's, I: Iterator<Item = &'s str> + Clone> LineBreakType<'l, 's>
+    for LineBreakTypeChunkedUtf8<I>
+{
+    type IterAttr = ChunkedCharIndices<'s, I>;
+    type CharType = char;
+
+    fn get_linebreak_property_with_rule(iterator: &LineBreakIterator<'l, 's, Self>, c: char) -> u8 {
+        get_linebreak_property_with_rule(
+            &iterator.data.property_table,
+            c,
+            iterator.options.strictness,
+            iterator.options.word_option,
+        )
+    }
+
+    #[inline]
+    fn use_complex_breaking(iterator: &LineBreakIterator<'l, 's, Self>, c: char) -> bool {
+        use_complex_breaking_utf32(&iterator.data.property_table, c as u32)
+    }
+
+    fn get_current_position_character_len(iterator: &LineBreakIterator<'l, 's, Self>) -> usize {
+        iterator.get_current_codepoint().map_or(0, |c| c.len_utf8())
+    }
+
+    fn handle_complex_language(
+        iter: &mut LineBreakIterator<'l, 's, Self>,
+        left_codepoint: char,
+    ) -> Option<usize> {
+        handle_complex_language_utf8(iter, left_codepoint)
+    }
+}
+
 #[derive(Debug)]
 pub struct LineBreakTypePotentiallyIllFormedUtf8;
 
@@ -1234,57 +1648,102 @@ impl<'l, 's> LineBreakType<'l, 's> for LineBreakTypeUtf16 {
     }
 
     fn handle_complex_language(
-        iterator: &mut LineBreakIterator<Self>,
+        iter: &mut LineBreakIterator<'l, 's, Self>,
         left_codepoint: Self::CharType,
     ) -> Option<usize> {
-        // word segmenter doesn't define break rules for some languages such as Thai.
-        let start_iter = iterator.iter.clone();
-        let start_point = iterator.current_pos_data;
-        let mut s = vec![left_codepoint as u16];
-        loop {
-            debug_assert!(!iterator.is_eof());
-            s.push(iterator.get_current_codepoint()? as u16);
-            iterator.advance_iter();
-            if let Some(current_codepoint) = iterator.get_current_codepoint() {
-                if !Self::use_complex_breaking(iterator, current_codepoint) {
-                    break;
-                }
-            } else {
-                // EOF
+        handle_complex_language_utf16(iter, left_codepoint)
+    }
+}
+
+#[derive(Debug)]
+pub struct LineBreakTypeChunkedUtf16<I>(PhantomData<I>);
+
+impl<'l, 's, I: Iterator<Item = &'s [u16]> + Clone> LineBreakType<'l, 's>
+    for LineBreakTypeChunkedUtf16<I>
+{
+    type IterAttr = ChunkedUtf16Indices<'s, I>;
+    type CharType = u32;
+
+    fn get_linebreak_property_with_rule(iterator: &LineBreakIterator<'l, 's, Self>, c: u32) -> u8 {
+        get_linebreak_property_utf32_with_rule(
+            &iterator.data.property_table,
+            c,
+            iterator.options.strictness,
+            iterator.options.word_option,
+        )
+    }
+
+    #[inline]
+    fn use_complex_breaking(iterator: &LineBreakIterator<'l, 's, Self>, c: u32) -> bool {
+        use_complex_breaking_utf32(&iterator.data.property_table, c)
+    }
+
+    fn get_current_position_character_len(iterator: &LineBreakIterator<'l, 's, Self>) -> usize {
+        match iterator.get_current_codepoint() {
+            None => 0,
+            Some(ch) if ch >= 0x10000 => 2,
+            _ => 1,
+        }
+    }
+
+    fn handle_complex_language(
+        iter: &mut LineBreakIterator<'l, 's, Self>,
+        left_codepoint: Self::CharType,
+    ) -> Option<usize> {
+        handle_complex_language_utf16(iter, left_codepoint)
+    }
+}
+
+/// handle_complex_language impl for UTF16 iterators
+fn handle_complex_language_utf16<'l, 's, T>(
+    iter: &mut LineBreakIterator<'l, 's, T>,
+    left_codepoint: T::CharType,
+) -> Option<usize>
+where
+    T: LineBreakType<'l, 's, CharType = u32>,
+{
+    // word segmenter doesn't define break rules for some languages such as Thai.
+    let start_iter = iter.iter.clone();
+    let start_point = iter.current_pos_data;
+    let mut s = vec![left_codepoint as u16];
+    loop {
+        debug_assert!(!iter.is_eof());
+        s.push(iter.get_current_codepoint()? as u16);
+        iter.advance_iter();
+        if let Some(current_codepoint) = iter.get_current_codepoint() {
+            if !T::use_complex_breaking(iter, current_codepoint) {
                 break;
             }
+        } else {
+            // EOF
+            break;
         }
+    }
 
-        // Restore iterator to move to head of complex string
-        iterator.iter = start_iter;
-        iterator.current_pos_data = start_point;
-        let breaks = complex_language_segment_utf16(iterator.complex, &s);
-        iterator.result_cache = breaks;
-        // result_cache vector is utf-16 index that is in BMP.
-        let first_pos = *iterator.result_cache.first()?;
-        let mut i = 1;
-        loop {
-            if i == first_pos {
-                // Re-calculate breaking offset
-                iterator.result_cache = iterator
-                    .result_cache
-                    .iter()
-                    .skip(1)
-                    .map(|r| r - i)
-                    .collect();
-                return iterator.get_current_position();
-            }
-            debug_assert!(
-                i < first_pos,
-                "we should always arrive at first_pos: near index {:?}",
-                iterator.get_current_position()
-            );
-            i += 1;
-            iterator.advance_iter();
-            if iterator.is_eof() {
-                iterator.result_cache.clear();
-                return Some(iterator.len);
-            }
+    // Restore iterator to move to head of complex string
+    iter.iter = start_iter;
+    iter.current_pos_data = start_point;
+    let breaks = complex_language_segment_utf16(iter.complex, &s);
+    iter.result_cache = breaks;
+    // result_cache vector is utf-16 index that is in BMP.
+    let first_pos = *iter.result_cache.first()?;
+    let mut i = 1;
+    loop {
+        if i == first_pos {
+            // Re-calculate breaking offset
+            iter.result_cache = iter.result_cache.iter().skip(1).map(|r| r - i).collect();
+            return iter.get_current_position();
+        }
+        debug_assert!(
+            i < first_pos,
+            "we should always arrive at first_pos: near index {:?}",
+            iter.get_current_position()
+        );
+        i += 1;
+        iter.advance_iter();
+        if iter.is_eof() {
+            iter.result_cache.clear();
+            return Some(iter.len);
         }
     }
 }
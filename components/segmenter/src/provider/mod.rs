@@ -24,7 +24,7 @@ pub use crate::rule_segmenter::RuleStatusType;
 
 use icu_collections::codepointtrie::CodePointTrie;
 use icu_provider::prelude::*;
-use zerovec::ZeroVec;
+use zerovec::{VarZeroVec, ZeroMap, ZeroSlice, ZeroVec};
 
 #[cfg(feature = "compiled_data")]
 #[derive(Debug)]
@@ -36,14 +36,19 @@ const _: () = {
     pub mod icu {
         pub use crate as segmenter;
         pub use icu_collections as collections;
+        pub use icu_locid_transform as locid_transform;
     }
     icu_segmenter_data::impl_segmenter_dictionary_w_auto_v1!(Baked);
     icu_segmenter_data::impl_segmenter_dictionary_wl_ext_v1!(Baked);
     icu_segmenter_data::impl_segmenter_grapheme_v1!(Baked);
+    icu_segmenter_data::impl_segmenter_line_override_v1!(Baked);
     icu_segmenter_data::impl_segmenter_line_v1!(Baked);
     #[cfg(feature = "lstm")]
     icu_segmenter_data::impl_segmenter_lstm_wl_auto_v1!(Baked);
+    icu_segmenter_data::impl_segmenter_sentence_override_v1!(Baked);
+    icu_segmenter_data::impl_segmenter_sentence_suppressions_v1!(Baked);
     icu_segmenter_data::impl_segmenter_sentence_v1!(Baked);
+    icu_segmenter_data::impl_segmenter_word_override_v1!(Baked);
     icu_segmenter_data::impl_segmenter_word_v1!(Baked);
 };
 
@@ -98,6 +103,35 @@ pub struct RuleBreakDataV1<'data> {
     pub complex_property: u8,
 }
 
+/// Locale-specific tailoring of the break properties of a [`RuleBreakDataV1`].
+///
+/// The values are indices into the properties of the root rules, so this data has to be
+/// generated together with the corresponding [`RuleBreakDataV1`].
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[icu_provider::data_struct(
+    LineBreakDataOverrideV1Marker = "segmenter/line/override@1",
+    WordBreakDataOverrideV1Marker = "segmenter/word/override@1",
+    SentenceBreakDataOverrideV1Marker = "segmenter/sentence/override@1"
+)]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize,databake::Bake),
+    databake(path = icu_segmenter::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct RuleBreakDataOverrideV1<'data> {
+    /// Break properties that replace the ones in [`RuleBreakDataV1::property_table`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub property_overrides: ZeroMap<'data, char, u8>,
+}
+
 /// Property table for rule-based breaking.
 ///
 /// <div class="stab unstable">
@@ -176,6 +210,69 @@ pub struct UCharDictionaryBreakDataV1<'data> {
     pub trie_data: ZeroVec<'data, u16>,
 }
 
+/// Sentence break suppressions: abbreviations after which a sentence break is not allowed.
+///
+/// This is the CLDR `ss=standard` data, for example `Mr.` in English.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[icu_provider::data_struct(
+    SentenceBreakSuppressionsV1Marker = "segmenter/sentence/suppressions@1"
+)]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize,databake::Bake),
+    databake(path = icu_segmenter::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct SentenceBreakSuppressionsV1<'data> {
+    /// The abbreviations, matched case-sensitively against the text preceding a break.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub suppressions: VarZeroVec<'data, str>,
+}
+
+/// Liang hyphenation patterns, as used by TeX, together with hyphenation exceptions.
+///
+/// Patterns are keyed by their letters, lowercased, with `.` marking the start or end of
+/// a word. The value of a pattern with `n` letters holds the `n + 1` levels between and
+/// around them; odd levels allow hyphenation and even levels forbid it, with higher levels
+/// taking precedence.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[icu_provider::data_struct(HyphenationPatternsV1Marker = "segmenter/hyphenation@1")]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize,databake::Bake),
+    databake(path = icu_segmenter::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct HyphenationPatternsV1<'data> {
+    /// The hyphenation patterns, mapping letters to levels.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub patterns: ZeroMap<'data, str, ZeroSlice<u8>>,
+
+    /// Words that are not hyphenated according to the patterns, mapping the lowercased word
+    /// to the number of characters before each hyphenation point.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub exceptions: ZeroMap<'data, str, ZeroSlice<u8>>,
+
+    /// The default minimum number of characters before a hyphenation point.
+    pub left_min: u8,
+
+    /// The default minimum number of characters after a hyphenation point.
+    pub right_min: u8,
+}
+
 pub(crate) struct UCharDictionaryBreakDataV1Marker;
 
 impl DataMarker for UCharDictionaryBreakDataV1Marker {
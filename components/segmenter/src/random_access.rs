@@ -0,0 +1,148 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Random access to boundaries, on top of the forward iterators.
+//!
+//! The break iterators only ever look at a bounded amount of context before the current
+//! position: once they have moved past a character whose property no rule continues into
+//! (a *restart* character), every later boundary is the same as if segmentation had started
+//! at that character. Queries at an arbitrary offset can therefore scan back to the closest
+//! restart character and segment forward from there, instead of segmenting from the start
+//! of the text.
+
+use crate::indices::Utf16Indices;
+
+/// A string that can be segmented starting at any character boundary.
+pub(crate) trait RandomAccessText: Copy {
+    /// The length of the text in code units.
+    fn len(self) -> usize;
+
+    /// Whether a character starts at `offset`, or `offset` is the length of the text.
+    fn is_char_boundary(self, offset: usize) -> bool;
+
+    /// The code point starting at `offset`.
+    fn code_point_at(self, offset: usize) -> Option<u32>;
+}
+
+impl RandomAccessText for &str {
+    fn len(self) -> usize {
+        str::len(self)
+    }
+
+    fn is_char_boundary(self, offset: usize) -> bool {
+        str::is_char_boundary(self, offset)
+    }
+
+    fn code_point_at(self, offset: usize) -> Option<u32> {
+        self.get(offset..)?.chars().next().map(u32::from)
+    }
+}
+
+impl RandomAccessText for &[u16] {
+    fn len(self) -> usize {
+        <[u16]>::len(self)
+    }
+
+    fn is_char_boundary(self, offset: usize) -> bool {
+        match (
+            offset.checked_sub(1).and_then(|i| self.get(i)),
+            self.get(offset),
+        ) {
+            // Unpaired surrogates are treated as characters, like in `Utf16Indices`.
+            (Some(lead), Some(trail)) => (lead & 0xfc00) != 0xd800 || (trail & 0xfc00) != 0xdc00,
+            (_, Some(_)) => true,
+            (_, None) => offset == <[u16]>::len(self),
+        }
+    }
+
+    fn code_point_at(self, offset: usize) -> Option<u32> {
+        Utf16Indices::new(self.get(offset..)?)
+            .next()
+            .map(|(_, c)| c)
+    }
+}
+
+/// Returns the last position at or before `offset` from which segmentation can be restarted,
+/// i.e. the start of a character for which `is_restart` holds, or 0.
+fn restart_point<T: RandomAccessText>(
+    text: T,
+    offset: usize,
+    is_restart: &impl Fn(u32) -> bool,
+) -> usize {
+    let mut pos = offset.min(text.len());
+    while pos > 0 {
+        if text.is_char_boundary(pos) && text.code_point_at(pos).map_or(false, is_restart) {
+            return pos;
+        }
+        pos -= 1;
+    }
+    0
+}
+
+/// Returns the first boundary after `offset`.
+///
+/// `segment(start)` must return the boundaries of the text as segmented from `start`, as
+/// absolute positions.
+pub(crate) fn following<T: RandomAccessText, I: Iterator<Item = usize>>(
+    text: T,
+    offset: usize,
+    is_restart: impl Fn(u32) -> bool,
+    segment: impl Fn(usize) -> I,
+) -> Option<usize> {
+    if offset >= text.len() {
+        return None;
+    }
+    segment(restart_point(text, offset, &is_restart)).find(|&b| b > offset)
+}
+
+/// Returns the last boundary before `offset`.
+///
+/// See [`following`] for the contract of `segment`.
+pub(crate) fn preceding<T: RandomAccessText, I: Iterator<Item = usize>>(
+    text: T,
+    offset: usize,
+    is_restart: impl Fn(u32) -> bool,
+    segment: impl Fn(usize) -> I,
+) -> Option<usize> {
+    if offset == 0 {
+        return None;
+    }
+    if offset > text.len() {
+        return Some(text.len());
+    }
+    let mut probe = offset - 1;
+    loop {
+        let start = restart_point(text, probe, &is_restart);
+        // A restarted iterator always reports its start, which is only known to be a
+        // boundary at the start of the text.
+        if let Some(b) = segment(start)
+            .take_while(|&b| b < offset)
+            .filter(|&b| start == 0 || b > start)
+            .last()
+        {
+            return Some(b);
+        }
+        // There is no boundary between `start` and `offset`; look twice as far back, so
+        // that long boundary-free runs are not segmented over and over again.
+        probe = start.saturating_sub(offset - start);
+    }
+}
+
+/// Returns whether `offset` is a boundary.
+///
+/// See [`following`] for the contract of `segment`.
+pub(crate) fn is_boundary<T: RandomAccessText, I: Iterator<Item = usize>>(
+    text: T,
+    offset: usize,
+    is_restart: impl Fn(u32) -> bool,
+    segment: impl Fn(usize) -> I,
+) -> bool {
+    if offset == 0 || offset == text.len() {
+        return true;
+    }
+    if offset > text.len() || !text.is_char_boundary(offset) {
+        return false;
+    }
+    segment(restart_point(text, offset - 1, &is_restart)).find(|&b| b >= offset) == Some(offset)
+}
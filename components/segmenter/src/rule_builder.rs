@@ -0,0 +1,1037 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::provider::*;
+use crate::rule_segmenter::RuleStatusType;
+use crate::symbols::*;
+use crate::SegmenterError;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::RangeInclusive;
+use icu_codepointtrie_builder::{CodePointTrieBuilder, CodePointTrieBuilderData};
+use icu_collections::codepointtrie::TrieType;
+use icu_properties::maps::{CodePointMapData, CodePointMapDataBorrowed};
+use icu_properties::provider::{
+    EastAsianWidthV1Marker, ExtendedPictographicV1Marker, GeneralCategoryV1Marker,
+    GraphemeClusterBreakV1Marker, LineBreakV1Marker, ScriptV1Marker, SentenceBreakV1Marker,
+    WordBreakV1Marker,
+};
+use icu_properties::sets::CodePointSetData;
+use icu_properties::{
+    EastAsianWidth, GeneralCategory, GraphemeClusterBreak, LineBreak, Script, SentenceBreak,
+    WordBreak,
+};
+use icu_provider::prelude::*;
+use zerovec::ZeroVec;
+
+// The rules use the same format as the rules that icu_datagen compiles into the default data.
+//
+// state machine name define by builtin name
+// [[tables]]
+// name = "Double_Quote"
+//
+// state machine define for combined state
+// [[tables]]
+// name = "Double_Quote_ALetter"
+// left = "Double_Quote"
+// right = "ALetter"
+//
+// state machine define using code point
+// [[tables]]
+// name = "ABC"
+// codepoint = [32, 33, ...]
+#[derive(serde::Deserialize, Debug)]
+struct SegmenterProperty {
+    name: String,
+    // If codepoint is defined, this is custom define, not builtin define.
+    codepoint: Option<Vec<u32>>,
+    // If left and right are defined, this define is combined state.
+    left: Option<String>,
+    right: Option<String>,
+    // This combine state is an intermediate match rule.
+    interm_break_state: Option<bool>,
+}
+
+// state machine break result define
+// The follow is "Double_Quote x Double_Quote".
+// [[rules]]
+// left = [ "Double_Qoute" ]
+// right = [ "Double_Qoute" ]
+// break_state = true # true if break opportunity.
+#[derive(serde::Deserialize, Debug)]
+struct SegmenterState {
+    left: Vec<String>,
+    right: Vec<String>,
+    break_state: Option<bool>,
+}
+
+// rule based segmenter define
+//
+// segmenter_type: builtin type. word, sentence, grapheme or line.
+// tables: state machine name defines.
+// rules: state machine rules.
+#[derive(serde::Deserialize, Debug)]
+struct SegmenterRuleTable {
+    segmenter_type: String,
+    tables: Vec<SegmenterProperty>,
+    rules: Vec<SegmenterState>,
+}
+
+// The property values of all code points at or above this limit are never looked up, so that
+// compiled rules match the data generated by icu_datagen.
+const CODEPOINT_TABLE_LEN: usize = 0xE1000;
+
+/// A compiler for custom segmentation rules.
+///
+/// [`RuleBreakDataBuilder`] compiles rule text into the [`RuleBreakDataV1`] state table that is
+/// used by the rule-based segmenters, so that segmenters with custom rules can be built at
+/// runtime. The rules use the TOML format of the rules that `icu_datagen` compiles into the
+/// default data:
+///
+/// - `segmenter_type` is one of `"word"`, `"sentence"`, `"grapheme"` or `"line"`, and selects
+///   the Unicode property that built-in tables are looked up in.
+/// - Each `[[tables]]` entry defines a state. It either names a value of that property (such
+///   as `"ALetter"`), lists its code points in `codepoint`, or combines a `left` and a `right`
+///   state into an intermediate state of a longer match.
+/// - Each `[[rules]]` entry sets the `break_state` between all `left` and `right` states, with
+///   `true` for a break and `false` for no break. `"Any"`, `"sot"` and `"eot"` are also accepted
+///   as states.
+///
+/// Code points listed in a later table take precedence over earlier tables. A table that repeats
+/// the name of an earlier table, or `"Unknown"`, with a `codepoint` list moves those code points
+/// to it.
+///
+/// The code point tries are built with [`icu_codepointtrie_builder`], in Wasm with the
+/// `rule_builder` Cargo feature, or with a local ICU4C with the `rule_builder_icu4c` Cargo
+/// feature.
+///
+/// ✨ *Enabled with the `rule_builder` or `rule_builder_icu4c` Cargo features.*
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. The rule format follows the data of `icu_datagen`.
+/// </div>
+///
+/// # Examples
+///
+/// Treat hashtags and @mentions as single words:
+///
+/// ```
+/// use icu_provider::prelude::*;
+/// use icu_provider_adapters::overlay::OverlayProvider;
+/// use icu_segmenter::provider::WordBreakDataV1Marker;
+/// use icu_segmenter::{RuleBreakDataBuilder, WordSegmenter};
+///
+/// let rules = r#"
+///     segmenter_type = "word"
+///
+///     [[tables]]
+///     name = "ALetter"
+///
+///     [[tables]]
+///     name = "Numeric"
+///
+///     [[tables]]
+///     name = "Sigil"
+///     codepoint = [0x23, 0x40] # '#' and '@'
+///
+///     [[rules]]
+///     left = ["Sigil", "ALetter", "Numeric"]
+///     right = ["ALetter", "Numeric"]
+///     break_state = false
+///
+///     [[rules]]
+///     left = ["Any"]
+///     right = ["Any"]
+///     break_state = true
+/// "#;
+///
+/// let data = RuleBreakDataBuilder::new()
+///     .build(rules)
+///     .expect("rules should be valid");
+///
+/// let mut provider = OverlayProvider::new(icu_segmenter::provider::Baked);
+/// provider.push_payload::<WordBreakDataV1Marker>(
+///     Default::default(),
+///     DataPayload::from_owned(data),
+/// );
+/// let segmenter = WordSegmenter::try_new_dictionary_unstable(&provider)
+///     .expect("data should be present");
+///
+/// let text = "Follow @icu4x #unicode15";
+/// let words: Vec<&str> = segmenter
+///     .segment_str(text)
+///     .collect::<Vec<_>>()
+///     .windows(2)
+///     .map(|w| &text[w[0]..w[1]])
+///     .collect();
+/// assert_eq!(
+///     words,
+///     ["Follow", " ", "@icu4x", " ", "#unicode15"]
+/// );
+/// ```
+///
+/// Invalid rules are reported when they are compiled:
+///
+/// ```
+/// use icu_segmenter::{RuleBreakDataBuilder, SegmenterError};
+///
+/// let rules = r#"
+///     segmenter_type = "word"
+///
+///     [[tables]]
+///     name = "ALetter"
+///
+///     [[rules]]
+///     left = ["ALetter"]
+///     right = ["Digit"]
+///     break_state = false
+/// "#;
+///
+/// assert!(matches!(
+///     RuleBreakDataBuilder::new().build(rules),
+///     Err(SegmenterError::InvalidRules(_))
+/// ));
+/// ```
+#[derive(Debug)]
+pub struct RuleBreakDataBuilder {
+    word_break: CodePointMapData<WordBreak>,
+    grapheme_cluster_break: CodePointMapData<GraphemeClusterBreak>,
+    sentence_break: CodePointMapData<SentenceBreak>,
+    line_break: CodePointMapData<LineBreak>,
+    east_asian_width: CodePointMapData<EastAsianWidth>,
+    general_category: CodePointMapData<GeneralCategory>,
+    script: CodePointMapData<Script>,
+    extended_pictographic: CodePointSetData,
+}
+
+#[cfg(feature = "compiled_data")]
+impl Default for RuleBreakDataBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RuleBreakDataBuilder {
+    /// Constructs a [`RuleBreakDataBuilder`] that looks up built-in tables in the Unicode
+    /// properties from compiled data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub fn new() -> Self {
+        use icu_properties::provider::Baked;
+        Self {
+            word_break: CodePointMapData::from_data(
+                DataPayload::<WordBreakV1Marker>::from_static_ref(Baked::SINGLETON_PROPS_WB_V1),
+            ),
+            grapheme_cluster_break: CodePointMapData::from_data(DataPayload::<
+                GraphemeClusterBreakV1Marker,
+            >::from_static_ref(
+                Baked::SINGLETON_PROPS_GCB_V1
+            )),
+            sentence_break: CodePointMapData::from_data(
+                DataPayload::<SentenceBreakV1Marker>::from_static_ref(Baked::SINGLETON_PROPS_SB_V1),
+            ),
+            line_break: CodePointMapData::from_data(
+                DataPayload::<LineBreakV1Marker>::from_static_ref(Baked::SINGLETON_PROPS_LB_V1),
+            ),
+            east_asian_width: CodePointMapData::from_data(
+                DataPayload::<EastAsianWidthV1Marker>::from_static_ref(
+                    Baked::SINGLETON_PROPS_EA_V1,
+                ),
+            ),
+            general_category: CodePointMapData::from_data(
+                DataPayload::<GeneralCategoryV1Marker>::from_static_ref(
+                    Baked::SINGLETON_PROPS_GC_V1,
+                ),
+            ),
+            script: CodePointMapData::from_data(DataPayload::<ScriptV1Marker>::from_static_ref(
+                Baked::SINGLETON_PROPS_SC_V1,
+            )),
+            extended_pictographic: CodePointSetData::from_data(DataPayload::<
+                ExtendedPictographicV1Marker,
+            >::from_static_ref(
+                Baked::SINGLETON_PROPS_EXTPICT_V1
+            )),
+        }
+    }
+
+    icu_provider::gen_any_buffer_data_constructors!(locale: skip, options: skip, error: SegmenterError,
+        #[cfg(skip)]
+        functions: [
+            new,
+            try_new_with_any_provider,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self,
+    ]);
+
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::new)]
+    pub fn try_new_unstable<D>(provider: &D) -> Result<Self, SegmenterError>
+    where
+        D: DataProvider<WordBreakV1Marker>
+            + DataProvider<GraphemeClusterBreakV1Marker>
+            + DataProvider<SentenceBreakV1Marker>
+            + DataProvider<LineBreakV1Marker>
+            + DataProvider<EastAsianWidthV1Marker>
+            + DataProvider<GeneralCategoryV1Marker>
+            + DataProvider<ScriptV1Marker>
+            + DataProvider<ExtendedPictographicV1Marker>
+            + ?Sized,
+    {
+        Ok(Self {
+            word_break: CodePointMapData::from_data::<WordBreakV1Marker>(
+                provider.load(Default::default())?.take_payload()?,
+            ),
+            grapheme_cluster_break: CodePointMapData::from_data::<GraphemeClusterBreakV1Marker>(
+                provider.load(Default::default())?.take_payload()?,
+            ),
+            sentence_break: CodePointMapData::from_data::<SentenceBreakV1Marker>(
+                provider.load(Default::default())?.take_payload()?,
+            ),
+            line_break: CodePointMapData::from_data::<LineBreakV1Marker>(
+                provider.load(Default::default())?.take_payload()?,
+            ),
+            east_asian_width: CodePointMapData::from_data::<EastAsianWidthV1Marker>(
+                provider.load(Default::default())?.take_payload()?,
+            ),
+            general_category: CodePointMapData::from_data::<GeneralCategoryV1Marker>(
+                provider.load(Default::default())?.take_payload()?,
+            ),
+            script: CodePointMapData::from_data::<ScriptV1Marker>(
+                provider.load(Default::default())?.take_payload()?,
+            ),
+            extended_pictographic: CodePointSetData::from_data::<ExtendedPictographicV1Marker>(
+                provider.load(Default::default())?.take_payload()?,
+            ),
+        })
+    }
+
+    /// Compiles the given rules into a [`RuleBreakDataV1`] with a fast code point trie.
+    ///
+    /// Returns [`SegmenterError::InvalidRules`] if the rules cannot be parsed, refer to unknown
+    /// property values or states, or define too many states.
+    pub fn build(&self, rules: &str) -> Result<RuleBreakDataV1<'static>, SegmenterError> {
+        self.build_with_trie_type(rules, TrieType::Fast)
+    }
+
+    /// Compiles the given rules into a [`RuleBreakDataV1`] with a code point trie of the given
+    /// type.
+    ///
+    /// See [`Self::build`].
+    pub fn build_with_trie_type(
+        &self,
+        rules: &str,
+        trie_type: TrieType,
+    ) -> Result<RuleBreakDataV1<'static>, SegmenterError> {
+        let segmenter: SegmenterRuleTable = toml::from_str(rules)
+            .map_err(|_| SegmenterError::InvalidRules("the rules are not valid TOML"))?;
+
+        let mut properties_map = vec![0u8; CODEPOINT_TABLE_LEN];
+        let mut properties_names = Vec::<&str>::new();
+        let mut simple_properties_count = 0;
+
+        properties_names.push("Unknown");
+        simple_properties_count += 1;
+
+        for p in &segmenter.tables {
+            if let Some(index) = properties_names.iter().position(|&n| n == p.name) {
+                // A repeated table that lists code points moves them to the earlier table.
+                if let (Some(codepoint), None, None) = (&p.codepoint, &p.left, &p.right) {
+                    let index = u8::try_from(index)
+                        .map_err(|_| SegmenterError::InvalidRules("too many tables"))?;
+                    fill_codepoints(&mut properties_map, codepoint, index)?;
+                }
+                continue;
+            }
+            properties_names.push(&p.name);
+            let property_index = u8::try_from(properties_names.len() - 1)
+                .map_err(|_| SegmenterError::InvalidRules("too many tables"))?;
+
+            if p.left.is_none() && p.right.is_none() && p.codepoint.is_none() {
+                // If any values aren't set, this is builtin type.
+                simple_properties_count += 1;
+                self.fill_builtin_property(
+                    &mut properties_map,
+                    &segmenter.segmenter_type,
+                    &p.name,
+                    property_index,
+                )?;
+            } else if let Some(codepoint) = &p.codepoint {
+                simple_properties_count += 1;
+                fill_codepoints(&mut properties_map, codepoint, property_index)?;
+            }
+        }
+
+        // sot and eot
+        properties_names.push("sot");
+        properties_names.push("eot");
+
+        let property_length = properties_names.len();
+        if property_length > INTERMEDIATE_MATCH_RULE as usize {
+            return Err(SegmenterError::InvalidRules("too many tables"));
+        }
+
+        let get_index =
+            |name: &str| {
+                properties_names.iter().position(|n| *n == name).ok_or(
+                    SegmenterError::InvalidRules("a rule refers to an unknown table"),
+                )
+            };
+
+        let mut break_state_table = BreakStateTable {
+            table: vec![UNKNOWN_RULE; property_length * property_length],
+            property_length,
+        };
+
+        for rule in &segmenter.rules {
+            let break_state = match rule.break_state {
+                Some(true) => BREAK_RULE,
+                Some(false) => KEEP_RULE,
+                None => NOT_MATCH_RULE,
+            };
+
+            for l in &rule.left {
+                if l == "Any" {
+                    // Special case: left is Any
+                    for r in &rule.right {
+                        if r == "Any" {
+                            // Fill all unknown state.
+                            for item in break_state_table.table.iter_mut() {
+                                if *item == UNKNOWN_RULE {
+                                    *item = break_state;
+                                }
+                            }
+                        } else {
+                            let right_index = get_index(r)?;
+                            for i in 0..simple_properties_count {
+                                break_state_table.set(i, right_index, break_state);
+                            }
+                        }
+                    }
+                    continue;
+                }
+                let left_index = get_index(l)?;
+                for r in &rule.right {
+                    // Special case: right is Any
+                    if r == "Any" {
+                        for i in 0..property_length {
+                            break_state_table.set(left_index, i, break_state);
+                        }
+                        continue;
+                    }
+                    let right_index = get_index(r)?;
+                    if r != "eot"
+                        && break_state_table.get(left_index, right_index) == NOT_MATCH_RULE
+                    {
+                        break_state_table.replace(left_index, right_index, UNKNOWN_RULE);
+                    }
+                    break_state_table.set(left_index, right_index, break_state);
+                    // Fill not match for combine state
+                    if left_index >= simple_properties_count {
+                        for i in 0..property_length {
+                            break_state_table.set(left_index, i, NOT_MATCH_RULE);
+                        }
+                    }
+                }
+            }
+        }
+
+        // State machine alias
+        for p in &segmenter.tables {
+            if let (Some(left), Some(right)) = (&p.left, &p.right) {
+                let right_index = get_index(right)?;
+                let left_index = get_index(left)?;
+                let interm_break_state = if p.interm_break_state.is_some() {
+                    INTERMEDIATE_MATCH_RULE
+                } else {
+                    0
+                };
+
+                // The number of properties is less than INTERMEDIATE_MATCH_RULE, so this fits.
+                let index = get_index(&p.name)? as i8;
+                break_state_table.replace(left_index, right_index, index | interm_break_state);
+            }
+        }
+
+        // Return 127 if the complex language isn't handled.
+        let complex_property = get_index("SA").map_or(127, |i| i as u8);
+
+        // rule status for word segmenter, indexed by the property without "Unknown", sot and eot
+        let mut rule_status_table = Vec::<u8>::new();
+        if segmenter.segmenter_type == "word" {
+            for name in &properties_names[1..property_length - 2] {
+                let rule_state = match *name {
+                    "Numeric" => RuleStatusType::Number,
+                    "ALetter" => RuleStatusType::Letter,
+                    "Hebrew_Letter" => RuleStatusType::Letter,
+                    "ExtendNumLet" => RuleStatusType::Letter,
+                    "Katakana" => RuleStatusType::Letter,
+                    "SA" => RuleStatusType::Letter,
+                    _ => RuleStatusType::None,
+                };
+                rule_status_table.push(rule_state as u8);
+            }
+        }
+
+        Ok(RuleBreakDataV1 {
+            property_table: RuleBreakPropertyTable(
+                CodePointTrieBuilder {
+                    data: CodePointTrieBuilderData::ValuesByCodePoint(&properties_map),
+                    default_value: 0,
+                    error_value: 0,
+                    trie_type,
+                }
+                .build(),
+            ),
+            break_state_table: RuleBreakStateTable(ZeroVec::new_owned(break_state_table.table)),
+            rule_status_table: RuleStatusTable(ZeroVec::new_owned(rule_status_table)),
+            property_count: property_length as u8,
+            last_codepoint_property: (simple_properties_count - 1) as i8,
+            sot_property: (property_length - 2) as u8,
+            eot_property: (property_length - 1) as u8,
+            complex_property,
+        })
+    }
+
+    /// Compiles locale-specific tailorings of the given rules into a
+    /// [`RuleBreakDataOverrideV1`].
+    ///
+    /// Each override assigns a code point to the table of `rules` with the given name. Later
+    /// overrides of the same code point take precedence.
+    ///
+    /// Returns [`SegmenterError::InvalidRules`] if the rules cannot be parsed, or an override
+    /// refers to an unknown table or an invalid code point.
+    pub fn build_override<'a>(
+        rules: &str,
+        overrides: impl IntoIterator<Item = (&'a str, u32)>,
+    ) -> Result<RuleBreakDataOverrideV1<'static>, SegmenterError> {
+        let segmenter: SegmenterRuleTable = toml::from_str(rules)
+            .map_err(|_| SegmenterError::InvalidRules("the rules are not valid TOML"))?;
+
+        // Tables get their indices in the same order as in `build_with_trie_type`.
+        let mut properties_names = vec!["Unknown"];
+        for p in &segmenter.tables {
+            if !properties_names.contains(&p.name.as_str()) {
+                properties_names.push(&p.name);
+            }
+        }
+
+        let mut property_overrides = BTreeMap::new();
+        for (name, c) in overrides {
+            let property_index = properties_names
+                .iter()
+                .position(|n| *n == name)
+                .and_then(|i| u8::try_from(i).ok())
+                .ok_or(SegmenterError::InvalidRules(
+                    "an override refers to an unknown table",
+                ))?;
+            let c = char::from_u32(c).ok_or(SegmenterError::InvalidRules("invalid code point"))?;
+            property_overrides.insert(c, property_index);
+        }
+        Ok(RuleBreakDataOverrideV1 {
+            property_overrides: property_overrides.into_iter().collect(),
+        })
+    }
+
+    /// Assigns `property_index` to all code points that have the built-in property value `name`.
+    fn fill_builtin_property(
+        &self,
+        properties_map: &mut [u8],
+        segmenter_type: &str,
+        name: &str,
+        property_index: u8,
+    ) -> Result<(), SegmenterError> {
+        let unknown_property = SegmenterError::InvalidRules("unknown property value");
+        let extended_pictographic = self.extended_pictographic.as_borrowed();
+        match segmenter_type {
+            "word" => {
+                // Extended_Pictographic isn't a part of word break property
+                // Extended pictographic property is within 0..U+0x20000
+                if name == "Extended_Pictographic" {
+                    fill(
+                        properties_map,
+                        extended_pictographic.iter_ranges(),
+                        0x20000,
+                        property_index,
+                    );
+                } else if name == "SA" {
+                    // Word break property doesn't define SA, but we will use non-UAX29 rules.
+                    // SA/CJ property is within 0..U+0x40000
+                    let lb = self.line_break.as_borrowed();
+                    let script = self.script.as_borrowed();
+                    for c in 0..0x40000 {
+                        if lb.get32(c) == LineBreak::ComplexContext
+                            || matches!(script.get32(c), Script::Han | Script::Hiragana)
+                        {
+                            if let Some(v) = properties_map.get_mut(c as usize) {
+                                *v = property_index;
+                            }
+                        }
+                    }
+                } else {
+                    let prop = word_break_from_name(name).ok_or(unknown_property)?;
+                    fill_value(
+                        properties_map,
+                        self.word_break.as_borrowed(),
+                        prop,
+                        property_index,
+                    );
+                }
+            }
+
+            "grapheme" => {
+                // Extended_Pictographic isn't a part of grapheme break property
+                // Extended pictographic property is within 0..U+0x20000
+                if name == "Extended_Pictographic" {
+                    fill(
+                        properties_map,
+                        extended_pictographic.iter_ranges(),
+                        0x20000,
+                        property_index,
+                    );
+                } else {
+                    let prop = grapheme_cluster_break_from_name(name).ok_or(unknown_property)?;
+                    fill_value(
+                        properties_map,
+                        self.grapheme_cluster_break.as_borrowed(),
+                        prop,
+                        property_index,
+                    );
+                }
+            }
+
+            "sentence" => {
+                let prop = sentence_break_from_name(name).ok_or(unknown_property)?;
+                fill_value(
+                    properties_map,
+                    self.sentence_break.as_borrowed(),
+                    prop,
+                    property_index,
+                );
+            }
+
+            "line" => {
+                let lb = self.line_break.as_borrowed();
+                let eaw = self.east_asian_width.as_borrowed();
+                let gc = self.general_category.as_borrowed();
+                let is_east_asian = |c: u32| {
+                    matches!(
+                        eaw.get32(c),
+                        EastAsianWidth::Fullwidth
+                            | EastAsianWidth::Halfwidth
+                            | EastAsianWidth::Wide
+                    )
+                };
+                let is_cjk_fullwidth = |c: u32| {
+                    matches!(
+                        eaw.get32(c),
+                        EastAsianWidth::Ambiguous
+                            | EastAsianWidth::Fullwidth
+                            | EastAsianWidth::Wide
+                    )
+                };
+                let prop = match name {
+                    "OP_OP30" | "OP_EA" => LineBreak::OpenPunctuation,
+                    // CP_EA is unused on the latest spec.
+                    "CP_EA" => LineBreak::CloseParenthesis,
+                    "ID_CN" => LineBreak::Ideographic,
+                    "PO_EAW" => LineBreak::PostfixNumeric,
+                    "PR_EAW" => LineBreak::PrefixNumeric,
+                    _ => {
+                        let prop = line_break_from_name(name).ok_or(unknown_property)?;
+                        fill_value(properties_map, lb, prop, property_index);
+                        return Ok(());
+                    }
+                };
+                let filter = |c: u32| match name {
+                    "OP_OP30" => !is_east_asian(c),
+                    "ID_CN" => {
+                        gc.get32(c) == GeneralCategory::Unassigned
+                            && extended_pictographic.contains32(c)
+                    }
+                    "PO_EAW" | "PR_EAW" => is_cjk_fullwidth(c),
+                    _ => is_east_asian(c),
+                };
+                for range in lb.iter_ranges_for_value(prop) {
+                    for c in range.filter(|&c| filter(c)) {
+                        if let Some(v) = properties_map.get_mut(c as usize) {
+                            *v = property_index;
+                        }
+                    }
+                }
+            }
+
+            _ => return Err(SegmenterError::InvalidRules("unknown segmenter type")),
+        }
+        Ok(())
+    }
+}
+
+fn word_break_from_name(name: &str) -> Option<WordBreak> {
+    match name {
+        "ALetter" => Some(WordBreak::ALetter),
+        "CR" => Some(WordBreak::CR),
+        "Double_Quote" => Some(WordBreak::DoubleQuote),
+        "Extend" => Some(WordBreak::Extend),
+        "ExtendNumLet" => Some(WordBreak::ExtendNumLet),
+        "Format" => Some(WordBreak::Format),
+        "Katakana" => Some(WordBreak::Katakana),
+        "Hebrew_Letter" => Some(WordBreak::HebrewLetter),
+        "LF" => Some(WordBreak::LF),
+        "MidLetter" => Some(WordBreak::MidLetter),
+        "MidNum" => Some(WordBreak::MidNum),
+        "MidNumLet" => Some(WordBreak::MidNumLet),
+        "Newline" => Some(WordBreak::Newline),
+        "Numeric" => Some(WordBreak::Numeric),
+        "Regional_Indicator" => Some(WordBreak::RegionalIndicator),
+        "Single_Quote" => Some(WordBreak::SingleQuote),
+        "WSegSpace" => Some(WordBreak::WSegSpace),
+        "ZWJ" => Some(WordBreak::ZWJ),
+        _ => None,
+    }
+}
+
+fn grapheme_cluster_break_from_name(name: &str) -> Option<GraphemeClusterBreak> {
+    match name {
+        "Control" => Some(GraphemeClusterBreak::Control),
+        "CR" => Some(GraphemeClusterBreak::CR),
+        "Extend" => Some(GraphemeClusterBreak::Extend),
+        "L" => Some(GraphemeClusterBreak::L),
+        "LF" => Some(GraphemeClusterBreak::LF),
+        "LV" => Some(GraphemeClusterBreak::LV),
+        "LVT" => Some(GraphemeClusterBreak::LVT),
+        "Prepend" => Some(GraphemeClusterBreak::Prepend),
+        "Regional_Indicator" => Some(GraphemeClusterBreak::RegionalIndicator),
+        "SpacingMark" => Some(GraphemeClusterBreak::SpacingMark),
+        "T" => Some(GraphemeClusterBreak::T),
+        "V" => Some(GraphemeClusterBreak::V),
+        "ZWJ" => Some(GraphemeClusterBreak::ZWJ),
+        _ => None,
+    }
+}
+
+fn sentence_break_from_name(name: &str) -> Option<SentenceBreak> {
+    match name {
+        "ATerm" => Some(SentenceBreak::ATerm),
+        "Close" => Some(SentenceBreak::Close),
+        "CR" => Some(SentenceBreak::CR),
+        "Extend" => Some(SentenceBreak::Extend),
+        "Format" => Some(SentenceBreak::Format),
+        "LF" => Some(SentenceBreak::LF),
+        "Lower" => Some(SentenceBreak::Lower),
+        "Numeric" => Some(SentenceBreak::Numeric),
+        "OLetter" => Some(SentenceBreak::OLetter),
+        "SContinue" => Some(SentenceBreak::SContinue),
+        "Sep" => Some(SentenceBreak::Sep),
+        "Sp" => Some(SentenceBreak::Sp),
+        "STerm" => Some(SentenceBreak::STerm),
+        "Upper" => Some(SentenceBreak::Upper),
+        _ => None,
+    }
+}
+
+fn line_break_from_name(name: &str) -> Option<LineBreak> {
+    match name {
+        "AI" => Some(LineBreak::Ambiguous),
+        "AL" => Some(LineBreak::Alphabetic),
+        "B2" => Some(LineBreak::BreakBoth),
+        "BA" => Some(LineBreak::BreakAfter),
+        "BB" => Some(LineBreak::BreakBefore),
+        "BK" => Some(LineBreak::MandatoryBreak),
+        "CB" => Some(LineBreak::ContingentBreak),
+        "CJ" => Some(LineBreak::ConditionalJapaneseStarter),
+        "CL" => Some(LineBreak::ClosePunctuation),
+        "CM" => Some(LineBreak::CombiningMark),
+        "CP" => Some(LineBreak::CloseParenthesis),
+        "CR" => Some(LineBreak::CarriageReturn),
+        "EB" => Some(LineBreak::EBase),
+        "EM" => Some(LineBreak::EModifier),
+        "EX" => Some(LineBreak::Exclamation),
+        "GL" => Some(LineBreak::Glue),
+        "H2" => Some(LineBreak::H2),
+        "H3" => Some(LineBreak::H3),
+        "HL" => Some(LineBreak::HebrewLetter),
+        "HY" => Some(LineBreak::Hyphen),
+        "ID" => Some(LineBreak::Ideographic),
+        "IN" => Some(LineBreak::Inseparable),
+        "IS" => Some(LineBreak::InfixNumeric),
+        "JL" => Some(LineBreak::JL),
+        "JT" => Some(LineBreak::JT),
+        "JV" => Some(LineBreak::JV),
+        "LF" => Some(LineBreak::LineFeed),
+        "NL" => Some(LineBreak::NextLine),
+        "NS" => Some(LineBreak::Nonstarter),
+        "NU" => Some(LineBreak::Numeric),
+        "OP" => Some(LineBreak::OpenPunctuation),
+        "PO" => Some(LineBreak::PostfixNumeric),
+        "PR" => Some(LineBreak::PrefixNumeric),
+        "QU" => Some(LineBreak::Quotation),
+        "RI" => Some(LineBreak::RegionalIndicator),
+        "SA" => Some(LineBreak::ComplexContext),
+        "SG" => Some(LineBreak::Surrogate),
+        "SP" => Some(LineBreak::Space),
+        "SY" => Some(LineBreak::BreakSymbols),
+        "WJ" => Some(LineBreak::WordJoiner),
+        "XX" => Some(LineBreak::Unknown),
+        "ZW" => Some(LineBreak::ZWSpace),
+        "ZWJ" => Some(LineBreak::ZWJ),
+        _ => None,
+    }
+}
+
+/// A square table of break states, indexed by the left and right property.
+struct BreakStateTable {
+    table: Vec<i8>,
+    property_length: usize,
+}
+
+impl BreakStateTable {
+    fn get(&self, left: usize, right: usize) -> i8 {
+        self.table
+            .get(left * self.property_length + right)
+            .copied()
+            .unwrap_or(UNKNOWN_RULE)
+    }
+
+    fn replace(&mut self, left: usize, right: usize, break_state: i8) {
+        if let Some(item) = self.table.get_mut(left * self.property_length + right) {
+            *item = break_state;
+        }
+    }
+
+    /// Sets the break state unless an earlier rule already determined it.
+    fn set(&mut self, left: usize, right: usize, break_state: i8) {
+        let current = self.get(left, right);
+        if current == UNKNOWN_RULE || current == NOT_MATCH_RULE {
+            self.replace(left, right, break_state);
+        }
+    }
+}
+
+/// Assigns `property_index` to the code points in `ranges` below `limit`.
+fn fill(
+    properties_map: &mut [u8],
+    ranges: impl Iterator<Item = RangeInclusive<u32>>,
+    limit: u32,
+    property_index: u8,
+) {
+    for range in ranges {
+        let start = *range.start() as usize;
+        let end = (*range.end()).min(limit - 1) as usize;
+        if let Some(values) = properties_map.get_mut(start..=end) {
+            values.fill(property_index);
+        }
+    }
+}
+
+/// Assigns `property_index` to the listed code points.
+fn fill_codepoints(
+    properties_map: &mut [u8],
+    codepoint: &[u32],
+    property_index: u8,
+) -> Result<(), SegmenterError> {
+    for &c in codepoint {
+        if char::from_u32(c).is_none() {
+            return Err(SegmenterError::InvalidRules("invalid code point"));
+        }
+        if let Some(v) = properties_map.get_mut(c as usize) {
+            *v = property_index;
+        }
+    }
+    Ok(())
+}
+
+/// Assigns `property_index` to the code points that have the value `prop` in `map`.
+fn fill_value<T: icu_collections::codepointtrie::TrieValue>(
+    properties_map: &mut [u8],
+    map: CodePointMapDataBorrowed<T>,
+    prop: T,
+    property_index: u8,
+) {
+    fill(
+        properties_map,
+        map.iter_ranges_for_value(prop),
+        CODEPOINT_TABLE_LEN as u32,
+        property_index,
+    );
+}
+
+#[cfg(all(test, feature = "compiled_data"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn custom_rules() {
+        let rules = r#"
+            segmenter_type = "sentence"
+
+            [[tables]]
+            name = "Sp"
+
+            [[tables]]
+            name = "STerm"
+
+            [[tables]]
+            name = "Upper"
+
+            [[rules]]
+            left = ["sot"]
+            right = ["Any"]
+            break_state = true
+
+            [[rules]]
+            left = ["STerm"]
+            right = ["Sp"]
+            break_state = false
+
+            [[rules]]
+            left = ["STerm", "Sp"]
+            right = ["Upper"]
+            break_state = true
+
+            [[rules]]
+            left = ["Any"]
+            right = ["Any"]
+            break_state = false
+        "#;
+        let data = RuleBreakDataBuilder::new().build(rules).unwrap();
+        assert_eq!(data.property_count, 6);
+        assert_eq!(data.last_codepoint_property, 3);
+        assert_eq!(data.sot_property, 4);
+        assert_eq!(data.eot_property, 5);
+        assert_eq!(data.complex_property, 127);
+        assert_eq!(data.property_table.0.get('!'), 2);
+        assert_eq!(data.property_table.0.get('A'), 3);
+        assert_eq!(data.property_table.0.get('a'), 0);
+
+        let segmenter = crate::SentenceSegmenter::try_new_unstable(&Rules(data)).unwrap();
+        let breakpoints: Vec<usize> = segmenter.segment_str("Hi! Yes! no").collect();
+        assert_eq!(breakpoints, [0, 4, 11]);
+    }
+
+    #[test]
+    fn invalid_rules() {
+        let builder = RuleBreakDataBuilder::new();
+        let error = |rules: &str| match builder.build(rules) {
+            Err(SegmenterError::InvalidRules(e)) => e,
+            _ => panic!("rules should be invalid: {rules}"),
+        };
+        assert_eq!(error("segmenter_type = "), "the rules are not valid TOML");
+        assert_eq!(
+            error(
+                r#"
+                segmenter_type = "paragraph"
+                rules = []
+                [[tables]]
+                name = "Sep"
+                "#
+            ),
+            "unknown segmenter type"
+        );
+        assert_eq!(
+            error(
+                r#"
+                segmenter_type = "word"
+                rules = []
+                [[tables]]
+                name = "Letter"
+                "#
+            ),
+            "unknown property value"
+        );
+        assert_eq!(
+            error(
+                r#"
+                segmenter_type = "word"
+                [[tables]]
+                name = "Surrogate"
+                codepoint = [0xD800]
+                [[rules]]
+                left = ["Any"]
+                right = ["Any"]
+                "#
+            ),
+            "invalid code point"
+        );
+    }
+
+    #[test]
+    fn repeated_tables() {
+        let rules = r#"
+            segmenter_type = "word"
+
+            [[tables]]
+            name = "ALetter"
+
+            [[tables]]
+            name = "MidLetter"
+
+            [[tables]]
+            name = "Unknown"
+            codepoint = [0x3A]
+
+            [[tables]]
+            name = "ALetter"
+            codepoint = [0x23]
+
+            [[rules]]
+            left = ["Any"]
+            right = ["Any"]
+            break_state = true
+        "#;
+        let data = RuleBreakDataBuilder::new().build(rules).unwrap();
+        assert_eq!(data.property_count, 5);
+        assert_eq!(data.property_table.0.get(':'), 0);
+        assert_eq!(data.property_table.0.get('·'), 2);
+        assert_eq!(data.property_table.0.get('#'), 1);
+    }
+
+    #[test]
+    fn override_rules() {
+        let rules = r#"
+            segmenter_type = "word"
+
+            [[tables]]
+            name = "ALetter"
+
+            [[tables]]
+            name = "MidLetter"
+
+            [[rules]]
+            left = ["Any"]
+            right = ["Any"]
+            break_state = true
+        "#;
+        let data =
+            RuleBreakDataBuilder::build_override(rules, [("MidLetter", 0x3A), ("Unknown", 0xB7)])
+                .unwrap();
+        assert_eq!(data.property_overrides.get_copied(&':'), Some(2));
+        assert_eq!(data.property_overrides.get_copied(&'·'), Some(0));
+        assert_eq!(data.property_overrides.get_copied(&'a'), None);
+
+        assert!(matches!(
+            RuleBreakDataBuilder::build_override(rules, [("MidNum", 0x2C)]),
+            Err(SegmenterError::InvalidRules(_))
+        ));
+    }
+
+    struct Rules(RuleBreakDataV1<'static>);
+
+    impl DataProvider<SentenceBreakDataV1Marker> for Rules {
+        fn load(
+            &self,
+            _: DataRequest,
+        ) -> Result<DataResponse<SentenceBreakDataV1Marker>, DataError> {
+            Ok(DataResponse {
+                metadata: Default::default(),
+                payload: Some(DataPayload::from_owned(self.0.clone())),
+            })
+        }
+    }
+}
@@ -3,9 +3,10 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use crate::complex::ComplexPayloads;
-use crate::indices::{Latin1Indices, Utf16Indices};
-use crate::provider::RuleBreakDataV1;
+use crate::indices::{ChunkedCharIndices, ChunkedUtf16Indices, Latin1Indices, Utf16Indices};
+use crate::provider::{RuleBreakDataOverrideV1, RuleBreakDataV1};
 use crate::symbols::*;
+use core::marker::PhantomData;
 use core::str::CharIndices;
 use utf8_iter::Utf8CharIndices;
 
@@ -57,6 +58,7 @@ pub struct RuleBreakIterator<'l, 's, Y: RuleBreakType<'l, 's> + ?Sized> {
     pub(crate) current_pos_data: Option<(usize, Y::CharType)>,
     pub(crate) result_cache: alloc::vec::Vec<usize>,
     pub(crate) data: &'l RuleBreakDataV1<'l>,
+    pub(crate) locale_override: Option<&'l RuleBreakDataOverrideV1<'l>>,
     pub(crate) complex: Option<&'l ComplexPayloads>,
     pub(crate) boundary_property: u8,
 }
@@ -215,8 +217,7 @@ impl<'l, 's, Y: RuleBreakType<'l, 's> + ?Sized> RuleBreakIterator<'l, 's, Y> {
     }
 
     fn get_break_property(&self, codepoint: Y::CharType) -> u8 {
-        // Note: Default value is 0 == UNKNOWN
-        self.data.property_table.0.get32(codepoint.into())
+        get_break_property(self.data, self.locale_override, codepoint.into())
     }
 
     fn get_break_state_from_table(&self, left: u8, right: u8) -> i8 {
@@ -267,6 +268,51 @@ impl<'l, 's, Y: RuleBreakType<'l, 's> + ?Sized> RuleBreakIterator<'l, 's, Y> {
     }
 }
 
+pub(crate) fn get_break_property(
+    data: &RuleBreakDataV1,
+    locale_override: Option<&RuleBreakDataOverrideV1>,
+    codepoint: u32,
+) -> u8 {
+    if let Some(prop) = locale_override
+        .and_then(|o| char::from_u32(codepoint).and_then(|c| o.property_overrides.get_copied(&c)))
+    {
+        return prop;
+    }
+    // Note: Default value is 0 == UNKNOWN
+    data.property_table.0.get32(codepoint)
+}
+
+/// Returns the set of simple properties, as a bit mask, that no state of the state machine
+/// continues into. Segmentation can restart before characters with these properties, see
+/// [`crate::random_access`].
+pub(crate) fn restart_properties(data: &RuleBreakDataV1) -> u64 {
+    let property_count = data.property_count as usize;
+    (0..property_count.min(u64::BITS as usize))
+        .filter(|&prop| {
+            prop as i8 <= data.last_codepoint_property
+                && prop as u8 != data.complex_property
+                && (0..property_count).all(|left| {
+                    data.break_state_table
+                        .0
+                        .get(left * property_count + prop)
+                        .map_or(false, |state| state < 0)
+                })
+        })
+        .fold(0, |mask, prop| mask | 1 << prop)
+}
+
+/// Returns whether segmentation can restart before a code point, see [`restart_properties`].
+pub(crate) fn is_restart_code_point<'a>(
+    data: &'a RuleBreakDataV1<'a>,
+    locale_override: Option<&'a RuleBreakDataOverrideV1<'a>>,
+) -> impl Fn(u32) -> bool + 'a {
+    let restart_properties = restart_properties(data);
+    move |codepoint| {
+        let prop = get_break_property(data, locale_override, codepoint);
+        prop < u64::BITS as u8 && restart_properties & 1 << prop != 0
+    }
+}
+
 #[derive(Debug)]
 pub struct RuleBreakTypeUtf8;
 
@@ -286,6 +332,27 @@ impl<'l, 's> RuleBreakType<'l, 's> for RuleBreakTypeUtf8 {
     }
 }
 
+#[derive(Debug)]
+pub struct RuleBreakTypeChunkedUtf8<I>(PhantomData<I>);
+
+impl<'l, 's, I: Iterator<Item = &'s str> + Clone> RuleBreakType<'l, 's>
+    for RuleBreakTypeChunkedUtf8<I>
+{
+    type IterAttr = ChunkedCharIndices<'s, I>;
+    type CharType = char;
+
+    fn get_current_position_character_len(iter: &RuleBreakIterator<'l, 's, Self>) -> usize {
+        iter.get_current_codepoint().map_or(0, |c| c.len_utf8())
+    }
+
+    fn handle_complex_language(
+        _: &mut RuleBreakIterator<'l, 's, Self>,
+        _: Self::CharType,
+    ) -> Option<usize> {
+        unreachable!()
+    }
+}
+
 #[derive(Debug)]
 pub struct RuleBreakTypePotentiallyIllFormedUtf8;
 
@@ -346,3 +413,28 @@ impl<'l, 's> RuleBreakType<'l, 's> for RuleBreakTypeUtf16 {
         unreachable!()
     }
 }
+
+#[derive(Debug)]
+pub struct RuleBreakTypeChunkedUtf16<I>(PhantomData<I>);
+
+impl<'l, 's, I: Iterator<Item = &'s [u16]> + Clone> RuleBreakType<'l, 's>
+    for RuleBreakTypeChunkedUtf16<I>
+{
+    type IterAttr = ChunkedUtf16Indices<'s, I>;
+    type CharType = u32;
+
+    fn get_current_position_character_len(iter: &RuleBreakIterator<'l, 's, Self>) -> usize {
+        match iter.get_current_codepoint() {
+            None => 0,
+            Some(ch) if ch >= 0x10000 => 2,
+            _ => 1,
+        }
+    }
+
+    fn handle_complex_language(
+        _: &mut RuleBreakIterator<'l, 's, Self>,
+        _: Self::CharType,
+    ) -> Option<usize> {
+        unreachable!()
+    }
+}
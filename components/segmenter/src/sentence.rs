@@ -2,11 +2,14 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
+use alloc::collections::VecDeque;
+use alloc::string::String;
 use alloc::vec::Vec;
 use icu_provider::prelude::*;
+use zerovec::VarZeroVec;
 
-use crate::indices::{Latin1Indices, Utf16Indices};
-use crate::iterator_helpers::derive_usize_iterator_with_type;
+use crate::indices::{ChunkedCharIndices, ChunkedUtf16Indices, Latin1Indices, Utf16Indices};
+use crate::random_access::{self, RandomAccessText};
 use crate::rule_segmenter::*;
 use crate::{provider::*, SegmenterError};
 use utf8_iter::Utf8CharIndices;
@@ -24,11 +27,174 @@ use utf8_iter::Utf8CharIndices;
 ///
 /// For examples of use, see [`SentenceSegmenter`].
 #[derive(Debug)]
-pub struct SentenceBreakIterator<'l, 's, Y: RuleBreakType<'l, 's> + ?Sized>(
-    RuleBreakIterator<'l, 's, Y>,
-);
+pub struct SentenceBreakIterator<'l, 's, Y: RuleBreakType<'l, 's> + ?Sized> {
+    iter: RuleBreakIterator<'l, 's, Y>,
+    suppressions: Option<SuppressionFilter<'l, 's, Y>>,
+}
+
+impl<'l, 's, Y: RuleBreakType<'l, 's> + ?Sized> Iterator for SentenceBreakIterator<'l, 's, Y> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let pos = self.iter.next()?;
+            if !self
+                .suppressions
+                .as_mut()
+                .map_or(false, |filter| filter.is_suppressed(pos))
+            {
+                return Some(pos);
+            }
+        }
+    }
+}
+
+impl<'l, 's, Y: RuleBreakType<'l, 's> + ?Sized> SentenceBreakIterator<'l, 's, Y> {
+    fn new(
+        iter: Y::IterAttr,
+        len: usize,
+        data: &'l RuleBreakDataV1<'l>,
+        locale_override: Option<&'l RuleBreakDataOverrideV1<'l>>,
+        suppressions: Option<&'l SentenceBreakSuppressionsV1<'l>>,
+    ) -> Self {
+        Self::new_with_context(
+            iter.clone(),
+            0,
+            iter,
+            len,
+            data,
+            locale_override,
+            suppressions,
+        )
+    }
+
+    /// Like [`Self::new`], but the suppressions are matched against `context`, which starts
+    /// `context_len` code units before `iter`.
+    fn new_with_context(
+        context: Y::IterAttr,
+        context_len: usize,
+        iter: Y::IterAttr,
+        len: usize,
+        data: &'l RuleBreakDataV1<'l>,
+        locale_override: Option<&'l RuleBreakDataOverrideV1<'l>>,
+        suppressions: Option<&'l SentenceBreakSuppressionsV1<'l>>,
+    ) -> Self {
+        Self {
+            suppressions: suppressions
+                .filter(|s| !s.suppressions.is_empty())
+                .map(|s| SuppressionFilter::new(context, context_len, s)),
+            iter: RuleBreakIterator {
+                iter,
+                len,
+                current_pos_data: None,
+                result_cache: Vec::new(),
+                data,
+                locale_override,
+                complex: None,
+                boundary_property: 0,
+            },
+        }
+    }
+}
+
+/// Tracks the text preceding each candidate break, so that breaks directly following
+/// an abbreviation (and any whitespace after it) can be suppressed.
+#[derive(Debug)]
+struct SuppressionFilter<'l, 's, Y: RuleBreakType<'l, 's> + ?Sized> {
+    iter: Y::IterAttr,
+    current: Option<(usize, Y::CharType)>,
+    /// The number of code units `iter` starts before the text being segmented.
+    offset: usize,
+    data: &'l SentenceBreakSuppressionsV1<'l>,
+    /// The length in chars of each suppression in `data`.
+    lengths: Vec<usize>,
+    /// The longest suppression in chars, plus one for the char before it.
+    window_len: usize,
+    /// The last `window_len` chars before the trailing whitespace.
+    window: VecDeque<char>,
+    /// The trailing whitespace, truncated to `window_len` chars.
+    whitespace: VecDeque<char>,
+}
+
+impl<'l, 's, Y: RuleBreakType<'l, 's> + ?Sized> SuppressionFilter<'l, 's, Y> {
+    fn new(
+        mut iter: Y::IterAttr,
+        offset: usize,
+        data: &'l SentenceBreakSuppressionsV1<'l>,
+    ) -> Self {
+        let lengths: Vec<usize> = data
+            .suppressions
+            .iter()
+            .map(|s| s.chars().count())
+            .collect();
+        let window_len = lengths.iter().copied().max().unwrap_or_default() + 1;
+        Self {
+            current: iter.next(),
+            iter,
+            offset,
+            data,
+            lengths,
+            window_len,
+            window: VecDeque::with_capacity(window_len),
+            whitespace: VecDeque::new(),
+        }
+    }
+
+    /// Returns whether the break at `pos` follows a suppression. Must be called with
+    /// increasing positions.
+    fn is_suppressed(&mut self, pos: usize) -> bool {
+        while let Some((i, c)) = self.current {
+            if i >= pos + self.offset {
+                break;
+            }
+            let c = char::from_u32(c.into()).unwrap_or(char::REPLACEMENT_CHARACTER);
+            if c.is_whitespace() {
+                Self::push(&mut self.whitespace, c, self.window_len);
+            } else {
+                while let Some(w) = self.whitespace.pop_front() {
+                    Self::push(&mut self.window, w, self.window_len);
+                }
+                Self::push(&mut self.window, c, self.window_len);
+            }
+            self.current = self.iter.next();
+        }
+        if self.current.is_none() {
+            // Never suppress the break at the end of the text.
+            return false;
+        }
+        self.data
+            .suppressions
+            .iter()
+            .zip(&self.lengths)
+            .any(|(suppression, &len)| {
+                let Some(start) = self.window.len().checked_sub(len) else {
+                    return false;
+                };
+                self.window.range(start..).copied().eq(suppression.chars())
+                    && start
+                        .checked_sub(1)
+                        .and_then(|i| self.window.get(i))
+                        .map_or(true, |c| !c.is_alphanumeric())
+            })
+    }
+
+    fn push(buffer: &mut VecDeque<char>, c: char, max_len: usize) {
+        if buffer.len() == max_len {
+            buffer.pop_front();
+        }
+        buffer.push_back(c);
+    }
+}
 
-derive_usize_iterator_with_type!(SentenceBreakIterator);
+/// The longest suppression in chars, plus one for the char before it.
+fn window_len(data: &SentenceBreakSuppressionsV1) -> usize {
+    data.suppressions
+        .iter()
+        .map(|s| s.chars().count())
+        .max()
+        .unwrap_or_default()
+        + 1
+}
 
 /// Sentence break iterator for an `str` (a UTF-8 string).
 ///
@@ -51,6 +217,18 @@ pub type SentenceBreakIteratorLatin1<'l, 's> = SentenceBreakIterator<'l, 's, Rul
 /// For examples of use, see [`SentenceSegmenter`].
 pub type SentenceBreakIteratorUtf16<'l, 's> = SentenceBreakIterator<'l, 's, RuleBreakTypeUtf16>;
 
+/// Sentence break iterator for an `str` (a UTF-8 string) split into chunks.
+///
+/// For examples of use, see [`SentenceSegmenter`].
+pub type SentenceBreakIteratorChunkedUtf8<'l, 's, I> =
+    SentenceBreakIterator<'l, 's, RuleBreakTypeChunkedUtf8<I>>;
+
+/// Sentence break iterator for a UTF-16 string split into chunks.
+///
+/// For examples of use, see [`SentenceSegmenter`].
+pub type SentenceBreakIteratorChunkedUtf16<'l, 's, I> =
+    SentenceBreakIterator<'l, 's, RuleBreakTypeChunkedUtf16<I>>;
+
 /// Supports loading sentence break data, and creating sentence break iterators for different string
 /// encodings.
 ///
@@ -97,9 +275,31 @@ pub type SentenceBreakIteratorUtf16<'l, 's> = SentenceBreakIterator<'l, 's, Rule
 ///     &["Ceci tuera cela. ", "Le livre tuera l’édifice."]
 /// );
 /// ```
+///
+/// Use locale-specific suppressions to avoid breaking after abbreviations:
+///
+/// ```rust
+/// use icu::locid::locale;
+/// use icu_segmenter::SentenceSegmenter;
+/// use itertools::Itertools;
+///
+/// let text = "Mr. Smith went to Washington. He left.";
+///
+/// let segmenter = SentenceSegmenter::new();
+/// let breakpoints: Vec<usize> = segmenter.segment_str(text).collect();
+/// assert_eq!(&breakpoints, &[0, 4, 30, 38]);
+///
+/// let segmenter =
+///     SentenceSegmenter::try_new_with_suppressions(&locale!("en").into())
+///         .expect("compiled data should include English");
+/// let breakpoints: Vec<usize> = segmenter.segment_str(text).collect();
+/// assert_eq!(&breakpoints, &[0, 30, 38]);
+/// ```
 #[derive(Debug)]
 pub struct SentenceSegmenter {
     payload: DataPayload<SentenceBreakDataV1Marker>,
+    payload_locale_override: Option<DataPayload<SentenceBreakDataOverrideV1Marker>>,
+    suppressions: Option<DataPayload<SentenceBreakSuppressionsV1Marker>>,
 }
 
 #[cfg(feature = "compiled_data")]
@@ -121,6 +321,8 @@ impl SentenceSegmenter {
             payload: DataPayload::from_static_ref(
                 crate::provider::Baked::SINGLETON_SEGMENTER_SENTENCE_V1,
             ),
+            payload_locale_override: None,
+            suppressions: None,
         }
     }
 
@@ -141,22 +343,162 @@ impl SentenceSegmenter {
         D: DataProvider<SentenceBreakDataV1Marker> + ?Sized,
     {
         let payload = provider.load(Default::default())?.take_payload()?;
-        Ok(Self { payload })
+        Ok(Self {
+            payload,
+            payload_locale_override: None,
+            suppressions: None,
+        })
+    }
+
+    icu_provider::gen_any_buffer_data_constructors!(
+        locale: include,
+        options: skip,
+        error: SegmenterError,
+        /// Constructs a [`SentenceSegmenter`] with the sentence break tailorings for the given
+        /// locale and compiled data.
+        ///
+        /// # Examples
+        ///
+        /// In Greek, the semicolon is a question mark:
+        ///
+        /// ```rust
+        /// use icu::locid::locale;
+        /// use icu_segmenter::SentenceSegmenter;
+        ///
+        /// let text = "Τι ώρα είναι; Είναι αργά.";
+        ///
+        /// let segmenter = SentenceSegmenter::new();
+        /// let breakpoints: Vec<usize> = segmenter.segment_str(text).collect();
+        /// assert_eq!(&breakpoints, &[0, 44]);
+        ///
+        /// let segmenter =
+        ///     SentenceSegmenter::try_new_for_locale(&locale!("el").into())
+        ///         .expect("locale should be present");
+        /// let breakpoints: Vec<usize> = segmenter.segment_str(text).collect();
+        /// assert_eq!(&breakpoints, &[0, 24, 44]);
+        /// ```
+        functions: [
+            try_new_for_locale,
+            try_new_for_locale_with_any_provider,
+            try_new_for_locale_with_buffer_provider,
+            try_new_for_locale_unstable,
+            Self,
+        ]
+    );
+
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::try_new_for_locale)]
+    pub fn try_new_for_locale_unstable<D>(
+        provider: &D,
+        locale: &DataLocale,
+    ) -> Result<Self, SegmenterError>
+    where
+        D: DataProvider<SentenceBreakDataV1Marker>
+            + DataProvider<SentenceBreakDataOverrideV1Marker>
+            + ?Sized,
+    {
+        let payload = provider.load(Default::default())?.take_payload()?;
+        let payload_locale_override = provider
+            .load(DataRequest {
+                locale,
+                metadata: Default::default(),
+            })?
+            .take_payload()?;
+        Ok(Self {
+            payload,
+            payload_locale_override: Some(payload_locale_override),
+            suppressions: None,
+        })
+    }
+
+    icu_provider::gen_any_buffer_data_constructors!(
+        locale: include,
+        options: skip,
+        error: SegmenterError,
+        /// Constructs a [`SentenceSegmenter`] with the sentence break tailorings for the given
+        /// locale that also does not break after the abbreviations listed in the CLDR sentence
+        /// break suppressions for that locale, using compiled data.
+        ///
+        /// For example, in English there is no break in "Mr. Smith".
+        functions: [
+            try_new_with_suppressions,
+            try_new_with_suppressions_with_any_provider,
+            try_new_with_suppressions_with_buffer_provider,
+            try_new_with_suppressions_unstable,
+            Self,
+        ]
+    );
+
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::try_new_with_suppressions)]
+    pub fn try_new_with_suppressions_unstable<D>(
+        provider: &D,
+        locale: &DataLocale,
+    ) -> Result<Self, SegmenterError>
+    where
+        D: DataProvider<SentenceBreakDataV1Marker>
+            + DataProvider<SentenceBreakDataOverrideV1Marker>
+            + DataProvider<SentenceBreakSuppressionsV1Marker>
+            + ?Sized,
+    {
+        let mut segmenter = Self::try_new_for_locale_unstable(provider, locale)?;
+        segmenter.suppressions = Some(
+            provider
+                .load(DataRequest {
+                    locale,
+                    metadata: Default::default(),
+                })?
+                .take_payload()?,
+        );
+        Ok(segmenter)
+    }
+
+    /// Adds abbreviations after which this segmenter does not break, in addition to
+    /// any locale suppressions it was constructed with.
+    ///
+    /// Suppressions are matched case-sensitively against the text before a break,
+    /// ignoring trailing whitespace, and only if they are not preceded by a letter or digit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use icu_segmenter::SentenceSegmenter;
+    ///
+    /// let text = "See Fig. B for details. Gen. Grant agreed.";
+    ///
+    /// let mut segmenter = SentenceSegmenter::new();
+    /// let breakpoints: Vec<usize> = segmenter.segment_str(text).collect();
+    /// assert_eq!(&breakpoints, &[0, 9, 24, 29, 42]);
+    ///
+    /// segmenter.add_suppressions(["Fig.", "Gen."]);
+    /// let breakpoints: Vec<usize> = segmenter.segment_str(text).collect();
+    /// assert_eq!(&breakpoints, &[0, 24, 42]);
+    /// ```
+    pub fn add_suppressions<'a>(&mut self, suppressions: impl IntoIterator<Item = &'a str>) {
+        let mut all: Vec<String> = self
+            .suppressions
+            .as_ref()
+            .map(|p| p.get().suppressions.iter().map(String::from).collect())
+            .unwrap_or_default();
+        for suppression in suppressions {
+            if !suppression.is_empty() && !all.iter().any(|s| s == suppression) {
+                all.push(suppression.into());
+            }
+        }
+        self.suppressions = Some(DataPayload::from_owned(SentenceBreakSuppressionsV1 {
+            suppressions: VarZeroVec::from(&all),
+        }));
     }
 
     /// Creates a sentence break iterator for an `str` (a UTF-8 string).
     ///
     /// There are always breakpoints at 0 and the string length, or only at 0 for the empty string.
     pub fn segment_str<'l, 's>(&'l self, input: &'s str) -> SentenceBreakIteratorUtf8<'l, 's> {
-        SentenceBreakIterator(RuleBreakIterator {
-            iter: input.char_indices(),
-            len: input.len(),
-            current_pos_data: None,
-            result_cache: Vec::new(),
-            data: self.payload.get(),
-            complex: None,
-            boundary_property: 0,
-        })
+        SentenceBreakIterator::new(
+            input.char_indices(),
+            input.len(),
+            self.payload.get(),
+            self.payload_locale_override.as_ref().map(|p| p.get()),
+            self.suppressions.as_ref().map(|p| p.get()),
+        )
     }
     /// Creates a sentence break iterator for a potentially ill-formed UTF8 string
     ///
@@ -167,15 +509,13 @@ impl SentenceSegmenter {
         &'l self,
         input: &'s [u8],
     ) -> SentenceBreakIteratorPotentiallyIllFormedUtf8<'l, 's> {
-        SentenceBreakIterator(RuleBreakIterator {
-            iter: Utf8CharIndices::new(input),
-            len: input.len(),
-            current_pos_data: None,
-            result_cache: Vec::new(),
-            data: self.payload.get(),
-            complex: None,
-            boundary_property: 0,
-        })
+        SentenceBreakIterator::new(
+            Utf8CharIndices::new(input),
+            input.len(),
+            self.payload.get(),
+            self.payload_locale_override.as_ref().map(|p| p.get()),
+            self.suppressions.as_ref().map(|p| p.get()),
+        )
     }
     /// Creates a sentence break iterator for a Latin-1 (8-bit) string.
     ///
@@ -184,31 +524,214 @@ impl SentenceSegmenter {
         &'l self,
         input: &'s [u8],
     ) -> SentenceBreakIteratorLatin1<'l, 's> {
-        SentenceBreakIterator(RuleBreakIterator {
-            iter: Latin1Indices::new(input),
-            len: input.len(),
-            current_pos_data: None,
-            result_cache: Vec::new(),
-            data: self.payload.get(),
-            complex: None,
-            boundary_property: 0,
-        })
+        SentenceBreakIterator::new(
+            Latin1Indices::new(input),
+            input.len(),
+            self.payload.get(),
+            self.payload_locale_override.as_ref().map(|p| p.get()),
+            self.suppressions.as_ref().map(|p| p.get()),
+        )
     }
 
     /// Creates a sentence break iterator for a UTF-16 string.
     ///
     /// There are always breakpoints at 0 and the string length, or only at 0 for the empty string.
     pub fn segment_utf16<'l, 's>(&'l self, input: &'s [u16]) -> SentenceBreakIteratorUtf16<'l, 's> {
-        SentenceBreakIterator(RuleBreakIterator {
-            iter: Utf16Indices::new(input),
-            len: input.len(),
-            current_pos_data: None,
-            result_cache: Vec::new(),
-            data: self.payload.get(),
-            complex: None,
-            boundary_property: 0,
+        SentenceBreakIterator::new(
+            Utf16Indices::new(input),
+            input.len(),
+            self.payload.get(),
+            self.payload_locale_override.as_ref().map(|p| p.get()),
+            self.suppressions.as_ref().map(|p| p.get()),
+        )
+    }
+
+    /// Creates a sentence break iterator for an `str` (a UTF-8 string) split into chunks, such
+    /// as the chunks of a rope.
+    ///
+    /// The breakpoints are offsets into the concatenation of the chunks, and are the same as
+    /// for the concatenated string.
+    ///
+    /// `chunks` can be any iterator over the chunks; it is cloned, so it should be cheap to clone.
+    pub fn segment_str_chunks<'l, 's, I>(
+        &'l self,
+        chunks: I,
+    ) -> SentenceBreakIteratorChunkedUtf8<'l, 's, I::IntoIter>
+    where
+        I: IntoIterator<Item = &'s str>,
+        I::IntoIter: Clone,
+    {
+        let chunks = chunks.into_iter();
+        SentenceBreakIterator::new(
+            ChunkedCharIndices::new(chunks.clone()),
+            chunks.map(str::len).sum(),
+            self.payload.get(),
+            self.payload_locale_override.as_ref().map(|p| p.get()),
+            self.suppressions.as_ref().map(|p| p.get()),
+        )
+    }
+
+    /// Creates a sentence break iterator for a UTF-16 string split into chunks.
+    ///
+    /// The breakpoints are offsets into the concatenation of the chunks, and are the same as
+    /// for the concatenated string. Surrogate pairs may be split across chunks.
+    ///
+    /// `chunks` can be any iterator over the chunks; it is cloned, so it should be cheap to clone.
+    pub fn segment_utf16_chunks<'l, 's, I>(
+        &'l self,
+        chunks: I,
+    ) -> SentenceBreakIteratorChunkedUtf16<'l, 's, I::IntoIter>
+    where
+        I: IntoIterator<Item = &'s [u16]>,
+        I::IntoIter: Clone,
+    {
+        let chunks = chunks.into_iter();
+        SentenceBreakIterator::new(
+            ChunkedUtf16Indices::new(chunks.clone()),
+            chunks.map(<[u16]>::len).sum(),
+            self.payload.get(),
+            self.payload_locale_override.as_ref().map(|p| p.get()),
+            self.suppressions.as_ref().map(|p| p.get()),
+        )
+    }
+
+    /// Returns the first sentence boundary after `offset` in an `str` (a UTF-8 string), or
+    /// `None` if `offset` is at or past the end of the text.
+    ///
+    /// Only the text around `offset` is segmented.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use icu::segmenter::SentenceSegmenter;
+    /// let segmenter = SentenceSegmenter::new();
+    ///
+    /// let text = "One. Two. Three.";
+    /// assert_eq!(segmenter.following_str(text, 6), Some(10));
+    /// assert_eq!(segmenter.preceding_str(text, 6), Some(5));
+    /// assert!(segmenter.is_boundary_str(text, 10));
+    /// assert!(!segmenter.is_boundary_str(text, 4));
+    /// ```
+    pub fn following_str(&self, input: &str, offset: usize) -> Option<usize> {
+        random_access::following(input, offset, self.is_restart_code_point(), |start| {
+            self.segment_str_from(input, start).map(move |b| b + start)
         })
     }
+
+    /// Returns the last sentence boundary before `offset` in an `str` (a UTF-8 string), or
+    /// `None` if `offset` is 0.
+    ///
+    /// See [`Self::following_str`] for an example.
+    pub fn preceding_str(&self, input: &str, offset: usize) -> Option<usize> {
+        random_access::preceding(input, offset, self.is_restart_code_point(), |start| {
+            self.segment_str_from(input, start).map(move |b| b + start)
+        })
+    }
+
+    /// Returns whether `offset` is a sentence boundary in an `str` (a UTF-8 string).
+    ///
+    /// See [`Self::following_str`] for an example.
+    pub fn is_boundary_str(&self, input: &str, offset: usize) -> bool {
+        random_access::is_boundary(input, offset, self.is_restart_code_point(), |start| {
+            self.segment_str_from(input, start).map(move |b| b + start)
+        })
+    }
+
+    /// Returns the first sentence boundary after `offset` in a UTF-16 string, or `None` if
+    /// `offset` is at or past the end of the text.
+    ///
+    /// Only the text around `offset` is segmented.
+    pub fn following_utf16(&self, input: &[u16], offset: usize) -> Option<usize> {
+        random_access::following(input, offset, self.is_restart_code_point(), |start| {
+            self.segment_utf16_from(input, start)
+                .map(move |b| b + start)
+        })
+    }
+
+    /// Returns the last sentence boundary before `offset` in a UTF-16 string, or `None` if
+    /// `offset` is 0.
+    pub fn preceding_utf16(&self, input: &[u16], offset: usize) -> Option<usize> {
+        random_access::preceding(input, offset, self.is_restart_code_point(), |start| {
+            self.segment_utf16_from(input, start)
+                .map(move |b| b + start)
+        })
+    }
+
+    /// Returns whether `offset` is a sentence boundary in a UTF-16 string.
+    pub fn is_boundary_utf16(&self, input: &[u16], offset: usize) -> bool {
+        random_access::is_boundary(input, offset, self.is_restart_code_point(), |start| {
+            self.segment_utf16_from(input, start)
+                .map(move |b| b + start)
+        })
+    }
+
+    fn is_restart_code_point(&self) -> impl Fn(u32) -> bool + '_ {
+        is_restart_code_point(
+            self.payload.get(),
+            self.payload_locale_override.as_ref().map(|p| p.get()),
+        )
+    }
+
+    /// Segments `input` from `start`, with positions relative to `start`.
+    fn segment_str_from<'l, 's>(
+        &'l self,
+        input: &'s str,
+        start: usize,
+    ) -> SentenceBreakIteratorUtf8<'l, 's> {
+        let context = self.suppression_context(input, start);
+        let input_from_start = input.get(start..).unwrap_or_default();
+        SentenceBreakIterator::new_with_context(
+            input.get(context..).unwrap_or_default().char_indices(),
+            start - context,
+            input_from_start.char_indices(),
+            input_from_start.len(),
+            self.payload.get(),
+            self.payload_locale_override.as_ref().map(|p| p.get()),
+            self.suppressions.as_ref().map(|p| p.get()),
+        )
+    }
+
+    /// Segments `input` from `start`, with positions relative to `start`.
+    fn segment_utf16_from<'l, 's>(
+        &'l self,
+        input: &'s [u16],
+        start: usize,
+    ) -> SentenceBreakIteratorUtf16<'l, 's> {
+        let context = self.suppression_context(input, start);
+        let input_from_start = input.get(start..).unwrap_or_default();
+        SentenceBreakIterator::new_with_context(
+            Utf16Indices::new(input.get(context..).unwrap_or_default()),
+            start - context,
+            Utf16Indices::new(input_from_start),
+            input_from_start.len(),
+            self.payload.get(),
+            self.payload_locale_override.as_ref().map(|p| p.get()),
+            self.suppressions.as_ref().map(|p| p.get()),
+        )
+    }
+
+    /// Returns where to start matching suppressions so that breaks after `start` are
+    /// suppressed the same way as when segmenting from the start of the text, i.e. far
+    /// enough back to fill the suppression window with non-whitespace characters.
+    fn suppression_context(&self, input: impl RandomAccessText, start: usize) -> usize {
+        let Some(suppressions) = self.suppressions.as_ref() else {
+            return start;
+        };
+        let mut remaining = window_len(suppressions.get());
+        let mut context = start;
+        while context > 0 && remaining > 0 {
+            context -= 1;
+            if input.is_char_boundary(context)
+                && input
+                    .code_point_at(context)
+                    .and_then(char::from_u32)
+                    .map_or(true, |c| !c.is_whitespace())
+            {
+                remaining -= 1;
+            }
+        }
+        context
+    }
 }
 
 #[cfg(all(test, feature = "serde"))]
@@ -218,3 +741,74 @@ fn empty_string() {
     let breaks: Vec<usize> = segmenter.segment_str("").collect();
     assert_eq!(breaks, [0]);
 }
+
+#[cfg(all(test, feature = "compiled_data"))]
+mod tests {
+    use super::*;
+    use icu_locid::langid;
+
+    #[test]
+    fn suppressions() {
+        let segmenter =
+            SentenceSegmenter::try_new_with_suppressions(&langid!("en").into()).unwrap();
+
+        // Suppressed with any amount of whitespace, but not at the end of the text.
+        let text = "Mr.  Smith met Prof.\nJones. Mr.";
+        let breaks: Vec<usize> = segmenter.segment_str(text).collect();
+        assert_eq!(breaks, [0, 28, 31]);
+
+        // All encodings agree.
+        let utf16: Vec<u16> = text.encode_utf16().collect();
+        let breaks: Vec<usize> = segmenter.segment_utf16(&utf16).collect();
+        assert_eq!(breaks, [0, 28, 31]);
+        let breaks: Vec<usize> = segmenter.segment_latin1(text.as_bytes()).collect();
+        assert_eq!(breaks, [0, 28, 31]);
+        let breaks: Vec<usize> = segmenter.segment_utf8(text.as_bytes()).collect();
+        assert_eq!(breaks, [0, 28, 31]);
+
+        // Not suppressed after a letter, or with a different case.
+        let text = "I saw HMr. Smith. MRS. Jones left.";
+        let breaks: Vec<usize> = segmenter.segment_str(text).collect();
+        assert_eq!(breaks, [0, 11, 18, 23, 34]);
+    }
+
+    #[test]
+    fn suppressions_fallback() {
+        // en-US falls back to en.
+        let segmenter =
+            SentenceSegmenter::try_new_with_suppressions(&langid!("en-US").into()).unwrap();
+        let breaks: Vec<usize> = segmenter.segment_str("Mr. Smith left.").collect();
+        assert_eq!(breaks, [0, 15]);
+
+        // German, Italian and Portuguese have their own suppressions.
+        for (langid, text) in [
+            (langid!("de"), "Das ist evtl. gut."),
+            (langid!("it"), "Ho visto il Sig. Rossi."),
+            (langid!("pt"), "Ela falou com a Dra. Silva."),
+        ] {
+            let segmenter =
+                SentenceSegmenter::try_new_with_suppressions(&(&langid).into()).unwrap();
+            let breaks: Vec<usize> = segmenter.segment_str(text).collect();
+            assert_eq!(breaks, [0, text.len()], "{langid}");
+        }
+
+        // Japanese has no suppressions.
+        let segmenter =
+            SentenceSegmenter::try_new_with_suppressions(&langid!("ja").into()).unwrap();
+        let breaks: Vec<usize> = segmenter.segment_str("Mr. Smith left.").collect();
+        assert_eq!(breaks, [0, 4, 15]);
+    }
+
+    #[test]
+    fn custom_suppressions() {
+        let mut segmenter =
+            SentenceSegmenter::try_new_with_suppressions(&langid!("fr").into()).unwrap();
+        let text = "M. Dupont a vu Gén. Leclerc. Il est parti.";
+        let breaks: Vec<usize> = segmenter.segment_str(text).collect();
+        assert_eq!(breaks, [0, 21, 30, 43]);
+
+        segmenter.add_suppressions(["Gén.", ""]);
+        let breaks: Vec<usize> = segmenter.segment_str(text).collect();
+        assert_eq!(breaks, [0, 30, 43]);
+    }
+}
@@ -3,14 +3,16 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use crate::complex::*;
-use crate::indices::{Latin1Indices, Utf16Indices};
+use crate::indices::{ChunkedCharIndices, ChunkedUtf16Indices, Latin1Indices, Utf16Indices};
 use crate::iterator_helpers::derive_usize_iterator_with_type;
 use crate::provider::*;
+use crate::random_access;
 use crate::rule_segmenter::*;
 use crate::SegmenterError;
 use alloc::string::String;
 use alloc::vec;
 use alloc::vec::Vec;
+use core::marker::PhantomData;
 use core::str::CharIndices;
 use icu_provider::prelude::*;
 use utf8_iter::Utf8CharIndices;
@@ -86,6 +88,18 @@ pub type WordBreakIteratorLatin1<'l, 's> = WordBreakIterator<'l, 's, RuleBreakTy
 /// For examples of use, see [`WordSegmenter`].
 pub type WordBreakIteratorUtf16<'l, 's> = WordBreakIterator<'l, 's, WordBreakTypeUtf16>;
 
+/// Word break iterator for an `str` (a UTF-8 string) split into chunks.
+///
+/// For examples of use, see [`WordSegmenter`].
+pub type WordBreakIteratorChunkedUtf8<'l, 's, I> =
+    WordBreakIterator<'l, 's, WordBreakTypeChunkedUtf8<I>>;
+
+/// Word break iterator for a UTF-16 string split into chunks.
+///
+/// For examples of use, see [`WordSegmenter`].
+pub type WordBreakIteratorChunkedUtf16<'l, 's, I> =
+    WordBreakIterator<'l, 's, WordBreakTypeChunkedUtf16<I>>;
+
 /// Supports loading word break data, and creating word break iterators for different string
 /// encodings.
 ///
@@ -153,6 +167,7 @@ pub type WordBreakIteratorUtf16<'l, 's> = WordBreakIterator<'l, 's, WordBreakTyp
 pub struct WordSegmenter {
     payload: DataPayload<WordBreakDataV1Marker>,
     complex: ComplexPayloads,
+    payload_locale_override: Option<DataPayload<WordBreakDataOverrideV1Marker>>,
 }
 
 impl WordSegmenter {
@@ -192,6 +207,7 @@ impl WordSegmenter {
                 crate::provider::Baked::SINGLETON_SEGMENTER_WORD_V1,
             ),
             complex: ComplexPayloads::new_auto(),
+            payload_locale_override: None,
         }
     }
 
@@ -223,6 +239,78 @@ impl WordSegmenter {
         Ok(Self {
             payload: provider.load(Default::default())?.take_payload()?,
             complex: ComplexPayloads::try_new_auto(provider)?,
+            payload_locale_override: None,
+        })
+    }
+
+    #[cfg(feature = "auto")]
+    icu_provider::gen_any_buffer_data_constructors!(
+        locale: include,
+        options: skip,
+        error: SegmenterError,
+        /// Constructs a [`WordSegmenter`] with the word break tailorings for the given locale
+        /// and compiled auto data for complex scripts.
+        ///
+        /// See [`Self::new_auto`] for the handling of complex scripts.
+        ///
+        /// Unlike the locale-invariant constructors, which implement the plain UAX #29 rules,
+        /// this applies the CLDR tailorings; in particular, the CLDR root rules do not keep
+        /// words together across a colon.
+        ///
+        /// # Examples
+        ///
+        /// In Finnish and Swedish, a colon between letters does not break a word:
+        ///
+        /// ```
+        /// use icu::locid::locale;
+        /// use icu::segmenter::WordSegmenter;
+        ///
+        /// let segmenter =
+        ///     WordSegmenter::try_new_auto_for_locale(&locale!("en").into())
+        ///         .expect("locale should be present");
+        /// let bps: Vec<usize> = segmenter.segment_str("EU:n").collect();
+        /// assert_eq!(bps, [0, 2, 3, 4]);
+        ///
+        /// let segmenter =
+        ///     WordSegmenter::try_new_auto_for_locale(&locale!("fi").into())
+        ///         .expect("locale should be present");
+        /// let bps: Vec<usize> = segmenter.segment_str("EU:n").collect();
+        /// assert_eq!(bps, [0, 4]);
+        /// ```
+        functions: [
+            try_new_auto_for_locale,
+            try_new_auto_for_locale_with_any_provider,
+            try_new_auto_for_locale_with_buffer_provider,
+            try_new_auto_for_locale_unstable,
+            Self
+        ]
+    );
+
+    #[cfg(feature = "auto")]
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::try_new_auto_for_locale)]
+    pub fn try_new_auto_for_locale_unstable<D>(
+        provider: &D,
+        locale: &DataLocale,
+    ) -> Result<Self, SegmenterError>
+    where
+        D: DataProvider<WordBreakDataV1Marker>
+            + DataProvider<WordBreakDataOverrideV1Marker>
+            + DataProvider<DictionaryForWordOnlyAutoV1Marker>
+            + DataProvider<LstmForWordLineAutoV1Marker>
+            + DataProvider<GraphemeClusterBreakDataV1Marker>
+            + ?Sized,
+    {
+        Ok(Self {
+            payload: provider.load(Default::default())?.take_payload()?,
+            complex: ComplexPayloads::try_new_auto(provider)?,
+            payload_locale_override: Some(
+                provider
+                    .load(DataRequest {
+                        locale,
+                        metadata: Default::default(),
+                    })?
+                    .take_payload()?,
+            ),
         })
     }
 
@@ -267,6 +355,7 @@ impl WordSegmenter {
                 crate::provider::Baked::SINGLETON_SEGMENTER_WORD_V1,
             ),
             complex: ComplexPayloads::new_lstm(),
+            payload_locale_override: None,
         }
     }
 
@@ -297,6 +386,52 @@ impl WordSegmenter {
         Ok(Self {
             payload: provider.load(Default::default())?.take_payload()?,
             complex: ComplexPayloads::try_new_lstm(provider)?,
+            payload_locale_override: None,
+        })
+    }
+
+    #[cfg(feature = "lstm")]
+    icu_provider::gen_any_buffer_data_constructors!(
+        locale: include,
+        options: skip,
+        error: SegmenterError,
+        /// Constructs a [`WordSegmenter`] with the word break tailorings for the given locale
+        /// and compiled LSTM data for complex scripts.
+        ///
+        /// See [`Self::new_lstm`] for the handling of complex scripts.
+        functions: [
+            try_new_lstm_for_locale,
+            try_new_lstm_for_locale_with_any_provider,
+            try_new_lstm_for_locale_with_buffer_provider,
+            try_new_lstm_for_locale_unstable,
+            Self
+        ]
+    );
+
+    #[cfg(feature = "lstm")]
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::try_new_lstm_for_locale)]
+    pub fn try_new_lstm_for_locale_unstable<D>(
+        provider: &D,
+        locale: &DataLocale,
+    ) -> Result<Self, SegmenterError>
+    where
+        D: DataProvider<WordBreakDataV1Marker>
+            + DataProvider<WordBreakDataOverrideV1Marker>
+            + DataProvider<LstmForWordLineAutoV1Marker>
+            + DataProvider<GraphemeClusterBreakDataV1Marker>
+            + ?Sized,
+    {
+        Ok(Self {
+            payload: provider.load(Default::default())?.take_payload()?,
+            complex: ComplexPayloads::try_new_lstm(provider)?,
+            payload_locale_override: Some(
+                provider
+                    .load(DataRequest {
+                        locale,
+                        metadata: Default::default(),
+                    })?
+                    .take_payload()?,
+            ),
         })
     }
 
@@ -335,6 +470,7 @@ impl WordSegmenter {
                 crate::provider::Baked::SINGLETON_SEGMENTER_WORD_V1,
             ),
             complex: ComplexPayloads::new_dict(),
+            payload_locale_override: None,
         }
     }
 
@@ -364,6 +500,51 @@ impl WordSegmenter {
         Ok(Self {
             payload: provider.load(Default::default())?.take_payload()?,
             complex: ComplexPayloads::try_new_dict(provider)?,
+            payload_locale_override: None,
+        })
+    }
+
+    icu_provider::gen_any_buffer_data_constructors!(
+        locale: include,
+        options: skip,
+        error: SegmenterError,
+        /// Constructs a [`WordSegmenter`] with the word break tailorings for the given locale
+        /// and compiled dictionary data for complex scripts.
+        ///
+        /// See [`Self::new_dictionary`] for the handling of complex scripts.
+        functions: [
+            try_new_dictionary_for_locale,
+            try_new_dictionary_for_locale_with_any_provider,
+            try_new_dictionary_for_locale_with_buffer_provider,
+            try_new_dictionary_for_locale_unstable,
+            Self
+        ]
+    );
+
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::try_new_dictionary_for_locale)]
+    pub fn try_new_dictionary_for_locale_unstable<D>(
+        provider: &D,
+        locale: &DataLocale,
+    ) -> Result<Self, SegmenterError>
+    where
+        D: DataProvider<WordBreakDataV1Marker>
+            + DataProvider<WordBreakDataOverrideV1Marker>
+            + DataProvider<DictionaryForWordOnlyAutoV1Marker>
+            + DataProvider<DictionaryForWordLineExtendedV1Marker>
+            + DataProvider<GraphemeClusterBreakDataV1Marker>
+            + ?Sized,
+    {
+        Ok(Self {
+            payload: provider.load(Default::default())?.take_payload()?,
+            complex: ComplexPayloads::try_new_dict(provider)?,
+            payload_locale_override: Some(
+                provider
+                    .load(DataRequest {
+                        locale,
+                        metadata: Default::default(),
+                    })?
+                    .take_payload()?,
+            ),
         })
     }
 
@@ -377,6 +558,7 @@ impl WordSegmenter {
             current_pos_data: None,
             result_cache: Vec::new(),
             data: self.payload.get(),
+            locale_override: self.payload_locale_override.as_ref().map(|p| p.get()),
             complex: Some(&self.complex),
             boundary_property: 0,
         })
@@ -397,6 +579,7 @@ impl WordSegmenter {
             current_pos_data: None,
             result_cache: Vec::new(),
             data: self.payload.get(),
+            locale_override: self.payload_locale_override.as_ref().map(|p| p.get()),
             complex: Some(&self.complex),
             boundary_property: 0,
         })
@@ -412,6 +595,7 @@ impl WordSegmenter {
             current_pos_data: None,
             result_cache: Vec::new(),
             data: self.payload.get(),
+            locale_override: self.payload_locale_override.as_ref().map(|p| p.get()),
             complex: Some(&self.complex),
             boundary_property: 0,
         })
@@ -427,10 +611,159 @@ impl WordSegmenter {
             current_pos_data: None,
             result_cache: Vec::new(),
             data: self.payload.get(),
+            locale_override: self.payload_locale_override.as_ref().map(|p| p.get()),
+            complex: Some(&self.complex),
+            boundary_property: 0,
+        })
+    }
+
+    /// Creates a word break iterator for an `str` (a UTF-8 string) split into chunks, such as
+    /// the chunks of a rope.
+    ///
+    /// The breakpoints are offsets into the concatenation of the chunks, and are the same as
+    /// for the concatenated string, including in complex scripts.
+    ///
+    /// `chunks` can be any iterator over the chunks; it is cloned, so it should be cheap to clone.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use icu::segmenter::WordSegmenter;
+    /// let segmenter = WordSegmenter::new_auto();
+    ///
+    /// let chunks = ["Hello Wo", "", "rld"];
+    /// let breakpoints: Vec<usize> =
+    ///     segmenter.segment_str_chunks(chunks).collect();
+    /// assert_eq!(&breakpoints, &[0, 5, 6, 11]);
+    /// ```
+    pub fn segment_str_chunks<'l, 's, I>(
+        &'l self,
+        chunks: I,
+    ) -> WordBreakIteratorChunkedUtf8<'l, 's, I::IntoIter>
+    where
+        I: IntoIterator<Item = &'s str>,
+        I::IntoIter: Clone,
+    {
+        let chunks = chunks.into_iter();
+        WordBreakIterator(RuleBreakIterator {
+            iter: ChunkedCharIndices::new(chunks.clone()),
+            len: chunks.map(str::len).sum(),
+            current_pos_data: None,
+            result_cache: Vec::new(),
+            data: self.payload.get(),
+            locale_override: self.payload_locale_override.as_ref().map(|p| p.get()),
+            complex: Some(&self.complex),
+            boundary_property: 0,
+        })
+    }
+
+    /// Creates a word break iterator for a UTF-16 string split into chunks.
+    ///
+    /// The breakpoints are offsets into the concatenation of the chunks, and are the same as
+    /// for the concatenated string. Surrogate pairs may be split across chunks.
+    ///
+    /// `chunks` can be any iterator over the chunks; it is cloned, so it should be cheap to clone.
+    pub fn segment_utf16_chunks<'l, 's, I>(
+        &'l self,
+        chunks: I,
+    ) -> WordBreakIteratorChunkedUtf16<'l, 's, I::IntoIter>
+    where
+        I: IntoIterator<Item = &'s [u16]>,
+        I::IntoIter: Clone,
+    {
+        let chunks = chunks.into_iter();
+        WordBreakIterator(RuleBreakIterator {
+            iter: ChunkedUtf16Indices::new(chunks.clone()),
+            len: chunks.map(<[u16]>::len).sum(),
+            current_pos_data: None,
+            result_cache: Vec::new(),
+            data: self.payload.get(),
+            locale_override: self.payload_locale_override.as_ref().map(|p| p.get()),
             complex: Some(&self.complex),
             boundary_property: 0,
         })
     }
+
+    /// Returns the first word boundary after `offset` in an `str` (a UTF-8 string), or `None`
+    /// if `offset` is at or past the end of the text.
+    ///
+    /// Only the text around `offset` is segmented.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use icu::segmenter::WordSegmenter;
+    /// let segmenter = WordSegmenter::new_auto();
+    ///
+    /// let text = "Hello World";
+    /// assert_eq!(segmenter.following_str(text, 2), Some(5));
+    /// assert_eq!(segmenter.following_str(text, 5), Some(6));
+    /// assert_eq!(segmenter.preceding_str(text, 8), Some(6));
+    /// assert!(segmenter.is_boundary_str(text, 6));
+    /// assert!(!segmenter.is_boundary_str(text, 7));
+    /// ```
+    pub fn following_str(&self, input: &str, offset: usize) -> Option<usize> {
+        random_access::following(input, offset, self.is_restart_code_point(), |start| {
+            self.segment_str(input.get(start..).unwrap_or_default())
+                .map(move |b| b + start)
+        })
+    }
+
+    /// Returns the last word boundary before `offset` in an `str` (a UTF-8 string), or `None`
+    /// if `offset` is 0.
+    ///
+    /// See [`Self::following_str`] for an example.
+    pub fn preceding_str(&self, input: &str, offset: usize) -> Option<usize> {
+        random_access::preceding(input, offset, self.is_restart_code_point(), |start| {
+            self.segment_str(input.get(start..).unwrap_or_default())
+                .map(move |b| b + start)
+        })
+    }
+
+    /// Returns whether `offset` is a word boundary in an `str` (a UTF-8 string).
+    ///
+    /// See [`Self::following_str`] for an example.
+    pub fn is_boundary_str(&self, input: &str, offset: usize) -> bool {
+        random_access::is_boundary(input, offset, self.is_restart_code_point(), |start| {
+            self.segment_str(input.get(start..).unwrap_or_default())
+                .map(move |b| b + start)
+        })
+    }
+
+    /// Returns the first word boundary after `offset` in a UTF-16 string, or `None` if
+    /// `offset` is at or past the end of the text.
+    ///
+    /// Only the text around `offset` is segmented.
+    pub fn following_utf16(&self, input: &[u16], offset: usize) -> Option<usize> {
+        random_access::following(input, offset, self.is_restart_code_point(), |start| {
+            self.segment_utf16(input.get(start..).unwrap_or_default())
+                .map(move |b| b + start)
+        })
+    }
+
+    /// Returns the last word boundary before `offset` in a UTF-16 string, or `None` if
+    /// `offset` is 0.
+    pub fn preceding_utf16(&self, input: &[u16], offset: usize) -> Option<usize> {
+        random_access::preceding(input, offset, self.is_restart_code_point(), |start| {
+            self.segment_utf16(input.get(start..).unwrap_or_default())
+                .map(move |b| b + start)
+        })
+    }
+
+    /// Returns whether `offset` is a word boundary in a UTF-16 string.
+    pub fn is_boundary_utf16(&self, input: &[u16], offset: usize) -> bool {
+        random_access::is_boundary(input, offset, self.is_restart_code_point(), |start| {
+            self.segment_utf16(input.get(start..).unwrap_or_default())
+                .map(move |b| b + start)
+        })
+    }
+
+    fn is_restart_code_point(&self) -> impl Fn(u32) -> bool + '_ {
+        is_restart_code_point(
+            self.payload.get(),
+            self.payload_locale_override.as_ref().map(|p| p.get()),
+        )
+    }
 }
 
 #[derive(Debug)]
@@ -452,6 +785,27 @@ impl<'l, 's> RuleBreakType<'l, 's> for WordBreakTypeUtf8 {
     }
 }
 
+#[derive(Debug)]
+pub struct WordBreakTypeChunkedUtf8<I>(PhantomData<I>);
+
+impl<'l, 's, I: Iterator<Item = &'s str> + Clone> RuleBreakType<'l, 's>
+    for WordBreakTypeChunkedUtf8<I>
+{
+    type IterAttr = ChunkedCharIndices<'s, I>;
+    type CharType = char;
+
+    fn get_current_position_character_len(iter: &RuleBreakIterator<'l, 's, Self>) -> usize {
+        iter.get_current_codepoint().map_or(0, |c| c.len_utf8())
+    }
+
+    fn handle_complex_language(
+        iter: &mut RuleBreakIterator<'l, 's, Self>,
+        left_codepoint: Self::CharType,
+    ) -> Option<usize> {
+        handle_complex_language_utf8(iter, left_codepoint)
+    }
+}
+
 #[derive(Debug)]
 pub struct WordBreakTypePotentiallyIllFormedUtf8;
 
@@ -542,53 +896,89 @@ impl<'l, 's> RuleBreakType<'l, 's> for WordBreakTypeUtf16 {
     }
 
     fn handle_complex_language(
-        iter: &mut RuleBreakIterator<Self>,
+        iter: &mut RuleBreakIterator<'l, 's, Self>,
+        left_codepoint: Self::CharType,
+    ) -> Option<usize> {
+        handle_complex_language_utf16(iter, left_codepoint)
+    }
+}
+
+#[derive(Debug)]
+pub struct WordBreakTypeChunkedUtf16<I>(PhantomData<I>);
+
+impl<'l, 's, I: Iterator<Item = &'s [u16]> + Clone> RuleBreakType<'l, 's>
+    for WordBreakTypeChunkedUtf16<I>
+{
+    type IterAttr = ChunkedUtf16Indices<'s, I>;
+    type CharType = u32;
+
+    fn get_current_position_character_len(iter: &RuleBreakIterator<'l, 's, Self>) -> usize {
+        match iter.get_current_codepoint() {
+            None => 0,
+            Some(ch) if ch >= 0x10000 => 2,
+            _ => 1,
+        }
+    }
+
+    fn handle_complex_language(
+        iter: &mut RuleBreakIterator<'l, 's, Self>,
         left_codepoint: Self::CharType,
     ) -> Option<usize> {
-        // word segmenter doesn't define break rules for some languages such as Thai.
-        let start_iter = iter.iter.clone();
-        let start_point = iter.current_pos_data;
-        let mut s = vec![left_codepoint as u16];
-        loop {
-            debug_assert!(!iter.is_eof());
-            s.push(iter.get_current_codepoint()? as u16);
-            iter.advance_iter();
-            if let Some(current_break_property) = iter.get_current_break_property() {
-                if current_break_property != iter.data.complex_property {
-                    break;
-                }
-            } else {
-                // EOF
+        handle_complex_language_utf16(iter, left_codepoint)
+    }
+}
+
+/// handle_complex_language impl for UTF16 iterators
+fn handle_complex_language_utf16<'l, 's, T>(
+    iter: &mut RuleBreakIterator<'l, 's, T>,
+    left_codepoint: T::CharType,
+) -> Option<usize>
+where
+    T: RuleBreakType<'l, 's, CharType = u32>,
+{
+    // word segmenter doesn't define break rules for some languages such as Thai.
+    let start_iter = iter.iter.clone();
+    let start_point = iter.current_pos_data;
+    let mut s = vec![left_codepoint as u16];
+    loop {
+        debug_assert!(!iter.is_eof());
+        s.push(iter.get_current_codepoint()? as u16);
+        iter.advance_iter();
+        if let Some(current_break_property) = iter.get_current_break_property() {
+            if current_break_property != iter.data.complex_property {
                 break;
             }
+        } else {
+            // EOF
+            break;
         }
+    }
 
-        // Restore iterator to move to head of complex string
-        iter.iter = start_iter;
-        iter.current_pos_data = start_point;
-        #[allow(clippy::unwrap_used)] // iter.complex present for word segmenter
-        let breaks = complex_language_segment_utf16(iter.complex.unwrap(), &s);
-        iter.result_cache = breaks;
-        // result_cache vector is utf-16 index that is in BMP.
-        let first_pos = *iter.result_cache.first()?;
-        let mut i = 1;
-        loop {
-            if i == first_pos {
-                // Re-calculate breaking offset
-                iter.result_cache = iter.result_cache.iter().skip(1).map(|r| r - i).collect();
-                return iter.get_current_position();
-            }
-            debug_assert!(
-                i < first_pos,
-                "we should always arrive at first_pos: near index {:?}",
-                iter.get_current_position()
-            );
-            i += 1;
-            iter.advance_iter();
-            if iter.is_eof() {
-                iter.result_cache.clear();
-                return Some(iter.len);
-            }
+    // Restore iterator to move to head of complex string
+    iter.iter = start_iter;
+    iter.current_pos_data = start_point;
+    #[allow(clippy::unwrap_used)] // iter.complex present for word segmenter
+    let breaks = complex_language_segment_utf16(iter.complex.unwrap(), &s);
+    iter.result_cache = breaks;
+    // result_cache vector is utf-16 index that is in BMP.
+    let first_pos = *iter.result_cache.first()?;
+    let mut i = 1;
+    loop {
+        if i == first_pos {
+            // Re-calculate breaking offset
+            iter.result_cache = iter.result_cache.iter().skip(1).map(|r| r - i).collect();
+            return iter.get_current_position();
+        }
+        debug_assert!(
+            i < first_pos,
+            "we should always arrive at first_pos: near index {:?}",
+            iter.get_current_position()
+        );
+        i += 1;
+        iter.advance_iter();
+        if iter.is_eof() {
+            iter.result_cache.clear();
+            return Some(iter.len);
         }
     }
 }
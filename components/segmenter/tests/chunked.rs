@@ -0,0 +1,102 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu::locid::locale;
+use icu_segmenter::GraphemeClusterSegmenter;
+use icu_segmenter::LineSegmenter;
+use icu_segmenter::SentenceSegmenter;
+use icu_segmenter::WordSegmenter;
+
+const TEXT: &str = "Mr. Smith paid $3.50 for 12,000.5 eggs!\r\nReally?  \
+    👨‍👩‍👧‍👦 🇺🇸🇫🇷 e\u{301}\u{302}. ภาษาไทยภาษาไทย ພາສາລາວ ကြည့်ပါ ខ្ញុំស្រលាញ់ \
+    こんにちは世界、カタカナ〜です。中文句子。 EU:n jäsen.";
+
+/// Splits `text` into chunks of `chunk_len` code units, plus empty chunks, without regard
+/// for character boundaries in the UTF-16 case.
+fn str_chunks(text: &str, chunk_len: usize) -> Vec<&str> {
+    let mut chunks = vec![""];
+    let mut start = 0;
+    while start < text.len() {
+        let mut end = (start + chunk_len).min(text.len());
+        while !text.is_char_boundary(end) {
+            end += 1;
+        }
+        chunks.push(&text[start..end]);
+        chunks.push("");
+        start = end;
+    }
+    chunks
+}
+
+fn utf16_chunks(text: &[u16], chunk_len: usize) -> Vec<&[u16]> {
+    let mut chunks: Vec<&[u16]> = text.chunks(chunk_len).collect();
+    chunks.insert(1.min(chunks.len()), &[]);
+    chunks
+}
+
+macro_rules! check_chunked {
+    ($segmenter:expr) => {{
+        let segmenter = &$segmenter;
+        let utf16: Vec<u16> = TEXT.encode_utf16().collect();
+        let expected_utf8: Vec<usize> = segmenter.segment_str(TEXT).collect();
+        let expected_utf16: Vec<usize> = segmenter.segment_utf16(&utf16).collect();
+        for chunk_len in [1, 2, 3, 5, 8, 13, 100] {
+            let chunks = str_chunks(TEXT, chunk_len);
+            let breakpoints: Vec<usize> = segmenter.segment_str_chunks(chunks).collect();
+            assert_eq!(breakpoints, expected_utf8, "UTF-8, chunks of {chunk_len}");
+
+            let chunks = utf16_chunks(&utf16, chunk_len);
+            let breakpoints: Vec<usize> = segmenter.segment_utf16_chunks(chunks).collect();
+            assert_eq!(breakpoints, expected_utf16, "UTF-16, chunks of {chunk_len}");
+        }
+
+        // Any cloneable iterator over the chunks works, not only slices.
+        let breakpoints: Vec<usize> = segmenter
+            .segment_str_chunks(TEXT.split_inclusive(' '))
+            .collect();
+        assert_eq!(breakpoints, expected_utf8, "UTF-8, split at spaces");
+
+        let breakpoints: Vec<usize> = segmenter.segment_str_chunks([]).collect();
+        assert_eq!(breakpoints, [0]);
+        let breakpoints: Vec<usize> = segmenter.segment_utf16_chunks([&[][..], &[]]).collect();
+        assert_eq!(breakpoints, [0]);
+    }};
+}
+
+#[test]
+fn grapheme_chunked() {
+    check_chunked!(GraphemeClusterSegmenter::new());
+}
+
+#[test]
+fn word_chunked() {
+    check_chunked!(WordSegmenter::new_auto());
+    check_chunked!(WordSegmenter::new_lstm());
+    check_chunked!(WordSegmenter::new_dictionary());
+    check_chunked!(WordSegmenter::try_new_auto_for_locale(&locale!("sv").into()).unwrap());
+
+    // Word types are also the same.
+    let segmenter = WordSegmenter::new_auto();
+    let chunks = str_chunks(TEXT, 3);
+    let mut expected = segmenter.segment_str(TEXT);
+    let mut iter = segmenter.segment_str_chunks(chunks);
+    while let Some(breakpoint) = expected.next() {
+        assert_eq!(iter.next(), Some(breakpoint));
+        assert_eq!(iter.word_type(), expected.word_type(), "{breakpoint}");
+    }
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn sentence_chunked() {
+    check_chunked!(SentenceSegmenter::new());
+    check_chunked!(SentenceSegmenter::try_new_with_suppressions(&locale!("en").into()).unwrap());
+}
+
+#[test]
+fn line_chunked() {
+    check_chunked!(LineSegmenter::new_auto());
+    check_chunked!(LineSegmenter::new_lstm());
+    check_chunked!(LineSegmenter::new_dictionary());
+}
@@ -0,0 +1,179 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu::locid::locale;
+use icu::locid_transform::fallback::LocaleFallbacker;
+use icu_provider::prelude::*;
+use icu_provider_adapters::fallback::LocaleFallbackProvider;
+use icu_segmenter::provider::*;
+use icu_segmenter::{Hyphenator, HyphenatorOptions, LineSegmenter};
+use zerovec::{ZeroMap, ZeroSlice};
+
+/// Parses TeX patterns such as `hy3ph` into letters and levels.
+fn parse_pattern(pattern: &str) -> (String, Vec<u8>) {
+    let mut letters = String::new();
+    let mut levels = vec![0];
+    for c in pattern.chars() {
+        match c.to_digit(10) {
+            Some(level) => *levels.last_mut().unwrap() = level as u8,
+            None => {
+                letters.push(c);
+                levels.push(0);
+            }
+        }
+    }
+    (letters, levels)
+}
+
+/// A provider with patterns for English and German only.
+struct TestProvider;
+
+impl DataProvider<HyphenationPatternsV1Marker> for TestProvider {
+    fn load(
+        &self,
+        req: DataRequest,
+    ) -> Result<DataResponse<HyphenationPatternsV1Marker>, DataError> {
+        let (patterns, exceptions, right_min): (&[&str], &[(&str, &[u8])], u8) = if req.locale
+            == &locale!("en").into()
+        {
+            // The examples from Liang's thesis and the TeXbook.
+            (
+                &[
+                    "hy3ph", "he2n", "hena4", "hen5at", "1na", "n2at", "1tio", "2io", "o2n", ".ta4",
+                ],
+                &[("table", &[2])],
+                3,
+            )
+        } else if req.locale == &locale!("de").into() {
+            (&["l1b", "n1t", "n1n"], &[], 2)
+        } else {
+            return Err(
+                DataErrorKind::MissingLocale.with_req(HyphenationPatternsV1Marker::KEY, req)
+            );
+        };
+        let patterns: Vec<_> = patterns.iter().copied().map(parse_pattern).collect();
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(HyphenationPatternsV1 {
+                patterns: patterns
+                    .iter()
+                    .map(|(letters, levels)| {
+                        (letters.as_str(), ZeroSlice::<u8>::from_ule_slice(levels))
+                    })
+                    .collect(),
+                exceptions: exceptions
+                    .iter()
+                    .map(|&(word, points)| (word, ZeroSlice::<u8>::from_ule_slice(points)))
+                    .collect::<ZeroMap<_, _>>(),
+                left_min: 2,
+                right_min,
+            })),
+        })
+    }
+}
+
+fn test_provider() -> LocaleFallbackProvider<TestProvider> {
+    LocaleFallbackProvider::new_with_fallbacker(
+        TestProvider,
+        LocaleFallbacker::new().static_to_owned(),
+    )
+}
+
+fn new_hyphenator(locale: &DataLocale, options: HyphenatorOptions) -> Hyphenator {
+    Hyphenator::try_new_unstable(&test_provider(), locale, options).unwrap()
+}
+
+#[test]
+fn hyphenate() {
+    let hyphenator = new_hyphenator(&locale!("en").into(), Default::default());
+    assert_eq!(hyphenator.hyphenate_str("hyphenation"), [2, 6]);
+    assert_eq!(hyphenator.hyphenate_str("HYPHENATION"), [2, 6]);
+    assert_eq!(
+        hyphenator.hyphenate_str("(hyphenation, hyphenation)"),
+        [3, 7, 16, 20]
+    );
+    assert_eq!(hyphenator.hyphenate_str("nation"), [2]);
+    // The exception takes precedence over `.ta4`.
+    assert_eq!(hyphenator.hyphenate_str("table"), [2]);
+    assert_eq!(hyphenator.hyphenate_str("Table tables"), [2]);
+    assert_eq!(hyphenator.hyphenate_str(""), Vec::<usize>::new());
+
+    let utf16: Vec<u16> = "😀 hyphenation".encode_utf16().collect();
+    assert_eq!(hyphenator.hyphenate_utf16(&utf16), [5, 9]);
+}
+
+#[test]
+fn hyphenate_with_fallback() {
+    let hyphenator = new_hyphenator(&locale!("en-US").into(), Default::default());
+    assert_eq!(hyphenator.hyphenate_str("hyphenation"), [2, 6]);
+
+    // Swiss German uses the German patterns.
+    let hyphenator = new_hyphenator(&locale!("de-CH").into(), Default::default());
+    assert_eq!(hyphenator.hyphenate_str("Silbentrennung"), [3, 6, 10]);
+    assert_eq!(hyphenator.hyphenate_str("hyphenation"), Vec::<usize>::new());
+
+    // There are no patterns for Dutch, nor for the root locale.
+    assert!(Hyphenator::try_new_unstable(
+        &test_provider(),
+        &locale!("nl-BE").into(),
+        Default::default()
+    )
+    .is_err());
+}
+
+#[test]
+fn hyphenate_with_options() {
+    let mut options = HyphenatorOptions::default();
+    options.left_min = Some(3);
+    let hyphenator = new_hyphenator(&locale!("en").into(), options);
+    assert_eq!(hyphenator.hyphenate_str("hyphenation nation"), [6]);
+    assert_eq!(hyphenator.hyphenate_str("table"), Vec::<usize>::new());
+
+    let mut options = HyphenatorOptions::default();
+    options.left_min = Some(1);
+    options.right_min = Some(5);
+    let hyphenator = new_hyphenator(&locale!("en").into(), options);
+    assert_eq!(hyphenator.hyphenate_str("hyphenation"), [2, 6]);
+    assert_eq!(hyphenator.hyphenate_str("nation"), Vec::<usize>::new());
+}
+
+#[test]
+fn hyphenate_line_breaks() {
+    let hyphenator = new_hyphenator(&locale!("en").into(), Default::default());
+    let segmenter = LineSegmenter::new_auto();
+
+    let text = "A hyphenation-nation table.";
+    let mut iter = hyphenator.hyphenate_line_breaks_str(text, segmenter.segment_str(text));
+    let mut breakpoints = Vec::new();
+    while let Some(breakpoint) = iter.next() {
+        breakpoints.push((breakpoint, iter.is_hyphenation_point()));
+    }
+    assert_eq!(
+        breakpoints,
+        [
+            (0, false),
+            (2, false),
+            (4, true),
+            (8, true),
+            (14, false),
+            (16, true),
+            (21, false),
+            (23, true),
+            (27, false),
+        ]
+    );
+
+    let utf16: Vec<u16> = text.encode_utf16().collect();
+    let mut iter = hyphenator.hyphenate_line_breaks_utf16(&utf16, segmenter.segment_utf16(&utf16));
+    let mut breakpoints_utf16 = Vec::new();
+    while let Some(breakpoint) = iter.next() {
+        breakpoints_utf16.push((breakpoint, iter.is_hyphenation_point()));
+    }
+    assert_eq!(breakpoints_utf16, breakpoints);
+
+    let breakpoints: Vec<usize> = hyphenator
+        .hyphenate_line_breaks_str("", segmenter.segment_str(""))
+        .collect();
+    assert_eq!(breakpoints, [0]);
+}
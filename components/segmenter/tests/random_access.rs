@@ -0,0 +1,182 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu::locid::locale;
+use icu_segmenter::GraphemeClusterSegmenter;
+use icu_segmenter::LineBreakOptions;
+use icu_segmenter::LineBreakStrictness;
+use icu_segmenter::LineBreakWordOption;
+use icu_segmenter::LineSegmenter;
+use icu_segmenter::SentenceSegmenter;
+use icu_segmenter::WordSegmenter;
+
+const SAMPLE: &str = "Mr. Smith (the one from the U.S.) paid $3.50 for 12,000.5 eggs! \
+    \"Really?\"  he asked.\r\nAnd   then... he left.  e.g. this isn't a break.\n\n\
+    Family: 👨‍👩‍👧‍👦 🇺🇸🇫🇷🇩🇪 ☝🏽 Ｈｅｌｌｏ 한국어 텍스트 नमस्ते e\u{301}\u{302}\u{303}.\u{2029}\
+    ภาษาไทยภาษาไทย ພາສາລາວ ကြည့်ပါ ខ្ញុំស្រលាញ់ こんにちは世界、カタカナ〜です。中文句子。\
+    Τι ώρα είναι; Είναι αργά. EU:n jäsen a:b 1-2—3 (ok)」「x」 http://example.com/a?b=c";
+
+/// Concatenates the test cases of a break test file into a single text, so that the test
+/// cases end up in unusual contexts.
+fn test_file_text(filename: &str, max_cases: usize) -> String {
+    std::fs::read_to_string(filename)
+        .unwrap()
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .take(max_cases)
+        .flat_map(|line| {
+            line.split('#')
+                .next()
+                .unwrap()
+                .split_ascii_whitespace()
+                .skip(1)
+                .step_by(2)
+                .map(|cp| char::from_u32(u32::from_str_radix(cp, 16).unwrap()).unwrap())
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+fn check(
+    len: usize,
+    boundaries: &[usize],
+    following: impl Fn(usize) -> Option<usize>,
+    preceding: impl Fn(usize) -> Option<usize>,
+    is_boundary: impl Fn(usize) -> bool,
+) {
+    for offset in 0..=len + 1 {
+        let i = boundaries.partition_point(|&b| b <= offset);
+        let expected_following = boundaries.get(i).copied();
+        let expected_preceding = boundaries
+            .get(..boundaries.partition_point(|&b| b < offset))
+            .and_then(|b| b.last())
+            .copied();
+        assert_eq!(following(offset), expected_following, "following({offset})");
+        assert_eq!(preceding(offset), expected_preceding, "preceding({offset})");
+        assert_eq!(
+            is_boundary(offset),
+            boundaries.binary_search(&offset).is_ok(),
+            "is_boundary({offset})"
+        );
+    }
+}
+
+macro_rules! check_random_access {
+    ($segmenter:expr, $text:expr) => {{
+        let segmenter = &$segmenter;
+        let text: &str = $text;
+        check(
+            text.len(),
+            &segmenter.segment_str(text).collect::<Vec<_>>(),
+            |offset| segmenter.following_str(text, offset),
+            |offset| segmenter.preceding_str(text, offset),
+            |offset| segmenter.is_boundary_str(text, offset),
+        );
+
+        // Include an unpaired surrogate, which is only possible in UTF-16.
+        let mut utf16: Vec<u16> = text.encode_utf16().collect();
+        utf16.insert(utf16.len() / 2, 0xd800);
+        check(
+            utf16.len(),
+            &segmenter.segment_utf16(&utf16).collect::<Vec<_>>(),
+            |offset| segmenter.following_utf16(&utf16, offset),
+            |offset| segmenter.preceding_utf16(&utf16, offset),
+            |offset| segmenter.is_boundary_utf16(&utf16, offset),
+        );
+    }};
+}
+
+#[test]
+fn grapheme_random_access() {
+    let segmenter = GraphemeClusterSegmenter::new();
+    check_random_access!(segmenter, SAMPLE);
+    check_random_access!(
+        segmenter,
+        &test_file_text("./tests/testdata/GraphemeBreakTest.txt", 1000)
+    );
+    check_random_access!(segmenter, "");
+}
+
+#[test]
+fn word_random_access() {
+    let text = test_file_text("./tests/testdata/WordBreakTest.txt", 1000);
+    for segmenter in [
+        WordSegmenter::new_auto(),
+        WordSegmenter::new_dictionary(),
+        WordSegmenter::try_new_auto_for_locale(&locale!("sv").into()).unwrap(),
+    ] {
+        check_random_access!(segmenter, SAMPLE);
+        check_random_access!(segmenter, &text);
+    }
+}
+
+#[test]
+fn sentence_random_access() {
+    let text = test_file_text("./tests/testdata/SentenceBreakTest.txt", 1000);
+    for segmenter in [
+        SentenceSegmenter::new(),
+        SentenceSegmenter::try_new_for_locale(&locale!("el").into()).unwrap(),
+        SentenceSegmenter::try_new_with_suppressions(&locale!("en").into()).unwrap(),
+    ] {
+        check_random_access!(segmenter, SAMPLE);
+        check_random_access!(segmenter, &text);
+    }
+
+    let mut segmenter = SentenceSegmenter::new();
+    segmenter.add_suppressions(["e.g.", "etc.", "Mr.", "U.S."]);
+    check_random_access!(segmenter, SAMPLE);
+    check_random_access!(
+        segmenter,
+        "See e.g.          Mr.\u{3000}\u{3000}Smith. Or etc. etc. etc.   X. e.g.\ne.g. Y."
+    );
+}
+
+#[test]
+fn line_random_access() {
+    let text = test_file_text("./tests/testdata/LineBreakTest.txt", 300);
+    for (strictness, word_option, ja_zh) in [
+        (
+            LineBreakStrictness::Strict,
+            LineBreakWordOption::Normal,
+            false,
+        ),
+        (
+            LineBreakStrictness::Normal,
+            LineBreakWordOption::Normal,
+            true,
+        ),
+        (
+            LineBreakStrictness::Loose,
+            LineBreakWordOption::Normal,
+            true,
+        ),
+        (
+            LineBreakStrictness::Anywhere,
+            LineBreakWordOption::Normal,
+            false,
+        ),
+        (
+            LineBreakStrictness::Strict,
+            LineBreakWordOption::BreakAll,
+            false,
+        ),
+        (
+            LineBreakStrictness::Loose,
+            LineBreakWordOption::KeepAll,
+            true,
+        ),
+    ] {
+        let mut options = LineBreakOptions::default();
+        options.strictness = strictness;
+        options.word_option = word_option;
+        options.ja_zh = ja_zh;
+        let segmenter = LineSegmenter::new_auto_with_options(options);
+        check_random_access!(segmenter, SAMPLE);
+        check_random_access!(segmenter, &text);
+    }
+
+    let segmenter = LineSegmenter::new_dictionary();
+    check_random_access!(segmenter, SAMPLE);
+    check_random_access!(segmenter, &text);
+}
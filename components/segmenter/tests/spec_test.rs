@@ -2,6 +2,7 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
+use icu::locid::locale;
 use icu_segmenter::GraphemeClusterSegmenter;
 use icu_segmenter::LineSegmenter;
 use icu_segmenter::SentenceSegmenter;
@@ -179,6 +180,24 @@ fn run_word_break_test() {
     }
 }
 
+#[test]
+fn word_break_colon_tailoring() {
+    // The locale-invariant rules follow UAX #29, where a colon is MidLetter.
+    let segmenter = WordSegmenter::new_dictionary();
+    let result: Vec<usize> = segmenter.segment_str("EU:n a\u{FF1A}b").collect();
+    assert_eq!(result, [0, 4, 5, 10]);
+
+    // The CLDR root rules do not keep words together across a colon.
+    let segmenter = WordSegmenter::try_new_dictionary_for_locale(&locale!("und").into()).unwrap();
+    let result: Vec<usize> = segmenter.segment_str("EU:n a\u{FF1A}b").collect();
+    assert_eq!(result, [0, 2, 3, 4, 5, 6, 9, 10]);
+
+    // Finnish uses colons to attach suffixes to abbreviations.
+    let segmenter = WordSegmenter::try_new_dictionary_for_locale(&locale!("fi").into()).unwrap();
+    let result: Vec<usize> = segmenter.segment_str("EU:n a\u{FF1A}b").collect();
+    assert_eq!(result, [0, 4, 5, 10]);
+}
+
 #[test]
 fn run_grapheme_break_test() {
     let test_iter = TestContentIterator::new("./tests/testdata/GraphemeBreakTest.txt");
@@ -9,3 +9,8 @@ pub use __impl_compactdecimal_long_v1 as impl_compactdecimal_long_v1;
 mod compactdecimal_short_v1;
 #[doc(inline)]
 pub use __impl_compactdecimal_short_v1 as impl_compactdecimal_short_v1;
+#[macro_use]
+#[path = "macros/compactdecimal_shortcurrency_v1.data.rs"]
+mod compactdecimal_shortcurrency_v1;
+#[doc(inline)]
+pub use __impl_compactdecimal_shortcurrency_v1 as impl_compactdecimal_shortcurrency_v1;
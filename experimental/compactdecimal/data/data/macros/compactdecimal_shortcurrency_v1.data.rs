@@ -0,0 +1,758 @@
+// @generated
+/// Implement `DataProvider<ShortCurrencyCompactDecimalFormatDataV1Marker>` on the given struct using the data
+/// hardcoded in this file. This allows the struct to be used with
+/// `icu`'s `_unstable` constructors.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_compactdecimal_shortcurrency_v1 {
+    ($ provider : path) => {
+        #[clippy::msrv = "1.66"]
+        impl icu_provider::DataProvider<icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker> for $provider {
+            fn load(&self, req: icu_provider::DataRequest) -> Result<icu_provider::DataResponse<icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker>, icu_provider::DataError> {
+                static SAT: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x04\x05\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0\x06\0\0\0\x07\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x07\0\0\0\0\0\x06\0 \0'\0A\0H\0O\0\0\x04\xC2\xA4\xC2\xA0\x03\x04\xC2\xA4\xC2\xA0\xC2\xA0\xE1\xB1\x9C\xE1\xB1\xAE\xE1\xB1\x9E\xE1\xB1\xA5\xE1\xB1\x9F\xE1\xB1\xAD\x03\x04\xC2\xA4\xC2\xA0K\x03\x04\xC2\xA4\xC2\xA0\xC2\xA0\xE1\xB1\x9C\xE1\xB1\xAE\xE1\xB1\x9E\xE1\xB1\xA5\xE1\xB1\x9F\xE1\xB1\xAD\x06\x04\xC2\xA4\xC2\xA0M\t\x04\xC2\xA4\xC2\xA0G\x0C\x04\xC2\xA4\xC2\xA0T") })
+                    },
+                };
+                static TO: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x04\x05\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0\x06\0\0\0\x07\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x07\0\0\0\0\0\x06\0\r\0\x14\0\x1B\0\"\0)\0\0\x04\xC2\xA4\xC2\xA0\x03\x04\xC2\xA4\xC2\xA0a\x04\x04\xC2\xA4\xC2\xA0m\x05\x04\xC2\xA4\xC2\xA0k\x06\x04\xC2\xA4\xC2\xA0M\t\x04\xC2\xA4\xC2\xA0P\x0C\x04\xC2\xA4\xC2\xA0T") })
+                    },
+                };
+                static SAT_U_NU_LATN: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x04\x05\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0\x06\0\0\0\x07\0\0\0\x08\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x01\x05\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x08\0\0\0\0\0\x06\0\x1E\08\0?\0Y\0`\0g\0\0\x04\xC2\xA4\xC2\xA0\x03\x02\xC2\xA4\xC2\xA0\xE1\xB1\x9C\xE1\xB1\xAE\xE1\xB1\x9E\xE1\xB1\xA5\xE1\xB1\x9F\xE1\xB1\xAD\x03\x04\xC2\xA4\xC2\xA0\xC2\xA0\xE1\xB1\x9C\xE1\xB1\xAE\xE1\xB1\x9E\xE1\xB1\xA5\xE1\xB1\x9F\xE1\xB1\xAD\x03\x04\xC2\xA4\xC2\xA0K\x03\x04\xC2\xA4\xC2\xA0\xC2\xA0\xE1\xB1\x9C\xE1\xB1\xAE\xE1\xB1\x9E\xE1\xB1\xA5\xE1\xB1\x9F\xE1\xB1\xAD\x06\x04\xC2\xA4\xC2\xA0M\t\x04\xC2\xA4\xC2\xA0G\x0C\x04\xC2\xA4\xC2\xA0T") })
+                    },
+                };
+                static MY: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x04\x05\x06\x07\x0B\x0C\r\x0E") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0\x06\0\0\0\x07\0\0\0\x08\0\0\0\t\0\0\0\n\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\n\0\0\0\0\0\x06\0\x1D\x007\0N\0b\0v\0\x9E\0\xC3\0\xE5\0\0\0\xC2\xA0\xC2\xA4\x03\x04\xC2\xA4\xC2\xA0\xC2\xA0\xE1\x80\x91\xE1\x80\xB1\xE1\x80\xAC\xE1\x80\x84\xE1\x80\xBA\x04\x04\xC2\xA4\xC2\xA0\xC2\xA0\xE1\x80\x9E\xE1\x80\xB1\xE1\x80\xAC\xE1\x80\x84\xE1\x80\xBA\xE1\x80\xB8\x05\x04\xC2\xA4\xC2\xA0\xC2\xA0\xE1\x80\x9E\xE1\x80\xAD\xE1\x80\x94\xE1\x80\xBA\xE1\x80\xB8\x06\x04\xC2\xA4\xC2\xA0\xC2\xA0\xE1\x80\x9E\xE1\x80\x94\xE1\x80\xBA\xE1\x80\xB8\x07\x04\xC2\xA4\xC2\xA0\xC2\xA0\xE1\x80\x80\xE1\x80\xAF\xE1\x80\x8B\xE1\x80\xB1\x0B\x12\xC2\xA4\xC2\xA0\xE1\x80\x80\xE1\x80\xAF\xE1\x80\x8B\xE1\x80\xB1\xC2\xA0\xC2\xA0\xE1\x80\x9E\xE1\x80\xB1\xE1\x80\xAC\xE1\x80\x84\xE1\x80\xBA\xE1\x80\xB8\x0C\x12\xC2\xA4\xC2\xA0\xE1\x80\x80\xE1\x80\xAF\xE1\x80\x8B\xE1\x80\xB1\xC2\xA0\xC2\xA0\xE1\x80\x9E\xE1\x80\xAD\xE1\x80\x94\xE1\x80\xBA\xE1\x80\xB8\r\x12\xC2\xA4\xC2\xA0\xE1\x80\x80\xE1\x80\xAF\xE1\x80\x8B\xE1\x80\xB1\xC2\xA0\xC2\xA0\xE1\x80\x9E\xE1\x80\x94\xE1\x80\xBA\xE1\x80\xB8\x0E\x04\xC2\xA4\xC2\xA0\xC2\xA0\xE1\x80\x80\xE1\x80\xB1\xE1\x80\xAC\xE1\x80\x8B\xE1\x80\xAD") })
+                    },
+                };
+                static RO: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x04\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0\x06\0\0\0\x07\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x01\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x07\0\0\0\0\0\x06\0\x11\0\x1C\0'\x003\0?\0\0\0\xC2\xA0\xC2\xA4\x03\0\xC2\xA0mie\xC2\xA0\xC2\xA4\x03\0\xC2\xA0mii\xC2\xA0\xC2\xA4\x03\0\xC2\xA0mii\xC2\xA0\xC2\xA4\x06\0\xC2\xA0mil.\xC2\xA0\xC2\xA4\t\0\xC2\xA0mld.\xC2\xA0\xC2\xA4\x0C\0\xC2\xA0tril.\xC2\xA0\xC2\xA4") })
+                    },
+                };
+                static YUE: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x04\x08\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x04\0\x0B\0\x12\0\x19\0\0\x02\xC2\xA4\x03\x02\xC2\xA4\xE5\x8D\x83\x04\x02\xC2\xA4\xE8\x90\xAC\x08\x02\xC2\xA4\xE5\x84\x84\x0C\x02\xC2\xA4\xE5\x85\x86") })
+                    },
+                };
+                static KO: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x04\x08\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x04\0\x0B\0\x12\0\x19\0\0\x02\xC2\xA4\x03\x02\xC2\xA4\xEC\xB2\x9C\x04\x02\xC2\xA4\xEB\xA7\x8C\x08\x02\xC2\xA4\xEC\x96\xB5\x0C\x02\xC2\xA4\xEC\xA1\xB0") })
+                    },
+                };
+                static MN: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x05\x06\t\n\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0\x06\0\0\0\x07\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x07\0\0\0\0\0\x06\0\x18\0(\x004\0F\0Z\0\0\x04\xC2\xA4\xC2\xA0\x03\x04\xC2\xA4\xC2\xA0\xC2\xA0\xD0\xBC\xD1\x8F\xD0\xBD\xD0\xB3\xD0\xB0\x03\x02\xC2\xA4\xC2\xA0\xD0\xBC\xD1\x8F\xD0\xBD\xD0\xB3\xD0\xB0\x06\x02\xC2\xA4\xC2\xA0\xD1\x81\xD0\xB0\xD1\x8F\t\x02\xC2\xA4\xC2\xA0\xD1\x82\xD1\x8D\xD1\x80\xD0\xB1\xD1\x83\xD0\xBC\t\x04\xC2\xA4\xC2\xA0\xC2\xA0\xD1\x82\xD1\x8D\xD1\x80\xD0\xB1\xD1\x83\xD0\xBC\x0C\x04\xC2\xA4\xC2\xA0\xC2\xA0\xD0\xB8\xD1\x85\xC2\xA0\xD0\xBD\xD0\xB0\xD1\x8F\xD0\xB4") })
+                    },
+                };
+                static LO: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x05\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0\x06\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x04\0\x13\0\"\x004\0C\0\0\x02\xC2\xA4\x03\x02\xC2\xA4\xC2\xA0\xE0\xBA\x9E\xE0\xBA\xB1\xE0\xBA\x99\x03\x02\xC2\xA4\xC2\xA0\xE0\xBA\x81\xE0\xBA\xB5\xE0\xBA\x9A\x06\x02\xC2\xA4\xC2\xA0\xE0\xBA\xA5\xE0\xBB\x89\xE0\xBA\xB2\xE0\xBA\x99\t\x02\xC2\xA4\xC2\xA0\xE0\xBA\x95\xE0\xBA\xB7\xE0\xBB\x89\x0C\x02\xC2\xA4\xC2\xA0\xE0\xBA\xA5\xE0\xBB\x89\xE0\xBA\xB2\xE0\xBA\x99\xE0\xBA\xA5\xE0\xBB\x89\xE0\xBA\xB2\xE0\xBA\x99") })
+                    },
+                };
+                static BRX: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x05\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0\x06\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x06\0\r\0\x12\0\x17\0\x1C\0\0\x04\xC2\xA4\xC2\xA0\x03\x04\xC2\xA4\xC2\xA0K\x03\x02\xC2\xA4K\x06\x02\xC2\xA4M\t\x02\xC2\xA4B\x0C\x02\xC2\xA4T") })
+                    },
+                };
+                static SW: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x05\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0\x06\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x06\0\x10\0\x1A\0!\0(\0\0\x04\xC2\xA4\xC2\xA0\x03\x08\xC2\xA4\xC2\xA0elfu\x03\x08\xC2\xA4\xC2\xA0laki\x06\x04\xC2\xA4\xC2\xA0M\t\x04\xC2\xA4\xC2\xA0B\x0C\x04\xC2\xA4\xC2\xA0T") })
+                    },
+                };
+                static SW_KE: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x05\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0\x06\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x06\0\x10\0\x1A\0!\0(\0\0\x04\xC2\xA4\xC2\xA0\x03\x08\xC2\xA4\xC2\xA0elfu\x03\x08\xC2\xA4\xC2\xA0laki\x06\x05\xC2\xA4\xC2\xA0M\t\x05\xC2\xA4\xC2\xA0B\x0C\x05\xC2\xA4\xC2\xA0T") })
+                    },
+                };
+                static AS: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x05\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0\x06\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x06\0\x1D\0.\0E\0a\0\0\x04\xC2\xA4\xC2\xA0\x03\x04\xC2\xA4\xC2\xA0\xC2\xA0\xE0\xA6\xB9\xE0\xA6\xBE\xE0\xA6\x9C\xE0\xA6\xBE\xE0\xA7\xB0\x03\x04\xC2\xA4\xC2\xA0\xC2\xA0\xE0\xA6\xB2\xE0\xA6\xBE\xE0\xA6\x96\x06\x04\xC2\xA4\xC2\xA0\xC2\xA0\xE0\xA6\xA8\xE0\xA6\xBF\xE0\xA6\xAF\xE0\xA7\x81\xE0\xA6\xA4\t\x04\xC2\xA4\xC2\xA0\xC2\xA0\xE0\xA6\xB6\xE0\xA6\xA4\xC2\xA0\xE0\xA6\x95\xE0\xA7\x8B\xE0\xA6\x9F\xE0\xA6\xBF\x0C\x04\xC2\xA4\xC2\xA0\xC2\xA0\xE0\xA6\xB6\xE0\xA6\xA4\xC2\xA0\xE0\xA6\xAA\xE0\xA7\xB0\xE0\xA6\xBE\xE0\xA7\xB0\xE0\xA7\x8D\xE0\xA6\xA6\xE0\xA7\x8D\xE0\xA6\xA7") })
+                    },
+                };
+                static HA: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x05\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x04\0\0\0\x05\0\0\0\x06\0\0\0\x07\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x01\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x07\0\0\0\0\0\x06\0\r\0\x14\0\x1B\0\"\0'\0\0\x04\xC2\xA4\xC2\xA0\x03\x04\xC2\xA4\xC2\xA0D\x03\x04\xC2\xA4\xC2\xA0K\x03\x04\xC2\xA4\xC2\xA0D\x06\x04\xC2\xA4\xC2\xA0M\t\x02\xC2\xA4B\x0C\x02\xC2\xA4T") })
+                    },
+                };
+                static SD: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x05\x06\x07\t\x0B\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0\x06\0\0\0\x07\0\0\0\x08\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x08\0\0\0\0\0\x06\0\x12\0\x1A\0\"\0.\08\0B\0\0\0\xC2\xA0\xC2\xA4\x03\x02\xC2\xA4\xD9\x87\xD8\xB2\xD8\xA7\xD8\xB1\x03\x02\xC2\xA4\xD9\x84\xDA\xA9\x05\x02\xC2\xA4\xD9\x84\xDA\xA9\x07\x02\xC2\xA4\xDA\xAA\xD8\xB1\xD9\x88\xDA\x99\t\x02\xC2\xA4\xD8\xA7\xD8\xB1\xD8\xA8\x0B\x02\xC2\xA4\xDA\xA9\xD8\xB1\xD8\xA8\x0C\x02\xC2\xA4\xD9\xBD\xD8\xB1\xD9\x84\xD9\x8A\xD9\x86") })
+                    },
+                };
+                static SD_U_NU_LATN: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x05\x06\x07\t\x0B\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0\x06\0\0\0\x07\0\0\0\x08\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x08\0\0\0\0\0\x06\0\x12\0\x1A\0\"\0.\08\0B\0\0\x04\xC2\xA4\xC2\xA0\x03\x02\xC2\xA4\xD9\x87\xD8\xB2\xD8\xA7\xD8\xB1\x03\x02\xC2\xA4\xD9\x84\xDA\xA9\x05\x02\xC2\xA4\xD9\x84\xDA\xA9\x07\x02\xC2\xA4\xDA\xAA\xD8\xB1\xD9\x88\xDA\x99\t\x02\xC2\xA4\xD8\xA7\xD8\xB1\xD8\xA8\x0B\x02\xC2\xA4\xDA\xA9\xD8\xB1\xD8\xA8\x0C\x02\xC2\xA4\xD9\xBD\xD8\xB1\xD9\x84\xD9\x8A\xD9\x86") })
+                    },
+                };
+                static EN_IN: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x05\x07\n\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0\x06\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x04\0\t\0\x0E\0\x14\0\x1B\0\0\x02\xC2\xA4\x03\x02\xC2\xA4T\x05\x02\xC2\xA4L\x07\x02\xC2\xA4Cr\n\x02\xC2\xA4TCr\x0C\x02\xC2\xA4LCr") })
+                    },
+                };
+                static MR: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x05\x07\t\x0B\r") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0\x06\0\0\0\x07\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x07\0\0\0\0\0\x04\0\r\0\x1C\0.\0@\0R\0\0\x02\xC2\xA4\x03\x02\xC2\xA4\xC2\xA0\xE0\xA4\xB9\x05\x02\xC2\xA4\xC2\xA0\xE0\xA4\xB2\xE0\xA4\xBE\xE0\xA4\x96\x07\x02\xC2\xA4\xC2\xA0\xE0\xA4\x95\xE0\xA5\x8B\xE0\xA4\x9F\xE0\xA5\x80\t\x02\xC2\xA4\xC2\xA0\xE0\xA4\x85\xE0\xA4\xAC\xE0\xA5\x8D\xE0\xA4\x9C\x0B\x02\xC2\xA4\xC2\xA0\xE0\xA4\x96\xE0\xA4\xB0\xE0\xA5\x8D\xE0\xA4\xB5\r\x02\xC2\xA4\xC2\xA0\xE0\xA4\xAA\xE0\xA4\xA6\xE0\xA5\x8D\xE0\xA4\xAE") })
+                    },
+                };
+                static HI: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x05\x07\t\x0B\r") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0\x06\0\0\0\x07\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x07\0\0\0\0\0\x04\0\x19\0(\x004\0@\0L\0\0\x02\xC2\xA4\x03\x02\xC2\xA4\xC2\xA0\xE0\xA4\xB9\xE0\xA4\x9C\xE0\xA4\xBC\xE0\xA4\xBE\xE0\xA4\xB0\x05\x02\xC2\xA4\xC2\xA0\xE0\xA4\xB2\xE0\xA4\xBE\xE0\xA4\x96\x07\x02\xC2\xA4\xC2\xA0\xE0\xA4\x95\xE0\xA5\xB0\t\x02\xC2\xA4\xC2\xA0\xE0\xA4\x85\xE0\xA5\xB0\x0B\x02\xC2\xA4\xC2\xA0\xE0\xA4\x96\xE0\xA5\xB0\r\x02\xC2\xA4\xC2\xA0\xE0\xA4\xA8\xE0\xA5\x80\xE0\xA4\xB2") })
+                    },
+                };
+                static PA: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x05\x07\t\x0B\r") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0\x06\0\0\0\x07\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x07\0\0\0\0\0\x04\0\x1B\0,\0@\0Q\0b\0\0\x02\xC2\xA4\x03\x04\xC2\xA4\xC2\xA0\xC2\xA0\xE0\xA8\xB9\xE0\xA8\x9C\xE0\xA8\xBC\xE0\xA8\xBE\xE0\xA8\xB0\x05\x04\xC2\xA4\xC2\xA0\xC2\xA0\xE0\xA8\xB2\xE0\xA9\xB1\xE0\xA8\x96\x07\x04\xC2\xA4\xC2\xA0\xC2\xA0\xE0\xA8\x95\xE0\xA8\xB0\xE0\xA9\x8B\xE0\xA9\x9C\t\x04\xC2\xA4\xC2\xA0\xC2\xA0\xE0\xA8\x85\xE0\xA8\xB0\xE0\xA8\xAC\x0B\x04\xC2\xA4\xC2\xA0\xC2\xA0\xE0\xA8\x96\xE0\xA8\xB0\xE0\xA8\xAC\r\x04\xC2\xA4\xC2\xA0\xC2\xA0\xE0\xA8\xA8\xE0\xA9\x80\xE0\xA8\xB2") })
+                    },
+                };
+                static NE: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x05\x07\t\x0B\r") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0\x06\0\0\0\x07\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x07\0\0\0\0\0\x06\0\x1A\0+\0?\0P\0a\0\0\x04\xC2\xA4\xC2\xA0\x03\x04\xC2\xA4\xC2\xA0\xC2\xA0\xE0\xA4\xB9\xE0\xA4\x9C\xE0\xA4\xBE\xE0\xA4\xB0\x05\x04\xC2\xA4\xC2\xA0\xC2\xA0\xE0\xA4\xB2\xE0\xA4\xBE\xE0\xA4\x96\x07\x04\xC2\xA4\xC2\xA0\xC2\xA0\xE0\xA4\x95\xE0\xA4\xB0\xE0\xA5\x8B\xE0\xA4\xA1\t\x04\xC2\xA4\xC2\xA0\xC2\xA0\xE0\xA4\x85\xE0\xA4\xB0\xE0\xA4\xAC\x0B\x04\xC2\xA4\xC2\xA0\xC2\xA0\xE0\xA4\x96\xE0\xA4\xB0\xE0\xA4\xAC\r\x04\xC2\xA4\xC2\xA0\xC2\xA0\xE0\xA4\xB6\xE0\xA4\x82\xE0\xA4\x96") })
+                    },
+                };
+                static UR: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x05\x07\t\x0B\x0C\r") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0\x06\0\0\0\x07\0\0\0\x08\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x08\0\0\0\0\0\x04\0\x14\0$\x004\0B\0R\0d\0\0\x02\xC2\xA4\x03\x04\xC2\xA4\xC2\xA0\xC2\xA0\xDB\x81\xD8\xB2\xD8\xA7\xD8\xB1\x05\x04\xC2\xA4\xC2\xA0\xC2\xA0\xD9\x84\xD8\xA7\xDA\xA9\xDA\xBE\x07\x04\xC2\xA4\xC2\xA0\xC2\xA0\xDA\xA9\xD8\xB1\xD9\x88\xDA\x91\t\x04\xC2\xA4\xC2\xA0\xC2\xA0\xD8\xA7\xD8\xB1\xD8\xA8\x0B\x04\xC2\xA4\xC2\xA0\xC2\xA0\xDA\xA9\xDA\xBE\xD8\xB1\xD8\xA8\x0C\x02\xC2\xA4\xC2\xA0\xD9\xB9\xD8\xB1\xDB\x8C\xD9\x84\xDB\x8C\xD9\x86\x0C\x04\xC2\xA4\xC2\xA0\xC2\xA0\xD9\xB9\xD8\xB1\xDB\x8C\xD9\x84\xDB\x8C\xD9\x86") })
+                    },
+                };
+                static UR_IN: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x05\x07\t\x0B\x0C\r") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0\x06\0\0\0\x07\0\0\0\x08\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x08\0\0\0\0\0\x06\0\x16\0&\x006\0D\0T\0f\0\0\x04\xC2\xA4\xC2\xA0\x03\x04\xC2\xA4\xC2\xA0\xC2\xA0\xDB\x81\xD8\xB2\xD8\xA7\xD8\xB1\x05\x04\xC2\xA4\xC2\xA0\xC2\xA0\xD9\x84\xD8\xA7\xDA\xA9\xDA\xBE\x07\x04\xC2\xA4\xC2\xA0\xC2\xA0\xDA\xA9\xD8\xB1\xD9\x88\xDA\x91\t\x04\xC2\xA4\xC2\xA0\xC2\xA0\xD8\xA7\xD8\xB1\xD8\xA8\x0B\x04\xC2\xA4\xC2\xA0\xC2\xA0\xDA\xA9\xDA\xBE\xD8\xB1\xD8\xA8\x0C\x02\xC2\xA4\xC2\xA0\xD9\xB9\xD8\xB1\xDB\x8C\xD9\x84\xDB\x8C\xD9\x86\x0C\x04\xC2\xA4\xC2\xA0\xC2\xA0\xD9\xB9\xD8\xB1\xDB\x8C\xD9\x84\xDB\x8C\xD9\x86") })
+                    },
+                };
+                static GU: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x05\x07\t\x0B\x0C\r\x0E") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0\x06\0\0\0\x07\0\0\0\x08\0\0\0\t\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\t\0\0\0\0\0\x04\0\x16\0%\x007\0F\0^\0y\0\x8B\0\0\x02\xC2\xA4\x03\x02\xC2\xA4\xC2\xA0\xE0\xAA\xB9\xE0\xAA\x9C\xE0\xAA\xBE\xE0\xAA\xB0\x05\x02\xC2\xA4\xC2\xA0\xE0\xAA\xB2\xE0\xAA\xBE\xE0\xAA\x96\x07\x02\xC2\xA4\xC2\xA0\xE0\xAA\x95\xE0\xAA\xB0\xE0\xAB\x8B\xE0\xAA\xA1\t\x02\xC2\xA4\xC2\xA0\xE0\xAA\x85\xE0\xAA\xAC\xE0\xAA\x9C\x0B\x02\xC2\xA4\xC2\xA0\xE0\xAA\xA8\xE0\xAA\xBF\xE0\xAA\x96\xE0\xAA\xB0\xE0\xAB\x8D\xE0\xAA\xB5\x0C\x02\xC2\xA4\xC2\xA0\xE0\xAA\xAE\xE0\xAA\xB9\xE0\xAA\xBE\xE0\xAA\xAA\xE0\xAA\xA6\xE0\xAB\x8D\xE0\xAA\xAE\r\x02\xC2\xA4\xC2\xA0\xE0\xAA\xB6\xE0\xAA\x82\xE0\xAA\x95\xE0\xAB\x81\x0E\x02\xC2\xA4\xC2\xA0\xE0\xAA\x9C\xE0\xAA\xB2\xE0\xAA\xA7\xE0\xAA\xBF") })
+                    },
+                };
+                static BN: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x05\x07\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x04\0\x10\0\x1C\0(\0\0\0\xC2\xA4\x03\0\xC2\xA0\xE0\xA6\xB9\xE0\xA6\xBE\xC2\xA4\x05\0\xC2\xA0\xE0\xA6\xB2\xE0\xA6\xBE\xC2\xA4\x07\0\xC2\xA0\xE0\xA6\x95\xE0\xA7\x8B\xC2\xA4\x0C\0\xC2\xA0\xE0\xA6\xB2\xE0\xA6\xBE.\xE0\xA6\x95\xE0\xA7\x8B.\xC2\xA4") })
+                    },
+                };
+                static BN_IN: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x05\x07\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x04\0\x10\0\x1C\0(\0\0\x02\xC2\xA4\x03\0\xC2\xA0\xE0\xA6\xB9\xE0\xA6\xBE\xC2\xA4\x05\0\xC2\xA0\xE0\xA6\xB2\xE0\xA6\xBE\xC2\xA4\x07\0\xC2\xA0\xE0\xA6\x95\xE0\xA7\x8B\xC2\xA4\x0C\0\xC2\xA0\xE0\xA6\xB2\xE0\xA6\xBE.\xE0\xA6\x95\xE0\xA7\x8B.\xC2\xA4") })
+                    },
+                };
+                static ES_GT: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\n\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x04\0\t\0\x0E\0\x15\0\0\x02\xC2\xA4\x03\x02\xC2\xA4K\x06\x02\xC2\xA4M\t\x02\xC2\xA4MRD\x0C\x02\xC2\xA4\xC2\xA0B") })
+                    },
+                };
+                static ES_US: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\n\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x04\0\x0B\0\x12\0\x19\0\0\x02\xC2\xA4\x03\x02\xC2\xA4\xC2\xA0K\x06\x02\xC2\xA4\xC2\xA0M\t\x02\xC2\xA4\xC2\xA0B\x0C\x02\xC2\xA4\xC2\xA0T") })
+                    },
+                };
+                static ES_419: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\n\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x04\0\x0B\0\x12\0\x1B\0\0\x02\xC2\xA4\x03\x02\xC2\xA4\xC2\xA0K\x06\x02\xC2\xA4\xC2\xA0M\t\x02\xC2\xA4\xC2\xA0MRD\x0C\x02\xC2\xA4\xC2\xA0B") })
+                    },
+                };
+                static ES_MX: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\n\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x04\0\x0B\0\x12\0\x1D\0\0\x02\xC2\xA4\x03\0\xC2\xA0k\xC2\xA4\x06\0\xC2\xA0M\xC2\xA4\t\0\xC2\xA0MRD\xC2\xA0\xC2\xA4\x0C\0\xC2\xA0B\xC2\xA4") })
+                    },
+                };
+                static ES_GQ: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\n\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x04\0\x0F\0\x16\0\"\0\0\x02\xC2\xA4\x03\0\xC2\xA0mil\xC2\xA0\xC2\xA4\x06\0\xC2\xA0M\xC2\xA4\t\0\xC2\xA0mil\xC2\xA0M\xC2\xA4\x0C\0\xC2\xA0B\xC2\xA4") })
+                    },
+                };
+                static CA: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\n\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\r\0\x14\0\x1C\0\0\0\xC2\xA0\xC2\xA4\x03\0\xC2\xA0k\xC2\xA4\x06\0\xC2\xA0M\xC2\xA4\t\0\xC2\xA0kM\xC2\xA4\x0C\0\xC2\xA0B\xC2\xA4") })
+                    },
+                };
+                static ES_AR: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\n\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\r\0\x14\0\x1D\0\0\x04\xC2\xA4\xC2\xA0\x03\x02\xC2\xA4\xC2\xA0K\x06\x02\xC2\xA4\xC2\xA0M\t\x02\xC2\xA4\xC2\xA0MRD\x0C\x02\xC2\xA4\xC2\xA0B") })
+                    },
+                };
+                static ES_PE: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\n\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\x0F\0\x18\0#\0\0\x04\xC2\xA4\xC2\xA0\x03\x04\xC2\xA4\xC2\xA0\xC2\xA0K\x06\x04\xC2\xA4\xC2\xA0\xC2\xA0M\t\x04\xC2\xA4\xC2\xA0\xC2\xA0MRD\x0C\x04\xC2\xA4\xC2\xA0\xC2\xA0B") })
+                    },
+                };
+                static ES: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\n\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\x11\0\x18\0$\0\0\0\xC2\xA0\xC2\xA4\x03\0\xC2\xA0mil\xC2\xA0\xC2\xA4\x06\0\xC2\xA0M\xC2\xA4\t\0\xC2\xA0mil\xC2\xA0M\xC2\xA4\x0C\0\xC2\xA0B\xC2\xA4") })
+                    },
+                };
+                static TA_U_NU_TAMLDEC: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\n\x0B\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0\x06\0\0\0\x07\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x07\0\0\0\0\0\x04\0\r\0\x19\0#\0/\09\0\0\x02\xC2\xA4\x03\x04\xC2\xA4\xC2\xA0\xE0\xAE\x86\x06\x04\xC2\xA4\xC2\xA0\xE0\xAE\xAE\xE0\xAE\xBF\t\x02\xC2\xA4\xE0\xAE\xAA\xE0\xAE\xBF\t\x04\xC2\xA4\xC2\xA0\xE0\xAE\xAA\xE0\xAE\xBF\t\x02\xC2\xA4\xE0\xAE\xAA\xE0\xAE\xBF\x0C\x04\xC2\xA4\xC2\xA0\xE0\xAE\x9F\xE0\xAE\xBF") })
+                    },
+                };
+                static PS_U_NU_LATN: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\n\x0B\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x06\0\0\0\x07\0\0\0\x08\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x01\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x08\0\0\0\0\0\x06\0\r\0\x14\0\x1B\0\"\0'\0,\0\0\x04\xC2\xA4\xC2\xA0\x03\0K\xC2\xA0\xC2\xA4\x06\0M\xC2\xA0\xC2\xA4\t\0G\xC2\xA0\xC2\xA4\t\0G\xC2\xA0\xC2\xA4\t\x02\xC2\xA4B\t\x02\xC2\xA4B\x0C\0T\xC2\xA0\xC2\xA4") })
+                    },
+                };
+                static TA: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\n\x0B\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x05\0\0\0\x06\0\0\0\x08\0\0\0\t\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x01\x05\x05\x01\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\t\0\0\0\0\0\x04\0\r\0\x19\0%\0/\0;\0G\0Q\0\0\x02\xC2\xA4\x03\x04\xC2\xA4\xC2\xA0\xE0\xAE\x86\x06\x04\xC2\xA4\xC2\xA0\xE0\xAE\xAE\xE0\xAE\xBF\t\x04\xC2\xA4\xC2\xA0\xE0\xAE\xAA\xE0\xAE\xBF\t\x02\xC2\xA4\xE0\xAE\xAA\xE0\xAE\xBF\t\x04\xC2\xA4\xC2\xA0\xE0\xAE\xAA\xE0\xAE\xBF\t\x04\xC2\xA4\xC2\xA0\xE0\xAE\xAA\xE0\xAE\xBF\t\x02\xC2\xA4\xE0\xAE\xAA\xE0\xAE\xBF\x0C\x04\xC2\xA4\xC2\xA0\xE0\xAE\x9F\xE0\xAE\xBF") })
+                    },
+                };
+                static TA_MY: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\n\x0B\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x05\0\0\0\x06\0\0\0\x08\0\0\0\t\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x01\x05\x05\x01\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\t\0\0\0\0\0\x06\0\x0F\0\x1B\0'\x001\0=\0I\0S\0\0\x04\xC2\xA4\xC2\xA0\x03\x04\xC2\xA4\xC2\xA0\xE0\xAE\x86\x06\x04\xC2\xA4\xC2\xA0\xE0\xAE\xAE\xE0\xAE\xBF\t\x04\xC2\xA4\xC2\xA0\xE0\xAE\xAA\xE0\xAE\xBF\t\x02\xC2\xA4\xE0\xAE\xAA\xE0\xAE\xBF\t\x04\xC2\xA4\xC2\xA0\xE0\xAE\xAA\xE0\xAE\xBF\t\x04\xC2\xA4\xC2\xA0\xE0\xAE\xAA\xE0\xAE\xBF\t\x02\xC2\xA4\xE0\xAE\xAA\xE0\xAE\xBF\x0C\x04\xC2\xA4\xC2\xA0\xE0\xAE\x9F\xE0\xAE\xBF") })
+                    },
+                };
+                static PS: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\n\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0\x06\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x06\0\r\0\x14\0\x1B\0 \0\0\x04\xC2\xA4\xC2\xA0\x03\0K\xC2\xA0\xC2\xA4\x06\0M\xC2\xA0\xC2\xA4\t\0G\xC2\xA0\xC2\xA4\t\x02\xC2\xA4B\x0C\0T\xC2\xA0\xC2\xA4") })
+                    },
+                };
+                static KA: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0B\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0\x06\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x06\0\0\0\0\0\x06\0\x15\0'\0<\0N\0\0\0\xC2\xA0\xC2\xA4\x03\0\xC2\xA0\xE1\x83\x90\xE1\x83\x97.\xC2\xA0\xC2\xA4\x06\0\xC2\xA0\xE1\x83\x9B\xE1\x83\x9A\xE1\x83\x9C.\xC2\xA0\xC2\xA4\t\0\xC2\xA0\xE1\x83\x9B\xE1\x83\x9A\xE1\x83\xA0\xE1\x83\x93.\xC2\xA0\xC2\xA4\t\0\xC2\xA0\xE1\x83\x9B\xE1\x83\x9A\xE1\x83\xA0.\xC2\xA0\xC2\xA4\x0C\0\xC2\xA0\xE1\x83\xA2\xE1\x83\xA0\xE1\x83\x9A.\xC2\xA0\xC2\xA4") })
+                    },
+                };
+                static AR: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\t\0\x17\0)\0;\0\0\x03\xE2\x80\x8F\xC2\xA0\xC2\xA4\x03\0\xC2\xA0\xD8\xA3\xD9\x84\xD9\x81\xC2\xA0\xC2\xA4\x06\0\xC2\xA0\xD9\x85\xD9\x84\xD9\x8A\xD9\x88\xD9\x86\xC2\xA0\xC2\xA4\t\0\xC2\xA0\xD9\x85\xD9\x84\xD9\x8A\xD8\xA7\xD8\xB1\xC2\xA0\xC2\xA4\x0C\0\xC2\xA0\xD8\xAA\xD8\xB1\xD9\x84\xD9\x8A\xD9\x88\xD9\x86\xC2\xA0\xC2\xA4") })
+                    },
+                };
+                static FA_U_NU_LATN: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\t\0\x19\0-\0C\0\0\x07\xE2\x80\x8E\xC2\xA4\xC2\xA0\x03\0\xC2\xA0\xD9\x87\xD8\xB2\xD8\xA7\xD8\xB1\xC2\xA0\xC2\xA4\x06\0\xC2\xA0\xD9\x85\xDB\x8C\xD9\x84\xDB\x8C\xD9\x88\xD9\x86\xC2\xA0\xC2\xA4\t\0\xC2\xA0\xD9\x85\xDB\x8C\xD9\x84\xDB\x8C\xD8\xA7\xD8\xB1\xD8\xAF\xC2\xA0\xC2\xA4\x0C\0\xC2\xA0\xD9\x87\xD8\xB2\xD8\xA7\xD8\xB1\xD9\x85\xDB\x8C\xD9\x84\xDB\x8C\xD8\xA7\xD8\xB1\xD8\xAF\xC2\xA0\xC2\xA4") })
+                    },
+                };
+                static AM: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x04\0\r\0\x16\0\x1F\0\0\x02\xC2\xA4\x03\x02\xC2\xA4\xC2\xA0\xE1\x88\xBA\x06\x02\xC2\xA4\xC2\xA0\xE1\x88\x9A\t\x02\xC2\xA4\xC2\xA0\xE1\x89\xA2\x0C\x02\xC2\xA4\xC2\xA0\xE1\x89\xB5") })
+                    },
+                };
+                static TI: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x04\0\r\0\x16\0\x1F\0\0\x02\xC2\xA4\x03\x02\xC2\xA4\xC2\xA0\xE1\x88\xBD\x06\x02\xC2\xA4\xC2\xA0\xE1\x88\x9A\t\x02\xC2\xA4\xC2\xA0\xE1\x89\xA2\x0C\x02\xC2\xA4\xC2\xA0\xE1\x89\xB5") })
+                    },
+                };
+                static TR: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x04\0\r\0\x17\0!\0\0\x02\xC2\xA4\x03\0\xC2\xA0B\xC2\xA0\xC2\xA4\x06\0\xC2\xA0Mn\xC2\xA0\xC2\xA4\t\0\xC2\xA0Mr\xC2\xA0\xC2\xA4\x0C\0\xC2\xA0Tn\xC2\xA0\xC2\xA4") })
+                    },
+                };
+                static MS: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x04\0\t\0\x0E\0\x13\0\0\x02\xC2\xA4\x03\x02\xC2\xA4K\x06\x02\xC2\xA4J\t\x02\xC2\xA4B\x0C\x02\xC2\xA4T") })
+                    },
+                };
+                static CHR: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x04\0\t\0\x0E\0\x13\0\0\x02\xC2\xA4\x03\x02\xC2\xA4K\x06\x02\xC2\xA4M\t\x02\xC2\xA4B\x0C\x02\xC2\xA4T") })
+                    },
+                };
+                static IG: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x04\0\t\0\x0E\0\x13\0\0\x02\xC2\xA4\x03\x02\xC2\xA4K\x06\x02\xC2\xA4M\t\x02\xC2\xA4G\x0C\x02\xC2\xA4T") })
+                    },
+                };
+                static GA: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x04\0\t\0\x0E\0\x13\0\0\x02\xC2\xA4\x03\x02\xC2\xA4k\x06\x02\xC2\xA4M\t\x02\xC2\xA4B\x0C\x02\xC2\xA4T") })
+                    },
+                };
+                static BHO: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x04\0\x0B\0\x12\0\x19\0\0\x02\xC2\xA4\x03\x04\xC2\xA4\xC2\xA0K\x06\x04\xC2\xA4\xC2\xA0M\t\x04\xC2\xA4\xC2\xA0G\x0C\x04\xC2\xA4\xC2\xA0T") })
+                    },
+                };
+                static OR: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x04\0\x0B\0\x15\0\x1F\0\0\x02\xC2\xA4\x03\x02\xC2\xA4\xE0\xAC\xB9\x06\x02\xC2\xA4\xE0\xAC\xA8\xE0\xAC\xBF\t\x02\xC2\xA4\xE0\xAC\xAC\xE0\xAC\xBF\x0C\x02\xC2\xA4\xE0\xAC\x9F\xE0\xAD\x8D\xE0\xAC\xB0\xE0\xAC\xBF") })
+                    },
+                };
+                static SI: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x04\0\x0B\0\x15\0\x1F\0\0\x02\xC2\xA4\x03\x05\xC2\xA4\xE0\xB6\xAF\x06\x08\xC2\xA4\xE0\xB6\xB8\xE0\xB7\x92\t\x08\xC2\xA4\xE0\xB6\xB6\xE0\xB7\x92\x0C\x11\xC2\xA4\xE0\xB6\xA7\xE0\xB7\x8A\xE2\x80\x8D\xE0\xB6\xBB\xE0\xB7\x92") })
+                    },
+                };
+                static ID: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x04\0\x0C\0\x14\0\x1B\0\0\x02\xC2\xA4\x03\x02\xC2\xA4\xC2\xA0rb\x06\x02\xC2\xA4\xC2\xA0jt\t\x02\xC2\xA4\xC2\xA0M\x0C\x02\xC2\xA4\xC2\xA0T") })
+                    },
+                };
+                static TE: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x04\0\x0E\0\x18\0\"\0\0\x02\xC2\xA4\x03\x02\xC2\xA4\xE0\xB0\xB5\xE0\xB1\x87\x06\x02\xC2\xA4\xE0\xB0\xAE\xE0\xB0\xBF\t\x02\xC2\xA4\xE0\xB0\xAC\xE0\xB0\xBF\x0C\x02\xC2\xA4\xE0\xB0\x9F\xE0\xB1\x8D\xE0\xB0\xB0\xE0\xB0\xBF") })
+                    },
+                };
+                static KN: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x04\0\x0E\0\x18\0\"\0\0\x02\xC2\xA4\x03\x02\xC2\xA4\xE0\xB2\xB8\xE0\xB2\xBE\x06\x02\xC2\xA4\xE0\xB2\xAE\xE0\xB2\xBF\t\x02\xC2\xA4\xE0\xB2\xAC\xE0\xB2\xBF\x0C\x02\xC2\xA4\xE0\xB2\x9F\xE0\xB3\x8D\xE0\xB2\xB0\xE0\xB2\xBF") })
+                    },
+                };
+                static KM: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x04\0\x16\0%\0=\0\0\0\xC2\xA4\x03\x02\xC2\xA4\xC2\xA0\xE1\x9E\x96\xE1\x9E\xB6\xE1\x9E\x93\xE1\x9F\x8B\x06\x02\xC2\xA4\xC2\xA0\xE1\x9E\x9B\xE1\x9E\xB6\xE1\x9E\x93\t\x02\xC2\xA4\xC2\xA0\xE1\x9E\x94\xE1\x9F\x8A\xE1\x9E\xB8\xE1\x9E\x9B\xE1\x9E\xB6\xE1\x9E\x93\x0C\x02\xC2\xA4\xC2\xA0\xE1\x9E\x91\xE1\x9F\x92\xE1\x9E\x9A\xE1\x9E\xB8\xE1\x9E\x9B\xE1\x9E\xB6\xE1\x9E\x93") })
+                    },
+                };
+                static AST: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\r\0\x14\0\x1B\0\0\0\xC2\xA0\xC2\xA4\x03\0K\xC2\xA0\xC2\xA4\x06\0M\xC2\xA0\xC2\xA4\t\0G\xC2\xA0\xC2\xA4\x0C\0T\xC2\xA0\xC2\xA4") })
+                    },
+                };
+                static BR: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\r\0\x14\0\x1B\0\0\0\xC2\xA0\xC2\xA4\x03\0\xC2\xA0k\xC2\xA4\x06\0\xC2\xA0M\xC2\xA4\t\0\xC2\xA0G\xC2\xA4\x0C\0\xC2\xA0T\xC2\xA4") })
+                    },
+                };
+                static CV: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\r\0\x14\0\x1B\0\0\0\xC2\xA0\xC2\xA4\x03\x04\xC2\xA4\xC2\xA0K\x06\x04\xC2\xA4\xC2\xA0M\t\x04\xC2\xA4\xC2\xA0G\x0C\x04\xC2\xA4\xC2\xA0T") })
+                    },
+                };
+                static UND: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\r\0\x14\0\x1B\0\0\x04\xC2\xA4\xC2\xA0\x03\x04\xC2\xA4\xC2\xA0K\x06\x04\xC2\xA4\xC2\xA0M\t\x04\xC2\xA4\xC2\xA0G\x0C\x04\xC2\xA4\xC2\xA0T") })
+                    },
+                };
+                static NL: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\r\0\x19\0%\0\0\x04\xC2\xA4\xC2\xA0\x03\x04\xC2\xA4\xC2\xA0K\x06\x04\xC2\xA4\xC2\xA0\xC2\xA0mln.\t\x04\xC2\xA4\xC2\xA0\xC2\xA0mld.\x0C\x04\xC2\xA4\xC2\xA0\xC2\xA0bln.") })
+                    },
+                };
+                static NN: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\r\0\x1A\0&\0\0\0\xC2\xA0\xC2\xA4\x03\x04\xC2\xA4\xC2\xA0k\x06\x04\xC2\xA4\xC2\xA0\xC2\xA0mill.\t\x04\xC2\xA4\xC2\xA0\xC2\xA0mrd.\x0C\x04\xC2\xA4\xC2\xA0\xC2\xA0bill.") })
+                    },
+                };
+                static NO: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\r\0\x1A\0&\0\0\x04\xC2\xA4\xC2\xA0\x03\x04\xC2\xA4\xC2\xA0k\x06\x04\xC2\xA4\xC2\xA0\xC2\xA0mill.\t\x04\xC2\xA4\xC2\xA0\xC2\xA0mrd.\x0C\x04\xC2\xA4\xC2\xA0\xC2\xA0bill.") })
+                    },
+                };
+                static EN_150: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\x0B\0\x10\0\x15\0\0\0\xC2\xA0\xC2\xA4\x03\x02\xC2\xA4K\x06\x02\xC2\xA4M\t\x02\xC2\xA4B\x0C\x02\xC2\xA4T") })
+                    },
+                };
+                static MS_BN: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\x0B\0\x10\0\x15\0\0\x04\xC2\xA4\xC2\xA0\x03\x02\xC2\xA4K\x06\x02\xC2\xA4J\t\x02\xC2\xA4B\x0C\x02\xC2\xA4T") })
+                    },
+                };
+                static EN_AT: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\x0B\0\x10\0\x15\0\0\x04\xC2\xA4\xC2\xA0\x03\x02\xC2\xA4K\x06\x02\xC2\xA4M\t\x02\xC2\xA4B\x0C\x02\xC2\xA4T") })
+                    },
+                };
+                static JV: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\x0C\0\x11\0\x16\0\0\x04\xC2\xA4\xC2\xA0\x03\x02\xC2\xA4\xC3\x88\x06\x02\xC2\xA4Y\t\x02\xC2\xA4M\x0C\x02\xC2\xA4T") })
+                    },
+                };
+                static FF_ADLM: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\x0E\0\x16\0\"\0\0\x04\xC2\xA4\xC2\xA0\x03\0\xF0\x9E\xA4\x93\xC2\xA4\x06\0\xF0\x9E\xA4\x81\xC2\xA4\t\0\xF0\x9E\xA4\x81\xF0\x9E\xA4\xB6\xC2\xA4\x0C\0\xF0\x9E\xA4\x9A\xC2\xA4") })
+                    },
+                };
+                static HU: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\x0F\0\x18\0#\0\0\0\xC2\xA0\xC2\xA4\x03\0\xC2\xA0E\xC2\xA0\xC2\xA4\x06\0\xC2\xA0M\xC2\xA0\xC2\xA4\t\0\xC2\xA0Mrd\xC2\xA0\xC2\xA4\x0C\0\xC2\xA0B\xC2\xA0\xC2\xA4") })
+                    },
+                };
+                static FR: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\x0F\0\x18\0\"\0\0\0\xC2\xA0\xC2\xA4\x03\0\xC2\xA0k\xC2\xA0\xC2\xA4\x06\0\xC2\xA0M\xC2\xA0\xC2\xA4\t\0\xC2\xA0Md\xC2\xA0\xC2\xA4\x0C\0\xC2\xA0Bn\xC2\xA0\xC2\xA4") })
+                    },
+                };
+                static VI: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\x0F\0\x19\0\"\0\0\0\xC2\xA0\xC2\xA4\x03\0\xC2\xA0N\xC2\xA0\xC2\xA4\x06\0\xC2\xA0Tr\xC2\xA0\xC2\xA4\t\0\xC2\xA0T\xC2\xA0\xC2\xA4\x0C\0\xC2\xA0NT\xC2\xA0\xC2\xA4") })
+                    },
+                };
+                static DA: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\x0F\0\x1B\0'\0\0\0\xC2\xA0\xC2\xA4\x03\0\xC2\xA0t\xC2\xA0\xC2\xA4\x06\0\xC2\xA0mio.\xC2\xA0\xC2\xA4\t\0\xC2\xA0mia.\xC2\xA0\xC2\xA4\x0C\0\xC2\xA0bio.\xC2\xA0\xC2\xA4") })
+                    },
+                };
+                static SV: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\x10\0\x1A\0$\0\0\0\xC2\xA0\xC2\xA4\x03\0\xC2\xA0tn\xC2\xA0\xC2\xA4\x06\0\xC2\xA0mn\xC2\xA0\xC2\xA4\t\0\xC2\xA0md\xC2\xA0\xC2\xA4\x0C\0\xC2\xA0bn\xC2\xA0\xC2\xA4") })
+                    },
+                };
+                static FI: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\x10\0\x1D\0)\0\0\0\xC2\xA0\xC2\xA4\x03\0\xC2\xA0t.\xC2\xA0\xC2\xA4\x06\0\xC2\xA0milj.\xC2\xA0\xC2\xA4\t\0\xC2\xA0mrd.\xC2\xA0\xC2\xA4\x0C\0\xC2\xA0bilj.\xC2\xA0\xC2\xA4") })
+                    },
+                };
+                static PT_PT: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\x11\0\x1A\0$\0\0\0\xC2\xA0\xC2\xA4\x03\0\xC2\xA0mil\xC2\xA0\xC2\xA4\x06\0\xC2\xA0M\xC2\xA0\xC2\xA4\t\0\xC2\xA0mM\xC2\xA0\xC2\xA4\x0C\0\xC2\xA0B\xC2\xA0\xC2\xA4") })
+                    },
+                };
+                static KGP: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\x11\0\x1B\0%\0\0\x04\xC2\xA4\xC2\xA0\x03\x04\xC2\xA4\xC2\xA0\xC2\xA0mil\x06\x04\xC2\xA4\xC2\xA0\xC2\xA0mi\t\x04\xC2\xA4\xC2\xA0\xC2\xA0bi\x0C\x04\xC2\xA4\xC2\xA0\xC2\xA0tri") })
+                    },
+                };
+                static YRL: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\x11\0\x1B\0%\0\0\x04\xC2\xA4\xC2\xA0\x03\x04\xC2\xA4\xC2\xA0\xC2\xA0miu\x06\x04\xC2\xA4\xC2\xA0\xC2\xA0mi\t\x04\xC2\xA4\xC2\xA0\xC2\xA0bi\x0C\x04\xC2\xA4\xC2\xA0\xC2\xA0tiri") })
+                    },
+                };
+                static IS: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\x11\0\x1B\0&\0\0\0\xC2\xA0\xC2\xA4\x03\0\xC2\xA0\xC3\xBE.\xC2\xA0\xC2\xA4\x06\0\xC2\xA0m.\xC2\xA0\xC2\xA4\t\0\xC2\xA0ma.\xC2\xA0\xC2\xA4\x0C\0\xC2\xA0bn\xC2\xA0\xC2\xA4") })
+                    },
+                };
+                static ET: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\x11\0\x1C\0'\0\0\0\xC2\xA0\xC2\xA4\x03\0\xC2\xA0tuh\xC2\xA0\xC2\xA4\x06\0\xC2\xA0mln\xC2\xA0\xC2\xA4\t\0\xC2\xA0mld\xC2\xA0\xC2\xA4\x0C\0\xC2\xA0trln\xC2\xA0\xC2\xA4") })
+                    },
+                };
+                static IA: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\x11\0\x1C\0'\0\0\x04\xC2\xA4\xC2\xA0\x03\x04\xC2\xA4\xC2\xA0\xC2\xA0mil\x06\x04\xC2\xA4\xC2\xA0\xC2\xA0mln\t\x04\xC2\xA4\xC2\xA0\xC2\xA0mld\x0C\x04\xC2\xA4\xC2\xA0\xC2\xA0bln") })
+                    },
+                };
+                static SC: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\x12\0\x1D\0(\0\0\0\xC2\xA0\xC2\xA4\x03\0\xC2\xA0m\xC3\xACg\xC2\xA0\xC2\xA4\x06\0\xC2\xA0Mln\xC2\xA0\xC2\xA4\t\0\xC2\xA0Mrd\xC2\xA0\xC2\xA4\x0C\0\xC2\xA0Bln\xC2\xA0\xC2\xA4") })
+                    },
+                };
+                static PL: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\x12\0\x1D\0(\0\0\0\xC2\xA0\xC2\xA4\x03\0\xC2\xA0tys.\xC2\xA0\xC2\xA4\x06\0\xC2\xA0mln\xC2\xA0\xC2\xA4\t\0\xC2\xA0mld\xC2\xA0\xC2\xA4\x0C\0\xC2\xA0bln\xC2\xA0\xC2\xA4") })
+                    },
+                };
+                static UZ: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\x12\0\x1D\0)\0\0\0\xC2\xA0\xC2\xA4\x03\0\xC2\xA0ming\xC2\xA0\xC2\xA4\x06\0\xC2\xA0mln\xC2\xA0\xC2\xA4\t\0\xC2\xA0mlrd\xC2\xA0\xC2\xA4\x0C\0\xC2\xA0trln\xC2\xA0\xC2\xA4") })
+                    },
+                };
+                static CS: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\x12\0\x1E\0*\0\0\0\xC2\xA0\xC2\xA4\x03\0\xC2\xA0tis.\xC2\xA0\xC2\xA4\x06\0\xC2\xA0mil.\xC2\xA0\xC2\xA4\t\0\xC2\xA0mld.\xC2\xA0\xC2\xA4\x0C\0\xC2\xA0bil.\xC2\xA0\xC2\xA4") })
+                    },
+                };
+                static HR: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\x12\0\x1E\0*\0\0\0\xC2\xA0\xC2\xA4\x03\0\xC2\xA0tis.\xC2\xA0\xC2\xA4\x06\0\xC2\xA0mil.\xC2\xA0\xC2\xA4\t\0\xC2\xA0mlr.\xC2\xA0\xC2\xA4\x0C\0\xC2\xA0bil.\xC2\xA0\xC2\xA4") })
+                    },
+                };
+                static SL: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\x12\0\x1E\0*\0\0\0\xC2\xA0\xC2\xA4\x03\0\xC2\xA0tis.\xC2\xA0\xC2\xA4\x06\0\xC2\xA0mio.\xC2\xA0\xC2\xA4\t\0\xC2\xA0mrd.\xC2\xA0\xC2\xA4\x0C\0\xC2\xA0bil.\xC2\xA0\xC2\xA4") })
+                    },
+                };
+                static DSB: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\x12\0\x1E\0*\0\0\0\xC2\xA0\xC2\xA4\x03\0\xC2\xA0tys.\xC2\xA0\xC2\xA4\x06\0\xC2\xA0mio.\xC2\xA0\xC2\xA4\t\0\xC2\xA0mrd.\xC2\xA0\xC2\xA4\x0C\0\xC2\xA0bil.\xC2\xA0\xC2\xA4") })
+                    },
+                };
+                static FO: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\x13\0 \0,\0\0\0\xC2\xA0\xC2\xA4\x03\0\xC2\xA0t\xC3\xBAs.\xC2\xA0\xC2\xA4\x06\0\xC2\xA0mi\xC3\xB3.\xC2\xA0\xC2\xA4\t\0\xC2\xA0mia.\xC2\xA0\xC2\xA4\x0C\0\xC2\xA0bi\xC3\xB3.\xC2\xA0\xC2\xA4") })
+                    },
+                };
+                static SQ: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\x13\0\x1E\0)\0\0\0\xC2\xA0\xC2\xA4\x03\0\xC2\xA0mij\xC3\xAB\xC2\xA0\xC2\xA4\x06\0\xC2\xA0mln\xC2\xA0\xC2\xA4\t\0\xC2\xA0mld\xC2\xA0\xC2\xA4\x0C\0\xC2\xA0bln\xC2\xA0\xC2\xA4") })
+                    },
+                };
+                static TK: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\x13\0\x1E\0*\0\0\0\xC2\xA0\xC2\xA4\x03\0\xC2\xA0m\xC3\xBC\xC5\x88\xC2\xA0\xC2\xA4\x06\0\xC2\xA0mln\xC2\xA0\xC2\xA4\t\0\xC2\xA0mlrd\xC2\xA0\xC2\xA4\x0C\0\xC2\xA0trln\xC2\xA0\xC2\xA4") })
+                    },
+                };
+                static BS: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\x13\0\x1F\0+\0\0\0\xC2\xA0\xC2\xA4\x03\0\xC2\xA0hilj.\xC2\xA0\xC2\xA4\x06\0\xC2\xA0mil.\xC2\xA0\xC2\xA4\t\0\xC2\xA0mlr.\xC2\xA0\xC2\xA4\x0C\0\xC2\xA0bil.\xC2\xA0\xC2\xA4") })
+                    },
+                };
+                static SR_LATN: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\x13\0\x1F\0,\0\0\0\xC2\xA0\xC2\xA4\x03\0\xC2\xA0hilj.\xC2\xA0\xC2\xA4\x06\0\xC2\xA0mil.\xC2\xA0\xC2\xA4\t\0\xC2\xA0mlrd.\xC2\xA0\xC2\xA4\x0C\0\xC2\xA0bil.\xC2\xA0\xC2\xA4") })
+                    },
+                };
+                static KY: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\x14\0\"\x000\0\0\0\xC2\xA0\xC2\xA4\x03\0\xC2\xA0\xD0\xBC\xD0\xB8\xD2\xA3\xC2\xA0\xC2\xA4\x06\0\xC2\xA0\xD0\xBC\xD0\xBB\xD0\xBD\xC2\xA0\xC2\xA4\t\0\xC2\xA0\xD0\xBC\xD0\xBB\xD0\xB4\xC2\xA0\xC2\xA4\x0C\0\xC2\xA0\xD1\x82\xD1\x80\xD0\xBB\xD0\xBD\xC2\xA0\xC2\xA4") })
+                    },
+                };
+                static KK: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\x14\0\"\x002\0\0\0\xC2\xA0\xC2\xA4\x03\0\xC2\xA0\xD0\xBC\xD1\x8B\xD2\xA3\xC2\xA0\xC2\xA4\x06\0\xC2\xA0\xD0\xBC\xD0\xBB\xD0\xBD\xC2\xA0\xC2\xA4\t\0\xC2\xA0\xD0\xBC\xD0\xBB\xD1\x80\xD0\xB4\xC2\xA0\xC2\xA4\x0C\0\xC2\xA0\xD1\x82\xD1\x80\xD0\xBB\xD0\xBD\xC2\xA0\xC2\xA4") })
+                    },
+                };
+                static HY: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\x14\0\"\x002\0\0\0\xC2\xA0\xC2\xA4\x03\0\xC2\xA0\xD5\xB0\xD5\xA6\xD6\x80\xC2\xA0\xC2\xA4\x06\0\xC2\xA0\xD5\xB4\xD5\xAC\xD5\xB6\xC2\xA0\xC2\xA4\t\0\xC2\xA0\xD5\xB4\xD5\xAC\xD6\x80\xD5\xA4\xC2\xA0\xC2\xA4\x0C\0\xC2\xA0\xD5\xBF\xD6\x80\xD5\xAC\xD5\xB6\xC2\xA0\xC2\xA4") })
+                    },
+                };
+                static LT: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\x15\0!\0.\0\0\0\xC2\xA0\xC2\xA4\x03\0\xC2\xA0t\xC5\xABkst.\xC2\xA0\xC2\xA4\x06\0\xC2\xA0mln.\xC2\xA0\xC2\xA4\t\0\xC2\xA0mlrd.\xC2\xA0\xC2\xA4\x0C\0\xC2\xA0trln.\xC2\xA0\xC2\xA4") })
+                    },
+                };
+                static UK: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\x15\0#\x003\0\0\0\xC2\xA0\xC2\xA4\x03\0\xC2\xA0\xD1\x82\xD0\xB8\xD1\x81.\xC2\xA0\xC2\xA4\x06\0\xC2\xA0\xD0\xBC\xD0\xBB\xD0\xBD\xC2\xA0\xC2\xA4\t\0\xC2\xA0\xD0\xBC\xD0\xBB\xD1\x80\xD0\xB4\xC2\xA0\xC2\xA4\x0C\0\xC2\xA0\xD1\x82\xD1\x80\xD0\xBB\xD0\xBD\xC2\xA0\xC2\xA4") })
+                    },
+                };
+                static BE: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\x15\0#\x003\0\0\0\xC2\xA0\xC2\xA4\x03\0\xC2\xA0\xD1\x82\xD1\x8B\xD1\x81.\xC2\xA0\xC2\xA4\x06\0\xC2\xA0\xD0\xBC\xD0\xBB\xD0\xBD\xC2\xA0\xC2\xA4\t\0\xC2\xA0\xD0\xBC\xD0\xBB\xD1\x80\xD0\xB4\xC2\xA0\xC2\xA4\x0C\0\xC2\xA0\xD1\x82\xD1\x80\xD0\xBB\xD0\xBD\xC2\xA0\xC2\xA4") })
+                    },
+                };
+                static MK: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\x15\0$\x005\0\0\0\xC2\xA0\xC2\xA4\x03\0\xC2\xA0\xD0\xB8\xD0\xBB\xD1\x98.\xC2\xA0\xC2\xA4\x06\0\xC2\xA0\xD0\xBC\xD0\xB8\xD0\xBB.\xC2\xA0\xC2\xA4\t\0\xC2\xA0\xD0\xBC\xD0\xB8\xD0\xBB\xD1\x98.\xC2\xA0\xC2\xA4\x0C\0\xC2\xA0\xD0\xB1\xD0\xB8\xD0\xBB.\xC2\xA0\xC2\xA4") })
+                    },
+                };
+                static BG: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\x15\0$\x005\0\0\0\xC2\xA0\xC2\xA4\x03\0\xC2\xA0\xD1\x85\xD0\xB8\xD0\xBB.\xC2\xA0\xC2\xA4\x06\0\xC2\xA0\xD0\xBC\xD0\xBB\xD0\xBD.\xC2\xA0\xC2\xA4\t\0\xC2\xA0\xD0\xBC\xD0\xBB\xD1\x80\xD0\xB4.\xC2\xA0\xC2\xA4\x0C\0\xC2\xA0\xD1\x82\xD1\x80\xD0\xBB\xD0\xBD.\xC2\xA0\xC2\xA4") })
+                    },
+                };
+                static SR: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\x15\0$\x005\0\0\0\xC2\xA0\xC2\xA4\x03\0\xC2\xA0\xD1\x85\xD0\xB8\xD1\x99.\xC2\xA0\xC2\xA4\x06\0\xC2\xA0\xD0\xBC\xD0\xB8\xD0\xBB.\xC2\xA0\xC2\xA4\t\0\xC2\xA0\xD0\xBC\xD0\xBB\xD1\x80\xD0\xB4.\xC2\xA0\xC2\xA4\x0C\0\xC2\xA0\xD0\xB1\xD0\xB8\xD0\xBB.\xC2\xA0\xC2\xA4") })
+                    },
+                };
+                static TG: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\x15\0$\x005\0\0\0\xC2\xA0\xC2\xA4\x03\0\xC2\xA0\xD2\xB3\xD0\xB7\xD1\x80.\xC2\xA0\xC2\xA4\x06\0\xC2\xA0\xD0\xBC\xD0\xBB\xD0\xBD.\xC2\xA0\xC2\xA4\t\0\xC2\xA0\xD0\xBC\xD0\xBB\xD1\x80\xD0\xB4.\xC2\xA0\xC2\xA4\x0C\0\xC2\xA0\xD1\x82\xD1\x80\xD0\xBB\xD0\xBD.\xC2\xA0\xC2\xA4") })
+                    },
+                };
+                static LV: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\x15\0\"\x000\0\0\0\xC2\xA0\xC2\xA4\x03\0\xC2\xA0t\xC5\xABkst.\xC2\xA0\xC2\xA4\x06\0\xC2\xA0milj.\xC2\xA0\xC2\xA4\t\0\xC2\xA0mljrd.\xC2\xA0\xC2\xA4\x0C\0\xC2\xA0trilj.\xC2\xA0\xC2\xA4") })
+                    },
+                };
+                static EL: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\x15\0\"\x001\0\0\0\xC2\xA0\xC2\xA4\x03\0\xC2\xA0\xCF\x87\xCE\xB9\xCE\xBB.\xC2\xA0\xC2\xA4\x06\0\xC2\xA0\xCE\xB5\xCE\xBA.\xC2\xA0\xC2\xA4\t\0\xC2\xA0\xCE\xB4\xCE\xB9\xCF\x83.\xC2\xA0\xC2\xA4\x0C\0\xC2\xA0\xCF\x84\xCF\x81\xCE\xB9\xCF\x83.\xC2\xA0\xC2\xA4") })
+                    },
+                };
+                static FA_AF: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\x16\0*\0@\0\0\x04\xC2\xA4\xC2\xA0\x03\0\xC2\xA0\xD9\x87\xD8\xB2\xD8\xA7\xD8\xB1\xC2\xA0\xC2\xA4\x06\0\xC2\xA0\xD9\x85\xDB\x8C\xD9\x84\xDB\x8C\xD9\x88\xD9\x86\xC2\xA0\xC2\xA4\t\0\xC2\xA0\xD9\x85\xDB\x8C\xD9\x84\xDB\x8C\xD8\xA7\xD8\xB1\xD8\xAF\xC2\xA0\xC2\xA4\x0C\0\xC2\xA0\xD9\x87\xD8\xB2\xD8\xA7\xD8\xB1\xD9\x85\xDB\x8C\xD9\x84\xDB\x8C\xD8\xA7\xD8\xB1\xD8\xAF\xC2\xA0\xC2\xA4") })
+                    },
+                };
+                static FA: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x07\0\x17\0+\0A\0\0\x05\xE2\x80\x8E\xC2\xA4\x03\0\xC2\xA0\xD9\x87\xD8\xB2\xD8\xA7\xD8\xB1\xC2\xA0\xC2\xA4\x06\0\xC2\xA0\xD9\x85\xDB\x8C\xD9\x84\xDB\x8C\xD9\x88\xD9\x86\xC2\xA0\xC2\xA4\t\0\xC2\xA0\xD9\x85\xDB\x8C\xD9\x84\xDB\x8C\xD8\xA7\xD8\xB1\xD8\xAF\xC2\xA0\xC2\xA4\x0C\0\xC2\xA0\xD9\x87\xD8\xB2\xD8\xA7\xD8\xB1\xD9\x85\xDB\x8C\xD9\x84\xDB\x8C\xD8\xA7\xD8\xB1\xD8\xAF\xC2\xA0\xC2\xA4") })
+                    },
+                };
+                static HE: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x0C\0\x14\0\x1C\0$\0\0\x03\xE2\x80\x8F\xC2\xA0\xE2\x80\x8F\xC2\xA4\x03\x02\xC2\xA4K\xE2\x80\x8F\x06\x02\xC2\xA4M\xE2\x80\x8F\t\x02\xC2\xA4B\xE2\x80\x8F\x0C\x02\xC2\xA4T\xE2\x80\x8F") })
+                    },
+                };
+                static AF: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\t\x0C\x0E") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0\x07\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05\x01\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x07\0\0\0\0\0\x04\0\x0B\0\x12\0\x1B\0#\0+\0\0\x02\xC2\xA4\x03\x02\xC2\xA4\xC2\xA0k\x06\x02\xC2\xA4\xC2\xA0m\t\x02\xC2\xA4\xC2\xA0mjd\x0C\x02\xC2\xA4\xC2\xA0bn\x0C\x02\xC2\xA4\xC2\xA0bn\x0C\x04\xC2\xA4\xC2\xA0\xC2\xA0bn") })
+                    },
+                };
+                static ZU: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x03\x06\x08\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x04\0\0\0\x05\0\0\0\x06\0\0\0\x07\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x01\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x07\0\0\0\0\0\x04\0\t\0\x0E\0\x15\0\x1A\0\x1F\0\0\x02\xC2\xA4\x03\x02\xC2\xA4K\x06\x02\xC2\xA4M\x06\x04\xC2\xA4\xC2\xA0M\x06\x02\xC2\xA4M\t\x02\xC2\xA4B\x0C\x02\xC2\xA4T") })
+                    },
+                };
+                static BS_CYRL: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x04\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x06\0\x14\0\"\x002\0\0\0\xC2\xA0\xC2\xA4\x03\0\xC2\xA0\xD1\x85\xD0\xB8\xD1\x99\xC2\xA0\xC2\xA4\x06\0\xC2\xA0\xD0\xBC\xD0\xB8\xD0\xBB\xC2\xA0\xC2\xA4\t\0\xC2\xA0\xD0\xBC\xD0\xBB\xD1\x80\xD0\xB4\xC2\xA0\xC2\xA4\x0C\0\xC2\xA0\xD0\xB1\xD0\xB8\xD0\xBB\xC2\xA0\xC2\xA4") })
+                    },
+                };
+                static ZH: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x04\x08\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x04\0\x0B\0\x12\0\0\x02\xC2\xA4\x04\x02\xC2\xA4\xE4\xB8\x87\x08\x02\xC2\xA4\xE4\xBA\xBF\x0C\x02\xC2\xA4\xE4\xB8\x87\xE4\xBA\xBF") })
+                    },
+                };
+                static YUE_HANS: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x04\x08\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x04\0\x0B\0\x12\0\0\x02\xC2\xA4\x04\x02\xC2\xA4\xE4\xB8\x87\x08\x02\xC2\xA4\xE4\xBA\xBF\x0C\x02\xC2\xA4\xE5\x85\x86") })
+                    },
+                };
+                static ZH_HANT: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x04\x08\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x04\0\x0B\0\x12\0\0\x02\xC2\xA4\x04\x02\xC2\xA4\xE8\x90\xAC\x08\x02\xC2\xA4\xE5\x84\x84\x0C\x02\xC2\xA4\xE5\x85\x86") })
+                    },
+                };
+                static JA: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x04\x08\x0C\x10") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0\x05\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x05\0\0\0\0\0\x04\0\x0B\0\x12\0\x19\0\0\x02\xC2\xA4\x04\x02\xC2\xA4\xE4\xB8\x87\x08\x02\xC2\xA4\xE5\x84\x84\x0C\x02\xC2\xA4\xE5\x85\x86\x10\x02\xC2\xA4\xE4\xBA\xAC") })
+                    },
+                };
+                static IT: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x06\0\x11\0\x1C\0\0\0\xC2\xA0\xC2\xA4\x06\0\xC2\xA0Mio\xC2\xA0\xC2\xA4\t\0\xC2\xA0Mrd\xC2\xA0\xC2\xA4\x0C\0\xC2\xA0Bln\xC2\xA0\xC2\xA4") })
+                    },
+                };
+                static IT_CH: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x06\0\x11\0\x1C\0\0\x04\xC2\xA4\xC2\xA0\x06\0\xC2\xA0Mio\xC2\xA0\xC2\xA4\t\0\xC2\xA0Mrd\xC2\xA0\xC2\xA4\x0C\0\xC2\xA0Bln\xC2\xA0\xC2\xA4") })
+                    },
+                };
+                static DE: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x06\0\x12\0\x1E\0\0\0\xC2\xA0\xC2\xA4\x06\0\xC2\xA0Mio.\xC2\xA0\xC2\xA4\t\0\xC2\xA0Mrd.\xC2\xA0\xC2\xA4\x0C\0\xC2\xA0Bio.\xC2\xA0\xC2\xA4") })
+                    },
+                };
+                static DE_AT: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x06\t\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0\x04\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x04\0\0\0\0\0\x06\0\x12\0\x1E\0\0\x04\xC2\xA4\xC2\xA0\x06\0\xC2\xA0Mio.\xC2\xA0\xC2\xA4\t\0\xC2\xA0Mrd.\xC2\xA0\xC2\xA4\x0C\0\xC2\xA0Bio.\xC2\xA0\xC2\xA4") })
+                    },
+                };
+                static GL: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x06\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x03\0\0\0\0\0\x06\0\r\0\0\0\xC2\xA0\xC2\xA4\x06\0\xC2\xA0M\xC2\xA4\x0C\0\xC2\xA0B\xC2\xA4") })
+                    },
+                };
+                static EU: <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::compactdecimal::provider::CompactDecimalPatternDataV1 {
+                    patterns: unsafe {
+                        #[allow(unused_unsafe)]
+                        zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\0\x06\x0C") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x01\0\0\0\x02\0\0\0\x03\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x05\x05\x05") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x03\0\0\0\0\0\x06\0\x0F\0\0\0\xC2\xA0\xC2\xA4\x06\0\xC2\xA0M\xC2\xA0\xC2\xA4\x0C\0\xC2\xA0B\xC2\xA0\xC2\xA4") })
+                    },
+                };
+                static VALUES: [&<icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::DataMarker>::Yokeable; 160usize] = [&AF, &AM, &AR, &AS, &AST, &AST, &BE, &BG, &BHO, &BN, &BN_IN, &BR, &BRX, &BS, &BS_CYRL, &CA, &BHO, &CHR, &CS, &CV, &CHR, &DA, &DE, &DE_AT, &DE_AT, &DE_AT, &BHO, &DSB, &EL, &CHR, &EN_150, &EN_AT, &EN_AT, &EN_IN, &EN_AT, &EN_AT, &ES, &ES_419, &ES_AR, &ES_AR, &ES_GQ, &ES_GT, &ES_MX, &ES_PE, &ES_AR, &ES_US, &ES_AR, &ET, &EU, &FA, &FA_AF, &FA_U_NU_LATN, &FF_ADLM, &FI, &CHR, &FO, &FR, &BR, &GA, &CHR, &GL, &GU, &HA, &HE, &HI, &HR, &DSB, &HU, &HY, &IA, &ID, &IG, &IS, &IT, &IT_CH, &JA, &JV, &KA, &CV, &KGP, &KK, &KM, &KN, &KO, &EN_AT, &CHR, &BHO, &BHO, &KY, &LO, &LT, &LV, &MK, &CHR, &MN, &MR, &MS, &MS_BN, &MY, &NE, &NL, &NN, &NO, &OR, &PA, &CHR, &PL, &PS, &PS_U_NU_LATN, &KGP, &PT_PT, &CV, &RO, &BE, &BHO, &SAT, &SAT_U_NU_LATN, &SC, &SD, &SD_U_NU_LATN, &SI, &CS, &SL, &CHR, &SQ, &SR, &SR_LATN, &BHO, &SV, &SW, &SW_KE, &TA, &TA_MY, &TA_MY, &TA_U_NU_TAMLDEC, &TE, &TG, &CHR, &TI, &TK, &TO, &TR, &CV, &UK, &UND, &UR, &UR_IN, &UZ, &CV, &VI, &BHO, &CHR, &YRL, &YUE, &YUE_HANS, &ZH, &CHR, &ZH_HANT, &CHR, &ZU];
+                static KEYS: [&str; 160usize] = ["af", "am", "ar", "as", "ast", "az", "be", "bg", "bho", "bn", "bn-IN", "br", "brx", "bs", "bs-Cyrl", "ca", "ceb", "chr", "cs", "cv", "cy", "da", "de", "de-AT", "de-CH", "de-LI", "doi", "dsb", "el", "en", "en-150", "en-AT", "en-CH", "en-IN", "en-MV", "en-NL", "es", "es-419", "es-AR", "es-CO", "es-GQ", "es-GT", "es-MX", "es-PE", "es-PY", "es-US", "es-UY", "et", "eu", "fa", "fa-AF", "fa-u-nu-latn", "ff-Adlm", "fi", "fil", "fo", "fr", "fr-CA", "ga", "gd", "gl", "gu", "ha", "he", "hi", "hr", "hsb", "hu", "hy", "ia", "id", "ig", "is", "it", "it-CH", "ja", "jv", "ka", "kea", "kgp", "kk", "km", "kn", "ko", "kok", "kok-u-nu-deva", "ks", "ks-Deva", "ky", "lo", "lt", "lv", "mk", "ml", "mn", "mr", "ms", "ms-BN", "my", "ne", "nl", "nn", "no", "or", "pa", "pcm", "pl", "ps", "ps-u-nu-latn", "pt", "pt-PT", "rm", "ro", "ru", "sa", "sat", "sat-u-nu-latn", "sc", "sd", "sd-u-nu-latn", "si", "sk", "sl", "so", "sq", "sr", "sr-Latn", "su", "sv", "sw", "sw-KE", "ta", "ta-MY", "ta-SG", "ta-u-nu-tamldec", "te", "tg", "th", "ti", "tk", "to", "tr", "tt", "uk", "und", "ur", "ur-IN", "uz", "uz-Cyrl", "vi", "xh", "yo", "yrl", "yue", "yue-Hans", "zh", "zh-HK", "zh-Hant", "zh-MO", "zu"];
+                let mut metadata = icu_provider::DataResponseMetadata::default();
+                let payload = if let Ok(payload) = KEYS.binary_search_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse()).map(|i| *unsafe { VALUES.get_unchecked(i) }) {
+                    payload
+                } else {
+                    const FALLBACKER: icu::locid_transform::fallback::LocaleFallbackerWithConfig<'static> = icu::locid_transform::fallback::LocaleFallbacker::new().for_config(<icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::KeyedDataMarker>::KEY.fallback_config());
+                    let mut fallback_iterator = FALLBACKER.fallback_for(req.locale.clone());
+                    loop {
+                        if let Ok(payload) = KEYS.binary_search_by(|k| fallback_iterator.get().strict_cmp(k.as_bytes()).reverse()).map(|i| *unsafe { VALUES.get_unchecked(i) }) {
+                            metadata.locale = Some(fallback_iterator.take());
+                            break payload;
+                        }
+                        fallback_iterator.step();
+                    }
+                };
+                Ok(icu_provider::DataResponse { payload: Some(icu_provider::DataPayload::from_static_ref(payload)), metadata })
+            }
+        }
+    };
+}
@@ -15,6 +15,7 @@ macro_rules! __impl_data_provider {
     ($ provider : path) => {
         impl_compactdecimal_long_v1!($provider);
         impl_compactdecimal_short_v1!($provider);
+        impl_compactdecimal_shortcurrency_v1!($provider);
     };
 }
 #[doc(inline)]
@@ -38,6 +39,7 @@ macro_rules! __impl_any_provider {
                 match key.hashed() {
                     h if h == <icu::compactdecimal::provider::LongCompactDecimalFormatDataV1Marker as icu_provider::KeyedDataMarker>::KEY.hashed() => icu_provider::DataProvider::<icu::compactdecimal::provider::LongCompactDecimalFormatDataV1Marker>::load(self, req).map(icu_provider::DataResponse::wrap_into_any_response),
                     h if h == <icu::compactdecimal::provider::ShortCompactDecimalFormatDataV1Marker as icu_provider::KeyedDataMarker>::KEY.hashed() => icu_provider::DataProvider::<icu::compactdecimal::provider::ShortCompactDecimalFormatDataV1Marker>::load(self, req).map(icu_provider::DataResponse::wrap_into_any_response),
+                    h if h == <icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker as icu_provider::KeyedDataMarker>::KEY.hashed() => icu_provider::DataProvider::<icu::compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker>::load(self, req).map(icu_provider::DataResponse::wrap_into_any_response),
                     _ => Err(icu_provider::DataErrorKind::MissingDataKey.with_req(key, req)),
                 }
             }
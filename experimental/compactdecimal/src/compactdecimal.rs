@@ -3,6 +3,7 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use alloc::borrow::Cow;
+use alloc::string::String;
 use core::convert::TryFrom;
 use fixed_decimal::{CompactDecimal, FixedDecimal};
 use icu_decimal::{
@@ -11,6 +12,15 @@ use icu_decimal::{
 };
 use icu_plurals::PluralRules;
 use icu_provider::prelude::*;
+use icu_singlenumberformatter::provider::{
+    CurrencyEssentialsV1, CurrencyEssentialsV1Marker, PatternSelection, NO_PLACE_HOLDER,
+    USE_ISO_CODE,
+};
+use icu_unitsformatter::{
+    provider::{LongUnitsDisplayNamesV1Marker, ShortUnitsDisplayNamesV1Marker},
+    MeasureUnit, UnitFormatter,
+};
+use tinystr::TinyAsciiStr;
 use zerovec::maps::ZeroMap2dCursor;
 
 use crate::{
@@ -18,6 +28,7 @@ use crate::{
     provider::{
         Count, ErasedCompactDecimalFormatDataV1Marker, LongCompactDecimalFormatDataV1Marker,
         PatternULE, ShortCompactDecimalFormatDataV1Marker,
+        ShortCurrencyCompactDecimalFormatDataV1Marker,
     },
     CompactDecimalError,
 };
@@ -95,6 +106,52 @@ pub struct CompactDecimalFormatter {
     pub(crate) plural_rules: PluralRules,
     pub(crate) fixed_decimal_format: FixedDecimalFormatter,
     pub(crate) compact_data: DataPayload<ErasedCompactDecimalFormatDataV1Marker>,
+    /// The symbol that replaces the currency sign in currency patterns.
+    pub(crate) currency_symbol: Option<CurrencySymbol>,
+    /// The unit whose pattern is applied to the compact number.
+    pub(crate) unit: Option<UnitFormatter>,
+}
+
+/// The display form of a currency in a locale.
+#[derive(Debug)]
+pub(crate) struct CurrencySymbol {
+    pub(crate) symbol: String,
+    /// Whether the symbol ends (if it precedes the number) or starts (if it
+    /// follows the number) with a letter, in which case it is separated from the
+    /// digits by a space.
+    pub(crate) alpha_next_to_number: bool,
+}
+
+impl CurrencySymbol {
+    /// Looks up the short symbol of `currency`, falling back to its ISO code.
+    fn new(essentials: &CurrencyEssentialsV1, currency: TinyAsciiStr<3>) -> Self {
+        let symbol = essentials
+            .currency_patterns_map
+            .get_copied(&currency.to_unvalidated())
+            .filter(|p| {
+                p.short_place_holder_index != USE_ISO_CODE
+                    && p.short_place_holder_index != NO_PLACE_HOLDER
+            })
+            .and_then(|p| {
+                Some((
+                    essentials
+                        .place_holders
+                        .get(usize::from(p.short_place_holder_index))?,
+                    p.short_pattern_standard,
+                ))
+            });
+        match symbol {
+            Some((symbol, pattern)) => Self {
+                symbol: symbol.into(),
+                alpha_next_to_number: pattern == PatternSelection::StandardAlphaNextToNumber,
+            },
+            // ISO codes consist of letters.
+            None => Self {
+                symbol: currency.as_str().into(),
+                alpha_next_to_number: true,
+            },
+        }
+    }
 }
 
 impl CompactDecimalFormatter {
@@ -137,6 +194,8 @@ impl CompactDecimalFormatter {
             )?
             .take_payload()?
             .cast(),
+            currency_symbol: None,
+            unit: None,
         })
     }
 
@@ -182,6 +241,8 @@ impl CompactDecimalFormatter {
             )?
             .take_payload()?
             .cast(),
+            currency_symbol: None,
+            unit: None,
         })
     }
 
@@ -224,6 +285,8 @@ impl CompactDecimalFormatter {
             )?
             .take_payload()?
             .cast(),
+            currency_symbol: None,
+            unit: None,
         })
     }
 
@@ -269,6 +332,303 @@ impl CompactDecimalFormatter {
             )?
             .take_payload()?
             .cast(),
+            currency_symbol: None,
+            unit: None,
+        })
+    }
+
+    /// Constructor that takes a selected locale, a currency, and a list of
+    /// preferences, then collects all compiled data necessary to format amounts
+    /// of the currency in short compact notation for the given locale.
+    ///
+    /// The currency is given by its ISO 4217 code, and displayed using its
+    /// symbol in the locale, or its code if the locale has no symbol for it.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_compactdecimal::CompactDecimalFormatter;
+    /// use icu_locid::locale;
+    /// use tinystr::tinystr;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let short_english_dollars = CompactDecimalFormatter::try_new_short_currency(
+    ///     &locale!("en").into(),
+    ///     tinystr!(3, "USD"),
+    ///     Default::default(),
+    /// )
+    /// .unwrap();
+    /// let short_french_euros = CompactDecimalFormatter::try_new_short_currency(
+    ///     &locale!("fr").into(),
+    ///     tinystr!(3, "EUR"),
+    ///     Default::default(),
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_writeable_eq!(short_english_dollars.format_i64(3_412_000_000), "$3.4B");
+    /// assert_writeable_eq!(short_english_dollars.format_i64(-843), "-$843");
+    /// # // The following line contains U+00A0 NO-BREAK SPACE.
+    /// assert_writeable_eq!(short_french_euros.format_i64(3_412_000_000), "3,4 Md €");
+    /// ```
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new_short_currency(
+        locale: &DataLocale,
+        currency: TinyAsciiStr<3>,
+        options: CompactDecimalFormatterOptions,
+    ) -> Result<Self, CompactDecimalError> {
+        let req = DataRequest {
+            locale,
+            metadata: Default::default(),
+        };
+        let essentials: DataPayload<CurrencyEssentialsV1Marker> =
+            crate::provider::Baked.load(req)?.take_payload()?;
+        Ok(Self {
+            fixed_decimal_format: FixedDecimalFormatter::try_new(
+                locale,
+                options.fixed_decimal_formatter_options,
+            )?,
+            plural_rules: PluralRules::try_new_cardinal(locale)?,
+            compact_data: DataProvider::<ShortCurrencyCompactDecimalFormatDataV1Marker>::load(
+                &crate::provider::Baked,
+                req,
+            )?
+            .take_payload()?
+            .cast(),
+            currency_symbol: Some(CurrencySymbol::new(essentials.get(), currency)),
+            unit: None,
+        })
+    }
+
+    icu_provider::gen_any_buffer_data_constructors!(
+        locale: include,
+        currency: TinyAsciiStr<3>,
+        options: CompactDecimalFormatterOptions,
+        error: CompactDecimalError,
+        #[cfg(skip)]
+        functions: [
+            try_new_short_currency,
+            try_new_short_currency_with_any_provider,
+            try_new_short_currency_with_buffer_provider,
+            try_new_short_currency_unstable,
+            Self,
+        ]
+    );
+
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::try_new_short_currency)]
+    pub fn try_new_short_currency_unstable<D>(
+        provider: &D,
+        locale: &DataLocale,
+        currency: TinyAsciiStr<3>,
+        options: CompactDecimalFormatterOptions,
+    ) -> Result<Self, CompactDecimalError>
+    where
+        D: DataProvider<ShortCurrencyCompactDecimalFormatDataV1Marker>
+            + DataProvider<CurrencyEssentialsV1Marker>
+            + DataProvider<icu_decimal::provider::DecimalSymbolsV1Marker>
+            + DataProvider<icu_plurals::provider::CardinalV1Marker>
+            + ?Sized,
+    {
+        let req = DataRequest {
+            locale,
+            metadata: Default::default(),
+        };
+        let essentials: DataPayload<CurrencyEssentialsV1Marker> =
+            provider.load(req)?.take_payload()?;
+        Ok(Self {
+            fixed_decimal_format: FixedDecimalFormatter::try_new_unstable(
+                provider,
+                locale,
+                options.fixed_decimal_formatter_options,
+            )?,
+            plural_rules: PluralRules::try_new_cardinal_unstable(provider, locale)?,
+            compact_data: DataProvider::<ShortCurrencyCompactDecimalFormatDataV1Marker>::load(
+                provider, req,
+            )?
+            .take_payload()?
+            .cast(),
+            currency_symbol: Some(CurrencySymbol::new(essentials.get(), currency)),
+            unit: None,
+        })
+    }
+
+    /// Constructor that takes a selected locale, a measurement unit, and a list of
+    /// preferences, then collects all compiled data necessary to format amounts
+    /// of the unit in short compact notation for the given locale.
+    ///
+    /// The unit is displayed with its short display name, as by
+    /// [`UnitFormatter::try_new_short`], in the plural form for the compact number: the plural
+    /// operands are those of the full value, such as 1200 for "1.2K", together with the
+    /// compact exponent `c`.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_compactdecimal::CompactDecimalFormatter;
+    /// use icu_locid::locale;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let short_english_kilometers = CompactDecimalFormatter::try_new_short_unit(
+    ///     &locale!("en").into(),
+    ///     "kilometer".parse().unwrap(),
+    ///     Default::default(),
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_writeable_eq!(short_english_kilometers.format_i64(3_412_000), "3.4M km");
+    /// ```
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new_short_unit(
+        locale: &DataLocale,
+        unit: MeasureUnit,
+        options: CompactDecimalFormatterOptions,
+    ) -> Result<Self, CompactDecimalError> {
+        Ok(Self {
+            unit: Some(UnitFormatter::try_new_short(
+                locale,
+                unit,
+                Default::default(),
+            )?),
+            ..Self::try_new_short(locale, options)?
+        })
+    }
+
+    icu_provider::gen_any_buffer_data_constructors!(
+        locale: include,
+        unit: MeasureUnit,
+        options: CompactDecimalFormatterOptions,
+        error: CompactDecimalError,
+        #[cfg(skip)]
+        functions: [
+            try_new_short_unit,
+            try_new_short_unit_with_any_provider,
+            try_new_short_unit_with_buffer_provider,
+            try_new_short_unit_unstable,
+            Self,
+        ]
+    );
+
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::try_new_short_unit)]
+    pub fn try_new_short_unit_unstable<D>(
+        provider: &D,
+        locale: &DataLocale,
+        unit: MeasureUnit,
+        options: CompactDecimalFormatterOptions,
+    ) -> Result<Self, CompactDecimalError>
+    where
+        D: DataProvider<ShortCompactDecimalFormatDataV1Marker>
+            + DataProvider<ShortUnitsDisplayNamesV1Marker>
+            + DataProvider<icu_decimal::provider::DecimalSymbolsV1Marker>
+            + DataProvider<icu_plurals::provider::CardinalV1Marker>
+            + ?Sized,
+    {
+        Ok(Self {
+            unit: Some(UnitFormatter::try_new_short_unstable(
+                provider,
+                locale,
+                unit,
+                Default::default(),
+            )?),
+            ..Self::try_new_short_unstable(provider, locale, options)?
+        })
+    }
+
+    /// Constructor that takes a selected locale, a measurement unit, and a list of
+    /// preferences, then collects all compiled data necessary to format amounts
+    /// of the unit in long compact notation for the given locale.
+    ///
+    /// The unit is displayed with its long display name, as by
+    /// [`UnitFormatter::try_new_long`], in the plural form for the compact number: the plural
+    /// operands are those of the full value, such as 1200 for "1.2K", together with the
+    /// compact exponent `c`.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_compactdecimal::CompactDecimalFormatter;
+    /// use icu_locid::locale;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let long_english_kilometers = CompactDecimalFormatter::try_new_long_unit(
+    ///     &locale!("en").into(),
+    ///     "kilometer".parse().unwrap(),
+    ///     Default::default(),
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_writeable_eq!(
+    ///     long_english_kilometers.format_i64(1_000_000),
+    ///     "1 million kilometers"
+    /// );
+    /// assert_writeable_eq!(
+    ///     long_english_kilometers.format_i64(1),
+    ///     "1 kilometer"
+    /// );
+    /// ```
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new_long_unit(
+        locale: &DataLocale,
+        unit: MeasureUnit,
+        options: CompactDecimalFormatterOptions,
+    ) -> Result<Self, CompactDecimalError> {
+        Ok(Self {
+            unit: Some(UnitFormatter::try_new_long(
+                locale,
+                unit,
+                Default::default(),
+            )?),
+            ..Self::try_new_long(locale, options)?
+        })
+    }
+
+    icu_provider::gen_any_buffer_data_constructors!(
+        locale: include,
+        unit: MeasureUnit,
+        options: CompactDecimalFormatterOptions,
+        error: CompactDecimalError,
+        #[cfg(skip)]
+        functions: [
+            try_new_long_unit,
+            try_new_long_unit_with_any_provider,
+            try_new_long_unit_with_buffer_provider,
+            try_new_long_unit_unstable,
+            Self,
+        ]
+    );
+
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::try_new_long_unit)]
+    pub fn try_new_long_unit_unstable<D>(
+        provider: &D,
+        locale: &DataLocale,
+        unit: MeasureUnit,
+        options: CompactDecimalFormatterOptions,
+    ) -> Result<Self, CompactDecimalError>
+    where
+        D: DataProvider<LongCompactDecimalFormatDataV1Marker>
+            + DataProvider<LongUnitsDisplayNamesV1Marker>
+            + DataProvider<icu_decimal::provider::DecimalSymbolsV1Marker>
+            + DataProvider<icu_plurals::provider::CardinalV1Marker>
+            + ?Sized,
+    {
+        Ok(Self {
+            unit: Some(UnitFormatter::try_new_long_unstable(
+                provider,
+                locale,
+                unit,
+                Default::default(),
+            )?),
+            ..Self::try_new_long_unstable(provider, locale, options)?
         })
     }
 
@@ -505,12 +865,14 @@ impl CompactDecimalFormatter {
         &self,
         magnitude: i16,
     ) -> (Option<ZeroMap2dCursor<i8, Count, PatternULE>>, u8) {
+        // Numbers below 1 use the same patterns as those below 10; this only
+        // matters for currency patterns, which are explicit for small numbers.
         let plural_map = self
             .compact_data
             .get()
             .patterns
             .iter0()
-            .filter(|cursor| i16::from(*cursor.key0()) <= magnitude)
+            .filter(|cursor| i16::from(*cursor.key0()) <= magnitude.max(0))
             .last();
         let exponent = plural_map
             .as_ref()
@@ -592,4 +954,82 @@ mod tests {
             assert_writeable_eq!(result10T, case.expected10T, "{:?}", case);
         }
     }
+
+    #[test]
+    fn test_currency() {
+        let cases: [(_, _, &[(i64, &str)]); 4] = [
+            (
+                locale!("en"),
+                tinystr::tinystr!(3, "USD"),
+                &[
+                    (0, "$0"),
+                    (12, "$12"),
+                    (-1_234, "-$1.2K"),
+                    (1_000_000, "$1M"),
+                    (25_600_000_000, "$26B"),
+                ],
+            ),
+            (
+                locale!("en"),
+                tinystr::tinystr!(3, "XYZ"),
+                &[(1_234, "XYZ\u{a0}1.2K"), (-5, "-XYZ\u{a0}5")],
+            ),
+            (
+                locale!("fr"),
+                tinystr::tinystr!(3, "EUR"),
+                &[(843, "843\u{a0}€"), (-3_412_000, "-3,4\u{a0}M\u{a0}€")],
+            ),
+            (
+                locale!("de"),
+                tinystr::tinystr!(3, "EUR"),
+                &[
+                    (3_412_000_000, "3,4\u{a0}Mrd.\u{a0}€"),
+                    (-25_600_000, "-26\u{a0}Mio.\u{a0}€"),
+                ],
+            ),
+        ];
+        for (locale, currency, expectations) in cases {
+            let formatter = CompactDecimalFormatter::try_new_short_currency(
+                &locale.into(),
+                currency,
+                Default::default(),
+            )
+            .unwrap();
+            for &(value, expected) in expectations {
+                assert_writeable_eq!(formatter.format_i64(value), expected, "{currency}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_unit() {
+        let short = CompactDecimalFormatter::try_new_short_unit(
+            &locale!("en").into(),
+            "kilometer-per-hour".parse().unwrap(),
+            Default::default(),
+        )
+        .unwrap();
+        assert_writeable_eq!(short.format_i64(12), "12 km/h");
+        assert_writeable_eq!(short.format_i64(-1_234), "-1.2K km/h");
+        let long = CompactDecimalFormatter::try_new_long_unit(
+            &locale!("en").into(),
+            "kilogram".parse().unwrap(),
+            Default::default(),
+        )
+        .unwrap();
+        assert_writeable_eq!(long.format_i64(1), "1 kilogram");
+        assert_writeable_eq!(long.format_i64(1_000), "1 thousand kilograms");
+        assert_writeable_eq!(long.format_i64(25_600_000), "26 million kilograms");
+        assert_eq!(
+            CompactDecimalFormatter::try_new_short_unit(
+                &locale!("en").into(),
+                "smoot".parse().unwrap(),
+                Default::default(),
+            )
+            .err(),
+            Some(CompactDecimalError::Units(
+                icu_unitsformatter::UnitsFormatterError::UnknownUnit
+            ))
+        );
+    }
 }
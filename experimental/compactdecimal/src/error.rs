@@ -6,6 +6,7 @@ use displaydoc::Display;
 use icu_decimal::DecimalError;
 use icu_plurals::PluralsError;
 use icu_provider::DataError;
+use icu_unitsformatter::UnitsFormatterError;
 
 /// A list of error outcomes for various operations in this module.
 ///
@@ -22,6 +23,9 @@ pub enum CompactDecimalError {
     /// An error originating from [`FixedDecimalFormatter`](icu_decimal::FixedDecimalFormatter).
     #[displaydoc("Error loading FixedDecimalFormatter: {0}")]
     Decimal(DecimalError),
+    /// An error originating from [`UnitFormatter`](icu_unitsformatter::UnitFormatter).
+    #[displaydoc("Error loading UnitFormatter: {0}")]
+    Units(UnitsFormatterError),
     /// An error due to a [`CompactDecimal`](fixed_decimal::CompactDecimal) with an
     /// exponent inconsistent with the compact decimal data for the locale, e.g.,
     /// when formatting 1c5 in English (US).
@@ -53,3 +57,9 @@ impl From<DecimalError> for CompactDecimalError {
         CompactDecimalError::Decimal(e)
     }
 }
+
+impl From<UnitsFormatterError> for CompactDecimalError {
+    fn from(e: UnitsFormatterError) -> Self {
+        CompactDecimalError::Units(e)
+    }
+}
@@ -3,8 +3,8 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use alloc::borrow::Cow;
-use fixed_decimal::{CompactDecimal, FixedDecimal};
-use writeable::Writeable;
+use fixed_decimal::{CompactDecimal, FixedDecimal, Sign};
+use writeable::{Part, PartsWrite, Writeable};
 use zerovec::maps::ZeroMap2dCursor;
 
 use crate::compactdecimal::CompactDecimalFormatter;
@@ -19,57 +19,170 @@ pub struct FormattedCompactDecimal<'l> {
     pub(crate) plural_map: Option<ZeroMap2dCursor<'l, 'l, i8, Count, PatternULE>>,
 }
 
-impl<'l> Writeable for FormattedCompactDecimal<'l> {
-    fn write_to<W>(&self, sink: &mut W) -> core::result::Result<(), core::fmt::Error>
+impl<'l> FormattedCompactDecimal<'l> {
+    /// Writes literal text from a pattern, replacing the currency sign with the
+    /// currency symbol.
+    fn write_literal<W>(&self, text: &str, sink: &mut W) -> core::fmt::Result
     where
         W: core::fmt::Write + ?Sized,
     {
-        if self.value.exponent() == 0 {
-            self.formatter
-                .fixed_decimal_format
-                .format(self.value.significand())
-                .write_to(sink)
-        } else {
-            let plural_map = self.plural_map.as_ref().ok_or(core::fmt::Error)?;
-            let chosen_pattern = (|| {
-                if self.value.significand() == &FixedDecimal::from(1) {
-                    if let Some(pattern) = plural_map.get1(&Count::Explicit1) {
-                        return Some(pattern);
+        match &self.formatter.currency_symbol {
+            None => sink.write_str(text),
+            Some(currency) => {
+                let mut chunks = text.split('¤');
+                sink.write_str(chunks.next().unwrap_or_default())?;
+                for chunk in chunks {
+                    sink.write_str(&currency.symbol)?;
+                    sink.write_str(chunk)?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    fn alpha_next_to_number(&self) -> bool {
+        self.formatter
+            .currency_symbol
+            .as_ref()
+            .map_or(false, |currency| currency.alpha_next_to_number)
+    }
+
+    /// Writes the compact number, without the unit.
+    fn write_number<W>(&self, sink: &mut W) -> core::fmt::Result
+    where
+        W: core::fmt::Write + ?Sized,
+    {
+        let formatted_significand = self
+            .formatter
+            .fixed_decimal_format
+            .format(self.value.significand());
+        let plural_map = match self.plural_map.as_ref() {
+            Some(plural_map) => plural_map,
+            None if self.value.exponent() == 0 => return formatted_significand.write_to(sink),
+            None => return Err(core::fmt::Error),
+        };
+        let chosen_pattern = (|| {
+            if self.value.significand() == &FixedDecimal::from(1) {
+                if let Some(pattern) = plural_map.get1(&Count::Explicit1) {
+                    return Some(pattern);
+                }
+            }
+            let plural_category = self
+                .formatter
+                .plural_rules
+                .category_for(self.value.significand());
+            plural_map
+                .get1(&plural_category.into())
+                .or_else(|| plural_map.get1(&Count::Other))
+        })()
+        .ok_or(core::fmt::Error)?;
+        match chosen_pattern.index {
+            u8::MAX => self.write_literal(&chosen_pattern.literal_text, sink),
+            _ => {
+                let i = usize::from(chosen_pattern.index);
+                let prefix = chosen_pattern
+                    .literal_text
+                    .get(..i)
+                    .ok_or(core::fmt::Error)?;
+                let suffix = chosen_pattern
+                    .literal_text
+                    .get(i..)
+                    .ok_or(core::fmt::Error)?;
+                if self.formatter.currency_symbol.is_some()
+                    && prefix.contains('¤')
+                    && self.value.significand().sign() == Sign::Negative
+                {
+                    // The sign goes before a preceding currency symbol, as in -$1.2K.
+                    formatted_significand.write_to_parts(&mut LeadingSign::new(sink, true))?;
+                    self.write_literal(prefix, sink)?;
+                    if prefix.ends_with('¤') && self.alpha_next_to_number() {
+                        sink.write_char('\u{a0}')?;
+                    }
+                    formatted_significand.write_to_parts(&mut LeadingSign::new(sink, false))?;
+                } else {
+                    self.write_literal(prefix, sink)?;
+                    if prefix.ends_with('¤') && self.alpha_next_to_number() {
+                        sink.write_char('\u{a0}')?;
                     }
+                    formatted_significand.write_to(sink)?;
                 }
-                let plural_category = self
-                    .formatter
-                    .plural_rules
-                    .category_for(self.value.significand());
-                plural_map
-                    .get1(&plural_category.into())
-                    .or_else(|| plural_map.get1(&Count::Other))
-            })()
-            .ok_or(core::fmt::Error)?;
-            match chosen_pattern.index {
-                u8::MAX => sink.write_str(&chosen_pattern.literal_text),
-                _ => {
-                    let i = usize::from(chosen_pattern.index);
-                    sink.write_str(
-                        chosen_pattern
-                            .literal_text
-                            .get(..i)
-                            .ok_or(core::fmt::Error)?,
-                    )?;
-                    self.formatter
-                        .fixed_decimal_format
-                        .format(self.value.significand())
-                        .write_to(sink)?;
-                    sink.write_str(
-                        chosen_pattern
-                            .literal_text
-                            .get(i..)
-                            .ok_or(core::fmt::Error)?,
-                    )
+                if suffix.starts_with('¤') && self.alpha_next_to_number() {
+                    sink.write_char('\u{a0}')?;
                 }
+                self.write_literal(suffix, sink)
+            }
+        }
+    }
+}
+
+impl<'l> Writeable for FormattedCompactDecimal<'l> {
+    fn write_to<W>(&self, sink: &mut W) -> core::result::Result<(), core::fmt::Error>
+    where
+        W: core::fmt::Write + ?Sized,
+    {
+        let Some(unit) = self.formatter.unit.as_ref() else {
+            return self.write_number(sink);
+        };
+        let category = self.formatter.plural_rules.category_for(&*self.value);
+        match unit.pattern(category).split_once("{0}") {
+            Some((prefix, suffix)) => {
+                sink.write_str(prefix)?;
+                self.write_number(sink)?;
+                sink.write_str(suffix)
             }
+            None => sink.write_str(unit.pattern(category)),
         }
     }
 }
 
 writeable::impl_display_with_writeable!(FormattedCompactDecimal<'_>);
+
+/// A [`PartsWrite`] that writes either only the leading minus sign of a formatted decimal, or
+/// everything but it, so that the sign can be moved in front of a currency symbol.
+struct LeadingSign<'a, W: ?Sized> {
+    sink: &'a mut W,
+    sign_only: bool,
+    leading: bool,
+    in_sign: bool,
+}
+
+impl<'a, W: core::fmt::Write + ?Sized> LeadingSign<'a, W> {
+    fn new(sink: &'a mut W, sign_only: bool) -> Self {
+        Self {
+            sink,
+            sign_only,
+            leading: true,
+            in_sign: false,
+        }
+    }
+}
+
+impl<W: core::fmt::Write + ?Sized> core::fmt::Write for LeadingSign<'_, W> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        if self.in_sign == self.sign_only {
+            self.sink.write_str(s)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<W: core::fmt::Write + ?Sized> PartsWrite for LeadingSign<'_, W> {
+    type SubPartsWrite = Self;
+
+    fn with_part(
+        &mut self,
+        part: Part,
+        mut f: impl FnMut(&mut Self::SubPartsWrite) -> core::fmt::Result,
+    ) -> core::fmt::Result {
+        if self.in_sign {
+            return f(self);
+        }
+        let is_sign = self.leading && part == icu_decimal::parts::MINUS_SIGN;
+        self.leading = false;
+        self.in_sign = is_sign;
+        let result = f(self);
+        self.in_sign = false;
+        result
+    }
+}
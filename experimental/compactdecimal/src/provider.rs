@@ -26,9 +26,12 @@ const _: () = {
     pub mod icu {
         pub use crate as compactdecimal;
         pub use icu_locid_transform as locid_transform;
+        pub use icu_singlenumberformatter as singlenumberformatter;
     }
     icu_compactdecimal_data::impl_compactdecimal_long_v1!(Baked);
     icu_compactdecimal_data::impl_compactdecimal_short_v1!(Baked);
+    icu_compactdecimal_data::impl_compactdecimal_shortcurrency_v1!(Baked);
+    icu_singlenumberformatter_data::impl_currency_essentials_v1!(Baked);
 };
 
 /// Relative time format V1 data struct.
@@ -56,6 +59,11 @@ const _: () = {
 ///
 /// Finally, the pattern indicating noncompact notation for the first few powers
 /// of ten is omitted; that is, there is an implicit (1, other) ↦ 0.
+///
+/// The currency patterns are the exception to this: their literal text contains
+/// the currency sign ¤, which is replaced by the currency symbol when formatting,
+/// and the noncompact pattern, derived from the standard currency pattern, is
+/// stored explicitly, e.g., (1, other) ↦ ¤0 for the CLDR pattern ¤#,##0.00.
 #[icu_provider::data_struct(
     marker(
         LongCompactDecimalFormatDataV1Marker,
@@ -66,6 +74,11 @@ const _: () = {
         ShortCompactDecimalFormatDataV1Marker,
         "compactdecimal/short@1",
         extension_key = "nu"
+    ),
+    marker(
+        ShortCurrencyCompactDecimalFormatDataV1Marker,
+        "compactdecimal/shortcurrency@1",
+        extension_key = "nu"
     )
 )]
 #[derive(Debug, Clone, Default, PartialEq)]
@@ -146,6 +159,8 @@ pub struct Pattern<'data> {
     /// The compact decimal exponent, e.g., 6 for "million".
     /// The value 0 indicates that compact notation is not used; in that case,
     /// literal text must be empty; this corresponds to the CLDR pattern "0".
+    /// In currency patterns, the literal text then contains the currency sign
+    /// instead, as in "¤" for the standard currency pattern "¤#,##0.00".
     /// This is derived from the numbers of 0s in the pattern and the associated
     /// `type` attribute; it is a more convenient representation than the number
     /// of 0s, because it is often common to multiple types; for instance, the
@@ -160,7 +175,7 @@ pub struct Pattern<'data> {
     pub index: u8,
     #[cfg_attr(feature = "serde", serde(borrow))]
     /// The underlying CLDR pattern with the placeholder removed, e.g.,
-    /// " M" for the pattern "000 M"
+    /// " M" for the pattern "000 M", or "¤M" for the currency pattern "¤000M"
     pub literal_text: Cow<'data, str>,
 }
 pub(crate) struct ErasedCompactDecimalFormatDataV1Marker;
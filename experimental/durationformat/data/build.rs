@@ -7,4 +7,4 @@ fn main() {
         println!("cargo:rustc-cfg=icu4x_custom_data");
     }
     println!("cargo:rerun-if-env-changed=ICU4X_DATA_DIR");
-}
\ No newline at end of file
+}
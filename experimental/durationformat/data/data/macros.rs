@@ -0,0 +1,6 @@
+// @generated
+#[macro_use]
+#[path = "macros/duration_digital_v1.data.rs"]
+mod duration_digital_v1;
+#[doc(inline)]
+pub use __impl_duration_digital_v1 as impl_duration_digital_v1;
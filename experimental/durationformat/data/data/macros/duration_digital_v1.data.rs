@@ -0,0 +1,34 @@
+// @generated
+/// Implement `DataProvider<DigitalDurationDataV1Marker>` on the given struct using the data
+/// hardcoded in this file. This allows the struct to be used with
+/// `icu`'s `_unstable` constructors.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_duration_digital_v1 {
+    ($ provider : path) => {
+        #[clippy::msrv = "1.66"]
+        impl icu_provider::DataProvider<icu::durationformat::provider::DigitalDurationDataV1Marker> for $provider {
+            fn load(&self, req: icu_provider::DataRequest) -> Result<icu_provider::DataResponse<icu::durationformat::provider::DigitalDurationDataV1Marker>, icu_provider::DataError> {
+                static DA: <icu::durationformat::provider::DigitalDurationDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::durationformat::provider::DigitalDurationDataV1 { separator: alloc::borrow::Cow::Borrowed(".") };
+                static UND: <icu::durationformat::provider::DigitalDurationDataV1Marker as icu_provider::DataMarker>::Yokeable = icu::durationformat::provider::DigitalDurationDataV1 { separator: alloc::borrow::Cow::Borrowed(":") };
+                static VALUES: [&<icu::durationformat::provider::DigitalDurationDataV1Marker as icu_provider::DataMarker>::Yokeable; 9usize] = [&DA, &DA, &DA, &DA, &DA, &DA, &DA, &DA, &UND];
+                static KEYS: [&str; 9usize] = ["da", "fi", "id", "si", "sl", "sr", "sr-Latn", "su", "und"];
+                let mut metadata = icu_provider::DataResponseMetadata::default();
+                let payload = if let Ok(payload) = KEYS.binary_search_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse()).map(|i| *unsafe { VALUES.get_unchecked(i) }) {
+                    payload
+                } else {
+                    const FALLBACKER: icu::locid_transform::fallback::LocaleFallbackerWithConfig<'static> = icu::locid_transform::fallback::LocaleFallbacker::new().for_config(<icu::durationformat::provider::DigitalDurationDataV1Marker as icu_provider::KeyedDataMarker>::KEY.fallback_config());
+                    let mut fallback_iterator = FALLBACKER.fallback_for(req.locale.clone());
+                    loop {
+                        if let Ok(payload) = KEYS.binary_search_by(|k| fallback_iterator.get().strict_cmp(k.as_bytes()).reverse()).map(|i| *unsafe { VALUES.get_unchecked(i) }) {
+                            metadata.locale = Some(fallback_iterator.take());
+                            break payload;
+                        }
+                        fallback_iterator.step();
+                    }
+                };
+                Ok(icu_provider::DataResponse { payload: Some(icu_provider::DataPayload::from_static_ref(payload)), metadata })
+            }
+        }
+    };
+}
@@ -0,0 +1,136 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_calendar::{Calendar, DateDuration};
+
+use crate::DurationFormatterError;
+
+/// The sign of a [`Duration`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[allow(clippy::exhaustive_enums)] // the sign of a duration is either positive or negative
+pub enum DurationSign {
+    /// A positive duration, or a duration of zero.
+    #[default]
+    Positive,
+    /// A negative duration.
+    Negative,
+}
+
+/// A duration, such as 1 hour, 20 minutes and 5 seconds, to be formatted by
+/// [`DurationFormatter`](crate::DurationFormatter).
+///
+/// All fields share the [`sign`](Self::sign) of the duration. Fields are not balanced,
+/// so 90 minutes are formatted as "90 min" rather than "1 hr, 30 min".
+///
+/// # Examples
+///
+/// A [`Duration`] can be created from a [`DateDuration`], and extended with time fields:
+///
+/// ```
+/// use icu_calendar::{DateDuration, Iso};
+/// use icu_durationformat::Duration;
+///
+/// let date_duration: DateDuration<Iso> = DateDuration::new(0, 0, 1, 2);
+/// let duration = Duration {
+///     hours: 5,
+///     ..Duration::try_from(date_duration).unwrap()
+/// };
+///
+/// assert_eq!(duration.weeks, 1);
+/// assert_eq!(duration.days, 2);
+/// assert_eq!(duration.hours, 5);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[allow(clippy::exhaustive_structs)] // constructed with struct literals and `..Default::default()`
+pub struct Duration {
+    /// The sign of the duration.
+    pub sign: DurationSign,
+    /// The number of years.
+    pub years: u64,
+    /// The number of months.
+    pub months: u64,
+    /// The number of weeks.
+    pub weeks: u64,
+    /// The number of days.
+    pub days: u64,
+    /// The number of hours.
+    pub hours: u64,
+    /// The number of minutes.
+    pub minutes: u64,
+    /// The number of seconds.
+    pub seconds: u64,
+    /// The number of milliseconds.
+    pub milliseconds: u64,
+    /// The number of microseconds.
+    pub microseconds: u64,
+    /// The number of nanoseconds.
+    pub nanoseconds: u64,
+}
+
+impl Duration {
+    /// Creates a new positive [`Duration`] of zero.
+    pub const fn new() -> Self {
+        Self {
+            sign: DurationSign::Positive,
+            years: 0,
+            months: 0,
+            weeks: 0,
+            days: 0,
+            hours: 0,
+            minutes: 0,
+            seconds: 0,
+            milliseconds: 0,
+            microseconds: 0,
+            nanoseconds: 0,
+        }
+    }
+
+    /// Returns the fields of the duration, from years to nanoseconds.
+    pub(crate) fn fields(&self) -> [u64; 10] {
+        [
+            self.years,
+            self.months,
+            self.weeks,
+            self.days,
+            self.hours,
+            self.minutes,
+            self.seconds,
+            self.milliseconds,
+            self.microseconds,
+            self.nanoseconds,
+        ]
+    }
+}
+
+impl<C: Calendar> TryFrom<DateDuration<C>> for Duration {
+    type Error = DurationFormatterError;
+
+    /// Creates a [`Duration`] from the fields of a [`DateDuration`].
+    ///
+    /// Returns [`DurationFormatterError::MixedSigns`] if the fields of the [`DateDuration`]
+    /// have different signs.
+    fn try_from(duration: DateDuration<C>) -> Result<Self, Self::Error> {
+        let fields = [
+            duration.years,
+            duration.months,
+            duration.weeks,
+            duration.days,
+        ];
+        let sign = if fields.iter().all(|&field| field >= 0) {
+            DurationSign::Positive
+        } else if fields.iter().all(|&field| field <= 0) {
+            DurationSign::Negative
+        } else {
+            return Err(DurationFormatterError::MixedSigns);
+        };
+        Ok(Self {
+            sign,
+            years: duration.years.unsigned_abs().into(),
+            months: duration.months.unsigned_abs().into(),
+            weeks: duration.weeks.unsigned_abs().into(),
+            days: duration.days.unsigned_abs().into(),
+            ..Self::new()
+        })
+    }
+}
@@ -0,0 +1,60 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use displaydoc::Display;
+use icu_decimal::DecimalError;
+use icu_list::ListError;
+use icu_provider::DataError;
+use icu_unitsformatter::UnitsFormatterError;
+
+/// A list of error outcomes for various operations in this module.
+///
+/// Re-exported as [`Error`](crate::Error).
+#[derive(Display, Debug, Copy, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum DurationFormatterError {
+    /// The options are inconsistent, e.g. seconds are displayed with their unit name after
+    /// minutes are displayed as digits.
+    #[displaydoc("Invalid options")]
+    InvalidOptions,
+    /// The fields of a duration have different signs.
+    #[displaydoc("Duration fields have mixed signs")]
+    MixedSigns,
+    /// An error originating from [`UnitFormatter`](icu_unitsformatter::UnitFormatter).
+    #[displaydoc("Error loading UnitFormatter: {0}")]
+    Units(UnitsFormatterError),
+    /// An error originating from [`ListFormatter`](icu_list::ListFormatter).
+    #[displaydoc("Error loading ListFormatter: {0}")]
+    List(ListError),
+    /// An error originating from [`DataProvider`](icu_provider::DataProvider).
+    #[displaydoc("Error loading data: {0}")]
+    Data(DataError),
+    /// An error originating from [`FixedDecimalFormatter`](icu_decimal::FixedDecimalFormatter).
+    #[displaydoc("Error loading FixedDecimalFormatter: {0}")]
+    Decimal(DecimalError),
+}
+
+impl From<UnitsFormatterError> for DurationFormatterError {
+    fn from(e: UnitsFormatterError) -> Self {
+        DurationFormatterError::Units(e)
+    }
+}
+
+impl From<ListError> for DurationFormatterError {
+    fn from(e: ListError) -> Self {
+        DurationFormatterError::List(e)
+    }
+}
+
+impl From<DataError> for DurationFormatterError {
+    fn from(e: DataError) -> Self {
+        DurationFormatterError::Data(e)
+    }
+}
+
+impl From<DecimalError> for DurationFormatterError {
+    fn from(e: DecimalError) -> Self {
+        DurationFormatterError::Decimal(e)
+    }
+}
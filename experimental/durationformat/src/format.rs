@@ -0,0 +1,187 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+use fixed_decimal::{FixedDecimal, Sign};
+use icu_unitsformatter::UnitFormatter;
+use writeable::Writeable;
+
+use crate::formatter::{Field, Style, HOURS, MINUTES, SECONDS};
+use crate::options::FieldDisplay;
+use crate::{Duration, DurationFormatter, DurationSign};
+
+/// The [`Part`](writeable::Part)s used by [`FormattedDuration`].
+///
+/// Each field or group of digital fields is annotated with the parts of [`icu_list::parts`],
+/// and numbers with the parts of [`icu_decimal::parts`]. Fields that are displayed with their
+/// unit names are annotated with the parts of [`icu_unitsformatter::parts`].
+pub mod parts {
+    use writeable::Part;
+
+    /// The [`Part`] used by [`FormattedDuration`](crate::FormattedDuration) to mark the
+    /// separator between digital fields, such as ":" in "1:20:05".
+    ///
+    /// * `category`: `"durationformat"`
+    /// * `value`: `"separator"`
+    pub const SEPARATOR: Part = Part {
+        category: "durationformat",
+        value: "separator",
+    };
+}
+
+/// An intermediate structure returned by [`DurationFormatter`].
+/// This structure can be consumed via [`Writeable`] trait to a string or buffer.
+#[derive(Debug)]
+pub struct FormattedDuration<'l> {
+    pub(crate) formatter: &'l DurationFormatter,
+    pub(crate) duration: Duration,
+}
+
+/// An element of the list of formatted fields.
+#[derive(Debug)]
+enum Element<'l> {
+    /// A field with its unit name, e.g. "20 min".
+    Unit(&'l UnitFormatter, FixedDecimal),
+    /// Consecutive fields displayed as digits, e.g. "1:20:05".
+    Digital(Vec<FixedDecimal>),
+}
+
+/// An [`Element`] along with the formatter that writes it.
+#[derive(Debug)]
+struct FormattedElement<'l> {
+    formatter: &'l DurationFormatter,
+    element: Element<'l>,
+}
+
+impl<'l> Writeable for FormattedElement<'l> {
+    fn write_to_parts<S: writeable::PartsWrite + ?Sized>(&self, sink: &mut S) -> core::fmt::Result {
+        match &self.element {
+            Element::Unit(unit_formatter, value) => {
+                unit_formatter.format(value).write_to_parts(sink)
+            }
+            Element::Digital(values) => {
+                for (index, value) in values.iter().enumerate() {
+                    if index > 0 {
+                        sink.with_part(parts::SEPARATOR, |s| {
+                            s.write_str(&self.formatter.digital.get().separator)
+                        })?;
+                    }
+                    self.formatter
+                        .fixed_decimal_format
+                        .format(value)
+                        .write_to_parts(sink)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl<'l> FormattedDuration<'l> {
+    /// Returns the formatted fields of the duration.
+    fn elements(&self) -> Vec<FormattedElement<'l>> {
+        let fields: &[Field] = &self.formatter.fields;
+        let values = self.duration.fields();
+
+        // Fractional fields are displayed as decimal digits of the preceding field.
+        let end = fields
+            .iter()
+            .position(|field| field.style == Style::Fractional)
+            .unwrap_or(fields.len());
+        let decimals: Vec<FixedDecimal> = (0..end)
+            .map(|index| {
+                if index + 1 != end || end == fields.len() {
+                    return FixedDecimal::from(values.get(index).copied().unwrap_or_default());
+                }
+                // Each subsecond field is a thousandth of the preceding one. The preceding field
+                // is at least seconds, so its value in nanoseconds fits in a u128.
+                let nanoseconds = |i: usize| 1000u128.pow((values.len() - 1 - i) as u32);
+                let total: u128 = values
+                    .iter()
+                    .enumerate()
+                    .skip(index)
+                    .map(|(i, &value)| u128::from(value) * nanoseconds(i))
+                    .sum();
+                let mut decimal = FixedDecimal::from(total)
+                    .multiplied_pow10(-3 * (values.len() - 1 - index) as i16);
+                match self.formatter.fractional_digits {
+                    Some(digits) => {
+                        decimal.trunc(-i16::from(digits));
+                        decimal.pad_end(-i16::from(digits));
+                    }
+                    None => decimal.trim_end(),
+                }
+                decimal
+            })
+            .collect();
+
+        let mut displayed: Vec<bool> = fields
+            .iter()
+            .zip(&decimals)
+            .map(|(field, decimal)| field.display == FieldDisplay::Always || !decimal.is_zero())
+            .collect();
+        // Numeric minutes are displayed between displayed hours and seconds, as in "1:00:05".
+        if displayed.get(HOURS) == Some(&true)
+            && displayed.get(SECONDS) == Some(&true)
+            && fields
+                .get(MINUTES)
+                .map_or(false, |field| field.style == Style::TwoDigit)
+        {
+            if let Some(minutes) = displayed.get_mut(MINUTES) {
+                *minutes = true;
+            }
+        }
+
+        let mut elements: Vec<FormattedElement<'l>> = Vec::new();
+        let mut is_first = true;
+        for ((field, mut decimal), displayed) in fields.iter().zip(decimals).zip(displayed) {
+            if !displayed {
+                continue;
+            }
+            // The sign of the duration is displayed on its first displayed field.
+            if is_first && self.duration.sign == DurationSign::Negative {
+                decimal.set_sign(Sign::Negative);
+            }
+            is_first = false;
+            match (field.style, &field.unit_formatter) {
+                (Style::Unit(_), Some(unit_formatter)) => elements.push(FormattedElement {
+                    formatter: self.formatter,
+                    element: Element::Unit(unit_formatter, decimal),
+                }),
+                (style, _) => {
+                    if style == Style::TwoDigit {
+                        decimal.pad_start(2);
+                    }
+                    if let Some(FormattedElement {
+                        element: Element::Digital(values),
+                        ..
+                    }) = elements.last_mut()
+                    {
+                        values.push(decimal);
+                    } else {
+                        elements.push(FormattedElement {
+                            formatter: self.formatter,
+                            element: Element::Digital(alloc::vec![decimal]),
+                        });
+                    }
+                }
+            }
+        }
+        elements
+    }
+}
+
+impl<'l> Writeable for FormattedDuration<'l> {
+    fn write_to_parts<S: writeable::PartsWrite + ?Sized>(&self, sink: &mut S) -> core::fmt::Result {
+        let elements = self.elements();
+        self.formatter
+            .list_formatter
+            .format(elements.iter())
+            .write_to_parts(sink)
+    }
+}
+
+writeable::impl_display_with_writeable!(FormattedDuration<'_>);
@@ -0,0 +1,317 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::vec::Vec;
+use icu_decimal::{provider::DecimalSymbolsV1Marker, FixedDecimalFormatter};
+use icu_list::{provider::UnitListV1Marker, ListFormatter, ListLength};
+use icu_plurals::provider::CardinalV1Marker;
+use icu_provider::prelude::*;
+use icu_unitsformatter::provider::{
+    LongUnitsDisplayNamesV1Marker, NarrowUnitsDisplayNamesV1Marker, ShortUnitsDisplayNamesV1Marker,
+};
+use icu_unitsformatter::UnitFormatter;
+
+use crate::format::FormattedDuration;
+use crate::options::{BaseStyle, DurationFormatterOptions, FieldDisplay, FieldStyle};
+use crate::provider::*;
+use crate::{Duration, DurationFormatterError};
+
+/// The CLDR unit identifiers of the fields of a duration, from years to nanoseconds.
+const UNITS: [&str; 10] = [
+    "year",
+    "month",
+    "week",
+    "day",
+    "hour",
+    "minute",
+    "second",
+    "millisecond",
+    "microsecond",
+    "nanosecond",
+];
+
+/// The index of hours in [`UNITS`].
+pub(crate) const HOURS: usize = 4;
+/// The index of minutes in [`UNITS`].
+pub(crate) const MINUTES: usize = 5;
+/// The index of seconds in [`UNITS`].
+pub(crate) const SECONDS: usize = 6;
+
+/// The width of a unit name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Width {
+    Long,
+    Short,
+    Narrow,
+}
+
+/// The resolved style of a field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Style {
+    Unit(Width),
+    Numeric,
+    TwoDigit,
+    /// Decimal digits of the preceding field, e.g. the milliseconds in "5.123 sec".
+    Fractional,
+}
+
+impl Style {
+    fn is_numeric(self) -> bool {
+        matches!(self, Style::Numeric | Style::TwoDigit | Style::Fractional)
+    }
+}
+
+/// The resolved options of a field.
+#[derive(Debug)]
+pub(crate) struct Field {
+    pub(crate) style: Style,
+    pub(crate) display: FieldDisplay,
+    /// The formatter of fields that are displayed with a unit name.
+    pub(crate) unit_formatter: Option<UnitFormatter>,
+}
+
+/// A formatter for durations, such as "1 hr, 20 min" or "1:20:05".
+///
+/// [`DurationFormatter`] follows ECMA-402 `Intl.DurationFormat`: the fields of a duration
+/// are formatted with their unit names and joined as a unit list, except for hours, minutes
+/// and seconds in the digital style, which are formatted as digits.
+///
+/// # Examples
+///
+/// ```
+/// use icu_durationformat::options::BaseStyle;
+/// use icu_durationformat::{Duration, DurationFormatter};
+/// use icu_locid::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let duration = Duration {
+///     hours: 1,
+///     minutes: 20,
+///     seconds: 5,
+///     ..Duration::new()
+/// };
+///
+/// let formatter =
+///     DurationFormatter::try_new(&locale!("en").into(), BaseStyle::Long.into())
+///         .unwrap();
+/// assert_writeable_eq!(
+///     formatter.format(duration),
+///     "1 hour, 20 minutes, 5 seconds"
+/// );
+///
+/// let formatter =
+///     DurationFormatter::try_new(&locale!("en").into(), BaseStyle::Digital.into())
+///         .unwrap();
+/// assert_writeable_eq!(formatter.format(duration), "1:20:05");
+/// ```
+#[derive(Debug)]
+pub struct DurationFormatter {
+    /// The fields from years to nanoseconds.
+    pub(crate) fields: Vec<Field>,
+    pub(crate) fractional_digits: Option<u8>,
+    pub(crate) digital: DataPayload<DigitalDurationDataV1Marker>,
+    pub(crate) fixed_decimal_format: FixedDecimalFormatter,
+    pub(crate) list_formatter: ListFormatter,
+}
+
+impl DurationFormatter {
+    /// Creates a new [`DurationFormatter`] from compiled data.
+    ///
+    /// Returns [`DurationFormatterError::InvalidOptions`] if the options are inconsistent.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new(
+        locale: &DataLocale,
+        options: DurationFormatterOptions,
+    ) -> Result<Self, DurationFormatterError> {
+        Self::try_new_internal(
+            options,
+            |width, unit| {
+                let unit = unit.parse()?;
+                match width {
+                    Width::Long => UnitFormatter::try_new_long(locale, unit, Default::default()),
+                    Width::Short => UnitFormatter::try_new_short(locale, unit, Default::default()),
+                    Width::Narrow => {
+                        UnitFormatter::try_new_narrow(locale, unit, Default::default())
+                    }
+                }
+            },
+            |length| ListFormatter::try_new_unit_with_length(locale, length),
+            crate::provider::Baked.load(DataRequest {
+                locale,
+                metadata: Default::default(),
+            })?,
+            FixedDecimalFormatter::try_new(locale, Default::default())?,
+        )
+    }
+
+    icu_provider::gen_any_buffer_data_constructors!(
+        locale: include,
+        options: DurationFormatterOptions,
+        error: DurationFormatterError,
+        #[cfg(skip)]
+        functions: [
+            try_new,
+            try_new_with_any_provider,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self,
+        ]
+    );
+
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<D>(
+        provider: &D,
+        locale: &DataLocale,
+        options: DurationFormatterOptions,
+    ) -> Result<Self, DurationFormatterError>
+    where
+        D: DataProvider<DigitalDurationDataV1Marker>
+            + DataProvider<LongUnitsDisplayNamesV1Marker>
+            + DataProvider<ShortUnitsDisplayNamesV1Marker>
+            + DataProvider<NarrowUnitsDisplayNamesV1Marker>
+            + DataProvider<UnitListV1Marker>
+            + DataProvider<CardinalV1Marker>
+            + DataProvider<DecimalSymbolsV1Marker>
+            + ?Sized,
+    {
+        Self::try_new_internal(
+            options,
+            |width, unit| {
+                let unit = unit.parse()?;
+                match width {
+                    Width::Long => UnitFormatter::try_new_long_unstable(
+                        provider,
+                        locale,
+                        unit,
+                        Default::default(),
+                    ),
+                    Width::Short => UnitFormatter::try_new_short_unstable(
+                        provider,
+                        locale,
+                        unit,
+                        Default::default(),
+                    ),
+                    Width::Narrow => UnitFormatter::try_new_narrow_unstable(
+                        provider,
+                        locale,
+                        unit,
+                        Default::default(),
+                    ),
+                }
+            },
+            |length| ListFormatter::try_new_unit_with_length_unstable(provider, locale, length),
+            provider.load(DataRequest {
+                locale,
+                metadata: Default::default(),
+            })?,
+            FixedDecimalFormatter::try_new_unstable(provider, locale, Default::default())?,
+        )
+    }
+
+    fn try_new_internal(
+        options: DurationFormatterOptions,
+        unit_formatter: impl Fn(Width, &str) -> Result<UnitFormatter, icu_unitsformatter::Error>,
+        list_formatter: impl FnOnce(ListLength) -> Result<ListFormatter, icu_list::ListError>,
+        digital: DataResponse<DigitalDurationDataV1Marker>,
+        fixed_decimal_format: FixedDecimalFormatter,
+    ) -> Result<Self, DurationFormatterError> {
+        if options.fractional_digits.map_or(false, |digits| digits > 9) {
+            return Err(DurationFormatterError::InvalidOptions);
+        }
+        let base_width = match options.base {
+            BaseStyle::Long => Width::Long,
+            BaseStyle::Short | BaseStyle::Digital => Width::Short,
+            BaseStyle::Narrow => Width::Narrow,
+        };
+
+        // Resolves the style and display of each field as in ECMA-402 GetDurationUnitOptions.
+        let mut fields = Vec::with_capacity(UNITS.len());
+        let mut previous: Option<Style> = None;
+        for (index, ((style, display), unit)) in options.fields().into_iter().zip(UNITS).enumerate()
+        {
+            let is_time = (HOURS..=SECONDS).contains(&index);
+            let is_subsecond = index > SECONDS;
+            let previous_is_numeric = previous.map_or(false, Style::is_numeric);
+            let (mut style, display_default) = match style {
+                Some(FieldStyle::Long) => (Style::Unit(Width::Long), FieldDisplay::Always),
+                Some(FieldStyle::Short) => (Style::Unit(Width::Short), FieldDisplay::Always),
+                Some(FieldStyle::Narrow) => (Style::Unit(Width::Narrow), FieldDisplay::Always),
+                Some(FieldStyle::Numeric) if is_time || is_subsecond => {
+                    (Style::Numeric, FieldDisplay::Always)
+                }
+                Some(FieldStyle::TwoDigit) if is_time => (Style::TwoDigit, FieldDisplay::Always),
+                Some(_) => return Err(DurationFormatterError::InvalidOptions),
+                None if options.base == BaseStyle::Digital => {
+                    if is_time {
+                        (Style::Numeric, FieldDisplay::Always)
+                    } else if is_subsecond {
+                        (Style::Numeric, FieldDisplay::Auto)
+                    } else {
+                        (Style::Unit(Width::Short), FieldDisplay::Auto)
+                    }
+                }
+                None if previous_is_numeric => {
+                    if index == MINUTES || index == SECONDS {
+                        (Style::Numeric, FieldDisplay::Always)
+                    } else {
+                        (Style::Numeric, FieldDisplay::Auto)
+                    }
+                }
+                None => (Style::Unit(base_width), FieldDisplay::Auto),
+            };
+            let mut display_default = display_default;
+            if is_subsecond && style == Style::Numeric {
+                style = Style::Fractional;
+                display_default = FieldDisplay::Auto;
+            }
+            if previous == Some(Style::Fractional) && style != Style::Fractional {
+                return Err(DurationFormatterError::InvalidOptions);
+            }
+            if previous_is_numeric {
+                if !style.is_numeric() {
+                    return Err(DurationFormatterError::InvalidOptions);
+                }
+                if index == MINUTES || index == SECONDS {
+                    style = Style::TwoDigit;
+                }
+            }
+            previous = Some(style);
+
+            let unit_formatter = match style {
+                Style::Unit(width) => Some(unit_formatter(width, unit)?),
+                _ => None,
+            };
+            fields.push(Field {
+                style,
+                display: display.unwrap_or(display_default),
+                unit_formatter,
+            });
+        }
+
+        let list_length = match options.base {
+            BaseStyle::Long => ListLength::Wide,
+            BaseStyle::Short | BaseStyle::Digital => ListLength::Short,
+            BaseStyle::Narrow => ListLength::Narrow,
+        };
+        Ok(Self {
+            fields,
+            fractional_digits: options.fractional_digits,
+            digital: digital.take_payload()?,
+            fixed_decimal_format,
+            list_formatter: list_formatter(list_length)?,
+        })
+    }
+
+    /// Formats a duration.
+    pub fn format(&self, duration: Duration) -> FormattedDuration<'_> {
+        FormattedDuration {
+            formatter: self,
+            duration,
+        }
+    }
+}
@@ -0,0 +1,77 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! 🚧 \[Experimental\] Duration formatting
+//!
+//! This module is published as its own crate ([`icu_durationformat`](https://docs.rs/icu_durationformat/latest/icu_durationformat/)).
+//!
+//! [`DurationFormatter`] formats durations, such as "1 hr, 20 min, 5 sec" or "1:20:05",
+//! following ECMA-402 `Intl.DurationFormat`. The style and display of each field, from years
+//! to nanoseconds, can be configured with [`DurationFormatterOptions`].
+//!
+//! # Examples
+//!
+//! ```
+//! use icu_durationformat::options::{BaseStyle, FieldStyle};
+//! use icu_durationformat::{Duration, DurationFormatter, DurationFormatterOptions};
+//! use icu_locid::locale;
+//! use writeable::assert_writeable_eq;
+//!
+//! let duration = Duration {
+//!     minutes: 3,
+//!     seconds: 7,
+//!     milliseconds: 250,
+//!     ..Duration::new()
+//! };
+//!
+//! let formatter =
+//!     DurationFormatter::try_new(&locale!("en").into(), BaseStyle::Short.into())
+//!         .unwrap();
+//! assert_writeable_eq!(formatter.format(duration), "3 min, 7 sec, 250 ms");
+//!
+//! let mut options = DurationFormatterOptions::from(BaseStyle::Long);
+//! options.milliseconds = Some(FieldStyle::Numeric);
+//! let formatter =
+//!     DurationFormatter::try_new(&locale!("en").into(), options).unwrap();
+//! assert_writeable_eq!(formatter.format(duration), "3 minutes, 7.25 seconds");
+//! ```
+//!
+//! <div class="stab unstable">
+//! 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+//! including in SemVer minor releases. Use with caution.
+//! </div>
+
+// https://github.com/unicode-org/icu4x/blob/main/docs/process/boilerplate.md#library-annotations
+#![cfg_attr(not(test), no_std)]
+#![cfg_attr(
+    not(test),
+    deny(
+        clippy::indexing_slicing,
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::exhaustive_structs,
+        clippy::exhaustive_enums,
+        missing_debug_implementations,
+    )
+)]
+#![warn(missing_docs)]
+
+extern crate alloc;
+
+mod duration;
+mod error;
+mod format;
+mod formatter;
+pub mod options;
+pub mod provider;
+
+pub use duration::{Duration, DurationSign};
+pub use error::DurationFormatterError;
+pub use format::parts;
+pub use format::FormattedDuration;
+pub use formatter::DurationFormatter;
+pub use options::DurationFormatterOptions;
+#[doc(no_inline)]
+pub use DurationFormatterError as Error;
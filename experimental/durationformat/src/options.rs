@@ -0,0 +1,148 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Options for configuring [`DurationFormatter`](crate::DurationFormatter).
+
+/// The style of a [`DurationFormatter`](crate::DurationFormatter), which is the default
+/// style of all fields.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum BaseStyle {
+    /// Long unit names, e.g. "1 hour, 20 minutes, 5 seconds".
+    Long,
+    /// Short unit names, e.g. "1 hr, 20 min, 5 sec".
+    #[default]
+    Short,
+    /// Narrow unit names, e.g. "1h 20m 5s".
+    Narrow,
+    /// Hours, minutes and seconds as digits, e.g. "1:20:05"; other fields use short
+    /// unit names.
+    Digital,
+}
+
+/// The style of a single field of a duration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FieldStyle {
+    /// A long unit name, e.g. "20 minutes".
+    Long,
+    /// A short unit name, e.g. "20 min".
+    Short,
+    /// A narrow unit name, e.g. "20m".
+    Narrow,
+    /// Digits only, e.g. "5" in "1:20:05".
+    ///
+    /// Only valid for hours, minutes, seconds and fractions of seconds. Fractions of seconds
+    /// are appended to the preceding field as decimal digits, e.g. "5.5 sec".
+    Numeric,
+    /// Two digits, e.g. "05" in "1:20:05".
+    ///
+    /// Only valid for hours, minutes and seconds.
+    TwoDigit,
+}
+
+/// Whether a field of a duration is displayed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FieldDisplay {
+    /// The field is only displayed if it is not zero.
+    Auto,
+    /// The field is always displayed.
+    Always,
+}
+
+/// A bag of options defining how durations will be formatted by
+/// [`DurationFormatter`](crate::DurationFormatter).
+///
+/// The options follow those of ECMA-402 `Intl.DurationFormat`: fields whose style is not set
+/// use the base style, or the numeric style if they follow a numeric field; fields whose
+/// display is not set are displayed if they are not zero, except for hours, minutes and seconds
+/// in the digital style, which are always displayed.
+///
+/// # Examples
+///
+/// ```
+/// use icu_durationformat::options::{BaseStyle, DurationFormatterOptions, FieldDisplay};
+///
+/// let mut options = DurationFormatterOptions::from(BaseStyle::Long);
+/// options.seconds_display = Some(FieldDisplay::Always);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub struct DurationFormatterOptions {
+    /// The default style of all fields.
+    pub base: BaseStyle,
+    /// The style of years.
+    pub years: Option<FieldStyle>,
+    /// Whether years are displayed.
+    pub years_display: Option<FieldDisplay>,
+    /// The style of months.
+    pub months: Option<FieldStyle>,
+    /// Whether months are displayed.
+    pub months_display: Option<FieldDisplay>,
+    /// The style of weeks.
+    pub weeks: Option<FieldStyle>,
+    /// Whether weeks are displayed.
+    pub weeks_display: Option<FieldDisplay>,
+    /// The style of days.
+    pub days: Option<FieldStyle>,
+    /// Whether days are displayed.
+    pub days_display: Option<FieldDisplay>,
+    /// The style of hours.
+    pub hours: Option<FieldStyle>,
+    /// Whether hours are displayed.
+    pub hours_display: Option<FieldDisplay>,
+    /// The style of minutes.
+    pub minutes: Option<FieldStyle>,
+    /// Whether minutes are displayed.
+    pub minutes_display: Option<FieldDisplay>,
+    /// The style of seconds.
+    pub seconds: Option<FieldStyle>,
+    /// Whether seconds are displayed.
+    pub seconds_display: Option<FieldDisplay>,
+    /// The style of milliseconds.
+    pub milliseconds: Option<FieldStyle>,
+    /// Whether milliseconds are displayed.
+    pub milliseconds_display: Option<FieldDisplay>,
+    /// The style of microseconds.
+    pub microseconds: Option<FieldStyle>,
+    /// Whether microseconds are displayed.
+    pub microseconds_display: Option<FieldDisplay>,
+    /// The style of nanoseconds.
+    pub nanoseconds: Option<FieldStyle>,
+    /// Whether nanoseconds are displayed.
+    pub nanoseconds_display: Option<FieldDisplay>,
+    /// The number of digits of the fractions of seconds that are appended to a field with
+    /// the [`FieldStyle::Numeric`] style, which must be at most 9.
+    ///
+    /// By default, as many digits as needed are displayed.
+    pub fractional_digits: Option<u8>,
+}
+
+impl DurationFormatterOptions {
+    /// Returns the style and display of each field, from years to nanoseconds.
+    pub(crate) fn fields(&self) -> [(Option<FieldStyle>, Option<FieldDisplay>); 10] {
+        [
+            (self.years, self.years_display),
+            (self.months, self.months_display),
+            (self.weeks, self.weeks_display),
+            (self.days, self.days_display),
+            (self.hours, self.hours_display),
+            (self.minutes, self.minutes_display),
+            (self.seconds, self.seconds_display),
+            (self.milliseconds, self.milliseconds_display),
+            (self.microseconds, self.microseconds_display),
+            (self.nanoseconds, self.nanoseconds_display),
+        ]
+    }
+}
+
+impl From<BaseStyle> for DurationFormatterOptions {
+    fn from(base: BaseStyle) -> Self {
+        Self {
+            base,
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,257 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_calendar::{DateDuration, Iso};
+use icu_durationformat::options::{BaseStyle, FieldDisplay, FieldStyle};
+use icu_durationformat::{
+    parts, Duration, DurationFormatter, DurationFormatterError, DurationFormatterOptions,
+    DurationSign,
+};
+use icu_locid::locale;
+use writeable::{assert_writeable_eq, assert_writeable_parts_eq};
+
+const DURATION: Duration = Duration {
+    sign: DurationSign::Positive,
+    years: 0,
+    months: 0,
+    weeks: 0,
+    days: 2,
+    hours: 1,
+    minutes: 0,
+    seconds: 5,
+    milliseconds: 250,
+    microseconds: 0,
+    nanoseconds: 0,
+};
+
+fn formatter(options: impl Into<DurationFormatterOptions>) -> DurationFormatter {
+    DurationFormatter::try_new(&locale!("en").into(), options.into()).unwrap()
+}
+
+/// Formats a duration in English and compares it with the expected string.
+macro_rules! assert_format {
+    ($options: expr, $duration: expr, $expected: expr) => {
+        let formatter = formatter($options);
+        assert_writeable_eq!(formatter.format($duration), $expected);
+    };
+}
+
+#[test]
+fn test_en_styles() {
+    assert_format!(
+        BaseStyle::Long,
+        DURATION,
+        "2 days, 1 hour, 5 seconds, 250 milliseconds"
+    );
+    assert_format!(BaseStyle::Short, DURATION, "2 days, 1 hr, 5 sec, 250 ms");
+    assert_format!(BaseStyle::Narrow, DURATION, "2d 1h 5s 250ms");
+    assert_format!(BaseStyle::Digital, DURATION, "2 days, 1:00:05.25");
+}
+
+#[test]
+fn test_fr() {
+    let formatter =
+        DurationFormatter::try_new(&locale!("fr").into(), BaseStyle::Long.into()).unwrap();
+    assert_writeable_eq!(
+        formatter.format(DURATION),
+        "2\u{a0}jours, 1\u{a0}heure, 5\u{a0}secondes et 250\u{a0}millisecondes"
+    );
+}
+
+#[test]
+fn test_digital_separator() {
+    let duration = Duration {
+        hours: 1,
+        minutes: 20,
+        seconds: 5,
+        ..Duration::new()
+    };
+    let formatter =
+        DurationFormatter::try_new(&locale!("fi").into(), BaseStyle::Digital.into()).unwrap();
+    assert_writeable_eq!(formatter.format(duration), "1.20.05");
+}
+
+#[test]
+fn test_fractional() {
+    let mut options = DurationFormatterOptions::from(BaseStyle::Long);
+    options.milliseconds = Some(FieldStyle::Numeric);
+    assert_format!(options, DURATION, "2 days, 1 hour, 5.25 seconds");
+
+    options.fractional_digits = Some(3);
+    assert_format!(options, DURATION, "2 days, 1 hour, 5.250 seconds");
+
+    options.fractional_digits = Some(0);
+    assert_format!(options, DURATION, "2 days, 1 hour, 5 seconds");
+
+    let mut options = DurationFormatterOptions::from(BaseStyle::Digital);
+    options.fractional_digits = Some(1);
+    let duration = Duration {
+        nanoseconds: 999_999,
+        ..DURATION
+    };
+    assert_format!(options, duration, "2 days, 1:00:05.2");
+
+    options.fractional_digits = Some(9);
+    assert_format!(options, duration, "2 days, 1:00:05.250999999");
+}
+
+#[test]
+fn test_digital_fields() {
+    let duration = Duration {
+        minutes: 20,
+        seconds: 5,
+        ..Duration::new()
+    };
+
+    // Hours are always displayed in the digital style.
+    assert_format!(BaseStyle::Digital, duration, "0:20:05");
+
+    let mut options = DurationFormatterOptions::from(BaseStyle::Short);
+    options.minutes = Some(FieldStyle::Numeric);
+    assert_format!(options, duration, "20:05");
+
+    options.hours = Some(FieldStyle::Long);
+    assert_format!(options, duration, "0 hours, 20:05");
+
+    let mut options = DurationFormatterOptions::from(BaseStyle::Short);
+    options.hours = Some(FieldStyle::TwoDigit);
+    options.seconds_display = Some(FieldDisplay::Auto);
+    assert_format!(
+        options,
+        Duration {
+            hours: 1,
+            ..Duration::new()
+        },
+        "01:00"
+    );
+    assert_format!(
+        options,
+        Duration {
+            hours: 1,
+            seconds: 5,
+            ..Duration::new()
+        },
+        "01:00:05"
+    );
+}
+
+#[test]
+fn test_display() {
+    let duration = Duration {
+        hours: 1,
+        ..Duration::new()
+    };
+    assert_format!(BaseStyle::Short, duration, "1 hr");
+
+    let mut options = DurationFormatterOptions::from(BaseStyle::Short);
+    options.minutes_display = Some(FieldDisplay::Always);
+    assert_format!(options, duration, "1 hr, 0 min");
+
+    // An explicit style makes a field always displayed.
+    options.seconds = Some(FieldStyle::Narrow);
+    assert_format!(options, duration, "1 hr, 0 min, 0s");
+
+    options.seconds_display = Some(FieldDisplay::Auto);
+    assert_format!(options, duration, "1 hr, 0 min");
+
+    assert_format!(BaseStyle::Short, Duration::new(), "");
+}
+
+#[test]
+fn test_negative() {
+    let duration = Duration {
+        sign: DurationSign::Negative,
+        ..DURATION
+    };
+    assert_format!(BaseStyle::Short, duration, "-2 days, 1 hr, 5 sec, 250 ms");
+
+    let duration = Duration {
+        days: 0,
+        ..duration
+    };
+    assert_format!(BaseStyle::Digital, duration, "-1:00:05.25");
+}
+
+#[test]
+fn test_date_duration() {
+    let duration: DateDuration<Iso> = DateDuration::new(-1, 0, -2, 0);
+    let duration = Duration::try_from(duration).unwrap();
+    assert_eq!(duration.sign, DurationSign::Negative);
+    assert_format!(BaseStyle::Long, duration, "-1 year, 2 weeks");
+
+    let duration: DateDuration<Iso> = DateDuration::new(1, 0, -2, 0);
+    assert_eq!(
+        Duration::try_from(duration),
+        Err(DurationFormatterError::MixedSigns)
+    );
+}
+
+#[test]
+fn test_parts() {
+    let duration = Duration {
+        days: 1,
+        hours: 1,
+        minutes: 20,
+        seconds: 5,
+        ..Duration::new()
+    };
+
+    let formatter = formatter(BaseStyle::Digital);
+    assert_writeable_parts_eq!(
+        formatter.format(duration),
+        "1 day, 1:20:05",
+        [
+            (0, 5, icu_list::parts::ELEMENT),
+            (0, 1, icu_decimal::parts::INTEGER),
+            (1, 2, icu_unitsformatter::parts::LITERAL),
+            (2, 5, icu_unitsformatter::parts::UNIT),
+            (5, 7, icu_list::parts::LITERAL),
+            (7, 14, icu_list::parts::ELEMENT),
+            (7, 8, icu_decimal::parts::INTEGER),
+            (8, 9, parts::SEPARATOR),
+            (9, 11, icu_decimal::parts::INTEGER),
+            (11, 12, parts::SEPARATOR),
+            (12, 14, icu_decimal::parts::INTEGER),
+        ]
+    );
+}
+
+#[test]
+fn test_invalid_options() {
+    let mut options = Vec::new();
+
+    // Numeric days
+    let mut days = DurationFormatterOptions::from(BaseStyle::Long);
+    days.days = Some(FieldStyle::Numeric);
+    options.push(days);
+
+    // Two-digit milliseconds
+    let mut milliseconds = DurationFormatterOptions::from(BaseStyle::Long);
+    milliseconds.milliseconds = Some(FieldStyle::TwoDigit);
+    options.push(milliseconds);
+
+    // A unit name after a numeric field
+    let mut seconds = DurationFormatterOptions::from(BaseStyle::Long);
+    seconds.minutes = Some(FieldStyle::Numeric);
+    seconds.seconds = Some(FieldStyle::Short);
+    options.push(seconds);
+
+    // A unit name after a fractional field
+    let mut microseconds = DurationFormatterOptions::from(BaseStyle::Digital);
+    microseconds.microseconds = Some(FieldStyle::Short);
+    options.push(microseconds);
+
+    // More than nine fractional digits
+    let mut fractional_digits = DurationFormatterOptions::from(BaseStyle::Digital);
+    fractional_digits.fractional_digits = Some(10);
+    options.push(fractional_digits);
+
+    for options in options {
+        assert_eq!(
+            DurationFormatter::try_new(&locale!("en").into(), options).unwrap_err(),
+            DurationFormatterError::InvalidOptions,
+            "{options:?}"
+        );
+    }
+}
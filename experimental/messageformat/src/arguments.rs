@@ -0,0 +1,100 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::vec::Vec;
+use fixed_decimal::FixedDecimal;
+use icu_calendar::{AnyCalendar, DateTime};
+
+/// A value that is passed to a message as an argument.
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub enum MessageArgument<'a> {
+    /// A string, which is formatted as is by unannotated placeholders and by `:string`, and
+    /// is parsed as a number by `:number`.
+    String(&'a str),
+    /// A number, which is formatted by unannotated placeholders and by `:number`.
+    Number(&'a FixedDecimal),
+    /// A date and time, which is formatted by `:datetime`.
+    DateTime(&'a DateTime<AnyCalendar>),
+    /// A list of strings, which is formatted by `:list`.
+    List(&'a [&'a str]),
+}
+
+impl<'a> From<&'a str> for MessageArgument<'a> {
+    fn from(value: &'a str) -> Self {
+        MessageArgument::String(value)
+    }
+}
+
+impl<'a> From<&'a FixedDecimal> for MessageArgument<'a> {
+    fn from(value: &'a FixedDecimal) -> Self {
+        MessageArgument::Number(value)
+    }
+}
+
+impl<'a> From<&'a DateTime<AnyCalendar>> for MessageArgument<'a> {
+    fn from(value: &'a DateTime<AnyCalendar>) -> Self {
+        MessageArgument::DateTime(value)
+    }
+}
+
+impl<'a> From<&'a [&'a str]> for MessageArgument<'a> {
+    fn from(value: &'a [&'a str]) -> Self {
+        MessageArgument::List(value)
+    }
+}
+
+/// The named arguments of a message.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+/// use icu_messageformat::{MessageArgument, MessageArguments};
+///
+/// let count = FixedDecimal::from(3);
+/// let arguments = MessageArguments::new()
+///     .with("name", "Alice")
+///     .with("count", &count);
+///
+/// assert!(matches!(
+///     arguments.get("name"),
+///     Some(MessageArgument::String("Alice"))
+/// ));
+/// assert!(arguments.get("other").is_none());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MessageArguments<'a> {
+    values: Vec<(&'a str, MessageArgument<'a>)>,
+}
+
+impl<'a> MessageArguments<'a> {
+    /// Creates an empty set of arguments.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an argument, replacing any previous argument with the same name.
+    pub fn with(mut self, name: &'a str, value: impl Into<MessageArgument<'a>>) -> Self {
+        self.insert(name, value);
+        self
+    }
+
+    /// Adds an argument, replacing any previous argument with the same name.
+    pub fn insert(&mut self, name: &'a str, value: impl Into<MessageArgument<'a>>) {
+        let value = value.into();
+        match self.values.iter_mut().find(|(n, _)| *n == name) {
+            Some(entry) => entry.1 = value,
+            None => self.values.push((name, value)),
+        }
+    }
+
+    /// Returns the argument with the given name.
+    pub fn get(&self, name: &str) -> Option<MessageArgument<'a>> {
+        self.values
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map(|(_, value)| *value)
+    }
+}
@@ -0,0 +1,127 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! The data model of a parsed message.
+//!
+//! A [`Message`] is obtained with [`parse`](crate::parse()) and formatted with
+//! [`MessageFormatter`](crate::MessageFormatter).
+//!
+//! More information: <https://github.com/unicode-org/message-format-wg/blob/main/spec/data-model/README.md>
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// A parsed message: its local variable declarations, followed by either a single pattern
+/// or a selection between several variants.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::exhaustive_structs)] // data model defined by the specification
+pub struct Message {
+    /// The `let` declarations of the message, in order.
+    pub declarations: Vec<Declaration>,
+    /// The body of the message.
+    pub body: Body,
+}
+
+/// A `let` declaration, which binds a local variable to the result of an expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::exhaustive_structs)] // data model defined by the specification
+pub struct Declaration {
+    /// The name of the local variable, without the `$`.
+    pub variable: String,
+    /// The expression whose result is bound to the variable.
+    pub value: Expression,
+}
+
+/// The body of a [`Message`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::exhaustive_enums)] // data model defined by the specification
+pub enum Body {
+    /// A single pattern, such as `{Hello, {$name}!}`.
+    Pattern(Pattern),
+    /// A `match` statement that selects one of several variants.
+    Select {
+        /// The expressions whose results select the variant.
+        selectors: Vec<Expression>,
+        /// The variants, each with one key per selector.
+        variants: Vec<Variant>,
+    },
+}
+
+/// One of the variants of a `match` statement, such as `when one {{$count} item}`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::exhaustive_structs)] // data model defined by the specification
+pub struct Variant {
+    /// The keys of the variant, one per selector.
+    pub keys: Vec<VariantKey>,
+    /// The pattern that is formatted when the variant is selected.
+    pub pattern: Pattern,
+}
+
+/// The key of a [`Variant`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::exhaustive_enums)] // data model defined by the specification
+pub enum VariantKey {
+    /// A key that matches values for which the selector function accepts the literal.
+    Literal(String),
+    /// The catch-all key `*`.
+    Wildcard,
+}
+
+/// A sequence of text and placeholders, enclosed in braces.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[allow(clippy::exhaustive_structs)] // data model defined by the specification
+pub struct Pattern {
+    /// The parts of the pattern, in order.
+    pub parts: Vec<PatternPart>,
+}
+
+/// A part of a [`Pattern`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::exhaustive_enums)] // data model defined by the specification
+pub enum PatternPart {
+    /// Text, with escape sequences resolved.
+    Text(String),
+    /// A placeholder, such as `{$count :number}`.
+    Expression(Expression),
+}
+
+/// An expression: an operand, an annotation, or an operand with an annotation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::exhaustive_structs)] // data model defined by the specification
+pub struct Expression {
+    /// The value the expression operates on, if any.
+    pub operand: Option<Operand>,
+    /// The function applied to the operand, if any.
+    pub annotation: Option<Annotation>,
+}
+
+/// The operand of an [`Expression`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::exhaustive_enums)] // data model defined by the specification
+pub enum Operand {
+    /// A literal, such as `|hello world|` or `42`, with escape sequences resolved.
+    Literal(String),
+    /// A variable, such as `$count`, without the `$`.
+    Variable(String),
+}
+
+/// A function annotation, such as `:number maximumFractionDigits=2`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::exhaustive_structs)] // data model defined by the specification
+pub struct Annotation {
+    /// The name of the function, including its sigil, such as `:number`.
+    pub function: String,
+    /// The options of the function, in order.
+    pub options: Vec<FunctionOption>,
+}
+
+/// An option of an [`Annotation`], such as `maximumFractionDigits=2`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::exhaustive_structs)] // data model defined by the specification
+pub struct FunctionOption {
+    /// The name of the option.
+    pub name: String,
+    /// The value of the option.
+    pub value: Operand,
+}
@@ -0,0 +1,77 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use displaydoc::Display;
+use icu_datetime::DateTimeError;
+use icu_decimal::DecimalError;
+use icu_list::ListError;
+use icu_plurals::PluralsError;
+use icu_provider::DataError;
+
+/// A list of error outcomes for various operations in this module.
+///
+/// Re-exported as [`Error`](crate::Error).
+#[derive(Display, Debug, Copy, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum MessageFormatError {
+    /// The message is not valid MessageFormat 2.0 syntax. Contains the byte offset at which
+    /// parsing failed.
+    #[displaydoc("Syntax error at offset {0}")]
+    Syntax(usize),
+    /// The message uses a function that is not supported.
+    #[displaydoc("Unknown function")]
+    UnknownFunction,
+    /// The message passes an option that is not supported, or a value that is not valid for
+    /// it, to a function.
+    #[displaydoc("Invalid function option")]
+    InvalidOption,
+    /// An error originating from [`DataProvider`](icu_provider::DataProvider).
+    #[displaydoc("Error loading data: {0}")]
+    Data(DataError),
+    /// An error originating from [`FixedDecimalFormatter`](icu_decimal::FixedDecimalFormatter).
+    #[displaydoc("Error loading FixedDecimalFormatter: {0}")]
+    Decimal(DecimalError),
+    /// An error originating from [`PluralRules`](icu_plurals::PluralRules).
+    #[displaydoc("Error loading plural rules: {0}")]
+    PluralRules(PluralsError),
+    /// An error originating from [`DateTimeFormatter`](icu_datetime::DateTimeFormatter).
+    #[displaydoc("Error loading DateTimeFormatter: {0}")]
+    DateTime(DateTimeError),
+    /// An error originating from [`ListFormatter`](icu_list::ListFormatter).
+    #[displaydoc("Error loading ListFormatter: {0}")]
+    List(ListError),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MessageFormatError {}
+
+impl From<DataError> for MessageFormatError {
+    fn from(e: DataError) -> Self {
+        MessageFormatError::Data(e)
+    }
+}
+
+impl From<DecimalError> for MessageFormatError {
+    fn from(e: DecimalError) -> Self {
+        MessageFormatError::Decimal(e)
+    }
+}
+
+impl From<PluralsError> for MessageFormatError {
+    fn from(e: PluralsError) -> Self {
+        MessageFormatError::PluralRules(e)
+    }
+}
+
+impl From<DateTimeError> for MessageFormatError {
+    fn from(e: DateTimeError) -> Self {
+        MessageFormatError::DateTime(e)
+    }
+}
+
+impl From<ListError> for MessageFormatError {
+    fn from(e: ListError) -> Self {
+        MessageFormatError::List(e)
+    }
+}
@@ -0,0 +1,310 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::arguments::{MessageArgument, MessageArguments};
+use crate::ast::{Operand, VariantKey};
+use crate::formatter::*;
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use core::fmt::{self, Write};
+use fixed_decimal::FixedDecimal;
+use icu_calendar::{AnyCalendar, DateTime};
+use icu_plurals::PluralCategory;
+use writeable::{PartsWrite, Writeable};
+
+/// The [`Part`](writeable::Part)s used by [`FormattedMessage`].
+pub mod parts {
+    use writeable::Part;
+
+    /// The [`Part`] used to mark text from the message pattern.
+    ///
+    /// * `category`: `"messageformat"`
+    /// * `value`: `"literal"`
+    pub const LITERAL: Part = Part {
+        category: "messageformat",
+        value: "literal",
+    };
+
+    /// The [`Part`] used to mark a placeholder that is formatted as a string.
+    ///
+    /// * `category`: `"messageformat"`
+    /// * `value`: `"string"`
+    pub const STRING: Part = Part {
+        category: "messageformat",
+        value: "string",
+    };
+
+    /// The [`Part`] used to mark a placeholder that is formatted as a number.
+    ///
+    /// * `category`: `"messageformat"`
+    /// * `value`: `"number"`
+    pub const NUMBER: Part = Part {
+        category: "messageformat",
+        value: "number",
+    };
+
+    /// The [`Part`] used to mark a placeholder that is formatted as a date and time.
+    ///
+    /// * `category`: `"messageformat"`
+    /// * `value`: `"datetime"`
+    pub const DATETIME: Part = Part {
+        category: "messageformat",
+        value: "datetime",
+    };
+
+    /// The [`Part`] used to mark a placeholder that is formatted as a list.
+    ///
+    /// * `category`: `"messageformat"`
+    /// * `value`: `"list"`
+    pub const LIST: Part = Part {
+        category: "messageformat",
+        value: "list",
+    };
+
+    /// The [`Part`] used to mark the fallback representation of a placeholder that could not
+    /// be formatted, such as `{$name}`.
+    ///
+    /// * `category`: `"messageformat"`
+    /// * `value`: `"fallback"`
+    pub const FALLBACK: Part = Part {
+        category: "messageformat",
+        value: "fallback",
+    };
+}
+
+/// The [`Writeable`] implementation that is returned by
+/// [`MessageFormatter::format`](crate::MessageFormatter::format). See the [`writeable`] crate
+/// for how to consume this.
+#[derive(Debug)]
+pub struct FormattedMessage<'l> {
+    pub(crate) formatter: &'l MessageFormatter,
+    pub(crate) arguments: &'l MessageArguments<'l>,
+}
+
+/// The value of an operand, after variables have been resolved.
+#[derive(Debug, Clone)]
+enum Value<'l> {
+    String(&'l str),
+    Number(Cow<'l, FixedDecimal>),
+    DateTime(&'l DateTime<AnyCalendar>),
+    List(&'l [&'l str]),
+}
+
+impl<'l> From<MessageArgument<'l>> for Value<'l> {
+    fn from(argument: MessageArgument<'l>) -> Self {
+        match argument {
+            MessageArgument::String(s) => Value::String(s),
+            MessageArgument::Number(n) => Value::Number(Cow::Borrowed(n)),
+            MessageArgument::DateTime(d) => Value::DateTime(d),
+            MessageArgument::List(l) => Value::List(l),
+        }
+    }
+}
+
+impl<'l> Value<'l> {
+    fn to_number(&self) -> Option<Cow<'l, FixedDecimal>> {
+        match self {
+            Value::Number(n) => Some(n.clone()),
+            Value::String(s) => s.parse().ok().map(Cow::Owned),
+            _ => None,
+        }
+    }
+}
+
+/// A local variable: its name, value, and the function of its declaration.
+type Local<'l> = (&'l str, Option<Value<'l>>, &'l Function);
+
+/// The resolved value of a selector.
+enum Selection<'l> {
+    None,
+    String(&'l str),
+    Number(FixedDecimal, Option<PluralCategory>),
+}
+
+impl<'l> Selection<'l> {
+    /// Returns the preference of the key for this selector, lower being better, or `None`
+    /// if the key does not match.
+    fn rank(&self, key: &str) -> Option<u8> {
+        match self {
+            Selection::None => None,
+            Selection::String(s) => (*s == key).then_some(0),
+            Selection::Number(n, category) => {
+                if key
+                    .parse::<FixedDecimal>()
+                    .map(|key| numerically_equal(n, &key))
+                    .unwrap_or(false)
+                {
+                    Some(0)
+                } else if category.is_some()
+                    && PluralCategory::get_for_cldr_string(key) == *category
+                {
+                    Some(1)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
+
+fn numerically_equal(a: &FixedDecimal, b: &FixedDecimal) -> bool {
+    let mut a = a.clone();
+    let mut b = b.clone();
+    a.trim_start();
+    a.trim_end();
+    b.trim_start();
+    b.trim_end();
+    a == b
+}
+
+impl<'l> FormattedMessage<'l> {
+    /// Resolves the operand of a placeholder, and the function that applies to it, where
+    /// `None` means that the placeholder is unannotated.
+    fn resolve(
+        &self,
+        locals: &[Local<'l>],
+        placeholder: &'l Placeholder,
+    ) -> (Option<Value<'l>>, Option<&'l Function>) {
+        let (value, inherited) = match &placeholder.operand {
+            Some(Operand::Variable(name)) => {
+                match locals.iter().rev().find(|(local, _, _)| local == name) {
+                    Some((_, value, function)) => (value.clone(), Some(*function)),
+                    None => (self.arguments.get(name).map(Value::from), None),
+                }
+            }
+            Some(Operand::Literal(literal)) => (Some(Value::String(literal)), None),
+            None => (None, None),
+        };
+        let function = match &placeholder.function {
+            Function::Default => inherited.filter(|f| !matches!(f, Function::Default)),
+            function => Some(function),
+        };
+        (value, function)
+    }
+
+    fn locals(&self) -> Vec<Local<'l>> {
+        let mut locals = Vec::with_capacity(self.formatter.declarations.len());
+        for (name, placeholder) in &self.formatter.declarations {
+            let (value, function) = self.resolve(&locals, placeholder);
+            locals.push((
+                name.as_str(),
+                value,
+                function.unwrap_or(&placeholder.function),
+            ));
+        }
+        locals
+    }
+
+    fn selection(&self, locals: &[Local<'l>], selector: &'l Placeholder) -> Selection<'l> {
+        match self.resolve(locals, selector) {
+            (Some(Value::String(s)), None | Some(Function::String)) => Selection::String(s),
+            (Some(Value::Number(n)), None) => Selection::Number(n.into_owned(), None),
+            (Some(value), Some(Function::Number(function))) => match value.to_number() {
+                Some(n) => {
+                    let rounded = function.rounded(&n);
+                    let category = function.rules.as_ref().map(|r| r.category_for(&rounded));
+                    Selection::Number(rounded, category)
+                }
+                None => Selection::None,
+            },
+            _ => Selection::None,
+        }
+    }
+
+    fn write_placeholder<W: PartsWrite + ?Sized>(
+        &self,
+        sink: &mut W,
+        locals: &[Local<'l>],
+        placeholder: &'l Placeholder,
+    ) -> fmt::Result {
+        match self.resolve(locals, placeholder) {
+            (Some(Value::String(s)), None | Some(Function::String)) => {
+                sink.with_part(parts::STRING, |w| w.write_str(s))
+            }
+            (Some(Value::Number(n)), Some(Function::String)) => {
+                sink.with_part(parts::STRING, |w| n.write_to_parts(w))
+            }
+            (Some(Value::Number(n)), None) => sink.with_part(parts::NUMBER, |w| {
+                self.formatter.default_number.format(&n).write_to_parts(w)
+            }),
+            (Some(value), Some(Function::Number(function))) => match value.to_number() {
+                Some(n) => sink.with_part(parts::NUMBER, |w| {
+                    function.formatter.format(&n).write_to_parts(w)
+                }),
+                None => sink.with_part(parts::FALLBACK, |w| w.write_str(&placeholder.fallback)),
+            },
+            (Some(Value::DateTime(date_time)), Some(Function::DateTime(formatter))) => {
+                match formatter.format(date_time) {
+                    Ok(formatted) => {
+                        sink.with_part(parts::DATETIME, |w| formatted.write_to_parts(w))
+                    }
+                    Err(_) => {
+                        sink.with_part(parts::FALLBACK, |w| w.write_str(&placeholder.fallback))
+                    }
+                }
+            }
+            (Some(Value::List(list)), Some(Function::List(formatter))) => sink
+                .with_part(parts::LIST, |w| {
+                    formatter.format(list.iter()).write_to_parts(w)
+                }),
+            (Some(Value::String(s)), Some(Function::List(formatter))) => sink
+                .with_part(parts::LIST, |w| {
+                    formatter.format(core::iter::once(s)).write_to_parts(w)
+                }),
+            _ => sink.with_part(parts::FALLBACK, |w| w.write_str(&placeholder.fallback)),
+        }
+    }
+}
+
+impl<'l> Writeable for FormattedMessage<'l> {
+    fn write_to_parts<W: PartsWrite + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        let locals = self.locals();
+
+        let pattern = match &self.formatter.body {
+            CompiledBody::Pattern(pattern) => pattern.as_slice(),
+            CompiledBody::Select {
+                selectors,
+                variants,
+            } => {
+                let selections = selectors
+                    .iter()
+                    .map(|selector| self.selection(&locals, selector))
+                    .collect::<Vec<_>>();
+                // Selects the variant whose keys are most preferred, comparing the selectors
+                // in order, with the wildcard being least preferred.
+                let mut best: Option<(Vec<u8>, &[CompiledPart])> = None;
+                'variants: for (keys, pattern) in variants {
+                    let mut ranks = Vec::with_capacity(keys.len());
+                    for (key, selection) in keys.iter().zip(&selections) {
+                        ranks.push(match key {
+                            VariantKey::Wildcard => u8::MAX,
+                            VariantKey::Literal(key) => match selection.rank(key) {
+                                Some(rank) => rank,
+                                None => continue 'variants,
+                            },
+                        });
+                    }
+                    if best.as_ref().map(|(b, _)| ranks < *b).unwrap_or(true) {
+                        best = Some((ranks, pattern));
+                    }
+                }
+                best.map(|(_, pattern)| pattern).unwrap_or_default()
+            }
+        };
+
+        for part in pattern {
+            match part {
+                CompiledPart::Text(text) => {
+                    sink.with_part(parts::LITERAL, |w| w.write_str(text))?
+                }
+                CompiledPart::Placeholder(placeholder) => {
+                    self.write_placeholder(sink, &locals, placeholder)?
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+writeable::impl_display_with_writeable!(FormattedMessage<'_>);
@@ -0,0 +1,687 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::arguments::MessageArguments;
+use crate::ast::*;
+use crate::format::FormattedMessage;
+use crate::MessageFormatError;
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use fixed_decimal::FixedDecimal;
+use icu_calendar::provider::{
+    JapaneseErasV1Marker, JapaneseExtendedErasV1Marker, WeekDataV1Marker,
+};
+use icu_datetime::options::length;
+use icu_datetime::provider::calendar::*;
+use icu_datetime::{DateTimeError, DateTimeFormatter};
+use icu_decimal::options::{
+    FixedDecimalFormatterOptions, GroupingStrategy, Notation, RoundingIncrement, RoundingMode,
+    RoundingPriority, TrailingZeroDisplay,
+};
+use icu_decimal::provider::{DecimalNotationSymbolsV1Marker, DecimalSymbolsV1Marker};
+use icu_decimal::{DecimalError, FixedDecimalFormatter};
+use icu_list::provider::{AndListV1Marker, OrListV1Marker, UnitListV1Marker};
+use icu_list::{ListError, ListFormatter, ListLength};
+use icu_plurals::provider::{CardinalV1Marker, OrdinalV1Marker};
+use icu_plurals::{PluralRuleType, PluralRules, PluralsError};
+use icu_provider::prelude::*;
+
+/// A formatter for messages in the [MessageFormat 2.0] syntax.
+///
+/// The functions used by the message are resolved, and their data loaded, when the
+/// formatter is constructed. The following functions are supported:
+///
+/// * `:number` formats a number with [`FixedDecimalFormatter`], and selects on its
+///   [`PluralRules`] category. It supports the options `minimumFractionDigits`,
+///   `maximumFractionDigits`, `minimumSignificantDigits`, `maximumSignificantDigits`,
+///   `roundingPriority`, `roundingIncrement`, `roundingMode`, `trailingZeroDisplay`,
+///   `useGrouping`, `style` (`decimal` or `percent`), `notation` (`standard`, `scientific` or
+///   `engineering`) and `select` (`plural`, `ordinal` or `exact`).
+/// * `:datetime` formats a date and time with [`DateTimeFormatter`]. It supports the options
+///   `dateStyle` and `timeStyle` (`full`, `long`, `medium` or `short`).
+/// * `:list` formats a list with [`ListFormatter`]. It supports the options `type`
+///   (`conjunction`, `disjunction` or `unit`) and `style` (`long`, `short` or `narrow`).
+/// * `:string` formats and selects on a value as a string.
+///
+/// Unannotated placeholders format strings as they are and numbers with the default
+/// [`FixedDecimalFormatter`] options. Option values have to be literals.
+///
+/// Formatting does not fail: as specified by MessageFormat 2.0, a placeholder that cannot be
+/// formatted, for example because its argument is missing, is replaced by a fallback
+/// representation such as `{$name}`.
+///
+/// [MessageFormat 2.0]: https://github.com/unicode-org/message-format-wg
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+/// use icu_locid::locale;
+/// use icu_messageformat::{MessageArguments, MessageFormatter};
+/// use writeable::assert_writeable_eq;
+///
+/// let formatter = MessageFormatter::try_new(
+///     &locale!("en").into(),
+///     "match {$count :number}
+///      when 0 {{$name} has no messages.}
+///      when one {{$name} has {$count} message.}
+///      when * {{$name} has {$count} messages.}"
+///         .parse()
+///         .expect("message should be valid"),
+/// )
+/// .expect("locale should be present");
+///
+/// let count = FixedDecimal::from(1234);
+/// let arguments = MessageArguments::new()
+///     .with("name", "Alice")
+///     .with("count", &count);
+///
+/// assert_writeable_eq!(
+///     formatter.format(&arguments),
+///     "Alice has 1,234 messages."
+/// );
+/// ```
+#[derive(Debug)]
+pub struct MessageFormatter {
+    pub(crate) declarations: Vec<(String, Placeholder)>,
+    pub(crate) body: CompiledBody,
+    pub(crate) default_number: FixedDecimalFormatter,
+}
+
+/// A [`Body`] whose placeholders have been resolved.
+#[derive(Debug)]
+pub(crate) enum CompiledBody {
+    Pattern(Vec<CompiledPart>),
+    Select {
+        selectors: Vec<Placeholder>,
+        variants: Vec<(Vec<VariantKey>, Vec<CompiledPart>)>,
+    },
+}
+
+/// A [`PatternPart`] whose placeholder has been resolved.
+#[derive(Debug)]
+pub(crate) enum CompiledPart {
+    Text(String),
+    Placeholder(Placeholder),
+}
+
+/// An [`Expression`] whose function has been resolved.
+#[derive(Debug)]
+pub(crate) struct Placeholder {
+    pub(crate) operand: Option<Operand>,
+    pub(crate) function: Function,
+    /// The text that replaces the placeholder if it cannot be formatted.
+    pub(crate) fallback: String,
+}
+
+#[derive(Debug)]
+pub(crate) enum Function {
+    /// The expression has no annotation.
+    Default,
+    String,
+    Number(Box<NumberFunction>),
+    DateTime(Box<DateTimeFormatter>),
+    List(Box<ListFormatter>),
+}
+
+#[derive(Debug)]
+pub(crate) struct NumberFunction {
+    pub(crate) formatter: FixedDecimalFormatter,
+    /// The rules used for selection, or `None` for `select=exact`.
+    pub(crate) rules: Option<PluralRules>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ListType {
+    Conjunction,
+    Disjunction,
+    Unit,
+}
+
+/// Loads the formatters that implement the functions of a message.
+trait FunctionLoader {
+    fn fixed_decimal_formatter(
+        &self,
+        locale: &DataLocale,
+        notation: Notation,
+        options: FixedDecimalFormatterOptions,
+    ) -> Result<FixedDecimalFormatter, DecimalError>;
+
+    fn plural_rules(
+        &self,
+        locale: &DataLocale,
+        rule_type: PluralRuleType,
+    ) -> Result<PluralRules, PluralsError>;
+
+    fn date_time_formatter(
+        &self,
+        locale: &DataLocale,
+        options: length::Bag,
+    ) -> Result<DateTimeFormatter, DateTimeError>;
+
+    fn list_formatter(
+        &self,
+        locale: &DataLocale,
+        list_type: ListType,
+        length: ListLength,
+    ) -> Result<ListFormatter, ListError>;
+}
+
+#[cfg(feature = "compiled_data")]
+struct BakedLoader;
+
+#[cfg(feature = "compiled_data")]
+impl FunctionLoader for BakedLoader {
+    fn fixed_decimal_formatter(
+        &self,
+        locale: &DataLocale,
+        notation: Notation,
+        options: FixedDecimalFormatterOptions,
+    ) -> Result<FixedDecimalFormatter, DecimalError> {
+        FixedDecimalFormatter::try_new_with_notation(locale, notation, options)
+    }
+
+    fn plural_rules(
+        &self,
+        locale: &DataLocale,
+        rule_type: PluralRuleType,
+    ) -> Result<PluralRules, PluralsError> {
+        PluralRules::try_new(locale, rule_type)
+    }
+
+    fn date_time_formatter(
+        &self,
+        locale: &DataLocale,
+        options: length::Bag,
+    ) -> Result<DateTimeFormatter, DateTimeError> {
+        DateTimeFormatter::try_new(locale, options.into())
+    }
+
+    fn list_formatter(
+        &self,
+        locale: &DataLocale,
+        list_type: ListType,
+        length: ListLength,
+    ) -> Result<ListFormatter, ListError> {
+        match list_type {
+            ListType::Conjunction => ListFormatter::try_new_and_with_length(locale, length),
+            ListType::Disjunction => ListFormatter::try_new_or_with_length(locale, length),
+            ListType::Unit => ListFormatter::try_new_unit_with_length(locale, length),
+        }
+    }
+}
+
+struct UnstableLoader<'a, P: ?Sized>(&'a P);
+
+impl<P> FunctionLoader for UnstableLoader<'_, P>
+where
+    P: DataProvider<CardinalV1Marker>
+        + DataProvider<OrdinalV1Marker>
+        + DataProvider<AndListV1Marker>
+        + DataProvider<OrListV1Marker>
+        + DataProvider<UnitListV1Marker>
+        + DataProvider<TimeSymbolsV1Marker>
+        + DataProvider<QuarterSymbolsV1Marker>
+        + DataProvider<FlexibleDayPeriodsV1Marker>
+        + DataProvider<TimeLengthsV1Marker>
+        + DataProvider<WeekDataV1Marker>
+        + DataProvider<DecimalSymbolsV1Marker>
+        + DataProvider<DecimalNotationSymbolsV1Marker>
+        + DataProvider<BuddhistDateLengthsV1Marker>
+        + DataProvider<BuddhistDateSymbolsV1Marker>
+        + DataProvider<ChineseDateLengthsV1Marker>
+        + DataProvider<ChineseDateSymbolsV1Marker>
+        + DataProvider<CopticDateLengthsV1Marker>
+        + DataProvider<CopticDateSymbolsV1Marker>
+        + DataProvider<DangiDateLengthsV1Marker>
+        + DataProvider<DangiDateSymbolsV1Marker>
+        + DataProvider<EthiopianDateLengthsV1Marker>
+        + DataProvider<EthiopianDateSymbolsV1Marker>
+        + DataProvider<GregorianDateLengthsV1Marker>
+        + DataProvider<GregorianDateSymbolsV1Marker>
+        + DataProvider<HebrewDateLengthsV1Marker>
+        + DataProvider<HebrewDateSymbolsV1Marker>
+        + DataProvider<IndianDateLengthsV1Marker>
+        + DataProvider<IndianDateSymbolsV1Marker>
+        + DataProvider<IslamicDateLengthsV1Marker>
+        + DataProvider<IslamicDateSymbolsV1Marker>
+        + DataProvider<JapaneseDateLengthsV1Marker>
+        + DataProvider<JapaneseDateSymbolsV1Marker>
+        + DataProvider<JapaneseErasV1Marker>
+        + DataProvider<JapaneseExtendedDateLengthsV1Marker>
+        + DataProvider<JapaneseExtendedDateSymbolsV1Marker>
+        + DataProvider<JapaneseExtendedErasV1Marker>
+        + DataProvider<PersianDateLengthsV1Marker>
+        + DataProvider<PersianDateSymbolsV1Marker>
+        + DataProvider<RocDateLengthsV1Marker>
+        + DataProvider<RocDateSymbolsV1Marker>
+        + ?Sized,
+{
+    fn fixed_decimal_formatter(
+        &self,
+        locale: &DataLocale,
+        notation: Notation,
+        options: FixedDecimalFormatterOptions,
+    ) -> Result<FixedDecimalFormatter, DecimalError> {
+        FixedDecimalFormatter::try_new_with_notation_unstable(self.0, locale, notation, options)
+    }
+
+    fn plural_rules(
+        &self,
+        locale: &DataLocale,
+        rule_type: PluralRuleType,
+    ) -> Result<PluralRules, PluralsError> {
+        PluralRules::try_new_unstable(self.0, locale, rule_type)
+    }
+
+    fn date_time_formatter(
+        &self,
+        locale: &DataLocale,
+        options: length::Bag,
+    ) -> Result<DateTimeFormatter, DateTimeError> {
+        DateTimeFormatter::try_new_unstable(self.0, locale, options.into())
+    }
+
+    fn list_formatter(
+        &self,
+        locale: &DataLocale,
+        list_type: ListType,
+        length: ListLength,
+    ) -> Result<ListFormatter, ListError> {
+        match list_type {
+            ListType::Conjunction => {
+                ListFormatter::try_new_and_with_length_unstable(self.0, locale, length)
+            }
+            ListType::Disjunction => {
+                ListFormatter::try_new_or_with_length_unstable(self.0, locale, length)
+            }
+            ListType::Unit => {
+                ListFormatter::try_new_unit_with_length_unstable(self.0, locale, length)
+            }
+        }
+    }
+}
+
+impl MessageFormatter {
+    /// Creates a new [`MessageFormatter`] for the given message from compiled data.
+    ///
+    /// Fails if the message uses an unknown function or an invalid option, or if the data for
+    /// one of its functions cannot be loaded.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new(locale: &DataLocale, message: Message) -> Result<Self, MessageFormatError> {
+        Self::try_new_with_loader(&BakedLoader, locale, message)
+    }
+
+    icu_provider::gen_any_buffer_data_constructors!(
+        locale: include,
+        message: Message,
+        error: MessageFormatError,
+        #[cfg(skip)]
+        functions: [
+            try_new,
+            try_new_with_any_provider,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self,
+        ]
+    );
+
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<P>(
+        provider: &P,
+        locale: &DataLocale,
+        message: Message,
+    ) -> Result<Self, MessageFormatError>
+    where
+        P: DataProvider<CardinalV1Marker>
+            + DataProvider<OrdinalV1Marker>
+            + DataProvider<AndListV1Marker>
+            + DataProvider<OrListV1Marker>
+            + DataProvider<UnitListV1Marker>
+            + DataProvider<TimeSymbolsV1Marker>
+            + DataProvider<QuarterSymbolsV1Marker>
+            + DataProvider<FlexibleDayPeriodsV1Marker>
+            + DataProvider<TimeLengthsV1Marker>
+            + DataProvider<WeekDataV1Marker>
+            + DataProvider<DecimalSymbolsV1Marker>
+            + DataProvider<DecimalNotationSymbolsV1Marker>
+            + DataProvider<BuddhistDateLengthsV1Marker>
+            + DataProvider<BuddhistDateSymbolsV1Marker>
+            + DataProvider<ChineseDateLengthsV1Marker>
+            + DataProvider<ChineseDateSymbolsV1Marker>
+            + DataProvider<CopticDateLengthsV1Marker>
+            + DataProvider<CopticDateSymbolsV1Marker>
+            + DataProvider<DangiDateLengthsV1Marker>
+            + DataProvider<DangiDateSymbolsV1Marker>
+            + DataProvider<EthiopianDateLengthsV1Marker>
+            + DataProvider<EthiopianDateSymbolsV1Marker>
+            + DataProvider<GregorianDateLengthsV1Marker>
+            + DataProvider<GregorianDateSymbolsV1Marker>
+            + DataProvider<HebrewDateLengthsV1Marker>
+            + DataProvider<HebrewDateSymbolsV1Marker>
+            + DataProvider<IndianDateLengthsV1Marker>
+            + DataProvider<IndianDateSymbolsV1Marker>
+            + DataProvider<IslamicDateLengthsV1Marker>
+            + DataProvider<IslamicDateSymbolsV1Marker>
+            + DataProvider<JapaneseDateLengthsV1Marker>
+            + DataProvider<JapaneseDateSymbolsV1Marker>
+            + DataProvider<JapaneseErasV1Marker>
+            + DataProvider<JapaneseExtendedDateLengthsV1Marker>
+            + DataProvider<JapaneseExtendedDateSymbolsV1Marker>
+            + DataProvider<JapaneseExtendedErasV1Marker>
+            + DataProvider<PersianDateLengthsV1Marker>
+            + DataProvider<PersianDateSymbolsV1Marker>
+            + DataProvider<RocDateLengthsV1Marker>
+            + DataProvider<RocDateSymbolsV1Marker>
+            + ?Sized,
+    {
+        Self::try_new_with_loader(&UnstableLoader(provider), locale, message)
+    }
+
+    fn try_new_with_loader(
+        loader: &impl FunctionLoader,
+        locale: &DataLocale,
+        message: Message,
+    ) -> Result<Self, MessageFormatError> {
+        let compile_pattern = |pattern: Pattern| {
+            pattern
+                .parts
+                .into_iter()
+                .map(|part| {
+                    Ok(match part {
+                        PatternPart::Text(text) => CompiledPart::Text(text),
+                        PatternPart::Expression(expression) => {
+                            CompiledPart::Placeholder(compile(loader, locale, expression)?)
+                        }
+                    })
+                })
+                .collect::<Result<Vec<_>, MessageFormatError>>()
+        };
+
+        let declarations = message
+            .declarations
+            .into_iter()
+            .map(|declaration| {
+                Ok((
+                    declaration.variable,
+                    compile(loader, locale, declaration.value)?,
+                ))
+            })
+            .collect::<Result<_, MessageFormatError>>()?;
+
+        let body = match message.body {
+            Body::Pattern(pattern) => CompiledBody::Pattern(compile_pattern(pattern)?),
+            Body::Select {
+                selectors,
+                variants,
+            } => CompiledBody::Select {
+                selectors: selectors
+                    .into_iter()
+                    .map(|selector| compile(loader, locale, selector))
+                    .collect::<Result<_, _>>()?,
+                variants: variants
+                    .into_iter()
+                    .map(|variant| Ok((variant.keys, compile_pattern(variant.pattern)?)))
+                    .collect::<Result<_, MessageFormatError>>()?,
+            },
+        };
+
+        Ok(Self {
+            declarations,
+            body,
+            default_number: loader.fixed_decimal_formatter(
+                locale,
+                Notation::Standard,
+                Default::default(),
+            )?,
+        })
+    }
+
+    /// Formats the message with the given arguments.
+    ///
+    /// The returned [`FormattedMessage`] implements [`Writeable`](writeable::Writeable),
+    /// including [`write_to_parts`](writeable::Writeable::write_to_parts) with the
+    /// [`parts`](crate::parts) of this crate.
+    pub fn format<'l>(&'l self, arguments: &'l MessageArguments<'l>) -> FormattedMessage<'l> {
+        FormattedMessage {
+            formatter: self,
+            arguments,
+        }
+    }
+
+    /// Formats the message with the given arguments to a string.
+    pub fn format_to_string(&self, arguments: &MessageArguments) -> String {
+        use writeable::Writeable;
+        self.format(arguments).write_to_string().into_owned()
+    }
+}
+
+/// Resolves the function of an expression and loads its data.
+fn compile(
+    loader: &impl FunctionLoader,
+    locale: &DataLocale,
+    expression: Expression,
+) -> Result<Placeholder, MessageFormatError> {
+    let mut fallback = String::from("{");
+    match (&expression.operand, &expression.annotation) {
+        (Some(Operand::Variable(name)), _) => {
+            fallback.push('$');
+            fallback.push_str(name);
+        }
+        (Some(Operand::Literal(literal)), _) => {
+            fallback.push('|');
+            fallback.push_str(literal);
+            fallback.push('|');
+        }
+        (None, Some(annotation)) => fallback.push_str(&annotation.function),
+        (None, None) => {}
+    }
+    fallback.push('}');
+
+    let function = match expression.annotation {
+        None => Function::Default,
+        Some(annotation) => {
+            let mut remaining = annotation
+                .options
+                .into_iter()
+                .map(|option| match option.value {
+                    Operand::Literal(value) => Ok((option.name, value)),
+                    Operand::Variable(_) => Err(MessageFormatError::InvalidOption),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let mut take = |name: &str| {
+                remaining
+                    .iter()
+                    .position(|(n, _)| n == name)
+                    .map(|i| remaining.remove(i).1)
+            };
+
+            let function = match annotation.function.as_str() {
+                ":string" => Function::String,
+                ":number" => {
+                    let mut options = FixedDecimalFormatterOptions::default();
+                    options.minimum_fraction_digits = take("minimumFractionDigits")
+                        .map(|v| parse_digits(&v))
+                        .transpose()?;
+                    options.maximum_fraction_digits = take("maximumFractionDigits")
+                        .map(|v| parse_digits(&v))
+                        .transpose()?;
+                    options.minimum_significant_digits = take("minimumSignificantDigits")
+                        .map(|v| parse_digits(&v))
+                        .transpose()?;
+                    options.maximum_significant_digits = take("maximumSignificantDigits")
+                        .map(|v| parse_digits(&v))
+                        .transpose()?;
+                    if let Some(v) = take("roundingPriority") {
+                        options.rounding_priority = match v.as_str() {
+                            "auto" => RoundingPriority::Auto,
+                            "morePrecision" => RoundingPriority::MorePrecision,
+                            "lessPrecision" => RoundingPriority::LessPrecision,
+                            _ => return Err(MessageFormatError::InvalidOption),
+                        };
+                    }
+                    if let Some(v) = take("roundingIncrement") {
+                        options.rounding_increment = match v.as_str() {
+                            "1" => RoundingIncrement::MultiplesOf1,
+                            "2" => RoundingIncrement::MultiplesOf2,
+                            "5" => RoundingIncrement::MultiplesOf5,
+                            "25" => RoundingIncrement::MultiplesOf25,
+                            _ => return Err(MessageFormatError::InvalidOption),
+                        };
+                    }
+                    if let Some(v) = take("roundingMode") {
+                        options.rounding_mode = match v.as_str() {
+                            "ceil" => RoundingMode::Ceil,
+                            "floor" => RoundingMode::Floor,
+                            "expand" => RoundingMode::Expand,
+                            "trunc" => RoundingMode::Trunc,
+                            "halfCeil" => RoundingMode::HalfCeil,
+                            "halfFloor" => RoundingMode::HalfFloor,
+                            "halfExpand" => RoundingMode::HalfExpand,
+                            "halfTrunc" => RoundingMode::HalfTrunc,
+                            "halfEven" => RoundingMode::HalfEven,
+                            _ => return Err(MessageFormatError::InvalidOption),
+                        };
+                    }
+                    if let Some(v) = take("trailingZeroDisplay") {
+                        options.trailing_zero_display = match v.as_str() {
+                            "auto" => TrailingZeroDisplay::Auto,
+                            "stripIfInteger" => TrailingZeroDisplay::StripIfInteger,
+                            _ => return Err(MessageFormatError::InvalidOption),
+                        };
+                    }
+                    if let Some(v) = take("useGrouping") {
+                        options.grouping_strategy = match v.as_str() {
+                            "auto" => GroupingStrategy::Auto,
+                            "always" => GroupingStrategy::Always,
+                            "min2" => GroupingStrategy::Min2,
+                            "false" => GroupingStrategy::Never,
+                            _ => return Err(MessageFormatError::InvalidOption),
+                        };
+                    }
+                    let mut notation = Notation::Standard;
+                    if let Some(v) = take("style") {
+                        notation = match v.as_str() {
+                            "decimal" => notation,
+                            "percent" => Notation::Percent,
+                            _ => return Err(MessageFormatError::InvalidOption),
+                        };
+                    }
+                    if let Some(v) = take("notation") {
+                        notation = match v.as_str() {
+                            "standard" => notation,
+                            "scientific" => Notation::Scientific,
+                            "engineering" => Notation::Engineering,
+                            _ => return Err(MessageFormatError::InvalidOption),
+                        };
+                    }
+                    let rules = match take("select").as_deref() {
+                        None | Some("plural") => {
+                            Some(loader.plural_rules(locale, PluralRuleType::Cardinal)?)
+                        }
+                        Some("ordinal") => {
+                            Some(loader.plural_rules(locale, PluralRuleType::Ordinal)?)
+                        }
+                        Some("exact") => None,
+                        Some(_) => return Err(MessageFormatError::InvalidOption),
+                    };
+                    Function::Number(Box::new(NumberFunction {
+                        formatter: loader.fixed_decimal_formatter(locale, notation, options)?,
+                        rules,
+                    }))
+                }
+                ":datetime" => {
+                    let date = take("dateStyle")
+                        .map(|v| parse_date_style(&v))
+                        .transpose()?;
+                    let time = take("timeStyle")
+                        .map(|v| parse_time_style(&v))
+                        .transpose()?;
+                    let bag = match (date, time) {
+                        (Some(date), Some(time)) => length::Bag::from_date_time_style(date, time),
+                        (Some(date), None) => length::Bag::from_date_style(date),
+                        (None, Some(time)) => length::Bag::from_time_style(time),
+                        (None, None) => length::Bag::from_date_time_style(
+                            length::Date::Medium,
+                            length::Time::Short,
+                        ),
+                    };
+                    Function::DateTime(Box::new(loader.date_time_formatter(locale, bag)?))
+                }
+                ":list" => {
+                    let list_type = match take("type").as_deref() {
+                        None | Some("conjunction") => ListType::Conjunction,
+                        Some("disjunction") => ListType::Disjunction,
+                        Some("unit") => ListType::Unit,
+                        Some(_) => return Err(MessageFormatError::InvalidOption),
+                    };
+                    let length = match take("style").as_deref() {
+                        None | Some("long") => ListLength::Wide,
+                        Some("short") => ListLength::Short,
+                        Some("narrow") => ListLength::Narrow,
+                        Some(_) => return Err(MessageFormatError::InvalidOption),
+                    };
+                    Function::List(Box::new(loader.list_formatter(locale, list_type, length)?))
+                }
+                _ => return Err(MessageFormatError::UnknownFunction),
+            };
+            if !remaining.is_empty() {
+                return Err(MessageFormatError::InvalidOption);
+            }
+            function
+        }
+    };
+
+    Ok(Placeholder {
+        operand: expression.operand,
+        function,
+        fallback,
+    })
+}
+
+fn parse_digits(value: &str) -> Result<u8, MessageFormatError> {
+    value
+        .parse()
+        .ok()
+        .filter(|&digits| digits <= 100)
+        .ok_or(MessageFormatError::InvalidOption)
+}
+
+fn parse_date_style(value: &str) -> Result<length::Date, MessageFormatError> {
+    match value {
+        "full" => Ok(length::Date::Full),
+        "long" => Ok(length::Date::Long),
+        "medium" => Ok(length::Date::Medium),
+        "short" => Ok(length::Date::Short),
+        _ => Err(MessageFormatError::InvalidOption),
+    }
+}
+
+fn parse_time_style(value: &str) -> Result<length::Time, MessageFormatError> {
+    match value {
+        "full" => Ok(length::Time::Full),
+        "long" => Ok(length::Time::Long),
+        "medium" => Ok(length::Time::Medium),
+        "short" => Ok(length::Time::Short),
+        _ => Err(MessageFormatError::InvalidOption),
+    }
+}
+
+impl NumberFunction {
+    /// Returns the number as it is formatted, after scaling and rounding, so that it selects
+    /// the plural category of the displayed number.
+    pub(crate) fn rounded(&self, value: &FixedDecimal) -> FixedDecimal {
+        let formatted = self.formatter.format(value);
+        let rounded = formatted.value().clone();
+        match formatted.exponent() {
+            Some(exponent) => rounded.multiplied_pow10(exponent),
+            None => rounded,
+        }
+    }
+}
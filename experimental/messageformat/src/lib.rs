@@ -0,0 +1,107 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! 🚧 \[Experimental\] Parsing and formatting of messages in the MessageFormat 2.0 syntax.
+//!
+//! This module is published as its own crate ([`icu_messageformat`](https://docs.rs/icu_messageformat/latest/icu_messageformat/)).
+//! See the [`icu`](https://docs.rs/icu/latest/icu/) crate for more details on the ICU4X project.
+//!
+//! [MessageFormat 2.0] is a syntax for localizable messages with placeholders and
+//! selection on plural categories or other values. A message is parsed with [`parse()`] into
+//! the data model of the [`ast`] module, and formatted with a [`MessageFormatter`], which
+//! delegates its built-in functions to [`FixedDecimalFormatter`](icu_decimal::FixedDecimalFormatter),
+//! [`PluralRules`](icu_plurals::PluralRules), [`DateTimeFormatter`](icu_datetime::DateTimeFormatter)
+//! and [`ListFormatter`](icu_list::ListFormatter).
+//!
+//! <div class="stab unstable">
+//! 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+//! including in SemVer minor releases. Use with caution.
+//! </div>
+//!
+//! [MessageFormat 2.0]: https://github.com/unicode-org/message-format-wg
+//!
+//! # Examples
+//!
+//! ```
+//! use fixed_decimal::FixedDecimal;
+//! use icu_locid::locale;
+//! use icu_messageformat::{MessageArguments, MessageFormatter};
+//! use writeable::assert_writeable_eq;
+//!
+//! let message = icu_messageformat::parse(
+//!     "let $guests = {$names :list}
+//!      match {$count :number}
+//!      when 1 {{$host} invited {$guests} to the party.}
+//!      when * {{$host} invited {$guests} and {$count} other people to the party.}",
+//! )
+//! .expect("message should be valid");
+//!
+//! let formatter = MessageFormatter::try_new(&locale!("en").into(), message)
+//!     .expect("locale should be present");
+//!
+//! let names = ["Ana", "Bruno"];
+//! let count = FixedDecimal::from(1000);
+//! let arguments = MessageArguments::new()
+//!     .with("host", "Chloé")
+//!     .with("names", &names[..])
+//!     .with("count", &count);
+//!
+//! assert_writeable_eq!(
+//!     formatter.format(&arguments),
+//!     "Chloé invited Ana and Bruno and 1,000 other people to the party."
+//! );
+//! ```
+//!
+//! Placeholders that cannot be formatted, for example because their argument is missing, are
+//! replaced by a fallback representation:
+//!
+//! ```
+//! use icu_locid::locale;
+//! use icu_messageformat::{MessageArguments, MessageFormatter};
+//! use writeable::assert_writeable_eq;
+//!
+//! let formatter = MessageFormatter::try_new(
+//!     &locale!("en").into(),
+//!     "{Hello, {$name}!}".parse().expect("message should be valid"),
+//! )
+//! .expect("locale should be present");
+//!
+//! let arguments = MessageArguments::new();
+//!
+//! assert_writeable_eq!(formatter.format(&arguments), "Hello, {$name}!");
+//! ```
+
+// https://github.com/unicode-org/icu4x/blob/main/docs/process/boilerplate.md#library-annotations
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+#![cfg_attr(
+    not(test),
+    deny(
+        clippy::indexing_slicing,
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::exhaustive_structs,
+        clippy::exhaustive_enums,
+        missing_debug_implementations,
+    )
+)]
+#![warn(missing_docs)]
+
+extern crate alloc;
+
+mod arguments;
+pub mod ast;
+mod error;
+mod format;
+mod formatter;
+mod parser;
+
+pub use arguments::{MessageArgument, MessageArguments};
+pub use error::MessageFormatError;
+pub use format::{parts, FormattedMessage};
+pub use formatter::MessageFormatter;
+pub use parser::parse;
+
+#[doc(no_inline)]
+pub use MessageFormatError as Error;
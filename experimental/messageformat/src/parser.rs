@@ -0,0 +1,494 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::ast::*;
+use crate::MessageFormatError;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::str::FromStr;
+
+/// Parses a message in the MessageFormat 2.0 syntax.
+///
+/// # Examples
+///
+/// ```
+/// use icu_messageformat::ast::*;
+///
+/// let message = icu_messageformat::parse("{Hello, {$name}!}").unwrap();
+///
+/// assert_eq!(
+///     message.body,
+///     Body::Pattern(Pattern {
+///         parts: vec![
+///             PatternPart::Text("Hello, ".into()),
+///             PatternPart::Expression(Expression {
+///                 operand: Some(Operand::Variable("name".into())),
+///                 annotation: None,
+///             }),
+///             PatternPart::Text("!".into()),
+///         ]
+///     })
+/// );
+///
+/// // Errors contain the byte offset at which parsing failed.
+/// assert_eq!(
+///     icu_messageformat::parse("{Hello, {$name!}"),
+///     Err(icu_messageformat::MessageFormatError::Syntax(14))
+/// );
+/// ```
+pub fn parse(source: &str) -> Result<Message, MessageFormatError> {
+    let mut parser = Parser {
+        source,
+        position: 0,
+    };
+    let message = parser.message()?;
+    if parser.position != source.len() {
+        return Err(MessageFormatError::Syntax(parser.position));
+    }
+    Ok(message)
+}
+
+impl FromStr for Message {
+    type Err = MessageFormatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse(s)
+    }
+}
+
+struct Parser<'a> {
+    source: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<char> {
+        self.source.get(self.position..)?.chars().next()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.position += c.len_utf8();
+        Some(c)
+    }
+
+    fn error<T>(&self) -> Result<T, MessageFormatError> {
+        Err(MessageFormatError::Syntax(self.position))
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), MessageFormatError> {
+        if self.peek() == Some(expected) {
+            self.position += expected.len_utf8();
+            Ok(())
+        } else {
+            self.error()
+        }
+    }
+
+    /// Consumes optional whitespace, returning whether any was consumed.
+    fn whitespace(&mut self) -> bool {
+        let start = self.position;
+        while self.peek().map(is_whitespace).unwrap_or(false) {
+            self.next();
+        }
+        self.position != start
+    }
+
+    /// Consumes mandatory whitespace.
+    fn required_whitespace(&mut self) -> Result<(), MessageFormatError> {
+        if self.whitespace() {
+            Ok(())
+        } else {
+            self.error()
+        }
+    }
+
+    /// Consumes the given keyword if it is next in the input.
+    fn keyword(&mut self, keyword: &str) -> bool {
+        let matches = self
+            .source
+            .get(self.position..)
+            .map(|rest| rest.starts_with(keyword))
+            .unwrap_or(false);
+        if matches {
+            self.position += keyword.len();
+        }
+        matches
+    }
+
+    fn message(&mut self) -> Result<Message, MessageFormatError> {
+        let mut declarations = Vec::new();
+        self.whitespace();
+        while self.keyword("let") {
+            self.required_whitespace()?;
+            self.expect('$')?;
+            let variable = self.name()?;
+            self.whitespace();
+            self.expect('=')?;
+            self.whitespace();
+            let value = self.expression()?;
+            declarations.push(Declaration { variable, value });
+            self.whitespace();
+        }
+
+        let body = if self.keyword("match") {
+            let mut selectors = Vec::new();
+            self.whitespace();
+            while self.peek() == Some('{') {
+                selectors.push(self.expression()?);
+                self.whitespace();
+            }
+            if selectors.is_empty() {
+                return self.error();
+            }
+
+            let mut variants = Vec::new();
+            while self.keyword("when") {
+                let mut keys = Vec::new();
+                while self.whitespace() && self.peek() != Some('{') {
+                    keys.push(if self.peek() == Some('*') {
+                        self.next();
+                        VariantKey::Wildcard
+                    } else {
+                        VariantKey::Literal(self.literal()?)
+                    });
+                }
+                if keys.len() != selectors.len() {
+                    return self.error();
+                }
+                let pattern = self.pattern()?;
+                variants.push(Variant { keys, pattern });
+                self.whitespace();
+            }
+            if variants.is_empty() {
+                return self.error();
+            }
+            Body::Select {
+                selectors,
+                variants,
+            }
+        } else {
+            let pattern = self.pattern()?;
+            self.whitespace();
+            Body::Pattern(pattern)
+        };
+
+        Ok(Message { declarations, body })
+    }
+
+    fn pattern(&mut self) -> Result<Pattern, MessageFormatError> {
+        self.expect('{')?;
+        let mut parts = Vec::new();
+        let mut text = String::new();
+        loop {
+            match self.peek() {
+                Some('}') => {
+                    self.next();
+                    break;
+                }
+                Some('{') => {
+                    if !text.is_empty() {
+                        parts.push(PatternPart::Text(core::mem::take(&mut text)));
+                    }
+                    parts.push(PatternPart::Expression(self.expression()?));
+                }
+                Some('\\') => {
+                    self.next();
+                    match self.next() {
+                        Some(c @ ('\\' | '{' | '}')) => text.push(c),
+                        _ => return Err(MessageFormatError::Syntax(self.position - 1)),
+                    }
+                }
+                Some(c) => {
+                    self.next();
+                    text.push(c);
+                }
+                None => return self.error(),
+            }
+        }
+        if !text.is_empty() {
+            parts.push(PatternPart::Text(text));
+        }
+        Ok(Pattern { parts })
+    }
+
+    fn expression(&mut self) -> Result<Expression, MessageFormatError> {
+        self.expect('{')?;
+        self.whitespace();
+        let operand = match self.peek() {
+            Some(':' | '+' | '-') if !self.at_number() => None,
+            _ => {
+                let operand = self.operand()?;
+                if !self.whitespace() {
+                    self.expect('}')?;
+                    return Ok(Expression {
+                        operand: Some(operand),
+                        annotation: None,
+                    });
+                }
+                Some(operand)
+            }
+        };
+
+        let annotation = match self.peek() {
+            Some(sigil @ (':' | '+' | '-')) => {
+                self.next();
+                let mut function = String::from(sigil);
+                function.push_str(&self.name()?);
+                let mut options = Vec::new();
+                while self.whitespace() && self.peek() != Some('}') {
+                    let name = self.name()?;
+                    self.whitespace();
+                    self.expect('=')?;
+                    self.whitespace();
+                    let value = self.operand()?;
+                    options.push(FunctionOption { name, value });
+                }
+                Some(Annotation { function, options })
+            }
+            _ if operand.is_some() => None,
+            _ => return self.error(),
+        };
+
+        self.expect('}')?;
+        Ok(Expression {
+            operand,
+            annotation,
+        })
+    }
+
+    fn operand(&mut self) -> Result<Operand, MessageFormatError> {
+        if self.peek() == Some('$') {
+            self.next();
+            Ok(Operand::Variable(self.name()?))
+        } else {
+            Ok(Operand::Literal(self.literal()?))
+        }
+    }
+
+    fn literal(&mut self) -> Result<String, MessageFormatError> {
+        match self.peek() {
+            Some('|') => {
+                self.next();
+                let mut literal = String::new();
+                loop {
+                    match self.next() {
+                        Some('|') => return Ok(literal),
+                        Some('\\') => match self.next() {
+                            Some(c @ ('\\' | '|')) => literal.push(c),
+                            _ => return Err(MessageFormatError::Syntax(self.position - 1)),
+                        },
+                        Some(c) => literal.push(c),
+                        None => return self.error(),
+                    }
+                }
+            }
+            _ if self.at_number() => self.number(),
+            _ => self.name(),
+        }
+    }
+
+    fn at_number(&self) -> bool {
+        let mut chars = self.source.get(self.position..).unwrap_or("").chars();
+        match chars.next() {
+            Some('-') => chars.next().map(|c| c.is_ascii_digit()).unwrap_or(false),
+            Some(c) => c.is_ascii_digit(),
+            None => false,
+        }
+    }
+
+    fn number(&mut self) -> Result<String, MessageFormatError> {
+        let start = self.position;
+        if self.peek() == Some('-') {
+            self.next();
+        }
+        if self.peek() == Some('0') {
+            self.next();
+        } else {
+            self.digits()?;
+        }
+        if self.peek() == Some('.') {
+            self.next();
+            self.digits()?;
+        }
+        if matches!(self.peek(), Some('e' | 'E')) {
+            self.next();
+            if matches!(self.peek(), Some('+' | '-')) {
+                self.next();
+            }
+            self.digits()?;
+        }
+        if self.peek().map(is_name_char).unwrap_or(false) {
+            return self.error();
+        }
+        Ok(String::from(
+            self.source.get(start..self.position).unwrap_or(""),
+        ))
+    }
+
+    fn digits(&mut self) -> Result<(), MessageFormatError> {
+        if !self.peek().map(|c| c.is_ascii_digit()).unwrap_or(false) {
+            return self.error();
+        }
+        while self.peek().map(|c| c.is_ascii_digit()).unwrap_or(false) {
+            self.next();
+        }
+        Ok(())
+    }
+
+    fn name(&mut self) -> Result<String, MessageFormatError> {
+        let start = self.position;
+        if !self.peek().map(is_name_start).unwrap_or(false) {
+            return self.error();
+        }
+        while self.peek().map(is_name_char).unwrap_or(false) {
+            self.next();
+        }
+        Ok(String::from(
+            self.source.get(start..self.position).unwrap_or(""),
+        ))
+    }
+}
+
+fn is_whitespace(c: char) -> bool {
+    matches!(c, ' ' | '\t' | '\r' | '\n' | '\u{3000}')
+}
+
+fn is_name_start(c: char) -> bool {
+    c.is_ascii_alphabetic()
+        || c == '_'
+        || matches!(c,
+            '\u{C0}'..='\u{D6}'
+            | '\u{D8}'..='\u{F6}'
+            | '\u{F8}'..='\u{2FF}'
+            | '\u{370}'..='\u{37D}'
+            | '\u{37F}'..='\u{1FFF}'
+            | '\u{200C}'..='\u{200D}'
+            | '\u{2070}'..='\u{218F}'
+            | '\u{2C00}'..='\u{2FEF}'
+            | '\u{3001}'..='\u{D7FF}'
+            | '\u{F900}'..='\u{FDCF}'
+            | '\u{FDF0}'..='\u{FFFD}'
+            | '\u{10000}'..='\u{EFFFF}')
+}
+
+fn is_name_char(c: char) -> bool {
+    is_name_start(c)
+        || c.is_ascii_digit()
+        || matches!(c, '-' | '.' | '\u{B7}' | '\u{300}'..='\u{36F}' | '\u{203F}'..='\u{2040}')
+}
+
+#[test]
+fn test_parse() {
+    let message = parse(
+        "let $n = {$count :number maximumFractionDigits=0}
+        match {$n} {$kind}
+        when 0 * {No \\{items\\}}
+        when one |a b| {{$n} item}
+        when * * {{$n} items, {|literal \\| pipe|}}",
+    )
+    .unwrap();
+
+    let count = Expression {
+        operand: Some(Operand::Variable("count".into())),
+        annotation: Some(Annotation {
+            function: ":number".into(),
+            options: vec![FunctionOption {
+                name: "maximumFractionDigits".into(),
+                value: Operand::Literal("0".into()),
+            }],
+        }),
+    };
+    let variable = |name: &str| Expression {
+        operand: Some(Operand::Variable(name.into())),
+        annotation: None,
+    };
+    assert_eq!(
+        message,
+        Message {
+            declarations: vec![Declaration {
+                variable: "n".into(),
+                value: count,
+            }],
+            body: Body::Select {
+                selectors: vec![variable("n"), variable("kind")],
+                variants: vec![
+                    Variant {
+                        keys: vec![VariantKey::Literal("0".into()), VariantKey::Wildcard],
+                        pattern: Pattern {
+                            parts: vec![PatternPart::Text("No {items}".into())],
+                        },
+                    },
+                    Variant {
+                        keys: vec![
+                            VariantKey::Literal("one".into()),
+                            VariantKey::Literal("a b".into())
+                        ],
+                        pattern: Pattern {
+                            parts: vec![
+                                PatternPart::Expression(variable("n")),
+                                PatternPart::Text(" item".into()),
+                            ],
+                        },
+                    },
+                    Variant {
+                        keys: vec![VariantKey::Wildcard, VariantKey::Wildcard],
+                        pattern: Pattern {
+                            parts: vec![
+                                PatternPart::Expression(variable("n")),
+                                PatternPart::Text(" items, ".into()),
+                                PatternPart::Expression(Expression {
+                                    operand: Some(Operand::Literal("literal | pipe".into())),
+                                    annotation: None,
+                                }),
+                            ],
+                        },
+                    },
+                ],
+            },
+        }
+    );
+
+    assert_eq!(
+        parse("{{:datetime dateStyle=long} {-1.5e3}}").unwrap().body,
+        Body::Pattern(Pattern {
+            parts: vec![
+                PatternPart::Expression(Expression {
+                    operand: None,
+                    annotation: Some(Annotation {
+                        function: ":datetime".into(),
+                        options: vec![FunctionOption {
+                            name: "dateStyle".into(),
+                            value: Operand::Literal("long".into()),
+                        }],
+                    }),
+                }),
+                PatternPart::Text(" ".into()),
+                PatternPart::Expression(Expression {
+                    operand: Some(Operand::Literal("-1.5e3".into())),
+                    annotation: None,
+                }),
+            ]
+        })
+    );
+
+    for (source, position) in [
+        ("", 0),
+        ("{unclosed", 9),
+        ("{bad \\escape}", 6),
+        ("{} trailing", 3),
+        ("{{}}", 2),
+        ("{{$1}}", 3),
+        ("{{01}}", 3),
+        ("let $x {a}", 7),
+        ("match {$x} when a b {}", 20),
+        ("match {$x}", 10),
+    ] {
+        assert_eq!(
+            parse(source),
+            Err(MessageFormatError::Syntax(position)),
+            "{source}"
+        );
+    }
+}
@@ -0,0 +1,205 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use fixed_decimal::FixedDecimal;
+use icu_calendar::DateTime;
+use icu_locid::locale;
+use icu_messageformat::{parts, MessageArguments, MessageFormatError, MessageFormatter};
+use icu_provider::DataLocale;
+use writeable::{assert_writeable_eq, assert_writeable_parts_eq};
+
+fn formatter(locale: DataLocale, message: &str) -> MessageFormatter {
+    MessageFormatter::try_new(&locale, message.parse().unwrap()).unwrap()
+}
+
+#[test]
+fn test_plural_selection() {
+    let formatter = formatter(
+        locale!("ru").into(),
+        "match {$count :number}
+         when 0 {нет файлов}
+         when one {{$count} файл}
+         when few {{$count} файла}
+         when * {{$count} файлов}",
+    );
+    for (count, expected) in [
+        (0, "нет файлов"),
+        (1, "1 файл"),
+        (3, "3 файла"),
+        (11, "11 файлов"),
+        (21, "21 файл"),
+        (1234, "1\u{a0}234 файла"),
+    ] {
+        let count = FixedDecimal::from(count);
+        let arguments = MessageArguments::new().with("count", &count);
+        assert_writeable_eq!(formatter.format(&arguments), expected);
+    }
+
+    // Numeric strings are selected on as numbers by `:number`, but unannotated placeholders
+    // format them as they are.
+    let arguments = MessageArguments::new().with("count", "2.5");
+    assert_writeable_eq!(formatter.format(&arguments), "2.5 файлов");
+}
+
+#[test]
+fn test_selection_on_rounded_value() {
+    let formatter = formatter(
+        locale!("en").into(),
+        "let $n = {$count :number maximumFractionDigits=0}
+         match {$n}
+         when one {{$n} day}
+         when * {{$n} days}",
+    );
+    for (count, expected) in [("1", "1 day"), ("1.2", "1 day"), ("1.5", "2 days")] {
+        let arguments = MessageArguments::new().with("count", count);
+        assert_writeable_eq!(formatter.format(&arguments), expected);
+    }
+}
+
+#[test]
+fn test_selection_on_displayed_value() {
+    let percent = formatter(
+        locale!("en").into(),
+        "let $n = {$share :number style=percent}
+         match {$n}
+         when 1 {exactly {$n}}
+         when one {{$n} point}
+         when * {{$n} points}",
+    );
+    for (share, expected) in [
+        ("0.01", "exactly 1%"),
+        ("0.014", "exactly 1%"),
+        ("0.5", "50% points"),
+    ] {
+        let arguments = MessageArguments::new().with("share", share);
+        assert_writeable_eq!(percent.format(&arguments), expected);
+    }
+
+    let significant = formatter(
+        locale!("en").into(),
+        "let $n = {$count :number maximumSignificantDigits=1 minimumSignificantDigits=1 \
+         trailingZeroDisplay=stripIfInteger}
+         match {$n}
+         when one {{$n} item}
+         when * {{$n} items}",
+    );
+    for (count, expected) in [("1.04", "1 item"), ("0.96", "1 item"), ("1.6", "2 items")] {
+        let arguments = MessageArguments::new().with("count", count);
+        assert_writeable_eq!(significant.format(&arguments), expected);
+    }
+}
+
+#[test]
+fn test_multiple_selectors() {
+    let formatter = formatter(
+        locale!("en").into(),
+        "match {$place :number select=ordinal} {$kind :string}
+         when one gold {{$place}st place, gold medal}
+         when * gold {{$place}th place, gold medal}
+         when one * {{$place}st place}
+         when two * {{$place}nd place}
+         when * * {{$place}th place}",
+    );
+    for (place, kind, expected) in [
+        (1, "gold", "1st place, gold medal"),
+        (1, "silver", "1st place"),
+        (2, "gold", "2nd place"),
+        (22, "bronze", "22nd place"),
+        (4, "bronze", "4th place"),
+    ] {
+        let place = FixedDecimal::from(place);
+        let arguments = MessageArguments::new()
+            .with("place", &place)
+            .with("kind", kind);
+        assert_writeable_eq!(formatter.format(&arguments), expected);
+    }
+}
+
+#[test]
+fn test_functions() {
+    let formatter = formatter(
+        locale!("en").into(),
+        "{{$date :datetime dateStyle=long}: {$people :list type=disjunction}, \
+         {$share :number style=percent maximumFractionDigits=1}, {|literal|}}",
+    );
+    let date = DateTime::try_new_iso_datetime(2023, 4, 5, 6, 7, 8)
+        .unwrap()
+        .to_any();
+    let people = ["Ana", "Bruno", "Chloé"];
+    let share = "0.12345".parse::<FixedDecimal>().unwrap();
+    let arguments = MessageArguments::new()
+        .with("date", &date)
+        .with("people", &people[..])
+        .with("share", &share);
+    assert_writeable_eq!(
+        formatter.format(&arguments),
+        "April 5, 2023: Ana, Bruno, or Chloé, 12.3%, literal"
+    );
+}
+
+#[test]
+fn test_fallback() {
+    let formatter = formatter(
+        locale!("en").into(),
+        "{{$missing} {$name :number} {$date} {:datetime}}",
+    );
+    let date = DateTime::try_new_iso_datetime(2023, 4, 5, 6, 7, 8)
+        .unwrap()
+        .to_any();
+    let arguments = MessageArguments::new()
+        .with("name", "Alice")
+        .with("date", &date);
+    assert_writeable_eq!(
+        formatter.format(&arguments),
+        "{$missing} {$name} {$date} {:datetime}"
+    );
+}
+
+#[test]
+fn test_parts() {
+    let formatter = formatter(locale!("en").into(), "{Hello, {$name}!}");
+    let arguments = MessageArguments::new().with("name", "Alice");
+    assert_writeable_parts_eq!(
+        formatter.format(&arguments),
+        "Hello, Alice!",
+        [
+            (0, 7, parts::LITERAL),
+            (7, 12, parts::STRING),
+            (12, 13, parts::LITERAL),
+        ]
+    );
+
+    let arguments = MessageArguments::new();
+    assert_writeable_parts_eq!(
+        formatter.format(&arguments),
+        "Hello, {$name}!",
+        [
+            (0, 7, parts::LITERAL),
+            (7, 14, parts::FALLBACK),
+            (14, 15, parts::LITERAL),
+        ]
+    );
+}
+
+#[test]
+fn test_errors() {
+    for (message, error) in [
+        ("{{$x :unknown}}", MessageFormatError::UnknownFunction),
+        (
+            "{{$x :number maximumFractionDigits=many}}",
+            MessageFormatError::InvalidOption,
+        ),
+        (
+            "{{$x :number maximumFractionDigits=$digits}}",
+            MessageFormatError::InvalidOption,
+        ),
+        ("{{$x :list unknown=1}}", MessageFormatError::InvalidOption),
+    ] {
+        assert_eq!(
+            MessageFormatter::try_new(&locale!("en").into(), message.parse().unwrap()).err(),
+            Some(error),
+            "{message}"
+        );
+    }
+}
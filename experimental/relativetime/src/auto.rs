@@ -0,0 +1,311 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use fixed_decimal::FixedDecimal;
+use icu_calendar::{AsCalendar, DateTime};
+use icu_decimal::{
+    options::FixedDecimalFormatterOptions, provider::DecimalSymbolsV1Marker, FixedDecimalFormatter,
+};
+use icu_plurals::{provider::CardinalV1Marker, PluralRules};
+use icu_provider::prelude::*;
+
+use crate::format::FormattedRelativeTime;
+use crate::options::{AutoRelativeTimeFormatterOptions, UnitThresholds};
+use crate::provider::*;
+use crate::RelativeTimeError;
+
+/// The units that [`AutoRelativeTimeFormatter`] selects from, in the order of its payloads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Unit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+const SECONDS_PER_MINUTE: u64 = 60;
+const SECONDS_PER_HOUR: u64 = 60 * SECONDS_PER_MINUTE;
+const SECONDS_PER_DAY: u64 = 24 * SECONDS_PER_HOUR;
+const SECONDS_PER_WEEK: u64 = 7 * SECONDS_PER_DAY;
+/// The number of seconds in 400 Gregorian years, which have 4800 months.
+const SECONDS_PER_400_YEARS: u128 = 146097 * SECONDS_PER_DAY as u128;
+
+/// A formatter to render locale-sensitive relative time, selecting the unit of the time
+/// automatically.
+///
+/// [`AutoRelativeTimeFormatter`] formats a duration in seconds, or the time between two
+/// [`DateTime`]s, in the largest unit that fits the duration according to
+/// [`UnitThresholds`], such as "5 minutes ago" or "in 3 weeks".
+///
+/// # Example
+///
+/// ```
+/// use icu::calendar::DateTime;
+/// use icu::locid::locale;
+/// use icu::relativetime::options::Numeric;
+/// use icu::relativetime::{
+///     AutoRelativeTimeFormatter, AutoRelativeTimeFormatterOptions,
+/// };
+/// use writeable::assert_writeable_eq;
+///
+/// let mut options = AutoRelativeTimeFormatterOptions::default();
+/// options.numeric = Numeric::Auto;
+/// let formatter =
+///     AutoRelativeTimeFormatter::try_new_long(&locale!("en").into(), options)
+///         .expect("locale should be present");
+///
+/// assert_writeable_eq!(formatter.format_seconds(-5 * 60), "5 minutes ago");
+/// assert_writeable_eq!(formatter.format_seconds(3 * 60 * 60), "in 3 hours");
+///
+/// let now = DateTime::try_new_iso_datetime(2023, 6, 15, 12, 0, 0).unwrap();
+/// let posted = DateTime::try_new_iso_datetime(2023, 6, 14, 9, 30, 0).unwrap();
+/// assert_writeable_eq!(formatter.format_datetimes(&now, &posted), "yesterday");
+/// ```
+///
+/// # Example
+///
+/// Weeks are only selected if they have a threshold:
+///
+/// ```
+/// use icu::locid::locale;
+/// use icu::relativetime::{
+///     AutoRelativeTimeFormatter, AutoRelativeTimeFormatterOptions,
+/// };
+/// use writeable::assert_writeable_eq;
+///
+/// let mut options = AutoRelativeTimeFormatterOptions::default();
+/// options.thresholds.day = 7;
+/// options.thresholds.week = Some(4);
+/// let formatter =
+///     AutoRelativeTimeFormatter::try_new_short(&locale!("en").into(), options)
+///         .expect("locale should be present");
+///
+/// assert_writeable_eq!(formatter.format_seconds(3 * 24 * 60 * 60), "in 3 days");
+/// assert_writeable_eq!(formatter.format_seconds(20 * 24 * 60 * 60), "in 3 wk.");
+/// assert_writeable_eq!(formatter.format_seconds(40 * 24 * 60 * 60), "in 1 mo.");
+/// ```
+#[derive(Debug)]
+pub struct AutoRelativeTimeFormatter {
+    plural_rules: PluralRules,
+    /// The data of each unit, in the order of [`Unit`].
+    rt: [DataPayload<ErasedRelativeTimeFormatV1Marker>; 7],
+    options: AutoRelativeTimeFormatterOptions,
+    fixed_decimal_format: FixedDecimalFormatter,
+}
+
+macro_rules! constructor {
+    ($unstable: ident, $baked: ident, $any: ident, $buffer: ident, $width: literal,
+     [$($marker: ty),+ $(,)?]) => {
+
+        #[doc = concat!("Create a new [`AutoRelativeTimeFormatter`] in the ", $width, " width, from compiled data.")]
+        ///
+        /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+        ///
+        /// [📚 Help choosing a constructor](icu_provider::constructors)
+        #[cfg(feature = "compiled_data")]
+        pub fn $baked(
+            locale: &DataLocale,
+            options: AutoRelativeTimeFormatterOptions,
+        ) -> Result<Self, RelativeTimeError> {
+            let plural_rules = PluralRules::try_new_cardinal(locale)?;
+            // Initialize FixedDecimalFormatter with default options
+            let fixed_decimal_format = FixedDecimalFormatter::try_new(
+                locale,
+                FixedDecimalFormatterOptions::default(),
+            )?;
+            let rt = [$(
+                DataProvider::<$marker>::load(
+                    &crate::provider::Baked,
+                    DataRequest {
+                        locale,
+                        metadata: Default::default(),
+                    },
+                )?
+                .take_payload()?
+                .cast(),
+            )+];
+            Ok(AutoRelativeTimeFormatter {
+                plural_rules,
+                rt,
+                options,
+                fixed_decimal_format,
+            })
+        }
+
+        icu_provider::gen_any_buffer_data_constructors!(
+            locale: include,
+            options: AutoRelativeTimeFormatterOptions,
+            error: RelativeTimeError,
+            #[cfg(skip)]
+            functions: [
+                $baked,
+                $any,
+                $buffer,
+                $unstable,
+                Self,
+            ]
+        );
+
+        #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::$baked)]
+        pub fn $unstable<D>(
+            provider: &D,
+            locale: &DataLocale,
+            options: AutoRelativeTimeFormatterOptions,
+        ) -> Result<Self, RelativeTimeError>
+        where
+            D: DataProvider<CardinalV1Marker>
+                $(+ DataProvider<$marker>)+
+                + DataProvider<DecimalSymbolsV1Marker>
+                + ?Sized,
+        {
+            let plural_rules = PluralRules::try_new_cardinal_unstable(provider, locale)?;
+            // Initialize FixedDecimalFormatter with default options
+            let fixed_decimal_format = FixedDecimalFormatter::try_new_unstable(
+                provider,
+                locale,
+                FixedDecimalFormatterOptions::default(),
+            )?;
+            let rt = [$(
+                DataProvider::<$marker>::load(
+                    provider,
+                    DataRequest {
+                        locale,
+                        metadata: Default::default(),
+                    },
+                )?
+                .take_payload()?
+                .cast(),
+            )+];
+            Ok(AutoRelativeTimeFormatter {
+                plural_rules,
+                rt,
+                options,
+                fixed_decimal_format,
+            })
+        }
+    };
+}
+
+impl AutoRelativeTimeFormatter {
+    constructor!(
+        try_new_long_unstable,
+        try_new_long,
+        try_new_long_with_any_provider,
+        try_new_long_with_buffer_provider,
+        "long",
+        [
+            LongSecondRelativeTimeFormatDataV1Marker,
+            LongMinuteRelativeTimeFormatDataV1Marker,
+            LongHourRelativeTimeFormatDataV1Marker,
+            LongDayRelativeTimeFormatDataV1Marker,
+            LongWeekRelativeTimeFormatDataV1Marker,
+            LongMonthRelativeTimeFormatDataV1Marker,
+            LongYearRelativeTimeFormatDataV1Marker,
+        ]
+    );
+    constructor!(
+        try_new_short_unstable,
+        try_new_short,
+        try_new_short_with_any_provider,
+        try_new_short_with_buffer_provider,
+        "short",
+        [
+            ShortSecondRelativeTimeFormatDataV1Marker,
+            ShortMinuteRelativeTimeFormatDataV1Marker,
+            ShortHourRelativeTimeFormatDataV1Marker,
+            ShortDayRelativeTimeFormatDataV1Marker,
+            ShortWeekRelativeTimeFormatDataV1Marker,
+            ShortMonthRelativeTimeFormatDataV1Marker,
+            ShortYearRelativeTimeFormatDataV1Marker,
+        ]
+    );
+    constructor!(
+        try_new_narrow_unstable,
+        try_new_narrow,
+        try_new_narrow_with_any_provider,
+        try_new_narrow_with_buffer_provider,
+        "narrow",
+        [
+            NarrowSecondRelativeTimeFormatDataV1Marker,
+            NarrowMinuteRelativeTimeFormatDataV1Marker,
+            NarrowHourRelativeTimeFormatDataV1Marker,
+            NarrowDayRelativeTimeFormatDataV1Marker,
+            NarrowWeekRelativeTimeFormatDataV1Marker,
+            NarrowMonthRelativeTimeFormatDataV1Marker,
+            NarrowYearRelativeTimeFormatDataV1Marker,
+        ]
+    );
+
+    /// Format a duration of `seconds`, which is in the past if it is negative, in the unit
+    /// selected by the [`UnitThresholds`] of this [`AutoRelativeTimeFormatter`].
+    pub fn format_seconds(&self, seconds: i64) -> FormattedRelativeTime<'_> {
+        let (unit, value) = select_unit(seconds.unsigned_abs(), &self.options.thresholds);
+        FormattedRelativeTime {
+            rt: &self.rt[unit as usize],
+            plural_rules: &self.plural_rules,
+            fixed_decimal_format: &self.fixed_decimal_format,
+            numeric: self.options.numeric,
+            value: FixedDecimal::from(value),
+            is_negative: seconds < 0,
+        }
+    }
+
+    /// Format the time of `datetime` relative to `now`, such as "5 minutes ago" if
+    /// `datetime` is five minutes before `now`.
+    ///
+    /// The [`DateTime`]s are compared in local time, with a precision of seconds.
+    pub fn format_datetimes<A: AsCalendar, B: AsCalendar>(
+        &self,
+        now: &DateTime<A>,
+        datetime: &DateTime<B>,
+    ) -> FormattedRelativeTime<'_> {
+        let seconds_since_epoch = |datetime: DateTime<icu_calendar::Iso>| {
+            i64::from(datetime.minutes_since_local_unix_epoch()) * 60
+                + i64::from(datetime.time.second.number())
+        };
+        self.format_seconds(
+            seconds_since_epoch(datetime.to_iso()) - seconds_since_epoch(now.to_iso()),
+        )
+    }
+}
+
+/// Returns the unit of a duration of `seconds`, and the duration rounded to that unit.
+fn select_unit(seconds: u64, thresholds: &UnitThresholds) -> (Unit, u64) {
+    // Rounds half up, like Moment.js.
+    let round = |divisor: u64| seconds / divisor + u64::from(seconds % divisor * 2 >= divisor);
+    let minutes = round(SECONDS_PER_MINUTE);
+    let hours = round(SECONDS_PER_HOUR);
+    let days = round(SECONDS_PER_DAY);
+    let weeks = round(SECONDS_PER_WEEK);
+    // Months and years have their average length in the Gregorian calendar.
+    let round_scaled = |scale: u128| {
+        let scaled = u128::from(seconds) * scale;
+        let rounded = (scaled + SECONDS_PER_400_YEARS / 2) / SECONDS_PER_400_YEARS;
+        u64::try_from(rounded).unwrap_or(u64::MAX)
+    };
+    let months = round_scaled(4800);
+    let years = round_scaled(400);
+
+    if seconds < u64::from(thresholds.second) {
+        (Unit::Second, seconds)
+    } else if minutes < u64::from(thresholds.minute) {
+        (Unit::Minute, minutes)
+    } else if hours < u64::from(thresholds.hour) {
+        (Unit::Hour, hours)
+    } else if days < u64::from(thresholds.day) {
+        (Unit::Day, days)
+    } else if thresholds
+        .week
+        .map_or(false, |threshold| weeks < u64::from(threshold))
+    {
+        (Unit::Week, weeks)
+    } else if months < u64::from(thresholds.month) {
+        (Unit::Month, months)
+    } else {
+        (Unit::Year, years)
+    }
+}
@@ -5,18 +5,24 @@
 use std::fmt::Write;
 
 use fixed_decimal::FixedDecimal;
+use icu_decimal::FixedDecimalFormatter;
+use icu_plurals::PluralRules;
+use icu_provider::DataPayload;
 use writeable::Writeable;
 
-use crate::{
-    options::{Numeric, RelativeTimeFormatterOptions},
-    relativetime::RelativeTimeFormatter,
-};
+use crate::{options::Numeric, provider::ErasedRelativeTimeFormatV1Marker};
 
+/// The [`Part`](writeable::Part)s used by [`FormattedRelativeTime`].
+///
+/// The number is annotated with the parts of [`icu_decimal::parts`].
 pub mod parts {
     use writeable::Part;
 
     /// The [`Part`] used by [`FormattedRelativeTime`](crate::FormattedRelativeTime) to mark the
     /// part of the string that is without a placeholder.
+    ///
+    /// * `category`: `"relativetime"`
+    /// * `value`: `"literal"`
     pub const LITERAL: Part = Part {
         category: "relativetime",
         value: "literal",
@@ -26,16 +32,18 @@ pub mod parts {
 /// An intermediate structure returned by [`RelativeTimeFormatter`](crate::RelativeTimeFormatter).
 /// This structure can be consumed via [`Writeable`](Writeable) trait to a string or buffer.
 pub struct FormattedRelativeTime<'a> {
-    pub(crate) formatter: &'a RelativeTimeFormatter,
-    pub(crate) options: &'a RelativeTimeFormatterOptions,
+    pub(crate) rt: &'a DataPayload<ErasedRelativeTimeFormatV1Marker>,
+    pub(crate) plural_rules: &'a PluralRules,
+    pub(crate) fixed_decimal_format: &'a FixedDecimalFormatter,
+    pub(crate) numeric: Numeric,
     pub(crate) value: FixedDecimal,
     pub(crate) is_negative: bool,
 }
 
 impl<'a> Writeable for FormattedRelativeTime<'a> {
     fn write_to_parts<S: writeable::PartsWrite + ?Sized>(&self, sink: &mut S) -> core::fmt::Result {
-        if self.options.numeric == Numeric::Auto {
-            let relatives = &self.formatter.rt.get().relatives;
+        if self.numeric == Numeric::Auto {
+            let relatives = &self.rt.get().relatives;
             if self.value.magnitude_range() == (0..=0) {
                 // Can be cast without overflow as it is a single digit.
                 let i8_value = if self.is_negative {
@@ -51,11 +59,11 @@ impl<'a> Writeable for FormattedRelativeTime<'a> {
         }
 
         let plural_rules_mapping = if self.is_negative {
-            &self.formatter.rt.get().past
+            &self.rt.get().past
         } else {
-            &self.formatter.rt.get().future
+            &self.rt.get().future
         };
-        let category = self.formatter.plural_rules.category_for(&self.value);
+        let category = self.plural_rules.category_for(&self.value);
         let singular_sub_pattern = match category {
             icu_plurals::PluralCategory::Zero => &plural_rules_mapping.zero,
             icu_plurals::PluralCategory::One => &plural_rules_mapping.one,
@@ -86,8 +94,7 @@ impl<'a> Writeable for FormattedRelativeTime<'a> {
                 };
 
             sink.with_part(parts::LITERAL, |s| s.write_str(prefix))?;
-            self.formatter
-                .fixed_decimal_format
+            self.fixed_decimal_format
                 .format(&self.value)
                 .write_to_parts(sink)?;
             sink.with_part(parts::LITERAL, |s| s.write_str(suffix))?;
@@ -17,14 +17,18 @@
 
 extern crate alloc;
 
+mod auto;
 mod error;
 mod format;
 pub mod options;
 pub mod provider;
 mod relativetime;
 
+pub use auto::AutoRelativeTimeFormatter;
 pub use error::RelativeTimeError;
+pub use format::parts;
 pub use format::FormattedRelativeTime;
+pub use options::AutoRelativeTimeFormatterOptions;
 pub use options::RelativeTimeFormatterOptions;
 pub use relativetime::RelativeTimeFormatter;
 #[doc(no_inline)]
@@ -22,3 +22,60 @@ pub enum Numeric {
     /// Automatically select special formatting if available else fallback to numeric formatting.
     Auto,
 }
+
+/// A bag of options for defining how to format time using
+/// [`AutoRelativeTimeFormatter`](crate::AutoRelativeTimeFormatter).
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct AutoRelativeTimeFormatterOptions {
+    /// Whether to always use numeric formatting for time.
+    pub numeric: Numeric,
+    /// The thresholds for selecting the unit of a duration.
+    pub thresholds: UnitThresholds,
+}
+
+impl From<RelativeTimeFormatterOptions> for AutoRelativeTimeFormatterOptions {
+    fn from(options: RelativeTimeFormatterOptions) -> Self {
+        Self {
+            numeric: options.numeric,
+            ..Default::default()
+        }
+    }
+}
+
+/// Thresholds for selecting the unit of a duration, as in Moment.js.
+///
+/// A duration is rounded to each unit from seconds to years, and formatted in the first unit
+/// whose rounded value is less than the threshold of that unit. Durations that reach the
+/// thresholds of all units are formatted in years.
+///
+/// The defaults are those of Moment.js, which never selects weeks. To select weeks, set
+/// [`week`](Self::week) and lower [`day`](Self::day), e.g. to 7.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct UnitThresholds {
+    /// The number of seconds below which a duration is formatted in seconds. Defaults to 45.
+    pub second: u32,
+    /// The number of minutes below which a duration is formatted in minutes. Defaults to 45.
+    pub minute: u32,
+    /// The number of hours below which a duration is formatted in hours. Defaults to 22.
+    pub hour: u32,
+    /// The number of days below which a duration is formatted in days. Defaults to 26.
+    pub day: u32,
+    /// The number of weeks below which a duration is formatted in weeks, if any.
+    /// Defaults to [`None`].
+    pub week: Option<u32>,
+    /// The number of months below which a duration is formatted in months. Defaults to 11.
+    pub month: u32,
+}
+
+impl Default for UnitThresholds {
+    fn default() -> Self {
+        Self {
+            second: 45,
+            minute: 45,
+            hour: 22,
+            day: 26,
+            week: None,
+            month: 11,
+        }
+    }
+}
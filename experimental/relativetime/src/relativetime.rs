@@ -373,8 +373,10 @@ impl RelativeTimeFormatter {
     pub fn format(&self, value: FixedDecimal) -> FormattedRelativeTime<'_> {
         let is_negative = value.sign() == Sign::Negative;
         FormattedRelativeTime {
-            options: &self.options,
-            formatter: self,
+            rt: &self.rt,
+            plural_rules: &self.plural_rules,
+            fixed_decimal_format: &self.fixed_decimal_format,
+            numeric: self.options.numeric,
             value: value.with_sign(Sign::None),
             is_negative,
         }
@@ -1241,3 +1241,102 @@ generate_test!(
         (10, "خلال ١٠ سنوات")
     ]
 );
+
+#[test]
+fn test_parts() {
+    use icu_relativetime::parts;
+    use writeable::assert_writeable_parts_eq;
+
+    let relative_time_formatter =
+        RelativeTimeFormatter::try_new_long_day(&locale!("en").into(), Default::default())
+            .expect("locale should be present");
+    assert_writeable_parts_eq!(
+        relative_time_formatter.format(FixedDecimal::from(-1234)),
+        "1,234 days ago",
+        [
+            (0, 1, icu_decimal::parts::INTEGER),
+            (1, 2, icu_decimal::parts::GROUP),
+            (2, 5, icu_decimal::parts::INTEGER),
+            (5, 14, parts::LITERAL),
+        ]
+    );
+}
+
+#[test]
+fn test_auto_unit() {
+    use icu_relativetime::{AutoRelativeTimeFormatter, AutoRelativeTimeFormatterOptions};
+
+    const MINUTE: i64 = 60;
+    const HOUR: i64 = 60 * MINUTE;
+    const DAY: i64 = 24 * HOUR;
+
+    let formatter = AutoRelativeTimeFormatter::try_new_long(
+        &locale!("en").into(),
+        AutoRelativeTimeFormatterOptions::default(),
+    )
+    .expect("locale should be present");
+
+    for (seconds, expected) in [
+        (0, "in 0 seconds"),
+        (44, "in 44 seconds"),
+        (-45, "1 minute ago"),
+        (89, "in 1 minute"),
+        (90, "in 2 minutes"),
+        (44 * MINUTE + 29, "in 44 minutes"),
+        (-(44 * MINUTE + 30), "1 hour ago"),
+        (21 * HOUR, "in 21 hours"),
+        (22 * HOUR, "in 1 day"),
+        (-25 * DAY, "25 days ago"),
+        (26 * DAY, "in 1 month"),
+        (300 * DAY, "in 10 months"),
+        (-320 * DAY, "1 year ago"),
+        (3650 * DAY, "in 10 years"),
+    ] {
+        assert_writeable_eq!(formatter.format_seconds(seconds), expected, "{seconds}");
+    }
+
+    let mut options = AutoRelativeTimeFormatterOptions {
+        numeric: Numeric::Auto,
+        ..Default::default()
+    };
+    options.thresholds.day = 7;
+    options.thresholds.week = Some(4);
+    let formatter = AutoRelativeTimeFormatter::try_new_long(&locale!("en").into(), options)
+        .expect("locale should be present");
+
+    for (seconds, expected) in [
+        (0, "now"),
+        (-DAY, "yesterday"),
+        (6 * DAY, "in 6 days"),
+        (-7 * DAY, "last week"),
+        (24 * DAY, "in 3 weeks"),
+        (26 * DAY, "next month"),
+    ] {
+        assert_writeable_eq!(formatter.format_seconds(seconds), expected, "{seconds}");
+    }
+}
+
+#[test]
+fn test_auto_unit_datetimes() {
+    use icu::calendar::{DateTime, Gregorian};
+    use icu_relativetime::{AutoRelativeTimeFormatter, AutoRelativeTimeFormatterOptions};
+
+    let formatter = AutoRelativeTimeFormatter::try_new_short(
+        &locale!("en").into(),
+        AutoRelativeTimeFormatterOptions::default(),
+    )
+    .expect("locale should be present");
+
+    let now = DateTime::try_new_iso_datetime(2023, 12, 31, 23, 58, 30).unwrap();
+    for ((year, month, day, hour, minute, second), expected) in [
+        ((2023, 12, 31, 23, 58, 25), "5 sec. ago"),
+        ((2024, 1, 1, 0, 3, 30), "in 5 min."),
+        ((2023, 12, 28, 23, 58, 30), "3 days ago"),
+        ((2026, 1, 1, 0, 0, 0), "in 2 yr."),
+    ] {
+        let datetime = DateTime::try_new_iso_datetime(year, month, day, hour, minute, second)
+            .unwrap()
+            .to_calendar(Gregorian);
+        assert_writeable_eq!(formatter.format_datetimes(&now, &datetime), expected);
+    }
+}
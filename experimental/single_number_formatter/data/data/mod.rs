@@ -31,12 +31,11 @@ pub use __impl_data_provider as impl_data_provider;
 #[macro_export]
 macro_rules! __impl_any_provider {
     ($ provider : path) => {
-        #[clippy::msrv = "1.65"]
+        #[clippy::msrv = "1.66"]
         impl icu_provider::AnyProvider for $provider {
             fn load_any(&self, key: icu_provider::DataKey, req: icu_provider::DataRequest) -> Result<icu_provider::AnyResponse, icu_provider::DataError> {
-                const CURRENCY_ESSENTIALS_V1: icu_provider::DataKeyHash = <icu_singlenumberformatter::provider::CurrencyEssentialsV1Marker as icu_provider::KeyedDataMarker>::KEY.hashed();
                 match key.hashed() {
-                    CURRENCY_ESSENTIALS_V1 => icu_provider::DataProvider::<icu_singlenumberformatter::provider::CurrencyEssentialsV1Marker>::load(self, req).and_then(|r| r.take_metadata_and_payload()).map(|(metadata, payload)| icu_provider::AnyResponse { payload: Some(payload.wrap_into_any_payload()), metadata }),
+                    h if h == <icu::singlenumberformatter::provider::CurrencyEssentialsV1Marker as icu_provider::KeyedDataMarker>::KEY.hashed() => icu_provider::DataProvider::<icu::singlenumberformatter::provider::CurrencyEssentialsV1Marker>::load(self, req).map(icu_provider::DataResponse::wrap_into_any_response),
                     _ => Err(icu_provider::DataErrorKind::MissingDataKey.with_req(key, req)),
                 }
             }
@@ -45,6 +44,6 @@ macro_rules! __impl_any_provider {
 }
 #[doc(inline)]
 pub use __impl_any_provider as impl_any_provider;
-#[clippy::msrv = "1.65"]
+#[clippy::msrv = "1.66"]
 pub struct BakedDataProvider;
 impl_data_provider!(BakedDataProvider);
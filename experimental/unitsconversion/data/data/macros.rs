@@ -4,3 +4,13 @@
 mod units_constants_v1;
 #[doc(inline)]
 pub use __impl_units_constants_v1 as impl_units_constants_v1;
+#[macro_use]
+#[path = "macros/units_info_v1.data.rs"]
+mod units_info_v1;
+#[doc(inline)]
+pub use __impl_units_info_v1 as impl_units_info_v1;
+#[macro_use]
+#[path = "macros/units_preferences_v1.data.rs"]
+mod units_preferences_v1;
+#[doc(inline)]
+pub use __impl_units_preferences_v1 as impl_units_preferences_v1;
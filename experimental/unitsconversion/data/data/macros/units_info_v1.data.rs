@@ -0,0 +1,34 @@
+// @generated
+/// Implement `DataProvider<UnitsInfoV1Marker>` on the given struct using the data
+/// hardcoded in this file. This allows the struct to be used with
+/// `icu`'s `_unstable` constructors.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_units_info_v1 {
+    ($ provider : path) => {
+        #[clippy::msrv = "1.66"]
+        impl $provider {
+            #[doc(hidden)]
+            pub const SINGLETON_UNITS_INFO_V1: &'static <icu::unitsconversion::provider::UnitsInfoV1Marker as icu_provider::DataMarker>::Yokeable = &icu::unitsconversion::provider::UnitsInfoV1 {
+                conversions: unsafe {
+                    #[allow(unused_unsafe)]
+                    zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"q\0\0\0\0\0\r\0\x11\0\x17\0!\0+\0<\0F\0I\0O\0R\0f\0l\0p\0w\0~\0\x83\0\x8A\0\x91\0\x94\0\x9E\0\xA4\0\xA7\0\xB1\0\xB7\0\xBD\0\xCA\0\xE0\0\xE3\0\xE7\0\xEB\0\xF0\0\xFA\0\x06\x01\x12\x01\x14\x01\x1E\x01$\x01/\x01C\x01N\x01R\x01Y\x01`\x01f\x01u\x01z\x01~\x01\x85\x01\x8A\x01\x94\x01\x98\x01\x9C\x01\xA0\x01\xA6\x01\xAB\x01\xB0\x01\xB6\x01\xBE\x01\xC2\x01\xCC\x01\xD1\x01\xD6\x01\xD9\x01\xDE\x01\xE2\x01\xF3\x01\xF9\x01\xFD\x01\x02\x02\x0E\x02\x1B\x02!\x02*\x02.\x021\x026\x02@\x02F\x02L\x02S\x02[\x02e\x02n\x02s\x02w\x02\x82\x02\x87\x02\x8C\x02\x93\x02\x98\x02\xA3\x02\xA8\x02\xB6\x02\xBD\x02\xC3\x02\xCD\x02\xD3\x02\xE3\x02\xED\x02\xF9\x02\xFE\x02\x08\x03\x10\x03\x18\x03\x1B\x03 \x03$\x03(\x03,\x037\x03;\x03?\x03100-kilometeracreamperearc-minutearc-secondastronomical-unitatmospherebarbarrelbitbritish-thermal-unitbushelbytecaloriecandelacaratcelsiuscenturycupcup-metricdaltondayday-persondecadedegreedessert-spoondessert-spoon-imperialdotdramdropdunamearth-massearth-radiuselectronvoltemfahrenheitfathomfluid-ouncefluid-ounce-imperialfoodcaloriefootfurlongg-forcegallongallon-imperialgraingramhectarehertzhorsepowerhourinchitemjiggerjoulekaratkelvinkilogramknotlight-yearliterlumenluxmetermilemile-scandinavianminutemolemonthmonth-personnautical-milenewtonofglucoseofhgohmounceounce-troyparsecpascalpercentpermillepermillionpermyriadpinchpintpint-metricpixelpointportionpoundpound-forcequartquart-imperialquarterradianrevolutionsecondsolar-luminositysolar-masssolar-radiusstonetablespoonteaspoontherm-ustontonnevoltwattweekweek-personyardyearyear-person") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"q\0\0\0\0\0\x15\x001\0G\0a\0{\0\x90\0\xC0\0\xF0\0\x0B\x01\x1E\x01U\x01p\x01\x83\x01\xBA\x01\xD1\x01\xE9\x01\xFF\x01\x13\x02.\x02I\x02\x80\x02\x96\x02\xAC\x02\xC0\x02\xDA\x02\xF5\x02\x10\x03%\x03@\x03[\x03w\x03\x8F\x03\xA4\x03\xDB\x03\xED\x03\x03\x04\x18\x043\x04N\x04\x85\x04\x9A\x04\xAF\x04\xD6\x04\xF1\x04\x0C\x05$\x05<\x05X\x05}\x05\xB3\x05\xC9\x05\xDE\x05\xF2\x05\r\x06D\x06[\x06q\x06\x89\x06\xA9\x06\xBE\x06\xD9\x06\x0E\x076\x07K\x07`\x07u\x07\x8B\x07\x9F\x07\xB3\x07\xC7\x07\xDC\x07\x0C\x08-\x08d\x08\xA8\x08\xC0\x08\xD8\x08\xED\x08\x1D\t4\tK\tb\ty\t\x94\t\xAF\t\xCA\t\xDF\t\xF4\t\x0B\n#\nS\nn\n\x89\n\x9D\n\xB7\n\xD1\n\xE7\n\x1D\x0B5\x0BJ\x0Bb\x0B}\x0B\x98\x0B\xCF\x0B\xE7\x0B\xFF\x0B<\x0Cr\x0C\x88\x0C\x9E\x0C\xB3\x0C\xC7\x0C\0\0\0\0\0j\xF8@\0\0\0\0\0\0\0\0meter /\xFF|\xB6\x9D\xAF@\0\0\0\0\0\0\0\0square-meter\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\0\0ampere)Q\xCE\xA0\xC8E\x08?\0\0\0\0\0\0\0\0revolution\x81E\x0F4\t\xE4\xA9>\0\0\0\0\0\0\0\0revolution\0\x006-]jAB\0\0\0\0\0\0\0\0meter\0\0\0\0\xD0\xBC\xF8@\0\0\0\0\0\0\0\0kilogram-per-meter-square-second\0\0\0\0\0j\xF8@\0\0\0\0\0\0\0\0kilogram-per-meter-square-second\x80\xFE\x18\x18\xB2Y\xC4?\0\0\0\0\0\0\0\0cubic-meter\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\0\0bit\xEA\xF2\xBB\xABfy\x90@\0\0\0\0\0\0\0\0kilogram-square-meter-per-square-secondr\xFF\xD0\xFB\xDA\n\xA2?\0\0\0\0\0\0\0\0cubic-meter\0\0\0\0\0\0 @\0\0\0\0\0\0\0\0bit#\xDB\xF9~j\xBC\x10@\0\0\0\0\0\0\0\0kilogram-square-meter-per-square-second\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\0\0candela-C\x1C\xEB\xE26*?\0\0\0\0\0\0\0\0kilogram\0\0\0\0\0\0\xF0?fffff\x12q@kelvin\0\0\0\0\0\0Y@\0\0\0\0\0\0\0\0year\x93\xB4\rz\x95\x02/?\0\0\0\0\0\0\0\0cubic-meter\xFC\xA9\xF1\xD2Mb0?\0\0\0\0\0\0\0\0cubic-meter\xF3R\xF3\xB4\xFA\x82\xE4=\0\0\0\0\0\0\0\0kilogram-square-meter-per-square-second\0\0\0\0\0\x18\xF5@\0\0\0\0\0\0\0\0second\0\0\0\0\0\x18\xF5@\0\0\0\0\0\0\0\0second\0\0\0\0\0\0$@\0\0\0\0\0\0\0\0year\x17l\xC1\x16l\xC1f?\0\0\0\0\0\0\0\0revolution\x93\xB4\rz\x95\x02\xBF>\0\0\0\0\0\0\0\0cubic-meter\xBD\xA8\xFE\xBD\xEB\x9E\xC2>\0\0\0\0\0\0\0\0cubic-meter\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\0\0pixel\x93\xB4\rz\x95\x02\xCF>\0\0\0\0\0\0\0\0cubic-meterJ\x12E\xDE\x84\x90k>\0\0\0\0\0\0\0\0cubic-meter\0\0\0\0\0@\x8F@\0\0\0\0\0\0\0\0square-meter!!bq\xA6\xC2\x13E\0\0\0\0\0\0\0\0kilogram\0\0\0\0\x9DTXA\0\0\0\0\0\0\0\0meter\xDC\x17\xAA\x83\xDA\xA4\x07<\0\0\0\0\0\0\0\0kilogram-square-meter-per-square-second\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\0\0emr\x1C\xC7q\x1C\xC7\xE1?>\xE9\x93>\xE9\xEBo@kelvin\xFC\xCB\xEE\xC9\xC3B\xFD?\0\0\0\0\0\0\0\0meter\x93\xB4\rz\x95\x02\xFF>\0\0\0\0\0\0\0\0cubic-meterbt\x97\xC9\x12\xCB\xFD>\0\0\0\0\0\0\0\0cubic-meter\0\0\0\0\0X\xB0@\0\0\0\0\0\0\0\0kilogram-square-meter-per-square-second\xFD\x87\xF4\xDB\xD7\x81\xD3?\0\0\0\0\0\0\0\0meterL7\x89A`%i@\0\0\0\0\0\0\0\0meter\x05\xA3\x92:\x01\x9D#@\0\0\0\0\0\0\0\0meter-per-square-second\x93\xB4\rz\x95\x02o?\0\0\0\0\0\0\0\0cubic-meter\xBD\xA8\xFE\xBD\xEB\x9Er?\0\0\0\0\0\0\0\0cubic-meter\xEB\x80\x0C\xCC\x94\xFC\x10?\0\0\0\0\0\0\0\0kilogram\xFC\xA9\xF1\xD2MbP?\0\0\0\0\0\0\0\0kilogram\0\0\0\0\0\x88\xC3@\0\0\0\0\0\0\0\0square-meter\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\0\0revolution-per-second\x02\xAAEV\x99M\x87@\0\0\0\0\0\0\0\0kilogram-square-meter-per-cubic-second\0\0\0\0\0 \xAC@\0\0\0\0\0\0\0\0second\xA7\nF%u\x02\x9A?\0\0\0\0\0\0\0\0meter\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\0\0itemnG\x8A\x1B\xF0A\x07?\0\0\0\0\0\0\0\0cubic-meter\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\0\0kilogram-square-meter-per-square-secondUUUUUU\xA5?\0\0\0\0\0\0\0\0portion\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\0\0kelvin\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\0\0kilogram\xCC\xED\x0F2Tv\xE0?\0\0\0\0\0\0\0\0meter-per-second`#\x91\xFB=\xCE@C\0\0\0\0\0\0\0\0meter\xFC\xA9\xF1\xD2MbP?\0\0\0\0\0\0\0\0cubic-meter\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\0\0candela-square-meter-per-square-meter\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\0\0candela-per-square-meter\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\0\0meterL7\x89A`%\x99@\0\0\0\0\0\0\0\0meter\0\0\0\0\0\x88\xC3@\0\0\0\0\0\0\0\0meter\0\0\0\0\0\0N@\0\0\0\0\0\0\0\0second\x17\xC5W\xCA\x85\xE1\xDFD\0\0\0\0\0\0\0\0itemUUUUUU\xB5?\0\0\0\0\0\0\0\0yearUUUUUU\xB5?\0\0\0\0\0\0\0\0year\0\0\0\0\0\xF0\x9C@\0\0\0\0\0\0\0\0meter\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\0\0kilogram-meter-per-square-second\xB5\xCD\xD9`\xD3\x1E\x06E\0\0\0\0\0\0\0\0item-per-kilogram\x18\tm\x19SF\0A\0\0\0\0\0\0\0\0kilogram-per-square-meter-square-second\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\0\0kilogram-square-meter-per-cubic-second-square-ampereQ^\xB7J\xA8\x07\x9D?\0\0\0\0\0\0\0\0kilogramk1\x915\x97\xD9\x9F?\0\0\0\0\0\0\0\0kilogram\xA2'W\xBE\x04h[C\0\0\0\0\0\0\0\0meter\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\0\0kilogram-per-meter-square-second{\x14\xAEG\xE1z\x84?\0\0\0\0\0\0\0\0portion\xFC\xA9\xF1\xD2MbP?\0\0\0\0\0\0\0\0portion\x8D\xED\xB5\xA0\xF7\xC6\xB0>\0\0\0\0\0\0\0\0portion-C\x1C\xEB\xE26\x1A?\0\0\0\0\0\0\0\0portion\x93\xB4\rz\x95\x02\x8F>\0\0\0\0\0\0\0\0cubic-meter\x93\xB4\rz\x95\x02??\0\0\0\0\0\0\0\0cubic-meter\xFC\xA9\xF1\xD2Mb@?\0\0\0\0\0\0\0\0cubic-meter\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\0\0pixelx\t\xB0\x04\xA1\x1E7?\0\0\0\0\0\0\0\0meter\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\0\0portionQ^\xB7J\xA8\x07\xDD?\0\0\0\0\0\0\0\0kilogram\xD7\x9Dk\x9B\xFA\xCA\x11@\0\0\0\0\0\0\0\0kilogram-meter-per-square-second\x93\xB4\rz\x95\x02O?\0\0\0\0\0\0\0\0cubic-meter\xBD\xA8\xFE\xBD\xEB\x9ER?\0\0\0\0\0\0\0\0cubic-meter\0\0\0\0\0\0\xD0?\0\0\0\0\0\0\0\0year\x83\xC8\xC9m0_\xC4?\0\0\0\0\0\0\0\0revolution\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\0\0revolution\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\0\0secondOMM\xD9P\xCAsE\0\0\0\0\0\0\0\0kilogram-square-meter-per-cubic-second\xD0\x1C(\xD5\x17\x199F\0\0\0\0\0\0\0\0kilogram\0\0\0\x10\xC5\xBB\xC4A\0\0\0\0\0\0\0\0meter\x87r`A\xB3f\x19@\0\0\0\0\0\0\0\0kilogram\x93\xB4\rz\x95\x02\xEF>\0\0\0\0\0\0\0\0cubic-meter\xB7\xCD\xB3\xA6c\xAC\xD4>\0\0\0\0\0\0\0\0cubic-meter\0\0\0@\x03&\x99A\0\0\0\0\0\0\0\0kilogram-square-meter-per-square-second\x1B\x12\xF7XzY\x8C@\0\0\0\0\0\0\0\0kilogram\0\0\0\0\0@\x8F@\0\0\0\0\0\0\0\0kilogram\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\0\0kilogram-square-meter-per-cubic-second-ampere\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\0\0kilogram-square-meter-per-cubic-second\0\0\0\0\0u\"A\0\0\0\0\0\0\0\0second\0\0\0\0\0u\"A\0\0\0\0\0\0\0\0second\xFC\xCB\xEE\xC9\xC3B\xED?\0\0\0\0\0\0\0\0meter\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\0\0year\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\0\0year") })
+                },
+                quantities: unsafe {
+                    #[allow(unused_unsafe)]
+                    zerovec::ZeroMap::from_parts_unchecked(unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x0E\0\0\0\0\0\x04\0\x11\0\x1C\0$\0*\x000\x004\0@\0E\0M\0R\0]\0c\0areaconcentrationconsumptiondurationenergylengthmassmass-densitypowerpressurespeedtemperaturevolumeyear-duration") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\x0E\0\0\0\0\0\x0C\0 \x005\0;\0b\0g\0o\0\x87\0\xAD\0\xCD\0\xDD\0\xE3\0\xEE\0square-meteritem-per-cubic-metercubic-meter-per-metersecondkilogram-square-meter-per-square-secondmeterkilogramkilogram-per-cubic-meterkilogram-square-meter-per-cubic-secondkilogram-per-meter-square-secondmeter-per-secondkelvincubic-meteryear") })
+                },
+            };
+        }
+        #[clippy::msrv = "1.66"]
+        impl icu_provider::DataProvider<icu::unitsconversion::provider::UnitsInfoV1Marker> for $provider {
+            fn load(&self, req: icu_provider::DataRequest) -> Result<icu_provider::DataResponse<icu::unitsconversion::provider::UnitsInfoV1Marker>, icu_provider::DataError> {
+                if req.locale.is_empty() {
+                    Ok(icu_provider::DataResponse { payload: Some(icu_provider::DataPayload::from_static_ref(Self::SINGLETON_UNITS_INFO_V1)), metadata: Default::default() })
+                } else {
+                    Err(icu_provider::DataErrorKind::ExtraneousLocale.with_req(<icu::unitsconversion::provider::UnitsInfoV1Marker as icu_provider::KeyedDataMarker>::KEY, req))
+                }
+            }
+        }
+    };
+}
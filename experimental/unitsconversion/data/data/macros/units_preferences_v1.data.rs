@@ -0,0 +1,30 @@
+// @generated
+/// Implement `DataProvider<UnitPreferencesV1Marker>` on the given struct using the data
+/// hardcoded in this file. This allows the struct to be used with
+/// `icu`'s `_unstable` constructors.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_units_preferences_v1 {
+    ($ provider : path) => {
+        #[clippy::msrv = "1.66"]
+        impl $provider {
+            #[doc(hidden)]
+            pub const SINGLETON_UNITS_PREFERENCES_V1: &'static <icu::unitsconversion::provider::UnitPreferencesV1Marker as icu_provider::DataMarker>::Yokeable = &icu::unitsconversion::provider::UnitPreferencesV1 {
+                preferences: unsafe {
+                    #[allow(unused_unsafe)]
+                    zerovec::ZeroMap2d::from_parts_unchecked(unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"(\0\0\0\0\0\x0C\0\x16\0#\0,\0G\0\\\0o\0\x87\0\x97\0\xA5\0\xB3\0\xBE\0\xCC\0\xDF\0\xEC\0\0\x01\x0F\x01\x1A\x01)\x017\x01F\x01Z\x01f\x01q\x01~\x01\x8A\x01\x9B\x01\xAB\x01\xB8\x01\xC6\x01\xD4\x01\xDE\x01\xF1\x01\x04\x02\x12\x02\x1E\x02(\x026\x02K\x02area/defaultarea/floorarea/geographarea/landconcentration/blood-glucoseconcentration/defaultconsumption/defaultconsumption/vehicle-fuelduration/defaultduration/mediaenergy/defaultenergy/foodlength/defaultlength/focal-lengthlength/personlength/person-heightlength/rainfalllength/roadlength/snowfalllength/vehiclelength/visibltymass-density/defaultmass/defaultmass/personpower/defaultpower/enginepressure/baromtrcpressure/defaultspeed/defaultspeed/rainfallspeed/snowfallspeed/windtemperature/defaulttemperature/weathervolume/defaultvolume/fluidvolume/oilvolume/vehicleyear-duration/defaultyear-duration/person-age") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"\x03\0\0\0\x08\0\0\0\x0B\0\0\0\x0E\0\0\0S\0\0\0T\0\0\0U\0\0\0b\0\0\0c\0\0\0d\0\0\0e\0\0\0g\0\0\0j\0\0\0k\0\0\0p\0\0\0\x85\0\0\0\x88\0\0\0\x8C\0\0\0\x8E\0\0\0\x91\0\0\0\x96\0\0\0\x97\0\0\0\x9A\0\0\0\x9E\0\0\0\x9F\0\0\0\xA2\0\0\0\xAC\0\0\0\xAF\0\0\0\xB2\0\0\0\xB5\0\0\0\xB7\0\0\0\xBF\0\0\0\xC1\0\0\0\xC8\0\0\0\xCB\0\0\0\xCE\0\0\0\xCF\0\0\0\xD1\0\0\0\xD2\0\0\0\xD3\0\0\0") }, unsafe { zerovec::ZeroVec::from_bytes_unchecked(b"001GB\0US\x00001CA\0GB\0MM\0US\x00001GB\0US\x00001GB\0US\x00001AG\0AI\0AO\0AU\0BA\0BG\0BH\0BM\0BN\0BW\0BY\0CA\0CH\0CM\0CN\0CZ\0DK\0DM\0EE\0FI\0FJ\0GB\0GD\0HK\0HR\0HU\0IE\0IM\0IS\0KE\0KN\0KW\0KZ\0LC\0LI\0LT\0LU\0LV\0ME\0MG\0MK\0MO\0MS\0MT\0MU\0MY\0MZ\0NA\0NL\0NO\0NZ\0OM\0PG\0RS\0RU\0SE\0SG\0SI\0SK\0TC\0TO\0UA\0UG\0VC\0VG\0VN\0VU\0ZA\x00001001001BR\0CA\0GB\0IT\0JP\0KR\0MX\0MY\0NL\0TH\0TR\0US\x00001001001001US\x00001GB\0US\x00001001CA\0GB\0IN\0US\x00001AT\0BE\0CA\0DZ\0EG\0ES\0FR\0GB\0HK\0ID\0IL\0IN\0IT\0JO\0MY\0SA\0SE\0TR\0US\0VN\x00001BR\0US\x00001GB\0SE\0US\x00001US\x00001GB\0US\x00001DE\0GB\0NL\0US\x00001001GB\0US\x00001GB\0HK\0US\x00001001GB\0US\x00001BR\0EG\0GB\0IL\0IN\0MX\0RU\0TH\0US\x00001GB\0US\x00001GB\0US\x00001BR\0US\x00001US\x00001FI\0KR\0NO\0PL\0RU\0SE\0US\x00001US\x00001BS\0BZ\0KY\0PR\0PW\0US\x00001GB\0US\x00001GB\0US\x00001001US\x00001001") }, unsafe { zerovec::VarZeroVec::from_bytes_unchecked(b"\xD3\0\0\0\0\0l\0\xC9\0&\x01L\x01q\x01\x96\x01\xBB\x01\xE0\x01\n\x02/\x02T\x02u\x02\x93\x02\xB1\x02\xEC\x02\x19\x03F\x03s\x03\xA0\x03\xCD\x03\xFA\x03'\x04T\x04\x81\x04\xAE\x04\xDB\x04\x08\x055\x05b\x05\x8F\x05\xBC\x05\xE9\x05\x16\x06C\x06p\x06\x9D\x06\xCA\x06\xF7\x06$\x07Q\x07~\x07\xAB\x07\xD8\x07\x05\x082\x08_\x08\x8C\x08\xB9\x08\xE6\x08\x13\t@\tm\t\x9A\t\xC7\t\xF4\t!\nN\n{\n\xA8\n\xD5\n\x02\x0B/\x0B\\\x0B\x89\x0B\xB6\x0B\xE3\x0B\x10\x0C=\x0Cj\x0C\x97\x0C\xC4\x0C\xF1\x0C\x1E\rK\rx\r\xA5\r\xD2\r\xFF\r,\x0EY\x0E\x86\x0E\xB3\x0E\xE0\x0E\x0E\x0F?\x0Fp\x0F\x9D\x0F\xCF\x0F\x01\x10.\x10[\x10\x88\x10\xB5\x10\xE2\x10\x0F\x11<\x11i\x11\x92\x11\x1B\x12V\x12}\x12\xA2\x12\xC7\x12\r\x13G\x13\x81\x13\xA5\x13\xC9\x13\xE7\x13\x05\x14#\x14A\x14e\x14\x93\x14\xC1\x14\xF6\x14$\x15R\x15\x80\x15\xAE\x15\xE3\x15\x11\x16?\x16m\x16\xA2\x16\xD0\x16\xFE\x16,\x17Z\x17\x88\x17\xB6\x17\xEB\x17\x19\x18=\x18a\x18\x7F\x18\xCF\x18\x17\x19\x82\x19\xCA\x19\xEE\x19\x0C\x1A+\x1AR\x1Ay\x1A\xAB\x1A\xCA\x1A\xF6\x1A\x15\x1BA\x1Bs\x1B\xD8\x1B\x13\x1CN\x1C~\x1C\xC0\x1C\xE9\x1C!\x1D\x88\x1D\xAA\x1D\xCE\x1D\xF2\x1D\x17\x1E9\x1E[\x1E}\x1E\x9F\x1E\xC2\x1E\xEB\x1E\x14\x1F6\x1FY\x1F\x8D\x1F\xC2\x1F\xF7\x1F# J q \x9E \xCB \xF2 \x1F!F!r!\x9C!\xC6!\xF0!\x1A\"D\"n\"\x95\"\xB6\"\xDA\"\xFB\"\x1F#C#g#\x8B#\xAF#\xD3#\x12$J$\x82$\xB5$\xFC$\x81%\xA1%\xC0%\xE0%\r&\x02\0\0\0\0\0\0\0 \0\0\0\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\x04\0\0\0\0\0\x10\0\x17\0#\0square-kilometerhectaresquare-metersquare-centimeter\x02\0\0\0\0\0\0\0 \0\0\0\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\x04\0\0\0\0\0\x0B\0\x0F\0\x1A\0square-mileacresquare-footsquare-inch\x02\0\0\0\0\0\0\0 \0\0\0\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\x04\0\0\0\0\0\x0B\0\x0F\0\x1A\0square-mileacresquare-footsquare-inch\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0square-meter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0square-foot\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0square-foot\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0square-foot\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0square-foot\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0square-kilometer\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0square-mile\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0square-mile\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0hectare\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0acre\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0acre\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0milligram-ofglucose-per-deciliter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimole-per-liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0item-per-cubic-meter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0liter-per-100-kilometer\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0liter-per-100-kilometer\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0liter-per-kilometer\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0mile-per-gallon-imperial\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0mile-per-gallon-imperial\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0liter-per-kilometer\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0liter-per-kilometer\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0liter-per-kilometer\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0liter-per-kilometer\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0liter-per-kilometer\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0liter-per-kilometer\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0liter-per-kilometer\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0liter-per-kilometer\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0mile-per-gallon\x02\0\0\0\0\0\0\08\0\0\0\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\x07\0\0\0\0\0\x03\0\x07\0\r\0\x13\0\x1E\0)\0dayhourminutesecondmillisecondmicrosecondnanosecond\x02\0\0\0\0\0\0\0\x10\0\0\0\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\x02\0\0\0\0\0\x11\0minute-and-secondsecond\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0kilowatt-hour\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0kilocalorie\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0foodcalorie\x02\0\0\0\0\0\0\0\x18\0\0\0\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\x03\0\0\0\0\0\t\0\x0E\0kilometermetercentimeter\x02\0\0\0\0\0\0\0\x18\0\0\0\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\x03\0\0\0\0\0\x04\0\x08\0milefootinch\x02\0\0\0\0\0\0\0\x18\0\0\0\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\x03\0\0\0\0\0\x04\0\x08\0milefootinch\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimeter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0centimeter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0inch\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0inch\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0inch\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0inch\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0centimeter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0meter-and-centimeter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0meter-and-centimeter\x02\0\0\0\0\0\0\0\x10\0\0\0\0\0\0\0\0\0\x08@\0\0\0\0\0\0\xF0?\x02\0\0\0\0\0\r\0foot-and-inchinch\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0meter-and-centimeter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0meter-and-centimeter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0meter-and-centimeter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0meter-and-centimeter\x02\0\0\0\0\0\0\0\x10\0\0\0\0\0\0\0\0\0\x08@\0\0\0\0\0\0\xF0?\x02\0\0\0\0\0\r\0foot-and-inchinch\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0meter-and-centimeter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0meter-and-centimeter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0meter-and-centimeter\x02\0\0\0\0\0\0\0\x10\0\0\0\0\0\0\0\0\0\x08@\0\0\0\0\0\0\xF0?\x02\0\0\0\0\0\r\0foot-and-inchinch\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0meter-and-centimeter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0meter-and-centimeter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0meter-and-centimeter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0meter-and-centimeter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0meter-and-centimeter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0meter-and-centimeter\x02\0\0\0\0\0\0\0\x10\0\0\0\0\0\0\0\0\0\x08@\0\0\0\0\0\0\xF0?\x02\0\0\0\0\0\r\0foot-and-inchinch\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0meter-and-centimeter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimeter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0centimeter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0inch\x02\0\0\0\0\0\0\0 \0\0\0\xCD\xCC\xCC\xCC\xCC\xCC\xEC?\0\0\0\0\0\xC0r@\0\0\0\0\0\0$@\0\0\0\0\0\0\xF0?\x04\0\0\0\0\0\t\0\x0E\0\x13\0kilometermetermetermeter\x02\0\0\0\0\0\0\0 \0\0\0\0\0\0\0\0\0\xE0?\0\0\0\0\0\0Y@\0\0\0\0\0\0$@\0\0\0\0\0\0\xF0?\x04\0\0\0\0\0\x04\0\x08\0\x0C\0mileyardyardyard\x02\0\0\0\0\0\0\0(\0\0\0\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\0\0\0\0\0\xC0r@\0\0\0\0\0\0$@\0\0\0\0\0\0\xF0?\x05\0\0\0\0\0\x11\0\x1A\0\x1F\0$\0mile-scandinaviankilometermetermetermeter\x02\0\0\0\0\0\0\0 \0\0\0\0\0\0\0\0\0\xE0?\0\0\0\0\0\0Y@\0\0\0\0\0\0$@\0\0\0\0\0\0\xF0?\x04\0\0\0\0\0\x04\0\x08\0\x0C\0milefootfootfoot\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0centimeter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0inch\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0meter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0foot-and-inch\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0foot-and-inch\x02\0\0\0\0\0\0\0\x10\0\0\0\x9A\x99\x99\x99\x99\x99\xB9?\0\0\0\0\0\0\xF0?\x02\0\0\0\0\0\t\0kilometermeter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0meter\x02\0\0\0\0\0\0\0\x10\0\0\0\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\x02\0\0\0\0\0\x04\0milefoot\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0meter\x02\0\0\0\0\0\0\0\x10\0\0\0\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\x02\0\0\0\0\0\x04\0milefoot\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0kilogram-per-cubic-meter\x02\0\0\0\0\0\0\0(\0\0\0\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\x05\0\0\0\0\0\x05\0\r\0\x11\0\x1A\0tonnekilogramgrammilligrammicrogram\x02\0\0\0\0\0\0\0\x18\0\0\0\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\x03\0\0\0\0\0\x03\0\x08\0tonpoundounce\x02\0\0\0\0\0\0\0\x18\0\0\0\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\x03\0\0\0\0\0\x03\0\x08\0tonpoundounce\x02\0\0\0\0\0\0\0\x10\0\0\0\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\x02\0\0\0\0\0\x08\0kilogramgram\x02\0\0\0\0\0\0\0\x10\0\0\0\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\x02\0\0\0\0\0\x0F\0stone-and-poundpound-and-ounce\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0pound-and-ounce\x02\0\0\0\0\0\0\0\x10\0\0\0\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\x02\0\0\0\0\0\x05\0poundpound-and-ounce\x02\0\0\0\0\0\0\0(\0\0\0\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\x05\0\0\0\0\0\x08\0\x10\0\x18\0\x1C\0gigawattmegawattkilowattwattmilliwatt\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0kilowatt\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0horsepower\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0horsepower\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0hectopascal\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millibar\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millibar\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millibar\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millibar\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0inch-ofhg\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimeter-ofhg\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimeter-ofhg\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millibar\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0inch-ofhg\x02\0\0\0\0\0\0\0\x10\0\0\0\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\x02\0\0\0\0\0\n\0megapascalpascal\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0pound-force-per-square-inch\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0pound-force-per-square-inch\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0kilometer-per-hour\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0mile-per-hour\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0mile-per-hour\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0millimeter-per-hour\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0centimeter-per-hour\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0inch-per-hour\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0centimeter-per-hour\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0inch-per-hour\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0kilometer-per-hour\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0meter-per-second\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0meter-per-second\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0meter-per-second\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0meter-per-second\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0meter-per-second\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0meter-per-second\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0mile-per-hour\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0celsius\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0fahrenheit\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0celsius\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0fahrenheit\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0fahrenheit\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0fahrenheit\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0fahrenheit\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0fahrenheit\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0fahrenheit\x02\0\0\0\0\0\0\0\x10\0\0\0\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\x02\0\0\0\0\0\x0B\0cubic-metercubic-centimeter\x02\0\0\0\0\0\0\0\x10\0\0\0\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\x02\0\0\0\0\0\n\0cubic-footcubic-inch\x02\0\0\0\0\0\0\0\x10\0\0\0\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\x02\0\0\0\0\0\n\0cubic-footcubic-inch\x02\0\0\0\0\0\0\0\x10\0\0\0\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\x02\0\0\0\0\0\x05\0litermilliliter\x02\0\0\0\0\0\0\0\x10\0\0\0\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\x02\0\0\0\0\0\x0F\0gallon-imperialfluid-ounce-imperial\x02\0\0\0\0\0\0\08\0\0\0\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\x07\0\0\0\0\0\x06\0\x0B\0\x0F\0\x12\0\x1D\0'\0gallonquartpintcupfluid-ouncetablespoonteaspoon\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0barrel\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0liter\x02\0\0\0\0\0\0\0\x08\0\0\0\0\0\0\0\0\0\xF0?\x01\0\0\0\0\0gallon\x02\0\0\0\0\0\0\0\x10\0\0\0\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\x02\0\0\0\0\0\x04\0yearmonth\x02\0\0\0\0\0\0\0\x18\0\0\0\0\0\0\0\0\0\x04@\0\0\0\0\0\0\xF0?\0\0\0\0\0\0\xF0?\x03\0\0\0\0\0\x0B\0'\0year-personyear-person-and-month-personmonth-person") })
+                },
+            };
+        }
+        #[clippy::msrv = "1.66"]
+        impl icu_provider::DataProvider<icu::unitsconversion::provider::UnitPreferencesV1Marker> for $provider {
+            fn load(&self, req: icu_provider::DataRequest) -> Result<icu_provider::DataResponse<icu::unitsconversion::provider::UnitPreferencesV1Marker>, icu_provider::DataError> {
+                if req.locale.is_empty() {
+                    Ok(icu_provider::DataResponse { payload: Some(icu_provider::DataPayload::from_static_ref(Self::SINGLETON_UNITS_PREFERENCES_V1)), metadata: Default::default() })
+                } else {
+                    Err(icu_provider::DataErrorKind::ExtraneousLocale.with_req(<icu::unitsconversion::provider::UnitPreferencesV1Marker as icu_provider::KeyedDataMarker>::KEY, req))
+                }
+            }
+        }
+    };
+}
@@ -14,6 +14,8 @@ include!("macros.rs");
 macro_rules! __impl_data_provider {
     ($ provider : path) => {
         impl_units_constants_v1!($provider);
+        impl_units_info_v1!($provider);
+        impl_units_preferences_v1!($provider);
     };
 }
 #[doc(inline)]
@@ -36,6 +38,8 @@ macro_rules! __impl_any_provider {
             fn load_any(&self, key: icu_provider::DataKey, req: icu_provider::DataRequest) -> Result<icu_provider::AnyResponse, icu_provider::DataError> {
                 match key.hashed() {
                     h if h == <icu::unitsconversion::provider::UnitsConstantsV1Marker as icu_provider::KeyedDataMarker>::KEY.hashed() => icu_provider::DataProvider::<icu::unitsconversion::provider::UnitsConstantsV1Marker>::load(self, req).map(icu_provider::DataResponse::wrap_into_any_response),
+                    h if h == <icu::unitsconversion::provider::UnitsInfoV1Marker as icu_provider::KeyedDataMarker>::KEY.hashed() => icu_provider::DataProvider::<icu::unitsconversion::provider::UnitsInfoV1Marker>::load(self, req).map(icu_provider::DataResponse::wrap_into_any_response),
+                    h if h == <icu::unitsconversion::provider::UnitPreferencesV1Marker as icu_provider::KeyedDataMarker>::KEY.hashed() => icu_provider::DataProvider::<icu::unitsconversion::provider::UnitPreferencesV1Marker>::load(self, req).map(icu_provider::DataResponse::wrap_into_any_response),
                     _ => Err(icu_provider::DataErrorKind::MissingDataKey.with_req(key, req)),
                 }
             }
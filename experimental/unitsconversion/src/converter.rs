@@ -0,0 +1,451 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::format;
+use alloc::vec::Vec;
+use icu_locid::extensions::unicode::key;
+use icu_locid::subtags::Region;
+use icu_locid::Locale;
+use icu_provider::prelude::*;
+use tinystr::tinystr;
+use zerovec::ule::AsULE;
+use zerovec::VarZeroVec;
+
+use crate::provider::*;
+use crate::UnitsConversionError;
+
+/// The relative tolerance used when splitting a value into mixed units, so that, e.g.,
+/// 1.8288 meters is 6 feet 0 inches rather than 5 feet 11.99999 inches.
+const EPSILON: f64 = 1e-9;
+
+/// The SI and binary prefixes, and their factors.
+const PREFIXES: [(&str, f64); 32] = [
+    ("quecto", 1e-30),
+    ("ronto", 1e-27),
+    ("yocto", 1e-24),
+    ("zepto", 1e-21),
+    ("atto", 1e-18),
+    ("femto", 1e-15),
+    ("pico", 1e-12),
+    ("nano", 1e-9),
+    ("micro", 1e-6),
+    ("milli", 1e-3),
+    ("centi", 1e-2),
+    ("deci", 1e-1),
+    ("deka", 1e1),
+    ("hecto", 1e2),
+    ("kilo", 1e3),
+    ("mega", 1e6),
+    ("giga", 1e9),
+    ("tera", 1e12),
+    ("peta", 1e15),
+    ("exa", 1e18),
+    ("zetta", 1e21),
+    ("yotta", 1e24),
+    ("ronna", 1e27),
+    ("quetta", 1e30),
+    ("kibi", 1024.0),
+    ("mebi", 1048576.0),
+    ("gibi", 1073741824.0),
+    ("tebi", 1099511627776.0),
+    ("pebi", 1125899906842624.0),
+    ("exbi", 1152921504606846976.0),
+    ("zebi", 1180591620717411303424.0),
+    ("yobi", 1208925819614629174706176.0),
+];
+
+/// A value in a unit, as returned by [`UnitsConverter::convert_for_usage`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub struct UnitValue<'a> {
+    /// The unit identifier, e.g. `foot`.
+    pub unit: &'a str,
+    /// The value in the unit.
+    pub value: f64,
+}
+
+/// A converter between measurement units, which also selects the units in which a quantity
+/// is preferably displayed for a given usage and region, based on CLDR unit preferences.
+///
+/// Units are identified by their
+/// [CLDR unit identifiers](https://www.unicode.org/reports/tr35/tr35-general.html#Unit_Identifiers),
+/// such as `kilometer-per-hour`. Units with SI or binary prefixes, powers, products and
+/// quotients are supported.
+///
+/// # Examples
+///
+/// ```
+/// use icu_locid::subtags_region as region;
+/// use icu_unitsconversion::UnitsConverter;
+///
+/// let converter = UnitsConverter::new();
+///
+/// let miles = converter.convert(10.0, "kilometer", "mile").unwrap();
+/// assert!((miles - 6.21371).abs() < 1e-5);
+///
+/// // A height of 1.8 meters is displayed in feet and inches in the United States
+/// let height = converter
+///     .convert_for_usage(1.8, "meter", "person-height", Some(region!("US")))
+///     .unwrap();
+/// assert_eq!(height.len(), 2);
+/// assert_eq!((height[0].unit, height[0].value), ("foot", 5.0));
+/// assert_eq!(height[1].unit, "inch");
+/// assert!((height[1].value - 10.866).abs() < 1e-3);
+/// ```
+#[derive(Debug)]
+pub struct UnitsConverter {
+    info: DataPayload<UnitsInfoV1Marker>,
+    preferences: DataPayload<UnitPreferencesV1Marker>,
+}
+
+#[cfg(feature = "compiled_data")]
+impl Default for UnitsConverter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A unit resolved to base units: a value `x` in the unit is `x * factor + offset` in the
+/// product of the base units, raised to their powers.
+#[derive(Debug)]
+struct ResolvedUnit<'a> {
+    factor: f64,
+    offset: f64,
+    /// The nonzero powers of the base units, sorted by base unit.
+    powers: Vec<(&'a str, i8)>,
+}
+
+impl<'a> ResolvedUnit<'a> {
+    fn new() -> Self {
+        Self {
+            factor: 1.0,
+            offset: 0.0,
+            powers: Vec::new(),
+        }
+    }
+
+    /// Multiplies the unit by a unit with the given factor and base unit powers, raised to `power`.
+    fn multiply(&mut self, factor: f64, powers: &[(&'a str, i8)], power: i8) {
+        self.factor *= factor.powi(power.into());
+        for &(base, p) in powers {
+            match self.powers.binary_search_by(|(b, _)| b.cmp(&base)) {
+                Ok(i) => {
+                    if let Some((_, existing)) = self.powers.get_mut(i) {
+                        *existing += p * power;
+                    }
+                }
+                Err(i) => self.powers.insert(i, (base, p * power)),
+            }
+        }
+        self.powers.retain(|&(_, p)| p != 0);
+    }
+
+    /// Converts a value in the unit to the base units.
+    fn to_base(&self, value: f64) -> f64 {
+        value * self.factor + self.offset
+    }
+
+    /// Converts a value in the base units to the unit.
+    fn to_unit(&self, value: f64) -> f64 {
+        (value - self.offset) / self.factor
+    }
+}
+
+impl UnitsConverter {
+    /// Creates a new [`UnitsConverter`] from compiled data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub const fn new() -> Self {
+        Self {
+            info: DataPayload::from_static_ref(crate::provider::Baked::SINGLETON_UNITS_INFO_V1),
+            preferences: DataPayload::from_static_ref(
+                crate::provider::Baked::SINGLETON_UNITS_PREFERENCES_V1,
+            ),
+        }
+    }
+
+    icu_provider::gen_any_buffer_data_constructors!(locale: skip, options: skip, error: DataError,
+    #[cfg(skip)]
+    functions: [
+        new,
+        try_new_with_any_provider,
+        try_new_with_buffer_provider,
+        try_new_unstable,
+        Self,
+    ]);
+
+    #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::new)]
+    pub fn try_new_unstable<D>(provider: &D) -> Result<Self, DataError>
+    where
+        D: DataProvider<UnitsInfoV1Marker> + DataProvider<UnitPreferencesV1Marker> + ?Sized,
+    {
+        Ok(Self {
+            info: provider.load(Default::default())?.take_payload()?,
+            preferences: provider.load(Default::default())?.take_payload()?,
+        })
+    }
+
+    /// Converts `value` from the unit `input` to the unit `output`.
+    ///
+    /// Returns [`UnitsConversionError::IncompatibleUnits`] if the units do not measure
+    /// the same quantity.
+    pub fn convert(
+        &self,
+        value: f64,
+        input: &str,
+        output: &str,
+    ) -> Result<f64, UnitsConversionError> {
+        let input = self.resolve(input)?;
+        let output = self.resolve(output)?;
+        if input.powers != output.powers {
+            return Err(UnitsConversionError::IncompatibleUnits);
+        }
+        Ok(output.to_unit(input.to_base(value)))
+    }
+
+    /// Converts `value` in the unit `input` to the units in which it is preferably displayed
+    /// for `usage`, such as `road` or `person-height`, in `region`.
+    ///
+    /// The usage falls back to less specific usages, and eventually to `default`; the region
+    /// falls back to the world (`001`), which is also used if no region is given.
+    ///
+    /// A single value is returned, unless the preferred unit is a mixed unit such as
+    /// `foot-and-inch`, in which case a value is returned for each of its units; all but
+    /// the last value are then integers.
+    pub fn convert_for_usage(
+        &self,
+        value: f64,
+        input: &str,
+        usage: &str,
+        region: Option<Region>,
+    ) -> Result<Vec<UnitValue<'_>>, UnitsConversionError> {
+        let input = self.resolve(input)?;
+        let base_value = input.to_base(value);
+
+        let quantity = self
+            .info
+            .get()
+            .quantities
+            .iter()
+            .find(|&(_, base_unit)| base_powers(base_unit).as_ref() == Some(&input.powers))
+            .map(|(quantity, _)| quantity)
+            .ok_or(UnitsConversionError::NoPreferences)?;
+
+        let preferences = self.preferences_for(quantity, usage, region)?;
+        // Data loaded from a data provider is always borrowed, which lets the returned unit
+        // identifiers borrow from the data rather than from `preferences`.
+        let units = match preferences.units {
+            VarZeroVec::Borrowed(units) => units,
+            _ => return Err(UnitsConversionError::NoPreferences),
+        };
+        let mut candidates = preferences.thresholds.iter().zip(units.iter()).peekable();
+        let unit = loop {
+            let (threshold, unit) = candidates
+                .next()
+                .ok_or(UnitsConversionError::NoPreferences)?;
+            if candidates.peek().is_none() {
+                break unit;
+            }
+            let first = unit.split("-and-").next().unwrap_or(unit);
+            if self.resolve(first)?.to_unit(base_value).abs() >= threshold {
+                break unit;
+            }
+        };
+
+        let mut result = Vec::new();
+        let mut remainder = base_value;
+        let mut units = unit.split("-and-").peekable();
+        while let Some(unit) = units.next() {
+            let resolved = self.resolve(unit)?;
+            let value = resolved.to_unit(remainder);
+            if units.peek().is_none() {
+                result.push(UnitValue { unit, value });
+                break;
+            }
+            let rounded = value.round();
+            if (value - rounded).abs() <= EPSILON * value.abs().max(1.0) {
+                result.push(UnitValue {
+                    unit,
+                    value: rounded,
+                });
+                remainder = 0.0;
+            } else {
+                result.push(UnitValue {
+                    unit,
+                    value: value.trunc(),
+                });
+                remainder = value.fract() * resolved.factor;
+            }
+        }
+        Ok(result)
+    }
+
+    /// Converts `value` in the unit `input` to the units in which it is preferably displayed
+    /// for `usage` in the region of `locale`.
+    ///
+    /// The region is taken from the `rg` Unicode extension keyword if present, and otherwise
+    /// from the region subtag. Locales without a region subtag, such as `en`, should be
+    /// maximized with `LocaleExpander` from `icu_locid_transform` first.
+    ///
+    /// See [`Self::convert_for_usage`].
+    pub fn convert_for_locale(
+        &self,
+        value: f64,
+        input: &str,
+        usage: &str,
+        locale: &Locale,
+    ) -> Result<Vec<UnitValue<'_>>, UnitsConversionError> {
+        // The rg keyword is a subdivision code, such as "usca", which starts with the region.
+        let region = locale
+            .extensions
+            .unicode
+            .keywords
+            .get(&key!("rg"))
+            .and_then(|value| value.as_single_subtag())
+            .and_then(|subdivision| subdivision.as_bytes().get(..2))
+            .and_then(|region| Region::try_from_bytes(region).ok())
+            .or(locale.id.region);
+        self.convert_for_usage(value, input, usage, region)
+    }
+
+    fn preferences_for(
+        &self,
+        quantity: &str,
+        mut usage: &str,
+        region: Option<Region>,
+    ) -> Result<UnitPreferences<'_>, UnitsConversionError> {
+        let preferences = &self.preferences.get().preferences;
+        let world = tinystr!(3, "001").to_unvalidated();
+        loop {
+            if let Some(cursor) = preferences.get0(&format!("{quantity}/{usage}")) {
+                if let Some(preferences) = region
+                    .and_then(|region| cursor.get1(&region.into_tinystr().to_unvalidated()))
+                    .or_else(|| cursor.get1(&world))
+                {
+                    return Ok(zerofrom::ZeroFrom::zero_from(preferences));
+                }
+            }
+            usage = match usage.rsplit_once('-') {
+                Some((parent, _)) => parent,
+                None if usage != "default" => "default",
+                None => return Err(UnitsConversionError::NoPreferences),
+            };
+        }
+    }
+
+    /// Resolves a unit identifier, such as `kilometer-per-hour`, to base units.
+    fn resolve(&self, unit: &str) -> Result<ResolvedUnit<'_>, UnitsConversionError> {
+        if let Some(info) = self.info.get().conversions.get(unit) {
+            // Only simple units have an offset.
+            let mut resolved = ResolvedUnit::new();
+            resolved.multiply(
+                f64::from_unaligned(info.factor),
+                &base_powers(&info.base_unit).ok_or(UnitsConversionError::UnknownUnit)?,
+                1,
+            );
+            resolved.offset = f64::from_unaligned(info.offset);
+            return Ok(resolved);
+        }
+
+        let (numerator, denominator) = match unit.split_once("-per-") {
+            Some((numerator, denominator)) => (numerator, denominator),
+            None => match unit.strip_prefix("per-") {
+                Some(denominator) => ("", denominator),
+                None => (unit, ""),
+            },
+        };
+        let mut resolved = ResolvedUnit::new();
+        self.resolve_product(numerator, 1, &mut resolved)?;
+        self.resolve_product(denominator, -1, &mut resolved)?;
+        Ok(resolved)
+    }
+
+    /// Resolves a product of single units, such as `kilowatt-hour`, and multiplies it into
+    /// `resolved`, raised to `sign`. The longest single unit is taken at each step.
+    fn resolve_product<'a>(
+        &'a self,
+        mut product: &str,
+        sign: i8,
+        resolved: &mut ResolvedUnit<'a>,
+    ) -> Result<(), UnitsConversionError> {
+        while !product.is_empty() {
+            let (power, rest) = split_power(product).ok_or(UnitsConversionError::UnknownUnit)?;
+            let mut candidate = rest;
+            let (factor, powers) = loop {
+                if let Some(single) = self.resolve_single(candidate) {
+                    break single;
+                }
+                candidate = candidate
+                    .rsplit_once('-')
+                    .map(|(candidate, _)| candidate)
+                    .ok_or(UnitsConversionError::UnknownUnit)?;
+            };
+            resolved.multiply(factor, &powers, power * sign);
+            product = rest
+                .get(candidate.len()..)
+                .map(|rest| rest.trim_start_matches('-'))
+                .unwrap_or_default();
+        }
+        Ok(())
+    }
+
+    /// Resolves a single unit without power, such as `kilometer` or `100`, to its factor and
+    /// base unit powers.
+    fn resolve_single(&self, unit: &str) -> Option<(f64, Vec<(&str, i8)>)> {
+        if unit.bytes().all(|b| b.is_ascii_digit()) {
+            // Numeric components, as in liter-per-100-kilometer
+            return Some((unit.parse().ok()?, Vec::new()));
+        }
+        let conversions = &self.info.get().conversions;
+        let simple = |unit: &str| {
+            let info = conversions.get(unit)?;
+            Some((
+                f64::from_unaligned(info.factor),
+                base_powers(&info.base_unit)?,
+            ))
+        };
+        simple(unit).or_else(|| {
+            PREFIXES.iter().find_map(|&(prefix, prefix_factor)| {
+                let (factor, powers) = simple(unit.strip_prefix(prefix)?)?;
+                Some((prefix_factor * factor, powers))
+            })
+        })
+    }
+}
+
+/// Returns the powers of the components of a base unit, such as
+/// `kilogram-meter-per-square-second`, sorted by component.
+fn base_powers(base_unit: &str) -> Option<Vec<(&str, i8)>> {
+    let (numerator, denominator) = base_unit.split_once("-per-").unwrap_or((base_unit, ""));
+    let mut resolved = ResolvedUnit::new();
+    for (mut product, sign) in [(numerator, 1), (denominator, -1)] {
+        while !product.is_empty() {
+            let (power, rest) = split_power(product)?;
+            let (base, rest) = rest.split_once('-').unwrap_or((rest, ""));
+            resolved.multiply(1.0, &[(base, 1)], power * sign);
+            product = rest;
+        }
+    }
+    Some(resolved.powers)
+}
+
+/// Splits the power prefix, such as `square-` or `pow4-`, off a single unit.
+fn split_power(unit: &str) -> Option<(i8, &str)> {
+    if let Some(unit) = unit.strip_prefix("square-") {
+        Some((2, unit))
+    } else if let Some(unit) = unit.strip_prefix("cubic-") {
+        Some((3, unit))
+    } else if let Some((power, unit)) = unit
+        .strip_prefix("pow")
+        .and_then(|unit| unit.split_once('-'))
+        .filter(|(power, _)| power.bytes().all(|b| b.is_ascii_digit()))
+    {
+        Some((power.parse().ok()?, unit))
+    } else {
+        Some((1, unit))
+    }
+}
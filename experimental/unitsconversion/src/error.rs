@@ -0,0 +1,32 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use displaydoc::Display;
+use icu_provider::DataError;
+
+/// A list of error outcomes for various operations in this module.
+///
+/// Re-exported as [`Error`](crate::Error).
+#[derive(Display, Debug, Copy, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum UnitsConversionError {
+    /// The unit identifier is not a known unit, nor a compound unit made of known units.
+    #[displaydoc("Unknown unit")]
+    UnknownUnit,
+    /// The units measure different quantities, such as a length and a mass.
+    #[displaydoc("Incompatible units")]
+    IncompatibleUnits,
+    /// There are no unit preferences for the quantity that the unit measures.
+    #[displaydoc("No unit preferences for the quantity")]
+    NoPreferences,
+    /// An error originating from [`DataProvider`](icu_provider::DataProvider).
+    #[displaydoc("Error loading data: {0}")]
+    Data(DataError),
+}
+
+impl From<DataError> for UnitsConversionError {
+    fn from(e: DataError) -> Self {
+        UnitsConversionError::Data(e)
+    }
+}
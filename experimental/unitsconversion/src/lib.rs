@@ -2,8 +2,64 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-//! Experimental.
+//! 🚧 \[Experimental\] Measurement unit conversion and preferences
+//!
+//! This module is published as its own crate ([`icu_unitsconversion`](https://docs.rs/icu_unitsconversion/latest/icu_unitsconversion/)).
+//!
+//! [`UnitsConverter`] converts values between measurement units, and selects the units in
+//! which a value is preferably displayed for a given usage and region, following the
+//! [CLDR unit preferences](https://www.unicode.org/reports/tr35/tr35-info.html#Unit_Preferences).
+//! For example, a road distance is displayed in kilometers in Germany, but in miles in the
+//! United States, and a person's height is displayed in feet and inches in the United States.
+//!
+//! # Examples
+//!
+//! ```
+//! use icu_locid::locale;
+//! use icu_unitsconversion::UnitsConverter;
+//!
+//! let converter = UnitsConverter::new();
+//!
+//! let road = converter
+//!     .convert_for_locale(5000.0, "meter", "road", &locale!("de-DE"))
+//!     .unwrap();
+//! assert_eq!((road[0].unit, road[0].value), ("kilometer", 5.0));
+//!
+//! let road = converter
+//!     .convert_for_locale(5000.0, "meter", "road", &locale!("en-US"))
+//!     .unwrap();
+//! assert_eq!(road[0].unit, "mile");
+//! assert!((road[0].value - 3.10686).abs() < 1e-5);
+//! ```
+//!
+//! <div class="stab unstable">
+//! 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+//! including in SemVer minor releases. Use with caution.
+//! </div>
+
+// https://github.com/unicode-org/icu4x/blob/main/docs/process/boilerplate.md#library-annotations
+#![cfg_attr(
+    not(test),
+    deny(
+        clippy::indexing_slicing,
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::exhaustive_structs,
+        clippy::exhaustive_enums,
+        missing_debug_implementations,
+    )
+)]
+#![warn(missing_docs)]
 
 extern crate alloc;
 
+mod converter;
+mod error;
 pub mod provider;
+
+pub use converter::{UnitValue, UnitsConverter};
+pub use error::UnitsConversionError;
+
+#[doc(no_inline)]
+pub use UnitsConversionError as Error;
@@ -9,8 +9,25 @@
 //!
 //! Read more about data providers: [`icu_provider`]
 
+use alloc::borrow::Cow;
 use icu_provider::prelude::*;
-use zerovec::ZeroMap;
+use tinystr::UnvalidatedTinyAsciiStr;
+use zerovec::{VarZeroVec, ZeroMap, ZeroMap2d, ZeroVec};
+
+#[cfg(feature = "compiled_data")]
+#[derive(Debug)]
+/// Baked data
+pub struct Baked;
+
+#[cfg(feature = "compiled_data")]
+const _: () = {
+    pub mod icu {
+        pub use crate as unitsconversion;
+    }
+    icu_unitsconversion_data::impl_units_constants_v1!(Baked);
+    icu_unitsconversion_data::impl_units_info_v1!(Baked);
+    icu_unitsconversion_data::impl_units_preferences_v1!(Baked);
+};
 
 /// This type contains all of the constants data for units conversion.
 ///
@@ -35,3 +52,127 @@ pub struct UnitsConstantsV1<'data> {
     #[cfg_attr(feature = "serde", serde(borrow))]
     pub constants_map: ZeroMap<'data, str, str>,
 }
+
+/// This type contains the conversion of each simple unit to its base unit, and the base
+/// unit of each quantity that has unit preferences.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[icu_provider::data_struct(marker(UnitsInfoV1Marker, "units/info@1", singleton))]
+#[derive(Default, Clone, PartialEq, Debug)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_unitsconversion::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct UnitsInfoV1<'data> {
+    /// Maps from a simple unit (e.g. foot) to its conversion to its base unit (e.g. meter).
+    ///
+    /// Units with SI prefixes, powers, products and quotients are not included, as their
+    /// conversions are derived from those of their components.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub conversions: ZeroMap<'data, str, ConversionInfoULE>,
+
+    /// Maps from a quantity (e.g. length) to its base unit (e.g. meter).
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub quantities: ZeroMap<'data, str, str>,
+}
+
+/// The conversion of a simple unit to its base unit: a value `x` in the unit is
+/// `x * factor + offset` in the base unit.
+///
+/// The factor and offset are evaluated at datagen time from the CLDR conversion of each unit,
+/// such as `ft2_to_m2 * 43560` for acre, and the exact constants in [`UnitsConstantsV1`].
+/// These per-unit expressions are not part of [`UnitsConstantsV1`], and [`UnitsConverter`]
+/// computes in `f64`, so the evaluated `f64` values are stored rather than making every
+/// conversion parse and evaluate expressions at runtime.
+///
+/// [`UnitsConverter`]: crate::UnitsConverter
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[zerovec::make_varule(ConversionInfoULE)]
+#[zerovec::skip_derive(Ord)]
+#[derive(Clone, Debug, PartialEq, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_unitsconversion::provider),
+    zerovec::derive(Serialize),
+)]
+#[zerovec::derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", zerovec::derive(Deserialize))]
+pub struct ConversionInfo<'data> {
+    /// The factor by which a value in the unit is multiplied.
+    pub factor: f64,
+    /// The offset which is added to the multiplied value, e.g. 273.15 for celsius.
+    pub offset: f64,
+    /// The base unit, e.g. meter for foot, or kilogram-meter-per-square-second for newton.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub base_unit: Cow<'data, str>,
+}
+
+/// This type contains the preferred units for displaying quantities, by usage and region.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[icu_provider::data_struct(marker(UnitPreferencesV1Marker, "units/preferences@1", singleton))]
+#[derive(Default, Clone, PartialEq, Debug)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_unitsconversion::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct UnitPreferencesV1<'data> {
+    /// Maps from a quantity and usage, separated by a slash (e.g. length/road), and a region
+    /// (e.g. US, or 001 for the rest of the world) to the preferred units.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub preferences: ZeroMap2d<'data, str, UnvalidatedRegion, UnitPreferencesULE>,
+}
+
+type UnvalidatedRegion = UnvalidatedTinyAsciiStr<3>;
+
+/// The preferred units for a quantity, usage and region, from largest to smallest.
+///
+/// A unit is used for a value if the value, converted to that unit, is at least the unit's
+/// threshold. The last unit is used for values that are below all thresholds.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[zerovec::make_varule(UnitPreferencesULE)]
+#[zerovec::skip_derive(Ord)]
+#[derive(Clone, Debug, PartialEq, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_unitsconversion::provider),
+    zerovec::derive(Serialize),
+)]
+#[zerovec::derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", zerovec::derive(Deserialize))]
+pub struct UnitPreferences<'data> {
+    /// The threshold of each unit, in that unit (or in the first unit of a mixed unit).
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub thresholds: ZeroVec<'data, f64>,
+    /// The units, e.g. mile or foot, or mixed units such as foot-and-inch.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub units: VarZeroVec<'data, str>,
+}
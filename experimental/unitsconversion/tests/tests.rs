@@ -0,0 +1,194 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_locid::{locale, subtags_region as region};
+use icu_unitsconversion::{UnitsConversionError, UnitsConverter};
+
+fn assert_approx_eq(actual: f64, expected: f64, message: &str) {
+    assert!(
+        (actual - expected).abs() <= 1e-6 * expected.abs().max(1.0),
+        "{message}: {actual} != {expected}"
+    );
+}
+
+#[test]
+fn test_convert() {
+    let converter = UnitsConverter::new();
+
+    for (value, input, output, expected) in [
+        (1.0, "foot", "meter", 0.3048),
+        (1.0, "mile", "kilometer", 1.609344),
+        (1.0, "kilometer", "centimeter", 100000.0),
+        (1.0, "pound", "gram", 453.59237),
+        (100.0, "kilometer-per-hour", "meter-per-second", 27.777778),
+        (60.0, "mile-per-hour", "kilometer-per-hour", 96.56064),
+        (1.0, "acre", "square-meter", 4046.8564224),
+        (1.0, "square-kilometer", "square-meter", 1e6),
+        (1.0, "cubic-meter", "liter", 1000.0),
+        (1.0, "kilowatt-hour", "joule", 3.6e6),
+        (1.0, "newton-meter", "joule", 1.0),
+        (1.0, "kibibyte", "byte", 1024.0),
+        (5.0, "liter-per-100-kilometer", "liter-per-kilometer", 0.05),
+        (0.0, "celsius", "fahrenheit", 32.0),
+        (100.0, "celsius", "fahrenheit", 212.0),
+        (-40.0, "fahrenheit", "celsius", -40.0),
+        (0.0, "celsius", "kelvin", 273.15),
+    ] {
+        assert_approx_eq(
+            converter.convert(value, input, output).unwrap(),
+            expected,
+            &format!("{value} {input} in {output}"),
+        );
+    }
+}
+
+#[test]
+fn test_convert_errors() {
+    let converter = UnitsConverter::new();
+
+    assert_eq!(
+        converter.convert(1.0, "meter", "kilogram"),
+        Err(UnitsConversionError::IncompatibleUnits)
+    );
+    assert_eq!(
+        converter.convert(1.0, "meter-per-second", "meter"),
+        Err(UnitsConversionError::IncompatibleUnits)
+    );
+    assert_eq!(
+        converter.convert(1.0, "cubit", "meter"),
+        Err(UnitsConversionError::UnknownUnit)
+    );
+    assert_eq!(
+        converter.convert(1.0, "meter", "kilocubit"),
+        Err(UnitsConversionError::UnknownUnit)
+    );
+    assert_eq!(
+        converter.convert_for_usage(1.0, "newton", "default", None),
+        Err(UnitsConversionError::NoPreferences)
+    );
+}
+
+#[test]
+fn test_person_height() {
+    let converter = UnitsConverter::new();
+
+    let us = converter
+        .convert_for_usage(1.8034, "meter", "person-height", Some(region!("US")))
+        .unwrap();
+    assert_eq!(us.len(), 2);
+    assert_eq!((us[0].unit, us[0].value), ("foot", 5.0));
+    assert_eq!(us[1].unit, "inch");
+    assert_approx_eq(us[1].value, 11.0, "inches");
+
+    // Exactly six feet, which must not become 5 feet 12 inches
+    let us = converter
+        .convert_for_usage(182.88, "centimeter", "person-height", Some(region!("US")))
+        .unwrap();
+    assert_eq!((us[0].unit, us[0].value), ("foot", 6.0));
+    assert_eq!((us[1].unit, us[1].value), ("inch", 0.0));
+
+    let fr = converter
+        .convert_for_usage(1.8034, "meter", "person-height", Some(region!("FR")))
+        .unwrap();
+    assert_eq!((fr[0].unit, fr[0].value), ("meter", 1.0));
+    assert_eq!(fr[1].unit, "centimeter");
+    assert_approx_eq(fr[1].value, 80.34, "centimeters");
+
+    // Falls back to the world
+    let de = converter
+        .convert_for_usage(1.8034, "meter", "person-height", Some(region!("DE")))
+        .unwrap();
+    assert_eq!(de.len(), 1);
+    assert_eq!(de[0].unit, "centimeter");
+    assert_approx_eq(de[0].value, 180.34, "centimeters");
+}
+
+#[test]
+fn test_road() {
+    let converter = UnitsConverter::new();
+
+    for (value, region, unit, expected) in [
+        (5000.0, region!("US"), "mile", 3.106856),
+        (200.0, region!("US"), "foot", 656.167979),
+        (5000.0, region!("GB"), "mile", 3.106856),
+        (200.0, region!("GB"), "yard", 218.722659),
+        (5000.0, region!("DE"), "kilometer", 5.0),
+        (950.0, region!("DE"), "kilometer", 0.95),
+        (850.0, region!("DE"), "meter", 850.0),
+    ] {
+        let result = converter
+            .convert_for_usage(value, "meter", "road", Some(region))
+            .unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].unit, unit, "{value} meters in {region}");
+        assert_approx_eq(
+            result[0].value,
+            expected,
+            &format!("{value} meters in {region}"),
+        );
+    }
+}
+
+#[test]
+fn test_usage_fallback() {
+    let converter = UnitsConverter::new();
+
+    // There are no preferences for length/road-footpath, so length/road is used
+    let result = converter
+        .convert_for_usage(5000.0, "meter", "road-footpath", Some(region!("US")))
+        .unwrap();
+    assert_eq!(result[0].unit, "mile");
+
+    // There are no preferences for length/unknown, so length/default is used
+    let result = converter
+        .convert_for_usage(5000.0, "meter", "unknown", Some(region!("US")))
+        .unwrap();
+    assert_eq!(result[0].unit, "mile");
+}
+
+#[test]
+fn test_temperature() {
+    let converter = UnitsConverter::new();
+
+    let us = converter
+        .convert_for_usage(20.0, "celsius", "weather", Some(region!("US")))
+        .unwrap();
+    assert_eq!(us[0].unit, "fahrenheit");
+    assert_approx_eq(us[0].value, 68.0, "fahrenheit");
+
+    let world = converter
+        .convert_for_usage(68.0, "fahrenheit", "weather", None)
+        .unwrap();
+    assert_eq!(world[0].unit, "celsius");
+    assert_approx_eq(world[0].value, 20.0, "celsius");
+}
+
+#[test]
+fn test_locale() {
+    let converter = UnitsConverter::new();
+
+    let us = converter
+        .convert_for_locale(5.0, "kilogram", "person", &locale!("en-US"))
+        .unwrap();
+    assert_eq!(us[0].unit, "pound");
+    assert_approx_eq(us[0].value, 11.023113, "pounds");
+
+    let gb = converter
+        .convert_for_locale(70.0, "kilogram", "person", &locale!("en-GB"))
+        .unwrap();
+    assert_eq!((gb[0].unit, gb[0].value), ("stone", 11.0));
+    assert_eq!(gb[1].unit, "pound");
+    assert_approx_eq(gb[1].value, 0.3235835, "pounds");
+
+    // The region override takes precedence over the region subtag
+    let rg = converter
+        .convert_for_locale(5.0, "kilogram", "person", &locale!("en-GB-u-rg-uszzzz"))
+        .unwrap();
+    assert_eq!(rg[0].unit, "pound");
+
+    let no_region = converter
+        .convert_for_locale(5.0, "kilogram", "person", &locale!("en"))
+        .unwrap();
+    assert_eq!((no_region[0].unit, no_region[0].value), ("kilogram", 5.0));
+}
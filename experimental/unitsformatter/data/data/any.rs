@@ -0,0 +1,2 @@
+// @generated
+impl_any_provider!(BakedDataProvider);
@@ -0,0 +1,16 @@
+// @generated
+#[macro_use]
+#[path = "macros/units_long_v1.data.rs"]
+mod units_long_v1;
+#[doc(inline)]
+pub use __impl_units_long_v1 as impl_units_long_v1;
+#[macro_use]
+#[path = "macros/units_narrow_v1.data.rs"]
+mod units_narrow_v1;
+#[doc(inline)]
+pub use __impl_units_narrow_v1 as impl_units_narrow_v1;
+#[macro_use]
+#[path = "macros/units_short_v1.data.rs"]
+mod units_short_v1;
+#[doc(inline)]
+pub use __impl_units_short_v1 as impl_units_short_v1;
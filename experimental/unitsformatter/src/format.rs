@@ -0,0 +1,84 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use core::fmt::Write;
+
+use icu_decimal::FormattedFixedDecimal;
+use writeable::Writeable;
+
+/// The [`Part`](writeable::Part)s used by [`FormattedUnit`].
+///
+/// The number is annotated with the parts of [`icu_decimal::parts`].
+pub mod parts {
+    use writeable::Part;
+
+    /// The [`Part`] used by [`FormattedUnit`](crate::FormattedUnit) to mark the name of the unit.
+    ///
+    /// * `category`: `"unitsformatter"`
+    /// * `value`: `"unit"`
+    pub const UNIT: Part = Part {
+        category: "unitsformatter",
+        value: "unit",
+    };
+
+    /// The [`Part`] used by [`FormattedUnit`](crate::FormattedUnit) to mark the whitespace
+    /// between the number and the name of the unit.
+    ///
+    /// * `category`: `"unitsformatter"`
+    /// * `value`: `"literal"`
+    pub const LITERAL: Part = Part {
+        category: "unitsformatter",
+        value: "literal",
+    };
+}
+
+/// An intermediate structure returned by [`UnitFormatter`](crate::UnitFormatter).
+/// This structure can be consumed via [`Writeable`] trait to a string or buffer.
+#[derive(Debug)]
+pub struct FormattedUnit<'l> {
+    pub(crate) pattern: &'l str,
+    pub(crate) number: FormattedFixedDecimal<'l>,
+}
+
+impl<'l> FormattedUnit<'l> {
+    fn write_affix<S: writeable::PartsWrite + ?Sized>(
+        sink: &mut S,
+        affix: &str,
+        is_prefix: bool,
+    ) -> core::fmt::Result {
+        let (unit, literal) = if is_prefix {
+            let unit = affix.trim_end();
+            (unit, affix.get(unit.len()..).unwrap_or_default())
+        } else {
+            let unit = affix.trim_start();
+            (
+                unit,
+                affix.get(..affix.len() - unit.len()).unwrap_or_default(),
+            )
+        };
+        if is_prefix && !unit.is_empty() {
+            sink.with_part(parts::UNIT, |s| s.write_str(unit))?;
+        }
+        if !literal.is_empty() {
+            sink.with_part(parts::LITERAL, |s| s.write_str(literal))?;
+        }
+        if !is_prefix && !unit.is_empty() {
+            sink.with_part(parts::UNIT, |s| s.write_str(unit))?;
+        }
+        Ok(())
+    }
+}
+
+impl<'l> Writeable for FormattedUnit<'l> {
+    fn write_to_parts<S: writeable::PartsWrite + ?Sized>(&self, sink: &mut S) -> core::fmt::Result {
+        let Some((prefix, suffix)) = self.pattern.split_once("{0}") else {
+            return sink.with_part(parts::UNIT, |s| s.write_str(self.pattern));
+        };
+        Self::write_affix(sink, prefix, true)?;
+        self.number.write_to_parts(sink)?;
+        Self::write_affix(sink, suffix, false)
+    }
+}
+
+writeable::impl_display_with_writeable!(FormattedUnit<'_>);
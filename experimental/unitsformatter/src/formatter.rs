@@ -0,0 +1,509 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use fixed_decimal::FixedDecimal;
+use icu_decimal::{provider::DecimalSymbolsV1Marker, FixedDecimalFormatter};
+use icu_plurals::{
+    provider::{CardinalV1Marker, RawPluralCategory},
+    PluralCategory, PluralRules,
+};
+use icu_provider::prelude::*;
+
+use crate::format::FormattedUnit;
+use crate::provider::*;
+use crate::{MeasureUnit, UnitFormatterOptions, UnitsFormatterError};
+
+/// A formatter for numbers with a measurement unit.
+///
+/// A [`UnitFormatter`] formats numbers of a single unit, in a single width. Units that the
+/// locale has no display names for are composed from their components, using the CLDR
+/// patterns for products, quotients, powers and SI prefixes.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+/// use icu_locid::locale;
+/// use icu_unitsformatter::UnitFormatter;
+/// use writeable::assert_writeable_eq;
+///
+/// let formatter = UnitFormatter::try_new_short(
+///     &locale!("en").into(),
+///     "kilometer-per-hour".parse().unwrap(),
+///     Default::default(),
+/// )
+/// .unwrap();
+/// let speed = FixedDecimal::from(120);
+///
+/// assert_writeable_eq!(formatter.format(&speed), "120 km/h");
+/// ```
+///
+/// Compound units:
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+/// use icu_locid::locale;
+/// use icu_unitsformatter::UnitFormatter;
+/// use writeable::assert_writeable_eq;
+///
+/// let formatter = UnitFormatter::try_new_long(
+///     &locale!("en").into(),
+///     "kilogram-per-cubic-meter".parse().unwrap(),
+///     Default::default(),
+/// )
+/// .unwrap();
+/// let density = FixedDecimal::from(1000);
+///
+/// assert_writeable_eq!(
+///     formatter.format(&density),
+///     "1,000 kilograms per cubic meter"
+/// );
+/// ```
+#[derive(Debug)]
+pub struct UnitFormatter {
+    /// The pattern for each plural category of the locale.
+    patterns: Vec<(PluralCategory, String)>,
+    plural_rules: PluralRules,
+    pub(crate) fixed_decimal_format: FixedDecimalFormatter,
+}
+
+macro_rules! constructor {
+    ($unstable: ident, $baked: ident, $any: ident, $buffer: ident, $marker: ty, $width: literal) => {
+        #[doc = concat!("Creates a new [`UnitFormatter`] for `unit` in the ", $width, " width, from compiled data.")]
+        ///
+        /// Returns [`UnitsFormatterError::UnknownUnit`] if the locale has no display name
+        /// for the unit, and the unit cannot be composed from units that it has display names for.
+        ///
+        /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+        ///
+        /// [📚 Help choosing a constructor](icu_provider::constructors)
+        #[cfg(feature = "compiled_data")]
+        pub fn $baked(
+            locale: &DataLocale,
+            unit: MeasureUnit,
+            options: UnitFormatterOptions,
+        ) -> Result<Self, UnitsFormatterError> {
+            let plural_rules = PluralRules::try_new_cardinal(locale)?;
+            let fixed_decimal_format =
+                FixedDecimalFormatter::try_new(locale, options.fixed_decimal_formatter_options)?;
+            let display_names: DataPayload<$marker> = crate::provider::Baked
+                .load(DataRequest {
+                    locale,
+                    metadata: Default::default(),
+                })?
+                .take_payload()?;
+            Self::try_new_internal(
+                display_names.get(),
+                &unit,
+                plural_rules,
+                fixed_decimal_format,
+            )
+        }
+
+        icu_provider::gen_any_buffer_data_constructors!(
+            locale: include,
+            unit: MeasureUnit,
+            options: UnitFormatterOptions,
+            error: UnitsFormatterError,
+            #[cfg(skip)]
+            functions: [
+                $baked,
+                $any,
+                $buffer,
+                $unstable,
+                Self,
+            ]
+        );
+
+        #[doc = icu_provider::gen_any_buffer_unstable_docs!(UNSTABLE, Self::$baked)]
+        pub fn $unstable<D>(
+            provider: &D,
+            locale: &DataLocale,
+            unit: MeasureUnit,
+            options: UnitFormatterOptions,
+        ) -> Result<Self, UnitsFormatterError>
+        where
+            D: DataProvider<CardinalV1Marker>
+                + DataProvider<$marker>
+                + DataProvider<DecimalSymbolsV1Marker>
+                + ?Sized,
+        {
+            let plural_rules = PluralRules::try_new_cardinal_unstable(provider, locale)?;
+            let fixed_decimal_format = FixedDecimalFormatter::try_new_unstable(
+                provider,
+                locale,
+                options.fixed_decimal_formatter_options,
+            )?;
+            let display_names: DataPayload<$marker> = provider
+                .load(DataRequest {
+                    locale,
+                    metadata: Default::default(),
+                })?
+                .take_payload()?;
+            Self::try_new_internal(
+                display_names.get(),
+                &unit,
+                plural_rules,
+                fixed_decimal_format,
+            )
+        }
+    };
+}
+
+impl UnitFormatter {
+    constructor!(
+        try_new_long_unstable,
+        try_new_long,
+        try_new_long_with_any_provider,
+        try_new_long_with_buffer_provider,
+        LongUnitsDisplayNamesV1Marker,
+        "long"
+    );
+    constructor!(
+        try_new_short_unstable,
+        try_new_short,
+        try_new_short_with_any_provider,
+        try_new_short_with_buffer_provider,
+        ShortUnitsDisplayNamesV1Marker,
+        "short"
+    );
+    constructor!(
+        try_new_narrow_unstable,
+        try_new_narrow,
+        try_new_narrow_with_any_provider,
+        try_new_narrow_with_buffer_provider,
+        NarrowUnitsDisplayNamesV1Marker,
+        "narrow"
+    );
+
+    fn try_new_internal(
+        display_names: &UnitsDisplayNamesV1,
+        unit: &MeasureUnit,
+        plural_rules: PluralRules,
+        fixed_decimal_format: FixedDecimalFormatter,
+    ) -> Result<Self, UnitsFormatterError> {
+        let patterns = plural_rules
+            .categories()
+            .map(|category| {
+                unit_pattern(display_names, unit.as_str(), category.into())
+                    .map(|pattern| (category, pattern))
+                    .ok_or(UnitsFormatterError::UnknownUnit)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self {
+            patterns,
+            plural_rules,
+            fixed_decimal_format,
+        })
+    }
+
+    /// Formats a number with the unit of this [`UnitFormatter`].
+    ///
+    /// The plural form of the unit is that of the number as it is displayed, that is, after
+    /// it is rounded according to the options of the [`FixedDecimalFormatter`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    /// use icu_decimal::options::FixedDecimalFormatterOptions;
+    /// use icu_locid::locale;
+    /// use icu_unitsformatter::UnitFormatter;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let mut options = FixedDecimalFormatterOptions::default();
+    /// options.maximum_fraction_digits = Some(0);
+    /// let formatter = UnitFormatter::try_new_long(
+    ///     &locale!("en").into(),
+    ///     "kilometer".parse().unwrap(),
+    ///     options.into(),
+    /// )
+    /// .unwrap();
+    ///
+    /// let value = FixedDecimal::from(12).multiplied_pow10(-1);
+    /// assert_writeable_eq!(formatter.format(&value), "1 kilometer");
+    /// ```
+    pub fn format<'l>(&'l self, value: &'l FixedDecimal) -> FormattedUnit<'l> {
+        let number = self.fixed_decimal_format.format(value);
+        let pattern = self.pattern(self.plural_rules.category_for(number.value()));
+        FormattedUnit { pattern, number }
+    }
+
+    /// Returns the pattern for a number of the unit in the plural category `category`,
+    /// such as "{0} kilometers", where `{0}` stands for the number.
+    ///
+    /// This allows the unit to be combined with numbers that are not formatted by a
+    /// [`FixedDecimalFormatter`], such as compact decimals.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_locid::locale;
+    /// use icu_plurals::PluralCategory;
+    /// use icu_unitsformatter::UnitFormatter;
+    ///
+    /// let formatter = UnitFormatter::try_new_long(
+    ///     &locale!("en").into(),
+    ///     "kilometer".parse().unwrap(),
+    ///     Default::default(),
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(formatter.pattern(PluralCategory::One), "{0} kilometer");
+    /// assert_eq!(formatter.pattern(PluralCategory::Other), "{0} kilometers");
+    /// ```
+    pub fn pattern(&self, category: PluralCategory) -> &str {
+        self.patterns
+            .iter()
+            .find(|(c, _)| *c == category)
+            .or_else(|| {
+                self.patterns
+                    .iter()
+                    .find(|(c, _)| *c == PluralCategory::Other)
+            })
+            .map(|(_, pattern)| pattern.as_str())
+            .unwrap_or("{0}")
+    }
+}
+
+/// The SI prefixes, by power of ten.
+const SI_PREFIXES: [(&str, i8); 24] = [
+    ("quecto", -30),
+    ("ronto", -27),
+    ("yocto", -24),
+    ("zepto", -21),
+    ("atto", -18),
+    ("femto", -15),
+    ("pico", -12),
+    ("nano", -9),
+    ("micro", -6),
+    ("milli", -3),
+    ("centi", -2),
+    ("deci", -1),
+    ("deka", 1),
+    ("hecto", 2),
+    ("kilo", 3),
+    ("mega", 6),
+    ("giga", 9),
+    ("tera", 12),
+    ("peta", 15),
+    ("exa", 18),
+    ("zetta", 21),
+    ("yotta", 24),
+    ("ronna", 27),
+    ("quetta", 30),
+];
+
+/// A unit that is not a product or quotient of other units, such as `square-kilometer`.
+struct SingleUnit<'a> {
+    /// The identifier of the base unit, such as `meter`.
+    base: &'a str,
+    /// The power of ten of the SI prefix, or 0 if there is none.
+    prefix: i8,
+    /// The dimensionality, which is 1, 2 or 3.
+    power: u8,
+}
+
+/// Returns the pattern for a number of `unit` in the plural category `category`, composing it
+/// from the patterns of its components if needed.
+fn unit_pattern(
+    display_names: &UnitsDisplayNamesV1,
+    unit: &str,
+    category: RawPluralCategory,
+) -> Option<String> {
+    if let Some(pattern) = simple_pattern(display_names, unit, category) {
+        return Some(pattern.into());
+    }
+    let Some((numerator, denominator)) = unit.split_once("-per-") else {
+        return product_pattern(display_names, unit, category);
+    };
+    let numerator = product_pattern(display_names, numerator, category)?;
+    if let Some(per_unit_pattern) = display_names.per_unit_patterns.get(denominator) {
+        return Some(substitute(per_unit_pattern, &[&numerator]));
+    }
+    let denominator = product_pattern(display_names, denominator, RawPluralCategory::One)?;
+    Some(map_name(&numerator, |numerator| {
+        substitute(&display_names.per, &[numerator, &unit_name(&denominator)])
+    }))
+}
+
+/// Returns the pattern that CLDR has for `unit`, falling back to the `other` category.
+fn simple_pattern<'a>(
+    display_names: &'a UnitsDisplayNamesV1,
+    unit: &str,
+    category: RawPluralCategory,
+) -> Option<&'a str> {
+    display_names.patterns.get_2d(unit, &category).or_else(|| {
+        display_names
+            .patterns
+            .get_2d(unit, &RawPluralCategory::Other)
+    })
+}
+
+/// Returns the pattern for a product of single units, such as `newton-meter`.
+///
+/// Only the last unit is inflected for `category`; the others use their singular names.
+fn product_pattern(
+    display_names: &UnitsDisplayNamesV1,
+    product: &str,
+    category: RawPluralCategory,
+) -> Option<String> {
+    let units = split_product(display_names, product)?;
+    let (last, rest) = units.split_last()?;
+    let mut pattern = single_unit_pattern(display_names, last, category)?;
+    for unit in rest.iter().rev() {
+        let name = unit_name(&single_unit_pattern(
+            display_names,
+            unit,
+            RawPluralCategory::One,
+        )?);
+        pattern = map_name(&pattern, |rest| {
+            substitute(&display_names.times, &[&name, rest])
+        });
+    }
+    Some(pattern)
+}
+
+/// Splits a product into single units, greedily taking the longest known single unit.
+fn split_product<'a>(
+    display_names: &UnitsDisplayNamesV1,
+    product: &'a str,
+) -> Option<Vec<SingleUnit<'a>>> {
+    let mut units = Vec::new();
+    let mut rest = product;
+    while !rest.is_empty() {
+        let (unit, tail) = core::iter::once((rest, ""))
+            .chain(
+                rest.char_indices()
+                    .rev()
+                    .filter(|&(_, c)| c == '-')
+                    .filter_map(|(i, _)| Some((rest.get(..i)?, rest.get(i + 1..)?))),
+            )
+            .find_map(|(candidate, tail)| {
+                Some((parse_single_unit(display_names, candidate)?, tail))
+            })?;
+        units.push(unit);
+        rest = tail;
+    }
+    Some(units)
+}
+
+/// Parses a single unit, such as `square-kilometer`, whose base unit has display names.
+fn parse_single_unit<'a>(
+    display_names: &UnitsDisplayNamesV1,
+    unit: &'a str,
+) -> Option<SingleUnit<'a>> {
+    let is_known = |base: &str| display_names.patterns.contains_key0(base);
+    if is_known(unit) {
+        return Some(SingleUnit {
+            base: unit,
+            prefix: 0,
+            power: 1,
+        });
+    }
+    let (power, unit) = if let Some(unit) = unit.strip_prefix("square-") {
+        (2, unit)
+    } else if let Some(unit) = unit.strip_prefix("cubic-") {
+        (3, unit)
+    } else {
+        (1, unit)
+    };
+    if is_known(unit) {
+        return Some(SingleUnit {
+            base: unit,
+            prefix: 0,
+            power,
+        });
+    }
+    SI_PREFIXES.iter().find_map(|&(name, prefix)| {
+        let base = unit.strip_prefix(name)?;
+        is_known(base).then_some(SingleUnit {
+            base,
+            prefix,
+            power,
+        })
+    })
+}
+
+/// Returns the pattern for a number of a single unit, applying its SI prefix and power.
+fn single_unit_pattern(
+    display_names: &UnitsDisplayNamesV1,
+    unit: &SingleUnit,
+    category: RawPluralCategory,
+) -> Option<String> {
+    let mut pattern = String::from(simple_pattern(display_names, unit.base, category)?);
+    if unit.prefix != 0 {
+        let prefix_pattern = display_names.prefixes.get(&unit.prefix)?;
+        pattern = map_name(&pattern, |name| substitute(prefix_pattern, &[name]));
+    }
+    let power_patterns = match unit.power {
+        1 => return Some(pattern),
+        2 => &display_names.power2,
+        3 => &display_names.power3,
+        _ => return None,
+    };
+    let power_pattern = power_patterns
+        .get(&category)
+        .or_else(|| power_patterns.get(&RawPluralCategory::Other))?;
+    Some(map_name(&pattern, |name| {
+        substitute(power_pattern, &[name])
+    }))
+}
+
+/// Returns the name of the unit in a pattern, that is, the pattern without the
+/// placeholder and the whitespace next to it. For example, "{0} meters" ↦ "meters".
+fn unit_name(pattern: &str) -> String {
+    match pattern.split_once("{0}") {
+        Some((before, after)) => {
+            let mut name = String::from(before.trim_end());
+            name.push_str(after.trim_start());
+            name
+        }
+        None => pattern.into(),
+    }
+}
+
+/// Replaces the name of the unit in a pattern with `f(name)`, keeping the placeholder
+/// and the whitespace next to it.
+fn map_name(pattern: &str, f: impl FnOnce(&str) -> String) -> String {
+    let name = unit_name(pattern);
+    match pattern.find(name.as_str()) {
+        Some(start) if !name.is_empty() => {
+            let mut result = String::with_capacity(pattern.len());
+            result.push_str(pattern.get(..start).unwrap_or_default());
+            result.push_str(&f(&name));
+            result.push_str(pattern.get(start + name.len()..).unwrap_or_default());
+            result
+        }
+        _ => pattern.into(),
+    }
+}
+
+/// Replaces the placeholders `{0}`, `{1}`, … in `pattern` with the corresponding `args`.
+/// Placeholders without a corresponding argument are kept.
+fn substitute(pattern: &str, args: &[&str]) -> String {
+    let mut result = String::with_capacity(pattern.len());
+    let mut rest = pattern;
+    while let Some(start) = rest.find('{') {
+        result.push_str(rest.get(..start).unwrap_or_default());
+        let placeholder = rest.get(start + 1..).unwrap_or_default();
+        let arg = placeholder
+            .split_once('}')
+            .and_then(|(index, tail)| Some((*args.get(index.parse::<usize>().ok()?)?, tail)));
+        match arg {
+            Some((arg, tail)) => {
+                result.push_str(arg);
+                rest = tail;
+            }
+            None => {
+                result.push('{');
+                rest = placeholder;
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
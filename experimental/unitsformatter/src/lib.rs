@@ -0,0 +1,72 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! 🚧 \[Experimental\] Measurement unit formatting
+//!
+//! This module is published as its own crate ([`icu_unitsformatter`](https://docs.rs/icu_unitsformatter/latest/icu_unitsformatter/)).
+//!
+//! [`UnitFormatter`] formats numbers with a measurement unit, such as "3 kilometers", "5 km/h"
+//! or "12°C", in a long, short or narrow width. Units are identified by their
+//! [CLDR unit identifier](https://www.unicode.org/reports/tr35/tr35-general.html#Unit_Identifiers);
+//! units for which CLDR has no display names, such as `newton-meter`, `megameter` or
+//! `kilogram-per-cubic-meter`, are composed from their components.
+//!
+//! # Examples
+//!
+//! ```
+//! use fixed_decimal::FixedDecimal;
+//! use icu_locid::locale;
+//! use icu_unitsformatter::{MeasureUnit, UnitFormatter};
+//! use writeable::assert_writeable_eq;
+//!
+//! let unit: MeasureUnit = "meter-per-square-second".parse().unwrap();
+//! let formatter =
+//!     UnitFormatter::try_new_long(&locale!("en").into(), unit, Default::default())
+//!         .unwrap();
+//!
+//! let one = FixedDecimal::from(1);
+//! let acceleration = FixedDecimal::from(981).multiplied_pow10(-2);
+//!
+//! assert_writeable_eq!(formatter.format(&one), "1 meter per second squared");
+//! assert_writeable_eq!(formatter.format(&acceleration), "9.81 meters per second squared");
+//! ```
+//!
+//! <div class="stab unstable">
+//! 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+//! including in SemVer minor releases. Use with caution.
+//! </div>
+
+// https://github.com/unicode-org/icu4x/blob/main/docs/process/boilerplate.md#library-annotations
+#![cfg_attr(not(test), no_std)]
+#![cfg_attr(
+    not(test),
+    deny(
+        clippy::indexing_slicing,
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::exhaustive_structs,
+        clippy::exhaustive_enums,
+        missing_debug_implementations,
+    )
+)]
+#![warn(missing_docs)]
+
+extern crate alloc;
+
+mod error;
+mod format;
+mod formatter;
+mod measureunit;
+pub mod options;
+pub mod provider;
+
+pub use error::UnitsFormatterError;
+pub use format::parts;
+pub use format::FormattedUnit;
+pub use formatter::UnitFormatter;
+pub use measureunit::MeasureUnit;
+pub use options::UnitFormatterOptions;
+#[doc(no_inline)]
+pub use UnitsFormatterError as Error;
@@ -0,0 +1,68 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::string::String;
+use core::str::FromStr;
+
+use crate::UnitsFormatterError;
+
+/// A measurement unit, identified by its
+/// [CLDR unit identifier](https://www.unicode.org/reports/tr35/tr35-general.html#Unit_Identifiers),
+/// such as `kilometer`, `square-meter` or `kilometer-per-hour`.
+///
+/// The identifier does not include the unit category, that is, it is `meter` rather
+/// than `length-meter`.
+///
+/// # Examples
+///
+/// ```
+/// use icu_unitsformatter::{MeasureUnit, UnitsFormatterError};
+///
+/// let unit: MeasureUnit = "meter-per-second".parse().unwrap();
+/// assert_eq!(unit.as_str(), "meter-per-second");
+///
+/// assert_eq!(
+///     "Meter".parse::<MeasureUnit>(),
+///     Err(UnitsFormatterError::InvalidUnit)
+/// );
+/// assert_eq!(
+///     "meter--per-second".parse::<MeasureUnit>(),
+///     Err(UnitsFormatterError::InvalidUnit)
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MeasureUnit(String);
+
+impl MeasureUnit {
+    /// Creates a [`MeasureUnit`] from a unit identifier.
+    ///
+    /// The identifier must consist of lowercase ASCII letters and digits, separated by single
+    /// hyphens. Whether it names a known unit is checked when a
+    /// [`UnitFormatter`](crate::UnitFormatter) is created for it.
+    pub fn try_from_str(s: &str) -> Result<Self, UnitsFormatterError> {
+        if s.split('-').all(|component| {
+            !component.is_empty()
+                && component
+                    .bytes()
+                    .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit())
+        }) {
+            Ok(Self(s.into()))
+        } else {
+            Err(UnitsFormatterError::InvalidUnit)
+        }
+    }
+
+    /// Returns the unit identifier.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for MeasureUnit {
+    type Err = UnitsFormatterError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from_str(s)
+    }
+}
@@ -0,0 +1,32 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Options for configuring [`UnitFormatter`](crate::UnitFormatter).
+
+use icu_decimal::options::{FixedDecimalFormatterOptions, GroupingStrategy};
+
+/// A bag of options defining how numbers will be formatted by
+/// [`UnitFormatter`](crate::UnitFormatter).
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+#[non_exhaustive]
+pub struct UnitFormatterOptions {
+    /// Options to configure the inner [`FixedDecimalFormatter`](icu_decimal::FixedDecimalFormatter).
+    pub fixed_decimal_formatter_options: FixedDecimalFormatterOptions,
+}
+
+impl From<FixedDecimalFormatterOptions> for UnitFormatterOptions {
+    fn from(fixed_decimal_formatter_options: FixedDecimalFormatterOptions) -> Self {
+        Self {
+            fixed_decimal_formatter_options,
+        }
+    }
+}
+
+impl From<GroupingStrategy> for UnitFormatterOptions {
+    fn from(grouping_strategy: GroupingStrategy) -> Self {
+        Self {
+            fixed_decimal_formatter_options: grouping_strategy.into(),
+        }
+    }
+}
@@ -0,0 +1,86 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// Provider structs must be stable
+#![allow(clippy::exhaustive_structs, clippy::exhaustive_enums)]
+
+//! Data provider struct definitions for this ICU4X component.
+//!
+//! Read more about data providers: [`icu_provider`]
+
+use alloc::borrow::Cow;
+use icu_plurals::provider::RawPluralCategory;
+use icu_provider::prelude::*;
+use zerovec::{ZeroMap, ZeroMap2d};
+
+#[cfg(feature = "compiled_data")]
+#[derive(Debug)]
+/// Baked data
+pub struct Baked;
+
+#[cfg(feature = "compiled_data")]
+const _: () = {
+    pub mod icu {
+        pub use crate as unitsformatter;
+        pub use icu_locid_transform as locid_transform;
+    }
+    icu_unitsformatter_data::impl_units_long_v1!(Baked);
+    icu_unitsformatter_data::impl_units_narrow_v1!(Baked);
+    icu_unitsformatter_data::impl_units_short_v1!(Baked);
+};
+
+/// The display names of measurement units in one width, and the patterns used to
+/// compose the display names of units that CLDR does not name directly.
+///
+/// All patterns use the placeholder `{0}`, and the `per` and `times` patterns also
+/// use the placeholder `{1}`, as in CLDR.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[icu_provider::data_struct(
+    LongUnitsDisplayNamesV1Marker = "units/long@1",
+    ShortUnitsDisplayNamesV1Marker = "units/short@1",
+    NarrowUnitsDisplayNamesV1Marker = "units/narrow@1"
+)]
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_unitsformatter::provider)
+)]
+#[yoke(prove_covariance_manually)]
+pub struct UnitsDisplayNamesV1<'data> {
+    /// The pattern for a number of each unit, by unit identifier and plural category,
+    /// e.g., ("meter", one) ↦ "{0} meter". The unit identifiers do not include the
+    /// unit category, that is, they are "meter" rather than "length-meter".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub patterns: ZeroMap2d<'data, str, RawPluralCategory, str>,
+    /// The pattern for a unit used as the denominator of a compound unit, if CLDR
+    /// has one, e.g., "hour" ↦ "{0} per hour".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub per_unit_patterns: ZeroMap<'data, str, str>,
+    /// The pattern that combines a numerator with the name of a denominator, e.g.,
+    /// "{0} per {1}".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub per: Cow<'data, str>,
+    /// The pattern that combines the names of the units of a product, e.g., "{0}-{1}".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub times: Cow<'data, str>,
+    /// The pattern that squares the name of a unit, by plural category, e.g.,
+    /// other ↦ "square {0}".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub power2: ZeroMap<'data, RawPluralCategory, str>,
+    /// The pattern that cubes the name of a unit, by plural category, e.g.,
+    /// other ↦ "cubic {0}".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub power3: ZeroMap<'data, RawPluralCategory, str>,
+    /// The patterns that apply an SI prefix to the name of a unit, by power of ten,
+    /// e.g., 3 ↦ "kilo{0}".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub prefixes: ZeroMap<'data, i8, str>,
+}
@@ -0,0 +1,165 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use fixed_decimal::FixedDecimal;
+use icu_decimal::options::FixedDecimalFormatterOptions;
+use icu_locid::locale;
+use icu_unitsformatter::{parts, UnitFormatter, UnitsFormatterError};
+use writeable::{assert_writeable_eq, assert_writeable_parts_eq};
+
+macro_rules! generate_test {
+    ($test_name: ident, $constructor: ident, $locale: expr,
+     [$(($unit: literal, $value: literal, $expected: literal)),+ $(,)?]) => {
+        #[test]
+        fn $test_name() {
+            $(
+                let formatter = UnitFormatter::$constructor(
+                    &$locale.into(),
+                    $unit.parse().unwrap(),
+                    Default::default(),
+                )
+                .expect("unit should be known");
+                let value = FixedDecimal::from($value);
+                assert_writeable_eq!(
+                    formatter.format(&value),
+                    $expected,
+                    "{}",
+                    $unit
+                );
+            )+
+        }
+    };
+}
+
+generate_test!(
+    test_en_long,
+    try_new_long,
+    locale!("en"),
+    [
+        ("kilometer", 1, "1 kilometer"),
+        ("kilometer", 5, "5 kilometers"),
+        ("foot", 1200, "1,200 feet"),
+        ("kilometer-per-hour", 120, "120 kilometers per hour"),
+        // Composed from the per-unit pattern of the denominator
+        ("kilometer-per-second", 3, "3 kilometers per second"),
+        // Composed from the per pattern
+        ("kilogram-per-cubic-meter", 1, "1 kilogram per cubic meter"),
+        // Products
+        ("newton-meter", 1, "1 newton-meter"),
+        ("newton-meter", 2, "2 newton-meters"),
+        // Powers and SI prefixes
+        ("square-centimeter", 5, "5 square centimeters"),
+        ("megameter", 5, "5 megameters"),
+        ("millinewton", 1, "1 millinewton"),
+    ]
+);
+
+generate_test!(
+    test_en_short,
+    try_new_short,
+    locale!("en"),
+    [
+        ("kilometer", 5, "5 km"),
+        ("kilometer-per-hour", 120, "120 km/h"),
+        ("newton-meter", 2, "2 N⋅m"),
+        ("megameter", 5, "5 Mm"),
+        ("kilogram-per-cubic-meter", 2, "2 kg/m³"),
+    ]
+);
+
+generate_test!(
+    test_en_narrow,
+    try_new_narrow,
+    locale!("en"),
+    [("meter", 5, "5m"), ("newton-meter", 2, "2N⋅m"),]
+);
+
+generate_test!(
+    test_fr_long,
+    try_new_long,
+    locale!("fr"),
+    [
+        ("meter", 1, "1\u{a0}mètre"),
+        ("meter", 2, "2\u{a0}mètres"),
+        (
+            "kilogram-per-cubic-meter",
+            2,
+            "2\u{a0}kilogrammes par mètre cube"
+        ),
+    ]
+);
+
+generate_test!(
+    test_fr_short,
+    try_new_short,
+    locale!("fr"),
+    [
+        ("kilometer", 5, "5\u{202f}km"),
+        ("newton-meter", 2, "2\u{202f}N⋅m"),
+    ]
+);
+
+#[test]
+fn test_plural_form_of_rounded_value() {
+    let mut options = FixedDecimalFormatterOptions::default();
+    options.maximum_fraction_digits = Some(0);
+    let formatter = UnitFormatter::try_new_long(
+        &locale!("en").into(),
+        "kilometer".parse().unwrap(),
+        options.into(),
+    )
+    .unwrap();
+
+    for (value, expected) in [
+        ("1.2", "1 kilometer"),
+        ("0.9", "1 kilometer"),
+        ("1.5", "2 kilometers"),
+    ] {
+        let value = value.parse::<FixedDecimal>().unwrap();
+        assert_writeable_eq!(formatter.format(&value), expected);
+    }
+}
+
+#[test]
+fn test_parts() {
+    let formatter = UnitFormatter::try_new_short(
+        &locale!("en").into(),
+        "kilometer".parse().unwrap(),
+        Default::default(),
+    )
+    .unwrap();
+    let value = FixedDecimal::from(1234);
+
+    assert_writeable_parts_eq!(
+        formatter.format(&value),
+        "1,234 km",
+        [
+            (0, 1, icu_decimal::parts::INTEGER),
+            (1, 2, icu_decimal::parts::GROUP),
+            (2, 5, icu_decimal::parts::INTEGER),
+            (5, 6, parts::LITERAL),
+            (6, 8, parts::UNIT),
+        ]
+    );
+}
+
+#[test]
+fn test_unknown_unit() {
+    for unit in [
+        "smoot",
+        "kilometer-per-smoot",
+        "kilometer-per-hour-per-second",
+    ] {
+        assert_eq!(
+            UnitFormatter::try_new_long(
+                &locale!("en").into(),
+                unit.parse().unwrap(),
+                Default::default()
+            )
+            .unwrap_err(),
+            UnitsFormatterError::UnknownUnit,
+            "{unit}"
+        );
+    }
+}
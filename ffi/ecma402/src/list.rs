@@ -41,19 +41,39 @@ impl ecma402_traits::listformat::Format for ListFormat {
         L: IntoIterator<Item = I>,
         W: Write,
     {
-        struct WriteableWrap<J: Display>(J);
-
-        impl<J: Display> Writeable for WriteableWrap<J> {
-            fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
-                write!(sink, "{}", self.0)
-            }
-        }
-
         let values = list.into_iter().map(WriteableWrap).collect::<Vec<_>>();
         self.0.format(values.iter()).write_to(writer)
     }
 }
 
+impl ListFormat {
+    /// Formats `list` into `(type, value)` pairs, where the type is `"element"` or `"literal"`.
+    ///
+    /// This implements [`Intl.ListFormat.prototype.formatToParts`][link].
+    ///
+    /// [link]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/ListFormat/formatToParts
+    pub fn format_to_parts<I, L>(&self, list: L) -> Result<Vec<(&'static str, String)>, fmt::Error>
+    where
+        I: Display,
+        L: IntoIterator<Item = I>,
+    {
+        let values = list.into_iter().map(WriteableWrap).collect::<Vec<_>>();
+        let (string, parts) = writeable::writeable_to_parts(&self.0.format(values.iter()))?;
+        Ok(parts
+            .into_iter()
+            .filter_map(|(start, end, part)| Some((part.value, string.get(start..end)?.into())))
+            .collect())
+    }
+}
+
+struct WriteableWrap<J: Display>(J);
+
+impl<J: Display> Writeable for WriteableWrap<J> {
+    fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        write!(sink, "{}", self.0)
+    }
+}
+
 #[test]
 fn test() {
     use ecma402_traits::listformat::Format;
@@ -73,3 +93,30 @@ fn test() {
 
     assert_eq!(buf, "Mallorca e Ibiza");
 }
+
+#[test]
+fn test_format_to_parts() {
+    use ecma402_traits::listformat::Format;
+
+    let parts = ListFormat::try_new(
+        crate::testing::TestLocale("en"),
+        Options {
+            in_type: Type::Disjunction,
+            style: Style::Long,
+        },
+    )
+    .unwrap()
+    .format_to_parts(["Mallorca", "Ibiza", "Menorca"])
+    .unwrap();
+
+    assert_eq!(
+        parts,
+        [
+            ("element", "Mallorca".into()),
+            ("literal", ", ".into()),
+            ("element", "Ibiza".into()),
+            ("literal", ", or ".into()),
+            ("element", "Menorca".into()),
+        ]
+    );
+}
@@ -0,0 +1,466 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! A data provider wrapper that memoizes responses.
+//!
+//! Use [`CachingProvider`] to avoid repeatedly loading and deserializing the same payloads,
+//! for example when formatters are constructed per request from a [`BufferProvider`].
+
+use alloc::vec::Vec;
+use icu_provider::prelude::*;
+use icu_provider::MaybeSendSync;
+use yoke::trait_hack::YokeTraitHack;
+use yoke::Yokeable;
+use zerofrom::ZeroFrom;
+
+#[cfg(not(feature = "sync"))]
+use core::cell::RefCell as SelectedLock;
+#[cfg(feature = "sync")]
+use std::sync::Mutex as SelectedLock;
+
+/// The number of responses retained by [`CachingProvider::new`].
+pub const DEFAULT_CAPACITY: usize = 64;
+
+/// A data provider wrapper that memoizes successful responses of the inner provider.
+///
+/// Responses are keyed by [`DataKey`] and [`DataLocale`], and the cache holds at most a
+/// configurable number of entries. When it is full, the least recently used entry is evicted.
+/// Errors are never cached.
+///
+/// [`CachingProvider`] can wrap:
+///
+/// - A [`BufferProvider`], in which case the raw buffers are cached. This saves the cost of
+///   loading the buffers, such as file system access in `FsDataProvider`.
+/// - A deserializing provider created with `as_deserializing()` (or any other
+///   [`DataProvider`]), in which case the deserialized payloads are cached. This saves the
+///   cost of deserialization.
+/// - An [`AnyProvider`], in which case the type-erased payloads are cached.
+///
+/// Cloning a cached payload is cheap: buffers and type-erased payloads are reference counted,
+/// and deserialized payloads borrow from their reference-counted buffers.
+///
+/// # Cargo feature: `sync`
+///
+/// By default, the cache is guarded by a [`RefCell`](core::cell::RefCell), so
+/// [`CachingProvider`] is not [`Sync`]. Enable the `sync` Cargo feature to guard it with a
+/// [`Mutex`](std::sync::Mutex) instead, which allows the provider to be shared across threads.
+///
+/// # Examples
+///
+/// ```
+/// use icu_locid::locale;
+/// use icu_provider::hello_world::*;
+/// use icu_provider::prelude::*;
+/// use icu_provider_adapters::cache::CachingProvider;
+///
+/// # let provider = icu_provider_blob::BlobDataProvider::try_new_from_static_blob(include_bytes!("../tests/data/blob.postcard")).unwrap();
+/// let provider = provider.as_deserializing();
+///
+/// // Cache up to 16 deserialized payloads:
+/// let provider = CachingProvider::new_with_capacity(provider, 16);
+///
+/// let req = DataRequest {
+///     locale: &locale!("ja").into(),
+///     metadata: Default::default(),
+/// };
+///
+/// // The first load deserializes the payload and caches it...
+/// let response: DataResponse<HelloWorldV1Marker> =
+///     provider.load(req).expect("data should be present");
+/// assert_eq!(response.take_payload().unwrap().get().message, "こんにちは世界");
+/// assert_eq!(provider.len(), 1);
+///
+/// // ...and the second load is served from the cache.
+/// let response: DataResponse<HelloWorldV1Marker> =
+///     provider.load(req).expect("data should be present");
+/// assert_eq!(response.take_payload().unwrap().get().message, "こんにちは世界");
+/// assert_eq!(provider.len(), 1);
+/// ```
+#[derive(Debug)]
+pub struct CachingProvider<P> {
+    inner: P,
+    cache: SelectedLock<LruCache>,
+}
+
+impl<P> CachingProvider<P> {
+    /// Wraps a provider with a cache holding up to [`DEFAULT_CAPACITY`] responses.
+    pub fn new(provider: P) -> Self {
+        Self::new_with_capacity(provider, DEFAULT_CAPACITY)
+    }
+
+    /// Wraps a provider with a cache holding up to `capacity` responses.
+    ///
+    /// A capacity of zero disables caching.
+    pub fn new_with_capacity(provider: P, capacity: usize) -> Self {
+        Self {
+            inner: provider,
+            cache: SelectedLock::new(LruCache {
+                entries: Vec::new(),
+                capacity,
+            }),
+        }
+    }
+
+    /// Returns a reference to the inner provider, bypassing the cache.
+    pub fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the inner provider.
+    ///
+    /// The cache is not invalidated; call [`CachingProvider::clear`] if the inner provider
+    /// starts returning different data.
+    pub fn inner_mut(&mut self) -> &mut P {
+        &mut self.inner
+    }
+
+    /// Returns ownership of the inner provider to the caller.
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+
+    /// Returns the maximum number of responses held by the cache.
+    pub fn capacity(&self) -> usize {
+        self.with_cache(|cache| cache.capacity)
+    }
+
+    /// Returns the number of responses currently held by the cache.
+    pub fn len(&self) -> usize {
+        self.with_cache(|cache| cache.entries.len())
+    }
+
+    /// Returns whether the cache is currently empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes all responses from the cache.
+    pub fn clear(&self) {
+        self.with_cache(|cache| cache.entries.clear())
+    }
+
+    /// Runs the closure with exclusive access to the cache.
+    ///
+    /// The inner provider must not be invoked from within the closure.
+    fn with_cache<R>(&self, f: impl FnOnce(&mut LruCache) -> R) -> R {
+        #[cfg(not(feature = "sync"))]
+        let mut cache = self.cache.borrow_mut();
+        // A panic while holding the lock cannot leave the cache in an inconsistent state.
+        #[cfg(feature = "sync")]
+        let mut cache = self
+            .cache
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        f(&mut cache)
+    }
+
+    /// Returns the cached response for the request, or loads it from the inner provider
+    /// and caches it.
+    ///
+    /// Function arguments:
+    ///
+    /// - F1 should perform a data load for the request and return the erased result of it
+    /// - F2 should extract a response from a cached payload, or return `None` if the payload
+    ///   has the wrong type
+    fn load_cached<F1, F2, R>(
+        &self,
+        key: DataKey,
+        req: DataRequest,
+        f1: F1,
+        f2: F2,
+    ) -> Result<R, DataError>
+    where
+        F1: FnOnce(DataRequest) -> Result<(DataResponseMetadata, CachedPayload), DataError>,
+        F2: Fn(DataResponseMetadata, CachedPayload) -> Option<R>,
+    {
+        let cached = self.with_cache(|cache| cache.get(key, req.locale));
+        if let Some((metadata, payload)) = cached {
+            if let Some(response) = f2(metadata, payload) {
+                return Ok(response);
+            }
+        }
+        let (metadata, payload) = f1(req)?;
+        self.with_cache(|cache| cache.insert(key, req.locale, metadata.clone(), payload.clone()));
+        f2(metadata, payload).ok_or_else(|| {
+            DataErrorKind::MismatchedType(core::any::type_name::<R>()).with_req(key, req)
+        })
+    }
+}
+
+impl<P> AnyProvider for CachingProvider<P>
+where
+    P: AnyProvider,
+{
+    fn load_any(&self, key: DataKey, req: DataRequest) -> Result<AnyResponse, DataError> {
+        self.load_cached(
+            key,
+            req,
+            |req| {
+                let response = self.inner.load_any(key, req)?;
+                let payload = response.payload.map(CachedPayload::Any);
+                Ok((response.metadata, payload.unwrap_or(CachedPayload::None)))
+            },
+            |metadata, payload| match payload {
+                CachedPayload::Any(payload) => Some(AnyResponse {
+                    metadata,
+                    payload: Some(payload),
+                }),
+                CachedPayload::None => Some(AnyResponse {
+                    metadata,
+                    payload: None,
+                }),
+                CachedPayload::Buffer(_) => None,
+            },
+        )
+    }
+}
+
+impl<P> BufferProvider for CachingProvider<P>
+where
+    P: BufferProvider,
+{
+    fn load_buffer(
+        &self,
+        key: DataKey,
+        req: DataRequest,
+    ) -> Result<DataResponse<BufferMarker>, DataError> {
+        self.load_cached(
+            key,
+            req,
+            |req| {
+                let response = self.inner.load_buffer(key, req)?;
+                let payload = response.payload.map(CachedPayload::Buffer);
+                Ok((response.metadata, payload.unwrap_or(CachedPayload::None)))
+            },
+            |metadata, payload| match payload {
+                CachedPayload::Buffer(payload) => Some(DataResponse {
+                    metadata,
+                    payload: Some(payload),
+                }),
+                CachedPayload::None => Some(DataResponse {
+                    metadata,
+                    payload: None,
+                }),
+                CachedPayload::Any(_) => None,
+            },
+        )
+    }
+}
+
+impl<P, M> DataProvider<M> for CachingProvider<P>
+where
+    P: DataProvider<M>,
+    M: KeyedDataMarker,
+    M::Yokeable: ZeroFrom<'static, M::Yokeable>,
+    M::Yokeable: MaybeSendSync,
+    for<'a> YokeTraitHack<<M::Yokeable as Yokeable<'a>>::Output>: Clone,
+{
+    fn load(&self, req: DataRequest) -> Result<DataResponse<M>, DataError> {
+        self.load_cached(
+            M::KEY,
+            req,
+            |req| {
+                let response = self.inner.load(req)?;
+                let payload = response
+                    .payload
+                    .map(|p| CachedPayload::Any(p.wrap_into_any_payload()));
+                Ok((response.metadata, payload.unwrap_or(CachedPayload::None)))
+            },
+            |metadata, payload| match payload {
+                CachedPayload::Any(payload) => {
+                    payload.downcast().ok().map(|payload| DataResponse {
+                        metadata,
+                        payload: Some(payload),
+                    })
+                }
+                CachedPayload::None => Some(DataResponse {
+                    metadata,
+                    payload: None,
+                }),
+                CachedPayload::Buffer(_) => None,
+            },
+        )
+    }
+}
+
+/// A payload as stored in the cache.
+#[derive(Debug, Clone)]
+enum CachedPayload {
+    /// A type-erased payload, returned by an [`AnyProvider`] or a [`DataProvider`].
+    Any(AnyPayload),
+    /// A buffer, returned by a [`BufferProvider`].
+    Buffer(DataPayload<BufferMarker>),
+    /// A response without a payload.
+    None,
+}
+
+#[derive(Debug)]
+struct CacheEntry {
+    key: DataKey,
+    locale: DataLocale,
+    metadata: DataResponseMetadata,
+    payload: CachedPayload,
+}
+
+/// A least-recently-used cache.
+///
+/// Entries are ordered from least to most recently used. Lookups are linear, which is fast
+/// for the small capacities this cache is designed for.
+#[derive(Debug)]
+struct LruCache {
+    entries: Vec<CacheEntry>,
+    capacity: usize,
+}
+
+impl LruCache {
+    fn get(
+        &mut self,
+        key: DataKey,
+        locale: &DataLocale,
+    ) -> Option<(DataResponseMetadata, CachedPayload)> {
+        let index = self
+            .entries
+            .iter()
+            .position(|entry| entry.key == key && &entry.locale == locale)?;
+        let entry = self.entries.remove(index);
+        let result = (entry.metadata.clone(), entry.payload.clone());
+        self.entries.push(entry);
+        Some(result)
+    }
+
+    fn insert(
+        &mut self,
+        key: DataKey,
+        locale: &DataLocale,
+        metadata: DataResponseMetadata,
+        payload: CachedPayload,
+    ) {
+        if self.capacity == 0 {
+            return;
+        }
+        self.entries
+            .retain(|entry| !(entry.key == key && &entry.locale == locale));
+        if self.entries.len() >= self.capacity {
+            self.entries.remove(0);
+        }
+        self.entries.push(CacheEntry {
+            key,
+            locale: locale.clone(),
+            metadata,
+            payload,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::empty::EmptyDataProvider;
+    use core::cell::Cell;
+    use icu_locid::locale;
+    use icu_provider::hello_world::*;
+
+    /// Counts the number of loads that reach the inner provider.
+    struct CountingProvider<P> {
+        inner: P,
+        count: Cell<usize>,
+    }
+
+    impl<P: DataProvider<HelloWorldV1Marker>> DataProvider<HelloWorldV1Marker> for CountingProvider<P> {
+        fn load(&self, req: DataRequest) -> Result<DataResponse<HelloWorldV1Marker>, DataError> {
+            self.count.set(self.count.get() + 1);
+            self.inner.load(req)
+        }
+    }
+
+    fn load(provider: &impl DataProvider<HelloWorldV1Marker>, locale: &DataLocale) -> String {
+        provider
+            .load(DataRequest {
+                locale,
+                metadata: Default::default(),
+            })
+            .and_then(DataResponse::take_payload)
+            .map(|payload| payload.get().message.to_string())
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn test_lru_eviction() {
+        let provider = CachingProvider::new_with_capacity(
+            CountingProvider {
+                inner: HelloWorldProvider,
+                count: Cell::new(0),
+            },
+            2,
+        );
+        let de = locale!("de").into();
+        let ja = locale!("ja").into();
+        let ru = locale!("ru").into();
+
+        assert_eq!(load(&provider, &de), "Hallo Welt");
+        assert_eq!(load(&provider, &ja), "こんにちは世界");
+        assert_eq!(provider.inner().count.get(), 2);
+
+        // Both are cached; "de" becomes the most recently used entry.
+        assert_eq!(load(&provider, &de), "Hallo Welt");
+        assert_eq!(provider.inner().count.get(), 2);
+
+        // Loading "ru" evicts "ja".
+        assert_eq!(load(&provider, &ru), "Привет, мир");
+        assert_eq!(provider.inner().count.get(), 3);
+        assert_eq!(provider.len(), 2);
+        assert_eq!(load(&provider, &de), "Hallo Welt");
+        assert_eq!(provider.inner().count.get(), 3);
+        assert_eq!(load(&provider, &ja), "こんにちは世界");
+        assert_eq!(provider.inner().count.get(), 4);
+
+        provider.clear();
+        assert!(provider.is_empty());
+        assert_eq!(load(&provider, &de), "Hallo Welt");
+        assert_eq!(provider.inner().count.get(), 5);
+    }
+
+    #[test]
+    fn test_zero_capacity() {
+        let provider = CachingProvider::new_with_capacity(
+            CountingProvider {
+                inner: HelloWorldProvider,
+                count: Cell::new(0),
+            },
+            0,
+        );
+        let de = locale!("de").into();
+        load(&provider, &de);
+        load(&provider, &de);
+        assert_eq!(provider.inner().count.get(), 2);
+        assert!(provider.is_empty());
+    }
+
+    #[test]
+    fn test_errors_not_cached() {
+        let provider = CachingProvider::new(EmptyDataProvider::new());
+        assert!(provider
+            .load_any(HelloWorldV1Marker::KEY, Default::default())
+            .is_err());
+        assert!(provider.is_empty());
+    }
+
+    #[test]
+    fn test_any_provider() {
+        let provider = CachingProvider::new(HelloWorldProvider.as_any_provider());
+        let req = DataRequest {
+            locale: &locale!("ja").into(),
+            metadata: Default::default(),
+        };
+        for _ in 0..2 {
+            let payload: DataPayload<HelloWorldV1Marker> = provider
+                .load_any(HelloWorldV1Marker::KEY, req)
+                .and_then(AnyResponse::downcast)
+                .and_then(DataResponse::take_payload)
+                .expect("data should be present");
+            assert_eq!(payload.get().message, "こんにちは世界");
+        }
+        assert_eq!(provider.len(), 1);
+    }
+}
@@ -8,6 +8,8 @@
 //! - Use the [`either`] module to choose between multiple provider types at runtime.
 //! - Use the [`filter`] module to programmatically reject certain data requests.
 //! - Use the [`fallback`] module to automatically resolve arbitrary locales for data loading.
+//! - Use the [`cache`] module to avoid repeatedly loading and deserializing the same data.
+//! - Use the [`overlay`] module to override individual payloads at runtime.
 
 // https://github.com/unicode-org/icu4x/blob/main/docs/process/boilerplate.md#library-annotations
 #![cfg_attr(not(any(test, feature = "std")), no_std)]
@@ -28,9 +30,11 @@
 extern crate alloc;
 
 pub mod any_payload;
+pub mod cache;
 pub mod either;
 pub mod empty;
 pub mod fallback;
 pub mod filter;
 pub mod fork;
 mod helpers;
+pub mod overlay;
@@ -0,0 +1,311 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! A data provider wrapper that overrides individual payloads.
+//!
+//! Use [`OverlayProvider`] to patch a few payloads, such as a currency symbol or a date pattern
+//! for a single locale, without regenerating data.
+
+use alloc::vec::Vec;
+use icu_provider::prelude::*;
+use icu_provider::MaybeSendSync;
+use yoke::trait_hack::YokeTraitHack;
+use yoke::Yokeable;
+use zerofrom::ZeroFrom;
+
+/// A data provider wrapper that serves replacement payloads for selected
+/// [`DataKey`]s and [`DataLocale`]s, and forwards all other requests to the inner provider.
+///
+/// Replacement payloads can be constructed in code with [`OverlayProvider::push_payload`],
+/// or deserialized from JSON with [`OverlayProvider::push_json`].
+///
+/// Overrides only match requests for exactly the same locale. To make them participate in
+/// locale fallback, wrap the [`OverlayProvider`] in a
+/// [`LocaleFallbackProvider`](crate::fallback::LocaleFallbackProvider). Requests for keys and
+/// locales that are not overridden then fall back as usual.
+///
+/// [`OverlayProvider`] implements [`DataProvider`] and [`AnyProvider`]. To overlay a
+/// [`BufferProvider`], wrap it with `as_deserializing()` first.
+///
+/// # Examples
+///
+/// ```
+/// use icu_locid::locale;
+/// use icu_provider::hello_world::*;
+/// use icu_provider::prelude::*;
+/// use icu_provider_adapters::fallback::LocaleFallbackProvider;
+/// use icu_provider_adapters::overlay::OverlayProvider;
+/// use std::borrow::Cow;
+///
+/// # let provider = icu_provider_blob::BlobDataProvider::try_new_from_static_blob(include_bytes!("../tests/data/blob.postcard")).unwrap();
+/// let mut provider = OverlayProvider::new(provider.as_deserializing());
+///
+/// // Override the German message:
+/// provider.push_payload::<HelloWorldV1Marker>(
+///     locale!("de").into(),
+///     DataPayload::from_owned(HelloWorldV1 {
+///         message: Cow::Borrowed("Grüß Gott, Welt"),
+///     }),
+/// );
+///
+/// // Enable locale fallback for all keys and locales:
+/// let provider = LocaleFallbackProvider::try_new_unstable(provider)
+///     .expect("Fallback data present");
+///
+/// let load = |locale: &DataLocale| -> String {
+///     let payload: DataPayload<HelloWorldV1Marker> = provider
+///         .load(DataRequest {
+///             locale,
+///             metadata: Default::default(),
+///         })
+///         .and_then(DataResponse::take_payload)
+///         .expect("data should be present");
+///     payload.get().message.to_string()
+/// };
+///
+/// // The override is used for "de", and for "de-CH" via fallback...
+/// assert_eq!(load(&locale!("de").into()), "Grüß Gott, Welt");
+/// assert_eq!(load(&locale!("de-CH").into()), "Grüß Gott, Welt");
+///
+/// // ...while locales with more specific inner data, and other languages, still get data
+/// // from the inner provider.
+/// assert_eq!(load(&locale!("de-AT").into()), "Servus Welt");
+/// assert_eq!(load(&locale!("ja-JP").into()), "こんにちは世界");
+/// ```
+#[derive(Debug)]
+pub struct OverlayProvider<P> {
+    inner: P,
+    overrides: Vec<(DataKey, DataLocale, AnyPayload)>,
+}
+
+impl<P> OverlayProvider<P> {
+    /// Wraps a provider without any overrides.
+    pub fn new(provider: P) -> Self {
+        Self {
+            inner: provider,
+            overrides: Vec::new(),
+        }
+    }
+
+    /// Overrides the payload for the key of `M` and the given locale.
+    ///
+    /// Replaces any previous override for the same key and locale.
+    pub fn push_payload<M>(&mut self, locale: DataLocale, payload: DataPayload<M>)
+    where
+        M: KeyedDataMarker,
+        M::Yokeable: MaybeSendSync,
+    {
+        self.push_any_payload(M::KEY, locale, payload.wrap_into_any_payload());
+    }
+
+    /// Overrides the payload for the given key and locale with a type-erased payload.
+    ///
+    /// Replaces any previous override for the same key and locale.
+    pub fn push_any_payload(&mut self, key: DataKey, locale: DataLocale, payload: AnyPayload) {
+        self.overrides
+            .retain(|(k, l, _)| !(*k == key && *l == locale));
+        self.overrides.push((key, locale, payload));
+    }
+
+    /// Overrides the payload for the key of `M` and the given locale with a payload
+    /// deserialized from JSON.
+    ///
+    /// The JSON is deserialized eagerly, so that errors are reported here rather than when
+    /// the payload is loaded. Replaces any previous override for the same key and locale.
+    ///
+    /// ✨ *Enabled with the `deserialize_json` Cargo feature.*
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_locid::locale;
+    /// use icu_provider::hello_world::*;
+    /// use icu_provider::prelude::*;
+    /// use icu_provider_adapters::overlay::OverlayProvider;
+    ///
+    /// let mut provider = OverlayProvider::new(HelloWorldProvider);
+    /// provider
+    ///     .push_json::<HelloWorldV1Marker>(
+    ///         locale!("de").into(),
+    ///         r#"{"message":"Servus Welt"}"#,
+    ///     )
+    ///     .expect("valid JSON");
+    ///
+    /// let payload: DataPayload<HelloWorldV1Marker> = provider
+    ///     .load(DataRequest {
+    ///         locale: &locale!("de").into(),
+    ///         metadata: Default::default(),
+    ///     })
+    ///     .and_then(DataResponse::take_payload)
+    ///     .expect("data should be present");
+    /// assert_eq!(payload.get().message, "Servus Welt");
+    ///
+    /// // Invalid JSON is rejected up front:
+    /// assert!(provider
+    ///     .push_json::<HelloWorldV1Marker>(locale!("de").into(), r#"{"mesage":"typo"}"#)
+    ///     .is_err());
+    /// ```
+    #[cfg(feature = "deserialize_json")]
+    pub fn push_json<M>(&mut self, locale: DataLocale, json: &str) -> Result<(), DataError>
+    where
+        M: KeyedDataMarker,
+        M::Yokeable: MaybeSendSync,
+        for<'de> YokeTraitHack<<M::Yokeable as Yokeable<'de>>::Output>: serde::Deserialize<'de>,
+    {
+        let payload = DataPayload::from_owned_buffer(json.as_bytes().into())
+            .into_deserialized::<M>(icu_provider::buf::BufferFormat::Json)
+            .map_err(|e| {
+                e.with_req(
+                    M::KEY,
+                    DataRequest {
+                        locale: &locale,
+                        metadata: Default::default(),
+                    },
+                )
+            })?;
+        self.push_payload(locale, payload);
+        Ok(())
+    }
+
+    /// Removes the override for the given key and locale, if any.
+    pub fn remove(&mut self, key: DataKey, locale: &DataLocale) {
+        self.overrides
+            .retain(|(k, l, _)| !(*k == key && l == locale));
+    }
+
+    /// Returns a reference to the inner provider, bypassing the overrides.
+    pub fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the inner provider.
+    pub fn inner_mut(&mut self) -> &mut P {
+        &mut self.inner
+    }
+
+    /// Returns ownership of the inner provider to the caller.
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+
+    fn get(&self, key: DataKey, locale: &DataLocale) -> Option<&AnyPayload> {
+        self.overrides
+            .iter()
+            .find(|(k, l, _)| *k == key && l == locale)
+            .map(|(_, _, payload)| payload)
+    }
+}
+
+impl<P> AnyProvider for OverlayProvider<P>
+where
+    P: AnyProvider,
+{
+    fn load_any(&self, key: DataKey, req: DataRequest) -> Result<AnyResponse, DataError> {
+        match self.get(key, req.locale) {
+            Some(payload) => Ok(AnyResponse {
+                metadata: Default::default(),
+                payload: Some(payload.clone()),
+            }),
+            None => self.inner.load_any(key, req),
+        }
+    }
+}
+
+impl<P, M> DataProvider<M> for OverlayProvider<P>
+where
+    P: DataProvider<M>,
+    M: KeyedDataMarker,
+    M::Yokeable: ZeroFrom<'static, M::Yokeable>,
+    M::Yokeable: MaybeSendSync,
+    for<'a> YokeTraitHack<<M::Yokeable as Yokeable<'a>>::Output>: Clone,
+{
+    fn load(&self, req: DataRequest) -> Result<DataResponse<M>, DataError> {
+        match self.get(M::KEY, req.locale) {
+            Some(payload) => Ok(DataResponse {
+                metadata: Default::default(),
+                payload: Some(
+                    payload
+                        .downcast_cloned()
+                        .map_err(|e| e.with_req(M::KEY, req))?,
+                ),
+            }),
+            None => self.inner.load(req),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_locid::locale;
+    use icu_provider::hello_world::*;
+    use std::borrow::Cow;
+
+    fn load(provider: &impl DataProvider<HelloWorldV1Marker>, locale: &DataLocale) -> String {
+        provider
+            .load(DataRequest {
+                locale,
+                metadata: Default::default(),
+            })
+            .and_then(DataResponse::take_payload)
+            .map(|payload| payload.get().message.to_string())
+            .unwrap_or_default()
+    }
+
+    #[cfg(feature = "deserialize_json")]
+    #[test]
+    fn test_push_json() {
+        let mut provider = OverlayProvider::new(HelloWorldProvider);
+        let de: DataLocale = locale!("de").into();
+        let ja: DataLocale = locale!("ja").into();
+
+        provider
+            .push_json::<HelloWorldV1Marker>(de.clone(), r#"{"message":"Grüß Gott"}"#)
+            .unwrap();
+        assert_eq!(load(&provider, &de), "Grüß Gott");
+        assert_eq!(load(&provider, &ja), "こんにちは世界");
+
+        // A second override for the same key and locale replaces the first.
+        provider
+            .push_json::<HelloWorldV1Marker>(de.clone(), r#"{"message":"Servus"}"#)
+            .unwrap();
+        assert_eq!(load(&provider, &de), "Servus");
+        assert_eq!(provider.overrides.len(), 1);
+
+        // Invalid JSON is rejected and leaves the existing override in place.
+        let err = provider
+            .push_json::<HelloWorldV1Marker>(de.clone(), r#"{"message":"#)
+            .unwrap_err();
+        assert_eq!(err.key, Some(HelloWorldV1Marker::KEY));
+        assert_eq!(load(&provider, &de), "Servus");
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut provider = OverlayProvider::new(HelloWorldProvider);
+        let de: DataLocale = locale!("de").into();
+        let ja: DataLocale = locale!("ja").into();
+
+        for locale in [&de, &ja] {
+            provider.push_payload::<HelloWorldV1Marker>(
+                locale.clone(),
+                DataPayload::from_owned(HelloWorldV1 {
+                    message: Cow::Borrowed("Override"),
+                }),
+            );
+        }
+        assert_eq!(load(&provider, &de), "Override");
+        assert_eq!(load(&provider, &ja), "Override");
+
+        // Only the override for the given locale is removed.
+        provider.remove(HelloWorldV1Marker::KEY, &de);
+        assert_eq!(load(&provider, &de), "Hallo Welt");
+        assert_eq!(load(&provider, &ja), "Override");
+
+        // Removing a missing override is a no-op.
+        provider.remove(HelloWorldV1Marker::KEY, &de);
+        assert_eq!(load(&provider, &de), "Hallo Welt");
+        assert_eq!(load(&provider, &ja), "Override");
+    }
+}
@@ -6,7 +6,7 @@ use crate::blob_schema::{BlobSchema, BlobSchemaV1};
 use alloc::boxed::Box;
 use icu_provider::buf::BufferFormat;
 use icu_provider::prelude::*;
-use icu_provider::Cart;
+use icu_provider::StorageCart;
 use yoke::*;
 
 /// A data provider that reads from serialized blobs of data.
@@ -87,7 +87,7 @@ use yoke::*;
 /// ```
 #[derive(Clone)]
 pub struct BlobDataProvider {
-    data: Yoke<BlobSchemaV1<'static>, Option<Cart>>,
+    data: Yoke<BlobSchemaV1<'static>, Option<StorageCart>>,
 }
 
 impl core::fmt::Debug for BlobDataProvider {
@@ -101,13 +101,74 @@ impl core::fmt::Debug for BlobDataProvider {
 impl BlobDataProvider {
     /// Create a [`BlobDataProvider`] from a blob of ICU4X data.
     pub fn try_new_from_blob(blob: Box<[u8]>) -> Result<Self, DataError> {
+        Self::try_new_from_blob_storage(blob)
+    }
+
+    /// Create a [`BlobDataProvider`] from a blob of ICU4X data held in shared storage.
+    ///
+    /// This is a generalization of [`try_new_from_blob`](BlobDataProvider::try_new_from_blob):
+    /// `blob` can be any type that stably dereferences to `[u8]`, such as an `Arc<[u8]>` or a
+    /// memory-mapped file. All payloads are borrowed from it without copying.
+    ///
+    /// When the `sync` Cargo feature on the [`icu_provider`] crate is enabled, `blob` must
+    /// also be [`Send`] and [`Sync`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_locid::locale;
+    /// use icu_provider::hello_world::HelloWorldFormatter;
+    /// use icu_provider_blob::BlobDataProvider;
+    /// use std::sync::Arc;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let blob: Arc<[u8]> = std::fs::read(concat!(
+    ///     env!("CARGO_MANIFEST_DIR"),
+    ///     "/tests/data/hello_world.postcard",
+    /// ))
+    /// .expect("Reading pre-computed postcard buffer")
+    /// .into();
+    ///
+    /// // The provider shares the blob instead of copying it:
+    /// let provider = BlobDataProvider::try_new_from_blob_storage(blob.clone())
+    ///     .expect("Deserialization should succeed");
+    /// assert_eq!(Arc::strong_count(&blob), 2);
+    ///
+    /// let formatter = HelloWorldFormatter::try_new_with_buffer_provider(
+    ///     &provider,
+    ///     &locale!("la").into(),
+    /// )
+    /// .expect("locale exists");
+    ///
+    /// assert_writeable_eq!(formatter.format(), "Ave, munde");
+    /// ```
+    pub fn try_new_from_blob_storage<S>(blob: S) -> Result<Self, DataError>
+    where
+        S: stable_deref_trait::StableDeref<Target = [u8]> + icu_provider::MaybeSendSync + 'static,
+    {
         Ok(Self {
-            data: Cart::try_make_yoke(blob, |bytes| {
+            data: StorageCart::try_make_yoke(blob, |bytes| {
                 BlobSchema::deserialize_v1(&mut postcard::Deserializer::from_bytes(bytes))
             })?,
         })
     }
 
+    /// Create a [`BlobDataProvider`] by memory-mapping a file containing a blob of ICU4X data.
+    ///
+    /// The file is not read into memory; payloads are borrowed from the mapped pages, which
+    /// can be shared by all processes that map the same file.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified or truncated while the provider, or any payload loaded
+    /// from it, is alive. See [`memmap2::Mmap::map`].
+    ///
+    /// ✨ *Enabled with the `memmap2` Cargo feature.*
+    #[cfg(feature = "memmap2")]
+    pub unsafe fn try_new_from_mmap_file(file: &std::fs::File) -> Result<Self, DataError> {
+        Self::try_new_from_blob_storage(memmap2::Mmap::map(file)?)
+    }
+
     /// Create a [`BlobDataProvider`] from a static blob. This is a special case of
     /// [`try_new_from_blob`](BlobDataProvider::try_new_from_blob) and is allocation-free.
     pub fn try_new_from_static_blob(blob: &'static [u8]) -> Result<Self, DataError> {
@@ -129,7 +190,7 @@ impl BufferProvider for BlobDataProvider {
         metadata.buffer_format = Some(BufferFormat::Postcard1);
         Ok(DataResponse {
             metadata,
-            payload: Some(DataPayload::from_yoked_buffer(
+            payload: Some(DataPayload::from_yoked_storage(
                 self.data
                     .try_map_project_cloned(|blob, _| blob.load(key, req))?,
             )),
@@ -171,6 +232,29 @@ mod test {
         ));
     }
 
+    #[cfg(feature = "memmap2")]
+    #[test]
+    fn test_mmap() {
+        let file = std::fs::File::open(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/data/hello_world.postcard"
+        ))
+        .unwrap();
+        // Safe because the test data is not modified
+        let provider = unsafe { BlobDataProvider::try_new_from_mmap_file(&file) }.unwrap();
+
+        let payload: DataPayload<HelloWorldV1Marker> = provider
+            .as_deserializing()
+            .load(DataRequest {
+                locale: &icu_locid::locale!("la").into(),
+                metadata: Default::default(),
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap();
+        assert_eq!(payload.get().message, "Ave, munde");
+    }
+
     #[test]
     fn test_singleton() {
         let mut blob: Vec<u8> = Vec::new();
@@ -169,6 +169,7 @@ pub use crate::response::Cart;
 pub use crate::response::DataPayload;
 pub use crate::response::DataResponse;
 pub use crate::response::DataResponseMetadata;
+pub use crate::response::StorageCart;
 #[cfg(feature = "macros")]
 pub use icu_provider_macros::data_struct;
 
@@ -74,7 +74,7 @@ pub struct DataResponseMetadata {
 pub struct DataPayload<M: DataMarker>(pub(crate) DataPayloadInner<M>);
 
 pub(crate) enum DataPayloadInner<M: DataMarker> {
-    Yoke(Yoke<M::Yokeable, Option<Cart>>),
+    Yoke(Yoke<M::Yokeable, Option<StorageCart>>),
     StaticRef(&'static M::Yokeable),
 }
 
@@ -108,6 +108,67 @@ impl Cart {
     }
 }
 
+/// A "cart" over a shared, immutable byte buffer.
+///
+/// Unlike [`Cart`], which owns a `Box<[u8]>`, the buffer can be any type that stably
+/// dereferences to `[u8]`, such as an `Arc<[u8]>` or a memory-mapped file.
+#[derive(Clone)]
+pub struct StorageCart(SelectedRc<StorageCartInner>);
+
+#[cfg(not(feature = "sync"))]
+type StorageCartInner = dyn Deref<Target = [u8]>;
+#[cfg(feature = "sync")]
+type StorageCartInner = dyn Deref<Target = [u8]> + Send + Sync;
+
+impl Debug for StorageCart {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("StorageCart").field(&&**self).finish()
+    }
+}
+
+impl Deref for StorageCart {
+    type Target = [u8];
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+// Safe because both Rc and Arc are StableDeref, the storage is required to be StableDeref
+// when the cart is created, and our impl delegates.
+unsafe impl stable_deref_trait::StableDeref for StorageCart {}
+// Safe because both Rc and Arc are CloneableCart, and our impl delegates.
+unsafe impl yoke::CloneableCart for StorageCart {}
+
+impl StorageCart {
+    /// Creates a `Yoke<Y, Option<StorageCart>>` from a shared byte buffer by applying `f`.
+    ///
+    /// Unlike [`Cart::try_make_yoke`], this does not require the bytes to be in a `Box`:
+    /// `storage` can be an `Arc<[u8]>`, a memory-mapped file, or any other type that
+    /// implements [`StableDeref`](stable_deref_trait::StableDeref). The bytes are borrowed,
+    /// not copied.
+    ///
+    /// # Cargo feature: `sync`
+    ///
+    /// When the `sync` Cargo feature is enabled, `storage` must also be [`Send`] and [`Sync`].
+    pub fn try_make_yoke<S, Y, F, E>(storage: S, f: F) -> Result<Yoke<Y, Option<Self>>, E>
+    where
+        S: stable_deref_trait::StableDeref<Target = [u8]> + crate::any::MaybeSendSync + 'static,
+        for<'a> Y: Yokeable<'a>,
+        F: FnOnce(&[u8]) -> Result<<Y as Yokeable>::Output, E>,
+    {
+        let cart: SelectedRc<StorageCartInner> = SelectedRc::new(storage);
+        Yoke::try_attach_to_cart(cart, |b| f(b))
+            // Safe because the cart is only wrapped
+            .map(|yoke| unsafe { yoke.replace_cart(StorageCart) })
+            .map(Yoke::wrap_cart_in_option)
+    }
+}
+
+impl From<Cart> for StorageCart {
+    fn from(cart: Cart) -> Self {
+        Self(cart.0)
+    }
+}
+
 impl<M> Debug for DataPayload<M>
 where
     M: DataMarker,
@@ -618,14 +679,24 @@ where
 impl DataPayload<BufferMarker> {
     /// Converts an owned byte buffer into a `DataPayload<BufferMarker>`.
     pub fn from_owned_buffer(buffer: Box<[u8]>) -> Self {
-        let yoke = Yoke::attach_to_cart(SelectedRc::new(buffer), |b| &**b);
+        let cart: SelectedRc<StorageCartInner> = SelectedRc::new(buffer);
+        let yoke = Yoke::attach_to_cart(cart, |b| &**b);
         // Safe because cart is wrapped
-        let yoke = unsafe { yoke.replace_cart(|b| Some(Cart(b))) };
+        let yoke = unsafe { yoke.replace_cart(|b| Some(StorageCart(b))) };
         Self(DataPayloadInner::Yoke(yoke))
     }
 
     /// Converts a yoked byte buffer into a `DataPayload<BufferMarker>`.
     pub fn from_yoked_buffer(yoke: Yoke<&'static [u8], Option<Cart>>) -> Self {
+        // Safe because the new cart owns the same allocation as the old one
+        let yoke = unsafe { yoke.replace_cart(|c| c.map(StorageCart::from)) };
+        Self(DataPayloadInner::Yoke(yoke))
+    }
+
+    /// Converts a byte buffer yoked to shared storage into a `DataPayload<BufferMarker>`.
+    ///
+    /// See [`StorageCart::try_make_yoke`].
+    pub fn from_yoked_storage(yoke: Yoke<&'static [u8], Option<StorageCart>>) -> Self {
         Self(DataPayloadInner::Yoke(yoke))
     }
 
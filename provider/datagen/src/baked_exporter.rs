@@ -555,10 +555,6 @@ impl BakedExporter {
             .map(|(key, marker)| {
                 if !self.insert_feature_gates {
                     quote!()
-                } else if *key
-                    == icu_datetime::provider::calendar::DateSkeletonPatternsV1Marker::KEY
-                {
-                    quote! { #[cfg(feature = "icu_datetime_experimental")] }
                 } else if *key == icu_provider::hello_world::HelloWorldV1Marker::KEY {
                     quote!()
                 } else {
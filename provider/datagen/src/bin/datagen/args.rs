@@ -152,6 +152,13 @@ pub struct Cli {
     )]
     segmenter_lstm_root: Option<PathBuf>,
 
+    #[arg(long, value_name = "PATH")]
+    #[arg(
+        help = "Path to a local directory of TeX hyphenation patterns named hyph-{tag}.tex (see https://github.com/hyphenation/tex-hyphen).\n\
+                  Hyphenation data is only generated if this is present."
+    )]
+    hyphenation_root: Option<PathBuf>,
+
     #[arg(long, value_enum, default_value_t = TrieType::Small)]
     #[arg(
         help = "Whether to optimize CodePointTrie data structures for size (\"small\") or speed (\"fast\").\n\
@@ -262,6 +269,11 @@ impl Cli {
                     }
                 }
             }
+            if let Some(path) = &mut config.hyphenation {
+                if path.is_relative() {
+                    *path = parent.join(path.clone());
+                }
+            }
             if let config::KeyInclude::ForBinary(path) = &mut config.keys {
                 if path.is_relative() {
                     *path = parent.join(path.clone());
@@ -300,6 +312,7 @@ impl Cli {
                     &self.segmenter_lstm_tag,
                     "segmenter-lstm",
                 )?,
+                hyphenation: self.hyphenation_root.clone(),
                 trie_type: match self.trie_type {
                     TrieType::Fast => config::TrieType::Fast,
                     TrieType::Small => config::TrieType::Small,
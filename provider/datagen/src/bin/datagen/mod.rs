@@ -79,6 +79,10 @@ fn main() -> eyre::Result<()> {
         _ => eyre::bail!("Downloading data from tags requires the `networking` Cargo feature"),
     };
 
+    if let Some(path) = config.hyphenation {
+        provider = provider.with_hyphenation_patterns(path)?;
+    }
+
     let mut driver = DatagenDriver::new();
     driver = match config.keys {
         config::KeyInclude::None => driver.with_keys([]),
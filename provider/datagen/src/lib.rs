@@ -111,10 +111,12 @@
 //! affect the behaviour of [`all_keys`]:
 //! * `icu_compactdecimal`
 //! * `icu_displaynames`
+//! * `icu_durationformat`
 //! * `icu_relativetime`
 //! * `icu_singlenumberformatter`
 //! * `icu_transliteration`
 //! * `icu_unitsconversion`
+//! * `icu_unitsformatter`
 //! * ...
 //!
 //! The meta-feature `experimental_components` is available to activate all experimental components.
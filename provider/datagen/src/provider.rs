@@ -22,6 +22,7 @@ use std::sync::Arc;
 /// * [`is_missing_cldr_error`](Self::is_missing_cldr_error)
 /// * [`is_missing_icuexport_error`](Self::is_missing_icuexport_error)
 /// * [`is_missing_segmenter_lstm_error`](Self::is_missing_segmenter_lstm_error)
+/// * [`is_missing_hyphenation_error`](Self::is_missing_hyphenation_error)
 #[allow(clippy::exhaustive_structs)] // any information will be added to SourceData
 #[derive(Debug, Clone)]
 pub struct DatagenProvider {
@@ -36,6 +37,7 @@ impl Default for DatagenProvider {
                 cldr_paths: None,
                 icuexport_paths: None,
                 segmenter_lstm_paths: None,
+                hyphenation_paths: None,
                 trie_type: Default::default(),
                 collation_han_database: Default::default(),
                 #[cfg(feature = "legacy_api")]
@@ -96,6 +98,8 @@ impl DatagenProvider {
                     .unwrap()
                     .with_segmenter_lstm(data_root.join("lstm"))
                     .unwrap()
+                    .with_hyphenation_patterns(data_root.join("hyphenation"))
+                    .unwrap()
             })
             .clone()
     }
@@ -138,6 +142,21 @@ impl DatagenProvider {
         })
     }
 
+    /// Adds hyphenation source data to the provider. The path should point to a local
+    /// directory or ZIP file containing TeX hyphenation patterns named `hyph-{tag}.tex`, such
+    /// as the `tex` directory of the [hyph-utf8 patterns](
+    /// https://github.com/hyphenation/tex-hyphen/tree/master/hyph-utf8/tex/generic/hyph-utf8/patterns).
+    ///
+    /// Without hyphenation source data, no hyphenation data is exported.
+    pub fn with_hyphenation_patterns(self, root: PathBuf) -> Result<Self, DataError> {
+        Ok(Self {
+            source: SourceData {
+                hyphenation_paths: Some(Arc::new(SerdeCache::new(AbstractFs::new(root)?))),
+                ..self.source
+            },
+        })
+    }
+
     /// Adds CLDR source data to the provider. The data will be downloaded from GitHub
     /// using the given tag (see [GitHub releases](https://github.com/unicode-org/cldr-json/releases)).
     ///
@@ -202,6 +221,9 @@ impl DatagenProvider {
     const MISSING_SEGMENTER_LSTM_ERROR: DataError =
         DataErrorKind::MissingSourceData.with_str_context("segmenter");
 
+    const MISSING_HYPHENATION_ERROR: DataError =
+        DataErrorKind::MissingSourceData.with_str_context("hyphenation");
+
     /// Identifies errors that are due to missing CLDR data.
     pub fn is_missing_cldr_error(mut e: DataError) -> bool {
         e.key = None;
@@ -220,6 +242,12 @@ impl DatagenProvider {
         e == Self::MISSING_SEGMENTER_LSTM_ERROR
     }
 
+    /// Identifies errors that are due to missing hyphenation data.
+    pub fn is_missing_hyphenation_error(mut e: DataError) -> bool {
+        e.key = None;
+        e == Self::MISSING_HYPHENATION_ERROR
+    }
+
     pub(crate) fn cldr(&self) -> Result<&CldrCache, DataError> {
         self.source
             .cldr_paths
@@ -241,6 +269,13 @@ impl DatagenProvider {
             .ok_or(Self::MISSING_SEGMENTER_LSTM_ERROR)
     }
 
+    pub(crate) fn hyphenation(&self) -> Result<&SerdeCache, DataError> {
+        self.source
+            .hyphenation_paths
+            .as_deref()
+            .ok_or(Self::MISSING_HYPHENATION_ERROR)
+    }
+
     /// Set this to use tries optimized for speed instead of data size
     pub fn with_fast_tries(self) -> Self {
         Self {
@@ -317,6 +352,7 @@ pub struct SourceData {
     cldr_paths: Option<Arc<CldrCache>>,
     icuexport_paths: Option<Arc<SerdeCache>>,
     segmenter_lstm_paths: Option<Arc<SerdeCache>>,
+    hyphenation_paths: Option<Arc<SerdeCache>>,
     trie_type: TrieType,
     collation_han_database: CollationHanDatabase,
     #[cfg(feature = "legacy_api")]
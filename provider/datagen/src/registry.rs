@@ -144,6 +144,7 @@ registry!(
     icu_calendar::provider::JapaneseErasV1Marker = "calendar/japanese@1",
     icu_calendar::provider::JapaneseExtendedErasV1Marker = "calendar/japanext@1",
     icu_calendar::provider::WeekDataV1Marker = "datetime/week_data@1",
+    icu_calendar::provider::WeekDataV2Marker = "datetime/week_data@2",
     #[cfg(any(all(), feature = "icu_casemap"))]
     icu_casemap::provider::CaseMapV1Marker = "props/casemap@1",
     icu_casemap::provider::CaseMapUnfoldV1Marker = "props/casemap_unfold@1",
@@ -157,7 +158,10 @@ registry!(
     #[cfg(feature = "icu_compactdecimal")]
     icu_compactdecimal::provider::LongCompactDecimalFormatDataV1Marker = "compactdecimal/long@1",
     icu_compactdecimal::provider::ShortCompactDecimalFormatDataV1Marker = "compactdecimal/short@1",
+    icu_compactdecimal::provider::ShortCurrencyCompactDecimalFormatDataV1Marker =
+        "compactdecimal/shortcurrency@1",
     #[cfg(any(all(), feature = "icu_datetime"))]
+    icu_datetime::provider::calendar::AppendItemsV1Marker = "datetime/appenditems@1",
     icu_datetime::provider::calendar::BuddhistDateLengthsV1Marker =
         "datetime/buddhist/datelengths@1",
     icu_datetime::provider::calendar::BuddhistDateSymbolsV1Marker =
@@ -173,6 +177,7 @@ registry!(
         "datetime/ethiopic/datelengths@1",
     icu_datetime::provider::calendar::EthiopianDateSymbolsV1Marker =
         "datetime/ethiopic/datesymbols@1",
+    icu_datetime::provider::calendar::FlexibleDayPeriodsV1Marker = "datetime/flexibledayperiods@1",
     icu_datetime::provider::calendar::GregorianDateLengthsV1Marker =
         "datetime/gregory/datelengths@1",
     icu_datetime::provider::calendar::GregorianDateSymbolsV1Marker =
@@ -193,6 +198,7 @@ registry!(
     icu_datetime::provider::calendar::IslamicDateSymbolsV1Marker = "datetime/islamic/datesymbols@1",
     icu_datetime::provider::calendar::PersianDateLengthsV1Marker = "datetime/persian/datelengths@1",
     icu_datetime::provider::calendar::PersianDateSymbolsV1Marker = "datetime/persian/datesymbols@1",
+    icu_datetime::provider::calendar::QuarterSymbolsV1Marker = "datetime/quartersymbols@1",
     icu_datetime::provider::calendar::RocDateLengthsV1Marker = "datetime/roc/datelengths@1",
     icu_datetime::provider::calendar::RocDateSymbolsV1Marker = "datetime/roc/datesymbols@1",
     icu_datetime::provider::calendar::TimeLengthsV1Marker = "datetime/timelengths@1",
@@ -208,6 +214,7 @@ registry!(
     icu_datetime::provider::time_zones::TimeZoneFormatsV1Marker = "time_zone/formats@1",
     icu_datetime::provider::time_zones::ExemplarCitiesV1Marker = "time_zone/exemplar_cities@1",
     #[cfg(any(all(), feature = "icu_decimal"))]
+    icu_decimal::provider::DecimalNotationSymbolsV1Marker = "decimal/notation@1",
     icu_decimal::provider::DecimalSymbolsV1Marker = "decimal/symbols@1",
     #[cfg(feature = "icu_displaynames")]
     icu_displaynames::provider::RegionDisplayNamesV1Marker = "displaynames/regions@1",
@@ -215,6 +222,8 @@ registry!(
     icu_displaynames::provider::LocaleDisplayNamesV1Marker = "displaynames/locales@1",
     icu_displaynames::provider::ScriptDisplayNamesV1Marker = "displaynames/scripts@1",
     icu_displaynames::provider::VariantDisplayNamesV1Marker = "displaynames/variants@1",
+    #[cfg(feature = "icu_durationformat")]
+    icu_durationformat::provider::DigitalDurationDataV1Marker = "duration/digital@1",
     #[cfg(any(all(), feature = "icu_list"))]
     icu_list::provider::AndListV1Marker = "list/and@1",
     icu_list::provider::OrListV1Marker = "list/or@1",
@@ -243,6 +252,7 @@ registry!(
     #[cfg(any(all(), feature = "icu_plurals"))]
     icu_plurals::provider::CardinalV1Marker = "plurals/cardinal@1",
     icu_plurals::provider::OrdinalV1Marker = "plurals/ordinal@1",
+    icu_plurals::provider::PluralRangesV1Marker = "plurals/ranges@1",
     #[cfg(any(all(), feature = "icu_properties"))]
     icu_properties::provider::AlnumV1Marker = "props/alnum@1",
     icu_properties::provider::AlphabeticV1Marker = "props/Alpha@1",
@@ -415,14 +425,26 @@ registry!(
     icu_singlenumberformatter::provider::CurrencyEssentialsV1Marker = "currency/essentials@1",
     #[cfg(feature = "icu_unitsconversion")]
     icu_unitsconversion::provider::UnitsConstantsV1Marker = "units/constants@1",
+    icu_unitsconversion::provider::UnitsInfoV1Marker = "units/info@1",
+    icu_unitsconversion::provider::UnitPreferencesV1Marker = "units/preferences@1",
+    #[cfg(feature = "icu_unitsformatter")]
+    icu_unitsformatter::provider::LongUnitsDisplayNamesV1Marker = "units/long@1",
+    icu_unitsformatter::provider::ShortUnitsDisplayNamesV1Marker = "units/short@1",
+    icu_unitsformatter::provider::NarrowUnitsDisplayNamesV1Marker = "units/narrow@1",
     #[cfg(any(all(), feature = "icu_segmenter"))]
     icu_segmenter::provider::DictionaryForWordLineExtendedV1Marker =
         "segmenter/dictionary/wl_ext@1",
     icu_segmenter::provider::DictionaryForWordOnlyAutoV1Marker = "segmenter/dictionary/w_auto@1",
     icu_segmenter::provider::GraphemeClusterBreakDataV1Marker = "segmenter/grapheme@1",
+    icu_segmenter::provider::HyphenationPatternsV1Marker = "segmenter/hyphenation@1",
+    icu_segmenter::provider::LineBreakDataOverrideV1Marker = "segmenter/line/override@1",
     icu_segmenter::provider::LineBreakDataV1Marker = "segmenter/line@1",
     icu_segmenter::provider::LstmForWordLineAutoV1Marker = "segmenter/lstm/wl_auto@1",
+    icu_segmenter::provider::SentenceBreakDataOverrideV1Marker = "segmenter/sentence/override@1",
     icu_segmenter::provider::SentenceBreakDataV1Marker = "segmenter/sentence@1",
+    icu_segmenter::provider::SentenceBreakSuppressionsV1Marker =
+        "segmenter/sentence/suppressions@1",
+    icu_segmenter::provider::WordBreakDataOverrideV1Marker = "segmenter/word/override@1",
     icu_segmenter::provider::WordBreakDataV1Marker = "segmenter/word@1",
     #[cfg(any(all(), feature = "icu_timezone"))]
     icu_timezone::provider::MetazonePeriodV1Marker = "time_zone/metazone_period@1",
@@ -62,6 +62,21 @@ pub struct DayPeriodSymbols {
     pub pm: Cow<'static, str>,
     pub noon: Option<Cow<'static, str>>,
     pub midnight: Option<Cow<'static, str>>,
+    /// The flexible day periods, such as "morning1", and the variants of the other symbols.
+    #[serde(flatten)]
+    pub others: HashMap<String, String>,
+}
+
+#[derive(Debug, PartialEq, Clone, Deserialize)]
+pub struct QuarterSymbols {
+    #[serde(rename = "1")]
+    pub q1: String,
+    #[serde(rename = "2")]
+    pub q2: String,
+    #[serde(rename = "3")]
+    pub q3: String,
+    #[serde(rename = "4")]
+    pub q4: String,
 }
 
 #[derive(PartialEq, Debug, Deserialize, Clone)]
@@ -112,11 +127,39 @@ pub struct DateTimeFormats {
     pub short: LengthPattern,
     #[serde(rename = "availableFormats")]
     pub available_formats: AvailableFormats,
+    #[serde(rename = "appendItems")]
+    pub append_items: AppendItems,
 }
 
 #[derive(PartialEq, Clone, Debug, Deserialize)]
 pub struct AvailableFormats(pub HashMap<String, String>);
 
+#[derive(PartialEq, Clone, Debug, Deserialize)]
+pub struct AppendItems {
+    #[serde(rename = "Era")]
+    pub era: String,
+    #[serde(rename = "Year")]
+    pub year: String,
+    #[serde(rename = "Quarter")]
+    pub quarter: String,
+    #[serde(rename = "Month")]
+    pub month: String,
+    #[serde(rename = "Week")]
+    pub week: String,
+    #[serde(rename = "Day")]
+    pub day: String,
+    #[serde(rename = "Day-Of-Week")]
+    pub day_of_week: String,
+    #[serde(rename = "Hour")]
+    pub hour: String,
+    #[serde(rename = "Minute")]
+    pub minute: String,
+    #[serde(rename = "Second")]
+    pub second: String,
+    #[serde(rename = "Timezone")]
+    pub timezone: String,
+}
+
 /// This struct represents a 1:1 mapping of the CLDR ca-gregorian.json data at the key
 /// "main.LANGID.dates.calendars.gregorian" where "LANGID" is the identifier.
 ///
@@ -129,6 +172,7 @@ pub struct Dates {
     // Not used yet, will be in the future
     pub month_patterns: Option<Contexts<MonthPatternSymbols>>,
     pub days: Contexts<DaySymbols>,
+    pub quarters: Contexts<QuarterSymbols>,
     #[serde(default)]
     pub eras: Eras,
     #[serde(rename = "dayPeriods")]
@@ -0,0 +1,38 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Serde structs representing CLDR JSON dayPeriods.json files.
+//!
+//! Sample file:
+//! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-core/supplemental/dayPeriods.json>
+
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// The times of a day period rule, as "hh:mm" strings.
+///
+/// A rule either applies at an exact time (noon and midnight), or from a time until
+/// before another one.
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct DayPeriodRule {
+    #[serde(rename = "_at")]
+    pub at: Option<String>,
+    #[serde(rename = "_from")]
+    pub from: Option<String>,
+    #[serde(rename = "_before")]
+    pub before: Option<String>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Supplemental {
+    /// The day period rules of a locale, keyed by the locale and then by the day period,
+    /// such as "morning1".
+    #[serde(rename = "dayPeriodRuleSet")]
+    pub day_period_rule_set: HashMap<String, HashMap<String, DayPeriodRule>>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Resource {
+    pub supplemental: Supplemental,
+}
@@ -13,8 +13,8 @@ pub mod coverage_levels;
 #[cfg(feature = "icu_singlenumberformatter")]
 pub mod currencies;
 pub mod currency_data;
-#[cfg(feature = "icu_relativetime")]
 pub mod date_fields;
+pub mod day_periods;
 pub mod directionality;
 #[cfg(feature = "icu_displaynames")]
 pub mod displaynames;
@@ -27,10 +27,15 @@ pub mod numbering_systems;
 pub mod numbers;
 pub mod parent_locales;
 pub mod plurals;
+pub mod segments;
 pub mod time_zones;
 #[cfg(feature = "icu_transliteration")]
 pub mod transforms;
-#[cfg(feature = "icu_unitsconversion")]
+#[cfg(any(
+    feature = "icu_durationformat",
+    feature = "icu_unitsconversion",
+    feature = "icu_unitsformatter"
+))]
 pub mod units;
 pub mod week_data;
 
@@ -22,6 +22,13 @@ pub struct Symbols {
     pub minus_sign: String,
     #[serde(rename = "plusSign")]
     pub plus_sign: String,
+    #[serde(rename = "percentSign")]
+    pub percent_sign: String,
+    #[serde(rename = "perMille")]
+    pub per_mille: String,
+    pub exponential: String,
+    #[serde(rename = "superscriptingExponent")]
+    pub superscripting_exponent: String,
 }
 
 #[derive(PartialEq, Debug, Deserialize)]
@@ -86,6 +93,11 @@ impl<'de> Visitor<'de> for DecimalFormatVisitor {
     }
 }
 
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct PercentFormats {
+    pub standard: String,
+}
+
 #[derive(PartialEq, Debug, Deserialize)]
 pub struct CurrencyFormattingPatterns {
     /// Standard pattern
@@ -94,6 +106,14 @@ pub struct CurrencyFormattingPatterns {
     /// Standard alphaNextToNumber pattern
     #[serde(rename = "standard-alphaNextToNumber")]
     pub standard_alpha_next_to_number: Option<String>,
+
+    /// Short compact patterns
+    pub short: Option<ShortCurrencyFormats>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct ShortCurrencyFormats {
+    pub standard: DecimalFormat,
 }
 
 #[derive(PartialEq, Debug, Default)]
@@ -102,6 +122,8 @@ pub struct NumberingSystemData {
     pub symbols: HashMap<TinyStr8, Symbols>,
     /// Map from numbering system to decimal formats
     pub formats: HashMap<TinyStr8, DecimalFormats>,
+    /// Map from numbering system to percent formats
+    pub percent_formats: HashMap<TinyStr8, PercentFormats>,
     /// Map from numbering system to patterns
     pub currency_patterns: HashMap<TinyStr8, CurrencyFormattingPatterns>,
 }
@@ -139,12 +161,16 @@ impl<'de> Visitor<'de> for NumberingSystemDataVisitor {
                     let value: DecimalFormats = access.next_value()?;
                     result.formats.insert(numsys, value);
                 }
+                "percentFormats" => {
+                    let value: PercentFormats = access.next_value()?;
+                    result.percent_formats.insert(numsys, value);
+                }
                 "currencyFormats" => {
                     let value: CurrencyFormattingPatterns = access.next_value()?;
                     result.currency_patterns.insert(numsys, value);
                 }
                 _ => {
-                    // When needed, consume "scientificFormats", "percentFormats", ...
+                    // When needed, consume "scientificFormats", ...
                     // For now, ignore them.
                 }
             }
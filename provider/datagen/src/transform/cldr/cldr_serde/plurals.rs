@@ -6,6 +6,7 @@
 //!
 //! Sample file:
 //! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-core/supplemental/plurals.json>
+//! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-core/supplemental/pluralRanges.json>
 
 use icu_locid::LanguageIdentifier;
 use serde::Deserialize;
@@ -40,3 +41,20 @@ pub struct Supplemental {
 pub struct Resource {
     pub supplemental: Supplemental,
 }
+
+/// Map from `pluralRange-start-{start}-end-{end}` to the category of the range.
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct LocalePluralRanges(pub HashMap<String, String>);
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct PluralRanges(pub HashMap<LanguageIdentifier, LocalePluralRanges>);
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct RangesSupplemental {
+    pub plurals: PluralRanges,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct RangesResource {
+    pub supplemental: RangesSupplemental,
+}
@@ -2,4 +2,9 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
+#[cfg(feature = "icu_unitsconversion")]
 pub mod units_constants;
+#[cfg(any(feature = "icu_durationformat", feature = "icu_unitsformatter"))]
+pub mod units_display_names;
+#[cfg(feature = "icu_unitsconversion")]
+pub mod units_preferences;
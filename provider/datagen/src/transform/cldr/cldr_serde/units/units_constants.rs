@@ -28,10 +28,37 @@ pub struct UnitConstants {
     pub constants: BTreeMap<String, Constant>,
 }
 
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct UnitQuantity {
+    #[serde(rename = "_quantity")]
+    pub quantity: String,
+
+    #[serde(rename = "_status")]
+    pub status: Option<String>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct ConvertUnit {
+    #[serde(rename = "_baseUnit")]
+    pub base_unit: String,
+
+    #[serde(rename = "_factor")]
+    pub factor: Option<String>,
+
+    #[serde(rename = "_offset")]
+    pub offset: Option<String>,
+}
+
 #[derive(PartialEq, Debug, Deserialize)]
 pub struct Supplemental {
     #[serde(rename = "unitConstants")]
     pub unit_constants: UnitConstants,
+
+    #[serde(rename = "unitQuantities")]
+    pub unit_quantities: BTreeMap<String, UnitQuantity>,
+
+    #[serde(rename = "convertUnits")]
+    pub convert_units: BTreeMap<String, ConvertUnit>,
 }
 
 #[derive(Deserialize)]
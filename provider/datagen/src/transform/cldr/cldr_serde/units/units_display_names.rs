@@ -0,0 +1,56 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Serde structs representing CLDR JSON units.json files.
+//!
+//! Sample file:
+//! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-units-full/main/en/units.json>
+
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+/// An entry of a units width, which is a unit, an SI prefix or a compound pattern.
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Patterns {
+    #[serde(rename = "displayName")]
+    pub display_name: Option<String>,
+
+    #[serde(rename = "perUnitPattern")]
+    pub per_unit_pattern: Option<String>,
+
+    #[serde(rename = "unitPrefixPattern")]
+    pub unit_prefix_pattern: Option<String>,
+
+    #[serde(rename = "compoundUnitPattern")]
+    pub compound_unit_pattern: Option<String>,
+
+    /// The remaining patterns, such as `unitPattern-count-one` or
+    /// `compoundUnitPattern1-count-other`.
+    #[serde(flatten)]
+    pub others: BTreeMap<String, String>,
+}
+
+/// A pattern for durations in the digital style, such as `h:mm:ss`.
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct DurationUnit {
+    #[serde(rename = "durationUnitPattern")]
+    pub pattern: String,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct UnitsData {
+    pub long: BTreeMap<String, Patterns>,
+    pub short: BTreeMap<String, Patterns>,
+    pub narrow: BTreeMap<String, Patterns>,
+
+    #[serde(rename = "durationUnit-type-hms")]
+    pub duration_unit_hms: DurationUnit,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Units {
+    pub units: UnitsData,
+}
+
+pub type Resource = super::super::LocaleResource<Units>;
@@ -0,0 +1,34 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Serde structs representing CLDR JSON unitPreferenceData.json file.
+//!
+//! The file:
+//! <https://github.com/unicode-org/cldr-json/blob/master/cldr-json/cldr-core/supplemental/unitPreferenceData.json>
+
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct UnitPreference {
+    pub unit: String,
+    pub geq: Option<String>,
+    pub skeleton: Option<String>,
+}
+
+/// Maps from a quantity (e.g. length), a usage (e.g. road) and a region (e.g. US, or 001)
+/// to the preferred units.
+pub type UnitPreferenceData =
+    BTreeMap<String, BTreeMap<String, BTreeMap<String, Vec<UnitPreference>>>>;
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Supplemental {
+    #[serde(rename = "unitPreferenceData")]
+    pub unit_preference_data: UnitPreferenceData,
+}
+
+#[derive(Deserialize)]
+pub struct Resource {
+    pub supplemental: Supplemental,
+}
@@ -393,6 +393,12 @@ impl_data_provider!(
     "gregory"
 );
 
+impl_data_provider!(
+    QuarterSymbolsV1Marker,
+    |dates, _| { symbols::convert_quarters(dates) },
+    "gregory"
+);
+
 impl_data_provider!(
     DateSkeletonPatternsV1Marker,
     |dates, _| { DateSkeletonPatternsV1::from(dates) },
@@ -486,6 +492,24 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_append_items() {
+        let provider = crate::DatagenProvider::latest_tested_offline_subset();
+
+        let append_items: DataPayload<AppendItemsV1Marker> = provider
+            .load(DataRequest {
+                locale: &locale!("en").into(),
+                metadata: Default::default(),
+            })
+            .expect("Failed to load payload")
+            .take_payload()
+            .expect("Failed to retrieve payload");
+
+        assert_eq!("{0} {1}", append_items.get().weekday.to_string());
+        assert_eq!("{0} '(day:' {1})", append_items.get().day.to_string());
+        assert_eq!("{0} {1}", append_items.get().time_zone.to_string());
+    }
+
     #[test]
     fn test_basic_symbols() {
         use icu_calendar::types::MonthCode;
@@ -519,6 +543,25 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_flexible_day_periods() {
+        let provider = crate::DatagenProvider::latest_tested_offline_subset();
+
+        let day_periods: DataPayload<FlexibleDayPeriodsV1Marker> = provider
+            .load(DataRequest {
+                locale: &locale!("en").into(),
+                metadata: Default::default(),
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap();
+        let wide = &day_periods.get().day_periods.wide;
+
+        assert_eq!(Some("in the morning"), wide.periods.get(&360));
+        assert_eq!(Some("in the afternoon"), wide.periods.get(&720));
+        assert_eq!(Some("noon"), wide.noon.as_deref());
+    }
+
     #[test]
     fn unalias_contexts() {
         let provider = crate::DatagenProvider::latest_tested_offline_subset();
@@ -8,6 +8,8 @@ use icu_datetime::provider::calendar::*;
 use icu_datetime::skeleton::reference::Skeleton;
 use icu_datetime::skeleton::SkeletonError;
 use icu_plurals::PluralCategory;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
 use std::collections::HashMap;
 use std::convert::TryFrom;
 
@@ -73,3 +75,73 @@ impl From<&cldr_serde::ca::Dates> for DateSkeletonPatternsV1<'_> {
         Self(skeletons)
     }
 }
+
+impl DataProvider<AppendItemsV1Marker> for crate::DatagenProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<AppendItemsV1Marker>, DataError> {
+        self.check_req::<AppendItemsV1Marker>(req)?;
+        let langid = req.locale.get_langid();
+
+        // The appendItems are the same for all calendars of a locale.
+        let resource: &cldr_serde::ca::Resource = self
+            .cldr()?
+            .dates("gregorian")
+            .read_and_parse(&langid, "ca-gregorian.json")?;
+        let append_items = &resource
+            .main
+            .value
+            .dates
+            .calendars
+            .get("gregorian")
+            .expect("CLDR file contains a gregorian calendar")
+            .datetime_formats
+            .append_items;
+
+        let resource: &cldr_serde::date_fields::Resource = self
+            .cldr()?
+            .dates("gregorian")
+            .read_and_parse(&langid, "dateFields.json")?;
+        let fields = &resource.main.value.dates.fields.0;
+
+        let pattern = |pattern: &str, field: &str| {
+            let display_name = fields
+                .get(field)
+                .map(|field| field.display_name.as_str())
+                .unwrap_or(field);
+            // The display name is a literal in the pattern.
+            pattern
+                .replace("{2}", &display_name.replace('\'', "''"))
+                .parse()
+                .map_err(|_| {
+                    DataError::custom("Invalid appendItems pattern").with_display_context(pattern)
+                })
+        };
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(AppendItemsV1 {
+                era: pattern(&append_items.era, "era")?,
+                year: pattern(&append_items.year, "year")?,
+                quarter: pattern(&append_items.quarter, "quarter")?,
+                month: pattern(&append_items.month, "month")?,
+                week: pattern(&append_items.week, "week")?,
+                day: pattern(&append_items.day, "day")?,
+                weekday: pattern(&append_items.day_of_week, "weekday")?,
+                hour: pattern(&append_items.hour, "hour")?,
+                minute: pattern(&append_items.minute, "minute")?,
+                second: pattern(&append_items.second, "second")?,
+                time_zone: pattern(&append_items.timezone, "zone")?,
+            })),
+        })
+    }
+}
+
+impl IterableDataProvider<AppendItemsV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(self
+            .cldr()?
+            .dates("gregorian")
+            .list_langs()?
+            .map(DataLocale::from)
+            .collect())
+    }
+}
@@ -5,8 +5,10 @@
 use crate::transform::cldr::cldr_serde::{self, ca};
 use icu_calendar::types::MonthCode;
 use icu_datetime::provider::calendar::*;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
 use std::borrow::Cow;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 use tinystr::{tinystr, TinyStr16, TinyStr4};
 
 pub fn convert_dates(other: &cldr_serde::ca::Dates, calendar: &str) -> DateSymbolsV1<'static> {
@@ -23,6 +25,137 @@ pub fn convert_times(other: &cldr_serde::ca::Dates) -> TimeSymbolsV1<'static> {
     }
 }
 
+pub fn convert_quarters(other: &cldr_serde::ca::Dates) -> QuarterSymbolsV1<'static> {
+    QuarterSymbolsV1 {
+        quarters: other.quarters.get(&()),
+    }
+}
+
+impl DataProvider<FlexibleDayPeriodsV1Marker> for crate::DatagenProvider {
+    fn load(
+        &self,
+        req: DataRequest,
+    ) -> Result<DataResponse<FlexibleDayPeriodsV1Marker>, DataError> {
+        self.check_req::<FlexibleDayPeriodsV1Marker>(req)?;
+        let langid = req.locale.get_langid();
+
+        // The day periods are the same for all calendars of a locale.
+        let resource: &cldr_serde::ca::Resource = self
+            .cldr()?
+            .dates("gregorian")
+            .read_and_parse(&langid, "ca-gregorian.json")?;
+        let day_periods = &resource
+            .main
+            .value
+            .dates
+            .calendars
+            .get("gregorian")
+            .expect("CLDR file contains a gregorian calendar")
+            .day_periods
+            .format;
+
+        let rule_sets: &cldr_serde::day_periods::Resource = self
+            .cldr()?
+            .core()
+            .read_and_parse("supplemental/dayPeriods.json")?;
+        let rule_sets = &rule_sets.supplemental.day_period_rule_set;
+
+        // Rules are given for languages, and for a few scripts and regions. Locales without
+        // rules use the root rules, which divide the day into AM and PM.
+        let mut lookup = langid.clone();
+        let rules = loop {
+            let key = if lookup.language.is_empty() {
+                "root".into()
+            } else {
+                lookup.to_string()
+            };
+            if let Some(rules) = rule_sets.get(&key) {
+                break rules;
+            }
+            if lookup.region.take().is_none() && lookup.script.take().is_none() {
+                lookup.language = Default::default();
+            }
+        };
+
+        let convert = |symbols: &ca::DayPeriodSymbols| {
+            convert_flexible_day_periods(symbols, rules)
+                .map_err(|name| DataError::custom("Missing day period").with_display_context(name))
+        };
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(FlexibleDayPeriodsV1 {
+                day_periods: flexible_day_periods::FormatWidthsV1 {
+                    abbreviated: convert(&day_periods.abbreviated)?,
+                    narrow: convert(&day_periods.narrow)?,
+                    short: day_periods.short.as_ref().map(convert).transpose()?,
+                    wide: convert(&day_periods.wide)?,
+                },
+            })),
+        })
+    }
+}
+
+impl IterableDataProvider<FlexibleDayPeriodsV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(self
+            .cldr()?
+            .dates("gregorian")
+            .list_langs()?
+            .map(DataLocale::from)
+            .collect())
+    }
+}
+
+/// Pairs the names of the day periods with the times given by the rules of the locale.
+///
+/// Returns the name of the day period if its name is missing.
+fn convert_flexible_day_periods<'a>(
+    symbols: &ca::DayPeriodSymbols,
+    rules: &'a HashMap<String, cldr_serde::day_periods::DayPeriodRule>,
+) -> Result<flexible_day_periods::SymbolsV1<'static>, &'a str> {
+    let name = |period: &'a str| -> Result<Cow<'static, str>, &'a str> {
+        match period {
+            "am" => Ok(symbols.am.clone()),
+            "pm" => Ok(symbols.pm.clone()),
+            "noon" => symbols.noon.clone().ok_or(period),
+            "midnight" => symbols.midnight.clone().ok_or(period),
+            _ => symbols
+                .others
+                .get(period)
+                .map(|name| Cow::Owned(name.clone()))
+                .ok_or(period),
+        }
+    };
+    let minute_of_day = |time: &str| -> Option<u16> {
+        let (hour, minute) = time.split_once(':')?;
+        Some(hour.parse::<u16>().ok()? * 60 + minute.parse::<u16>().ok()?)
+    };
+
+    let mut periods = BTreeMap::new();
+    let mut noon = None;
+    let mut midnight = None;
+    for (period, rule) in rules {
+        match (period.as_str(), &rule.from) {
+            ("noon", _) => noon = Some(name(period)?),
+            ("midnight", _) => midnight = Some(name(period)?),
+            (_, Some(from)) => {
+                periods.insert(minute_of_day(from).ok_or(period.as_str())?, name(period)?);
+            }
+            _ => return Err(period),
+        }
+    }
+
+    Ok(flexible_day_periods::SymbolsV1 {
+        periods: periods
+            .iter()
+            .map(|(start, name)| (*start, name.as_ref()))
+            .collect(),
+        noon,
+        midnight,
+    })
+}
+
 fn convert_eras(eras: &cldr_serde::ca::Eras, calendar: &str) -> Eras<'static> {
     let map = get_era_code_map(calendar);
     let mut out_eras = Eras::default();
@@ -299,6 +432,12 @@ symbols_from!(
     [sun, mon, tue, wed, thu, fri, sat]
 );
 
+symbols_from!(
+    [cldr_serde::ca::QuarterSymbols, quarters],
+    (),
+    [q1, q2, q3, q4]
+);
+
 symbols_from!(
     [
         cldr_serde::ca::DayPeriodSymbols,
@@ -6,70 +6,122 @@ use crate::transform::cldr::cldr_serde::{
     self,
     week_data::{Territory, DEFAULT_TERRITORY},
 };
-use icu_calendar::provider::{WeekDataV1, WeekDataV1Marker};
+use icu_calendar::provider::{
+    WeekDataV1, WeekDataV1Marker, WeekDataV2, WeekDataV2Marker, WeekdaySet,
+};
+use icu_calendar::types::IsoWeekday;
 use icu_locid::LanguageIdentifier;
 use icu_provider::datagen::IterableDataProvider;
 use icu_provider::prelude::*;
+use std::collections::BTreeMap;
 use std::collections::HashSet;
 
-impl IterableDataProvider<WeekDataV1Marker> for crate::DatagenProvider {
-    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+impl crate::DatagenProvider {
+    fn week_data(&self) -> Result<&cldr_serde::week_data::WeekData, DataError> {
         let week_data: &cldr_serde::week_data::Resource = self
             .cldr()?
             .core()
             .read_and_parse("supplemental/weekData.json")?;
-        let week_data = &week_data.supplemental.week_data;
-        let regions: HashSet<DataLocale> = week_data
-            .min_days
-            .keys()
-            .chain(week_data.first_day.keys())
-            .filter_map(|t| match t {
-                &DEFAULT_TERRITORY => Some(None),
-                Territory::Region(r) => Some(Some(*r)),
-                _ => None,
-            })
-            .map(LanguageIdentifier::from)
-            .map(DataLocale::from)
-            .collect();
-        Ok(regions.into_iter().collect())
+        Ok(&week_data.supplemental.week_data)
+    }
+}
+
+/// Returns the locales for the given territories, dropping alternative variants.
+fn supported_locales<'a>(territories: impl Iterator<Item = &'a Territory>) -> Vec<DataLocale> {
+    let regions: HashSet<DataLocale> = territories
+        .filter_map(|t| match t {
+            &DEFAULT_TERRITORY => Some(None),
+            Territory::Region(r) => Some(Some(*r)),
+            _ => None,
+        })
+        .map(LanguageIdentifier::from)
+        .map(DataLocale::from)
+        .collect();
+    regions.into_iter().collect()
+}
+
+/// Returns the territory that data for `req` is keyed by.
+fn territory(req: DataRequest) -> Territory {
+    req.locale
+        .region()
+        .map(Territory::Region)
+        .unwrap_or_else(|| DEFAULT_TERRITORY.clone())
+}
+
+/// Returns the value for `territory` in `map`, or the default territory's value.
+fn get_or_default<'a, V>(
+    map: &'a BTreeMap<Territory, V>,
+    territory: &Territory,
+    field: &'static str,
+) -> Result<&'a V, DataError> {
+    map.get(territory)
+        .or_else(|| map.get(&DEFAULT_TERRITORY))
+        .ok_or_else(|| {
+            DataError::custom("Missing default entry in weekData.json").with_display_context(field)
+        })
+}
+
+impl IterableDataProvider<WeekDataV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        let week_data = self.week_data()?;
+        Ok(supported_locales(
+            week_data.min_days.keys().chain(week_data.first_day.keys()),
+        ))
     }
 }
 
 impl DataProvider<WeekDataV1Marker> for crate::DatagenProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<WeekDataV1Marker>, DataError> {
         self.check_req::<WeekDataV1Marker>(req)?;
-        let territory = req
-            .locale
-            .region()
-            .map(|v| -> Result<Territory, DataError> { Ok(Territory::Region(v)) })
-            .transpose()?
-            .unwrap_or_else(|| DEFAULT_TERRITORY.clone());
-
-        let week_data: &cldr_serde::week_data::Resource = self
-            .cldr()?
-            .core()
-            .read_and_parse("supplemental/weekData.json")?;
-        let week_data = &week_data.supplemental.week_data;
+        let territory = territory(req);
+        let week_data = self.week_data()?;
 
         Ok(DataResponse {
             metadata: Default::default(),
             payload: Some(DataPayload::from_owned(WeekDataV1 {
-                first_weekday: week_data
-                    .first_day
-                    .get(&territory)
-                    .or_else(|| week_data.first_day.get(&DEFAULT_TERRITORY))
-                    .ok_or(DataError::custom(
-                        "Missing default entry for firstDay in weekData.json",
-                    ))?
-                    .into(),
-                min_week_days: week_data
-                    .min_days
-                    .get(&territory)
-                    .or_else(|| week_data.min_days.get(&DEFAULT_TERRITORY))
-                    .ok_or(DataError::custom(
-                        "Missing default entry for minDays in weekData.json",
-                    ))?
-                    .0,
+                first_weekday: get_or_default(&week_data.first_day, &territory, "firstDay")?.into(),
+                min_week_days: get_or_default(&week_data.min_days, &territory, "minDays")?.0,
+            })),
+        })
+    }
+}
+
+impl IterableDataProvider<WeekDataV2Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        let week_data = self.week_data()?;
+        Ok(supported_locales(
+            week_data
+                .min_days
+                .keys()
+                .chain(week_data.first_day.keys())
+                .chain(week_data.weekend_start.keys())
+                .chain(week_data.weekend_end.keys()),
+        ))
+    }
+}
+
+impl DataProvider<WeekDataV2Marker> for crate::DatagenProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<WeekDataV2Marker>, DataError> {
+        self.check_req::<WeekDataV2Marker>(req)?;
+        let territory = territory(req);
+        let week_data = self.week_data()?;
+
+        let weekend_start: IsoWeekday =
+            get_or_default(&week_data.weekend_start, &territory, "weekendStart")?.into();
+        let weekend_end: IsoWeekday =
+            get_or_default(&week_data.weekend_end, &territory, "weekendEnd")?.into();
+        // The weekend runs from its start to its end day, wrapping around the end of the ISO week.
+        let weekend_len = (weekend_end as usize + 7 - weekend_start as usize) % 7 + 1;
+        let weekend = (0..weekend_len)
+            .map(|i| IsoWeekday::from(weekend_start as usize + i))
+            .collect::<Vec<_>>();
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(WeekDataV2 {
+                first_weekday: get_or_default(&week_data.first_day, &territory, "firstDay")?.into(),
+                min_week_days: get_or_default(&week_data.min_days, &territory, "minDays")?.0,
+                weekend: WeekdaySet::new(&weekend),
             })),
         })
     }
@@ -131,3 +183,44 @@ fn basic_cldr_week_data() {
         gg_week_data.get().first_weekday
     );
 }
+
+#[test]
+fn cldr_weekend() {
+    use icu_locid::langid;
+
+    let provider = crate::DatagenProvider::latest_tested_offline_subset();
+
+    let weekend = |langid: LanguageIdentifier| {
+        let week_data: DataPayload<WeekDataV2Marker> = provider
+            .load(DataRequest {
+                locale: &DataLocale::from(langid),
+                metadata: Default::default(),
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap();
+        week_data.get().weekend
+    };
+
+    assert_eq!(
+        weekend(LanguageIdentifier::UND),
+        WeekdaySet::new(&[IsoWeekday::Saturday, IsoWeekday::Sunday])
+    );
+    assert_eq!(
+        weekend(langid!("und-EG")),
+        WeekdaySet::new(&[IsoWeekday::Friday, IsoWeekday::Saturday])
+    );
+    assert_eq!(
+        weekend(langid!("und-AF")),
+        WeekdaySet::new(&[IsoWeekday::Thursday, IsoWeekday::Friday])
+    );
+    // Only weekendStart is defined for IN, so the weekend ends on the default Sunday.
+    assert_eq!(
+        weekend(langid!("und-IN")),
+        WeekdaySet::new(&[IsoWeekday::Sunday])
+    );
+    assert_eq!(
+        weekend(langid!("und-IR")),
+        WeekdaySet::new(&[IsoWeekday::Friday])
+    );
+}
@@ -2,12 +2,14 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
+use super::compact_decimal_pattern;
 use crate::transform::cldr::cldr_serde;
 use icu_compactdecimal::provider::*;
 use icu_locid::extensions::unicode::key;
 use icu_provider::datagen::IterableDataProvider;
 use icu_provider::prelude::*;
 use std::convert::TryFrom;
+use tinystr::tinystr;
 
 impl DataProvider<ShortCompactDecimalFormatDataV1Marker> for crate::DatagenProvider {
     fn load(
@@ -105,6 +107,73 @@ impl DataProvider<LongCompactDecimalFormatDataV1Marker> for crate::DatagenProvid
     }
 }
 
+impl DataProvider<ShortCurrencyCompactDecimalFormatDataV1Marker> for crate::DatagenProvider {
+    fn load(
+        &self,
+        req: DataRequest,
+    ) -> Result<DataResponse<ShortCurrencyCompactDecimalFormatDataV1Marker>, DataError> {
+        self.check_req::<ShortCurrencyCompactDecimalFormatDataV1Marker>(req)?;
+        let langid = req.locale.get_langid();
+
+        let resource: &cldr_serde::numbers::Resource = self
+            .cldr()?
+            .numbers()
+            .read_and_parse(&langid, "numbers.json")?;
+
+        let numbers = &resource.main.value.numbers;
+
+        let nsname = match req.locale.get_unicode_ext(&key!("nu")) {
+            Some(v) => *v
+                .as_tinystr_slice()
+                .first()
+                .expect("expecting subtag if key is present"),
+            None => numbers.default_numbering_system,
+        };
+
+        let currency_formats = numbers
+            .numsys_data
+            .currency_patterns
+            .get(&nsname)
+            .ok_or_else(|| {
+                DataError::custom("Could not find currency formats for numbering system")
+                    .with_display_context(&nsname)
+            })?;
+
+        // Some numbering systems, such as arab, have no compact currency patterns
+        // of their own; use those of latn instead.
+        let short = currency_formats
+            .short
+            .as_ref()
+            .or_else(|| {
+                numbers
+                    .numsys_data
+                    .currency_patterns
+                    .get(&tinystr!(8, "latn"))?
+                    .short
+                    .as_ref()
+            })
+            .ok_or_else(|| {
+                DataError::custom("Could not find short currency formats")
+                    .with_display_context(&nsname)
+            })?;
+
+        let result = compact_decimal_pattern::compact_currency_patterns(
+            &short.standard,
+            &currency_formats.standard,
+        )
+        .map_err(|s| {
+            DataError::custom("Could not create compact currency patterns")
+                .with_display_context(&s)
+                .with_display_context(&nsname)
+        })?;
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(result)),
+        })
+    }
+}
+
 impl IterableDataProvider<ShortCompactDecimalFormatDataV1Marker> for crate::DatagenProvider {
     fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
         self.supported_locales()
@@ -117,6 +186,14 @@ impl IterableDataProvider<LongCompactDecimalFormatDataV1Marker> for crate::Datag
     }
 }
 
+impl IterableDataProvider<ShortCurrencyCompactDecimalFormatDataV1Marker>
+    for crate::DatagenProvider
+{
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        self.supported_locales()
+    }
+}
+
 #[cfg(test)]
 
 mod tests {
@@ -258,4 +335,81 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_compact_short_currency() {
+        let provider = crate::DatagenProvider::latest_tested_offline_subset();
+
+        let ja_compact_short_currency: DataPayload<ShortCurrencyCompactDecimalFormatDataV1Marker> =
+            provider
+                .load(DataRequest {
+                    locale: &locale!("ja").into(),
+                    metadata: Default::default(),
+                })
+                .unwrap()
+                .take_payload()
+                .unwrap();
+
+        // The noncompact pattern is explicit, and the currency sign is part of the literal text.
+        let copy: Box<[(i8, Count, Pattern)]> = ja_compact_short_currency
+            .get()
+            .patterns
+            .iter0()
+            .flat_map(|kkv| {
+                let key0 = *kkv.key0();
+                kkv.into_iter1()
+                    .map(move |(k, v)| (key0, Count::from_unaligned(*k), Pattern::zero_from(v)))
+            })
+            .collect();
+        assert_eq!(
+            copy.as_ref(),
+            [
+                (
+                    0,
+                    Count::Other,
+                    Pattern {
+                        index: 2,
+                        exponent: 0,
+                        literal_text: Cow::Borrowed("¤")
+                    }
+                ),
+                (
+                    4,
+                    Count::Other,
+                    Pattern {
+                        index: 2,
+                        exponent: 4,
+                        literal_text: Cow::Borrowed("¤万")
+                    }
+                ),
+                (
+                    8,
+                    Count::Other,
+                    Pattern {
+                        index: 2,
+                        exponent: 8,
+                        literal_text: Cow::Borrowed("¤億")
+                    }
+                ),
+                (
+                    12,
+                    Count::Other,
+                    Pattern {
+                        index: 2,
+                        exponent: 12,
+                        literal_text: Cow::Borrowed("¤兆")
+                    }
+                ),
+                (
+                    16,
+                    Count::Other,
+                    Pattern {
+                        index: 2,
+                        exponent: 16,
+                        literal_text: Cow::Borrowed("¤京")
+                    }
+                )
+            ]
+        );
+    }
 }
@@ -62,6 +62,8 @@ impl FromStr for DecimalSubPattern {
             "#,##,##0.###" => (3, 2, 0, 3),
             "0.######" => (0, 0, 0, 6),
             "#,##0.00" => (3, 3, 2, 2),
+            "#,##0" => (3, 3, 0, 0),
+            "#,##,##0" => (3, 2, 0, 0),
             _ => return Err(Error::UnknownPatternBody(body.to_string())),
         };
         Ok(Self {
@@ -200,6 +202,20 @@ fn test_basic() {
                 }),
             }),
         },
+        TestCase {
+            pattern: "#,##,##0 %",
+            expected: Ok(DecimalPattern {
+                positive: DecimalSubPattern {
+                    prefix: "".into(),
+                    suffix: " %".into(),
+                    primary_grouping: 3,
+                    secondary_grouping: 2,
+                    min_fraction_digits: 0,
+                    max_fraction_digits: 0,
+                },
+                negative: None,
+            }),
+        },
         TestCase {
             pattern: "xyz",
             expected: Err(Error::NoBodyInSubpattern),
@@ -14,6 +14,7 @@ mod compact;
 #[cfg(feature = "icu_compactdecimal")]
 mod compact_decimal_pattern;
 pub(crate) mod decimal_pattern;
+mod notation;
 mod symbols;
 
 impl crate::DatagenProvider {
@@ -0,0 +1,125 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::transform::cldr::cldr_serde;
+use icu_decimal::provider::*;
+use icu_locid::extensions::unicode::key;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use std::borrow::Cow;
+use std::convert::TryFrom;
+use tinystr::TinyAsciiStr;
+
+impl DataProvider<DecimalNotationSymbolsV1Marker> for crate::DatagenProvider {
+    fn load(
+        &self,
+        req: DataRequest,
+    ) -> Result<DataResponse<DecimalNotationSymbolsV1Marker>, DataError> {
+        self.check_req::<DecimalNotationSymbolsV1Marker>(req)?;
+        let langid = req.locale.get_langid();
+
+        let resource: &cldr_serde::numbers::Resource = self
+            .cldr()?
+            .numbers()
+            .read_and_parse(&langid, "numbers.json")?;
+
+        let numbers = &resource.main.value.numbers;
+
+        let nsname = match req.locale.get_unicode_ext(&key!("nu")) {
+            Some(v) => *v
+                .as_tinystr_slice()
+                .first()
+                .expect("expecting subtag if key is present"),
+            None => numbers.default_numbering_system,
+        };
+
+        let result = DecimalNotationSymbolsV1::try_from(NumbersWithNumsys(numbers, nsname))
+            .map_err(|s| {
+                DataError::custom("Could not create decimal notation symbols")
+                    .with_display_context(&s)
+                    .with_display_context(&nsname)
+            })?;
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(result)),
+        })
+    }
+}
+
+impl IterableDataProvider<DecimalNotationSymbolsV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        self.supported_locales()
+    }
+}
+
+#[derive(Debug)]
+struct NumbersWithNumsys<'a>(pub &'a cldr_serde::numbers::Numbers, pub TinyAsciiStr<8>);
+
+impl TryFrom<NumbersWithNumsys<'_>> for DecimalNotationSymbolsV1<'static> {
+    type Error = Cow<'static, str>;
+
+    fn try_from(other: NumbersWithNumsys<'_>) -> Result<Self, Self::Error> {
+        let NumbersWithNumsys(numbers, nsname) = other;
+        let symbols = numbers
+            .numsys_data
+            .symbols
+            .get(&nsname)
+            .ok_or("Could not find symbols for numbering system")?;
+        let percent_formats = numbers
+            .numsys_data
+            .percent_formats
+            .get(&nsname)
+            .ok_or("Could not find percent formats for numbering system")?;
+        let parsed_pattern: super::decimal_pattern::DecimalPattern = percent_formats
+            .standard
+            .parse()
+            .map_err(|s: super::decimal_pattern::Error| s.to_string())?;
+
+        // CLDR has no per-mille patterns; like ICU, place the per-mille sign where the
+        // percent pattern places the percent sign.
+        let localize = |sign: &str| AffixesV1 {
+            prefix: Cow::Owned(parsed_pattern.positive.prefix.replace('%', sign)),
+            suffix: Cow::Owned(parsed_pattern.positive.suffix.replace('%', sign)),
+        };
+
+        Ok(Self {
+            percent_affixes: localize(&symbols.percent_sign),
+            per_mille_affixes: localize(&symbols.per_mille),
+            exponential: Cow::Owned(symbols.exponential.clone()),
+            superscripting_exponent: Cow::Owned(symbols.superscripting_exponent.clone()),
+        })
+    }
+}
+
+#[test]
+fn test_basic() {
+    use icu_locid::locale;
+
+    let provider = crate::DatagenProvider::latest_tested_offline_subset();
+
+    let load = |locale: &DataLocale| -> DataPayload<DecimalNotationSymbolsV1Marker> {
+        provider
+            .load(DataRequest {
+                locale,
+                metadata: Default::default(),
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap()
+    };
+
+    let en = load(&locale!("en").into());
+    assert_eq!(en.get().percent_affixes.suffix, "%");
+    assert_eq!(en.get().per_mille_affixes.suffix, "‰");
+    assert_eq!(en.get().exponential, "E");
+
+    let tr = load(&locale!("tr").into());
+    assert_eq!(tr.get().percent_affixes.prefix, "%");
+    assert_eq!(tr.get().percent_affixes.suffix, "");
+
+    let ar_eg = load(&locale!("ar-EG").into());
+    assert_eq!(ar_eg.get().percent_affixes.suffix, "٪؜");
+    assert_eq!(ar_eg.get().exponential, "اس");
+}
@@ -19,9 +19,14 @@ pub mod locale_canonicalizer;
 pub mod plurals;
 #[cfg(feature = "icu_relativetime")]
 pub mod relativetime;
+pub mod segmenter;
 pub mod source;
 pub mod time_zones;
 #[cfg(feature = "icu_transliteration")]
 pub mod transforms;
-#[cfg(feature = "icu_unitsconversion")]
+#[cfg(any(
+    feature = "icu_durationformat",
+    feature = "icu_unitsconversion",
+    feature = "icu_unitsformatter"
+))]
 pub mod units;
@@ -5,8 +5,10 @@
 use crate::transform::cldr::cldr_serde;
 use icu_plurals::provider::*;
 use icu_plurals::rules::runtime::ast::Rule;
+use icu_plurals::PluralCategory;
 use icu_provider::datagen::IterableDataProvider;
 use icu_provider::prelude::*;
+use zerovec::ZeroMap2d;
 
 impl crate::DatagenProvider {
     fn get_rules_for(&self, key: DataKey) -> Result<&cldr_serde::plurals::Rules, DataError> {
@@ -83,6 +85,81 @@ impl From<&cldr_serde::plurals::LocalePluralRules> for PluralRulesV1<'static> {
     }
 }
 
+impl crate::DatagenProvider {
+    fn get_plural_ranges(&self) -> Result<&cldr_serde::plurals::PluralRanges, DataError> {
+        Ok(&self
+            .cldr()?
+            .core()
+            .read_and_parse::<cldr_serde::plurals::RangesResource>(
+                "supplemental/pluralRanges.json",
+            )?
+            .supplemental
+            .plurals)
+    }
+}
+
+impl DataProvider<PluralRangesV1Marker> for crate::DatagenProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<PluralRangesV1Marker>, DataError> {
+        self.check_req::<PluralRangesV1Marker>(req)?;
+        // CLDR only lists languages whose ranges need data. For all others, such as languages
+        // whose ranges always have the category of their end, the root locale has no ranges.
+        let ranges = if req.locale.is_und() {
+            PluralRangesV1::default()
+        } else {
+            PluralRangesV1::try_from(
+                self.get_plural_ranges()?
+                    .0
+                    .get(&req.locale.get_langid())
+                    .ok_or(DataErrorKind::MissingLocale.into_error())?,
+            )?
+        };
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(ranges)),
+        })
+    }
+}
+
+impl IterableDataProvider<PluralRangesV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(self
+            .get_plural_ranges()?
+            .0
+            .keys()
+            // TODO(#568): Avoid the clone
+            .cloned()
+            .map(DataLocale::from)
+            .chain([Default::default()])
+            .collect())
+    }
+}
+
+impl TryFrom<&cldr_serde::plurals::LocalePluralRanges> for PluralRangesV1<'static> {
+    type Error = DataError;
+    fn try_from(other: &cldr_serde::plurals::LocalePluralRanges) -> Result<Self, Self::Error> {
+        fn category(s: &str) -> Result<RawPluralCategory, DataError> {
+            PluralCategory::get_for_cldr_string(s)
+                .map(RawPluralCategory::from)
+                .ok_or_else(|| DataError::custom("Unknown plural category").with_display_context(s))
+        }
+        let mut ranges = ZeroMap2d::new();
+        for (key, result) in other.0.iter() {
+            let (start, end) = key
+                .strip_prefix("pluralRange-start-")
+                .and_then(|range| range.split_once("-end-"))
+                .ok_or_else(|| {
+                    DataError::custom("Invalid plural range").with_display_context(key)
+                })?;
+            let (start, end, result) = (category(start)?, category(end)?, category(result)?);
+            // Ranges with the category of their end are not stored.
+            if result != end {
+                ranges.insert(&start, &end, &result);
+            }
+        }
+        Ok(Self { ranges })
+    }
+}
+
 #[test]
 fn test_basic() {
     use icu_locid::langid;
@@ -114,3 +191,46 @@ fn test_basic() {
         cs_rules.get().many
     );
 }
+
+#[test]
+fn test_ranges() {
+    use icu_locid::langid;
+
+    let provider = crate::DatagenProvider::latest_tested_offline_subset();
+
+    let ar_ranges: DataPayload<PluralRangesV1Marker> = provider
+        .load(DataRequest {
+            locale: &langid!("ar").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    let get = |start: PluralCategory, end: PluralCategory| {
+        ar_ranges
+            .get()
+            .ranges
+            .get_copied_2d(&start.into(), &end.into())
+            .map(PluralCategory::from)
+    };
+    assert_eq!(
+        get(PluralCategory::Zero, PluralCategory::One),
+        Some(PluralCategory::Zero)
+    );
+    assert_eq!(
+        get(PluralCategory::Other, PluralCategory::Two),
+        Some(PluralCategory::Other)
+    );
+    assert_eq!(get(PluralCategory::Other, PluralCategory::Few), None);
+
+    let und_ranges: DataPayload<PluralRangesV1Marker> = provider
+        .load(DataRequest {
+            locale: &Default::default(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    assert!(und_ranges.get().ranges.is_empty());
+}
@@ -0,0 +1,79 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::transform::cldr::cldr_serde;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use icu_segmenter::provider::*;
+use zerovec::VarZeroVec;
+
+impl DataProvider<SentenceBreakSuppressionsV1Marker> for crate::DatagenProvider {
+    fn load(
+        &self,
+        req: DataRequest,
+    ) -> Result<DataResponse<SentenceBreakSuppressionsV1Marker>, DataError> {
+        self.check_req::<SentenceBreakSuppressionsV1Marker>(req)?;
+        let langid = req.locale.get_langid();
+
+        let segments = self.cldr()?.segments();
+        // CLDR has no root suppressions, so `und` is empty.
+        let suppressions = if segments.file_exists(&langid, "suppressions.json")? {
+            let resource: &cldr_serde::segments::Resource =
+                segments.read_and_parse(&langid, "suppressions.json")?;
+            resource
+                .segments
+                .segmentations
+                .sentence_break
+                .iter()
+                .flat_map(|b| b.standard.iter())
+                .map(|s| s.suppression.as_str())
+                .collect::<Vec<_>>()
+        } else {
+            Vec::new()
+        };
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(SentenceBreakSuppressionsV1 {
+                suppressions: VarZeroVec::from(&suppressions),
+            })),
+        })
+    }
+}
+
+impl IterableDataProvider<SentenceBreakSuppressionsV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(self
+            .cldr()?
+            .segments()
+            .list_langs()?
+            .chain([Default::default()])
+            .collect::<std::collections::HashSet<_>>()
+            .into_iter()
+            .map(DataLocale::from)
+            .collect())
+    }
+}
+
+#[test]
+fn test_basic() {
+    let provider = crate::DatagenProvider::latest_tested_offline_subset();
+
+    let en: DataPayload<SentenceBreakSuppressionsV1Marker> = provider
+        .load(DataRequest {
+            locale: &icu_locid::langid!("en").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    assert!(en.get().suppressions.iter().any(|s| s == "Mr."));
+
+    let und: DataPayload<SentenceBreakSuppressionsV1Marker> = provider
+        .load(Default::default())
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    assert!(und.get().suppressions.is_empty());
+}
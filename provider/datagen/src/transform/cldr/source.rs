@@ -40,11 +40,11 @@ impl CldrCache {
     }
 
     pub fn numbers(&self) -> CldrDirLang<'_> {
-        CldrDirLang(self, "cldr-numbers".to_owned())
+        CldrDirLang(self, "cldr-numbers".to_owned(), "main")
     }
 
     pub fn misc(&self) -> CldrDirLang<'_> {
-        CldrDirLang(self, "cldr-misc".to_owned())
+        CldrDirLang(self, "cldr-misc".to_owned(), "main")
     }
 
     pub fn bcp47(&self) -> CldrDirNoLang<'_> {
@@ -52,7 +52,12 @@ impl CldrCache {
     }
 
     pub fn displaynames(&self) -> CldrDirLang<'_> {
-        CldrDirLang(self, "cldr-localenames".to_owned())
+        CldrDirLang(self, "cldr-localenames".to_owned(), "main")
+    }
+
+    #[cfg(any(feature = "icu_durationformat", feature = "icu_unitsformatter"))]
+    pub fn units(&self) -> CldrDirLang<'_> {
+        CldrDirLang(self, "cldr-units".to_owned(), "main")
     }
 
     #[cfg(feature = "icu_transliteration")]
@@ -68,9 +73,14 @@ impl CldrCache {
             } else {
                 format!("cldr-cal-{cal}")
             },
+            "main",
         )
     }
 
+    pub fn segments(&self) -> CldrDirLang<'_> {
+        CldrDirLang(self, "cldr-segments".to_owned(), "segments")
+    }
+
     pub fn locales(
         &self,
         levels: impl IntoIterator<Item = CoverageLevel>,
@@ -172,7 +182,7 @@ impl<'a> CldrDirNoLang<'a> {
     }
 }
 
-pub(crate) struct CldrDirLang<'a>(&'a CldrCache, String);
+pub(crate) struct CldrDirLang<'a>(&'a CldrCache, String, &'static str);
 
 impl<'a> CldrDirLang<'a> {
     pub fn read_and_parse<S>(
@@ -184,7 +194,7 @@ impl<'a> CldrDirLang<'a> {
         for<'de> S: serde::Deserialize<'de> + 'static + Send + Sync,
     {
         let dir_suffix = self.0.dir_suffix()?;
-        let path = format!("{}-{dir_suffix}/main/{lang}/{file_name}", self.1);
+        let path = format!("{}-{dir_suffix}/{}/{lang}/{file_name}", self.1, self.2);
         if self.0.serde_cache.file_exists(&path)? {
             self.0.serde_cache.read_and_parse_json(&path)
         } else if let Some(new_langid) = self.0.add_script(lang)? {
@@ -198,7 +208,7 @@ impl<'a> CldrDirLang<'a> {
 
     pub fn list_langs(&self) -> Result<impl Iterator<Item = LanguageIdentifier> + '_, DataError> {
         let dir_suffix = self.0.dir_suffix()?;
-        let path = format!("{}-{dir_suffix}/main", self.1);
+        let path = format!("{}-{dir_suffix}/{}", self.1, self.2);
         Ok(self
             .0
             .serde_cache
@@ -217,7 +227,7 @@ impl<'a> CldrDirLang<'a> {
         file_name: &str,
     ) -> Result<bool, DataError> {
         let dir_suffix = self.0.dir_suffix()?;
-        let path = format!("{}-{dir_suffix}/main/{lang}/{file_name}", self.1);
+        let path = format!("{}-{dir_suffix}/{}/{lang}/{file_name}", self.1, self.2);
         if self.0.serde_cache.file_exists(&path)? {
             Ok(true)
         } else if let Some(new_langid) = self.0.add_script(lang)? {
@@ -0,0 +1,72 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::transform::cldr::cldr_serde;
+use icu_durationformat::provider::*;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+
+impl DataProvider<DigitalDurationDataV1Marker> for crate::DatagenProvider {
+    fn load(
+        &self,
+        req: DataRequest,
+    ) -> Result<DataResponse<DigitalDurationDataV1Marker>, DataError> {
+        self.check_req::<DigitalDurationDataV1Marker>(req)?;
+        let langid = req.locale.get_langid();
+        let resource: &cldr_serde::units::units_display_names::Resource =
+            self.cldr()?.units().read_and_parse(&langid, "units.json")?;
+
+        let pattern = &resource.main.value.units.duration_unit_hms.pattern;
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(DigitalDurationDataV1 {
+                separator: separator(pattern)?.into(),
+            })),
+        })
+    }
+}
+
+impl IterableDataProvider<DigitalDurationDataV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(self
+            .cldr()?
+            .units()
+            .list_langs()?
+            .map(DataLocale::from)
+            .collect())
+    }
+}
+
+/// Returns the separator between hours and minutes of a pattern such as `h:mm:ss`.
+fn separator(pattern: &str) -> Result<String, DataError> {
+    pattern
+        .trim_start_matches(['h', 'H'])
+        .split_once('m')
+        .map(|(separator, _)| separator.replace('\'', ""))
+        .filter(|separator| !separator.is_empty())
+        .ok_or_else(|| {
+            DataError::custom("Invalid duration unit pattern").with_display_context(pattern)
+        })
+}
+
+#[test]
+fn test_basic() {
+    use icu_locid::locale;
+
+    let provider = crate::DatagenProvider::latest_tested_offline_subset();
+
+    let en: DataPayload<DigitalDurationDataV1Marker> = provider
+        .load(DataRequest {
+            locale: &locale!("en").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    assert_eq!(en.get().separator, ":");
+
+    assert_eq!(separator("h.mm.ss").unwrap(), ".");
+    assert_eq!(separator("HH'h'mm").unwrap(), "h");
+    assert!(separator("hmmss").is_err());
+}
@@ -0,0 +1,194 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use std::collections::BTreeMap;
+
+use crate::transform::cldr::cldr_serde;
+use crate::transform::cldr::cldr_serde::units::units_display_names::Patterns;
+use icu_plurals::{provider::RawPluralCategory, PluralCategory};
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use icu_unitsformatter::provider::*;
+use zerovec::{ZeroMap, ZeroMap2d};
+
+macro_rules! make_data_provider {
+    ($($marker: ident => $width: ident),+ $(,)?) => {
+        $(
+            impl DataProvider<$marker> for crate::DatagenProvider {
+                fn load(&self, req: DataRequest) -> Result<DataResponse<$marker>, DataError> {
+                    self.check_req::<$marker>(req)?;
+                    let langid = req.locale.get_langid();
+                    let resource: &cldr_serde::units::units_display_names::Resource = self
+                        .cldr()?
+                        .units()
+                        .read_and_parse(&langid, "units.json")?;
+
+                    Ok(DataResponse {
+                        metadata: Default::default(),
+                        payload: Some(DataPayload::from_owned(
+                            convert(&resource.main.value.units.$width)?,
+                        )),
+                    })
+                }
+            }
+
+            impl IterableDataProvider<$marker> for crate::DatagenProvider {
+                fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+                    Ok(self
+                        .cldr()?
+                        .units()
+                        .list_langs()?
+                        .map(DataLocale::from)
+                        .collect())
+                }
+            }
+        )+
+    };
+}
+
+make_data_provider!(
+    LongUnitsDisplayNamesV1Marker => long,
+    ShortUnitsDisplayNamesV1Marker => short,
+    NarrowUnitsDisplayNamesV1Marker => narrow,
+);
+
+/// Returns the plural patterns of an entry, such as `unitPattern-count-one`, for the given
+/// key prefix. Patterns for grammatical cases and genders are skipped.
+fn plural_patterns<'a>(
+    patterns: &'a Patterns,
+    prefix: &'a str,
+) -> impl Iterator<Item = Result<(RawPluralCategory, &'a str), DataError>> + 'a {
+    patterns.others.iter().filter_map(move |(key, pattern)| {
+        let category = key.strip_prefix(prefix)?.strip_prefix("-count-")?;
+        if category.contains('-') {
+            return None;
+        }
+        Some(
+            PluralCategory::get_for_cldr_string(category)
+                .map(|category| (category.into(), pattern.as_str()))
+                .ok_or_else(|| {
+                    DataError::custom("Unknown plural category").with_display_context(key)
+                }),
+        )
+    })
+}
+
+fn compound_pattern<'a>(
+    width: &'a BTreeMap<String, Patterns>,
+    key: &str,
+) -> Result<&'a str, DataError> {
+    width
+        .get(key)
+        .and_then(|patterns| patterns.compound_unit_pattern.as_deref())
+        .ok_or_else(|| DataError::custom("Missing compound unit pattern").with_display_context(key))
+}
+
+fn power_patterns(
+    width: &BTreeMap<String, Patterns>,
+    key: &str,
+) -> Result<ZeroMap<'static, RawPluralCategory, str>, DataError> {
+    let patterns = width.get(key).ok_or_else(|| {
+        DataError::custom("Missing compound unit pattern").with_display_context(key)
+    })?;
+    plural_patterns(patterns, "compoundUnitPattern1").collect()
+}
+
+fn convert(width: &BTreeMap<String, Patterns>) -> Result<UnitsDisplayNamesV1<'static>, DataError> {
+    let mut patterns = BTreeMap::<&str, BTreeMap<RawPluralCategory, &str>>::new();
+    let mut per_unit_patterns = BTreeMap::<&str, &str>::new();
+    let mut prefixes = BTreeMap::<i8, &str>::new();
+
+    for (key, entry) in width {
+        if let Some(power) = key.strip_prefix("10p") {
+            let power = power
+                .parse::<i8>()
+                .map_err(|_| DataError::custom("Invalid SI prefix").with_display_context(key))?;
+            if let Some(pattern) = &entry.unit_prefix_pattern {
+                prefixes.insert(power, pattern);
+            }
+            continue;
+        }
+        // Units are keyed by their category and identifier, such as "length-meter".
+        let Some((_category, unit)) = key.split_once('-') else {
+            continue;
+        };
+        if patterns.contains_key(unit) {
+            // The same unit can appear in several categories, keep the first one.
+            continue;
+        }
+        let unit_patterns =
+            plural_patterns(entry, "unitPattern").collect::<Result<BTreeMap<_, _>, _>>()?;
+        if unit_patterns.is_empty() {
+            continue;
+        }
+        patterns.insert(unit, unit_patterns);
+        if let Some(pattern) = &entry.per_unit_pattern {
+            per_unit_patterns.insert(unit, pattern);
+        }
+    }
+
+    Ok(UnitsDisplayNamesV1 {
+        patterns: patterns
+            .iter()
+            .flat_map(|(unit, patterns)| {
+                patterns
+                    .iter()
+                    .map(move |(category, pattern)| (*unit, category, *pattern))
+            })
+            .collect::<ZeroMap2d<_, _, _>>(),
+        per_unit_patterns: per_unit_patterns.into_iter().collect(),
+        per: compound_pattern(width, "per")?.to_owned().into(),
+        times: compound_pattern(width, "times")?.to_owned().into(),
+        power2: power_patterns(width, "power2")?,
+        power3: power_patterns(width, "power3")?,
+        prefixes: prefixes.into_iter().collect(),
+    })
+}
+
+#[test]
+fn test_basic() {
+    use icu_locid::locale;
+
+    let provider = crate::DatagenProvider::latest_tested_offline_subset();
+
+    let en: DataPayload<LongUnitsDisplayNamesV1Marker> = provider
+        .load(DataRequest {
+            locale: &locale!("en").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    let en = en.get();
+
+    assert_eq!(
+        en.patterns.get_2d("meter", &RawPluralCategory::One),
+        Some("{0} meter")
+    );
+    assert_eq!(
+        en.patterns.get_2d("meter", &RawPluralCategory::Other),
+        Some("{0} meters")
+    );
+    assert_eq!(en.per_unit_patterns.get("hour"), Some("{0} per hour"));
+    assert_eq!(en.per, "{0} per {1}");
+    assert_eq!(en.times, "{0}-{1}");
+    assert_eq!(en.power2.get(&RawPluralCategory::Other), Some("square {0}"));
+    assert_eq!(en.prefixes.get(&3), Some("kilo{0}"));
+
+    let fr: DataPayload<ShortUnitsDisplayNamesV1Marker> = provider
+        .load(DataRequest {
+            locale: &locale!("fr").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    assert_eq!(
+        fr.get()
+            .patterns
+            .get_2d("kilometer", &RawPluralCategory::Other),
+        Some("{0}\u{202f}km")
+    );
+}
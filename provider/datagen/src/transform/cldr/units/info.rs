@@ -0,0 +1,152 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+use crate::transform::cldr::cldr_serde;
+use crate::transform::cldr::cldr_serde::units::units_constants::Constant;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use icu_unitsconversion::provider::*;
+use zerovec::ule::encode_varule_to_box;
+use zerovec::ZeroMap;
+
+impl DataProvider<UnitsInfoV1Marker> for crate::DatagenProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<UnitsInfoV1Marker>, DataError> {
+        self.check_req::<UnitsInfoV1Marker>(req)?;
+
+        let units_data: &cldr_serde::units::units_constants::Resource = self
+            .cldr()?
+            .core()
+            .read_and_parse("supplemental/units.json")?;
+        let preferences_data: &cldr_serde::units::units_preferences::Resource = self
+            .cldr()?
+            .core()
+            .read_and_parse("supplemental/unitPreferenceData.json")?;
+        let constants = &units_data.supplemental.unit_constants.constants;
+
+        let conversions = units_data
+            .supplemental
+            .convert_units
+            .iter()
+            .map(|(unit, convert)| {
+                let factor = match &convert.factor {
+                    Some(factor) => evaluate(factor, constants)?,
+                    None => 1.0,
+                };
+                let offset = match &convert.offset {
+                    Some(offset) => evaluate(offset, constants)?,
+                    None => 0.0,
+                };
+                Ok((
+                    unit.as_str(),
+                    ConversionInfo {
+                        factor,
+                        offset,
+                        base_unit: Cow::Borrowed(convert.base_unit.as_str()),
+                    },
+                ))
+            })
+            .collect::<Result<Vec<_>, DataError>>()?;
+
+        // Only the quantities with unit preferences are needed.
+        let quantities = units_data
+            .supplemental
+            .unit_quantities
+            .iter()
+            .filter(|(_, quantity)| {
+                preferences_data
+                    .supplemental
+                    .unit_preference_data
+                    .contains_key(&quantity.quantity)
+            })
+            .map(|(base_unit, quantity)| (quantity.quantity.as_str(), base_unit.as_str()))
+            .collect::<BTreeMap<_, _>>();
+
+        let conversions: ZeroMap<str, ConversionInfoULE> = conversions
+            .iter()
+            .map(|(unit, info)| (*unit, encode_varule_to_box(info)))
+            .collect();
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(UnitsInfoV1 {
+                conversions,
+                quantities: quantities.into_iter().collect(),
+            })),
+        })
+    }
+}
+
+impl IterableDataProvider<UnitsInfoV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(vec![Default::default()])
+    }
+}
+
+/// Evaluates a CLDR conversion expression, such as `ft2_to_m2 * 43560` or `1/360*60`.
+///
+/// An expression is a numerator and an optional denominator, separated by a slash, each of
+/// which is a product of numbers and constants. Constants are looked up in their exact form
+/// and evaluated recursively, so rounding only happens in `f64` arithmetic.
+fn evaluate(expression: &str, constants: &BTreeMap<String, Constant>) -> Result<f64, DataError> {
+    let product = |product: &str| {
+        product.split('*').try_fold(1.0, |acc, factor| {
+            let factor = factor.trim();
+            let value = match factor.parse::<f64>() {
+                Ok(value) => value,
+                Err(_) => evaluate(
+                    &constants
+                        .get(factor)
+                        .ok_or_else(|| {
+                            DataError::custom("Unknown unit constant").with_display_context(factor)
+                        })?
+                        .value,
+                    constants,
+                )?,
+            };
+            Ok::<_, DataError>(acc * value)
+        })
+    };
+    match expression.split_once('/') {
+        Some((numerator, denominator)) => Ok(product(numerator)? / product(denominator)?),
+        None => product(expression),
+    }
+}
+
+#[test]
+fn test_basic() {
+    let provider = crate::DatagenProvider::latest_tested_offline_subset();
+
+    let und: DataPayload<UnitsInfoV1Marker> = provider
+        .load(Default::default())
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    let und = und.get();
+
+    let foot: ConversionInfo = zerofrom::ZeroFrom::zero_from(und.conversions.get("foot").unwrap());
+    assert_eq!(
+        foot,
+        ConversionInfo {
+            factor: 0.3048,
+            offset: 0.0,
+            base_unit: "meter".into(),
+        }
+    );
+
+    let fahrenheit: ConversionInfo =
+        zerofrom::ZeroFrom::zero_from(und.conversions.get("fahrenheit").unwrap());
+    assert_eq!(fahrenheit.base_unit, "kelvin");
+    assert!((fahrenheit.factor - 5.0 / 9.0).abs() < 1e-12);
+    assert!((fahrenheit.offset - 255.372222).abs() < 1e-6);
+
+    let acre: ConversionInfo = zerofrom::ZeroFrom::zero_from(und.conversions.get("acre").unwrap());
+    assert!((acre.factor - 4046.8564224).abs() < 1e-6);
+
+    assert_eq!(und.quantities.get("length"), Some("meter"));
+    assert_eq!(und.quantities.get("speed"), Some("meter-per-second"));
+    assert_eq!(und.quantities.get("force"), None);
+}
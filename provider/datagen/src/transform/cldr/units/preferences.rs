@@ -0,0 +1,108 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::transform::cldr::cldr_serde;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use icu_unitsconversion::provider::*;
+use tinystr::TinyAsciiStr;
+use zerovec::ule::encode_varule_to_box;
+use zerovec::{VarZeroVec, ZeroMap2d, ZeroVec};
+
+impl DataProvider<UnitPreferencesV1Marker> for crate::DatagenProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<UnitPreferencesV1Marker>, DataError> {
+        self.check_req::<UnitPreferencesV1Marker>(req)?;
+
+        let preferences_data: &cldr_serde::units::units_preferences::Resource = self
+            .cldr()?
+            .core()
+            .read_and_parse("supplemental/unitPreferenceData.json")?;
+
+        let mut entries = Vec::new();
+        for (quantity, usages) in &preferences_data.supplemental.unit_preference_data {
+            for (usage, regions) in usages {
+                let key = format!("{quantity}/{usage}");
+                for (region, preferences) in regions {
+                    let region = TinyAsciiStr::<3>::from_str(region)
+                        .map_err(|_| {
+                            DataError::custom("Invalid region").with_display_context(region)
+                        })?
+                        .to_unvalidated();
+                    let thresholds = preferences
+                        .iter()
+                        .map(|preference| match &preference.geq {
+                            Some(geq) => geq.parse::<f64>().map_err(|_| {
+                                DataError::custom("Invalid threshold").with_display_context(geq)
+                            }),
+                            None => Ok(1.0),
+                        })
+                        .collect::<Result<ZeroVec<f64>, _>>()?;
+                    let units = preferences
+                        .iter()
+                        .map(|preference| preference.unit.as_str())
+                        .collect::<Vec<_>>();
+                    entries.push((
+                        key.clone(),
+                        region,
+                        UnitPreferences {
+                            thresholds,
+                            units: VarZeroVec::from(&units),
+                        },
+                    ));
+                }
+            }
+        }
+
+        let preferences: ZeroMap2d<str, _, UnitPreferencesULE> = entries
+            .iter()
+            .map(|(key, region, preferences)| {
+                (key.as_str(), region, encode_varule_to_box(preferences))
+            })
+            .collect();
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(UnitPreferencesV1 { preferences })),
+        })
+    }
+}
+
+impl IterableDataProvider<UnitPreferencesV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(vec![Default::default()])
+    }
+}
+
+#[test]
+fn test_basic() {
+    use tinystr::tinystr;
+
+    let provider = crate::DatagenProvider::latest_tested_offline_subset();
+
+    let und: DataPayload<UnitPreferencesV1Marker> = provider
+        .load(Default::default())
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    let road: UnitPreferences = zerofrom::ZeroFrom::zero_from(
+        und.get()
+            .preferences
+            .get_2d("length/road", &tinystr!(3, "US").to_unvalidated())
+            .unwrap(),
+    );
+    assert_eq!(road.thresholds.to_vec(), [0.5, 100.0, 10.0, 1.0]);
+    assert_eq!(
+        road.units.iter().collect::<Vec<_>>(),
+        ["mile", "foot", "foot", "foot"]
+    );
+
+    let height: UnitPreferences = zerofrom::ZeroFrom::zero_from(
+        und.get()
+            .preferences
+            .get_2d("length/person-height", &tinystr!(3, "001").to_unvalidated())
+            .unwrap(),
+    );
+    assert_eq!(height.units.iter().collect::<Vec<_>>(), ["centimeter"]);
+}
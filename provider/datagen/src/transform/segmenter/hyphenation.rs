@@ -0,0 +1,326 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module contains provider implementations backed by TeX hyphenation patterns.
+
+use icu_locid::LanguageIdentifier;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use icu_segmenter::provider::*;
+use std::collections::BTreeMap;
+use zerovec::ZeroSlice;
+
+/// Pattern files for languages whose patterns only come with a region or variant.
+const DEFAULT_PATTERNS: &[(&str, &str)] =
+    &[("de", "de-1996"), ("el", "el-monoton"), ("en", "en-us")];
+
+/// The contents of a `hyph-{tag}.tex` file.
+#[derive(Debug, PartialEq)]
+struct TexPatterns {
+    patterns: BTreeMap<String, Vec<u8>>,
+    exceptions: BTreeMap<String, Vec<u8>>,
+    left_min: u8,
+    right_min: u8,
+}
+
+impl TexPatterns {
+    fn parse(source: &str) -> Result<Self, DataError> {
+        let mut patterns = BTreeMap::new();
+        for pattern in tex_groups(source, "\\patterns") {
+            let mut letters = String::new();
+            let mut levels = vec![0];
+            for c in pattern.chars() {
+                match (c.to_digit(10), levels.last_mut()) {
+                    (Some(level), Some(last)) => *last = level as u8,
+                    _ => {
+                        letters.push(c);
+                        levels.push(0);
+                    }
+                }
+            }
+            if letters.is_empty() {
+                return Err(
+                    DataError::custom("Invalid hyphenation pattern").with_display_context(pattern)
+                );
+            }
+            patterns.insert(letters, levels);
+        }
+
+        let mut exceptions = BTreeMap::new();
+        for exception in tex_groups(source, "\\hyphenation") {
+            let mut word = String::new();
+            let mut points = Vec::new();
+            for c in exception.chars() {
+                if c == '-' {
+                    points.push(
+                        u8::try_from(word.chars().count())
+                            .map_err(|_| DataError::custom("Hyphenation exception too long"))?,
+                    );
+                } else {
+                    // Like at runtime, characters that do not lowercase to a single character
+                    // are kept as they are.
+                    let mut lower = c.to_lowercase();
+                    word.push(match (lower.next(), lower.next()) {
+                        (Some(l), None) => l,
+                        _ => c,
+                    });
+                }
+            }
+            exceptions.insert(word, points);
+        }
+
+        // hyph-utf8 files declare their minimums in a YAML-like header:
+        // % hyphenmins:
+        // %     typesetting:
+        // %         left:  2
+        // %         right: 3
+        let mut left_min = 2;
+        let mut right_min = 3;
+        let mut hyphenmins_indent = None;
+        let mut typesetting = false;
+        for line in source.lines() {
+            let Some(comment) = line.strip_prefix('%') else {
+                continue;
+            };
+            let indent = comment.len() - comment.trim_start().len();
+            let comment = comment.trim();
+            if comment == "hyphenmins:" {
+                hyphenmins_indent = Some(indent);
+                typesetting = false;
+                continue;
+            }
+            if hyphenmins_indent.map_or(true, |i| indent <= i) {
+                hyphenmins_indent = None;
+                continue;
+            }
+            match comment.split_once(':') {
+                Some(("typesetting", _)) => typesetting = true,
+                Some(("generation", _)) => typesetting = false,
+                Some(("left", value)) if typesetting => {
+                    left_min = value.trim().parse().map_err(|_| {
+                        DataError::custom("Invalid hyphenmins").with_display_context(value)
+                    })?
+                }
+                Some(("right", value)) if typesetting => {
+                    right_min = value.trim().parse().map_err(|_| {
+                        DataError::custom("Invalid hyphenmins").with_display_context(value)
+                    })?
+                }
+                _ => {}
+            }
+        }
+
+        Ok(Self {
+            patterns,
+            exceptions,
+            left_min,
+            right_min,
+        })
+    }
+}
+
+/// Returns the whitespace-separated entries of all `command{...}` groups, ignoring comments.
+fn tex_groups<'a>(source: &'a str, command: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+    let mut in_group = false;
+    source
+        .lines()
+        .map(|line| line.split('%').next().unwrap_or_default())
+        .flat_map(|line| line.split_whitespace())
+        .flat_map(move |token| {
+            let mut entries = Vec::new();
+            let mut rest = token;
+            loop {
+                if in_group {
+                    match rest.split_once('}') {
+                        Some((entry, after)) => {
+                            entries.push(entry);
+                            in_group = false;
+                            rest = after;
+                        }
+                        None => {
+                            entries.push(rest);
+                            break;
+                        }
+                    }
+                } else {
+                    match rest
+                        .split_once(command)
+                        .and_then(|(_, after)| after.strip_prefix('{'))
+                    {
+                        Some(after) => {
+                            in_group = true;
+                            rest = after;
+                        }
+                        None => break,
+                    }
+                }
+            }
+            entries
+        })
+        .filter(|entry| !entry.is_empty())
+}
+
+impl crate::DatagenProvider {
+    /// Returns the pattern file for each supported locale.
+    fn hyphenation_pattern_files(&self) -> Result<Vec<(DataLocale, String)>, DataError> {
+        let files: BTreeMap<String, String> = self
+            .hyphenation()?
+            .list("")?
+            .filter_map(|file| {
+                let tag = file.strip_prefix("hyph-")?.strip_suffix(".tex")?;
+                Some((tag.to_ascii_lowercase(), file))
+            })
+            .collect();
+        Ok(files
+            .iter()
+            .map(|(tag, file)| (tag.as_str(), file))
+            .chain(
+                DEFAULT_PATTERNS
+                    .iter()
+                    .filter(|(language, _)| !files.contains_key(*language))
+                    .filter_map(|(language, tag)| Some((*language, files.get(*tag)?))),
+            )
+            // Private use tags such as `la-x-classic` cannot be represented as data locales.
+            .filter_map(|(tag, file)| {
+                let langid = LanguageIdentifier::try_from_bytes(tag.as_bytes()).ok()?;
+                Some((DataLocale::from(langid), file.clone()))
+            })
+            .collect())
+    }
+}
+
+impl DataProvider<HyphenationPatternsV1Marker> for crate::DatagenProvider {
+    fn load(
+        &self,
+        req: DataRequest,
+    ) -> Result<DataResponse<HyphenationPatternsV1Marker>, DataError> {
+        self.hyphenation()?;
+        self.check_req::<HyphenationPatternsV1Marker>(req)?;
+        let file = self
+            .hyphenation_pattern_files()?
+            .into_iter()
+            .find(|(locale, _)| locale == req.locale)
+            .map(|(_, file)| file)
+            .ok_or(DataErrorKind::MissingLocale.into_error())?;
+        let source = self.hyphenation()?.root.read_to_string(&file)?;
+        let tex = TexPatterns::parse(&source).map_err(|e| e.with_display_context(&file))?;
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(HyphenationPatternsV1 {
+                patterns: tex
+                    .patterns
+                    .iter()
+                    .map(|(letters, levels)| (letters.as_str(), ZeroSlice::from_ule_slice(levels)))
+                    .collect(),
+                exceptions: tex
+                    .exceptions
+                    .iter()
+                    .map(|(word, points)| (word.as_str(), ZeroSlice::from_ule_slice(points)))
+                    .collect(),
+                left_min: tex.left_min,
+                right_min: tex.right_min,
+            })),
+        })
+    }
+}
+
+impl IterableDataProvider<HyphenationPatternsV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        if self.hyphenation().is_err() {
+            // Hyphenation patterns are not part of the default sources, so don't fail exports
+            // of all keys without them.
+            log::info!("No hyphenation patterns were provided, skipping segmenter/hyphenation@1");
+            return Ok(Vec::new());
+        }
+        Ok(self
+            .hyphenation_pattern_files()?
+            .into_iter()
+            .map(|(locale, _)| locale)
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_locid::locale;
+    use icu_segmenter::{Hyphenator, HyphenatorOptions};
+
+    #[test]
+    fn parse() {
+        let source = "% hyphenmins:\n\
+                      %     generation:\n\
+                      %         left:  1\n\
+                      %         right: 1\n\
+                      %     typesetting:\n\
+                      %         left:  2\n\
+                      %         right: 2\n\
+                      % texlicense:\n\
+                      %     left: 5\n\
+                      \\patterns{ % comment\n\
+                      .ach4 a1b\n\
+                      x2y3z}\n\
+                      \\hyphenation{Ta-ble\n\
+                      as-so-ciate}";
+        assert_eq!(
+            TexPatterns::parse(source).unwrap(),
+            TexPatterns {
+                patterns: [
+                    (".ach".into(), vec![0, 0, 0, 0, 4]),
+                    ("ab".into(), vec![0, 1, 0]),
+                    ("xyz".into(), vec![0, 2, 3, 0]),
+                ]
+                .into_iter()
+                .collect(),
+                exceptions: [("table".into(), vec![2]), ("associate".into(), vec![2, 4]),]
+                    .into_iter()
+                    .collect(),
+                left_min: 2,
+                right_min: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn hyphenate() {
+        let provider = crate::DatagenProvider::latest_tested_offline_subset();
+
+        let locales =
+            IterableDataProvider::<HyphenationPatternsV1Marker>::supported_locales(&provider)
+                .unwrap();
+        assert!(locales.contains(&locale!("en").into()));
+        assert!(locales.contains(&locale!("en-US").into()));
+
+        let hyphenator =
+            Hyphenator::try_new_unstable(&provider, &locale!("en").into(), Default::default())
+                .unwrap();
+        assert_eq!(hyphenator.hyphenate_str("Hyphenation"), [2, 6]);
+        assert_eq!(hyphenator.hyphenate_str("table"), [2]);
+
+        let mut options = HyphenatorOptions::default();
+        options.right_min = Some(2);
+        let hyphenator =
+            Hyphenator::try_new_unstable(&provider, &locale!("en-US").into(), options).unwrap();
+        assert_eq!(hyphenator.hyphenate_str("nation"), [2]);
+    }
+
+    #[test]
+    fn missing_source() {
+        let provider = crate::DatagenProvider::default();
+        assert_eq!(
+            IterableDataProvider::<HyphenationPatternsV1Marker>::supported_locales(&provider),
+            Ok(Vec::new())
+        );
+        let error = DataProvider::<HyphenationPatternsV1Marker>::load(
+            &provider,
+            DataRequest {
+                locale: &locale!("en").into(),
+                metadata: Default::default(),
+            },
+        )
+        .unwrap_err();
+        assert!(crate::DatagenProvider::is_missing_hyphenation_error(error));
+    }
+}
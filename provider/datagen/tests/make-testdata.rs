@@ -34,6 +34,8 @@ fn generate_json_and_verify_postcard() {
         .with_icuexport(data_root.join("icuexport"))
         .unwrap()
         .with_segmenter_lstm(data_root.join("lstm"))
+        .unwrap()
+        .with_hyphenation_patterns(data_root.join("hyphenation"))
         .unwrap();
 
     let json_out = Box::new(
@@ -162,12 +162,16 @@ lazy_static::lazy_static! {
 
         // mostly used for provider, may in the future be exposed for options
         "icu::datetime::fields",
-        // experimental
+        // Components bags are not yet exposed over FFI
         "icu::datetime::options::components",
         "icu::datetime::options::preferences",
+        "icu::datetime::DateTimeFormatter::try_new_with_components",
         "icu::datetime::DateTimeFormatter::try_new_experimental",
+        "icu::datetime::TypedDateTimeFormatter::try_new_with_components",
         "icu::datetime::TypedDateTimeFormatter::try_new_experimental",
+        "icu::datetime::TypedZonedDateTimeFormatter::try_new_with_components",
         "icu::datetime::TypedZonedDateTimeFormatter::try_new_experimental",
+        "icu::datetime::ZonedDateTimeFormatter::try_new_with_components",
         "icu::datetime::ZonedDateTimeFormatter::try_new_experimental",
 
         // Not necessary for now
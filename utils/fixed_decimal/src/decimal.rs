@@ -159,6 +159,76 @@ pub enum SignDisplay {
     Negative,
 }
 
+/// Increment used in a rounding operation.
+///
+/// Used by [`FixedDecimal::round_with_mode_and_increment()`].
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum RoundingIncrement {
+    /// Round the least significant digit to any digit (0-9).
+    ///
+    /// This is equivalent to rounding without an increment.
+    MultiplesOf1,
+
+    /// Round the least significant digit to multiples of 2 (0, 2, 4, 6, 8).
+    MultiplesOf2,
+
+    /// Round the least significant digit to multiples of 5 (0, 5).
+    MultiplesOf5,
+
+    /// Round the two least significant digits to multiples of 25 (0, 25, 50, 75).
+    ///
+    /// With this increment, the rounding position index will match the least significant
+    /// digit of the multiple of 25; e.g. the number .25 would have a rounding position
+    /// index of -2.
+    MultiplesOf25,
+}
+
+/// Mode used in a rounding operation.
+///
+/// Each mode corresponds to one of the rounding methods of [`FixedDecimal`], such as
+/// [`FixedDecimal::half_even()`], and is used by [`FixedDecimal::round_with_mode()`].
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum RoundingMode {
+    /// Round towards positive infinity. See [`FixedDecimal::ceil()`].
+    Ceil,
+    /// Round away from zero. See [`FixedDecimal::expand()`].
+    Expand,
+    /// Round towards negative infinity. See [`FixedDecimal::floor()`].
+    Floor,
+    /// Round towards zero. See [`FixedDecimal::trunc()`].
+    Trunc,
+    /// Round to the nearest value, with ties towards positive infinity.
+    /// See [`FixedDecimal::half_ceil()`].
+    HalfCeil,
+    /// Round to the nearest value, with ties away from zero.
+    /// See [`FixedDecimal::half_expand()`].
+    HalfExpand,
+    /// Round to the nearest value, with ties towards negative infinity.
+    /// See [`FixedDecimal::half_floor()`].
+    HalfFloor,
+    /// Round to the nearest value, with ties towards zero.
+    /// See [`FixedDecimal::half_trunc()`].
+    HalfTrunc,
+    /// Round to the nearest value, with ties towards the even neighbor.
+    /// See [`FixedDecimal::half_even()`].
+    HalfEven,
+}
+
+impl Default for RoundingIncrement {
+    fn default() -> Self {
+        Self::MultiplesOf1
+    }
+}
+
+impl Default for RoundingMode {
+    /// Returns [`RoundingMode::HalfExpand`], the rounding mode taught in school.
+    fn default() -> Self {
+        Self::HalfExpand
+    }
+}
+
 impl Default for FixedDecimal {
     /// Returns a `FixedDecimal` representing zero.
     fn default() -> Self {
@@ -1572,6 +1642,237 @@ impl FixedDecimal {
         self
     }
 
+    /// Rounds the number at a particular position using the given [`RoundingMode`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::{FixedDecimal, RoundingMode};
+    /// # use std::str::FromStr;
+    ///
+    /// let mut dec = FixedDecimal::from_str("-3.75").unwrap();
+    /// dec.round_with_mode(-1, RoundingMode::HalfEven);
+    /// assert_eq!("-3.8", dec.to_string());
+    /// let mut dec = FixedDecimal::from_str("-3.75").unwrap();
+    /// dec.round_with_mode(-1, RoundingMode::Ceil);
+    /// assert_eq!("-3.7", dec.to_string());
+    /// ```
+    pub fn round_with_mode(&mut self, position: i16, mode: RoundingMode) {
+        match mode {
+            RoundingMode::Ceil => self.ceil(position),
+            RoundingMode::Expand => self.expand(position),
+            RoundingMode::Floor => self.floor(position),
+            RoundingMode::Trunc => self.trunc(position),
+            RoundingMode::HalfCeil => self.half_ceil(position),
+            RoundingMode::HalfExpand => self.half_expand(position),
+            RoundingMode::HalfFloor => self.half_floor(position),
+            RoundingMode::HalfTrunc => self.half_trunc(position),
+            RoundingMode::HalfEven => self.half_even(position),
+        }
+    }
+
+    /// Rounds the number at a particular position using the given [`RoundingMode`],
+    /// returning the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::{FixedDecimal, RoundingMode};
+    /// # use std::str::FromStr;
+    ///
+    /// let dec = FixedDecimal::from_str("-3.75").unwrap();
+    /// assert_eq!(
+    ///     "-3.8",
+    ///     dec.rounded_with_mode(-1, RoundingMode::HalfExpand).to_string()
+    /// );
+    /// ```
+    pub fn rounded_with_mode(mut self, position: i16, mode: RoundingMode) -> Self {
+        self.round_with_mode(position, mode);
+        self
+    }
+
+    /// Rounds the number at a particular position to a multiple of the given
+    /// [`RoundingIncrement`], using the given [`RoundingMode`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::{FixedDecimal, RoundingIncrement, RoundingMode};
+    /// # use std::str::FromStr;
+    ///
+    /// let mut dec = FixedDecimal::from_str("7.1").unwrap();
+    /// dec.round_with_mode_and_increment(
+    ///     0,
+    ///     RoundingMode::HalfExpand,
+    ///     RoundingIncrement::MultiplesOf5,
+    /// );
+    /// assert_eq!("5", dec.to_string());
+    ///
+    /// let mut dec = FixedDecimal::from_str("1.375").unwrap();
+    /// dec.round_with_mode_and_increment(
+    ///     -2,
+    ///     RoundingMode::HalfEven,
+    ///     RoundingIncrement::MultiplesOf25,
+    /// );
+    /// assert_eq!("1.50", dec.to_string());
+    ///
+    /// let mut dec = FixedDecimal::from_str("-9.9").unwrap();
+    /// dec.round_with_mode_and_increment(
+    ///     -1,
+    ///     RoundingMode::Floor,
+    ///     RoundingIncrement::MultiplesOf2,
+    /// );
+    /// assert_eq!("-10.0", dec.to_string());
+    /// ```
+    pub fn round_with_mode_and_increment(
+        &mut self,
+        position: i16,
+        mode: RoundingMode,
+        increment: RoundingIncrement,
+    ) {
+        let increment: u8 = match increment {
+            RoundingIncrement::MultiplesOf1 => return self.round_with_mode(position, mode),
+            RoundingIncrement::MultiplesOf2 => 2,
+            RoundingIncrement::MultiplesOf5 => 5,
+            RoundingIncrement::MultiplesOf25 => 25,
+        };
+        if position > i16::MAX - 2 || position == i16::MIN {
+            // TODO(#2297): Decide on behavior here
+            return self.round_with_mode(position, mode);
+        }
+
+        // The two digits at the rounding position determine the remainder modulo the increment.
+        let low_digits = 10 * self.digit_at(position + 1) + self.digit_at(position);
+        let remainder = low_digits % increment;
+        let fraction_is_zero = self.is_zero() || self.nonzero_magnitude_end() >= position;
+
+        // Compare the distance to the lower multiple, `remainder + fraction`, with half of the
+        // increment, where `fraction` is the value of the digits after the rounding position.
+        let half_cmp = match (2 * remainder + 1).cmp(&increment) {
+            Ordering::Less => Ordering::Less,
+            Ordering::Greater if 2 * remainder == increment && fraction_is_zero => Ordering::Equal,
+            Ordering::Greater => Ordering::Greater,
+            // `remainder + fraction` is half of the increment if `fraction` is one half.
+            Ordering::Equal => match self.digit_at(position - 1).cmp(&5) {
+                Ordering::Equal if self.nonzero_magnitude_end() < position - 1 => Ordering::Greater,
+                ordering => ordering,
+            },
+        };
+
+        if remainder == 0 && fraction_is_zero {
+            self.trunc(position);
+            return;
+        }
+        let is_negative = self.sign == Sign::Negative;
+        let should_expand = match mode {
+            RoundingMode::Ceil => !is_negative,
+            RoundingMode::Expand => true,
+            RoundingMode::Floor => is_negative,
+            RoundingMode::Trunc => false,
+            _ => match half_cmp {
+                Ordering::Less => false,
+                Ordering::Greater => true,
+                Ordering::Equal => match mode {
+                    RoundingMode::HalfCeil => !is_negative,
+                    RoundingMode::HalfFloor => is_negative,
+                    RoundingMode::HalfTrunc => false,
+                    // The lower multiple is even if its quotient by the increment is even.
+                    RoundingMode::HalfEven => (low_digits / increment) % 2 != 0,
+                    _ => true,
+                },
+            },
+        };
+
+        let lower_multiple = low_digits - remainder;
+        self.trunc(position);
+        self.replace_low_digits(
+            position,
+            if should_expand {
+                lower_multiple + increment
+            } else {
+                lower_multiple
+            },
+        );
+    }
+
+    /// Rounds the number at a particular position to a multiple of the given
+    /// [`RoundingIncrement`], using the given [`RoundingMode`], returning the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::{FixedDecimal, RoundingIncrement, RoundingMode};
+    /// # use std::str::FromStr;
+    ///
+    /// let dec = FixedDecimal::from_str("0.33").unwrap();
+    /// assert_eq!(
+    ///     "0.25",
+    ///     dec.rounded_with_mode_and_increment(
+    ///         -2,
+    ///         RoundingMode::HalfExpand,
+    ///         RoundingIncrement::MultiplesOf25
+    ///     )
+    ///     .to_string()
+    /// );
+    /// ```
+    pub fn rounded_with_mode_and_increment(
+        mut self,
+        position: i16,
+        mode: RoundingMode,
+        increment: RoundingIncrement,
+    ) -> Self {
+        self.round_with_mode_and_increment(position, mode, increment);
+        self
+    }
+
+    /// Replaces the digits at `position + 1` and `position` with the two digits of `value`,
+    /// carrying into `position + 2` if `value` is 100.
+    ///
+    /// The number must not have nonzero digits below `position`, and `position + 2` must not
+    /// overflow.
+    fn replace_low_digits(&mut self, position: i16, value: u8) {
+        debug_assert!(value <= 100);
+        debug_assert!(self.is_zero() || self.nonzero_magnitude_end() >= position);
+        // Expand the digits into a dense list from `top` down to `position`, with a spare
+        // leading zero to absorb the carry.
+        let top = cmp::max(self.magnitude, position + 1) + 1;
+        let mut dense: SmallVec<[u8; 8]> =
+            (position..=top).rev().map(|m| self.digit_at(m)).collect();
+        let len = dense.len();
+        let (mut carry, low) = (value / 100, value % 100);
+        if let Some(digit) = dense.get_mut(len - 2) {
+            *digit = low / 10;
+        }
+        if let Some(digit) = dense.get_mut(len - 1) {
+            *digit = low % 10;
+        }
+        for digit in dense.iter_mut().rev().skip(2) {
+            if carry == 0 {
+                break;
+            }
+            *digit += carry;
+            carry = *digit / 10;
+            *digit %= 10;
+        }
+
+        // Rebuild the sparse representation without leading or trailing zeros.
+        match dense.iter().position(|d| *d != 0) {
+            Some(first) => {
+                let last = dense.iter().rposition(|d| *d != 0).unwrap_or(first);
+                self.magnitude = top - first as i16;
+                self.digits = dense.drain(first..=last).collect();
+                self.upper_magnitude = cmp::max(self.upper_magnitude, self.magnitude);
+            }
+            None => {
+                self.digits.clear();
+                self.magnitude = 0;
+            }
+        }
+
+        #[cfg(debug_assertions)]
+        self.check_invariants();
+    }
+
     /// Concatenate another `FixedDecimal` into the end of this `FixedDecimal`.
     ///
     /// All nonzero digits in `other` must have lower magnitude than nonzero digits in `self`.
@@ -3463,3 +3764,148 @@ fn test_concatenate() {
         }
     }
 }
+
+#[test]
+fn test_round_with_mode_and_increment() {
+    #[derive(Debug)]
+    struct TestCase {
+        pub input: &'static str,
+        pub position: i16,
+        pub mode: RoundingMode,
+        pub increment: RoundingIncrement,
+        pub expected: &'static str,
+    }
+    let cases = [
+        TestCase {
+            input: "7.1",
+            position: 0,
+            mode: RoundingMode::HalfExpand,
+            increment: RoundingIncrement::MultiplesOf5,
+            expected: "5",
+        },
+        TestCase {
+            input: "7.5",
+            position: 0,
+            mode: RoundingMode::HalfExpand,
+            increment: RoundingIncrement::MultiplesOf5,
+            expected: "10",
+        },
+        TestCase {
+            input: "7.5",
+            position: 0,
+            mode: RoundingMode::HalfTrunc,
+            increment: RoundingIncrement::MultiplesOf5,
+            expected: "5",
+        },
+        TestCase {
+            input: "7.50001",
+            position: 0,
+            mode: RoundingMode::HalfTrunc,
+            increment: RoundingIncrement::MultiplesOf5,
+            expected: "10",
+        },
+        TestCase {
+            input: "-7.5",
+            position: 0,
+            mode: RoundingMode::HalfCeil,
+            increment: RoundingIncrement::MultiplesOf5,
+            expected: "-5",
+        },
+        TestCase {
+            input: "-7.5",
+            position: 0,
+            mode: RoundingMode::HalfFloor,
+            increment: RoundingIncrement::MultiplesOf5,
+            expected: "-10",
+        },
+        TestCase {
+            input: "3",
+            position: 0,
+            mode: RoundingMode::HalfEven,
+            increment: RoundingIncrement::MultiplesOf2,
+            expected: "4",
+        },
+        TestCase {
+            input: "5",
+            position: 0,
+            mode: RoundingMode::HalfEven,
+            increment: RoundingIncrement::MultiplesOf2,
+            expected: "4",
+        },
+        TestCase {
+            input: "1.125",
+            position: -2,
+            mode: RoundingMode::HalfEven,
+            increment: RoundingIncrement::MultiplesOf25,
+            expected: "1.00",
+        },
+        TestCase {
+            input: "1.375",
+            position: -2,
+            mode: RoundingMode::HalfEven,
+            increment: RoundingIncrement::MultiplesOf25,
+            expected: "1.50",
+        },
+        TestCase {
+            input: "0.33",
+            position: -2,
+            mode: RoundingMode::HalfExpand,
+            increment: RoundingIncrement::MultiplesOf25,
+            expected: "0.25",
+        },
+        TestCase {
+            input: "0.01",
+            position: -2,
+            mode: RoundingMode::Ceil,
+            increment: RoundingIncrement::MultiplesOf25,
+            expected: "0.25",
+        },
+        TestCase {
+            input: "-0.01",
+            position: -2,
+            mode: RoundingMode::Ceil,
+            increment: RoundingIncrement::MultiplesOf25,
+            expected: "-0.00",
+        },
+        TestCase {
+            input: "999.9",
+            position: -1,
+            mode: RoundingMode::Expand,
+            increment: RoundingIncrement::MultiplesOf2,
+            expected: "1000.0",
+        },
+        TestCase {
+            input: "987.6",
+            position: 1,
+            mode: RoundingMode::Trunc,
+            increment: RoundingIncrement::MultiplesOf25,
+            expected: "750",
+        },
+        TestCase {
+            input: "987.6",
+            position: 1,
+            mode: RoundingMode::Expand,
+            increment: RoundingIncrement::MultiplesOf25,
+            expected: "1000",
+        },
+        TestCase {
+            input: "12.5",
+            position: 0,
+            mode: RoundingMode::HalfExpand,
+            increment: RoundingIncrement::MultiplesOf1,
+            expected: "13",
+        },
+        TestCase {
+            input: "0",
+            position: -1,
+            mode: RoundingMode::Expand,
+            increment: RoundingIncrement::MultiplesOf5,
+            expected: "0.0",
+        },
+    ];
+    for cas in &cases {
+        let mut dec = FixedDecimal::from_str(cas.input).unwrap();
+        dec.round_with_mode_and_increment(cas.position, cas.mode, cas.increment);
+        assert_eq!(cas.expected, dec.to_string(), "{cas:?}");
+    }
+}
@@ -47,7 +47,7 @@ impl<V: Writeable> fmt::Display for WriteableMessage<V> {
 fn main() {
     icu_benchmark_macros::main_setup!();
 
-    let (string, parts) = writeable_to_parts_for_test(&WriteableMessage("world")).unwrap();
+    let (string, parts) = writeable_to_parts(&WriteableMessage("world")).unwrap();
 
     assert_eq!(string, "Hello world 😅");
 
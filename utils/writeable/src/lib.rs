@@ -28,6 +28,10 @@
 //! 1. More efficient, since the sink can pre-allocate bytes.
 //! 2. Smaller code, since the format machinery can be short-circuited.
 //!
+//! Formatters can also annotate spans of their output with [`Part`]s, such as the integer digits
+//! of a number or the month of a date, by implementing [`Writeable::write_to_parts`]. See
+//! [`PartsWrite`] and [`writeable_to_parts`] for how to consume these annotations.
+//!
 //! # Examples
 //!
 //! ```
@@ -166,9 +170,68 @@ pub struct Part {
 }
 
 /// A sink that supports annotating parts of the string with `Part`s.
+///
+/// Formatters annotate their output by implementing [`Writeable::write_to_parts`]. Consumers
+/// that want to know which spans of the output are, for example, the integer digits of a
+/// number or the month of a date, implement this trait on their sink and call
+/// [`Writeable::write_to_parts`], or use [`writeable_to_parts`].
+///
+/// # Examples
+///
+/// A sink that wraps every part in brackets:
+///
+/// ```
+/// use core::fmt::{self, Write};
+/// use writeable::{Part, PartsWrite, Writeable};
+///
+/// struct Brackets(String);
+///
+/// impl fmt::Write for Brackets {
+///     fn write_str(&mut self, s: &str) -> fmt::Result {
+///         self.0.write_str(s)
+///     }
+/// }
+///
+/// impl PartsWrite for Brackets {
+///     type SubPartsWrite = Self;
+///
+///     fn with_part(
+///         &mut self,
+///         part: Part,
+///         mut f: impl FnMut(&mut Self) -> fmt::Result,
+///     ) -> fmt::Result {
+///         write!(self.0, "[{}:", part.value)?;
+///         f(self)?;
+///         self.0.write_char(']')
+///     }
+/// }
+///
+/// struct Greeting;
+///
+/// impl Writeable for Greeting {
+///     fn write_to_parts<S: PartsWrite + ?Sized>(&self, sink: &mut S) -> fmt::Result {
+///         sink.write_str("Hello, ")?;
+///         sink.with_part(
+///             Part {
+///                 category: "greeting",
+///                 value: "name",
+///             },
+///             |sink| sink.write_str("Alice"),
+///         )
+///     }
+/// }
+///
+/// let mut sink = Brackets(String::new());
+/// Greeting.write_to_parts(&mut sink).unwrap();
+/// assert_eq!(sink.0, "Hello, [name:Alice]");
+/// ```
 pub trait PartsWrite: fmt::Write {
+    /// The sink that is passed to the closure of [`with_part`](Self::with_part).
     type SubPartsWrite: PartsWrite + ?Sized;
 
+    /// Annotates everything that `f` writes to the sink with the given `Part`.
+    ///
+    /// Parts can be nested: the closure may itself call `with_part`.
     fn with_part(
         &mut self,
         part: Part,
@@ -176,35 +239,41 @@ pub trait PartsWrite: fmt::Write {
     ) -> fmt::Result;
 }
 
+/// A [`PartsWrite`] that writes to a [`fmt::Write`] sink, discarding the `Part` annotations.
+///
+/// This is what [`Writeable::write_to`] uses to call [`Writeable::write_to_parts`].
+#[derive(Debug)]
+#[allow(clippy::exhaustive_structs)] // newtype
+pub struct CoreWriteAsPartsWrite<W: fmt::Write + ?Sized>(pub W);
+
+impl<W: fmt::Write + ?Sized> fmt::Write for CoreWriteAsPartsWrite<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0.write_str(s)
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.0.write_char(c)
+    }
+}
+
+impl<W: fmt::Write + ?Sized> PartsWrite for CoreWriteAsPartsWrite<W> {
+    type SubPartsWrite = CoreWriteAsPartsWrite<W>;
+
+    fn with_part(
+        &mut self,
+        _part: Part,
+        mut f: impl FnMut(&mut Self::SubPartsWrite) -> fmt::Result,
+    ) -> fmt::Result {
+        f(self)
+    }
+}
+
 /// `Writeable` is an alternative to `std::fmt::Display` with the addition of a length function.
 pub trait Writeable {
     /// Writes a string to the given sink. Errors from the sink are bubbled up.
     /// The default implementation delegates to `write_to_parts`, and discards any
     /// `Part` annotations.
     fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
-        struct CoreWriteAsPartsWrite<W: fmt::Write + ?Sized>(W);
-        impl<W: fmt::Write + ?Sized> fmt::Write for CoreWriteAsPartsWrite<W> {
-            fn write_str(&mut self, s: &str) -> fmt::Result {
-                self.0.write_str(s)
-            }
-
-            fn write_char(&mut self, c: char) -> fmt::Result {
-                self.0.write_char(c)
-            }
-        }
-
-        impl<W: fmt::Write + ?Sized> PartsWrite for CoreWriteAsPartsWrite<W> {
-            type SubPartsWrite = CoreWriteAsPartsWrite<W>;
-
-            fn with_part(
-                &mut self,
-                _part: Part,
-                mut f: impl FnMut(&mut Self::SubPartsWrite) -> fmt::Result,
-            ) -> fmt::Result {
-                f(self)
-            }
-        }
-
         self.write_to_parts(&mut CoreWriteAsPartsWrite(sink))
     }
 
@@ -392,6 +461,45 @@ macro_rules! assert_writeable_parts_eq {
 #[allow(clippy::type_complexity)]
 pub fn writeable_to_parts_for_test<W: Writeable>(
     writeable: &W,
+) -> Result<(String, Vec<(usize, usize, Part)>), fmt::Error> {
+    writeable_to_parts(writeable)
+}
+
+/// Writes a [`Writeable`] to a new `String`, together with its [`Part`] annotations.
+///
+/// The annotations are returned as `(start, end, part)` byte ranges into the string, sorted by
+/// start and then by descending length, so that a part comes before the parts nested in it.
+/// Empty parts are omitted.
+///
+/// # Examples
+///
+/// ```
+/// use core::fmt::{self, Write};
+/// use writeable::{Part, PartsWrite, Writeable};
+///
+/// const NAME: Part = Part {
+///     category: "greeting",
+///     value: "name",
+/// };
+///
+/// struct Greeting<'a>(&'a str);
+///
+/// impl Writeable for Greeting<'_> {
+///     fn write_to_parts<S: PartsWrite + ?Sized>(&self, sink: &mut S) -> fmt::Result {
+///         sink.write_str("Hello, ")?;
+///         sink.with_part(NAME, |sink| sink.write_str(self.0))?;
+///         sink.write_char('!')
+///     }
+/// }
+///
+/// let (string, parts) = writeable::writeable_to_parts(&Greeting("Alice")).unwrap();
+///
+/// assert_eq!(string, "Hello, Alice!");
+/// assert_eq!(parts, [(7, 12, NAME)]);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn writeable_to_parts<W: Writeable + ?Sized>(
+    writeable: &W,
 ) -> Result<(String, Vec<(usize, usize, Part)>), fmt::Error> {
     struct State {
         string: alloc::string::String,
@@ -414,11 +522,14 @@ pub fn writeable_to_parts_for_test<W: Writeable>(
             part: Part,
             mut f: impl FnMut(&mut Self::SubPartsWrite) -> fmt::Result,
         ) -> fmt::Result {
+            // Reserve the slot when the part opens, so that a part precedes the parts nested
+            // in it even if they span the same range.
             let start = self.string.len();
+            let index = self.parts.len();
+            self.parts.push((start, start, part));
             f(self)?;
-            let end = self.string.len();
-            if start < end {
-                self.parts.push((start, end, part));
+            if let Some((_, end, _)) = self.parts.get_mut(index) {
+                *end = self.string.len();
             }
             Ok(())
         }
@@ -430,9 +541,10 @@ pub fn writeable_to_parts_for_test<W: Writeable>(
     };
     writeable.write_to_parts(&mut state)?;
 
+    state.parts.retain(|(begin, end, _)| begin < end);
     // Sort by first open and last closed
     state
         .parts
-        .sort_unstable_by_key(|(begin, end, _)| (*begin, end.wrapping_neg()));
+        .sort_by_key(|(begin, end, _)| (*begin, end.wrapping_neg()));
     Ok((state.string, state.parts))
 }